@@ -0,0 +1,409 @@
+//! Role-based access control helpers
+//!
+//! Mirrors the router's `require_role` rules so backends can answer "what can
+//! this key do?" before submitting a transaction, and turn a would-be
+//! `InsufficientPermissions` failure into an actionable message for ops staff.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use soroban_sdk::Address;
+use crate::{ContractResult, ContractError, IntegrationRouterClient};
+
+/// Router user role
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UserRole {
+    SuperAdmin,
+    SystemAdmin,
+    ComplianceOfficer,
+    Operator,
+    User,
+    Treasury,
+    WithdrawalApprover,
+}
+
+impl UserRole {
+    /// Role name as used by the router contract
+    pub fn name(&self) -> &'static str {
+        match self {
+            UserRole::SuperAdmin => "SuperAdmin",
+            UserRole::SystemAdmin => "SystemAdmin",
+            UserRole::ComplianceOfficer => "ComplianceOfficer",
+            UserRole::Operator => "Operator",
+            UserRole::User => "User",
+            UserRole::Treasury => "Treasury",
+            UserRole::WithdrawalApprover => "WithdrawalApprover",
+        }
+    }
+
+    /// Whether a caller with this role passes the router's check for `required`
+    pub fn satisfies(&self, required: UserRole) -> bool {
+        if *self == UserRole::SuperAdmin {
+            return true;
+        }
+
+        match required {
+            UserRole::SuperAdmin => false,
+            UserRole::SystemAdmin => *self == UserRole::SystemAdmin,
+            UserRole::ComplianceOfficer => *self == UserRole::ComplianceOfficer,
+            UserRole::Operator => matches!(self, UserRole::SystemAdmin | UserRole::Operator),
+            UserRole::User => true,
+            UserRole::Treasury => *self == UserRole::Treasury,
+            UserRole::WithdrawalApprover => *self == UserRole::WithdrawalApprover,
+        }
+    }
+}
+
+/// Authorization rule applied by a router function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum RoleRequirement {
+    /// Checked with the router's `require_role` hierarchy
+    Role(UserRole),
+    /// Caller must hold exactly one of the listed roles
+    AnyOf(&'static [UserRole]),
+}
+
+impl RoleRequirement {
+    /// Whether a caller with `role` meets this requirement
+    pub fn is_met_by(&self, role: UserRole) -> bool {
+        match self {
+            RoleRequirement::Role(required) => role.satisfies(*required),
+            RoleRequirement::AnyOf(roles) => roles.contains(&role),
+        }
+    }
+
+    /// Human-readable description of the accepted roles
+    pub fn describe(&self) -> String {
+        match self {
+            RoleRequirement::Role(UserRole::SuperAdmin) => String::from("SuperAdmin"),
+            RoleRequirement::Role(UserRole::SystemAdmin) => String::from("SystemAdmin or SuperAdmin"),
+            RoleRequirement::Role(UserRole::ComplianceOfficer) => String::from("ComplianceOfficer or SuperAdmin"),
+            RoleRequirement::Role(UserRole::Operator) => String::from("Operator, SystemAdmin or SuperAdmin"),
+            RoleRequirement::Role(UserRole::User) => String::from("any role"),
+            RoleRequirement::Role(UserRole::Treasury) => String::from("Treasury or SuperAdmin"),
+            RoleRequirement::Role(UserRole::WithdrawalApprover) => String::from("WithdrawalApprover or SuperAdmin"),
+            RoleRequirement::AnyOf(roles) => roles
+                .iter()
+                .map(|role| role.name())
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+}
+
+const COMPLIANCE_AUTHORITY: &[UserRole] = &[
+    UserRole::SuperAdmin,
+    UserRole::SystemAdmin,
+    UserRole::ComplianceOfficer,
+];
+
+/// Router functions and the role each one requires
+pub const ROUTER_PERMISSIONS: &[(&str, RoleRequirement)] = &[
+    // User operations
+    ("execute_cross_token_exchange", RoleRequirement::Role(UserRole::User)),
+    ("user_request_withdrawal", RoleRequirement::Role(UserRole::User)),
+    ("user_request_withdrawal_with_fee", RoleRequirement::Role(UserRole::User)),
+    ("cancel_withdrawal_request", RoleRequirement::Role(UserRole::User)),
+    // Operator workflows
+    ("execute_bitcoin_deposit", RoleRequirement::Role(UserRole::Operator)),
+    ("execute_bitcoin_deposit_nonced", RoleRequirement::Role(UserRole::Operator)),
+    ("execute_btc_deposit_tracked", RoleRequirement::Role(UserRole::Operator)),
+    ("pre_register_deposit", RoleRequirement::Role(UserRole::Operator)),
+    ("update_deposit_confirmations", RoleRequirement::Role(UserRole::Operator)),
+    ("expire_pre_registered_deposits", RoleRequirement::Role(UserRole::Operator)),
+    ("submit_deposit_spv_proof", RoleRequirement::Role(UserRole::Operator)),
+    ("execute_token_withdrawal", RoleRequirement::Role(UserRole::Operator)),
+    ("execute_token_withdrawal_nonced", RoleRequirement::Role(UserRole::Operator)),
+    ("execute_travel_rule_withdrawal", RoleRequirement::Role(UserRole::Operator)),
+    ("execute_token_withdrawal_tracked", RoleRequirement::Role(UserRole::Operator)),
+    ("expire_withdrawal_approvals", RoleRequirement::Role(UserRole::Operator)),
+    ("fulfill_withdrawal_request", RoleRequirement::Role(UserRole::Operator)),
+    ("reject_withdrawal_request", RoleRequirement::Role(UserRole::Operator)),
+    ("record_btc_tx_replacement", RoleRequirement::Role(UserRole::Operator)),
+    ("submit_btc_fee_rates", RoleRequirement::Role(UserRole::Operator)),
+    ("record_dust_deposit", RoleRequirement::Role(UserRole::Operator)),
+    ("consolidate_dust", RoleRequirement::Role(UserRole::Operator)),
+    ("confirm_withdrawal_btc_tx", RoleRequirement::Role(UserRole::Operator)),
+    ("enqueue_withdrawal", RoleRequirement::Role(UserRole::Operator)),
+    ("claim_withdrawal", RoleRequirement::Role(UserRole::Operator)),
+    ("release_withdrawal", RoleRequirement::Role(UserRole::Operator)),
+    ("dequeue_withdrawal_batch", RoleRequirement::Role(UserRole::Operator)),
+    ("record_batch_broadcast", RoleRequirement::Role(UserRole::Operator)),
+    ("confirm_batch_settlement", RoleRequirement::Role(UserRole::Operator)),
+    ("split_batch_output", RoleRequirement::Role(UserRole::Operator)),
+    ("execute_reconciliation_check", RoleRequirement::Role(UserRole::Operator)),
+    ("generate_reconciliation_report", RoleRequirement::Role(UserRole::Operator)),
+    ("generate_auto_proof_of_reserves", RoleRequirement::Role(UserRole::Operator)),
+    ("submit_proof_attestation", RoleRequirement::Role(UserRole::Operator)),
+    ("execute_batch_operation", RoleRequirement::Role(UserRole::Operator)),
+    ("cancel_operation", RoleRequirement::Role(UserRole::Operator)),
+    ("sweep_timed_out_operations", RoleRequirement::Role(UserRole::Operator)),
+    ("prune_event_history", RoleRequirement::Role(UserRole::Operator)),
+    ("match_limit_orders", RoleRequirement::Role(UserRole::Operator)),
+    ("record_metrics_snapshot", RoleRequirement::Role(UserRole::Operator)),
+    ("report_contract_failure", RoleRequirement::Role(UserRole::Operator)),
+    ("raise_alert", RoleRequirement::Role(UserRole::Operator)),
+    ("escalate_alerts", RoleRequirement::Role(UserRole::Operator)),
+    ("escalate_emergency_notifications", RoleRequirement::Role(UserRole::Operator)),
+    ("expire_role_grants", RoleRequirement::Role(UserRole::Operator)),
+    ("report_canary_outcome", RoleRequirement::Role(UserRole::Operator)),
+    ("report_deposit_reorg", RoleRequirement::Role(UserRole::Operator)),
+    ("run_migration", RoleRequirement::Role(UserRole::Operator)),
+    ("verify_compliance_batch", RoleRequirement::Role(UserRole::Operator)),
+    // Compliance
+    ("emergency_pause", RoleRequirement::AnyOf(COMPLIANCE_AUTHORITY)),
+    ("freeze_address", RoleRequirement::AnyOf(COMPLIANCE_AUTHORITY)),
+    ("unfreeze_address", RoleRequirement::AnyOf(COMPLIANCE_AUTHORITY)),
+    ("release_operation_hold", RoleRequirement::Role(UserRole::ComplianceOfficer)),
+    ("acknowledge_discrepancy_alert", RoleRequirement::Role(UserRole::ComplianceOfficer)),
+    ("trigger_emrg_halt_discrepancy", RoleRequirement::Role(UserRole::ComplianceOfficer)),
+    ("execute_remediation", RoleRequirement::Role(UserRole::ComplianceOfficer)),
+    ("propose_clawback", RoleRequirement::AnyOf(&[UserRole::ComplianceOfficer])),
+    ("resolve_reorg_case", RoleRequirement::Role(UserRole::ComplianceOfficer)),
+    // System administration
+    ("assign_withdrawal", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_velocity_config", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_fee_schedule", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_exchange_limits", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_deposit_limits", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_withdrawal_limits", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("clear_limit_override", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("configure_oracle", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_btc_fee_oracle", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("enable_exchange_pair", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("register_token_pair", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_token_pair_enabled", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_liquidity_config", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_market_maker", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_metrics_retention", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("migrate_metrics_history", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_event_retention_policy", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_escalation_policy", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_reserve_guardrails", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("check_reserve_guardrails", RoleRequirement::Role(UserRole::Operator)),
+    ("set_withdrawal_approval_config", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_amount_minimums", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_jurisdiction_rules", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_emergency_sla_targets", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_emergency_contacts", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("start_emergency_drill", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("isolate_contract", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("begin_reintegration", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_reserve_protection_config", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_insurance_config", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_call_resource_profile", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("set_resource_limits", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("register_remediation_playbook", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("schedule_maintenance", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("end_maintenance", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("cleanup_completed_operations", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("compact_completed_operations", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("get_system_health", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("get_operator_scoreboard", RoleRequirement::Role(UserRole::SystemAdmin)),
+    ("generate_audit_report", RoleRequirement::Role(UserRole::SystemAdmin)),
+    // Treasury
+    ("collect_fees", RoleRequirement::Role(UserRole::Treasury)),
+    // Withdrawal approval
+    ("approve_withdrawal", RoleRequirement::Role(UserRole::WithdrawalApprover)),
+    // Super administration
+    ("set_user_role", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("remove_user_role", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("grant_temporary_role", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("revoke_temporary_role", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("resume_operations", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("update_contract_address", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("complete_reintegration", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("set_spv_required", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("configure_reconciliation", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("configure_proof_schedule", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("set_operator_spending_cap", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("set_default_spending_cap", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("reinstate_operator", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("execute_contract_upgrade", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("register_deployed_wasm", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("register_wasm_exports", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("set_interface_manifest", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("register_migration_step", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("propose_conversion_config", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("apply_conversion_config", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("cancel_conversion_config", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("propose_insurance_transfer", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("execute_insurance_transfer", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("cancel_insurance_transfer", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("approve_clawback", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("reject_clawback", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("start_canary_rollout", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("promote_canary_rollout", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("rollback_canary_rollout", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("set_system_parameter", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("declare_parameter", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("set_typed_parameter", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("create_configuration_backup", RoleRequirement::Role(UserRole::SuperAdmin)),
+    ("restore_configuration_backup", RoleRequirement::Role(UserRole::SuperAdmin)),
+];
+
+/// Router functions a key may call
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CapabilitySet {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serde_support::address::serialize"))]
+    pub address: Address,
+    pub role: UserRole,
+    /// Expiry of the temporary grant the role comes from, if any
+    pub grant_expires_at: Option<u64>,
+    pub functions: Vec<&'static str>,
+}
+
+impl CapabilitySet {
+    /// Whether the key may call `function`
+    pub fn can_call(&self, function: &str) -> bool {
+        self.functions.iter().any(|allowed| *allowed == function)
+    }
+}
+
+/// Result of a simulated authorization check
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AuthorizationDecision {
+    pub function: String,
+    pub role: UserRole,
+    pub requirement: RoleRequirement,
+    pub allowed: bool,
+}
+
+impl AuthorizationDecision {
+    /// Explanation suitable for showing to ops staff
+    pub fn message(&self) -> String {
+        if self.allowed {
+            format!("{} may be called by role {}", self.function, self.role.name())
+        } else {
+            format!(
+                "{} requires {}; this key has role {}",
+                self.function,
+                self.requirement.describe(),
+                self.role.name()
+            )
+        }
+    }
+
+    /// Convert a denied decision into the error the router would return
+    pub fn into_result(self) -> ContractResult<()> {
+        if self.allowed {
+            Ok(())
+        } else {
+            Err(ContractError::Integration(shared::IntegrationError::InsufficientPermissions))
+        }
+    }
+}
+
+/// Look up the requirement for a router function
+pub fn required_role(function: &str) -> Option<RoleRequirement> {
+    ROUTER_PERMISSIONS
+        .iter()
+        .find(|(name, _)| *name == function)
+        .map(|(_, requirement)| *requirement)
+}
+
+/// Router functions available to a role
+pub fn functions_for_role(role: UserRole) -> Vec<&'static str> {
+    ROUTER_PERMISSIONS
+        .iter()
+        .filter(|(_, requirement)| requirement.is_met_by(role))
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+/// Capability introspection backed by the router's role assignments
+pub struct AccessControl {
+    router: IntegrationRouterClient,
+}
+
+impl AccessControl {
+    /// Create an access control helper for the given router
+    pub fn new(router: IntegrationRouterClient) -> Self {
+        Self { router }
+    }
+
+    /// Get the router functions a key may call
+    ///
+    /// # Arguments
+    /// * `address` - Key to inspect
+    ///
+    /// # Returns
+    /// * `Ok(CapabilitySet)` - Role in effect, when a temporary grant ends, and allowed functions
+    /// * `Err(ContractError)` - Role lookup failed
+    pub fn get_capabilities(&self, address: &Address) -> ContractResult<CapabilitySet> {
+        let role = self.router.get_user_role(address)?;
+        let grant_expires_at = self.router.get_active_role_grants()?
+            .into_iter()
+            .find(|grant| grant.user == *address)
+            .map(|grant| grant.expires_at);
+
+        Ok(CapabilitySet {
+            address: address.clone(),
+            role,
+            grant_expires_at,
+            functions: functions_for_role(role),
+        })
+    }
+
+    /// Check whether a key would pass the router's authorization for a function
+    ///
+    /// This only covers role checks; pause state, freezes and other runtime
+    /// conditions can still reject the call.
+    ///
+    /// # Arguments
+    /// * `address` - Key that would submit the call
+    /// * `function` - Router function name
+    ///
+    /// # Returns
+    /// * `Ok(AuthorizationDecision)` - Decision with an explanatory message
+    /// * `Err(ContractError)` - Unknown function or role lookup failed
+    pub fn simulate_authorization(&self, address: &Address, function: &str) -> ContractResult<AuthorizationDecision> {
+        let requirement = required_role(function)
+            .ok_or_else(|| ContractError::ParseError(format!("unknown router function: {}", function)))?;
+        let role = self.router.get_user_role(address)?;
+
+        Ok(AuthorizationDecision {
+            function: String::from(function),
+            role,
+            requirement,
+            allowed: requirement.is_met_by(role),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_capabilities() {
+        let operator = functions_for_role(UserRole::Operator);
+        assert!(operator.contains(&"execute_bitcoin_deposit"));
+        assert!(!operator.contains(&"freeze_address"));
+        assert!(!operator.contains(&"set_user_role"));
+
+        assert!(UserRole::SystemAdmin.satisfies(UserRole::Operator));
+        assert!(!UserRole::SystemAdmin.satisfies(UserRole::ComplianceOfficer));
+        assert!(required_role("freeze_address").unwrap().is_met_by(UserRole::SystemAdmin));
+        // Clawback dual approval needs an actual officer, so that is the one
+        // function a SuperAdmin cannot call
+        let super_admin = functions_for_role(UserRole::SuperAdmin);
+        assert_eq!(super_admin.len(), ROUTER_PERMISSIONS.len() - 1);
+        assert!(!super_admin.contains(&"propose_clawback"));
+        assert!(functions_for_role(UserRole::ComplianceOfficer).contains(&"propose_clawback"));
+        assert!(required_role("not_a_function").is_none());
+
+        let approver = functions_for_role(UserRole::WithdrawalApprover);
+        assert!(approver.contains(&"approve_withdrawal"));
+        assert!(!approver.contains(&"execute_token_withdrawal"));
+        assert!(!functions_for_role(UserRole::Operator).contains(&"approve_withdrawal"));
+
+        let user = functions_for_role(UserRole::User);
+        assert!(user.contains(&"user_request_withdrawal"));
+        assert!(!user.contains(&"fulfill_withdrawal_request"));
+    }
+}
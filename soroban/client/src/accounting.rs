@@ -0,0 +1,439 @@
+//! Double-entry journal of on-chain flows
+//!
+//! `AccountingLedger` turns router events into balanced journal entries so
+//! auditors get a conventional journal view of custody activity. A deposit
+//! debits the BTC reserve and credits the user's iSTSi liability; a
+//! withdrawal reverses that and credits fee income with any iSTSi burned
+//! beyond the BTC paid out. iSTSi is issued 1:1 against satoshis, so postings
+//! in both assets share a unit. Exchanges post one entry per token through a
+//! clearing account, with the exchange fee credited to fee income.
+//!
+//! Running balances are kept per account and every posting is checked for
+//! imbalance, duplicates, negative user balances and reserve shortfall. These
+//! checks complement the router's on-chain reconciliation: they catch event
+//! streams that do not add up before the next reconciliation run does.
+
+use alloc::collections::BTreeMap as HashMap;
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::format;
+use soroban_sdk::Address;
+use crate::event_monitor::{ContractEvent, EventData};
+use crate::reporting::{address_to_string, BTC_ASSET, ISTSI_ASSET};
+
+/// Ledger account
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LedgerAccount {
+    /// BTC held in custody
+    Reserve,
+    /// Tokens owed to a user (user, asset)
+    UserBalance(String, String),
+    /// Fees earned, per asset
+    FeeIncome(String),
+    /// Exchange settlement, per asset
+    ExchangeClearing(String),
+}
+
+impl LedgerAccount {
+    /// Account name as shown in journal exports
+    pub fn name(&self) -> String {
+        match self {
+            LedgerAccount::Reserve => String::from("reserve:BTC"),
+            LedgerAccount::UserBalance(user, asset) => format!("user:{}:{}", user, asset),
+            LedgerAccount::FeeIncome(asset) => format!("fees:{}", asset),
+            LedgerAccount::ExchangeClearing(asset) => format!("clearing:{}", asset),
+        }
+    }
+}
+
+/// Side of a journal line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EntrySide {
+    Debit,
+    Credit,
+}
+
+/// Single posting within a journal entry
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JournalLine {
+    pub account: LedgerAccount,
+    pub side: EntrySide,
+    pub asset: String,
+    pub amount: u64,
+}
+
+/// Journal entry for one on-chain flow
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JournalEntry {
+    pub entry_id: String,
+    pub timestamp: u64,
+    pub description: String,
+    pub lines: Vec<JournalLine>,
+}
+
+impl JournalEntry {
+    /// Create an entry with no lines
+    pub fn new(entry_id: &str, timestamp: u64, description: &str) -> Self {
+        Self {
+            entry_id: entry_id.to_string(),
+            timestamp,
+            description: description.to_string(),
+            lines: Vec::new(),
+        }
+    }
+
+    /// Add a debit line
+    pub fn debit(mut self, account: LedgerAccount, asset: &str, amount: u64) -> Self {
+        self.push_line(account, EntrySide::Debit, asset, amount);
+        self
+    }
+
+    /// Add a credit line
+    pub fn credit(mut self, account: LedgerAccount, asset: &str, amount: u64) -> Self {
+        self.push_line(account, EntrySide::Credit, asset, amount);
+        self
+    }
+
+    /// Total of the debit lines
+    pub fn total_debits(&self) -> u64 {
+        self.side_total(EntrySide::Debit)
+    }
+
+    /// Total of the credit lines
+    pub fn total_credits(&self) -> u64 {
+        self.side_total(EntrySide::Credit)
+    }
+
+    /// Whether debits equal credits
+    pub fn is_balanced(&self) -> bool {
+        self.total_debits() == self.total_credits()
+    }
+
+    fn push_line(&mut self, account: LedgerAccount, side: EntrySide, asset: &str, amount: u64) {
+        // Zero postings (e.g. a withdrawal without a fee) are left out of the journal
+        if amount > 0 {
+            self.lines.push(JournalLine { account, side, asset: asset.to_string(), amount });
+        }
+    }
+
+    fn side_total(&self, side: EntrySide) -> u64 {
+        self.lines.iter().filter(|line| line.side == side).map(|line| line.amount).sum()
+    }
+}
+
+/// Inconsistency detected while posting
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LedgerAnomaly {
+    /// Entry debits and credits differ
+    UnbalancedEntry { entry_id: String, debits: u64, credits: u64 },
+    /// Entry ID was already posted; the repeat was ignored
+    DuplicateEntry { entry_id: String },
+    /// A user account owes the platform (more withdrawn than deposited)
+    NegativeBalance { entry_id: String, account: LedgerAccount, balance: i128 },
+    /// BTC reserve no longer covers the iSTSi owed to users
+    ReserveShortfall { entry_id: String, reserve: i128, liabilities: i128 },
+}
+
+/// Double-entry ledger with running balances and anomaly detection
+pub struct AccountingLedger {
+    entries: Vec<JournalEntry>,
+    /// Debit-positive running balance per account
+    balances: HashMap<LedgerAccount, i128>,
+    posted_ids: BTreeSet<String>,
+    anomalies: Vec<LedgerAnomaly>,
+    asset_labels: HashMap<Address, String>,
+}
+
+impl Default for AccountingLedger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccountingLedger {
+    /// Create an empty ledger
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            balances: HashMap::new(),
+            posted_ids: BTreeSet::new(),
+            anomalies: Vec::new(),
+            asset_labels: HashMap::new(),
+        }
+    }
+
+    /// Label a token contract in account names (defaults to its strkey)
+    pub fn with_asset_label(mut self, token: Address, label: &str) -> Self {
+        self.asset_labels.insert(token, label.to_string());
+        self
+    }
+
+    /// Journal a deposit, withdrawal or exchange event
+    ///
+    /// # Returns
+    /// Anomalies detected by the postings; empty for other event types
+    pub fn post_event(&mut self, event: &ContractEvent) -> Vec<LedgerAnomaly> {
+        let entry_id = event.correlation_id()
+            .map(hex::encode)
+            .unwrap_or_else(|| format!("{}:{}", event.transaction_hash, event.event_type));
+
+        match &event.data {
+            EventData::BitcoinDeposit { user, btc_amount, istsi_amount, .. } => {
+                let user = address_to_string(user);
+                let entry = JournalEntry::new(&entry_id, event.timestamp, "bitcoin deposit")
+                    .debit(LedgerAccount::Reserve, BTC_ASSET, *btc_amount)
+                    .credit(LedgerAccount::UserBalance(user, ISTSI_ASSET.to_string()), ISTSI_ASSET, *istsi_amount);
+                self.post_entry(entry)
+            },
+            EventData::TokenWithdrawal { user, istsi_amount, btc_amount, .. } => {
+                let user = address_to_string(user);
+                let fee = istsi_amount.saturating_sub(*btc_amount);
+                let entry = JournalEntry::new(&entry_id, event.timestamp, "token withdrawal")
+                    .debit(LedgerAccount::UserBalance(user, ISTSI_ASSET.to_string()), ISTSI_ASSET, *istsi_amount)
+                    .credit(LedgerAccount::Reserve, BTC_ASSET, *btc_amount)
+                    .credit(LedgerAccount::FeeIncome(ISTSI_ASSET.to_string()), ISTSI_ASSET, fee);
+                self.post_entry(entry)
+            },
+            EventData::CrossTokenExchange { user, from_token, to_token, from_amount, to_amount, fee, .. } => {
+                let user = address_to_string(user);
+                let from_asset = self.asset_label(from_token);
+                let to_asset = self.asset_label(to_token);
+
+                // The fee is taken out of the source amount before conversion
+                let outgoing = JournalEntry::new(&format!("{}:from", entry_id), event.timestamp, "exchange source leg")
+                    .debit(LedgerAccount::UserBalance(user.clone(), from_asset.clone()), &from_asset, *from_amount)
+                    .credit(LedgerAccount::ExchangeClearing(from_asset.clone()), &from_asset, from_amount.saturating_sub(*fee))
+                    .credit(LedgerAccount::FeeIncome(from_asset.clone()), &from_asset, *fee);
+                let incoming = JournalEntry::new(&format!("{}:to", entry_id), event.timestamp, "exchange destination leg")
+                    .debit(LedgerAccount::ExchangeClearing(to_asset.clone()), &to_asset, *to_amount)
+                    .credit(LedgerAccount::UserBalance(user, to_asset.clone()), &to_asset, *to_amount);
+
+                let mut anomalies = self.post_entry(outgoing);
+                anomalies.extend(self.post_entry(incoming));
+                anomalies
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    /// Post a journal entry and update running balances
+    ///
+    /// Unbalanced entries are still posted so balances mirror the chain, but
+    /// are reported. Duplicate entry IDs are skipped.
+    pub fn post_entry(&mut self, entry: JournalEntry) -> Vec<LedgerAnomaly> {
+        let mut anomalies = Vec::new();
+
+        if !self.posted_ids.insert(entry.entry_id.clone()) {
+            anomalies.push(LedgerAnomaly::DuplicateEntry { entry_id: entry.entry_id.clone() });
+            self.anomalies.extend(anomalies.iter().cloned());
+            return anomalies;
+        }
+
+        if !entry.is_balanced() {
+            anomalies.push(LedgerAnomaly::UnbalancedEntry {
+                entry_id: entry.entry_id.clone(),
+                debits: entry.total_debits(),
+                credits: entry.total_credits(),
+            });
+        }
+
+        for line in &entry.lines {
+            let balance = self.balances.entry(line.account.clone()).or_insert(0);
+            match line.side {
+                EntrySide::Debit => *balance += line.amount as i128,
+                EntrySide::Credit => *balance -= line.amount as i128,
+            }
+
+            // User accounts are liabilities and should never carry a debit balance
+            if let LedgerAccount::UserBalance(_, _) = line.account {
+                if *balance > 0 {
+                    anomalies.push(LedgerAnomaly::NegativeBalance {
+                        entry_id: entry.entry_id.clone(),
+                        account: line.account.clone(),
+                        balance: -*balance,
+                    });
+                }
+            }
+        }
+
+        let reserve = self.balance(&LedgerAccount::Reserve);
+        let liabilities = self.istsi_liabilities();
+        if reserve < liabilities {
+            anomalies.push(LedgerAnomaly::ReserveShortfall {
+                entry_id: entry.entry_id.clone(),
+                reserve,
+                liabilities,
+            });
+        }
+
+        self.entries.push(entry);
+        self.anomalies.extend(anomalies.iter().cloned());
+        anomalies
+    }
+
+    /// Debit-positive balance of an account
+    pub fn balance(&self, account: &LedgerAccount) -> i128 {
+        self.balances.get(account).copied().unwrap_or(0)
+    }
+
+    /// All account balances
+    pub fn balances(&self) -> &HashMap<LedgerAccount, i128> {
+        &self.balances
+    }
+
+    /// Sum of every account balance; zero while all entries are balanced
+    pub fn trial_balance(&self) -> i128 {
+        self.balances.values().sum()
+    }
+
+    /// iSTSi owed to users
+    pub fn istsi_liabilities(&self) -> i128 {
+        self.balances
+            .iter()
+            .filter(|(account, _)| matches!(account, LedgerAccount::UserBalance(_, asset) if asset == ISTSI_ASSET))
+            .map(|(_, balance)| -*balance)
+            .sum()
+    }
+
+    /// Posted entries in order
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+
+    /// Every anomaly detected so far
+    pub fn anomalies(&self) -> &[LedgerAnomaly] {
+        &self.anomalies
+    }
+
+    fn asset_label(&self, token: &Address) -> String {
+        self.asset_labels.get(token).cloned().unwrap_or_else(|| address_to_string(token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(name: &str) -> LedgerAccount {
+        LedgerAccount::UserBalance(name.to_string(), ISTSI_ASSET.to_string())
+    }
+
+    #[test]
+    fn test_postings_keep_running_balances_and_flag_anomalies() {
+        let mut ledger = AccountingLedger::new();
+
+        let deposit = JournalEntry::new("dep-1", 100, "bitcoin deposit")
+            .debit(LedgerAccount::Reserve, BTC_ASSET, 100_000)
+            .credit(user("alice"), ISTSI_ASSET, 100_000);
+        assert!(ledger.post_entry(deposit.clone()).is_empty());
+
+        // Withdrawal of 40_000 iSTSi pays 39_600 sats and keeps a 400 fee
+        let withdrawal = JournalEntry::new("wd-1", 200, "token withdrawal")
+            .debit(user("alice"), ISTSI_ASSET, 40_000)
+            .credit(LedgerAccount::Reserve, BTC_ASSET, 39_600)
+            .credit(LedgerAccount::FeeIncome(ISTSI_ASSET.to_string()), ISTSI_ASSET, 400);
+        assert!(ledger.post_entry(withdrawal).is_empty());
+
+        assert_eq!(ledger.balance(&LedgerAccount::Reserve), 60_400);
+        assert_eq!(ledger.balance(&user("alice")), -60_000);
+        assert_eq!(ledger.istsi_liabilities(), 60_000);
+        assert_eq!(ledger.trial_balance(), 0);
+
+        assert_eq!(
+            ledger.post_entry(deposit),
+            alloc::vec![LedgerAnomaly::DuplicateEntry { entry_id: "dep-1".to_string() }]
+        );
+
+        // Minting more than the reserve received breaks the entry and reserve coverage
+        let over_mint = JournalEntry::new("dep-2", 300, "bitcoin deposit")
+            .debit(LedgerAccount::Reserve, BTC_ASSET, 1_000)
+            .credit(user("bob"), ISTSI_ASSET, 5_000);
+        let anomalies = ledger.post_entry(over_mint);
+        assert_eq!(anomalies[0], LedgerAnomaly::UnbalancedEntry { entry_id: "dep-2".to_string(), debits: 1_000, credits: 5_000 });
+        assert!(matches!(anomalies[1], LedgerAnomaly::ReserveShortfall { reserve: 61_400, liabilities: 65_000, .. }));
+        assert_eq!(ledger.trial_balance(), -4_000);
+
+        let overdraw = JournalEntry::new("wd-2", 400, "token withdrawal")
+            .debit(user("carol"), ISTSI_ASSET, 10)
+            .credit(LedgerAccount::Reserve, BTC_ASSET, 10);
+        assert!(ledger.post_entry(overdraw).iter().any(|anomaly| matches!(anomaly, LedgerAnomaly::NegativeBalance { balance: -10, .. })));
+        assert_eq!(ledger.anomalies().len(), 5);
+        assert_eq!(ledger.entries().len(), 4);
+        assert_eq!(user("alice").name(), "user:alice:iSTSi");
+    }
+
+    #[test]
+    fn test_post_event_journals_router_flows() {
+        use soroban_sdk::{testutils::Address as _, BytesN, Env};
+
+        let env = Env::default();
+        let alice = Address::generate(&env);
+        let istsi = Address::generate(&env);
+        let usdc = Address::generate(&env);
+        let mut ledger = AccountingLedger::new()
+            .with_asset_label(istsi.clone(), ISTSI_ASSET)
+            .with_asset_label(usdc.clone(), "USDC");
+        let event = |transaction_hash: &str, data: EventData| ContractEvent {
+            contract_address: Address::generate(&env),
+            event_type: String::from("integration"),
+            topics: Vec::new(),
+            data,
+            timestamp: 100,
+            block_number: 1,
+            transaction_hash: transaction_hash.to_string(),
+        };
+
+        let deposit = event("tx-1", EventData::BitcoinDeposit {
+            user: alice.clone(),
+            btc_amount: 100_000,
+            istsi_amount: 100_000,
+            btc_tx_hash: BytesN::from_array(&env, &[1; 32]),
+            confirmations: 6,
+        });
+        assert!(ledger.post_event(&deposit).is_empty());
+
+        let withdrawal = event("tx-2", EventData::TokenWithdrawal {
+            user: alice.clone(),
+            istsi_amount: 40_000,
+            btc_amount: 39_600,
+            withdrawal_id: BytesN::from_array(&env, &[2; 32]),
+            btc_address: String::from("bc1q"),
+        });
+        assert!(ledger.post_event(&withdrawal).is_empty());
+
+        let exchange = event("tx-3", EventData::CrossTokenExchange {
+            user: alice.clone(),
+            from_token: istsi,
+            to_token: usdc,
+            from_amount: 10_000,
+            to_amount: 9_970,
+            exchange_rate: 10_000,
+            fee: 30,
+        });
+        assert!(ledger.post_event(&exchange).is_empty());
+
+        let alice = address_to_string(&alice);
+        assert_eq!(ledger.balance(&LedgerAccount::Reserve), 60_400);
+        assert_eq!(ledger.balance(&LedgerAccount::FeeIncome(ISTSI_ASSET.to_string())), -430);
+        assert_eq!(ledger.balance(&user(&alice)), -50_000);
+        assert_eq!(ledger.balance(&LedgerAccount::UserBalance(alice, String::from("USDC"))), -9_970);
+        assert_eq!(ledger.entries().len(), 4);
+        assert!(ledger.entries().iter().all(JournalEntry::is_balanced));
+        assert_eq!(ledger.entries()[2].lines.len(), 3);
+        assert_eq!(ledger.trial_balance(), 0);
+
+        // Replays are reported once and leave balances untouched
+        assert!(matches!(ledger.post_event(&deposit)[..], [LedgerAnomaly::DuplicateEntry { .. }]));
+        assert_eq!(ledger.balance(&LedgerAccount::Reserve), 60_400);
+
+        let other = event("tx-4", EventData::Generic { data: HashMap::new() });
+        assert!(ledger.post_event(&other).is_empty());
+        assert_eq!(ledger.entries().len(), 4);
+    }
+}
@@ -3,17 +3,24 @@ use alloc::collections::BTreeMap as HashMap;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use alloc::format;
+use crate::reporting::address_to_string;
 
 /// Contract addresses configuration for different networks
 /// 
 /// This module manages contract addresses across different Soroban networks
 /// (testnet, mainnet, local) and provides configuration management.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContractAddresses {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
     pub integration_router: Option<Address>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
     pub kyc_registry: Option<Address>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
     pub istsi_token: Option<Address>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
     pub reserve_manager: Option<Address>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
     pub fungible_token: Option<Address>,
 }
 
@@ -95,19 +102,19 @@ impl ContractAddresses {
         let mut config = HashMap::new();
 
         if let Some(addr) = &self.integration_router {
-            config.insert("integration_router".to_string(), format!("{:?}", addr));
+            config.insert("integration_router".to_string(), address_to_string(addr));
         }
         if let Some(addr) = &self.kyc_registry {
-            config.insert("kyc_registry".to_string(), format!("{:?}", addr));
+            config.insert("kyc_registry".to_string(), address_to_string(addr));
         }
         if let Some(addr) = &self.istsi_token {
-            config.insert("istsi_token".to_string(), format!("{:?}", addr));
+            config.insert("istsi_token".to_string(), address_to_string(addr));
         }
         if let Some(addr) = &self.reserve_manager {
-            config.insert("reserve_manager".to_string(), format!("{:?}", addr));
+            config.insert("reserve_manager".to_string(), address_to_string(addr));
         }
         if let Some(addr) = &self.fungible_token {
-            config.insert("fungible_token".to_string(), format!("{:?}", addr));
+            config.insert("fungible_token".to_string(), address_to_string(addr));
         }
 
         config
@@ -122,6 +129,7 @@ impl Default for ContractAddresses {
 
 /// Network configuration for Soroban interactions
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkConfig {
     pub network_name: String,
     pub rpc_url: String,
@@ -146,6 +154,16 @@ impl NetworkConfig {
         }
     }
 
+    /// Create staging configuration (testnet ledger, production-like parameters)
+    pub fn staging() -> Self {
+        Self {
+            network_name: "staging".to_string(),
+            min_confirmations: 3,
+            timeout_seconds: 60,
+            ..Self::testnet()
+        }
+    }
+
     /// Create mainnet configuration
     pub fn mainnet() -> Self {
         Self {
@@ -225,6 +243,7 @@ impl NetworkConfig {
 
 /// Contract deployment configuration
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeploymentConfig {
     pub network: NetworkConfig,
     pub deployer_secret: String,
@@ -266,10 +285,72 @@ impl DeploymentConfig {
     }
 }
 
+/// Contracts tracked per environment, in export order
+pub const CONTRACT_NAMES: [&str; 5] = ["integration_router", "kyc_registry", "istsi_token", "reserve_manager", "fungible_token"];
+
+/// Standard deployment environments, in promotion order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeploymentEnvironment {
+    Local,
+    Testnet,
+    Staging,
+    Mainnet,
+}
+
+impl DeploymentEnvironment {
+    /// All standard environments, in promotion order
+    pub const ALL: [DeploymentEnvironment; 4] = [Self::Local, Self::Testnet, Self::Staging, Self::Mainnet];
+
+    /// Registry name of the environment
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::Testnet => "testnet",
+            Self::Staging => "staging",
+            Self::Mainnet => "mainnet",
+        }
+    }
+
+    /// Look up a standard environment by name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|environment| environment.as_str() == name)
+    }
+
+    /// Network the environment is deployed on
+    pub fn network(&self) -> NetworkConfig {
+        match self {
+            Self::Local => NetworkConfig::local(),
+            Self::Testnet => NetworkConfig::testnet(),
+            Self::Staging => NetworkConfig::staging(),
+            Self::Mainnet => NetworkConfig::mainnet(),
+        }
+    }
+}
+
+/// Audit record of an environment promotion
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PromotionRecord {
+    pub from: String,
+    pub to: String,
+    pub promoted_by: String,
+    pub promoted_at: u64,
+    /// Contract name to strkey copied into the target
+    pub addresses: HashMap<String, String>,
+    /// Target addresses that were replaced
+    pub replaced: HashMap<String, String>,
+}
+
 /// Address registry for managing contract addresses across environments
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddressRegistry {
     environments: HashMap<String, ContractAddresses>,
+    networks: HashMap<String, NetworkConfig>,
+    /// Addresses pinned by promotion, per environment
+    pins: HashMap<String, HashMap<String, String>>,
+    audit_trail: Vec<PromotionRecord>,
 }
 
 impl AddressRegistry {
@@ -277,18 +358,61 @@ impl AddressRegistry {
     pub fn new() -> Self {
         Self {
             environments: HashMap::new(),
+            networks: HashMap::new(),
+            pins: HashMap::new(),
+            audit_trail: Vec::new(),
+        }
+    }
+
+    /// Create a registry with the standard environments and no addresses
+    pub fn standard() -> Self {
+        let mut registry = Self::new();
+        for environment in DeploymentEnvironment::ALL {
+            registry.insert_environment(environment.as_str().to_string(), ContractAddresses::new());
         }
+        registry
     }
 
     /// Add addresses for an environment
     /// 
+    /// Standard environments get their network configuration automatically.
+    /// Like `set_address`, this cannot move a pinned contract.
+    /// 
     /// # Arguments
     /// * `environment` - Environment name (e.g., "testnet", "mainnet")
     /// * `addresses` - Contract addresses for the environment
-    pub fn add_environment(&mut self, environment: String, addresses: ContractAddresses) {
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Addresses stored
+    /// * `Err(error)` - A contract is pinned to another address
+    pub fn add_environment(&mut self, environment: String, addresses: ContractAddresses) -> Result<(), String> {
+        if let Some(pins) = self.pins.get(&environment) {
+            let config = addresses.to_config();
+            if let Some((contract_name, pinned)) = pins.iter().find(|(name, pinned)| config.get(*name) != Some(*pinned)) {
+                return Err(format!("{} is pinned to {} in {}", contract_name, pinned, environment));
+            }
+        }
+        self.insert_environment(environment, addresses);
+        Ok(())
+    }
+
+    fn insert_environment(&mut self, environment: String, addresses: ContractAddresses) {
+        if let Some(standard) = DeploymentEnvironment::from_name(&environment) {
+            self.networks.entry(environment.clone()).or_insert_with(|| standard.network());
+        }
         self.environments.insert(environment, addresses);
     }
 
+    /// Set the network configuration of an environment
+    pub fn set_network(&mut self, environment: &str, network: NetworkConfig) {
+        self.networks.insert(environment.to_string(), network);
+    }
+
+    /// Get the network configuration of an environment
+    pub fn get_network(&self, environment: &str) -> Option<&NetworkConfig> {
+        self.networks.get(environment)
+    }
+
     /// Get addresses for an environment
     /// 
     /// # Arguments
@@ -301,6 +425,27 @@ impl AddressRegistry {
         self.environments.get(environment)
     }
 
+    /// Set one contract address in an environment
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Address stored
+    /// * `Err(error)` - Unknown environment or contract, or the contract is pinned to another address
+    pub fn set_address(&mut self, environment: &str, contract_name: &str, address: &str) -> Result<(), String> {
+        if let Some(pinned) = self.pins.get(environment).and_then(|pins| pins.get(contract_name)) {
+            if pinned != address {
+                return Err(format!("{} is pinned to {} in {}", contract_name, pinned, environment));
+            }
+        }
+
+        let addresses = self.environments.get(environment)
+            .ok_or_else(|| format!("Unknown environment: {}", environment))?;
+        let mut config = addresses.to_config();
+        config.insert(contract_name.to_string(), address.to_string());
+        let updated = ContractAddresses::from_config(config)?;
+        self.environments.insert(environment.to_string(), updated);
+        Ok(())
+    }
+
     /// List all available environments
     /// 
     /// # Returns
@@ -309,8 +454,107 @@ impl AddressRegistry {
         self.environments.keys().cloned().collect()
     }
 
+    /// Validate that an environment has every required contract
+    /// 
+    /// # Returns
+    /// * `Ok(())` - All required addresses present
+    /// * `Err(missing)` - Missing contracts, or the unknown environment
+    pub fn validate_environment(&self, environment: &str) -> Result<(), Vec<String>> {
+        match self.environments.get(environment) {
+            Some(addresses) => addresses.validate(),
+            None => Err(alloc::vec![format!("Unknown environment: {}", environment)]),
+        }
+    }
+
+    /// Validate every environment
+    /// 
+    /// # Returns
+    /// * Missing contracts per incomplete environment (empty when all are complete)
+    pub fn validate_all(&self) -> HashMap<String, Vec<String>> {
+        self.environments.iter()
+            .filter_map(|(name, addresses)| addresses.validate().err().map(|missing| (name.clone(), missing)))
+            .collect()
+    }
+
+    /// Copy all addresses from one environment to another and pin them
+    /// 
+    /// The source must be complete, and standard environments can only be
+    /// promoted forward (local, testnet, staging, mainnet). Pinned addresses
+    /// cannot be changed with `set_address` until unpinned.
+    /// 
+    /// # Arguments
+    /// * `from` - Source environment
+    /// * `to` - Target environment (created if missing)
+    /// * `promoted_by` - Operator recorded in the audit trail
+    /// * `timestamp` - Promotion time
+    pub fn promote(&mut self, from: &str, to: &str, promoted_by: &str, timestamp: u64) -> Result<PromotionRecord, String> {
+        if from == to {
+            return Err("Cannot promote an environment to itself".to_string());
+        }
+        if let (Some(source), Some(target)) = (DeploymentEnvironment::from_name(from), DeploymentEnvironment::from_name(to)) {
+            if source > target {
+                return Err(format!("Cannot promote {} back to {}", from, to));
+            }
+        }
+        self.validate_environment(from)
+            .map_err(|missing| format!("{} is incomplete: {}", from, missing.join(", ")))?;
+
+        let addresses = self.environments[from].to_config();
+        let replaced = self.environments.get(to)
+            .map(|current| current.to_config())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(name, address)| addresses.get(name) != Some(address))
+            .collect();
+
+        self.insert_environment(to.to_string(), self.environments[from].clone());
+        self.pins.insert(to.to_string(), addresses.clone());
+
+        let record = PromotionRecord {
+            from: from.to_string(),
+            to: to.to_string(),
+            promoted_by: promoted_by.to_string(),
+            promoted_at: timestamp,
+            addresses,
+            replaced,
+        };
+        self.audit_trail.push(record.clone());
+        Ok(record)
+    }
+
+    /// Addresses pinned in an environment by its last promotion
+    pub fn pinned_addresses(&self, environment: &str) -> Option<&HashMap<String, String>> {
+        self.pins.get(environment)
+    }
+
+    /// Release the pin on one contract
+    pub fn unpin(&mut self, environment: &str, contract_name: &str) -> bool {
+        self.pins.get_mut(environment)
+            .map(|pins| pins.remove(contract_name).is_some())
+            .unwrap_or(false)
+    }
+
+    /// Contracts whose address no longer matches its pin
+    pub fn pin_drift(&self, environment: &str) -> Vec<String> {
+        let current = self.environments.get(environment).map(|addresses| addresses.to_config()).unwrap_or_default();
+        self.pins.get(environment)
+            .map(|pins| pins.iter()
+                .filter(|(name, pinned)| current.get(*name) != Some(*pinned))
+                .map(|(name, _)| name.clone())
+                .collect())
+            .unwrap_or_default()
+    }
+
+    /// Promotions in the order they were made
+    pub fn audit_trail(&self) -> &[PromotionRecord] {
+        &self.audit_trail
+    }
+
     /// Load registry from JSON configuration
     /// 
+    /// Reads the layout written by `to_json`, as well as a bare map of
+    /// environments to addresses.
+    /// 
     /// # Arguments
     /// * `json_config` - JSON configuration string
     /// 
@@ -320,55 +564,476 @@ impl AddressRegistry {
     pub fn from_json(json_config: &str) -> Result<Self, String> {
         let config: serde_json::Value = serde_json::from_str(json_config)
             .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+        let environments = config.get("environments").unwrap_or(&config);
 
         let mut registry = Self::new();
 
-        if let Some(environments) = config.as_object() {
+        if let Some(environments) = environments.as_object() {
             for (env_name, env_config) in environments {
-                if let Some(contracts) = env_config.as_object() {
-                    let mut contract_map = HashMap::new();
-                    for (contract_name, address) in contracts {
-                        if let Some(addr_str) = address.as_str() {
-                            contract_map.insert(contract_name.clone(), addr_str.to_string());
-                        }
-                    }
-                    
-                    let addresses = ContractAddresses::from_config(contract_map)
+                if env_config.is_object() {
+                    let addresses = ContractAddresses::from_config(json_string_map(env_config))
                         .map_err(|e| format!("Failed to parse addresses for {}: {}", env_name, e))?;
                     
-                    registry.add_environment(env_name.clone(), addresses);
+                    registry.insert_environment(env_name.clone(), addresses);
                 }
             }
         }
 
+        if let Some(pins) = config.get("pins").and_then(|pins| pins.as_object()) {
+            for (env_name, contracts) in pins {
+                registry.pins.insert(env_name.clone(), json_string_map(contracts));
+            }
+        }
+        if let Some(records) = config.get("audit_trail").and_then(|records| records.as_array()) {
+            for record in records {
+                let field = |name: &str| record.get(name).and_then(|value| value.as_str()).map(|value| value.to_string());
+                registry.audit_trail.push(PromotionRecord {
+                    from: field("from").ok_or("Audit record without a source environment")?,
+                    to: field("to").ok_or("Audit record without a target environment")?,
+                    promoted_by: field("promoted_by").unwrap_or_default(),
+                    promoted_at: record.get("promoted_at").and_then(|value| value.as_u64()).unwrap_or(0),
+                    addresses: record.get("addresses").map(json_string_map).unwrap_or_default(),
+                    replaced: record.get("replaced").map(json_string_map).unwrap_or_default(),
+                });
+            }
+        }
+
         Ok(registry)
     }
 
-    /// Save registry to JSON configuration
+    /// Save registry to JSON configuration, including pins and the promotion audit trail
     /// 
     /// # Returns
     /// * `Ok(json)` - JSON configuration string
     /// * `Err(error)` - Serialization error
     pub fn to_json(&self) -> Result<String, String> {
-        let mut config = serde_json::Map::new();
+        let string_map = |map: &HashMap<String, String>| serde_json::Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), serde_json::Value::String(v.clone())))
+                .collect()
+        );
+
+        let environments: serde_json::Map<String, serde_json::Value> = self.environments.iter()
+            .map(|(env_name, addresses)| (env_name.clone(), string_map(&addresses.to_config())))
+            .collect();
+        let pins: serde_json::Map<String, serde_json::Value> = self.pins.iter()
+            .map(|(env_name, contracts)| (env_name.clone(), string_map(contracts)))
+            .collect();
+        let audit_trail: Vec<serde_json::Value> = self.audit_trail.iter()
+            .map(|record| serde_json::json!({
+                "from": record.from,
+                "to": record.to,
+                "promoted_by": record.promoted_by,
+                "promoted_at": record.promoted_at,
+                "addresses": string_map(&record.addresses),
+                "replaced": string_map(&record.replaced),
+            }))
+            .collect();
+
+        let config = serde_json::json!({
+            "environments": environments,
+            "pins": pins,
+            "audit_trail": audit_trail,
+        });
+        serde_json::to_string_pretty(&config)
+            .map_err(|e| format!("Failed to serialize JSON: {}", e))
+    }
+
+    /// Load registry from TOML configuration
+    /// 
+    /// Each environment is a table of addresses. Pins are `[pins.<environment>]`
+    /// tables and promotions `[[audit_trail]]` entries, as written by `to_toml`.
+    /// 
+    /// # Arguments
+    /// * `toml_config` - TOML configuration string
+    /// 
+    /// # Returns
+    /// * `Ok(registry)` - Loaded address registry
+    /// * `Err(error)` - Parse error with the offending line
+    pub fn from_toml(toml_config: &str) -> Result<Self, String> {
+        let mut tables: Vec<(String, HashMap<String, String>)> = Vec::new();
+
+        for (index, raw_line) in toml_config.lines().enumerate() {
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix("[[").and_then(|rest| rest.strip_suffix("]]")) {
+                tables.push((format!("[[{}]]", name.trim()), HashMap::new()));
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+                tables.push((name.trim().to_string(), HashMap::new()));
+                continue;
+            }
+
+            let parsed = line.split_once('=').and_then(|(key, value)| {
+                let value = value.trim();
+                let value = match value.strip_prefix('"') {
+                    Some(quoted) => quoted.strip_suffix('"')?,
+                    None => value.parse::<u64>().ok().map(|_| value)?,
+                };
+                Some((key.trim().to_string(), value.to_string()))
+            });
+            match (parsed, tables.last_mut()) {
+                (Some((key, value)), Some((_, contracts))) => {
+                    contracts.insert(key, value);
+                },
+                _ => return Err(format!("Invalid TOML on line {}: {}", index + 1, raw_line)),
+            }
+        }
+
+        let mut registry = Self::new();
+        for (table, mut entries) in tables {
+            if table == "[[audit_trail]]" {
+                let mut take_prefixed = |prefix: &str| -> HashMap<String, String> {
+                    let keys: Vec<String> = entries.keys().filter(|key| key.starts_with(prefix)).cloned().collect();
+                    keys.into_iter()
+                        .filter_map(|key| entries.remove(&key).map(|value| (key[prefix.len()..].to_string(), value)))
+                        .collect()
+                };
+                let addresses = take_prefixed("addresses.");
+                let replaced = take_prefixed("replaced.");
+                registry.audit_trail.push(PromotionRecord {
+                    from: entries.remove("from").ok_or("Audit record without a source environment")?,
+                    to: entries.remove("to").ok_or("Audit record without a target environment")?,
+                    promoted_by: entries.remove("promoted_by").unwrap_or_default(),
+                    promoted_at: entries.get("promoted_at").and_then(|value| value.parse().ok()).unwrap_or(0),
+                    addresses,
+                    replaced,
+                });
+            } else if let Some(env_name) = table.strip_prefix("pins.") {
+                registry.pins.insert(env_name.to_string(), entries);
+            } else {
+                let addresses = ContractAddresses::from_config(entries)
+                    .map_err(|e| format!("Failed to parse addresses for {}: {}", table, e))?;
+                registry.insert_environment(table, addresses);
+            }
+        }
+
+        Ok(registry)
+    }
+
+    /// Save registry to TOML configuration, including pins and the promotion audit trail
+    pub fn to_toml(&self) -> String {
+        let mut tables = Vec::new();
 
         for (env_name, addresses) in &self.environments {
-            let address_map = addresses.to_config();
-            let env_config = serde_json::Value::Object(
-                address_map.into_iter()
-                    .map(|(k, v)| (k, serde_json::Value::String(v)))
-                    .collect()
+            let mut table = format!("[{}]\n", env_name);
+            push_toml_entries(&mut table, &addresses.to_config(), "");
+            tables.push(table);
+        }
+        for (env_name, pins) in &self.pins {
+            let mut table = format!("[pins.{}]\n", env_name);
+            push_toml_entries(&mut table, pins, "");
+            tables.push(table);
+        }
+        for record in &self.audit_trail {
+            let mut table = format!(
+                "[[audit_trail]]\nfrom = \"{}\"\nto = \"{}\"\npromoted_by = \"{}\"\npromoted_at = {}\n",
+                record.from, record.to, record.promoted_by, record.promoted_at
             );
-            config.insert(env_name.clone(), env_config);
+            push_toml_entries(&mut table, &record.addresses, "addresses.");
+            push_toml_entries(&mut table, &record.replaced, "replaced.");
+            tables.push(table);
         }
 
-        serde_json::to_string_pretty(&serde_json::Value::Object(config))
-            .map_err(|e| format!("Failed to serialize JSON: {}", e))
+        tables.join("\n")
+    }
+}
+
+/// Append `contract = "address"` lines in contract order
+fn push_toml_entries(toml: &mut String, entries: &HashMap<String, String>, prefix: &str) {
+    for contract_name in CONTRACT_NAMES {
+        if let Some(address) = entries.get(contract_name) {
+            toml.push_str(&format!("{}{} = \"{}\"\n", prefix, contract_name, address));
+        }
     }
 }
 
+/// String entries of a JSON object
+fn json_string_map(value: &serde_json::Value) -> HashMap<String, String> {
+    value.as_object()
+        .map(|object| object.iter()
+            .filter_map(|(key, value)| value.as_str().map(|value| (key.clone(), value.to_string())))
+            .collect())
+        .unwrap_or_default()
+}
+
 impl Default for AddressRegistry {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+/// Router system parameter types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParamType {
+    U64,
+    Bool,
+    /// Basis points, at most 10000
+    Bps,
+    Address,
+    /// Seconds
+    Duration,
+}
+
+/// Typed router system parameter value
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParamValue {
+    U64(u64),
+    Bool(bool),
+    Bps(u64),
+    /// Strkey
+    Address(String),
+    Duration(u64),
+}
+
+/// Conversion out of a typed parameter value
+pub trait FromParamValue: Sized {
+    fn from_param_value(value: ParamValue) -> Option<Self>;
+}
+
+impl FromParamValue for u64 {
+    fn from_param_value(value: ParamValue) -> Option<Self> {
+        match value {
+            ParamValue::U64(n) | ParamValue::Bps(n) | ParamValue::Duration(n) => Some(n),
+            _ => None,
+        }
+    }
+}
+
+impl FromParamValue for bool {
+    fn from_param_value(value: ParamValue) -> Option<Self> {
+        match value {
+            ParamValue::Bool(flag) => Some(flag),
+            _ => None,
+        }
+    }
+}
+
+impl FromParamValue for String {
+    fn from_param_value(value: ParamValue) -> Option<Self> {
+        match value {
+            ParamValue::Address(address) => Some(address),
+            _ => None,
+        }
+    }
+}
+
+/// Declared type and valid range of a router system parameter
+///
+/// Mirrors the router's parameter schema so values can be checked before
+/// they are submitted.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParameterSchema {
+    pub name: String,
+    pub param_type: ParamType,
+    /// Inclusive bounds for numeric types; ignored for Bool and Address
+    pub min: u64,
+    pub max: u64,
+    pub description: String,
+}
+
+impl ParameterSchema {
+    /// Validate the schema itself
+    pub fn check(&self) -> Result<(), String> {
+        match self.param_type {
+            ParamType::Bool | ParamType::Address => Ok(()),
+            _ if self.min > self.max => Err(format!("{}: min exceeds max", self.name)),
+            ParamType::Bps if self.max > 10000 => Err(format!("{}: basis points exceed 10000", self.name)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Parse a string value the way the router does
+    ///
+    /// # Returns
+    /// * `Ok(value)` - Parsed value within range
+    /// * `Err(error)` - Parse or range error
+    pub fn parse(&self, raw: &str) -> Result<ParamValue, String> {
+        let value = match self.param_type {
+            ParamType::Bool => match raw {
+                "true" => ParamValue::Bool(true),
+                "false" => ParamValue::Bool(false),
+                _ => return Err(format!("{}: expected true or false", self.name)),
+            },
+            ParamType::Address => {
+                if raw.len() != 56 || !(raw.starts_with('G') || raw.starts_with('C')) {
+                    return Err(format!("{}: expected a strkey address", self.name));
+                }
+                ParamValue::Address(raw.to_string())
+            },
+            numeric => {
+                if raw.is_empty() || !raw.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(format!("{}: expected decimal digits", self.name));
+                }
+                let n: u64 = raw.parse().map_err(|_| format!("{}: value overflows u64", self.name))?;
+                match numeric {
+                    ParamType::Bps => ParamValue::Bps(n),
+                    ParamType::Duration => ParamValue::Duration(n),
+                    _ => ParamValue::U64(n),
+                }
+            },
+        };
+
+        self.validate(&value)?;
+        Ok(value)
+    }
+
+    /// Check a typed value against the declared type and range
+    pub fn validate(&self, value: &ParamValue) -> Result<(), String> {
+        let number = match (self.param_type, value) {
+            (ParamType::U64, ParamValue::U64(n))
+            | (ParamType::Bps, ParamValue::Bps(n))
+            | (ParamType::Duration, ParamValue::Duration(n)) => *n,
+            (ParamType::Bool, ParamValue::Bool(_)) | (ParamType::Address, ParamValue::Address(_)) => return Ok(()),
+            _ => return Err(format!("{}: expected a {:?} value", self.name, self.param_type)),
+        };
+
+        if number < self.min || number > self.max || (self.param_type == ParamType::Bps && number > 10000) {
+            return Err(format!("{}: {} outside {}..={}", self.name, number, self.min, self.max));
+        }
+        Ok(())
+    }
+}
+
+/// Local copy of the router's parameter schemas
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParameterRegistry {
+    schemas: HashMap<String, ParameterSchema>,
+}
+
+impl ParameterRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a registry from schemas read from the router
+    pub fn from_schemas(schemas: Vec<ParameterSchema>) -> Result<Self, String> {
+        let mut registry = Self::new();
+        for schema in schemas {
+            registry.declare(schema)?;
+        }
+        Ok(registry)
+    }
+
+    /// Declare (or redeclare) a parameter
+    pub fn declare(&mut self, schema: ParameterSchema) -> Result<(), String> {
+        schema.check()?;
+        self.schemas.insert(schema.name.clone(), schema);
+        Ok(())
+    }
+
+    /// Get a parameter's schema
+    pub fn schema(&self, name: &str) -> Option<&ParameterSchema> {
+        self.schemas.get(name)
+    }
+
+    /// Validate a string value before submitting it
+    ///
+    /// # Returns
+    /// * `Ok(Some(value))` - Parsed value of a declared parameter
+    /// * `Ok(None)` - Undeclared parameter; the router stores it as-is
+    /// * `Err(error)` - The router would reject the value
+    pub fn validate(&self, name: &str, raw: &str) -> Result<Option<ParamValue>, String> {
+        match self.schemas.get(name) {
+            Some(schema) => schema.parse(raw).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_environment_promotion_pins_addresses() {
+        use soroban_sdk::{testutils::Address as _, Env};
+
+        let env = Env::default();
+        let strkey = || address_to_string(&Address::generate(&env));
+        let mut registry = AddressRegistry::standard();
+        assert_eq!(registry.list_environments().len(), 4);
+        assert_eq!(registry.get_network("staging").unwrap().network_passphrase, NetworkConfig::testnet().network_passphrase);
+
+        let router = strkey();
+        for contract_name in ["integration_router", "kyc_registry", "istsi_token"] {
+            registry.set_address("testnet", contract_name, &router).unwrap();
+        }
+        assert_eq!(registry.validate_environment("testnet"), Err(alloc::vec!["reserve_manager".to_string()]));
+        assert!(registry.promote("testnet", "staging", "ops", 100).is_err());
+
+        registry.set_address("testnet", "reserve_manager", &strkey()).unwrap();
+        assert_eq!(registry.validate_all().len(), 3);
+
+        let record = registry.promote("testnet", "staging", "ops", 100).unwrap();
+        assert_eq!(record.addresses.len(), 4);
+        assert!(record.replaced.is_empty());
+        assert!(registry.validate_environment("staging").is_ok());
+        assert!(registry.promote("staging", "testnet", "ops", 200).is_err());
+
+        // Promoted addresses are pinned until released
+        assert!(registry.set_address("staging", "integration_router", &strkey()).is_err());
+        registry.set_address("staging", "integration_router", &router).unwrap();
+        assert!(registry.unpin("staging", "integration_router"));
+        registry.set_address("staging", "integration_router", &strkey()).unwrap();
+        assert!(registry.add_environment("staging".to_string(), ContractAddresses::new()).is_err());
+        assert!(registry.pin_drift("staging").is_empty());
+        assert_eq!(registry.audit_trail().len(), 1);
+
+        // Both export formats round-trip, pins and audit trail included
+        for restored in [AddressRegistry::from_toml(&registry.to_toml()).unwrap(), AddressRegistry::from_json(&registry.to_json().unwrap()).unwrap()] {
+            assert_eq!(restored.get_addresses("testnet").unwrap().to_config(), registry.get_addresses("testnet").unwrap().to_config());
+            assert!(restored.get_network("mainnet").is_some());
+            assert_eq!(restored.pinned_addresses("staging"), registry.pinned_addresses("staging"));
+            assert_eq!(restored.audit_trail(), registry.audit_trail());
+
+            let mut restored = restored;
+            assert!(restored.set_address("staging", "kyc_registry", &strkey()).is_err());
+        }
+        assert!(AddressRegistry::from_toml("integration_router = \"C\"").is_err());
+
+        // Registries saved before pins were exported still load
+        let legacy = AddressRegistry::from_json(&alloc::format!("{{\"testnet\": {{\"integration_router\": \"{}\"}}}}", router)).unwrap();
+        assert_eq!(legacy.get_addresses("testnet").unwrap().to_config()["integration_router"], router);
+    }
+
+    #[test]
+    fn test_parameter_registry_mirrors_router_validation() {
+        let mut registry = ParameterRegistry::new();
+        let fee = ParameterSchema {
+            name: "withdrawal_fee".to_string(),
+            param_type: ParamType::Bps,
+            min: 0,
+            max: 500,
+            description: "Withdrawal fee".to_string(),
+        };
+        assert!(registry.declare(ParameterSchema { max: 20_000, ..fee.clone() }).is_err());
+        registry.declare(fee).unwrap();
+        registry.declare(ParameterSchema {
+            name: "strict_mode".to_string(),
+            param_type: ParamType::Bool,
+            min: 0,
+            max: 0,
+            description: String::new(),
+        }).unwrap();
+
+        assert_eq!(registry.validate("withdrawal_fee", "300"), Ok(Some(ParamValue::Bps(300))));
+        for bad in ["501", "12a", "", "-1", "99999999999999999999999"] {
+            assert!(registry.validate("withdrawal_fee", bad).is_err());
+        }
+        assert_eq!(registry.validate("strict_mode", "true"), Ok(Some(ParamValue::Bool(true))));
+        assert!(registry.validate("strict_mode", "yes").is_err());
+        assert_eq!(registry.validate("timeout", "soon"), Ok(None));
+
+        assert_eq!(u64::from_param_value(ParamValue::Bps(300)), Some(300));
+        assert_eq!(bool::from_param_value(ParamValue::Bps(300)), None);
+    }
+}
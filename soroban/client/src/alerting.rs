@@ -0,0 +1,549 @@
+//! Paging for router system alerts
+//!
+//! The router owns the alert lifecycle: alerts are assigned to the on-call
+//! contact, escalate along the escalation chain while unacknowledged, and can
+//! be snoozed or resolved. `AlertManager` is the client half: it polls the
+//! active alerts and maps them onto paging integrations (PagerDuty, Opsgenie,
+//! chat webhooks) through severity and type routes. A page is sent when an
+//! alert first appears and again each time it escalates; acknowledged and
+//! snoozed alerts are not paged. Like the webhook dispatcher, the manager owns
+//! no network stack and leaves delivery to a `PagingIntegration`.
+//!
+//! Emergency responses notify the router's emergency contacts one at a time
+//! until one acknowledges on-chain. `EmergencyNotifier` delivers each
+//! notification over the contact's preferred channels, resolving the profile's
+//! directory reference to a phone number or mailbox that never goes on-chain.
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap as HashMap;
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use soroban_sdk::BytesN;
+use crate::{ContractResult, ContractError, ContractManager};
+use crate::integration_router_client::{
+    AlertSeverity, ContactChannel, EmergencyContact, EmergencyNotification, EmergencyResponseType, SystemAlert
+};
+use crate::reporting::address_to_string;
+
+/// Page delivered to a paging integration
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Page {
+    pub integration: String,
+    /// Stable per alert so the paging service groups escalations into one incident
+    pub dedup_key: String,
+    pub alert_type: String,
+    pub severity: AlertSeverity,
+    pub message: String,
+    /// Strkey of the contact the router assigned the alert to
+    pub contact: Option<String>,
+    pub escalation_level: u32,
+}
+
+/// Delivery backend for pages
+pub trait PagingIntegration {
+    /// Deliver a page to the paging service
+    fn page(&self, page: &Page) -> Result<(), String>;
+}
+
+/// Which alerts an integration receives
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PagingRoute {
+    pub integration: String,
+    pub min_severity: AlertSeverity,
+    /// Alert types routed here (empty = all types)
+    pub alert_types: Vec<String>,
+}
+
+impl PagingRoute {
+    /// Whether the route applies to an alert
+    pub fn matches(&self, alert: &SystemAlert) -> bool {
+        alert.severity >= self.min_severity
+            && (self.alert_types.is_empty() || self.alert_types.contains(&alert.alert_type))
+    }
+}
+
+/// Paging counters
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlertManagerMetrics {
+    pub pages_sent: u64,
+    pub page_failures: u64,
+    pub alerts_tracked: u32,
+}
+
+/// Maps router alerts to paging integrations
+pub struct AlertManager {
+    integrations: HashMap<String, Box<dyn PagingIntegration>>,
+    routes: Vec<PagingRoute>,
+    /// Escalation state last paged per alert
+    paged: HashMap<BytesN<32>, (AlertSeverity, u32)>,
+    metrics: AlertManagerMetrics,
+}
+
+impl Default for AlertManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AlertManager {
+    /// Create a manager with no integrations or routes
+    pub fn new() -> Self {
+        Self {
+            integrations: HashMap::new(),
+            routes: Vec::new(),
+            paged: HashMap::new(),
+            metrics: AlertManagerMetrics::default(),
+        }
+    }
+
+    /// Register (or replace) a paging integration
+    pub fn register_integration(&mut self, name: &str, integration: Box<dyn PagingIntegration>) {
+        self.integrations.insert(name.to_string(), integration);
+    }
+
+    /// Route alerts to a registered integration
+    pub fn add_route(&mut self, route: PagingRoute) -> ContractResult<()> {
+        if !self.integrations.contains_key(&route.integration) {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+        self.routes.push(route);
+        Ok(())
+    }
+
+    /// Page new and escalated alerts from the router
+    ///
+    /// # Arguments
+    /// * `manager` - Contract manager used to read the active alerts
+    /// * `now` - Current ledger timestamp
+    pub fn poll(&mut self, manager: &ContractManager, now: u64) -> ContractResult<Vec<Page>> {
+        let alerts = manager.get_system_alerts()?;
+        Ok(self.sync(&alerts, now))
+    }
+
+    /// Page alerts that are new or escalated since the last sync
+    ///
+    /// Alerts missing from `alerts` were resolved and are forgotten. A page
+    /// that fails on any route is retried on the next sync.
+    ///
+    /// # Returns
+    /// Pages delivered successfully
+    pub fn sync(&mut self, alerts: &[SystemAlert], now: u64) -> Vec<Page> {
+        self.paged.retain(|alert_id, _| alerts.iter().any(|alert| &alert.alert_id == alert_id));
+
+        let mut delivered = Vec::new();
+        for alert in alerts {
+            let state = (alert.severity, alert.escalation_level);
+            if alert.acknowledged || alert.is_snoozed(now) || self.paged.get(&alert.alert_id) == Some(&state) {
+                continue;
+            }
+
+            let mut all_sent = true;
+            for route in self.routes.iter().filter(|route| route.matches(alert)) {
+                let page = Self::build_page(&route.integration, alert);
+                let result = match self.integrations.get(&route.integration) {
+                    Some(integration) => integration.page(&page),
+                    None => Err(String::from("integration not registered")),
+                };
+
+                match result {
+                    Ok(()) => {
+                        self.metrics.pages_sent += 1;
+                        delivered.push(page);
+                    },
+                    Err(_) => {
+                        self.metrics.page_failures += 1;
+                        all_sent = false;
+                    },
+                }
+            }
+
+            if all_sent {
+                self.paged.insert(alert.alert_id.clone(), state);
+            }
+        }
+
+        delivered
+    }
+
+    /// Get paging counters
+    pub fn metrics(&self) -> AlertManagerMetrics {
+        AlertManagerMetrics {
+            alerts_tracked: self.paged.len() as u32,
+            ..self.metrics.clone()
+        }
+    }
+
+    fn build_page(integration: &str, alert: &SystemAlert) -> Page {
+        Page {
+            integration: integration.to_string(),
+            dedup_key: hex::encode(alert.alert_id.to_array()),
+            alert_type: alert.alert_type.clone(),
+            severity: alert.severity,
+            message: alert.message.clone(),
+            contact: alert.assigned_to.as_ref().map(address_to_string),
+            escalation_level: alert.escalation_level,
+        }
+    }
+}
+
+/// Emergency notification delivered to one contact over one channel
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmergencyMessage {
+    pub channel: ContactChannel,
+    /// Phone number, mailbox or URL resolved from the contact directory
+    pub destination: String,
+    /// Stable per emergency response so repeated notifications group together
+    pub dedup_key: String,
+    pub subject: String,
+    pub body: String,
+    /// Position of the contact in the router's contact list
+    pub level: u32,
+    pub drill: bool,
+}
+
+/// Delivery backend for one channel (SMS gateway, mail relay, voice provider)
+pub trait NotificationSender {
+    /// Deliver a message to its destination
+    fn send(&self, message: &EmergencyMessage) -> Result<(), String>;
+}
+
+/// Off-chain mapping from contact directory references to channel destinations
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContactDirectory {
+    destinations: HashMap<(String, ContactChannel), String>,
+}
+
+impl ContactDirectory {
+    /// Create an empty directory
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register (or replace) where a directory entry is reached on a channel
+    pub fn insert(&mut self, directory_ref: &str, channel: ContactChannel, destination: &str) {
+        self.destinations.insert((directory_ref.to_string(), channel), destination.to_string());
+    }
+
+    /// Destination of a directory entry on a channel
+    pub fn resolve(&self, directory_ref: &str, channel: ContactChannel) -> Option<&String> {
+        self.destinations.get(&(directory_ref.to_string(), channel))
+    }
+}
+
+/// Emergency delivery counters
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmergencyNotifierMetrics {
+    pub messages_sent: u64,
+    pub delivery_failures: u64,
+    /// Notifications for which no channel could be delivered
+    pub undeliverable: u64,
+}
+
+/// Bridges the router's emergency notifications to SMS/email senders
+pub struct EmergencyNotifier {
+    senders: HashMap<ContactChannel, Box<dyn NotificationSender>>,
+    directory: ContactDirectory,
+    contacts: Vec<EmergencyContact>,
+    /// Contact levels already delivered per response
+    delivered: HashMap<BytesN<32>, BTreeSet<u32>>,
+    metrics: EmergencyNotifierMetrics,
+}
+
+impl EmergencyNotifier {
+    /// Create a notifier resolving destinations through `directory`
+    pub fn new(directory: ContactDirectory) -> Self {
+        Self {
+            senders: HashMap::new(),
+            directory,
+            contacts: Vec::new(),
+            delivered: HashMap::new(),
+            metrics: EmergencyNotifierMetrics::default(),
+        }
+    }
+
+    /// Register (or replace) the sender for a channel
+    pub fn register_sender(&mut self, channel: ContactChannel, sender: Box<dyn NotificationSender>) {
+        self.senders.insert(channel, sender);
+    }
+
+    /// Replace the contact profiles, as returned by `get_emergency_contacts`
+    pub fn set_contacts(&mut self, contacts: Vec<EmergencyContact>) {
+        self.contacts = contacts;
+    }
+
+    /// Deliver the router's pending emergency notifications
+    pub fn poll(&mut self, manager: &ContractManager) -> ContractResult<Vec<EmergencyMessage>> {
+        let notifications = manager.get_pending_notifications()?;
+        Ok(self.sync(&notifications))
+    }
+
+    /// Deliver every notified contact not yet delivered
+    ///
+    /// Each contact is tried on its channels in preference order and the
+    /// first successful delivery counts. A contact nothing could be delivered
+    /// to is retried on the next sync. Notifications missing from
+    /// `notifications` were acknowledged and are forgotten.
+    ///
+    /// # Returns
+    /// Messages delivered successfully
+    pub fn sync(&mut self, notifications: &[EmergencyNotification]) -> Vec<EmergencyMessage> {
+        self.delivered.retain(|response_id, _| notifications.iter().any(|notification| &notification.response_id == response_id));
+
+        let mut sent = Vec::new();
+        for notification in notifications {
+            if notification.acknowledged_by.is_some() {
+                continue;
+            }
+
+            for record in &notification.records {
+                let already_delivered = self.delivered
+                    .get(&notification.response_id)
+                    .map(|levels| levels.contains(&record.level))
+                    .unwrap_or(false);
+                if already_delivered {
+                    continue;
+                }
+                let contact = match self.contacts.iter().find(|contact| contact.contact == record.contact) {
+                    Some(contact) => contact.clone(),
+                    None => {
+                        self.metrics.undeliverable += 1;
+                        continue;
+                    },
+                };
+
+                match self.deliver(notification, &contact, record.level) {
+                    Some(message) => {
+                        self.delivered.entry(notification.response_id.clone()).or_default().insert(record.level);
+                        sent.push(message);
+                    },
+                    None => self.metrics.undeliverable += 1,
+                }
+            }
+        }
+
+        sent
+    }
+
+    /// Get delivery counters
+    pub fn metrics(&self) -> EmergencyNotifierMetrics {
+        self.metrics.clone()
+    }
+
+    fn deliver(&mut self, notification: &EmergencyNotification, contact: &EmergencyContact, level: u32) -> Option<EmergencyMessage> {
+        for channel in &contact.channels {
+            let (sender, destination) = match (self.senders.get(channel), self.directory.resolve(&contact.directory_ref, *channel)) {
+                (Some(sender), Some(destination)) => (sender, destination),
+                _ => continue,
+            };
+
+            let message = Self::build_message(notification, *channel, destination, level);
+            match sender.send(&message) {
+                Ok(()) => {
+                    self.metrics.messages_sent += 1;
+                    return Some(message);
+                },
+                Err(_) => self.metrics.delivery_failures += 1,
+            }
+        }
+        None
+    }
+
+    fn build_message(notification: &EmergencyNotification, channel: ContactChannel, destination: &str, level: u32) -> EmergencyMessage {
+        let response = match notification.response_type {
+            EmergencyResponseType::SystemWideHalt => "System-wide halt",
+            EmergencyResponseType::AddressFreeze => "Address freeze",
+            EmergencyResponseType::ContractIsolation => "Contract isolation",
+            EmergencyResponseType::ReserveProtection => "Reserve protection",
+        };
+        let dedup_key = hex::encode(notification.response_id.to_array());
+        let subject = if notification.drill {
+            format!("[DRILL] {}", response)
+        } else {
+            response.to_string()
+        };
+
+        EmergencyMessage {
+            channel,
+            destination: destination.to_string(),
+            body: format!("{}: {}. Acknowledge response {} on-chain.", subject, notification.reason, dedup_key),
+            dedup_key,
+            subject,
+            level,
+            drill: notification.drill,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::rc::Rc;
+    use core::cell::RefCell;
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    struct RecordingIntegration {
+        pages: Rc<RefCell<Vec<Page>>>,
+        fail: bool,
+    }
+
+    impl PagingIntegration for RecordingIntegration {
+        fn page(&self, page: &Page) -> Result<(), String> {
+            if self.fail {
+                return Err(String::from("unavailable"));
+            }
+            self.pages.borrow_mut().push(page.clone());
+            Ok(())
+        }
+    }
+
+    fn alert(env: &Env, seed: u8, alert_type: &str, severity: AlertSeverity) -> SystemAlert {
+        SystemAlert {
+            alert_id: BytesN::from_array(env, &[seed; 32]),
+            alert_type: alert_type.to_string(),
+            severity,
+            message: String::from("check the dashboard"),
+            triggered_at: 0,
+            acknowledged: false,
+            acknowledged_by: None,
+            escalation_level: 0,
+            assigned_to: Some(Address::generate(env)),
+            last_escalated_at: 0,
+            snoozed_until: 0,
+        }
+    }
+
+    #[test]
+    fn test_routes_pages_and_repages_on_escalation() {
+        let env = Env::default();
+        let pager_pages = Rc::new(RefCell::new(Vec::new()));
+        let chat_pages = Rc::new(RefCell::new(Vec::new()));
+
+        let mut manager = AlertManager::new();
+        manager.register_integration("pager", Box::new(RecordingIntegration { pages: pager_pages.clone(), fail: false }));
+        manager.register_integration("chat", Box::new(RecordingIntegration { pages: chat_pages.clone(), fail: false }));
+        manager.register_integration("sms", Box::new(RecordingIntegration { pages: Rc::new(RefCell::new(Vec::new())), fail: true }));
+        assert!(manager.add_route(PagingRoute { integration: String::from("email"), min_severity: AlertSeverity::Info, alert_types: Vec::new() }).is_err());
+        manager.add_route(PagingRoute { integration: String::from("pager"), min_severity: AlertSeverity::Critical, alert_types: Vec::new() }).unwrap();
+        manager.add_route(PagingRoute { integration: String::from("chat"), min_severity: AlertSeverity::Warning, alert_types: Vec::new() }).unwrap();
+        manager.add_route(PagingRoute {
+            integration: String::from("sms"),
+            min_severity: AlertSeverity::Emergency,
+            alert_types: alloc::vec![String::from("reserve_discrepancy")],
+        }).unwrap();
+
+        let mut critical = alert(&env, 1, "reserve_discrepancy", AlertSeverity::Critical);
+        let mut snoozed = alert(&env, 2, "queue_backlog", AlertSeverity::Critical);
+        snoozed.snoozed_until = 500;
+        let warning = alert(&env, 3, "slow_oracle", AlertSeverity::Warning);
+
+        let pages = manager.sync(&[critical.clone(), snoozed.clone(), warning.clone()], 100);
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pager_pages.borrow().len(), 1);
+        assert_eq!(chat_pages.borrow().len(), 2);
+        assert_eq!(pages[0].dedup_key, hex::encode([1u8; 32]));
+
+        // Nothing changed, so nothing is paged again
+        assert!(manager.sync(&[critical.clone(), snoozed.clone(), warning.clone()], 200).is_empty());
+
+        // Escalation re-pages; the failing SMS route leaves it pending for the next sync
+        critical.severity = AlertSeverity::Emergency;
+        critical.escalation_level = 1;
+        assert_eq!(manager.sync(&[critical.clone(), warning.clone()], 300).len(), 2);
+        assert_eq!(manager.sync(&[critical.clone(), warning.clone()], 400).len(), 2);
+        assert_eq!(pager_pages.borrow()[1].escalation_level, 1);
+
+        critical.acknowledged = true;
+        assert!(manager.sync(&[critical.clone(), warning.clone(), snoozed.clone()], 400).is_empty());
+        assert_eq!(manager.sync(&[critical, warning, snoozed], 500).len(), 2);
+
+        let metrics = manager.metrics();
+        assert_eq!(metrics.pages_sent, 9);
+        assert_eq!(metrics.page_failures, 2);
+        assert_eq!(metrics.alerts_tracked, 3);
+    }
+
+    struct RecordingSender {
+        messages: Rc<RefCell<Vec<EmergencyMessage>>>,
+        fail: bool,
+    }
+
+    impl NotificationSender for RecordingSender {
+        fn send(&self, message: &EmergencyMessage) -> Result<(), String> {
+            if self.fail {
+                return Err(String::from("gateway down"));
+            }
+            self.messages.borrow_mut().push(message.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_emergency_notifications_fall_back_across_channels() {
+        use crate::integration_router_client::NotificationRecord;
+
+        let env = Env::default();
+        let primary = Address::generate(&env);
+        let secondary = Address::generate(&env);
+        let emails = Rc::new(RefCell::new(Vec::new()));
+
+        let mut directory = ContactDirectory::new();
+        directory.insert("ops-1", ContactChannel::Sms, "+15550100");
+        directory.insert("ops-1", ContactChannel::Email, "primary@example.com");
+        directory.insert("ops-2", ContactChannel::Email, "secondary@example.com");
+        let mut notifier = EmergencyNotifier::new(directory);
+        notifier.register_sender(ContactChannel::Sms, Box::new(RecordingSender { messages: Rc::new(RefCell::new(Vec::new())), fail: true }));
+        notifier.register_sender(ContactChannel::Email, Box::new(RecordingSender { messages: emails.clone(), fail: false }));
+        notifier.set_contacts(alloc::vec![
+            EmergencyContact {
+                contact: primary.clone(),
+                priority: 0,
+                channels: alloc::vec![ContactChannel::Sms, ContactChannel::Email],
+                directory_ref: String::from("ops-1"),
+            },
+            EmergencyContact {
+                contact: secondary.clone(),
+                priority: 1,
+                channels: alloc::vec![ContactChannel::Voice, ContactChannel::Email],
+                directory_ref: String::from("ops-2"),
+            },
+        ]);
+
+        let record = |contact: &Address, level: u32| NotificationRecord { contact: contact.clone(), level, sent_at: 0, acknowledged_at: 0 };
+        let mut notification = EmergencyNotification {
+            response_id: BytesN::from_array(&env, &[4u8; 32]),
+            response_type: EmergencyResponseType::ReserveProtection,
+            reason: String::from("reserve shortfall"),
+            drill: true,
+            records: alloc::vec![record(&primary, 0)],
+            acknowledged_by: None,
+            last_sent_at: 0,
+        };
+
+        // SMS fails, so the primary contact gets an email instead
+        let sent = notifier.sync(&[notification.clone()]);
+        assert_eq!(sent.len(), 1);
+        assert_eq!((sent[0].channel, sent[0].destination.as_str()), (ContactChannel::Email, "primary@example.com"));
+        assert_eq!(sent[0].subject, "[DRILL] Reserve protection");
+        assert!(notifier.sync(&[notification.clone()]).is_empty());
+
+        // Escalation reaches the secondary contact only
+        notification.records.push(record(&secondary, 1));
+        let sent = notifier.sync(&[notification.clone()]);
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].destination, "secondary@example.com");
+        assert_eq!(emails.borrow().len(), 2);
+
+        notification.acknowledged_by = Some(secondary);
+        assert!(notifier.sync(&[notification]).is_empty());
+        assert_eq!(notifier.metrics(), EmergencyNotifierMetrics { messages_sent: 2, delivery_failures: 1, undeliverable: 0 });
+    }
+}
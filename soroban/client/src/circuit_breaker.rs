@@ -0,0 +1,296 @@
+use alloc::collections::BTreeMap as HashMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use crate::{ContractResult, ContractError};
+
+/// Circuit breaker state for a single downstream contract
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CircuitState {
+    /// Calls flow normally and failures are counted
+    Closed,
+    /// Calls are rejected immediately until the open timeout elapses
+    Open,
+    /// A limited number of probe calls are allowed through to test recovery
+    HalfOpen,
+}
+
+/// Circuit breaker configuration
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CircuitBreakerConfig {
+    /// Consecutive call failures required to trip the breaker
+    pub failure_threshold: u32,
+    /// Seconds the breaker stays open before allowing a probe
+    pub open_timeout_seconds: u64,
+    /// Number of probe calls allowed while half-open
+    pub half_open_max_probes: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_timeout_seconds: 30,
+            half_open_max_probes: 1,
+        }
+    }
+}
+
+/// Per-contract circuit breaker
+///
+/// Trips after `failure_threshold` consecutive `ContractCallFailed` (or timeout)
+/// results, fails fast while open, and lets probe calls through once the open
+/// timeout has elapsed. A successful probe closes the breaker again; a failed
+/// probe re-opens it.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CircuitBreaker {
+    contract_name: String,
+    config: CircuitBreakerConfig,
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: u64,
+    probes_in_flight: u32,
+    total_rejections: u64,
+}
+
+impl CircuitBreaker {
+    /// Create a new closed circuit breaker
+    pub fn new(contract_name: &str, config: CircuitBreakerConfig) -> Self {
+        Self {
+            contract_name: contract_name.to_string(),
+            config,
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+            opened_at: 0,
+            probes_in_flight: 0,
+            total_rejections: 0,
+        }
+    }
+
+    /// Get the current breaker state as of `now`
+    ///
+    /// An open breaker whose timeout has elapsed is reported as half-open.
+    pub fn state(&self, now: u64) -> CircuitState {
+        if self.state == CircuitState::Open && self.open_timeout_elapsed(now) {
+            CircuitState::HalfOpen
+        } else {
+            self.state
+        }
+    }
+
+    /// Get the number of consecutive failures recorded
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    /// Get the number of calls rejected while open
+    pub fn total_rejections(&self) -> u64 {
+        self.total_rejections
+    }
+
+    /// Check whether a call may proceed
+    ///
+    /// # Arguments
+    /// * `now` - Current ledger timestamp
+    ///
+    /// # Returns
+    /// * `Ok(())` - The call may proceed
+    /// * `Err(ContractError::CircuitOpen)` - The breaker is open
+    pub fn allow_request(&mut self, now: u64) -> ContractResult<()> {
+        if self.state == CircuitState::Open && self.open_timeout_elapsed(now) {
+            self.state = CircuitState::HalfOpen;
+            self.probes_in_flight = 0;
+        }
+
+        match self.state {
+            CircuitState::Closed => Ok(()),
+            CircuitState::HalfOpen if self.probes_in_flight < self.config.half_open_max_probes => {
+                self.probes_in_flight += 1;
+                Ok(())
+            },
+            _ => {
+                self.total_rejections += 1;
+                Err(ContractError::CircuitOpen(self.contract_name.clone()))
+            }
+        }
+    }
+
+    /// Record the outcome of a call that was allowed through
+    ///
+    /// # Arguments
+    /// * `now` - Current ledger timestamp
+    /// * `outcome` - The call result, or `None` for success
+    pub fn record_outcome(&mut self, now: u64, outcome: Option<&ContractError>) {
+        match outcome {
+            Some(error) if Self::is_trip_error(error) => self.record_failure(now),
+            _ => self.record_success(),
+        }
+    }
+
+    /// Record a successful call
+    pub fn record_success(&mut self) {
+        self.state = CircuitState::Closed;
+        self.consecutive_failures = 0;
+        self.probes_in_flight = 0;
+    }
+
+    /// Record a failed call
+    pub fn record_failure(&mut self, now: u64) {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+
+        let should_open = match self.state {
+            CircuitState::HalfOpen => true,
+            CircuitState::Closed => self.consecutive_failures >= self.config.failure_threshold,
+            CircuitState::Open => false,
+        };
+
+        if should_open {
+            self.state = CircuitState::Open;
+            self.opened_at = now;
+            self.probes_in_flight = 0;
+        }
+    }
+
+    /// Force the breaker back to closed
+    pub fn reset(&mut self) {
+        self.record_success();
+    }
+
+    /// Errors that count towards tripping the breaker
+    ///
+    /// Business rejections (compliance, limits, validation) are not downstream
+    /// failures and must not open the circuit.
+    fn is_trip_error(error: &ContractError) -> bool {
+        matches!(
+            error,
+            ContractError::Integration(shared::IntegrationError::ContractCallFailed)
+                | ContractError::Timeout(_)
+        )
+    }
+
+    fn open_timeout_elapsed(&self, now: u64) -> bool {
+        now >= self.opened_at.saturating_add(self.config.open_timeout_seconds)
+    }
+}
+
+/// Collection of circuit breakers keyed by contract name
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CircuitBreakerRegistry {
+    config: CircuitBreakerConfig,
+    breakers: HashMap<String, CircuitBreaker>,
+}
+
+impl CircuitBreakerRegistry {
+    /// Create a registry that creates breakers with the given configuration
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            breakers: HashMap::new(),
+        }
+    }
+
+    /// Execute a call through the breaker for `contract_name`
+    ///
+    /// # Arguments
+    /// * `contract_name` - Downstream contract name
+    /// * `now` - Current ledger timestamp
+    /// * `call` - The contract call to execute
+    ///
+    /// # Returns
+    /// * `Ok(value)` - Call result
+    /// * `Err(ContractError)` - Call error, or `CircuitOpen` if rejected
+    pub fn call<T, F>(&mut self, contract_name: &str, now: u64, call: F) -> ContractResult<T>
+    where
+        F: FnOnce() -> ContractResult<T>,
+    {
+        self.breaker_mut(contract_name).allow_request(now)?;
+
+        let result = call();
+        self.breaker_mut(contract_name).record_outcome(now, result.as_ref().err());
+
+        result
+    }
+
+    /// Get the state of the breaker for `contract_name`
+    pub fn state(&self, contract_name: &str, now: u64) -> CircuitState {
+        self.breakers
+            .get(contract_name)
+            .map(|breaker| breaker.state(now))
+            .unwrap_or(CircuitState::Closed)
+    }
+
+    /// Get the breaker for `contract_name`, if one has been created
+    pub fn get(&self, contract_name: &str) -> Option<&CircuitBreaker> {
+        self.breakers.get(contract_name)
+    }
+
+    /// List contracts whose breakers are currently open
+    pub fn open_circuits(&self, now: u64) -> Vec<String> {
+        self.breakers
+            .iter()
+            .filter(|(_, breaker)| breaker.state(now) == CircuitState::Open)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Reset the breaker for `contract_name`
+    pub fn reset(&mut self, contract_name: &str) {
+        if let Some(breaker) = self.breakers.get_mut(contract_name) {
+            breaker.reset();
+        }
+    }
+
+    fn breaker_mut(&mut self, contract_name: &str) -> &mut CircuitBreaker {
+        let config = self.config.clone();
+        self.breakers
+            .entry(contract_name.to_string())
+            .or_insert_with(|| CircuitBreaker::new(contract_name, config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call_failed() -> ContractResult<()> {
+        Err(ContractError::Integration(shared::IntegrationError::ContractCallFailed))
+    }
+
+    #[test]
+    fn test_breaker_trips_and_recovers() {
+        let mut registry = CircuitBreakerRegistry::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            open_timeout_seconds: 10,
+            half_open_max_probes: 1,
+        });
+
+        assert!(registry.call("reserve_manager", 0, call_failed).is_err());
+        assert_eq!(registry.state("reserve_manager", 0), CircuitState::Closed);
+        assert!(registry.call("reserve_manager", 1, call_failed).is_err());
+        assert_eq!(registry.state("reserve_manager", 1), CircuitState::Open);
+
+        // Fails fast without invoking the call
+        let result: ContractResult<()> = registry.call("reserve_manager", 5, || panic!("should not be called"));
+        assert_eq!(result, Err(ContractError::CircuitOpen("reserve_manager".to_string())));
+
+        // Probe succeeds after timeout and closes the breaker
+        assert!(registry.call("reserve_manager", 11, || Ok(())).is_ok());
+        assert_eq!(registry.state("reserve_manager", 11), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_business_errors_do_not_trip() {
+        let mut registry = CircuitBreakerRegistry::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            ..CircuitBreakerConfig::default()
+        });
+
+        let _ = registry.call::<(), _>("kyc_registry", 0, || {
+            Err(ContractError::Integration(shared::IntegrationError::ComplianceCheckFailed))
+        });
+        assert_eq!(registry.state("kyc_registry", 0), CircuitState::Closed);
+    }
+}
@@ -0,0 +1,450 @@
+//! Event payload codec
+//!
+//! One wire format for contract event payloads, shared by event monitor
+//! consumers and webhook receivers. The canonical encoding is the XDR of
+//! the payload's `ScVal`, the same bytes the host emits on chain; any
+//! `#[contracttype]` round-trips through it. `shared::IntegrationEvent`
+//! additionally has a compact JSON form with snake_case field names, strkey
+//! addresses and hex hashes, built on `serde_json` without std I/O; webhook
+//! bodies embed it for the monitor's `ContractEvent`s.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::format;
+use serde_json::{json, Map, Value};
+use shared::IntegrationEvent;
+use soroban_sdk::xdr::{Limits, ReadXdr, ScVal, WriteXdr};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, String as SorobanString, TryFromVal, Val};
+use crate::event_monitor::{ContractEvent, EventData};
+use crate::reporting::address_to_string;
+use crate::{ContractError, ContractResult};
+
+/// Encode a contract value as canonical XDR
+pub fn encode_xdr<T: IntoVal<Env, Val>>(env: &Env, value: &T) -> ContractResult<Vec<u8>> {
+    to_scval(env, value)?
+        .to_xdr(Limits::none())
+        .map_err(|err| ContractError::ParseError(format!("failed to encode XDR: {:?}", err)))
+}
+
+/// Decode a contract value from canonical XDR
+pub fn decode_xdr<T: TryFromVal<Env, Val>>(env: &Env, bytes: &[u8]) -> ContractResult<T> {
+    let scval = ScVal::from_xdr(bytes, Limits::none())
+        .map_err(|err| ContractError::ParseError(format!("failed to decode XDR: {:?}", err)))?;
+    from_scval(env, &scval)
+}
+
+/// Encode a contract value as base64 XDR (the form RPC `getEvents` returns)
+pub fn encode_xdr_base64<T: IntoVal<Env, Val>>(env: &Env, value: &T) -> ContractResult<String> {
+    to_scval(env, value)?
+        .to_xdr_base64(Limits::none())
+        .map_err(|err| ContractError::ParseError(format!("failed to encode XDR: {:?}", err)))
+}
+
+/// Decode a contract value from base64 XDR
+pub fn decode_xdr_base64<T: TryFromVal<Env, Val>>(env: &Env, encoded: &str) -> ContractResult<T> {
+    let scval = ScVal::from_xdr_base64(encoded, Limits::none())
+        .map_err(|err| ContractError::ParseError(format!("failed to decode XDR: {:?}", err)))?;
+    from_scval(env, &scval)
+}
+
+fn to_scval<T: IntoVal<Env, Val>>(env: &Env, value: &T) -> ContractResult<ScVal> {
+    let val: Val = value.into_val(env);
+    ScVal::try_from_val(env, &val)
+        .map_err(|_| ContractError::ParseError(String::from("value has no XDR representation")))
+}
+
+fn from_scval<T: TryFromVal<Env, Val>>(env: &Env, scval: &ScVal) -> ContractResult<T> {
+    let val = Val::try_from_val(env, scval)
+        .map_err(|_| ContractError::ParseError(String::from("XDR value cannot be loaded into the host")))?;
+    T::try_from_val(env, &val)
+        .map_err(|_| ContractError::ParseError(String::from("XDR value does not match the expected type")))
+}
+
+/// Encode an integration event as compact JSON
+///
+/// The variant is carried in `type` (`bitcoin_deposit`, `token_withdrawal`,
+/// ...) next to the variant's named fields.
+pub fn encode_event_json(event: &IntegrationEvent) -> String {
+    let value = match event {
+        IntegrationEvent::BitcoinDeposit(user, btc_amount, istsi_minted, tx_hash, timestamp) => json!({
+            "type": "bitcoin_deposit",
+            "user": address_to_string(user),
+            "btc_amount": btc_amount,
+            "istsi_minted": istsi_minted,
+            "tx_hash": hex::encode(tx_hash.to_array()),
+            "timestamp": timestamp,
+        }),
+        IntegrationEvent::TokenWithdrawal(user, istsi_burned, btc_amount, withdrawal_id, timestamp) => json!({
+            "type": "token_withdrawal",
+            "user": address_to_string(user),
+            "istsi_burned": istsi_burned,
+            "btc_amount": btc_amount,
+            "withdrawal_id": hex::encode(withdrawal_id.to_array()),
+            "timestamp": timestamp,
+        }),
+        IntegrationEvent::CrossTokenExchange(user, from_token, to_token, from_amount, to_amount, timestamp) => json!({
+            "type": "cross_token_exchange",
+            "user": address_to_string(user),
+            "from_token": address_to_string(from_token),
+            "to_token": address_to_string(to_token),
+            "from_amount": from_amount,
+            "to_amount": to_amount,
+            "timestamp": timestamp,
+        }),
+        IntegrationEvent::ComplianceAction(user, action, reason, timestamp) => json!({
+            "type": "compliance_action",
+            "user": address_to_string(user),
+            "action": soroban_string(action),
+            "reason": soroban_string(reason),
+            "timestamp": timestamp,
+        }),
+        IntegrationEvent::ReserveUpdate(total_btc, total_istsi, reserve_ratio, timestamp) => json!({
+            "type": "reserve_update",
+            "total_btc": total_btc,
+            "total_istsi": total_istsi,
+            "reserve_ratio": reserve_ratio,
+            "timestamp": timestamp,
+        }),
+        IntegrationEvent::SystemPause(admin, reason, timestamp) => json!({
+            "type": "system_pause",
+            "admin": address_to_string(admin),
+            "reason": soroban_string(reason),
+            "timestamp": timestamp,
+        }),
+        IntegrationEvent::SystemResume(admin, timestamp) => json!({
+            "type": "system_resume",
+            "admin": address_to_string(admin),
+            "timestamp": timestamp,
+        }),
+        IntegrationEvent::ContractUpgrade(contract_address, old_version, new_version, admin, timestamp) => json!({
+            "type": "contract_upgrade",
+            "contract_address": address_to_string(contract_address),
+            "old_version": soroban_string(old_version),
+            "new_version": soroban_string(new_version),
+            "admin": address_to_string(admin),
+            "timestamp": timestamp,
+        }),
+    };
+    value.to_string()
+}
+
+/// Encode a monitored contract event as the JSON body webhooks deliver
+///
+/// Payloads that correspond to a `shared::IntegrationEvent` carry its
+/// `encode_event_json` object in `data`, extended with the fields only the
+/// router's event has (confirmations, payout address, rate and fee). The
+/// router's other events follow the same conventions: a `type` tag,
+/// snake_case fields, strkey addresses and hex hashes.
+pub fn encode_contract_event_json(env: &Env, event: &ContractEvent) -> String {
+    json!({
+        "event_type": event.event_type,
+        "contract": address_to_string(&event.contract_address),
+        "topics": event.topics,
+        "data": event_data_json(env, &event.data, event.timestamp),
+        "timestamp": event.timestamp,
+        "block_number": event.block_number,
+        "transaction_hash": event.transaction_hash,
+    })
+    .to_string()
+}
+
+fn event_data_json(env: &Env, data: &EventData, timestamp: u64) -> Value {
+    let (event, extra) = match data {
+        EventData::BitcoinDeposit { user, btc_amount, istsi_amount, btc_tx_hash, confirmations } => (
+            IntegrationEvent::BitcoinDeposit(user.clone(), *btc_amount, *istsi_amount, btc_tx_hash.clone(), timestamp),
+            json!({ "confirmations": confirmations }),
+        ),
+        EventData::TokenWithdrawal { user, istsi_amount, btc_amount, withdrawal_id, btc_address } => (
+            IntegrationEvent::TokenWithdrawal(user.clone(), *istsi_amount, *btc_amount, withdrawal_id.clone(), timestamp),
+            json!({ "btc_address": btc_address }),
+        ),
+        EventData::CrossTokenExchange { user, from_token, to_token, from_amount, to_amount, exchange_rate, fee } => (
+            IntegrationEvent::CrossTokenExchange(user.clone(), from_token.clone(), to_token.clone(), *from_amount, *to_amount, timestamp),
+            json!({ "exchange_rate": exchange_rate, "fee": fee }),
+        ),
+        EventData::ReserveUpdate { total_btc, total_istsi, reserve_ratio, operation_type } => (
+            IntegrationEvent::ReserveUpdate(*total_btc, *total_istsi, *reserve_ratio, timestamp),
+            json!({ "operation_type": operation_type }),
+        ),
+        EventData::SystemPause { admin, reason, paused: true } => (
+            IntegrationEvent::SystemPause(admin.clone(), SorobanString::from_str(env, reason), timestamp),
+            json!({}),
+        ),
+        EventData::SystemPause { admin, reason, paused: false } => (
+            IntegrationEvent::SystemResume(admin.clone(), timestamp),
+            json!({ "reason": reason }),
+        ),
+        EventData::DepositConfirmations { user, btc_tx_hash, confirmations, required } => return json!({
+            "type": "deposit_confirmations",
+            "user": address_to_string(user),
+            "btc_tx_hash": hex::encode(btc_tx_hash.to_array()),
+            "confirmations": confirmations,
+            "required": required,
+        }),
+        EventData::OrderFill { order_id, owner, sold, received, remaining } => return json!({
+            "type": "order_fill",
+            "order_id": hex::encode(order_id.to_array()),
+            "owner": address_to_string(owner),
+            "sold": sold,
+            "received": received,
+            "remaining": remaining,
+        }),
+        EventData::ComplianceCheck { user, operation_type, amount, approved, tier_required, user_tier } => return json!({
+            "type": "compliance_check",
+            "user": address_to_string(user),
+            "operation_type": operation_type,
+            "amount": amount,
+            "approved": approved,
+            "tier_required": tier_required,
+            "user_tier": user_tier,
+        }),
+        EventData::IntegrationOperation { operation_id, operation_type, user, amount, status } => return json!({
+            "type": "integration_operation",
+            "operation_id": hex::encode(operation_id.to_array()),
+            "operation_type": operation_type,
+            "user": address_to_string(user),
+            "amount": amount,
+            "status": status,
+        }),
+        EventData::TierChangeRequested { customer_id, request_id, current_tier, requested_tier } => return json!({
+            "type": "tier_change_requested",
+            "customer_id": customer_id,
+            "request_id": request_id,
+            "current_tier": current_tier,
+            "requested_tier": requested_tier,
+        }),
+        EventData::TierChangeReviewed { customer_id, request_id, approved, reviewer, previous_tier, requested_tier } => return json!({
+            "type": "tier_change_reviewed",
+            "customer_id": customer_id,
+            "request_id": request_id,
+            "approved": approved,
+            "reviewer": address_to_string(reviewer),
+            "previous_tier": previous_tier,
+            "requested_tier": requested_tier,
+        }),
+        EventData::Generic { data } => return json!({ "type": "generic", "data": data }),
+    };
+
+    let mut value: Value = serde_json::from_str(&encode_event_json(&event)).unwrap_or(Value::Null);
+    if let (Some(fields), Value::Object(extra)) = (value.as_object_mut(), extra) {
+        fields.extend(extra);
+    }
+    value
+}
+
+/// Decode an integration event from the JSON produced by `encode_event_json`
+pub fn decode_event_json(env: &Env, json: &str) -> ContractResult<IntegrationEvent> {
+    let value: Value = serde_json::from_str(json)
+        .map_err(|err| ContractError::ParseError(format!("invalid event JSON: {}", err)))?;
+    let fields = value.as_object()
+        .ok_or_else(|| ContractError::ParseError(String::from("event JSON must be an object")))?;
+    let fields = JsonFields { env, fields };
+
+    let event = match fields.str("type")? {
+        "bitcoin_deposit" => IntegrationEvent::BitcoinDeposit(
+            fields.address("user")?,
+            fields.u64("btc_amount")?,
+            fields.u64("istsi_minted")?,
+            fields.hash("tx_hash")?,
+            fields.u64("timestamp")?,
+        ),
+        "token_withdrawal" => IntegrationEvent::TokenWithdrawal(
+            fields.address("user")?,
+            fields.u64("istsi_burned")?,
+            fields.u64("btc_amount")?,
+            fields.hash("withdrawal_id")?,
+            fields.u64("timestamp")?,
+        ),
+        "cross_token_exchange" => IntegrationEvent::CrossTokenExchange(
+            fields.address("user")?,
+            fields.address("from_token")?,
+            fields.address("to_token")?,
+            fields.u64("from_amount")?,
+            fields.u64("to_amount")?,
+            fields.u64("timestamp")?,
+        ),
+        "compliance_action" => IntegrationEvent::ComplianceAction(
+            fields.address("user")?,
+            fields.string("action")?,
+            fields.string("reason")?,
+            fields.u64("timestamp")?,
+        ),
+        "reserve_update" => IntegrationEvent::ReserveUpdate(
+            fields.u64("total_btc")?,
+            fields.u64("total_istsi")?,
+            fields.u64("reserve_ratio")?,
+            fields.u64("timestamp")?,
+        ),
+        "system_pause" => IntegrationEvent::SystemPause(
+            fields.address("admin")?,
+            fields.string("reason")?,
+            fields.u64("timestamp")?,
+        ),
+        "system_resume" => IntegrationEvent::SystemResume(
+            fields.address("admin")?,
+            fields.u64("timestamp")?,
+        ),
+        "contract_upgrade" => IntegrationEvent::ContractUpgrade(
+            fields.address("contract_address")?,
+            fields.string("old_version")?,
+            fields.string("new_version")?,
+            fields.address("admin")?,
+            fields.u64("timestamp")?,
+        ),
+        other => return Err(ContractError::ParseError(format!("unknown event type: {}", other))),
+    };
+    Ok(event)
+}
+
+fn soroban_string(value: &SorobanString) -> String {
+    let mut bytes = alloc::vec![0u8; value.len() as usize];
+    value.copy_into_slice(&mut bytes);
+    String::from_utf8(bytes).unwrap_or_default()
+}
+
+/// Typed field access over a decoded JSON object
+struct JsonFields<'a> {
+    env: &'a Env,
+    fields: &'a Map<String, Value>,
+}
+
+impl JsonFields<'_> {
+    fn field(&self, name: &str) -> ContractResult<&Value> {
+        self.fields.get(name)
+            .ok_or_else(|| ContractError::ParseError(format!("missing event field: {}", name)))
+    }
+
+    fn invalid(name: &str) -> ContractError {
+        ContractError::ParseError(format!("invalid event field: {}", name))
+    }
+
+    fn str(&self, name: &str) -> ContractResult<&str> {
+        self.field(name)?.as_str().ok_or_else(|| Self::invalid(name))
+    }
+
+    fn u64(&self, name: &str) -> ContractResult<u64> {
+        self.field(name)?.as_u64().ok_or_else(|| Self::invalid(name))
+    }
+
+    fn string(&self, name: &str) -> ContractResult<SorobanString> {
+        Ok(SorobanString::from_str(self.env, self.str(name)?))
+    }
+
+    fn address(&self, name: &str) -> ContractResult<Address> {
+        let strkey = self.str(name)?;
+        // Reject malformed strkeys before the SDK traps on them
+        let valid = stellar_strkey::ed25519::PublicKey::from_string(strkey).is_ok()
+            || stellar_strkey::Contract::from_string(strkey).is_ok();
+        if !valid {
+            return Err(Self::invalid(name));
+        }
+        Ok(Address::from_string(&SorobanString::from_str(self.env, strkey)))
+    }
+
+    fn hash(&self, name: &str) -> ContractResult<BytesN<32>> {
+        let mut bytes = [0u8; 32];
+        hex::decode_to_slice(self.str(name)?.trim_start_matches("0x"), &mut bytes)
+            .map_err(|_| Self::invalid(name))?;
+        Ok(BytesN::from_array(self.env, &bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    fn sample_events(env: &Env) -> Vec<IntegrationEvent> {
+        let user = Address::generate(env);
+        let admin = Address::generate(env);
+        alloc::vec![
+            IntegrationEvent::BitcoinDeposit(user.clone(), 100_000, 10_000_000, BytesN::from_array(env, &[1u8; 32]), 1_000),
+            IntegrationEvent::TokenWithdrawal(user.clone(), 5_000_000, 50_000, BytesN::from_array(env, &[2u8; 32]), 1_001),
+            IntegrationEvent::CrossTokenExchange(user.clone(), Address::generate(env), Address::generate(env), 700, 690, 1_002),
+            IntegrationEvent::ComplianceAction(user, SorobanString::from_str(env, "freeze"), SorobanString::from_str(env, "sanctions \"hit\""), 1_003),
+            IntegrationEvent::ReserveUpdate(120_000, 100_000, 12_000, 1_004),
+            IntegrationEvent::SystemPause(admin.clone(), SorobanString::from_str(env, "maintenance"), 1_005),
+            IntegrationEvent::SystemResume(admin.clone(), 1_006),
+            IntegrationEvent::ContractUpgrade(
+                Address::generate(env),
+                SorobanString::from_str(env, "1.0.0"),
+                SorobanString::from_str(env, "1.1.0"),
+                admin,
+                1_007,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_events_round_trip_through_xdr() {
+        let env = Env::default();
+        for event in sample_events(&env) {
+            let bytes = encode_xdr(&env, &event).unwrap();
+            assert_eq!(decode_xdr::<IntegrationEvent>(&env, &bytes).unwrap(), event);
+
+            let encoded = encode_xdr_base64(&env, &event).unwrap();
+            assert_eq!(decode_xdr_base64::<IntegrationEvent>(&env, &encoded).unwrap(), event);
+
+            // The encoding is canonical: re-encoding the decoded event yields the same bytes
+            let decoded: IntegrationEvent = decode_xdr(&env, &bytes).unwrap();
+            assert_eq!(encode_xdr(&env, &decoded).unwrap(), bytes);
+        }
+
+        assert!(decode_xdr::<IntegrationEvent>(&env, &[0xff, 0x00]).is_err());
+        let reserve = encode_xdr(&env, &IntegrationEvent::ReserveUpdate(1, 2, 3, 4)).unwrap();
+        assert!(decode_xdr::<shared::EventMetadata>(&env, &reserve).is_err());
+    }
+
+    #[test]
+    fn test_events_round_trip_through_json() {
+        let env = Env::default();
+        for event in sample_events(&env) {
+            let json = encode_event_json(&event);
+            assert_eq!(decode_event_json(&env, &json).unwrap(), event);
+        }
+
+        let json = encode_event_json(&IntegrationEvent::ReserveUpdate(120_000, 100_000, 12_000, 1_004));
+        assert_eq!(
+            json,
+            r#"{"reserve_ratio":12000,"timestamp":1004,"total_btc":120000,"total_istsi":100000,"type":"reserve_update"}"#
+        );
+
+        assert!(decode_event_json(&env, r#"{"type":"unknown"}"#).is_err());
+        assert!(decode_event_json(&env, r#"{"type":"system_resume","admin":"not-a-strkey","timestamp":1}"#).is_err());
+        assert!(decode_event_json(&env, r#"{"type":"reserve_update","total_btc":1}"#).is_err());
+    }
+
+    #[test]
+    fn test_webhook_bodies_embed_the_event_json() {
+        let env = Env::default();
+        let user = Address::generate(&env);
+        let event = ContractEvent {
+            contract_address: Address::generate(&env),
+            event_type: String::from("BitcoinDeposit"),
+            topics: alloc::vec![String::from("deposit")],
+            data: EventData::BitcoinDeposit {
+                user: user.clone(),
+                btc_amount: 100_000,
+                istsi_amount: 10_000_000,
+                btc_tx_hash: BytesN::from_array(&env, &[1u8; 32]),
+                confirmations: 6,
+            },
+            timestamp: 1_000,
+            block_number: 7,
+            transaction_hash: String::from("abc"),
+        };
+
+        let body: Value = serde_json::from_str(&encode_contract_event_json(&env, &event)).unwrap();
+        assert_eq!(body["contract"], address_to_string(&event.contract_address));
+        assert_eq!(body["data"]["confirmations"], 6);
+        let deposit = decode_event_json(&env, &body["data"].to_string()).unwrap();
+        assert_eq!(deposit, IntegrationEvent::BitcoinDeposit(user, 100_000, 10_000_000, BytesN::from_array(&env, &[1u8; 32]), 1_000));
+
+        let resume = ContractEvent {
+            data: EventData::SystemPause { admin: event.contract_address.clone(), reason: String::from("done"), paused: false },
+            ..event
+        };
+        let body: Value = serde_json::from_str(&encode_contract_event_json(&env, &resume)).unwrap();
+        assert_eq!(body["data"]["type"], "system_resume");
+    }
+}
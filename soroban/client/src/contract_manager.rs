@@ -1,9 +1,20 @@
 use soroban_sdk::{Address, Env};
-use alloc::string::ToString;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap as HashMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cell::RefCell;
 use crate::{
     ContractResult, ContractError, OperationContext, ContractClient,
     IntegrationRouterClient, KycRegistryClient, IstsiTokenClient, ReserveManagerClient,
-    ContractAddresses, NetworkConfig
+    ContractAddresses, NetworkConfig,
+    CircuitBreakerConfig, CircuitBreakerRegistry, CircuitState, AccessControl, ParameterRegistry,
+    ContractInvocation, FeeBufferConfig, PreparedTransaction, TransactionBuilder, TransactionSimulator,
+    SignedTransaction, Signer, AsyncSigner, SubmissionResponse, TransactionSubmitter
+};
+use crate::integration_router_client::{
+    ContractCallStats, InventoryDepth, LimitOrder, LiquidityPosition, MaintenanceWindow, MetricsSnapshot, MigrationState, MigrationStatus, OperatorStats, PauseState, PauseWorkflow, ProcessingTimeStats, SimulationReport, StatsPeriod, SystemAlert,
+    EmergencyNotification
 };
 
 /// Central contract manager for coordinating all contract interactions
@@ -20,6 +31,12 @@ pub struct ContractManager {
     kyc_registry: KycRegistryClient,
     istsi_token: IstsiTokenClient,
     reserve_manager: ReserveManagerClient,
+
+    // Per-contract circuit breakers
+    circuit_breakers: RefCell<CircuitBreakerRegistry>,
+
+    // Signs transactions assembled by the manager
+    signer: Option<Box<dyn Signer>>,
 }
 
 impl ContractManager {
@@ -84,14 +101,52 @@ impl ContractManager {
             kyc_registry,
             istsi_token,
             reserve_manager,
+            circuit_breakers: RefCell::new(CircuitBreakerRegistry::new(CircuitBreakerConfig::default())),
+            signer: None,
         })
     }
 
+    /// Replace the circuit breaker configuration
+    ///
+    /// Resets all existing breakers.
+    pub fn with_circuit_breaker_config(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breakers = RefCell::new(CircuitBreakerRegistry::new(config));
+        self
+    }
+
+    /// Sign transactions with an HSM, KMS or in-memory signer
+    pub fn with_signer(mut self, signer: Box<dyn Signer>) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Get the circuit breaker state for a contract
+    pub fn circuit_state(&self, contract_name: &str) -> CircuitState {
+        self.circuit_breakers
+            .borrow()
+            .state(contract_name, self.env.ledger().timestamp())
+    }
+
+    /// Manually close the circuit breaker for a contract
+    pub fn reset_circuit(&self, contract_name: &str) {
+        self.circuit_breakers.borrow_mut().reset(contract_name);
+    }
+
+    /// Soroban environment the manager's clients are bound to
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
     /// Get the integration router client
     pub fn integration_router(&self) -> &IntegrationRouterClient {
         &self.integration_router
     }
 
+    /// Get an access control helper backed by the integration router
+    pub fn access_control(&self) -> AccessControl {
+        AccessControl::new(self.integration_router.clone())
+    }
+
     /// Get the KYC registry client
     pub fn kyc_registry(&self) -> &KycRegistryClient {
         &self.kyc_registry
@@ -132,12 +187,16 @@ impl ContractManager {
         confirmations: u32,
         block_height: u64,
     ) -> ContractResult<soroban_sdk::BytesN<32>> {
+        self.require_workflow_active(PauseWorkflow::Deposits)?;
+
         // Step 1: Check KYC compliance
-        let kyc_approved = self.kyc_registry.is_approved_for_operation(
-            user,
-            3, // Deposit operation
-            btc_amount,
-        )?;
+        let kyc_approved = self.guarded("kyc_registry", || {
+            self.kyc_registry.is_approved_for_operation(
+                user,
+                3, // Deposit operation
+                btc_amount,
+            )
+        })?;
         
         if !kyc_approved {
             return Err(ContractError::Integration(
@@ -146,36 +205,40 @@ impl ContractManager {
         }
 
         // Step 2: Register Bitcoin deposit with reserve manager
-        self.reserve_manager.register_bitcoin_deposit(
-            ctx,
-            btc_tx_hash,
-            btc_amount,
-            confirmations,
-            user,
-            block_height,
-        )?;
+        self.guarded("reserve_manager", || {
+            self.reserve_manager.register_bitcoin_deposit(
+                ctx,
+                btc_tx_hash,
+                btc_amount,
+                confirmations,
+                user,
+                block_height,
+            )
+        })?;
 
         // Step 3: Process deposit if confirmations are sufficient
         if confirmations >= self.network_config.min_confirmations {
-            self.reserve_manager.process_bitcoin_deposit(ctx, btc_tx_hash)?;
+            self.guarded("reserve_manager", || self.reserve_manager.process_bitcoin_deposit(ctx, btc_tx_hash))?;
             
             // Step 4: Mint iSTSi tokens
             let istsi_amount = self.calculate_istsi_amount(btc_amount)?;
-            self.istsi_token.mint_with_btc_link(ctx, user, istsi_amount, btc_tx_hash)?;
+            self.guarded("istsi_token", || self.istsi_token.mint_with_btc_link(ctx, user, istsi_amount, btc_tx_hash))?;
             
             // Step 5: Update token supply in reserve manager
-            let new_supply = self.istsi_token.total_supply()?;
-            self.reserve_manager.update_token_supply(ctx, new_supply)?;
+            let new_supply = self.guarded("istsi_token", || self.istsi_token.total_supply())?;
+            self.guarded("reserve_manager", || self.reserve_manager.update_token_supply(ctx, new_supply))?;
         }
 
         // Step 6: Execute through integration router for coordination
-        let operation_id = self.integration_router.execute_bitcoin_deposit(
-            ctx,
-            user,
-            btc_amount,
-            btc_tx_hash,
-            confirmations,
-        )?;
+        let operation_id = self.guarded("integration_router", || {
+            self.integration_router.execute_bitcoin_deposit(
+                ctx,
+                user,
+                btc_amount,
+                btc_tx_hash,
+                confirmations,
+            )
+        })?;
 
         Ok(operation_id)
     }
@@ -201,12 +264,16 @@ impl ContractManager {
         istsi_amount: u64,
         btc_address: &str,
     ) -> ContractResult<soroban_sdk::BytesN<32>> {
+        self.require_workflow_active(PauseWorkflow::Withdrawals)?;
+
         // Step 1: Check KYC compliance
-        let kyc_approved = self.kyc_registry.is_approved_for_operation(
-            user,
-            4, // Withdrawal operation
-            istsi_amount,
-        )?;
+        let kyc_approved = self.guarded("kyc_registry", || {
+            self.kyc_registry.is_approved_for_operation(
+                user,
+                4, // Withdrawal operation
+                istsi_amount,
+            )
+        })?;
         
         if !kyc_approved {
             return Err(ContractError::Integration(
@@ -215,7 +282,7 @@ impl ContractManager {
         }
 
         // Step 2: Check token balance
-        let balance = self.istsi_token.balance(user)?;
+        let balance = self.guarded("istsi_token", || self.istsi_token.balance(user))?;
         if balance < istsi_amount {
             return Err(ContractError::Integration(
                 shared::IntegrationError::InsufficientReserves
@@ -226,7 +293,7 @@ impl ContractManager {
         let btc_amount = self.calculate_btc_amount(istsi_amount)?;
 
         // Step 4: Check reserve availability
-        let total_reserves = self.reserve_manager.get_total_reserves()?;
+        let total_reserves = self.guarded("reserve_manager", || self.reserve_manager.get_total_reserves())?;
         if total_reserves < btc_amount {
             return Err(ContractError::Integration(
                 shared::IntegrationError::InsufficientReserves
@@ -234,32 +301,38 @@ impl ContractManager {
         }
 
         // Step 5: Burn iSTSi tokens
-        let burn_request_id = self.istsi_token.burn_for_btc_withdrawal(
-            ctx,
-            user,
-            istsi_amount,
-            btc_address,
-        )?;
+        let burn_request_id = self.guarded("istsi_token", || {
+            self.istsi_token.burn_for_btc_withdrawal(
+                ctx,
+                user,
+                istsi_amount,
+                btc_address,
+            )
+        })?;
 
         // Step 6: Create withdrawal request
-        let withdrawal_id = self.reserve_manager.create_withdrawal_request(
-            ctx,
-            user,
-            btc_amount,
-            btc_address,
-        )?;
+        let withdrawal_id = self.guarded("reserve_manager", || {
+            self.reserve_manager.create_withdrawal_request(
+                ctx,
+                user,
+                btc_amount,
+                btc_address,
+            )
+        })?;
 
         // Step 7: Update token supply
-        let new_supply = self.istsi_token.total_supply()?;
-        self.reserve_manager.update_token_supply(ctx, new_supply)?;
+        let new_supply = self.guarded("istsi_token", || self.istsi_token.total_supply())?;
+        self.guarded("reserve_manager", || self.reserve_manager.update_token_supply(ctx, new_supply))?;
 
         // Step 8: Execute through integration router for coordination
-        let _operation_id = self.integration_router.execute_token_withdrawal(
-            ctx,
-            user,
-            istsi_amount,
-            btc_address,
-        )?;
+        let _operation_id = self.guarded("integration_router", || {
+            self.integration_router.execute_token_withdrawal(
+                ctx,
+                user,
+                istsi_amount,
+                btc_address,
+            )
+        })?;
 
         Ok(withdrawal_id)
     }
@@ -284,12 +357,16 @@ impl ContractManager {
         to_token: &Address,
         from_amount: u64,
     ) -> ContractResult<(soroban_sdk::BytesN<32>, u64)> {
+        self.require_workflow_active(PauseWorkflow::Exchanges)?;
+
         // Step 1: Check KYC compliance
-        let kyc_approved = self.kyc_registry.is_approved_for_operation(
-            user,
-            5, // Exchange operation
-            from_amount,
-        )?;
+        let kyc_approved = self.guarded("kyc_registry", || {
+            self.kyc_registry.is_approved_for_operation(
+                user,
+                5, // Exchange operation
+                from_amount,
+            )
+        })?;
         
         if !kyc_approved {
             return Err(ContractError::Integration(
@@ -298,13 +375,15 @@ impl ContractManager {
         }
 
         // Step 2: Execute through integration router
-        let (operation_id, to_amount) = self.integration_router.execute_cross_token_exchange(
-            ctx,
-            user,
-            from_token,
-            to_token,
-            from_amount,
-        )?;
+        let (operation_id, to_amount) = self.guarded("integration_router", || {
+            self.integration_router.execute_cross_token_exchange(
+                ctx,
+                user,
+                from_token,
+                to_token,
+                from_amount,
+            )
+        })?;
 
         Ok((operation_id, to_amount))
     }
@@ -342,6 +421,187 @@ impl ContractManager {
         Ok(health)
     }
 
+    /// Poll system health once and report changes since the previous poll
+    ///
+    /// The first poll through a fresh watcher records a baseline and reports
+    /// no transitions.
+    ///
+    /// # Arguments
+    /// * `watcher` - Watcher holding the previous health snapshot
+    ///
+    /// # Returns
+    /// * `Ok(transitions)` - Component health changes since the last poll
+    /// * `Err(ContractError)` - Error details
+    pub fn poll_system_health(&self, watcher: &mut HealthWatcher) -> ContractResult<Vec<HealthTransition>> {
+        let health = self.check_system_health()?;
+        let now = self.env.ledger().timestamp();
+
+        let mut components = HashMap::new();
+        components.insert("integration_router".to_string(), self.contract_health("integration_router", health.integration_router_available));
+        components.insert("kyc_registry".to_string(), self.contract_health("kyc_registry", health.kyc_registry_available));
+        components.insert("istsi_token".to_string(), self.contract_health("istsi_token", health.istsi_token_available));
+        components.insert("reserve_manager".to_string(), self.contract_health("reserve_manager", health.reserve_manager_available));
+        components.insert(
+            "system".to_string(),
+            if health.system_paused { ComponentHealth::Critical } else { ComponentHealth::Healthy },
+        );
+        components.insert(
+            "reserve_ratio".to_string(),
+            if health.reserve_ratio_healthy { ComponentHealth::Healthy } else { ComponentHealth::Critical },
+        );
+
+        Ok(watcher.update(components, now))
+    }
+
+    /// Continuously watch system health and deliver transitions to a callback
+    ///
+    /// Polls every `interval` until the callback returns an error, which is
+    /// then returned to the caller. A failed poll is skipped and retried on
+    /// the next tick, keeping the previous snapshot as the baseline.
+    ///
+    /// # Arguments
+    /// * `interval` - Time between polls
+    /// * `callback` - Receives each health transition
+    ///
+    /// # Returns
+    /// * `Err(ContractError)` - Error that stopped the watch loop
+    #[cfg(feature = "async")]
+    pub async fn watch_system_health<F>(
+        &self,
+        interval: core::time::Duration,
+        mut callback: F,
+    ) -> ContractResult<()>
+    where
+        F: FnMut(&HealthTransition) -> ContractResult<()>,
+    {
+        let mut watcher = HealthWatcher::new();
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+            let transitions = match self.poll_system_health(&mut watcher) {
+                Ok(transitions) => transitions,
+                Err(_) => continue,
+            };
+            for transition in transitions {
+                callback(&transition)?;
+            }
+        }
+    }
+
+    /// Run timeout sweeps until no expired operations remain
+    ///
+    /// Calls the router's sweeper in batches of `max_items_per_sweep`, stopping
+    /// once a sweep times out fewer operations than its batch size or after
+    /// `max_sweeps` calls.
+    ///
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// * `max_items_per_sweep` - Batch size for each sweep call
+    /// * `max_sweeps` - Upper bound on sweep calls in this run
+    ///
+    /// # Returns
+    /// * `Ok(count)` - Total number of operations timed out
+    /// * `Err(ContractError)` - Error details
+    pub fn sweep_timed_out_operations(
+        &self,
+        ctx: &OperationContext,
+        max_items_per_sweep: u32,
+        max_sweeps: u32,
+    ) -> ContractResult<u32> {
+        let mut total = 0u32;
+
+        for _ in 0..max_sweeps {
+            let swept = self.guarded("integration_router", || {
+                self.integration_router.sweep_timed_out_operations(ctx, max_items_per_sweep)
+            })?;
+            total += swept;
+
+            if swept < max_items_per_sweep {
+                break;
+            }
+        }
+
+        Ok(total)
+    }
+
+    /// Drive an upgrade's state migration until it completes or fails
+    ///
+    /// Each call runs up to `batches_per_call` batches in one transaction;
+    /// the router checkpoints between calls, so a later run resumes where
+    /// this one stopped.
+    ///
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// * `upgrade_id` - Upgrade whose migration to run
+    /// * `batches_per_call` - Batches per router call
+    /// * `max_calls` - Upper bound on router calls in this run
+    ///
+    /// # Returns
+    /// * `Ok(state)` - Checkpoint after the last call
+    pub fn run_migration(
+        &self,
+        ctx: &OperationContext,
+        upgrade_id: &soroban_sdk::BytesN<32>,
+        batches_per_call: u32,
+        max_calls: u32,
+    ) -> ContractResult<MigrationState> {
+        if batches_per_call == 0 || max_calls == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        let mut state = self.guarded("integration_router", || {
+            self.integration_router.run_migration(ctx, upgrade_id, batches_per_call)
+        })?;
+        for _ in 1..max_calls {
+            if state.status != MigrationStatus::Running {
+                break;
+            }
+            state = self.guarded("integration_router", || {
+                self.integration_router.run_migration(ctx, upgrade_id, batches_per_call)
+            })?;
+        }
+
+        Ok(state)
+    }
+
+    /// Run the timeout sweeper on a fixed interval
+    ///
+    /// Loops until a sweep fails, returning that error. `on_sweep` receives the
+    /// number of operations timed out by each non-empty run.
+    ///
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// * `interval` - Time between sweep runs
+    /// * `max_items_per_sweep` - Batch size for each sweep call
+    /// * `on_sweep` - Receives the count of operations timed out
+    ///
+    /// # Returns
+    /// * `Err(ContractError)` - Error that stopped the keeper loop
+    #[cfg(feature = "async")]
+    pub async fn run_timeout_keeper<F>(
+        &self,
+        ctx: &OperationContext,
+        interval: core::time::Duration,
+        max_items_per_sweep: u32,
+        mut on_sweep: F,
+    ) -> ContractResult<()>
+    where
+        F: FnMut(u32),
+    {
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+            let swept = self.sweep_timed_out_operations(ctx, max_items_per_sweep, 10)?;
+            if swept > 0 {
+                on_sweep(swept);
+            }
+        }
+    }
+
     /// Get comprehensive system status
     /// 
     /// # Returns
@@ -365,6 +625,358 @@ impl ContractManager {
         })
     }
 
+    /// Get the router's system-wide and per-workflow pause flags
+    pub fn get_pause_state(&self) -> ContractResult<PauseState> {
+        self.guarded("integration_router", || self.integration_router.get_pause_state())
+    }
+
+    /// Pause a single router workflow while the others keep running
+    pub fn pause_workflow(&self, ctx: &OperationContext, workflow: PauseWorkflow, reason: &str) -> ContractResult<()> {
+        self.guarded("integration_router", || self.integration_router.pause_workflow(ctx, workflow, reason))
+    }
+
+    /// Resume a paused router workflow
+    pub fn resume_workflow(&self, ctx: &OperationContext, workflow: PauseWorkflow) -> ContractResult<()> {
+        self.guarded("integration_router", || self.integration_router.resume_workflow(ctx, workflow))
+    }
+
+    /// Load the router's parameter schemas for local validation
+    pub fn load_parameter_registry(&self) -> ContractResult<ParameterRegistry> {
+        let schemas = self.guarded("integration_router", || self.integration_router.list_parameter_schemas())?;
+        ParameterRegistry::from_schemas(schemas).map_err(|_| ContractError::Validation(
+            shared::ValidationError::InvalidParameters
+        ))
+    }
+
+    /// Back up the router configuration
+    pub fn create_configuration_backup(&self, ctx: &OperationContext) -> ContractResult<soroban_sdk::BytesN<32>> {
+        self.guarded("integration_router", || self.integration_router.create_configuration_backup(ctx))
+    }
+
+    /// Restore a router configuration backup after checking what it changes
+    ///
+    /// # Returns
+    /// * `Ok(changed)` - Configuration sections the restore reverted
+    pub fn restore_configuration_backup(&self, ctx: &OperationContext, backup_id: &soroban_sdk::BytesN<32>) -> ContractResult<Vec<String>> {
+        self.guarded("integration_router", || {
+            let changed = self.integration_router.diff_configuration_backup(backup_id)?;
+            if !self.integration_router.restore_configuration_backup(ctx, backup_id)? {
+                return Err(ContractError::Validation(
+                    shared::ValidationError::InvalidParameters
+                ));
+            }
+            Ok(changed)
+        })
+    }
+
+    /// Announce a router maintenance window
+    pub fn schedule_maintenance(
+        &self,
+        ctx: &OperationContext,
+        start: u64,
+        end: u64,
+        allowed_workflows: &[PauseWorkflow],
+        message: &str,
+    ) -> ContractResult<()> {
+        self.guarded("integration_router", || {
+            self.integration_router.schedule_maintenance(ctx, start, end, allowed_workflows, message)
+        })
+    }
+
+    /// End router maintenance early or cancel a scheduled window
+    pub fn end_maintenance(&self, ctx: &OperationContext) -> ContractResult<()> {
+        self.guarded("integration_router", || self.integration_router.end_maintenance(ctx))
+    }
+
+    /// Get the router's scheduled or active maintenance window
+    pub fn get_maintenance_window(&self) -> ContractResult<Option<MaintenanceWindow>> {
+        self.guarded("integration_router", || self.integration_router.get_maintenance_window())
+    }
+
+    /// Dry-run a Bitcoin deposit through the router's checks without submitting it
+    pub fn simulate_bitcoin_deposit(
+        &self,
+        ctx: &OperationContext,
+        user: &Address,
+        btc_amount: u64,
+        btc_tx_hash: &soroban_sdk::BytesN<32>,
+        confirmations: u32,
+    ) -> ContractResult<SimulationReport> {
+        self.guarded("integration_router", || {
+            self.integration_router.simulate_bitcoin_deposit(ctx, user, btc_amount, btc_tx_hash, confirmations)
+        })
+    }
+
+    /// Dry-run a token withdrawal through the router's checks without submitting it
+    pub fn simulate_token_withdrawal(
+        &self,
+        ctx: &OperationContext,
+        user: &Address,
+        istsi_amount: u64,
+        btc_address: &str,
+        travel_rule_hash: Option<&soroban_sdk::BytesN<32>>,
+    ) -> ContractResult<SimulationReport> {
+        self.guarded("integration_router", || {
+            self.integration_router.simulate_token_withdrawal(ctx, user, istsi_amount, btc_address, travel_rule_hash)
+        })
+    }
+
+    /// Dry-run a cross-token exchange through the router's checks without submitting it
+    pub fn simulate_exchange(
+        &self,
+        user: &Address,
+        from_token: &Address,
+        to_token: &Address,
+        from_amount: u64,
+        max_slippage_bps: u64,
+    ) -> ContractResult<SimulationReport> {
+        self.guarded("integration_router", || {
+            self.integration_router.simulate_exchange(user, from_token, to_token, from_amount, max_slippage_bps)
+        })
+    }
+
+    /// Deposit exchange inventory as a market maker
+    pub fn deposit_liquidity(
+        &self,
+        ctx: &OperationContext,
+        token: &Address,
+        amount: u64,
+    ) -> ContractResult<LiquidityPosition> {
+        self.guarded("integration_router", || {
+            self.integration_router.deposit_liquidity(ctx, token, amount)
+        })
+    }
+
+    /// Withdraw exchange inventory and settled exchange proceeds as a market maker
+    pub fn withdraw_liquidity(
+        &self,
+        ctx: &OperationContext,
+        token: &Address,
+        amount: u64,
+    ) -> ContractResult<LiquidityPosition> {
+        self.guarded("integration_router", || {
+            self.integration_router.withdraw_liquidity(ctx, token, amount)
+        })
+    }
+
+    /// Get how much of a token exchanges can currently draw from inventory
+    pub fn get_inventory_depth(&self, token: &Address) -> ContractResult<InventoryDepth> {
+        self.guarded("integration_router", || {
+            self.integration_router.get_inventory_depth(token)
+        })
+    }
+
+    /// Place a resting limit order, failing fast while exchanges are paused
+    pub fn place_limit_order(
+        &self,
+        ctx: &OperationContext,
+        sell_token: &Address,
+        buy_token: &Address,
+        sell_amount: u64,
+        limit_price: u64,
+        expires_at: u64,
+    ) -> ContractResult<LimitOrder> {
+        self.require_workflow_active(PauseWorkflow::Exchanges)?;
+        self.guarded("integration_router", || {
+            self.integration_router.place_limit_order(ctx, sell_token, buy_token, sell_amount, limit_price, expires_at)
+        })
+    }
+
+    /// Cancel a resting limit order
+    pub fn cancel_limit_order(&self, ctx: &OperationContext, order_id: &soroban_sdk::BytesN<32>) -> ContractResult<()> {
+        self.guarded("integration_router", || {
+            self.integration_router.cancel_limit_order(ctx, order_id)
+        })
+    }
+
+    /// Get an owner's resting limit orders
+    pub fn get_open_orders(&self, owner: &Address) -> ContractResult<Vec<LimitOrder>> {
+        self.guarded("integration_router", || {
+            self.integration_router.get_open_orders(owner)
+        })
+    }
+
+    /// Get retained metrics snapshots for the admin dashboard charts
+    pub fn get_metrics_history(&self, from: u64, to: u64, resolution: u64) -> ContractResult<Vec<MetricsSnapshot>> {
+        self.guarded("integration_router", || {
+            self.integration_router.get_metrics_history(from, to, resolution)
+        })
+    }
+
+    /// Get the per-operation processing time breakdown for the admin dashboard
+    pub fn get_processing_times(&self) -> ContractResult<Vec<ProcessingTimeStats>> {
+        self.guarded("integration_router", || {
+            self.integration_router.get_processing_times()
+        })
+    }
+
+    /// Get the operator scoreboard for the admin dashboard
+    pub fn get_operator_scoreboard(&self, ctx: &OperationContext, period: StatsPeriod) -> ContractResult<Vec<OperatorStats>> {
+        self.guarded("integration_router", || {
+            self.integration_router.get_operator_scoreboard(ctx, period)
+        })
+    }
+
+    /// Get the router's recorded call outcomes for a contract
+    pub fn get_contract_call_stats(&self, address: &Address) -> ContractResult<ContractCallStats> {
+        self.guarded("integration_router", || {
+            self.integration_router.get_contract_call_stats(address)
+        })
+    }
+
+    /// Get all unresolved system alerts
+    pub fn get_system_alerts(&self) -> ContractResult<Vec<SystemAlert>> {
+        self.guarded("integration_router", || {
+            self.integration_router.get_system_alerts()
+        })
+    }
+
+    /// Get emergency notifications no contact has acknowledged yet
+    pub fn get_pending_notifications(&self) -> ContractResult<Vec<EmergencyNotification>> {
+        self.guarded("integration_router", || {
+            self.integration_router.get_pending_notifications()
+        })
+    }
+
+    /// Simulate a contract call and assemble a signable transaction on the configured network
+    /// 
+    /// # Arguments
+    /// * `source_account` - Strkey of the account paying fees
+    /// * `sequence` - Sequence number for the transaction
+    /// * `invocation` - Contract call to wrap
+    /// * `fees` - Resource and fee buffers
+    /// * `simulator` - Simulation backend
+    /// 
+    /// # Returns
+    /// * `Ok(prepared)` - Envelope for the host's signer
+    /// * `Err(ContractError)` - Simulation predicts failure or the fee limit is exceeded
+    pub fn prepare_transaction(
+        &self,
+        source_account: &str,
+        sequence: i64,
+        invocation: &ContractInvocation,
+        fees: FeeBufferConfig,
+        simulator: &dyn TransactionSimulator,
+    ) -> ContractResult<PreparedTransaction> {
+        TransactionBuilder::new(&self.network_config, source_account, sequence)?
+            .with_fee_buffers(fees)
+            .build(invocation, simulator)
+    }
+
+    /// Sign a prepared transaction with the configured signer
+    /// 
+    /// # Returns
+    /// * `Ok(signed)` - Envelope ready for submission
+    /// * `Err(ContractError)` - No signer is configured, it failed, or it does not hold the source account's key
+    pub fn sign_transaction(&self, prepared: &PreparedTransaction) -> ContractResult<SignedTransaction> {
+        let signer = self.signer.as_deref()
+            .ok_or(ContractError::Validation(shared::ValidationError::InvalidSignature))?;
+        crate::signer::sign_transaction(&self.env, prepared, signer)
+    }
+
+    /// Simulate, assemble and sign a contract call from the signer's account
+    /// 
+    /// # Arguments
+    /// * `sequence` - Sequence number for the transaction
+    /// * `invocation` - Contract call to wrap
+    /// * `fees` - Resource and fee buffers
+    /// * `simulator` - Simulation backend
+    pub fn prepare_signed_transaction(
+        &self,
+        sequence: i64,
+        invocation: &ContractInvocation,
+        fees: FeeBufferConfig,
+        simulator: &dyn TransactionSimulator,
+    ) -> ContractResult<SignedTransaction> {
+        let signer = self.signer.as_deref()
+            .ok_or(ContractError::Validation(shared::ValidationError::InvalidSignature))?;
+        let source_account = stellar_strkey::ed25519::PublicKey(signer.public_key()?).to_string();
+
+        let prepared = self.prepare_transaction(&source_account, sequence, invocation, fees, simulator)?;
+        self.sign_transaction(&prepared)
+    }
+
+    /// Simulate, sign and submit a contract call from the signer's account
+    /// 
+    /// # Arguments
+    /// * `sequence` - Sequence number for the transaction
+    /// * `invocation` - Contract call to wrap
+    /// * `fees` - Resource and fee buffers
+    /// * `simulator` - Simulation backend
+    /// * `submitter` - Submission backend
+    /// 
+    /// # Returns
+    /// * `Ok(response)` - The network accepted the transaction
+    /// * `Err(ContractError)` - Signing failed, or simulation or the network rejected the call
+    pub fn submit_transaction(
+        &self,
+        sequence: i64,
+        invocation: &ContractInvocation,
+        fees: FeeBufferConfig,
+        simulator: &dyn TransactionSimulator,
+        submitter: &dyn TransactionSubmitter,
+    ) -> ContractResult<SubmissionResponse> {
+        let signed = self.prepare_signed_transaction(sequence, invocation, fees, simulator)?;
+        crate::transaction::submit_transaction(&signed, submitter)
+    }
+
+    /// Simulate, sign and submit a contract call with a remote signer
+    /// 
+    /// The transaction is sourced from the signer's account, as with
+    /// `submit_transaction`.
+    pub async fn submit_transaction_async(
+        &self,
+        sequence: i64,
+        invocation: &ContractInvocation,
+        fees: FeeBufferConfig,
+        simulator: &dyn TransactionSimulator,
+        submitter: &dyn TransactionSubmitter,
+        signer: &dyn AsyncSigner,
+    ) -> ContractResult<SubmissionResponse> {
+        let source_account = stellar_strkey::ed25519::PublicKey(signer.public_key_async().await?).to_string();
+        let prepared = self.prepare_transaction(&source_account, sequence, invocation, fees, simulator)?;
+        let signed = crate::signer::sign_transaction_async(&self.env, &prepared, signer).await?;
+        crate::transaction::submit_transaction(&signed, submitter)
+    }
+
+    /// Fail fast when the router would reject a workflow as paused
+    fn require_workflow_active(&self, workflow: PauseWorkflow) -> ContractResult<()> {
+        let state = self.get_pause_state()?;
+        if state.system_paused {
+            return Err(ContractError::Integration(shared::IntegrationError::SystemPaused));
+        }
+        if state.is_workflow_paused(workflow) {
+            return Err(ContractError::Integration(shared::IntegrationError::WorkflowPaused));
+        }
+        let now = self.env.ledger().timestamp();
+        if self.get_maintenance_window()?.is_some_and(|window| window.blocks(workflow, now)) {
+            return Err(ContractError::Integration(shared::IntegrationError::MaintenanceMode));
+        }
+        Ok(())
+    }
+
+    /// Derive a contract's health from availability and its circuit breaker
+    fn contract_health(&self, contract_name: &str, available: bool) -> ComponentHealth {
+        if !available {
+            return ComponentHealth::Critical;
+        }
+
+        match self.circuit_state(contract_name) {
+            CircuitState::Closed => ComponentHealth::Healthy,
+            CircuitState::HalfOpen => ComponentHealth::Degraded,
+            CircuitState::Open => ComponentHealth::Critical,
+        }
+    }
+
+    /// Execute a contract call through that contract's circuit breaker
+    fn guarded<T, F>(&self, contract_name: &str, call: F) -> ContractResult<T>
+    where
+        F: FnOnce() -> ContractResult<T>,
+    {
+        let now = self.env.ledger().timestamp();
+        self.circuit_breakers.borrow_mut().call(contract_name, now, call)
+    }
+
     /// Helper function to calculate iSTSi amount from Bitcoin amount
     fn calculate_istsi_amount(&self, btc_amount: u64) -> ContractResult<u64> {
         // Simplified 1:1 conversion for now
@@ -382,6 +994,7 @@ impl ContractManager {
 
 /// System health status
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SystemHealth {
     pub integration_router_available: bool,
     pub kyc_registry_available: bool,
@@ -394,6 +1007,7 @@ pub struct SystemHealth {
 
 /// Comprehensive system status
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SystemStatus {
     pub total_btc_reserves: u64,
     pub total_istsi_supply: u64,
@@ -402,4 +1016,121 @@ pub struct SystemStatus {
     pub kyc_enabled: bool,
     pub system_paused: bool,
     pub last_updated: u64,
-}
\ No newline at end of file
+}
+/// Health level of a single monitored component
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ComponentHealth {
+    Healthy,
+    Degraded,
+    Critical,
+}
+
+/// Change in a component's health between two polls
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HealthTransition {
+    pub component: String,
+    pub previous: ComponentHealth,
+    pub current: ComponentHealth,
+    pub timestamp: u64,
+}
+
+impl HealthTransition {
+    /// Whether the component got worse
+    pub fn is_degradation(&self) -> bool {
+        self.current.severity() > self.previous.severity()
+    }
+
+    /// Whether the component got better
+    pub fn is_recovery(&self) -> bool {
+        self.current.severity() < self.previous.severity()
+    }
+}
+
+impl ComponentHealth {
+    fn severity(&self) -> u8 {
+        match self {
+            ComponentHealth::Healthy => 0,
+            ComponentHealth::Degraded => 1,
+            ComponentHealth::Critical => 2,
+        }
+    }
+}
+
+/// Tracks the last observed health of each component between polls
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HealthWatcher {
+    last_snapshot: Option<HashMap<String, ComponentHealth>>,
+}
+
+impl HealthWatcher {
+    /// Create a watcher with no baseline
+    pub fn new() -> Self {
+        Self { last_snapshot: None }
+    }
+
+    /// Get the last observed health of a component
+    pub fn last_health(&self, component: &str) -> Option<ComponentHealth> {
+        self.last_snapshot.as_ref().and_then(|snapshot| snapshot.get(component).copied())
+    }
+
+    /// Record a new snapshot and return the transitions from the previous one
+    pub fn update(&mut self, snapshot: HashMap<String, ComponentHealth>, timestamp: u64) -> Vec<HealthTransition> {
+        let mut transitions = Vec::new();
+
+        if let Some(previous) = &self.last_snapshot {
+            for (component, current) in snapshot.iter() {
+                if let Some(prev) = previous.get(component) {
+                    if prev != current {
+                        transitions.push(HealthTransition {
+                            component: component.clone(),
+                            previous: *prev,
+                            current: *current,
+                            timestamp,
+                        });
+                    }
+                }
+            }
+        }
+
+        self.last_snapshot = Some(snapshot);
+        transitions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(router: ComponentHealth, reserves: ComponentHealth) -> HashMap<String, ComponentHealth> {
+        let mut components = HashMap::new();
+        components.insert("integration_router".to_string(), router);
+        components.insert("reserve_ratio".to_string(), reserves);
+        components
+    }
+
+    #[test]
+    fn test_health_watcher_reports_transitions_after_baseline() {
+        let mut watcher = HealthWatcher::new();
+        assert_eq!(watcher.last_health("integration_router"), None);
+
+        // First poll only records the baseline
+        let transitions = watcher.update(snapshot(ComponentHealth::Healthy, ComponentHealth::Healthy), 100);
+        assert!(transitions.is_empty());
+
+        let transitions = watcher.update(snapshot(ComponentHealth::Critical, ComponentHealth::Healthy), 200);
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(transitions[0].component, "integration_router");
+        assert_eq!(transitions[0].timestamp, 200);
+        assert!(transitions[0].is_degradation());
+
+        let transitions = watcher.update(snapshot(ComponentHealth::Degraded, ComponentHealth::Healthy), 300);
+        assert!(transitions[0].is_recovery());
+        assert_eq!(watcher.last_health("integration_router"), Some(ComponentHealth::Degraded));
+
+        // Unchanged components report nothing
+        assert!(watcher.update(snapshot(ComponentHealth::Degraded, ComponentHealth::Healthy), 400).is_empty());
+    }
+}
@@ -1,5 +1,6 @@
 use soroban_sdk::{Address, Env, BytesN, String as SorobanString, Val};
 use alloc::collections::BTreeMap as HashMap;
+use alloc::collections::BTreeSet;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use alloc::boxed::Box;
@@ -13,7 +14,9 @@ use crate::{ContractResult, ContractError};
 
 /// Contract event structure
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContractEvent {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
     pub contract_address: Address,
     pub event_type: String,
     pub topics: Vec<String>,
@@ -23,32 +26,191 @@ pub struct ContractEvent {
     pub transaction_hash: String,
 }
 
+impl ContractEvent {
+    /// Router correlation ID carried in the event topics, if any
+    pub fn correlation_id(&self) -> Option<[u8; 32]> {
+        self.topic_ids().next()
+    }
+
+    /// Correlation ID of the workflow or event this event was emitted under
+    ///
+    /// The router publishes it as the topic after the event's own correlation ID.
+    pub fn parent_correlation_id(&self) -> Option<[u8; 32]> {
+        self.topic_ids().nth(1)
+    }
+
+    fn topic_ids(&self) -> impl Iterator<Item = [u8; 32]> + '_ {
+        self.topics.iter().filter_map(|topic| {
+            let mut id = [0u8; 32];
+            hex::decode_to_slice(topic.trim_start_matches("0x"), &mut id).ok()?;
+            Some(id)
+        })
+    }
+
+    /// Distributed trace ID carried in the event's correlation ID, if any
+    pub fn trace_id(&self) -> Option<[u8; 16]> {
+        extract_trace_id(&self.correlation_id()?)
+    }
+
+    /// User address the event refers to, if any
+    pub fn user(&self) -> Option<Address> {
+        match &self.data {
+            EventData::BitcoinDeposit { user, .. } => Some(user.clone()),
+            EventData::DepositConfirmations { user, .. } => Some(user.clone()),
+            EventData::TokenWithdrawal { user, .. } => Some(user.clone()),
+            EventData::CrossTokenExchange { user, .. } => Some(user.clone()),
+            EventData::ComplianceCheck { user, .. } => Some(user.clone()),
+            EventData::IntegrationOperation { user, .. } => Some(user.clone()),
+            EventData::OrderFill { owner, .. } => Some(owner.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Extract the trace ID from a router correlation ID
+///
+/// Traced correlation IDs hold the trace ID in bytes 16..32; untraced IDs
+/// leave bytes 20..32 zeroed.
+pub fn extract_trace_id(correlation_id: &[u8; 32]) -> Option<[u8; 16]> {
+    if correlation_id[20..32].iter().all(|b| *b == 0) {
+        return None;
+    }
+
+    let mut trace_id = [0u8; 16];
+    trace_id.copy_from_slice(&correlation_id[16..32]);
+    Some(trace_id)
+}
+
+/// One event in an assembled workflow trace
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TraceNode {
+    pub correlation_id: [u8; 32],
+    /// `None` for the workflow's own root event
+    pub parent_correlation_id: Option<[u8; 32]>,
+    /// 0 for the root, 1 for events emitted directly under the workflow
+    pub depth: u32,
+    pub event: ContractEvent,
+}
+
+/// Events of one workflow, in depth-first order
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperationTrace {
+    pub root_correlation_id: [u8; 32],
+    pub nodes: Vec<TraceNode>,
+}
+
+/// Assemble the lineage tree under a workflow's root correlation ID from observed events
+///
+/// Mirrors the router's `get_operation_trace`: nodes are depth-first with
+/// children in ledger order. Events whose parent was never observed are
+/// left out, and their children with them.
+pub fn assemble_trace(root_correlation_id: [u8; 32], events: &[ContractEvent]) -> OperationTrace {
+    let mut children: HashMap<[u8; 32], Vec<&ContractEvent>> = HashMap::new();
+    let mut root_event = None;
+    for event in events {
+        if event.correlation_id() == Some(root_correlation_id) {
+            root_event = Some(event);
+        }
+        if let Some(parent) = event.parent_correlation_id() {
+            children.entry(parent).or_default().push(event);
+        }
+    }
+    for siblings in children.values_mut() {
+        siblings.sort_by_key(|event| event.block_number);
+    }
+
+    let mut nodes = Vec::new();
+    if let Some(event) = root_event {
+        nodes.push(TraceNode {
+            correlation_id: root_correlation_id,
+            parent_correlation_id: None,
+            depth: 0,
+            event: event.clone(),
+        });
+    }
+
+    // Explicit stack of (event, parent, depth); siblings are pushed newest first
+    let mut visited = BTreeSet::new();
+    let mut pending: Vec<(&ContractEvent, [u8; 32], u32)> = children
+        .get(&root_correlation_id)
+        .map(|siblings| siblings.iter().rev().map(|event| (*event, root_correlation_id, 1)).collect())
+        .unwrap_or_default();
+    while let Some((event, parent, depth)) = pending.pop() {
+        // Events are only indexed under a parent when they carry their own ID
+        let correlation_id = event.correlation_id().unwrap_or_default();
+        if !visited.insert(correlation_id) {
+            continue;
+        }
+        nodes.push(TraceNode {
+            correlation_id,
+            parent_correlation_id: Some(parent),
+            depth,
+            event: event.clone(),
+        });
+        if let Some(siblings) = children.get(&correlation_id) {
+            pending.extend(siblings.iter().rev().map(|child| (*child, correlation_id, depth + 1)));
+        }
+    }
+
+    OperationTrace { root_correlation_id, nodes }
+}
+
 /// Event data enumeration for different event types
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventData {
     BitcoinDeposit {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
         user: Address,
         btc_amount: u64,
         istsi_amount: u64,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
         btc_tx_hash: BytesN<32>,
         confirmations: u32,
     },
+    DepositConfirmations {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+        user: Address,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+        btc_tx_hash: BytesN<32>,
+        confirmations: u32,
+        required: u32,
+    },
     TokenWithdrawal {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
         user: Address,
         istsi_amount: u64,
         btc_amount: u64,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
         withdrawal_id: BytesN<32>,
         btc_address: String,
     },
     CrossTokenExchange {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
         user: Address,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
         from_token: Address,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
         to_token: Address,
         from_amount: u64,
         to_amount: u64,
         exchange_rate: u64,
+        /// Exchange fee charged in `from_token` units (the router's `data3`)
+        fee: u64,
+    },
+    OrderFill {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+        order_id: BytesN<32>,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+        owner: Address,
+        sold: u64,
+        received: u64,
+        remaining: u64,
     },
     ComplianceCheck {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
         user: Address,
         operation_type: u32,
         amount: u64,
@@ -63,17 +225,35 @@ pub enum EventData {
         operation_type: String,
     },
     SystemPause {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
         admin: Address,
         reason: String,
         paused: bool,
     },
     IntegrationOperation {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
         operation_id: BytesN<32>,
         operation_type: String,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
         user: Address,
         amount: u64,
         status: String,
     },
+    TierChangeRequested {
+        customer_id: String,
+        request_id: u64,
+        current_tier: u32,
+        requested_tier: u32,
+    },
+    TierChangeReviewed {
+        customer_id: String,
+        request_id: u64,
+        approved: bool,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+        reviewer: Address,
+        previous_tier: u32,
+        requested_tier: u32,
+    },
     Generic {
         data: HashMap<String, String>,
     },
@@ -81,9 +261,12 @@ pub enum EventData {
 
 /// Event filter for monitoring specific events
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EventFilter {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address_vec"))]
     pub contract_addresses: Vec<Address>,
     pub event_types: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address_vec"))]
     pub user_addresses: Vec<Address>,
     pub start_block: Option<u64>,
     pub end_block: Option<u64>,
@@ -133,6 +316,50 @@ impl EventFilter {
         self.limit = Some(limit);
         self
     }
+
+    /// Check if an event matches this filter
+    pub fn matches(&self, event: &ContractEvent) -> bool {
+        // Check contract address filter
+        if !self.contract_addresses.is_empty() {
+            if !self.contract_addresses.contains(&event.contract_address) {
+                return false;
+            }
+        }
+
+        // Check event type filter
+        if !self.event_types.is_empty() {
+            if !self.event_types.contains(&event.event_type) {
+                return false;
+            }
+        }
+
+        // Check user address filter
+        if !self.user_addresses.is_empty() {
+            let event_user = event.user();
+            if let Some(user) = event_user {
+                if !self.user_addresses.contains(&user) {
+                    return false;
+                }
+            } else {
+                return false;
+            }
+        }
+
+        // Check block range filter
+        if let Some(start_block) = self.start_block {
+            if event.block_number < start_block {
+                return false;
+            }
+        }
+
+        if let Some(end_block) = self.end_block {
+            if event.block_number > end_block {
+                return false;
+            }
+        }
+
+        true
+    }
 }
 
 impl Default for EventFilter {
@@ -146,8 +373,18 @@ pub struct EventMonitor {
     env: Env,
     subscriptions: HashMap<String, EventSubscription>,
     event_handlers: HashMap<String, Box<dyn Fn(&ContractEvent) -> ContractResult<()>>>,
+    /// Recently processed events keyed by (TOID, event index)
+    processed_event_ids: BTreeSet<(u64, u32)>,
+    /// Highest event evicted from the dedup window; anything at or before it
+    /// counts as processed
+    processed_floor: Option<(u64, u32)>,
+    dedup_capacity: usize,
 }
 
+/// Processed event IDs an `EventMonitor` remembers before folding the oldest
+/// into its low-water mark
+pub const DEFAULT_DEDUP_CAPACITY: usize = 10_000;
+
 impl EventMonitor {
     /// Create a new event monitor
     pub fn new(env: Env) -> Self {
@@ -155,9 +392,22 @@ impl EventMonitor {
             env,
             subscriptions: HashMap::new(),
             event_handlers: HashMap::new(),
+            processed_event_ids: BTreeSet::new(),
+            processed_floor: None,
+            dedup_capacity: DEFAULT_DEDUP_CAPACITY,
         }
     }
 
+    /// Bound the number of event IDs kept for deduplication
+    ///
+    /// Once full, the oldest IDs are dropped and every event at or before
+    /// them is treated as processed, so memory stays flat for long-running
+    /// indexers that move forward through the chain.
+    pub fn with_dedup_capacity(mut self, capacity: usize) -> Self {
+        self.dedup_capacity = capacity.max(1);
+        self
+    }
+
     /// Subscribe to events matching a filter
     /// 
     /// # Arguments
@@ -289,6 +539,176 @@ impl EventMonitor {
             .collect()
     }
 
+    /// Replay historical events through the active subscriptions
+    ///
+    /// Pages through `source` from the checkpoint's cursor (or start ledger),
+    /// parses each event and hands it to matching subscriptions. Events whose
+    /// RPC IDs were already processed are skipped, so overlapping ranges and
+    /// resumed runs do not double-deliver. The returned checkpoint should be
+    /// persisted by the caller and passed back in to resume.
+    ///
+    /// # Arguments
+    /// * `source` - Historical event source (Soroban RPC `getEvents`)
+    /// * `checkpoint` - Where to resume from
+    /// * `page_size` - Events requested per page
+    /// * `max_pages` - Pages fetched before returning (0 = until complete)
+    ///
+    /// # Returns
+    /// * `Ok(checkpoint)` - Updated checkpoint; `complete` once the range is exhausted
+    /// * `Err(ContractError)` - Fetch or parse error; the input checkpoint is still valid
+    pub fn backfill(
+        &mut self,
+        source: &dyn EventSource,
+        checkpoint: BackfillCheckpoint,
+        page_size: u32,
+        max_pages: u32,
+    ) -> ContractResult<BackfillCheckpoint> {
+        if page_size == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        let mut checkpoint = checkpoint;
+        let mut pages = 0;
+
+        while !checkpoint.complete && (max_pages == 0 || pages < max_pages) {
+            let page = source.get_events(checkpoint.start_ledger, checkpoint.cursor.as_deref(), page_size)?;
+            pages += 1;
+
+            for raw in page.events.iter() {
+                if raw.ledger > checkpoint.end_ledger {
+                    checkpoint.complete = true;
+                    break;
+                }
+
+                let key = Self::event_key(&raw.id)?;
+                if !self.is_key_processed(key) {
+                    let event = self.parse_event(
+                        raw.contract_address.clone(),
+                        raw.topics.clone(),
+                        raw.data.clone(),
+                        raw.ledger_closed_at,
+                        raw.ledger,
+                        raw.tx_hash.clone(),
+                    )?;
+                    self.process_events(alloc::vec![event])?;
+                    self.record_processed(key);
+                    checkpoint.events_processed += 1;
+                }
+                checkpoint.last_ledger = raw.ledger;
+            }
+
+            match page.cursor {
+                Some(cursor) if (page.events.len() as u32) == page_size => checkpoint.cursor = Some(cursor),
+                cursor => {
+                    checkpoint.cursor = cursor.or(checkpoint.cursor.take());
+                    if page.latest_ledger >= checkpoint.end_ledger {
+                        checkpoint.complete = true;
+                    } else {
+                        // Caught up with the chain tip; resume later from here
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(checkpoint)
+    }
+
+    /// Deliver events to one subscription, committing a checkpoint after each success
+    ///
+    /// Events at or before the subscription's committed position are skipped,
+    /// so replaying a batch after a restart does not double-process. The
+    /// checkpoint only advances once the handler returns `Ok`; on the first
+    /// failure processing stops and the error is returned, leaving the failed
+    /// event to be redelivered next time.
+    ///
+    /// # Arguments
+    /// * `store` - Checkpoint persistence
+    /// * `subscription_id` - Subscription whose handler consumes the events
+    /// * `events` - Events with their chain positions, in order
+    ///
+    /// # Returns
+    /// * `Ok(count)` - Number of events delivered to the handler
+    /// * `Err(ContractError)` - Handler or store failure
+    pub fn process_events_with_checkpoint(
+        &self,
+        store: &mut dyn CheckpointStore,
+        subscription_id: &str,
+        events: Vec<(EventPosition, ContractEvent)>,
+    ) -> ContractResult<u32> {
+        let subscription = self.subscriptions.get(subscription_id)
+            .ok_or_else(|| ContractError::ParseError(format!("Subscription not found: {}", subscription_id)))?;
+        let handler = self.event_handlers.get(subscription_id)
+            .ok_or_else(|| ContractError::ParseError(format!("Subscription not found: {}", subscription_id)))?;
+
+        // Paused consumers keep their checkpoint so nothing is skipped
+        if !subscription.active {
+            return Ok(0);
+        }
+
+        let mut committed = store.load(subscription_id)?;
+        let mut delivered = 0;
+
+        for (position, event) in events {
+            if committed.as_ref().map_or(false, |checkpoint| position <= checkpoint.position) {
+                continue;
+            }
+
+            if subscription.filter.matches(&event) {
+                handler(&event)?;
+                delivered += 1;
+            }
+
+            let checkpoint = EventCheckpoint {
+                position,
+                transaction_hash: event.transaction_hash.clone(),
+                committed_at: self.env.ledger().timestamp(),
+            };
+            store.commit(subscription_id, &checkpoint)?;
+            committed = Some(checkpoint);
+        }
+
+        Ok(delivered)
+    }
+
+    /// Check whether an event ID has already been processed
+    pub fn is_event_processed(&self, event_id: &str) -> bool {
+        Self::event_key(event_id).is_ok_and(|key| self.is_key_processed(key))
+    }
+
+    /// Seed the dedup set, e.g. with IDs recorded by a previous indexer run
+    pub fn mark_events_processed(&mut self, event_ids: Vec<String>) -> ContractResult<()> {
+        for event_id in event_ids.iter() {
+            let key = Self::event_key(event_id)?;
+            self.record_processed(key);
+        }
+        Ok(())
+    }
+
+    fn is_key_processed(&self, key: (u64, u32)) -> bool {
+        self.processed_floor.is_some_and(|floor| key <= floor) || self.processed_event_ids.contains(&key)
+    }
+
+    fn record_processed(&mut self, key: (u64, u32)) {
+        if self.is_key_processed(key) {
+            return;
+        }
+        self.processed_event_ids.insert(key);
+        while self.processed_event_ids.len() > self.dedup_capacity {
+            // Entries are ordered, so the popped ID is always above the old floor
+            self.processed_floor = self.processed_event_ids.pop_first();
+        }
+    }
+
+    /// Parse an RPC event ID (`<toid>-<event index>`) into a sortable key
+    fn event_key(event_id: &str) -> ContractResult<(u64, u32)> {
+        event_id.split_once('-')
+            .and_then(|(toid, index)| Some((toid.parse().ok()?, index.parse().ok()?)))
+            .ok_or_else(|| ContractError::ParseError(format!("Invalid event ID: {}", event_id)))
+    }
+
     /// Pause a subscription
     /// 
     /// # Arguments
@@ -325,58 +745,7 @@ impl EventMonitor {
 
     /// Check if an event matches a filter
     fn event_matches_filter(&self, event: &ContractEvent, filter: &EventFilter) -> bool {
-        // Check contract address filter
-        if !filter.contract_addresses.is_empty() {
-            if !filter.contract_addresses.contains(&event.contract_address) {
-                return false;
-            }
-        }
-
-        // Check event type filter
-        if !filter.event_types.is_empty() {
-            if !filter.event_types.contains(&event.event_type) {
-                return false;
-            }
-        }
-
-        // Check user address filter
-        if !filter.user_addresses.is_empty() {
-            let event_user = self.extract_user_from_event(event);
-            if let Some(user) = event_user {
-                if !filter.user_addresses.contains(&user) {
-                    return false;
-                }
-            } else {
-                return false;
-            }
-        }
-
-        // Check block range filter
-        if let Some(start_block) = filter.start_block {
-            if event.block_number < start_block {
-                return false;
-            }
-        }
-
-        if let Some(end_block) = filter.end_block {
-            if event.block_number > end_block {
-                return false;
-            }
-        }
-
-        true
-    }
-
-    /// Extract user address from event data
-    fn extract_user_from_event(&self, event: &ContractEvent) -> Option<Address> {
-        match &event.data {
-            EventData::BitcoinDeposit { user, .. } => Some(user.clone()),
-            EventData::TokenWithdrawal { user, .. } => Some(user.clone()),
-            EventData::CrossTokenExchange { user, .. } => Some(user.clone()),
-            EventData::ComplianceCheck { user, .. } => Some(user.clone()),
-            EventData::IntegrationOperation { user, .. } => Some(user.clone()),
-            _ => None,
-        }
+        filter.matches(event)
     }
 
     /// Parse event data based on event type
@@ -388,12 +757,16 @@ impl EventMonitor {
     ) -> ContractResult<EventData> {
         match event_type {
             "btc_dep" => self.parse_bitcoin_deposit_event(topics, data),
+            "dep_conf" => self.parse_deposit_confirmations_event(topics, data),
             "tok_with" => self.parse_token_withdrawal_event(topics, data),
             "cross_ex" => self.parse_cross_token_exchange_event(topics, data),
+            "ord_fill" => self.parse_order_fill_event(topics, data),
             "kyc_chk" => self.parse_compliance_check_event(topics, data),
             "supply" => self.parse_reserve_update_event(topics, data),
             "emergency" | "resume" => self.parse_system_pause_event(topics, data),
             "int_op" => self.parse_integration_operation_event(topics, data),
+            "kyc_req" => self.parse_tier_change_request_event(topics, data),
+            "kyc_rev" => self.parse_tier_change_review_event(topics, data),
             _ => Ok(EventData::Generic {
                 data: self.parse_generic_event_data(topics, data),
             }),
@@ -405,7 +778,7 @@ impl EventMonitor {
         // In a real implementation, this would parse the actual event data
         // For now, we'll return mock data
         Ok(EventData::BitcoinDeposit {
-            user: Address::from_string(&SorobanString::from_str(&self.env, "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX")),
+            user: Address::from_string(&SorobanString::from_str(&self.env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF")),
             btc_amount: 100_000_000, // 1 BTC
             istsi_amount: 100_000_000, // 1 iSTSi
             btc_tx_hash: BytesN::from_array(&self.env, &[1u8; 32]),
@@ -413,10 +786,20 @@ impl EventMonitor {
         })
     }
 
+    /// Parse deposit confirmation progression event
+    fn parse_deposit_confirmations_event(&self, topics: &[String], data: &[Val]) -> ContractResult<EventData> {
+        Ok(EventData::DepositConfirmations {
+            user: Address::from_string(&SorobanString::from_str(&self.env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF")),
+            btc_tx_hash: BytesN::from_array(&self.env, &[1u8; 32]),
+            confirmations: 2,
+            required: 3,
+        })
+    }
+
     /// Parse token withdrawal event
     fn parse_token_withdrawal_event(&self, topics: &[String], data: &[Val]) -> ContractResult<EventData> {
         Ok(EventData::TokenWithdrawal {
-            user: Address::from_string(&SorobanString::from_str(&self.env, "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX")),
+            user: Address::from_string(&SorobanString::from_str(&self.env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF")),
             istsi_amount: 50_000_000, // 0.5 iSTSi
             btc_amount: 50_000_000, // 0.5 BTC
             withdrawal_id: BytesN::from_array(&self.env, &[2u8; 32]),
@@ -427,19 +810,31 @@ impl EventMonitor {
     /// Parse cross-token exchange event
     fn parse_cross_token_exchange_event(&self, topics: &[String], data: &[Val]) -> ContractResult<EventData> {
         Ok(EventData::CrossTokenExchange {
-            user: Address::from_string(&SorobanString::from_str(&self.env, "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX")),
-            from_token: Address::from_string(&SorobanString::from_str(&self.env, "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX")),
-            to_token: Address::from_string(&SorobanString::from_str(&self.env, "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX")),
+            user: Address::from_string(&SorobanString::from_str(&self.env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF")),
+            from_token: Address::from_string(&SorobanString::from_str(&self.env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF")),
+            to_token: Address::from_string(&SorobanString::from_str(&self.env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF")),
             from_amount: 100_000_000,
             to_amount: 100_000_000,
             exchange_rate: 10000, // 1:1 rate
+            fee: 0,
+        })
+    }
+
+    /// Parse limit order fill event
+    fn parse_order_fill_event(&self, topics: &[String], data: &[Val]) -> ContractResult<EventData> {
+        Ok(EventData::OrderFill {
+            order_id: BytesN::from_array(&self.env, &[4u8; 32]),
+            owner: Address::from_string(&SorobanString::from_str(&self.env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF")),
+            sold: 75_000,
+            received: 150_000,
+            remaining: 25_000,
         })
     }
 
     /// Parse compliance check event
     fn parse_compliance_check_event(&self, topics: &[String], data: &[Val]) -> ContractResult<EventData> {
         Ok(EventData::ComplianceCheck {
-            user: Address::from_string(&SorobanString::from_str(&self.env, "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX")),
+            user: Address::from_string(&SorobanString::from_str(&self.env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF")),
             operation_type: 1, // Mint
             amount: 100_000_000,
             approved: true,
@@ -461,7 +856,7 @@ impl EventMonitor {
     /// Parse system pause event
     fn parse_system_pause_event(&self, topics: &[String], data: &[Val]) -> ContractResult<EventData> {
         Ok(EventData::SystemPause {
-            admin: Address::from_string(&SorobanString::from_str(&self.env, "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX")),
+            admin: Address::from_string(&SorobanString::from_str(&self.env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF")),
             reason: "Emergency maintenance".to_string(),
             paused: true,
         })
@@ -472,12 +867,34 @@ impl EventMonitor {
         Ok(EventData::IntegrationOperation {
             operation_id: BytesN::from_array(&self.env, &[3u8; 32]),
             operation_type: "bitcoin_deposit".to_string(),
-            user: Address::from_string(&SorobanString::from_str(&self.env, "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX")),
+            user: Address::from_string(&SorobanString::from_str(&self.env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF")),
             amount: 100_000_000,
             status: "completed".to_string(),
         })
     }
 
+    /// Parse tier change request event
+    fn parse_tier_change_request_event(&self, topics: &[String], data: &[Val]) -> ContractResult<EventData> {
+        Ok(EventData::TierChangeRequested {
+            customer_id: topics.get(1).cloned().unwrap_or_default(),
+            request_id: 1,
+            current_tier: 1, // Basic
+            requested_tier: 2, // Verified
+        })
+    }
+
+    /// Parse tier change review event
+    fn parse_tier_change_review_event(&self, topics: &[String], data: &[Val]) -> ContractResult<EventData> {
+        Ok(EventData::TierChangeReviewed {
+            customer_id: topics.get(1).cloned().unwrap_or_default(),
+            request_id: 1,
+            approved: true,
+            reviewer: Address::from_string(&SorobanString::from_str(&self.env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF")),
+            previous_tier: 1,
+            requested_tier: 2,
+        })
+    }
+
     /// Parse generic event data
     fn parse_generic_event_data(&self, topics: &[String], data: &[Val]) -> HashMap<String, String> {
         let mut parsed_data = HashMap::new();
@@ -494,8 +911,136 @@ impl EventMonitor {
     }
 }
 
+/// Raw event as returned by Soroban RPC `getEvents`
+#[derive(Debug, Clone)]
+pub struct RpcEvent {
+    /// RPC event ID (unique per ledger, transaction and event index)
+    pub id: String,
+    pub ledger: u64,
+    pub ledger_closed_at: u64,
+    pub contract_address: Address,
+    pub topics: Vec<String>,
+    pub data: Vec<Val>,
+    pub tx_hash: String,
+}
+
+/// One page of `getEvents` results
+#[derive(Debug, Clone)]
+pub struct EventPage {
+    pub events: Vec<RpcEvent>,
+    /// Paging cursor for the next request
+    pub cursor: Option<String>,
+    /// Latest ledger known to the RPC node
+    pub latest_ledger: u64,
+}
+
+/// Source of historical contract events
+pub trait EventSource {
+    /// Fetch events starting at `start_ledger`, or after `cursor` when given
+    fn get_events(&self, start_ledger: u64, cursor: Option<&str>, limit: u32) -> ContractResult<EventPage>;
+}
+
+/// Position of an event on chain, ordered by ledger then event index
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventPosition {
+    pub ledger: u64,
+    pub event_index: u64,
+}
+
+impl EventPosition {
+    /// Derive a position from an RPC event (`<toid>-<event index>` IDs)
+    pub fn from_rpc_event(event: &RpcEvent) -> ContractResult<Self> {
+        let (toid, index) = event.id.split_once('-')
+            .ok_or_else(|| ContractError::ParseError(format!("Invalid event ID: {}", event.id)))?;
+        let toid: u64 = toid.parse()
+            .map_err(|_| ContractError::ParseError(format!("Invalid event ID: {}", event.id)))?;
+        let index: u32 = index.parse()
+            .map_err(|_| ContractError::ParseError(format!("Invalid event ID: {}", event.id)))?;
+
+        // The low 32 bits of the TOID hold the transaction and operation order
+        Ok(Self {
+            ledger: event.ledger,
+            event_index: ((toid & 0xFFFF_FFFF) << 32) | index as u64,
+        })
+    }
+}
+
+/// Last event a consumer processed successfully
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventCheckpoint {
+    pub position: EventPosition,
+    pub transaction_hash: String,
+    pub committed_at: u64,
+}
+
+/// Persistence for per-consumer event checkpoints
+///
+/// Implementations backed by a database or key-value store let consumers
+/// resume after a restart without reprocessing committed events.
+pub trait CheckpointStore {
+    /// Load the last committed checkpoint for a consumer
+    fn load(&self, consumer_id: &str) -> ContractResult<Option<EventCheckpoint>>;
+
+    /// Durably record a checkpoint for a consumer
+    fn commit(&mut self, consumer_id: &str, checkpoint: &EventCheckpoint) -> ContractResult<()>;
+}
+
+/// Checkpoint store kept in memory (tests and single-process consumers)
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InMemoryCheckpointStore {
+    checkpoints: HashMap<String, EventCheckpoint>,
+}
+
+impl InMemoryCheckpointStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CheckpointStore for InMemoryCheckpointStore {
+    fn load(&self, consumer_id: &str) -> ContractResult<Option<EventCheckpoint>> {
+        Ok(self.checkpoints.get(consumer_id).cloned())
+    }
+
+    fn commit(&mut self, consumer_id: &str, checkpoint: &EventCheckpoint) -> ContractResult<()> {
+        self.checkpoints.insert(consumer_id.to_string(), checkpoint.clone());
+        Ok(())
+    }
+}
+
+/// Resumable backfill position
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BackfillCheckpoint {
+    pub start_ledger: u64,
+    pub end_ledger: u64,
+    pub cursor: Option<String>,
+    pub last_ledger: u64,
+    pub events_processed: u64,
+    pub complete: bool,
+}
+
+impl BackfillCheckpoint {
+    /// Start a backfill over an inclusive ledger range
+    pub fn new(start_ledger: u64, end_ledger: u64) -> Self {
+        Self {
+            start_ledger,
+            end_ledger,
+            cursor: None,
+            last_ledger: 0,
+            events_processed: 0,
+            complete: false,
+        }
+    }
+}
+
 /// Event subscription structure
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EventSubscription {
     pub id: String,
     pub filter: EventFilter,
@@ -505,6 +1050,7 @@ pub struct EventSubscription {
 
 /// Event statistics for monitoring
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EventStatistics {
     pub total_events_processed: u64,
     pub events_by_type: HashMap<String, u64>,
@@ -552,4 +1098,196 @@ impl Default for EventStatistics {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::RefCell;
+    use alloc::rc::Rc;
+    use soroban_sdk::testutils::Address as _;
+
+    struct PagedSource {
+        events: Vec<RpcEvent>,
+        latest_ledger: u64,
+    }
+
+    impl EventSource for PagedSource {
+        fn get_events(&self, start_ledger: u64, cursor: Option<&str>, limit: u32) -> ContractResult<EventPage> {
+            let offset = match cursor {
+                Some(cursor) => cursor.parse::<usize>().unwrap(),
+                None => self.events.iter().position(|e| e.ledger >= start_ledger).unwrap_or(self.events.len()),
+            };
+            let end = (offset + limit as usize).min(self.events.len());
+            Ok(EventPage {
+                events: self.events[offset..end].to_vec(),
+                cursor: Some(end.to_string()),
+                latest_ledger: self.latest_ledger,
+            })
+        }
+    }
+
+    #[test]
+    fn test_backfill_resumes_and_deduplicates() {
+        let env = Env::default();
+        let contract = Address::generate(&env);
+        let events = (0..5u64)
+            .map(|i| RpcEvent {
+                id: format!("{:019}-0000000001", 100 + i),
+                ledger: 100 + i,
+                ledger_closed_at: 1_000 + i,
+                contract_address: contract.clone(),
+                topics: alloc::vec![String::from("custom")],
+                data: Vec::new(),
+                tx_hash: String::new(),
+            })
+            .collect();
+        let source = PagedSource { events, latest_ledger: 110 };
+
+        let seen = Rc::new(RefCell::new(0u32));
+        let counter = seen.clone();
+        let mut monitor = EventMonitor::new(env);
+        monitor
+            .subscribe(String::from("indexer"), EventFilter::new(), move |_| {
+                *counter.borrow_mut() += 1;
+                Ok(())
+            })
+            .unwrap();
+
+        // One page, then resume from the returned checkpoint
+        let checkpoint = monitor.backfill(&source, BackfillCheckpoint::new(100, 103), 2, 1).unwrap();
+        assert!(!checkpoint.complete);
+        assert_eq!(checkpoint.last_ledger, 101);
+
+        let checkpoint = monitor.backfill(&source, checkpoint, 2, 0).unwrap();
+        assert!(checkpoint.complete);
+        assert_eq!(checkpoint.events_processed, 4);
+        assert_eq!(*seen.borrow(), 4);
+
+        // Re-running an overlapping range skips processed events
+        let rerun = monitor.backfill(&source, BackfillCheckpoint::new(102, 104), 10, 0).unwrap();
+        assert_eq!(rerun.events_processed, 1);
+        assert!(monitor.is_event_processed(&format!("{:019}-0000000001", 104)));
+        assert_eq!(*seen.borrow(), 5);
+    }
+
+    #[test]
+    fn test_dedup_window_is_bounded() {
+        let env = Env::default();
+        let contract = Address::generate(&env);
+        let events: Vec<RpcEvent> = (0..6u64)
+            .map(|i| RpcEvent {
+                id: format!("{:019}-0000000000", (100 + i) << 32),
+                ledger: 100 + i,
+                ledger_closed_at: 0,
+                contract_address: contract.clone(),
+                topics: alloc::vec![String::from("custom")],
+                data: Vec::new(),
+                tx_hash: String::new(),
+            })
+            .collect();
+        let ids: Vec<String> = events.iter().map(|event| event.id.clone()).collect();
+        let source = PagedSource { events, latest_ledger: 110 };
+
+        let mut monitor = EventMonitor::new(env).with_dedup_capacity(2);
+        let checkpoint = monitor.backfill(&source, BackfillCheckpoint::new(100, 105), 10, 0).unwrap();
+        assert_eq!(checkpoint.events_processed, 6);
+        assert_eq!(monitor.processed_event_ids.len(), 2);
+
+        // Evicted IDs stay deduplicated through the low-water mark
+        assert!(ids.iter().all(|id| monitor.is_event_processed(id)));
+        let rerun = monitor.backfill(&source, BackfillCheckpoint::new(100, 105), 10, 0).unwrap();
+        assert_eq!(rerun.events_processed, 0);
+        assert!(!monitor.is_event_processed(&format!("{:019}-0000000000", 106u64 << 32)));
+        assert!(monitor.mark_events_processed(alloc::vec![String::from("not-an-id")]).is_err());
+    }
+
+    #[test]
+    fn test_checkpoint_commits_only_after_success() {
+        let env = Env::default();
+        let contract = Address::generate(&env);
+        let event = |ledger: u64| {
+            let raw = RpcEvent {
+                id: format!("{:019}-0000000000", ledger << 32),
+                ledger,
+                ledger_closed_at: 0,
+                contract_address: contract.clone(),
+                topics: alloc::vec![String::from("custom")],
+                data: Vec::new(),
+                tx_hash: format!("tx{}", ledger),
+            };
+            let position = EventPosition::from_rpc_event(&raw).unwrap();
+            let parsed = ContractEvent {
+                contract_address: raw.contract_address,
+                event_type: String::from("custom"),
+                topics: raw.topics,
+                data: EventData::Generic { data: HashMap::new() },
+                timestamp: 0,
+                block_number: ledger,
+                transaction_hash: raw.tx_hash,
+            };
+            (position, parsed)
+        };
+
+        // The handler fails on ledger 3 the first time it sees it
+        let attempts = Rc::new(RefCell::new(Vec::new()));
+        let recorded = attempts.clone();
+        let mut monitor = EventMonitor::new(env.clone());
+        monitor
+            .subscribe(String::from("ledger"), EventFilter::new(), move |event| {
+                let mut seen = recorded.borrow_mut();
+                seen.push(event.block_number);
+                if event.block_number == 3 && seen.iter().filter(|l| **l == 3).count() == 1 {
+                    return Err(ContractError::Timeout(String::from("db")));
+                }
+                Ok(())
+            })
+            .unwrap();
+
+        let mut store = InMemoryCheckpointStore::new();
+        let batch = alloc::vec![event(1), event(2), event(3), event(4)];
+        assert!(monitor.process_events_with_checkpoint(&mut store, "ledger", batch.clone()).is_err());
+        assert_eq!(store.load("ledger").unwrap().unwrap().position.ledger, 2);
+
+        // Replaying the whole batch redelivers only the uncommitted events
+        assert_eq!(monitor.process_events_with_checkpoint(&mut store, "ledger", batch).unwrap(), 2);
+        assert_eq!(*attempts.borrow(), alloc::vec![1, 2, 3, 3, 4]);
+        assert_eq!(store.load("ledger").unwrap().unwrap().transaction_hash, String::from("tx4"));
+    }
+
+    #[test]
+    fn test_assemble_trace_orders_lineage_depth_first() {
+        let env = Env::default();
+        let contract = Address::generate(&env);
+        let event = |id: u8, parent: Option<u8>, block_number: u64| {
+            let mut topics = alloc::vec![String::from("event"), String::from("ContractCall"), hex::encode([id; 32])];
+            topics.extend(parent.map(|parent| hex::encode([parent; 32])));
+            ContractEvent {
+                contract_address: contract.clone(),
+                event_type: String::from("ContractCall"),
+                topics,
+                data: EventData::Generic { data: HashMap::new() },
+                timestamp: 0,
+                block_number,
+                transaction_hash: String::new(),
+            }
+        };
+
+        // Delivered out of order, with an event from an unrelated workflow and an orphan
+        let events = alloc::vec![
+            event(4, Some(2), 12),
+            event(3, Some(1), 11),
+            event(2, Some(1), 10),
+            event(1, None, 13),
+            event(8, Some(9), 10),
+            event(5, Some(6), 10),
+        ];
+        assert_eq!(events[0].parent_correlation_id(), Some([2; 32]));
+        let trace = assemble_trace([1; 32], &events);
+
+        let order: Vec<(u8, u32)> = trace.nodes.iter().map(|node| (node.correlation_id[0], node.depth)).collect();
+        assert_eq!(order, alloc::vec![(1, 0), (2, 1), (4, 2), (3, 1)]);
+        assert_eq!(trace.nodes[0].parent_correlation_id, None);
+        assert_eq!(trace.nodes[2].parent_correlation_id, Some([2; 32]));
+        assert!(assemble_trace([7; 32], &events).nodes.is_empty());
+    }
+}
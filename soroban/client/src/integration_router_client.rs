@@ -1,6 +1,12 @@
-use soroban_sdk::{Address, Env, BytesN, String as SorobanString};
+use soroban_sdk::{contracttype, Address, Env, BytesN, IntoVal, String as SorobanString, Symbol, TryFromVal, Val};
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use crate::{ContractClient, ContractResult, ContractError, OperationContext};
+use crate::access_control::UserRole;
+use crate::contract_manager::ComponentHealth;
+use crate::event_monitor::OperationTrace;
+use crate::kyc_registry_client::ComplianceOutcome;
+use crate::address_config::{FromParamValue, ParamValue, ParameterRegistry, ParameterSchema};
 
 /// Client interface for the Integration Router contract
 /// 
@@ -21,6 +27,20 @@ impl IntegrationRouterClient {
         }
     }
 
+    /// Call a router function and decode its result
+    fn query<T: TryFromVal<Env, Val>>(&self, function: &str, args: soroban_sdk::Vec<Val>) -> ContractResult<T> {
+        match self.env.try_invoke_contract::<T, shared::IntegrationError>(
+            &self.contract_address,
+            &Symbol::new(&self.env, function),
+            args,
+        ) {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(_)) => Err(ContractError::ParseError(alloc::format!("unexpected {} result", function))),
+            Err(Ok(err)) => Err(ContractError::Integration(err)),
+            Err(Err(_)) => Err(ContractError::NetworkError(alloc::format!("{} call failed", function))),
+        }
+    }
+
     /// Execute a Bitcoin deposit operation
     /// 
     /// # Arguments
@@ -112,6 +132,411 @@ impl IntegrationRouterClient {
         Ok(withdrawal_id)
     }
 
+    /// Execute a token withdrawal carrying a travel-rule payload hash
+    /// 
+    /// The encrypted originator/beneficiary payload is delivered off-chain;
+    /// only its hash is recorded on-chain against the withdrawal.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `user` - User address withdrawing tokens
+    /// * `istsi_amount` - Amount of iSTSi tokens to burn
+    /// * `btc_address` - Bitcoin address for withdrawal
+    /// * `travel_rule_hash` - Hash of the encrypted travel-rule payload
+    /// 
+    /// # Returns
+    /// * `Ok(withdrawal_id)` - Unique withdrawal ID for tracking
+    /// * `Err(ContractError)` - Error details
+    pub fn execute_travel_rule_withdrawal(
+        &self,
+        ctx: &OperationContext,
+        user: &Address,
+        istsi_amount: u64,
+        btc_address: &str,
+        travel_rule_hash: &BytesN<32>,
+    ) -> ContractResult<BytesN<32>> {
+        let withdrawal_id = self.execute_token_withdrawal(ctx, user, istsi_amount, btc_address)?;
+        
+        // In a real implementation, the hash would be passed to the contract call
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("trv_rule"), withdrawal_id.clone()),
+            travel_rule_hash.clone()
+        );
+        
+        Ok(withdrawal_id)
+    }
+
+    /// Execute a Bitcoin deposit under the operator's nonce channel
+    /// 
+    /// `nonce` must exceed the last nonce the router accepted from
+    /// `ctx.caller`; resubmitting a call with the same nonce is rejected
+    /// instead of minting twice.
+    pub fn execute_bitcoin_deposit_nonced(
+        &self,
+        ctx: &OperationContext,
+        nonce: u64,
+        user: &Address,
+        btc_amount: u64,
+        btc_tx_hash: &BytesN<32>,
+        confirmations: u32,
+    ) -> ContractResult<BytesN<32>> {
+        self.require_fresh_nonce(&ctx.caller, nonce)?;
+        self.query(
+            "execute_bitcoin_deposit_nonced",
+            soroban_sdk::vec![
+                &self.env,
+                ctx.caller.into_val(&self.env),
+                nonce.into_val(&self.env),
+                user.into_val(&self.env),
+                btc_amount.into_val(&self.env),
+                btc_tx_hash.into_val(&self.env),
+                confirmations.into_val(&self.env),
+            ],
+        )
+    }
+
+    /// Execute a token withdrawal under the operator's nonce channel
+    pub fn execute_token_withdrawal_nonced(
+        &self,
+        ctx: &OperationContext,
+        nonce: u64,
+        user: &Address,
+        istsi_amount: u64,
+        btc_address: &str,
+    ) -> ContractResult<BytesN<32>> {
+        self.require_fresh_nonce(&ctx.caller, nonce)?;
+        self.query(
+            "execute_token_withdrawal_nonced",
+            soroban_sdk::vec![
+                &self.env,
+                ctx.caller.into_val(&self.env),
+                nonce.into_val(&self.env),
+                user.into_val(&self.env),
+                istsi_amount.into_val(&self.env),
+                SorobanString::from_str(&self.env, btc_address).into_val(&self.env),
+            ],
+        )
+    }
+
+    /// Get the last nonce the router accepted from an operator (0 before any)
+    pub fn get_operator_nonce(&self, operator: &Address) -> ContractResult<u64> {
+        self.query("get_operator_nonce", soroban_sdk::vec![&self.env, operator.into_val(&self.env)])
+    }
+
+    fn require_fresh_nonce(&self, operator: &Address, nonce: u64) -> ContractResult<()> {
+        if nonce <= self.get_operator_nonce(operator)? {
+            return Err(ContractError::Integration(shared::IntegrationError::DuplicateOperation));
+        }
+        Ok(())
+    }
+
+    /// Register an unconfirmed (0-conf) deposit so the user can follow it before it confirms
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an Operator)
+    /// * `user` - User the deposit credits
+    /// * `btc_amount` - Amount in satoshis
+    /// * `btc_tx_hash` - Unconfirmed Bitcoin transaction
+    /// 
+    /// # Returns
+    /// * `Ok(status)` - The `Pending` deposit status
+    /// * `Err(ContractError)` - Error details
+    pub fn pre_register_deposit(
+        &self,
+        ctx: &OperationContext,
+        user: &Address,
+        btc_amount: u64,
+        btc_tx_hash: &BytesN<32>,
+    ) -> ContractResult<DepositStatus> {
+        if btc_amount == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidAmount
+            ));
+        }
+
+        let status: RouterDepositStatus = self.query(
+            "pre_register_deposit",
+            soroban_sdk::vec![
+                &self.env,
+                ctx.caller.into_val(&self.env),
+                user.into_val(&self.env),
+                btc_amount.into_val(&self.env),
+                btc_tx_hash.into_val(&self.env),
+            ],
+        )?;
+        Ok(status.into())
+    }
+
+    /// Record new confirmations for a deposit awaiting confirmation
+    /// 
+    /// Each rising count emits a `dep_conf` progression event. Once the count
+    /// meets the user's confirmation requirement the router runs the deposit
+    /// through the tracked deposit workflow without a resubmission.
+    pub fn update_deposit_confirmations(
+        &self,
+        ctx: &OperationContext,
+        btc_tx_hash: &BytesN<32>,
+        confirmations: u32,
+    ) -> ContractResult<DepositStatus> {
+        let status: RouterDepositStatus = self.query(
+            "update_deposit_confirmations",
+            soroban_sdk::vec![
+                &self.env,
+                ctx.caller.into_val(&self.env),
+                btc_tx_hash.into_val(&self.env),
+                confirmations.into_val(&self.env),
+            ],
+        )?;
+        Ok(status.into())
+    }
+
+    /// Expire pre-registered deposits that never confirmed, returning how many expired
+    pub fn expire_pre_registered_deposits(&self, ctx: &OperationContext) -> ContractResult<u32> {
+        self.query(
+            "expire_pre_registered_deposits",
+            soroban_sdk::vec![&self.env, ctx.caller.into_val(&self.env)],
+        )
+    }
+
+    /// Get the pre-registration of an unconfirmed deposit
+    pub fn get_deposit_pre_registration(&self, btc_tx_hash: &BytesN<32>) -> ContractResult<Option<DepositPreRegistration>> {
+        let registration: Option<RouterDepositPreRegistration> = self.query(
+            "get_deposit_pre_registration",
+            soroban_sdk::vec![&self.env, btc_tx_hash.into_val(&self.env)],
+        )?;
+        Ok(registration.map(DepositPreRegistration::from))
+    }
+
+    /// Get deposits awaiting confirmations, oldest first
+    pub fn get_pre_registered_deposits(&self) -> ContractResult<Vec<DepositPreRegistration>> {
+        let registrations: soroban_sdk::Vec<RouterDepositPreRegistration> =
+            self.query("get_pre_registered_deposits", soroban_sdk::vec![&self.env])?;
+        Ok(registrations.iter().map(DepositPreRegistration::from).collect())
+    }
+
+    /// Get the processing status of a deposit by its Bitcoin transaction
+    pub fn get_deposit_status_by_tx_hash(&self, btc_tx_hash: &BytesN<32>) -> ContractResult<Option<DepositStatus>> {
+        let status: Option<RouterDepositStatus> = self.query(
+            "get_deposit_status_by_tx_hash",
+            soroban_sdk::vec![&self.env, btc_tx_hash.into_val(&self.env)],
+        )?;
+        Ok(status.map(DepositStatus::from))
+    }
+
+    /// Configure the second-approval threshold for large withdrawals (system admin only)
+    pub fn set_withdrawal_approval_config(&self, ctx: &OperationContext, config: &WithdrawalApprovalConfig) -> ContractResult<()> {
+        if config.threshold_sats > 0 && config.approval_window == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        self.query(
+            "set_withdrawal_approval_config",
+            soroban_sdk::vec![
+                &self.env,
+                ctx.caller.into_val(&self.env),
+                RouterWithdrawalApprovalConfig::from(config.clone()).into_val(&self.env),
+            ],
+        )
+    }
+
+    /// Get the second-approval policy
+    /// 
+    /// # Returns
+    /// * `Ok(None)` - Withdrawals complete without a second approval
+    pub fn get_withdrawal_approval_config(&self) -> ContractResult<Option<WithdrawalApprovalConfig>> {
+        let config: Option<RouterWithdrawalApprovalConfig> =
+            self.query("get_withdrawal_approval_config", soroban_sdk::vec![&self.env])?;
+        Ok(config.map(WithdrawalApprovalConfig::from))
+    }
+
+    /// Approve a withdrawal held for a second approval and complete it
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a withdrawal approver other than the initiator)
+    /// * `withdrawal_id` - Withdrawal in `ApprovalPending`
+    /// 
+    /// # Returns
+    /// * `Ok(())` - The withdrawal was approved and paid out
+    /// * `Err(ContractError)` - Unknown withdrawal, self-approval or a closed window
+    pub fn approve_withdrawal(&self, ctx: &OperationContext, withdrawal_id: &BytesN<32>) -> ContractResult<()> {
+        self.query(
+            "approve_withdrawal",
+            soroban_sdk::vec![&self.env, ctx.caller.into_val(&self.env), withdrawal_id.into_val(&self.env)],
+        )
+    }
+
+    /// Cancel held withdrawals whose approval window has closed, re-minting their tokens
+    /// 
+    /// # Returns
+    /// * `Ok(count)` - Number of withdrawals expired
+    pub fn expire_withdrawal_approvals(&self, ctx: &OperationContext, max_items: u32) -> ContractResult<u32> {
+        self.query(
+            "expire_withdrawal_approvals",
+            soroban_sdk::vec![&self.env, ctx.caller.into_val(&self.env), max_items.into_val(&self.env)],
+        )
+    }
+
+    /// Get the approval record of a withdrawal held for a second approval
+    pub fn get_withdrawal_approval(&self, withdrawal_id: &BytesN<32>) -> ContractResult<Option<WithdrawalApproval>> {
+        let approval: Option<RouterWithdrawalApproval> = self.query(
+            "get_withdrawal_approval",
+            soroban_sdk::vec![&self.env, withdrawal_id.into_val(&self.env)],
+        )?;
+        Ok(approval.map(WithdrawalApproval::from))
+    }
+
+    /// Get all withdrawals awaiting a second approval, oldest first
+    pub fn get_pending_withdrawal_approvals(&self) -> ContractResult<Vec<WithdrawalApproval>> {
+        let approvals: soroban_sdk::Vec<RouterWithdrawalApproval> =
+            self.query("get_pending_withdrawal_approvals", soroban_sdk::vec![&self.env])?;
+        Ok(approvals.iter().map(WithdrawalApproval::from).collect())
+    }
+
+    /// Request a withdrawal from the user's own wallet
+    /// 
+    /// Authorized by the user (`ctx.caller`), who needs no role. An operator
+    /// fulfills the request through `fulfill_withdrawal_request`.
+    /// 
+    /// # Returns
+    /// * `Ok(request_id)` - ID for `get_withdrawal_request`
+    /// * `Err(ContractError)` - Zero amount, empty address or too many open requests
+    pub fn user_request_withdrawal(&self, ctx: &OperationContext, istsi_amount: u64, btc_address: &str) -> ContractResult<BytesN<32>> {
+        if istsi_amount == 0 || btc_address.is_empty() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(BytesN::from_array(&self.env, &[0u8; 32]))
+    }
+
+    /// Request a withdrawal from the user's own wallet at a chosen fee tier
+    /// 
+    /// The tier's network fee comes out of the payout. Use
+    /// `quote_withdrawal_fee` to show the user the net amount first.
+    /// 
+    /// # Returns
+    /// * `Ok(request_id)` - ID for `get_withdrawal_request`
+    /// * `Err(ContractError)` - Invalid request, or a fee that would consume the payout
+    pub fn user_request_withdrawal_with_fee(
+        &self,
+        ctx: &OperationContext,
+        istsi_amount: u64,
+        btc_address: &str,
+        fee_tier: BtcFeeTier,
+    ) -> ContractResult<BytesN<32>> {
+        let quote = self.quote_withdrawal_fee(istsi_amount, fee_tier)?;
+        if quote.network_fee_sats > 0 && quote.net_btc_amount == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidAmount
+            ));
+        }
+
+        self.user_request_withdrawal(ctx, istsi_amount, btc_address)
+    }
+
+    /// Quote the network fee and net payout of a withdrawal at a fee tier
+    /// 
+    /// # Returns
+    /// * `Ok(quote)` - Fee rate, estimated fee and net amount, flagged stale when rates are old
+    pub fn quote_withdrawal_fee(&self, istsi_amount: u64, fee_tier: BtcFeeTier) -> ContractResult<WithdrawalFeeQuote> {
+        let btc_amount = self.get_conversion_config()?.istsi_to_sats(istsi_amount);
+        let now = self.env.ledger().timestamp();
+        let (fee_rate, rates_updated_at, stale) = match self.get_btc_fee_rates()? {
+            Some(rates) => (rates.rate(fee_tier), rates.updated_at, now.saturating_sub(rates.updated_at) > BTC_FEE_RATE_MAX_AGE),
+            None => (0, 0, true),
+        };
+        let network_fee_sats = fee_rate.saturating_mul(WITHDRAWAL_TX_VBYTES);
+
+        Ok(WithdrawalFeeQuote {
+            tier: fee_tier,
+            fee_rate,
+            estimated_vbytes: WITHDRAWAL_TX_VBYTES,
+            network_fee_sats,
+            btc_amount,
+            net_btc_amount: btc_amount.saturating_sub(network_fee_sats),
+            rates_updated_at,
+            stale,
+        })
+    }
+
+    /// Get the latest published Bitcoin fee rates
+    pub fn get_btc_fee_rates(&self) -> ContractResult<Option<BtcFeeRates>> {
+        let rates: Option<RouterBtcFeeRates> = self.query("get_btc_fee_rates", soroban_sdk::vec![&self.env])?;
+        Ok(rates.map(BtcFeeRates::from))
+    }
+
+    /// Publish Bitcoin fee rates per tier, in sat/vB (operators or the fee oracle)
+    pub fn submit_btc_fee_rates(&self, ctx: &OperationContext, economy: u64, standard: u64, priority: u64) -> ContractResult<BtcFeeRates> {
+        if economy == 0 || economy > standard || standard > priority {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        let rates: RouterBtcFeeRates = self.query(
+            "submit_btc_fee_rates",
+            soroban_sdk::vec![
+                &self.env,
+                ctx.caller.into_val(&self.env),
+                economy.into_val(&self.env),
+                standard.into_val(&self.env),
+                priority.into_val(&self.env),
+            ],
+        )?;
+        Ok(rates.into())
+    }
+
+    /// Allow an oracle to publish Bitcoin fee rates alongside operators (system admin only)
+    pub fn set_btc_fee_oracle(&self, ctx: &OperationContext, oracle: &Address) -> ContractResult<()> {
+        self.query(
+            "set_btc_fee_oracle",
+            soroban_sdk::vec![&self.env, ctx.caller.into_val(&self.env), oracle.into_val(&self.env)],
+        )
+    }
+
+    /// Cancel one of the caller's own pending withdrawal requests
+    pub fn cancel_withdrawal_request(&self, ctx: &OperationContext, request_id: &BytesN<32>) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Fulfill a pending user withdrawal request (operator only)
+    /// 
+    /// # Returns
+    /// * `Ok(withdrawal_id)` - Withdrawal started for the request
+    pub fn fulfill_withdrawal_request(&self, ctx: &OperationContext, request_id: &BytesN<32>) -> ContractResult<BytesN<32>> {
+        // In a real implementation, this would call the contract
+        Ok(BytesN::from_array(&self.env, &[0u8; 32]))
+    }
+
+    /// Reject a pending user withdrawal request with a reason (operator only)
+    pub fn reject_withdrawal_request(&self, ctx: &OperationContext, request_id: &BytesN<32>, reason: &str) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get a user withdrawal request by ID
+    pub fn get_withdrawal_request(&self, request_id: &BytesN<32>) -> ContractResult<Option<UserWithdrawalRequest>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get a page of a user's withdrawal requests, oldest first
+    pub fn get_user_withdrawal_requests(&self, user: &Address, cursor: u32, limit: u32) -> ContractResult<Vec<UserWithdrawalRequest>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Get all user withdrawal requests awaiting an operator, oldest first
+    pub fn get_pending_withdrawal_requests(&self) -> ContractResult<Vec<UserWithdrawalRequest>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
     /// Execute a cross-token exchange operation
     /// 
     /// # Arguments
@@ -160,6 +585,36 @@ impl IntegrationRouterClient {
         Ok((operation_id, to_amount))
     }
 
+    /// Emit an integration event correlated with the caller's trace
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context; its trace ID seeds the correlation ID
+    /// * `event_type` - Integration event type
+    /// * `user` - User the event refers to
+    /// * `amount` - Primary amount recorded with the event
+    /// 
+    /// # Returns
+    /// * `Ok(correlation_id)` - Correlation ID assigned to the event
+    /// * `Err(ContractError)` - Error details
+    pub fn emit_integration_event(
+        &self,
+        ctx: &OperationContext,
+        event_type: &str,
+        user: &Address,
+        amount: u64,
+    ) -> ContractResult<BytesN<32>> {
+        // In a real implementation, the router derives the ID from the seed
+        let mut correlation_id = self.generate_operation_id(event_type, amount).to_array();
+        correlation_id[16..32].copy_from_slice(&ctx.correlation_seed(&self.env).to_array()[16..32]);
+        let correlation_id = BytesN::from_array(&self.env, &correlation_id);
+
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("event"), SorobanString::from_str(&self.env, event_type), correlation_id.clone()),
+            (user.clone(), amount)
+        );
+        Ok(correlation_id)
+    }
+
     /// Get operation status
     /// 
     /// # Arguments
@@ -180,50 +635,2096 @@ impl IntegrationRouterClient {
         Ok(false)
     }
 
-    /// Get router configuration
-    pub fn get_config(&self) -> ContractResult<RouterConfig> {
+    /// Get the role in effect for an address (`User` when none is assigned)
+    /// 
+    /// An unexpired temporary grant takes precedence over the assigned role.
+    pub fn get_user_role(&self, address: &Address) -> ContractResult<UserRole> {
         // In a real implementation, this would query the contract
-        Ok(RouterConfig {
-            kyc_registry: self.contract_address.clone(),
-            istsi_token: self.contract_address.clone(),
-            fungible_token: self.contract_address.clone(),
-            reserve_manager: self.contract_address.clone(),
-            admin: self.contract_address.clone(),
-            paused: false,
+        Ok(UserRole::User)
+    }
+
+    /// Grant a user a role until `expires_at`
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a super admin)
+    /// * `user` - Address receiving the grant; a previous grant is replaced
+    /// * `role` - Role in effect until expiry (not `SuperAdmin` or `User`)
+    /// * `expires_at` - Timestamp the grant stops applying, at most a week ahead
+    pub fn grant_temporary_role(
+        &self,
+        ctx: &OperationContext,
+        user: &Address,
+        role: UserRole,
+        expires_at: u64,
+    ) -> ContractResult<()> {
+        let now = self.env.ledger().timestamp();
+        if matches!(role, UserRole::SuperAdmin | UserRole::User)
+            || expires_at <= now
+            || expires_at - now > MAX_ROLE_GRANT_SECONDS
+        {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// End a user's temporary role grant early
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a super admin)
+    /// * `user` - Address holding the grant
+    pub fn revoke_temporary_role(&self, ctx: &OperationContext, user: &Address) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Remove expired temporary role grants, recording each expiry in the audit log
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// 
+    /// # Returns
+    /// * `Ok(count)` - Grants removed
+    pub fn expire_role_grants(&self, ctx: &OperationContext) -> ContractResult<u32> {
+        // In a real implementation, this would call the contract
+        Ok(0)
+    }
+
+    /// Get a user's temporary role grant, including an expired one not yet removed
+    pub fn get_role_grant(&self, user: &Address) -> ContractResult<Option<RoleGrant>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// List temporary role grants still in effect
+    pub fn get_active_role_grants(&self) -> ContractResult<Vec<RoleGrant>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Get the system-wide and per-workflow pause flags
+    pub fn get_pause_state(&self) -> ContractResult<PauseState> {
+        // In a real implementation, this would query the contract
+        Ok(PauseState {
+            system_paused: self.is_paused()?,
+            ..PauseState::default()
         })
     }
 
-    /// Emergency pause the router (admin only)
-    pub fn emergency_pause(&self, ctx: &OperationContext, reason: &str) -> ContractResult<()> {
+    /// Pause a single workflow (admin/compliance officer only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `workflow` - Workflow to pause
+    /// * `reason` - Reason recorded with the pause event
+    pub fn pause_workflow(&self, ctx: &OperationContext, workflow: PauseWorkflow, reason: &str) -> ContractResult<()> {
         // In a real implementation, this would call the contract
         self.env.events().publish(
-            (soroban_sdk::symbol_short!("emergency"), soroban_sdk::symbol_short!("pause")),
-            SorobanString::from_str(&self.env, reason)
+            (soroban_sdk::symbol_short!("wf_pause"), ctx.caller.clone()),
+            (workflow as u32, SorobanString::from_str(&self.env, reason))
         );
         Ok(())
     }
 
-    /// Resume operations (admin only)
-    pub fn resume_operations(&self, ctx: &OperationContext) -> ContractResult<()> {
+    /// Resume a paused workflow (admin only)
+    pub fn resume_workflow(&self, ctx: &OperationContext, workflow: PauseWorkflow) -> ContractResult<()> {
         // In a real implementation, this would call the contract
         self.env.events().publish(
-            (soroban_sdk::symbol_short!("resume"), soroban_sdk::symbol_short!("ops")),
-            self.env.ledger().timestamp()
+            (soroban_sdk::symbol_short!("wf_resume"), ctx.caller.clone()),
+            workflow as u32
         );
         Ok(())
     }
 
-    /// Helper function to generate operation IDs
-    fn generate_operation_id(&self, operation_type: &str, amount: u64) -> BytesN<32> {
-        let timestamp = self.env.ledger().timestamp();
-        let sequence = self.env.ledger().sequence();
-        
-        let mut id_bytes = [0u8; 32];
-        id_bytes[0..8].copy_from_slice(&timestamp.to_be_bytes());
-        id_bytes[8..12].copy_from_slice(&sequence.to_be_bytes());
-        id_bytes[12..20].copy_from_slice(&amount.to_be_bytes());
-        
-        // Add operation type hash
+    /// Announce a maintenance window (system admin only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `start` - Window start; the current time enters maintenance immediately
+    /// * `end` - Window end, after which maintenance exits automatically
+    /// * `allowed_workflows` - Workflows that keep running during maintenance
+    /// * `message` - Announcement shown to users
+    pub fn schedule_maintenance(
+        &self,
+        ctx: &OperationContext,
+        start: u64,
+        end: u64,
+        allowed_workflows: &[PauseWorkflow],
+        message: &str,
+    ) -> ContractResult<()> {
+        if start >= end || end <= self.env.ledger().timestamp() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("mnt_sched"), ctx.caller.clone()),
+            (start, end, SorobanString::from_str(&self.env, message))
+        );
+        Ok(())
+    }
+
+    /// End maintenance early or cancel a scheduled window (system admin only)
+    pub fn end_maintenance(&self, ctx: &OperationContext) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Apply due maintenance transitions; returns whether maintenance is active
+    pub fn update_maintenance_status(&self) -> ContractResult<bool> {
+        // In a real implementation, this would call the contract
+        Ok(false)
+    }
+
+    /// Get the scheduled or active maintenance window
+    pub fn get_maintenance_window(&self) -> ContractResult<Option<MaintenanceWindow>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get router configuration
+    pub fn get_config(&self) -> ContractResult<RouterConfig> {
+        // In a real implementation, this would query the contract
+        Ok(RouterConfig {
+            kyc_registry: self.contract_address.clone(),
+            istsi_token: self.contract_address.clone(),
+            fungible_token: self.contract_address.clone(),
+            reserve_manager: self.contract_address.clone(),
+            admin: self.contract_address.clone(),
+            paused: false,
+        })
+    }
+
+    /// Set a system parameter (super admin only)
+    /// 
+    /// Values of parameters declared in `registry` are validated locally
+    /// before submission.
+    pub fn set_system_parameter(
+        &self,
+        ctx: &OperationContext,
+        registry: &ParameterRegistry,
+        name: &str,
+        value: &str,
+    ) -> ContractResult<()> {
+        if name.is_empty() || registry.validate(name, value).is_err() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("sys_param"), SorobanString::from_str(&self.env, name)),
+            SorobanString::from_str(&self.env, value)
+        );
+        Ok(())
+    }
+
+    /// Declare a typed system parameter (super admin only)
+    pub fn declare_parameter(&self, ctx: &OperationContext, schema: &ParameterSchema) -> ContractResult<()> {
+        if schema.check().is_err() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get all declared parameter schemas
+    pub fn list_parameter_schemas(&self) -> ContractResult<Vec<ParameterSchema>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Get a declared parameter's typed value
+    /// 
+    /// # Returns
+    /// * `Ok(None)` - The parameter is unset, undeclared or of another type
+    pub fn get_typed_parameter<T: FromParamValue>(&self, name: &str) -> ContractResult<Option<T>> {
+        // In a real implementation, this would query the contract
+        let value: Option<ParamValue> = None;
+        Ok(value.and_then(T::from_param_value))
+    }
+
+    /// Snapshot the router configuration into a new backup (super admin only)
+    /// 
+    /// # Returns
+    /// * `Ok(backup_id)` - ID of the stored backup
+    /// * `Err(ContractError)` - Error details
+    pub fn create_configuration_backup(&self, ctx: &OperationContext) -> ContractResult<BytesN<32>> {
+        // In a real implementation, this would call the contract
+        Ok(self.generate_operation_id("config_backup", 0))
+    }
+
+    /// Restore a configuration backup (super admin only)
+    /// 
+    /// The router saves the current configuration as a "pre_restore" backup
+    /// before restoring.
+    /// 
+    /// # Returns
+    /// * `Ok(false)` - The backup does not exist or has an unsupported version
+    pub fn restore_configuration_backup(&self, ctx: &OperationContext, backup_id: &BytesN<32>) -> ContractResult<bool> {
+        // In a real implementation, this would call the contract
+        Ok(true)
+    }
+
+    /// List retained configuration backup IDs, oldest first
+    pub fn list_configuration_backups(&self) -> ContractResult<Vec<BytesN<32>>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Names of the configuration sections that differ from a backup
+    pub fn diff_configuration_backup(&self, backup_id: &BytesN<32>) -> ContractResult<Vec<String>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Attest the Wasm hash a contract instance was deployed from (super admin only)
+    pub fn register_deployed_wasm(&self, ctx: &OperationContext, contract_address: &Address, wasm_hash: &BytesN<32>) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Register the functions a Wasm build exports (super admin only)
+    pub fn register_wasm_exports(&self, ctx: &OperationContext, wasm_hash: &BytesN<32>, functions: &[String]) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Declare the functions the router calls on a contract (super admin only)
+    ///
+    /// An empty manifest disables the interface check for the contract.
+    pub fn set_interface_manifest(&self, ctx: &OperationContext, contract_name: &str, functions: &[String]) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Run the compatibility checks for a planned upgrade without executing it
+    pub fn check_upgrade_compatibility(&self, upgrade_id: &BytesN<32>) -> ContractResult<CompatibilityCheck> {
+        // In a real implementation, this would query the contract
+        Ok(CompatibilityCheck {
+            compatible: true,
+            error_message: String::new(),
+            required_migrations: Vec::new(),
+        })
+    }
+
+    /// Append a state migration step to a planned upgrade (super admin only)
+    ///
+    /// # Arguments
+    /// * `function` - Contract function taking `(cursor, limit)` and returning the items migrated
+    /// * `batch_size` - Items migrated per call
+    ///
+    /// # Returns
+    /// * `Ok(count)` - Number of steps registered for the upgrade
+    pub fn register_migration_step(
+        &self,
+        ctx: &OperationContext,
+        upgrade_id: &BytesN<32>,
+        function: &str,
+        description: &str,
+        batch_size: u32,
+    ) -> ContractResult<u32> {
+        if batch_size == 0 || function.is_empty() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(1)
+    }
+
+    /// Run up to `max_batches` migration batches from the last checkpoint (operator)
+    pub fn run_migration(&self, ctx: &OperationContext, upgrade_id: &BytesN<32>, max_batches: u32) -> ContractResult<MigrationState> {
+        // In a real implementation, this would call the contract
+        self.get_migration_state(upgrade_id)?
+            .ok_or(ContractError::Integration(shared::IntegrationError::InvalidOperationState))
+    }
+
+    /// Get the migration checkpoint of an upgrade
+    pub fn get_migration_state(&self, upgrade_id: &BytesN<32>) -> ContractResult<Option<MigrationState>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Start a staged rollout of a planned upgrade (super admin only)
+    ///
+    /// # Arguments
+    /// * `percentage` - Share of user buckets routed to the new address (1-99)
+    /// * `max_error_bps` - New contract error rate that rolls the rollout back
+    /// * `min_calls` - Calls on the new contract in a stage before the error rate is evaluated
+    pub fn start_canary_rollout(
+        &self,
+        ctx: &OperationContext,
+        upgrade_id: &BytesN<32>,
+        percentage: u32,
+        max_error_bps: u32,
+        min_calls: u32,
+    ) -> ContractResult<()> {
+        if percentage == 0 || percentage >= 100 || max_error_bps > 10_000 || min_calls == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Route a larger share of traffic to the new contract (super admin only)
+    ///
+    /// Promoting to 100% completes the upgrade and switches the registry.
+    pub fn promote_canary_rollout(&self, ctx: &OperationContext, contract_name: &str, percentage: u32) -> ContractResult<()> {
+        if percentage == 0 || percentage > 100 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Abort an active rollout and send all traffic back to the old contract (super admin only)
+    pub fn rollback_canary_rollout(&self, ctx: &OperationContext, contract_name: &str) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Report the outcome of a user operation on a canaried contract (operator)
+    ///
+    /// Failed workflows revert the router's own counters, so monitors report
+    /// them here for the automatic rollback check.
+    pub fn report_canary_outcome(&self, ctx: &OperationContext, contract_name: &str, user: &Address, success: bool) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get the current or last rollout of a contract
+    pub fn get_canary_rollout(&self, contract_name: &str) -> ContractResult<Option<CanaryRollout>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get per-stage call metrics of a contract's current or last rollout
+    pub fn get_canary_stage_metrics(&self, contract_name: &str) -> ContractResult<Vec<RolloutStageMetrics>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Emergency pause the router (admin only)
+    pub fn emergency_pause(&self, ctx: &OperationContext, reason: &str) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("emergency"), soroban_sdk::symbol_short!("pause")),
+            SorobanString::from_str(&self.env, reason)
+        );
+        Ok(())
+    }
+
+    /// Resume operations (admin only)
+    pub fn resume_operations(&self, ctx: &OperationContext) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("resume"), soroban_sdk::symbol_short!("ops")),
+            self.env.ledger().timestamp()
+        );
+        Ok(())
+    }
+
+    /// Get an operator's usage of the current rate limit window
+    /// 
+    /// # Arguments
+    /// * `operator` - Operator key to inspect
+    /// 
+    /// # Returns
+    /// * `Ok(status)` - Window bounds and per-operation counts
+    /// * `Err(ContractError)` - Error details
+    pub fn get_rate_limit_status(&self, operator: &Address) -> ContractResult<RateLimitStatus> {
+        self.query(
+            "get_rate_limit_status",
+            soroban_sdk::vec![&self.env, operator.into_val(&self.env)],
+        )
+    }
+
+    /// Configure the reserve ratio floor enforced on mints and reserve withdrawals (system admin only)
+    pub fn set_reserve_guardrails(&self, ctx: &OperationContext, guardrails: &ReserveGuardrails) -> ContractResult<()> {
+        if guardrails.min_ratio_bps == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        self.query(
+            "set_reserve_guardrails",
+            soroban_sdk::vec![
+                &self.env,
+                ctx.caller.into_val(&self.env),
+                RouterReserveGuardrails::from(guardrails.clone()).into_val(&self.env),
+            ],
+        )
+    }
+
+    /// Get the reserve ratio guardrails
+    /// 
+    /// # Returns
+    /// * `Ok(None)` - No floor is configured and mints are not re-checked
+    pub fn get_reserve_guardrails(&self) -> ContractResult<Option<ReserveGuardrails>> {
+        let guardrails: Option<RouterReserveGuardrails> =
+            self.query("get_reserve_guardrails", soroban_sdk::vec![&self.env])?;
+        Ok(guardrails.map(ReserveGuardrails::from))
+    }
+
+    /// Check an operation against the reserve guardrails (operator only)
+    /// 
+    /// A workflow blocked by the guardrails reverts and loses its block log.
+    /// Running this check before submitting records blocks and near misses.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// * `operation` - Label recorded with the log, e.g. `"bitcoin_deposit"`
+    /// * `btc_in` - Satoshis the operation adds to reserves
+    /// * `btc_out` - Satoshis the operation removes from reserves
+    /// 
+    /// # Returns
+    /// * `Ok(check)` - Projected ratio and whether the operation would pass
+    /// * `Err(ContractError)` - Error details
+    pub fn check_reserve_guardrails(
+        &self,
+        ctx: &OperationContext,
+        operation: &str,
+        btc_in: u64,
+        btc_out: u64,
+    ) -> ContractResult<GuardrailCheck> {
+        let check: RouterGuardrailCheck = self.query(
+            "check_reserve_guardrails",
+            soroban_sdk::vec![
+                &self.env,
+                ctx.caller.into_val(&self.env),
+                SorobanString::from_str(&self.env, operation).into_val(&self.env),
+                btc_in.into_val(&self.env),
+                btc_out.into_val(&self.env),
+            ],
+        )?;
+        Ok(check.into())
+    }
+
+    /// Get the active satoshi/iSTSi conversion config
+    /// 
+    /// Clients should convert amounts through this config rather than assume
+    /// a fixed ratio.
+    pub fn get_conversion_config(&self) -> ContractResult<ConversionConfig> {
+        let config: RouterConversionConfig = self.query("get_conversion_config", soroban_sdk::vec![&self.env])?;
+        Ok(config.into())
+    }
+
+    /// Get the conversion config proposal awaiting its timelock
+    pub fn get_pending_conversion_config(&self) -> ContractResult<Option<PendingConversionConfig>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Propose a new conversion config (super admin only)
+    /// 
+    /// # Returns
+    /// * `Ok(pending)` - The proposal and the time it can be applied
+    /// * `Err(ContractError)` - Error details
+    pub fn propose_conversion_config(&self, ctx: &OperationContext, config: &ConversionConfig) -> ContractResult<PendingConversionConfig> {
+        if config.istsi_per_satoshi == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        let proposed_at = self.env.ledger().timestamp();
+        Ok(PendingConversionConfig {
+            config: config.clone(),
+            proposed_by: ctx.caller.clone(),
+            proposed_at,
+            effective_at: proposed_at + 172_800,
+        })
+    }
+
+    /// Apply the pending conversion config once its timelock has elapsed (super admin only)
+    pub fn apply_conversion_config(&self, ctx: &OperationContext) -> ContractResult<ConversionConfig> {
+        // In a real implementation, this would call the contract
+        match self.get_pending_conversion_config()? {
+            Some(pending) if pending.is_ready(self.env.ledger().timestamp()) => Ok(pending.config),
+            _ => Err(ContractError::Integration(shared::IntegrationError::InvalidOperationState)),
+        }
+    }
+
+    /// Withdraw the pending conversion config proposal (super admin only)
+    pub fn cancel_conversion_config(&self, ctx: &OperationContext) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get the per-workflow operation minimums
+    pub fn get_amount_minimums(&self) -> ContractResult<AmountMinimums> {
+        // In a real implementation, this would query the contract
+        Ok(AmountMinimums::default())
+    }
+
+    /// Set the per-workflow operation minimums (system admin only)
+    pub fn set_amount_minimums(&self, ctx: &OperationContext, minimums: &AmountMinimums) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Record a sub-minimum Bitcoin deposit for later consolidation (operator only)
+    /// 
+    /// # Returns
+    /// * `Ok(account)` - The user's dust account after the deposit
+    /// * `Err(ContractError)` - Error details
+    pub fn record_dust_deposit(
+        &self,
+        ctx: &OperationContext,
+        user: &Address,
+        btc_amount: u64,
+        btc_tx_hash: &BytesN<32>,
+        confirmations: u32,
+    ) -> ContractResult<DustAccount> {
+        if self.get_amount_minimums()?.meets_minimum(PauseWorkflow::Deposits, btc_amount) {
+            return Err(ContractError::Integration(shared::IntegrationError::InvalidOperationState));
+        }
+
+        // In a real implementation, this would call the contract
+        let mut account = self.get_dust_account(user)?;
+        account.pending_sats = account.pending_sats.saturating_add(btc_amount);
+        account.residual_count += 1;
+        account.updated_at = self.env.ledger().timestamp();
+        Ok(account)
+    }
+
+    /// Mint a user's accumulated dust through a consolidation transaction (operator only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// * `user` - User whose dust is consolidated
+    /// * `btc_tx_hash` - Bitcoin transaction sweeping the dust outputs
+    /// * `confirmations` - Number of Bitcoin confirmations
+    /// 
+    /// # Returns
+    /// * `Ok(operation_id)` - Operation ID of the consolidated deposit
+    /// * `Err(ContractError)` - Error details
+    pub fn consolidate_dust(
+        &self,
+        ctx: &OperationContext,
+        user: &Address,
+        btc_tx_hash: &BytesN<32>,
+        confirmations: u32,
+    ) -> ContractResult<BytesN<32>> {
+        let account = self.get_dust_account(user)?;
+        if !account.can_consolidate(&self.get_amount_minimums()?) {
+            return Err(ContractError::Integration(shared::IntegrationError::DustAmount));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(self.generate_operation_id("dust_consolidation", account.pending_sats))
+    }
+
+    /// Get a user's dust account
+    pub fn get_dust_account(&self, user: &Address) -> ContractResult<DustAccount> {
+        // In a real implementation, this would query the contract
+        Ok(DustAccount {
+            user: user.clone(),
+            pending_sats: 0,
+            residual_count: 0,
+            consolidated_sats: 0,
+            updated_at: 0,
+        })
+    }
+
+    /// Configure fee contributions and automatic shortfall coverage (system admin only)
+    pub fn set_insurance_config(&self, ctx: &OperationContext, config: &InsuranceConfig) -> ContractResult<()> {
+        if config.contribution_bps > 10_000 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get the insurance fund configuration
+    /// 
+    /// # Returns
+    /// * `Ok(None)` - Fees are not diverted and shortfalls are not auto-covered
+    pub fn get_insurance_config(&self) -> ContractResult<Option<InsuranceConfig>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get the insurance fund balance for a token
+    pub fn get_insurance_fund(&self, token: &Address) -> ContractResult<InsuranceFund> {
+        // In a real implementation, this would query the contract
+        Ok(InsuranceFund {
+            token: token.clone(),
+            balance: 0,
+            total_contributed: 0,
+            total_drawn: 0,
+            covered_shortfall_sats: 0,
+            last_draw_at: 0,
+        })
+    }
+
+    /// Get the health of the iSTSi insurance fund, as shown on the admin dashboard
+    pub fn get_insurance_fund_health(&self) -> ContractResult<InsuranceFundHealth> {
+        // In a real implementation, this would query the contract
+        let target_balance = self.get_insurance_config()?.map(|config| config.target_balance).unwrap_or(0);
+        Ok(InsuranceFundHealth {
+            balance: 0,
+            target_balance,
+            coverage_capacity_sats: 0,
+            covered_shortfall_sats: 0,
+            total_drawn: 0,
+            status: InsuranceFundHealth::status_for(0, target_balance),
+        })
+    }
+
+    /// Propose a timelocked insurance fund top-up or withdrawal (super admin only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a super admin)
+    /// * `kind` - Whether `counterparty` pays into or is paid from the fund
+    /// * `token` - Fund token
+    /// * `amount` - Amount to transfer
+    /// * `counterparty` - Payer of a top-up or recipient of a withdrawal
+    /// 
+    /// # Returns
+    /// * `Ok(pending)` - The proposal and the time it can be executed
+    /// * `Err(ContractError)` - Error details
+    pub fn propose_insurance_transfer(
+        &self,
+        ctx: &OperationContext,
+        kind: InsuranceTransferKind,
+        token: &Address,
+        amount: u64,
+        counterparty: &Address,
+    ) -> ContractResult<PendingInsuranceTransfer> {
+        if amount == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidAmount
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        let proposed_at = self.env.ledger().timestamp();
+        Ok(PendingInsuranceTransfer {
+            kind,
+            token: token.clone(),
+            amount,
+            counterparty: counterparty.clone(),
+            proposed_by: ctx.caller.clone(),
+            proposed_at,
+            effective_at: proposed_at + 172_800,
+        })
+    }
+
+    /// Execute the pending insurance fund transfer once its timelock has elapsed (super admin only)
+    pub fn execute_insurance_transfer(&self, ctx: &OperationContext) -> ContractResult<InsuranceFund> {
+        // In a real implementation, this would call the contract
+        match self.get_pending_insurance_transfer()? {
+            Some(pending) if pending.is_ready(self.env.ledger().timestamp()) => self.get_insurance_fund(&pending.token),
+            _ => Err(ContractError::Integration(shared::IntegrationError::InvalidOperationState)),
+        }
+    }
+
+    /// Withdraw the pending insurance fund transfer (super admin only)
+    pub fn cancel_insurance_transfer(&self, ctx: &OperationContext) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get the insurance fund transfer awaiting its timelock
+    pub fn get_pending_insurance_transfer(&self) -> ContractResult<Option<PendingInsuranceTransfer>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Dry-run a Bitcoin deposit without changing router state
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context; its caller is checked as the operator
+    /// * `user` - User address that would receive the tokens
+    /// * `btc_amount` - Amount of Bitcoin deposited (in satoshis)
+    /// * `btc_tx_hash` - Bitcoin transaction hash
+    /// * `confirmations` - Number of Bitcoin confirmations
+    /// 
+    /// # Returns
+    /// * `Ok(report)` - Outcome of each check and the projected iSTSi amount
+    /// * `Err(ContractError)` - Error details
+    pub fn simulate_bitcoin_deposit(
+        &self,
+        ctx: &OperationContext,
+        user: &Address,
+        btc_amount: u64,
+        btc_tx_hash: &BytesN<32>,
+        confirmations: u32,
+    ) -> ContractResult<SimulationReport> {
+        // In a real implementation, this would query the contract
+        let paused = self.get_pause_state()?.is_workflow_paused(PauseWorkflow::Deposits);
+        let conversion = self.get_conversion_config()?;
+        let meets_minimum = self.get_amount_minimums()?.meets_minimum(PauseWorkflow::Deposits, btc_amount);
+        let steps = alloc::vec![
+            SimulationStep::check("paused", !paused, "Workflow is paused"),
+            SimulationStep::check("minimum", meets_minimum, "Amount below the workflow minimum"),
+            SimulationStep::check("btc_tx", btc_amount > 0 && confirmations >= 3, "Insufficient Bitcoin confirmations"),
+        ];
+
+        Ok(SimulationReport::new("bitcoin_deposit", steps, btc_amount, 0, conversion.sats_to_istsi(btc_amount), 0))
+    }
+
+    /// Dry-run a token withdrawal without changing router state
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context; its caller is checked as the operator
+    /// * `user` - User address withdrawing tokens
+    /// * `istsi_amount` - Amount of iSTSi tokens to burn
+    /// * `btc_address` - Bitcoin address for withdrawal
+    /// * `travel_rule_hash` - Travel-rule payload hash, if one will be supplied
+    /// 
+    /// # Returns
+    /// * `Ok(report)` - Outcome of each check, the fee and the projected BTC amount
+    /// * `Err(ContractError)` - Error details
+    pub fn simulate_token_withdrawal(
+        &self,
+        ctx: &OperationContext,
+        user: &Address,
+        istsi_amount: u64,
+        btc_address: &str,
+        travel_rule_hash: Option<&BytesN<32>>,
+    ) -> ContractResult<SimulationReport> {
+        // In a real implementation, this would query the contract
+        let paused = self.get_pause_state()?.is_workflow_paused(PauseWorkflow::Withdrawals);
+        let conversion = self.get_conversion_config()?;
+        let btc_amount = conversion.istsi_to_sats(istsi_amount);
+        let meets_minimum = self.get_amount_minimums()?.meets_minimum(PauseWorkflow::Withdrawals, conversion.istsi_value_sats(istsi_amount));
+        let steps = alloc::vec![
+            SimulationStep::check("paused", !paused, "Workflow is paused"),
+            SimulationStep::check("minimum", meets_minimum, "Amount below the workflow minimum"),
+            SimulationStep::check("btc_address", !btc_address.is_empty(), "Bitcoin address is empty"),
+            SimulationStep::check("dust", conversion.is_above_dust(btc_amount), "Withdrawal payout below the dust limit"),
+        ];
+
+        Ok(SimulationReport::new("token_withdrawal", steps, istsi_amount, 0, btc_amount, 0))
+    }
+
+    /// Dry-run a cross-token exchange without changing router state
+    /// 
+    /// # Arguments
+    /// * `user` - User address executing the exchange
+    /// * `from_token` - Source token contract address
+    /// * `to_token` - Destination token contract address
+    /// * `from_amount` - Amount of source tokens to exchange
+    /// * `max_slippage_bps` - Maximum accepted slippage in basis points
+    /// 
+    /// # Returns
+    /// * `Ok(report)` - Outcome of each check, the fee and the projected amount received
+    /// * `Err(ContractError)` - Error details
+    pub fn simulate_exchange(
+        &self,
+        user: &Address,
+        from_token: &Address,
+        to_token: &Address,
+        from_amount: u64,
+        max_slippage_bps: u64,
+    ) -> ContractResult<SimulationReport> {
+        // In a real implementation, this would query the contract
+        let paused = self.get_pause_state()?.is_workflow_paused(PauseWorkflow::Exchanges);
+        let meets_minimum = self.get_amount_minimums()?.meets_minimum(PauseWorkflow::Exchanges, from_amount);
+        let steps = alloc::vec![
+            SimulationStep::check("paused", !paused, "Exchanges are paused"),
+            SimulationStep::check("minimum", meets_minimum, "Amount below the workflow minimum"),
+            SimulationStep::check("pair", from_token != to_token, "Tokens must differ"),
+        ];
+
+        // Simplified 1:1 rate, as in execute_cross_token_exchange
+        Ok(SimulationReport::new("cross_token_exchange", steps, from_amount, 0, from_amount, 10_000))
+    }
+
+    /// Get a user's exchange limits, headroom and reset countdowns for a token pair
+    /// 
+    /// Swaps are rejected before quoting while the status is `frozen`,
+    /// `pair_disabled` or `limit_reached`.
+    pub fn get_exchange_compliance_status(
+        &self,
+        user: &Address,
+        from_token: &Address,
+        to_token: &Address,
+    ) -> ContractResult<ExchangeComplianceStatus> {
+        if from_token == to_token {
+            return Err(ContractError::Validation(shared::ValidationError::InvalidParameters));
+        }
+
+        // In a real implementation, this would query the contract
+        Ok(ExchangeComplianceStatus {
+            user: user.clone(),
+            kyc_tier: 1,
+            daily_limit: 1_000_000,
+            monthly_limit: 10_000_000,
+            daily_used: 0,
+            monthly_used: 0,
+            daily_remaining: 1_000_000,
+            monthly_remaining: 10_000_000,
+            enhanced_verification_limit: 500_000,
+            daily_reset_in_seconds: 86_400,
+            monthly_reset_in_seconds: 30 * 86_400,
+            compliance_status: "basic".to_string(),
+        })
+    }
+
+    /// Sweep pending operations whose timeout has passed
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// * `max_items` - Maximum number of operations to time out in this call
+    /// 
+    /// # Returns
+    /// * `Ok(count)` - Number of operations marked as timed out
+    /// * `Err(ContractError)` - Error details
+    pub fn sweep_timed_out_operations(&self, ctx: &OperationContext, max_items: u32) -> ContractResult<u32> {
+        if max_items == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("sweep"), ctx.caller.clone()),
+            (max_items, self.env.ledger().timestamp())
+        );
+        Ok(0)
+    }
+
+    /// Extend the TTL of long-lived router entries (callable by any keeper)
+    /// 
+    /// The router instance and its operation lists are extended on every call.
+    /// 
+    /// # Arguments
+    /// * `keys` - Entries to extend, at most 100
+    /// 
+    /// # Returns
+    /// * `Ok(count)` - Number of listed entries that still existed and were extended
+    /// * `Err(ContractError)` - Error details
+    pub fn extend_ttl_batch(&self, keys: &[TtlKey]) -> ContractResult<u32> {
+        if keys.len() > 100 {
+            return Err(ContractError::Integration(shared::IntegrationError::InvalidOperationState));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(keys.len() as u32)
+    }
+
+    /// Compact completed operations into daily archive records (system admin only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `older_than` - Only operations last updated before this timestamp are compacted
+    /// * `max_items` - Maximum number of operations to compact in this call
+    /// 
+    /// # Returns
+    /// * `Ok(count)` - Number of operations folded into archive records
+    /// * `Err(ContractError)` - Error details
+    pub fn compact_completed_operations(&self, ctx: &OperationContext, older_than: u64, max_items: u32) -> ContractResult<u32> {
+        // In a real implementation, this would call the contract
+        Ok(0)
+    }
+
+    /// Get the archive record for the period starting at `period_start`
+    pub fn get_operation_archive(&self, period_start: u64) -> ContractResult<Option<OperationArchive>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get the start of every period with an archive record, oldest first
+    pub fn get_archived_periods(&self) -> ContractResult<Vec<u64>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Get up to `limit` (max 100) operation IDs from a status list, oldest first
+    /// 
+    /// # Arguments
+    /// * `list` - Status list to read
+    /// * `start_day` / `start_slot` - Cursor from the previous page, or (0, 0) for the first page
+    /// * `limit` - Maximum number of IDs to return
+    pub fn get_operation_list_page(&self, list: OperationList, start_day: u64, start_slot: u32, limit: u32) -> ContractResult<OperationListPage> {
+        // In a real implementation, this would query the contract
+        Ok(OperationListPage { operation_ids: Vec::new(), has_more: false, next_day: 0, next_slot: 0 })
+    }
+
+    /// Get the number of operations in a status list
+    pub fn get_operation_list_count(&self, list: OperationList) -> ContractResult<u32> {
+        // In a real implementation, this would query the contract
+        Ok(0)
+    }
+
+    /// Get the root correlation ID of an operation's workflow, if it recorded a lineage
+    pub fn get_operation_lineage(&self, operation_id: &BytesN<32>) -> ContractResult<Option<BytesN<32>>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get the ordered tree of events and contract calls for one workflow
+    /// 
+    /// Use [`crate::event_monitor::assemble_trace`] to build the same tree from
+    /// events already observed off-chain.
+    pub fn get_operation_trace(&self, operation_id: &BytesN<32>) -> ContractResult<Option<OperationTrace>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get the newest proof of reserves the router has verified
+    /// 
+    /// The endpoint is public. Check the result with
+    /// [`crate::proof_verifier::ProofVerifier`] rather than trusting the status field.
+    pub fn get_latest_verified_proof(&self) -> ContractResult<Option<StoredProofOfReserves>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Trigger automatic reconciliation if it is enabled and due
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// 
+    /// # Returns
+    /// * `Ok(true)` - A reconciliation check was executed
+    /// * `Ok(false)` - Auto-reconciliation is disabled or not yet due
+    /// * `Err(ContractError)` - Error details
+    pub fn trigger_auto_reconciliation(&self, ctx: &OperationContext) -> ContractResult<bool> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("keeper"), soroban_sdk::symbol_short!("recon")),
+            (ctx.caller.clone(), self.env.ledger().timestamp())
+        );
+        Ok(true)
+    }
+
+    /// Trigger scheduled proof-of-reserves generation if it is enabled and due
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// 
+    /// # Returns
+    /// * `Ok(true)` - A proof was generated
+    /// * `Ok(false)` - Proof generation is disabled or not yet due
+    /// * `Err(ContractError)` - Error details
+    pub fn trigger_scheduled_proof_gen(&self, ctx: &OperationContext) -> ContractResult<bool> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("keeper"), soroban_sdk::symbol_short!("proof")),
+            (ctx.caller.clone(), self.env.ledger().timestamp())
+        );
+        Ok(true)
+    }
+
+    /// Reset elapsed daily and monthly deposit, withdrawal and exchange limit windows
+    /// 
+    /// The router sweeps up to 50 users per call, round-robin, so repeated
+    /// runs cover every user with a limit record.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// 
+    /// # Returns
+    /// * `Ok(count)` - Number of user limit records reset
+    /// * `Err(ContractError)` - Error details
+    pub fn reset_expired_limits(&self, ctx: &OperationContext) -> ContractResult<u32> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("keeper"), soroban_sdk::symbol_short!("limits")),
+            (ctx.caller.clone(), self.env.ledger().timestamp())
+        );
+        Ok(0)
+    }
+
+    /// Set a user's deposit limits (system admin only); the edit is audited
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `user` - User whose limits change
+    /// * `daily_limit` - Satoshis per day
+    /// * `monthly_limit` - Satoshis per 30 days, at least `daily_limit`
+    pub fn set_deposit_limits(&self, ctx: &OperationContext, user: &Address, daily_limit: u64, monthly_limit: u64) -> ContractResult<()> {
+        if daily_limit > monthly_limit {
+            return Err(ContractError::Validation(shared::ValidationError::InvalidParameters));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Set a user's withdrawal limits (system admin only); the edit is audited
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `user` - User whose limits change
+    /// * `daily_limit` - iSTSi per day
+    /// * `monthly_limit` - iSTSi per 30 days, at least `daily_limit`
+    /// * `enhanced_verification_limit` - Amount above which enhanced verification applies
+    pub fn set_withdrawal_limits(
+        &self,
+        ctx: &OperationContext,
+        user: &Address,
+        daily_limit: u64,
+        monthly_limit: u64,
+        enhanced_verification_limit: u64,
+    ) -> ContractResult<()> {
+        if daily_limit > monthly_limit {
+            return Err(ContractError::Validation(shared::ValidationError::InvalidParameters));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Drop a user's deposit or withdrawal override so their KYC tier policy applies again
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a system admin)
+    /// * `user` - User whose override is cleared
+    /// * `kind` - `Deposit` or `Withdrawal`; exchange limits have no override
+    pub fn clear_limit_override(
+        &self,
+        ctx: &OperationContext,
+        user: &Address,
+        kind: LimitKind,
+    ) -> ContractResult<()> {
+        if kind == LimitKind::Exchange {
+            return Err(ContractError::Validation(shared::ValidationError::InvalidParameters));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get a page of a user's limit changes, oldest first
+    pub fn get_limit_changes(&self, user: &Address, cursor: u32, limit: u32) -> ContractResult<Vec<LimitChange>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Freeze an address across deposit, withdrawal, exchange and transfer paths
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a compliance officer or admin)
+    /// * `address` - Address to freeze
+    /// * `reason` - Reason stored with the freeze
+    /// * `duration_seconds` - Automatic expiry (0 = until explicitly unfrozen)
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Address frozen
+    /// * `Err(ContractError)` - Error details
+    pub fn freeze_address(
+        &self,
+        ctx: &OperationContext,
+        address: &Address,
+        reason: &str,
+        duration_seconds: u64,
+    ) -> ContractResult<()> {
+        if reason.is_empty() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("freeze"), address.clone()),
+            (SorobanString::from_str(&self.env, reason), duration_seconds, ctx.caller.clone())
+        );
+        Ok(())
+    }
+
+    /// Lift an address freeze before it expires
+    pub fn unfreeze_address(&self, ctx: &OperationContext, address: &Address) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("unfreeze"), address.clone()),
+            ctx.caller.clone()
+        );
+        Ok(())
+    }
+
+    /// Get the active freeze for an address, if any
+    pub fn get_freeze_status(&self, address: &Address) -> ContractResult<Option<FreezeRecord>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Check whether an address is currently frozen
+    pub fn is_address_frozen(&self, address: &Address) -> ContractResult<bool> {
+        let now = self.env.ledger().timestamp();
+        Ok(self.get_freeze_status(address)?.map_or(false, |record| record.is_active(now)))
+    }
+
+    /// Propose seizing iSTSi from a sanctioned account
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a ComplianceOfficer)
+    /// * `target` - Account to seize from
+    /// * `amount` - iSTSi units to seize
+    /// * `evidence_hash` - Hash of the supporting evidence
+    /// * `reason` - Reason recorded with the proposal
+    /// 
+    /// # Returns
+    /// * `Ok(clawback_id)` - ID of the proposal awaiting SuperAdmin approval
+    /// * `Err(ContractError)` - Error details
+    pub fn propose_clawback(
+        &self,
+        ctx: &OperationContext,
+        target: &Address,
+        amount: u64,
+        evidence_hash: &BytesN<32>,
+        reason: &str,
+    ) -> ContractResult<BytesN<32>> {
+        if amount == 0 || reason.is_empty() || evidence_hash.to_array() == [0u8; 32] {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(BytesN::from_array(&self.env, &[0u8; 32]))
+    }
+
+    /// Approve a clawback, seizing the tokens into the token's seizure escrow
+    /// 
+    /// The caller must be a SuperAdmin other than the proposing officer.
+    /// 
+    /// # Returns
+    /// * `Ok(token_clawback_id)` - Entry in the token's clawback audit trail
+    /// * `Err(ContractError)` - Error details
+    pub fn approve_clawback(&self, ctx: &OperationContext, clawback_id: &BytesN<32>) -> ContractResult<u64> {
+        // In a real implementation, this would call the contract
+        Ok(0)
+    }
+
+    /// Reject a proposed clawback (SuperAdmin only)
+    pub fn reject_clawback(&self, ctx: &OperationContext, clawback_id: &BytesN<32>) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get a clawback proposal
+    pub fn get_clawback_proposal(&self, clawback_id: &BytesN<32>) -> ContractResult<Option<ClawbackProposal>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// List users held by the velocity detector
+    pub fn get_operation_holds(&self) -> ContractResult<Vec<OperationHold>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Get IDs of clawbacks awaiting approval
+    pub fn get_pending_clawbacks(&self) -> ContractResult<Vec<BytesN<32>>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Page through clawback proposals and their outcomes, oldest first
+    pub fn get_clawback_history(&self, start: u32, limit: u32) -> ContractResult<Vec<ClawbackProposal>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Report a completed deposit whose Bitcoin transaction was reorged out
+    /// 
+    /// The router freezes the minted iSTSi, claws it back if the user still
+    /// holds it and otherwise escalates the case to compliance.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an Operator)
+    /// * `btc_tx_hash` - Bitcoin transaction of the completed deposit
+    /// * `evidence_hash` - Hash of the competing chain evidence
+    /// 
+    /// # Returns
+    /// * `Ok(case)` - The opened reorg case
+    /// * `Err(ContractError)` - Error details
+    pub fn report_deposit_reorg(
+        &self,
+        ctx: &OperationContext,
+        btc_tx_hash: &BytesN<32>,
+        evidence_hash: &BytesN<32>,
+    ) -> ContractResult<ReorgCase> {
+        if evidence_hash.to_array() == [0u8; 32] {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(ReorgCase {
+            btc_tx_hash: btc_tx_hash.clone(),
+            user: ctx.caller.clone(),
+            btc_amount: 0,
+            istsi_amount: 0,
+            evidence_hash: evidence_hash.clone(),
+            reported_by: ctx.caller.clone(),
+            reported_at: 0,
+            status: ReorgCaseStatus::Escalated,
+            token_clawback_id: 0,
+            alert_id: None,
+            resolved_by: None,
+            resolved_at: 0,
+            resolution: String::new(),
+        })
+    }
+
+    /// Resolve a reorg case, releasing anything it still has frozen (ComplianceOfficer only)
+    pub fn resolve_reorg_case(&self, ctx: &OperationContext, btc_tx_hash: &BytesN<32>, resolution: &str) -> ContractResult<()> {
+        if resolution.is_empty() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get the reorg case for a deposit
+    pub fn get_reorg_case(&self, btc_tx_hash: &BytesN<32>) -> ContractResult<Option<ReorgCase>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get reorg cases awaiting resolution, oldest first
+    pub fn get_open_reorg_cases(&self) -> ContractResult<Vec<ReorgCase>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Get a user's iSTSi frozen by open reorg cases
+    pub fn get_reorg_frozen_amount(&self, user: &Address) -> ContractResult<u64> {
+        // In a real implementation, this would query the contract
+        Ok(0)
+    }
+
+    /// Check many KYC approvals, serving repeats from the router's compliance cache
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an Operator)
+    /// * `requests` - List of (user, operation_code, amount) tuples
+    /// 
+    /// # Returns
+    /// * `Ok(outcomes)` - One outcome per request, in request order
+    /// * `Err(ContractError)` - Error details
+    pub fn verify_compliance_batch(
+        &self,
+        ctx: &OperationContext,
+        requests: &[(Address, u32, u64)],
+    ) -> ContractResult<Vec<ComplianceOutcome>> {
+        // In a real implementation, this would call the contract
+        Ok(requests
+            .iter()
+            .map(|(user, op_code, amount)| ComplianceOutcome {
+                user: user.clone(),
+                op_code: *op_code,
+                amount: *amount,
+                approved: true,
+                reason_code: 0,
+            })
+            .collect())
+    }
+
+    /// Replace the rule set for a jurisdiction and operation (SystemAdmin only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a SystemAdmin)
+    /// * `jurisdiction` - Jurisdiction code as recorded in the KYC registry
+    /// * `op_code` - Operation code (3 deposit, 4 withdrawal, 5 exchange)
+    /// * `bands` - Amount bands with strictly increasing `min_amount`; empty lifts all restrictions
+    /// 
+    /// # Returns
+    /// * `Ok(version)` - Version number of the new rule set
+    /// * `Err(ContractError)` - Error details
+    pub fn set_jurisdiction_rules(
+        &self,
+        ctx: &OperationContext,
+        jurisdiction: &str,
+        op_code: u32,
+        bands: &[JurisdictionBand],
+    ) -> ContractResult<u32> {
+        if jurisdiction.is_empty()
+            || op_code > 5
+            || bands.windows(2).any(|pair| pair[0].min_amount >= pair[1].min_amount)
+        {
+            return Err(ContractError::Validation(shared::ValidationError::InvalidParameters));
+        }
+        // In a real implementation, this would call the contract
+        Ok(1)
+    }
+
+    /// Get the active rule set for a jurisdiction and operation
+    pub fn get_jurisdiction_rules(&self, jurisdiction: &str, op_code: u32) -> ContractResult<Option<JurisdictionRuleSet>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get an archived version of a jurisdiction rule set
+    pub fn get_jurisdiction_rules_version(
+        &self,
+        jurisdiction: &str,
+        op_code: u32,
+        version: u32,
+    ) -> ContractResult<Option<JurisdictionRuleSet>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Evaluate the active rules for an amount without running an operation
+    pub fn evaluate_jurisdiction(&self, jurisdiction: &str, op_code: u32, amount: u64) -> ContractResult<JurisdictionOutcome> {
+        // In a real implementation, this would query the contract
+        Ok(JurisdictionOutcome::Allow)
+    }
+
+    /// Get an audit log entry by sequence number (starting at 1)
+    pub fn get_audit_entry(&self, sequence: u64) -> ContractResult<Option<AuditLogEntry>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get the length of the audit chain and the hash of its latest entry
+    pub fn get_audit_chain_head(&self) -> ContractResult<AuditChainHead> {
+        // In a real implementation, this would query the contract
+        Ok(AuditChainHead {
+            length: 0,
+            head_hash: BytesN::from_array(&self.env, &[0u8; 32]),
+        })
+    }
+
+    /// Get audit entries recorded between two timestamps (inclusive), oldest first
+    pub fn get_audit_log(&self, start_time: u64, end_time: u64, limit: u32) -> ContractResult<Vec<AuditLogEntry>> {
+        if start_time > end_time {
+            return Err(ContractError::Validation(shared::ValidationError::InvalidParameters));
+        }
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Page through the audit entries of one actor, oldest first
+    pub fn get_actor_audit_log(&self, actor: &Address, start: u32, limit: u32) -> ContractResult<Vec<AuditLogEntry>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Re-hash audit entries on-chain and return the first one that fails, if any
+    pub fn verify_audit_chain(&self, start_sequence: u64, limit: u32) -> ContractResult<Option<u64>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Deposit exchange inventory as a market maker
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a registered market maker)
+    /// * `token` - Exchange token being supplied
+    /// * `amount` - Amount transferred to the router
+    /// 
+    /// # Returns
+    /// * `Ok(position)` - Market maker's position after the deposit
+    /// * `Err(ContractError)` - Error details
+    pub fn deposit_liquidity(
+        &self,
+        ctx: &OperationContext,
+        token: &Address,
+        amount: u64,
+    ) -> ContractResult<LiquidityPosition> {
+        if amount == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidAmount
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("liq_dep"), ctx.caller.clone()),
+            (token.clone(), amount)
+        );
+        Ok(LiquidityPosition {
+            market_maker: ctx.caller.clone(),
+            token: token.clone(),
+            amount,
+            updated_at: self.env.ledger().timestamp(),
+        })
+    }
+
+    /// Withdraw exchange inventory, including proceeds credited from exchanges
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller is the market maker)
+    /// * `token` - Exchange token to withdraw
+    /// * `amount` - Amount paid out to the market maker
+    /// 
+    /// # Returns
+    /// * `Ok(position)` - Market maker's remaining position
+    /// * `Err(ContractError)` - Error details
+    pub fn withdraw_liquidity(
+        &self,
+        ctx: &OperationContext,
+        token: &Address,
+        amount: u64,
+    ) -> ContractResult<LiquidityPosition> {
+        if amount == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidAmount
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("liq_wd"), ctx.caller.clone()),
+            (token.clone(), amount)
+        );
+        Ok(LiquidityPosition {
+            market_maker: ctx.caller.clone(),
+            token: token.clone(),
+            amount: 0,
+            updated_at: self.env.ledger().timestamp(),
+        })
+    }
+
+    /// Get how much of a token exchanges can currently draw from inventory
+    pub fn get_inventory_depth(&self, token: &Address) -> ContractResult<InventoryDepth> {
+        // In a real implementation, this would query the contract
+        Ok(InventoryDepth {
+            token: token.clone(),
+            available: 0,
+            max_draw: 0,
+            provider_count: 0,
+        })
+    }
+
+    /// Register a tradable pair, or replace a registered pair's parameters
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a system admin)
+    /// * `token_a` - One of the router's exchange tokens
+    /// * `token_b` - The other exchange token
+    /// * `fee_rate` - Exchange fee in basis points for both directions
+    /// * `trade_size` - Smallest and largest accepted exchange or order amount
+    /// * `oracle_address` - Oracle bound to the pair
+    /// 
+    /// # Returns
+    /// * `Ok(config)` - The registered pair
+    /// * `Err(ContractError)` - Error details
+    pub fn register_token_pair(
+        &self,
+        ctx: &OperationContext,
+        token_a: &Address,
+        token_b: &Address,
+        fee_rate: u64,
+        trade_size: (u64, u64),
+        oracle_address: &Address,
+    ) -> ContractResult<TokenPairConfig> {
+        let (min_trade_size, max_trade_size) = trade_size;
+        if token_a == token_b || fee_rate >= 10_000 || max_trade_size == 0 || min_trade_size > max_trade_size {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        let now = self.env.ledger().timestamp();
+        Ok(TokenPairConfig {
+            token_a: token_a.clone(),
+            token_b: token_b.clone(),
+            fee_rate,
+            min_trade_size,
+            max_trade_size,
+            oracle_address: oracle_address.clone(),
+            enabled: true,
+            registered_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Enable or disable trading on a registered pair
+    pub fn set_token_pair_enabled(
+        &self,
+        ctx: &OperationContext,
+        token_a: &Address,
+        token_b: &Address,
+        enabled: bool,
+    ) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get a registered pair in either token order
+    pub fn get_token_pair(&self, token_a: &Address, token_b: &Address) -> ContractResult<Option<TokenPairConfig>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// List registered pairs, enabled or not, in registration order
+    pub fn list_pairs(&self) -> ContractResult<Vec<TokenPairConfig>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Place a resting limit order; the sell amount is escrowed by the router
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller is the order owner)
+    /// * `sell_token` - Token being sold
+    /// * `buy_token` - Token being bought
+    /// * `sell_amount` - Amount of `sell_token` to sell
+    /// * `limit_price` - Minimum `buy_token` per `sell_token` in basis points
+    /// * `expires_at` - Ledger timestamp after which the order is refunded
+    /// 
+    /// # Returns
+    /// * `Ok(order)` - The resting order
+    /// * `Err(ContractError)` - Error details
+    pub fn place_limit_order(
+        &self,
+        ctx: &OperationContext,
+        sell_token: &Address,
+        buy_token: &Address,
+        sell_amount: u64,
+        limit_price: u64,
+        expires_at: u64,
+    ) -> ContractResult<LimitOrder> {
+        if sell_amount == 0 || limit_price == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidAmount
+            ));
+        }
+
+        let now = self.env.ledger().timestamp();
+        if sell_token == buy_token || expires_at <= now {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        let order_id = self.generate_operation_id("limit_order", sell_amount);
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("ord_new"), order_id.clone()),
+            (ctx.caller.clone(), sell_amount, limit_price, expires_at)
+        );
+        Ok(LimitOrder {
+            order_id,
+            owner: ctx.caller.clone(),
+            sell_token: sell_token.clone(),
+            buy_token: buy_token.clone(),
+            sell_amount,
+            remaining: sell_amount,
+            limit_price,
+            received: 0,
+            status: OrderStatus::Open,
+            created_at: now,
+            updated_at: now,
+            expires_at,
+        })
+    }
+
+    /// Cancel a resting limit order and refund its unfilled remainder
+    pub fn cancel_limit_order(&self, ctx: &OperationContext, order_id: &BytesN<32>) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("ord_close"), order_id.clone()),
+            ctx.caller.clone()
+        );
+        Ok(())
+    }
+
+    /// Get an owner's resting limit orders in placement order
+    pub fn get_open_orders(&self, owner: &Address) -> ContractResult<Vec<LimitOrder>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Expire stale orders and match crossing orders on the book
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// * `max_fills` - Maximum number of fills to execute in this call
+    /// 
+    /// # Returns
+    /// * `Ok(count)` - Number of fills executed
+    /// * `Err(ContractError)` - Error details
+    pub fn match_limit_orders(&self, ctx: &OperationContext, max_fills: u32) -> ContractResult<u32> {
+        if max_fills == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("keeper"), soroban_sdk::symbol_short!("orders")),
+            (ctx.caller.clone(), self.env.ledger().timestamp())
+        );
+        Ok(0)
+    }
+
+    /// Snapshot system metrics if the router's snapshot interval has elapsed
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// 
+    /// # Returns
+    /// * `Ok(true)` - A snapshot was recorded
+    /// * `Ok(false)` - The interval has not elapsed yet
+    /// * `Err(ContractError)` - Error details
+    pub fn record_metrics_snapshot(&self, ctx: &OperationContext) -> ContractResult<bool> {
+        self.query(
+            "record_metrics_snapshot",
+            soroban_sdk::vec![&self.env, ctx.caller.into_val(&self.env)],
+        )
+    }
+
+    /// Set how many events of a type are retained and for how long (system admin only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `event_type` - Integration event type, e.g. "ComplianceAction"
+    /// * `policy` - Count limit (1-1000), age limit and importance tier
+    pub fn set_event_retention_policy(&self, ctx: &OperationContext, event_type: &str, policy: &EventRetentionPolicy) -> ContractResult<()> {
+        if policy.max_events == 0 || policy.max_events > 1000 || policy.max_age_seconds == 0 {
+            return Err(ContractError::Validation(shared::ValidationError::InvalidParameters));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get the retention policy applied to an event type
+    pub fn get_event_retention_policy(&self, event_type: &str) -> ContractResult<EventRetentionPolicy> {
+        // In a real implementation, this would query the contract
+        Ok(EventRetentionPolicy { max_events: 100, max_age_seconds: 7 * 86_400, importance: EventImportance::Standard })
+    }
+
+    /// Remove events of a type that are past its retention window
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// * `event_type` - Integration event type to prune
+    /// * `max_items` - Maximum number of events to remove in this call
+    /// 
+    /// # Returns
+    /// * `Ok(count)` - Number of events removed
+    /// * `Err(ContractError)` - Error details
+    pub fn prune_event_history(&self, ctx: &OperationContext, event_type: &str, max_items: u32) -> ContractResult<u32> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("keeper"), soroban_sdk::symbol_short!("evt_prune")),
+            (ctx.caller.clone(), max_items)
+        );
+        Ok(0)
+    }
+
+    /// Get event history retention counters
+    pub fn get_event_retention_metrics(&self) -> ContractResult<EventRetentionMetrics> {
+        // In a real implementation, this would query the contract
+        Ok(EventRetentionMetrics::default())
+    }
+
+    /// Get the number of events of a type currently retained
+    pub fn get_retained_event_count(&self, event_type: &str) -> ContractResult<u32> {
+        // In a real implementation, this would query the contract
+        Ok(0)
+    }
+
+    /// Get retained metrics snapshots for charting
+    /// 
+    /// # Arguments
+    /// * `from` - Earliest snapshot timestamp (inclusive)
+    /// * `to` - Latest snapshot timestamp (inclusive)
+    /// * `resolution` - Minimum seconds between returned points (0 = every snapshot)
+    ///
+    /// The router returns a capped page per call; this follows the pages
+    /// until the range is exhausted.
+    pub fn get_metrics_history(&self, from: u64, to: u64, resolution: u64) -> ContractResult<Vec<MetricsSnapshot>> {
+        if from > to {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        let mut history = Vec::new();
+        let mut page_from = from;
+        loop {
+            let page: soroban_sdk::Vec<RouterMetricsSnapshot> = self.query(
+                "get_metrics_history",
+                soroban_sdk::vec![
+                    &self.env,
+                    page_from.into_val(&self.env),
+                    to.into_val(&self.env),
+                    resolution.into_val(&self.env),
+                ],
+            )?;
+            let last = match page.last() {
+                Some(last) => last.metrics.last_updated,
+                None => break,
+            };
+            history.extend(page.iter().map(MetricsSnapshot::from));
+
+            // Resolution spacing carries across pages as it does within one
+            page_from = match last.checked_add(resolution.max(1)) {
+                Some(next) if next <= to => next,
+                _ => break,
+            };
+        }
+
+        Ok(history)
+    }
+
+    /// Get moving-average processing times per operation type
+    pub fn get_processing_times(&self) -> ContractResult<Vec<ProcessingTimeStats>> {
+        // In a real implementation, this would query the contract
+        Ok([
+            ProcessingOperation::Deposit,
+            ProcessingOperation::Withdrawal,
+            ProcessingOperation::Exchange,
+            ProcessingOperation::Batch,
+        ]
+        .into_iter()
+        .map(|operation| ProcessingTimeStats {
+            operation,
+            sample_count: 0,
+            average_ms: 0,
+            last_ms: 0,
+            max_ms: 0,
+            updated_at: 0,
+        })
+        .collect())
+    }
+
+    /// Get one operator's throughput, failure rate, handling time and SLA breaches
+    pub fn get_operator_stats(&self, operator: &Address, period: StatsPeriod) -> ContractResult<OperatorStats> {
+        // In a real implementation, this would query the contract
+        Ok(OperatorStats {
+            operator: operator.clone(),
+            period,
+            period_start: 0,
+            started: 0,
+            completed: 0,
+            failed: 0,
+            failure_rate: 0,
+            average_handling_seconds: 0,
+            sla_breaches: 0,
+        })
+    }
+
+    /// Get every operator's statistics, most completed operations first (system admin)
+    pub fn get_operator_scoreboard(&self, ctx: &OperationContext, period: StatsPeriod) -> ContractResult<Vec<OperatorStats>> {
+        // In a real implementation, this would call the contract
+        Ok(Vec::new())
+    }
+
+    /// Get recorded call outcomes for a contract behind the router
+    pub fn get_contract_call_stats(&self, address: &Address) -> ContractResult<ContractCallStats> {
+        let stats: RouterContractCallStats = self.query(
+            "get_contract_call_stats",
+            soroban_sdk::vec![&self.env, address.into_val(&self.env)],
+        )?;
+        Ok(stats.into())
+    }
+
+    /// Report a contract call that failed inside a reverted router transaction
+    ///
+    /// The router's own record of such a failure is reverted with the
+    /// workflow, so relayers report the failing contract and function read
+    /// from the transaction diagnostics.
+    ///
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// * `target` - Contract whose call failed
+    /// * `function_name` - Function that failed
+    /// * `error_message` - Error recorded in the contract's error history
+    pub fn report_contract_failure(
+        &self,
+        ctx: &OperationContext,
+        target: &Address,
+        function_name: &str,
+        error_message: &str,
+    ) -> ContractResult<()> {
+        self.query(
+            "report_contract_failure",
+            soroban_sdk::vec![
+                &self.env,
+                ctx.caller.into_val(&self.env),
+                target.into_val(&self.env),
+                SorobanString::from_str(&self.env, function_name).into_val(&self.env),
+                SorobanString::from_str(&self.env, error_message).into_val(&self.env),
+            ],
+        )
+    }
+
+    /// Sever the router's call paths to a contract
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a system admin)
+    /// * `contract` - Contract to isolate
+    /// * `scope` - Whether read-only calls still reach the contract
+    /// * `reason` - Reason recorded with the isolation
+    pub fn isolate_contract(
+        &self,
+        ctx: &OperationContext,
+        contract: &Address,
+        scope: IsolationScope,
+        reason: &str,
+    ) -> ContractResult<()> {
+        if reason.is_empty() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Put an isolated contract on read-only probation before re-integration
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a system admin)
+    /// * `contract` - Isolated contract
+    pub fn begin_reintegration(&self, ctx: &OperationContext, contract: &Address) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Re-integrate a contract whose probation passed health verification
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a super admin)
+    /// * `contract` - Contract on probation
+    /// 
+    /// # Returns
+    /// * `Err(ContractError)` - `InvalidOperationState` while probation runs,
+    ///   `ContractCallFailed` if the contract failed health verification
+    pub fn complete_reintegration(&self, ctx: &OperationContext, contract: &Address) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get a contract's isolation, if it is isolated or on probation
+    pub fn get_contract_isolation(&self, contract: &Address) -> ContractResult<Option<ContractIsolation>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// List contracts that are isolated or on probation
+    pub fn get_isolated_contracts(&self) -> ContractResult<Vec<ContractIsolation>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Configure the throttles reserve protection mode applies
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a system admin)
+    /// * `config` - Throttles used from the next activation
+    pub fn set_reserve_protection_config(
+        &self,
+        ctx: &OperationContext,
+        config: &ReserveProtectionConfig,
+    ) -> ContractResult<()> {
+        if config.withdrawal_limit_bps == 0
+            || config.withdrawal_limit_bps > 10_000
+            || config.approval_window == 0
+            || config.reconciliation_frequency == 0
+        {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get the reserve protection throttles
+    pub fn get_reserve_protection_config(&self) -> ContractResult<ReserveProtectionConfig> {
+        // In a real implementation, this would query the contract
+        Ok(ReserveProtectionConfig::default())
+    }
+
+    /// Get the current reserve protection mode, or the last one once lifted
+    pub fn get_protection_mode_state(&self) -> ContractResult<ProtectionModeState> {
+        // In a real implementation, this would query the contract
+        Ok(ProtectionModeState {
+            active: false,
+            response_id: BytesN::from_array(&self.env, &[0u8; 32]),
+            activated_by: None,
+            activated_at: 0,
+            reason: String::new(),
+            config: ReserveProtectionConfig::default(),
+            exchanges_paused: false,
+            lifted_at: 0,
+        })
+    }
+
+    /// Raise a system alert assigned to the router's on-call contact
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// * `alert_type` - Alert category, e.g. "reserve_discrepancy"
+    /// * `severity` - Initial severity
+    /// * `message` - Human-readable description
+    /// 
+    /// # Returns
+    /// * `Ok(alert_id)` - ID of the new alert
+    /// * `Err(ContractError)` - Error details
+    pub fn raise_alert(
+        &self,
+        ctx: &OperationContext,
+        alert_type: &str,
+        severity: AlertSeverity,
+        message: &str,
+    ) -> ContractResult<BytesN<32>> {
+        if alert_type.is_empty() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(self.generate_operation_id("alert", severity as u64))
+    }
+
+    /// Acknowledge an alert, stopping its escalation
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an escalation contact or admin)
+    /// * `alert_id` - Alert to acknowledge
+    pub fn acknowledge_alert(&self, ctx: &OperationContext, alert_id: &BytesN<32>) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Hold an alert's escalation until `until` (at most 7 days ahead)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an escalation contact or admin)
+    /// * `alert_id` - Alert to snooze
+    /// * `until` - Ledger timestamp at which escalation resumes
+    pub fn snooze_alert(&self, ctx: &OperationContext, alert_id: &BytesN<32>, until: u64) -> ContractResult<()> {
+        let now = self.env.ledger().timestamp();
+        if until <= now || until > now + 7 * 86400 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Escalate unacknowledged Critical and Emergency alerts that are past their escalation period
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// 
+    /// # Returns
+    /// * `Ok(count)` - Number of alerts escalated
+    /// * `Err(ContractError)` - Error details
+    pub fn escalate_alerts(&self, ctx: &OperationContext) -> ContractResult<u32> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("keeper"), soroban_sdk::symbol_short!("alerts")),
+            (ctx.caller.clone(), self.env.ledger().timestamp())
+        );
+        Ok(0)
+    }
+
+    /// Get all unresolved system alerts
+    pub fn get_system_alerts(&self) -> ContractResult<Vec<SystemAlert>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Set the emergency contact profiles and acknowledgment timeout
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a system admin)
+    /// * `contacts` - Contact profiles; the router orders them by priority
+    /// * `ack_timeout` - Seconds a contact has to acknowledge before the next is notified
+    pub fn set_emergency_contacts(
+        &self,
+        ctx: &OperationContext,
+        contacts: &[EmergencyContact],
+        ack_timeout: u64,
+    ) -> ContractResult<()> {
+        let duplicate = contacts.iter().enumerate()
+            .any(|(i, contact)| contacts[..i].iter().any(|other| other.contact == contact.contact));
+        if ack_timeout == 0 || duplicate || contacts.iter().any(|contact| contact.channels.is_empty()) {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get the emergency contacts in notification order
+    pub fn get_emergency_contacts(&self) -> ContractResult<EmergencyContactList> {
+        // In a real implementation, this would query the contract
+        Ok(EmergencyContactList { contacts: Vec::new(), ack_timeout: 900 })
+    }
+
+    /// Get emergency notifications no contact has acknowledged yet
+    pub fn get_pending_notifications(&self) -> ContractResult<Vec<EmergencyNotification>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Acknowledge an emergency notification, stopping its escalation
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be a contact the router notified)
+    /// * `response_id` - Emergency response the notification is for
+    pub fn ack_emergency_notification(&self, ctx: &OperationContext, response_id: &BytesN<32>) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Notify the next contact for notifications unacknowledged past the timeout
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller must be an operator)
+    /// 
+    /// # Returns
+    /// * `Ok(count)` - Contacts notified
+    pub fn escalate_emergency_notifications(&self, ctx: &OperationContext) -> ContractResult<u32> {
+        // In a real implementation, this would call the contract
+        Ok(0)
+    }
+
+    /// Helper function to generate operation IDs
+    fn generate_operation_id(&self, operation_type: &str, amount: u64) -> BytesN<32> {
+        let timestamp = self.env.ledger().timestamp();
+        let sequence = self.env.ledger().sequence();
+        
+        let mut id_bytes = [0u8; 32];
+        id_bytes[0..8].copy_from_slice(&timestamp.to_be_bytes());
+        id_bytes[8..12].copy_from_slice(&sequence.to_be_bytes());
+        id_bytes[12..20].copy_from_slice(&amount.to_be_bytes());
+        
+        // Add operation type hash
         let type_hash = operation_type.len() as u64;
         id_bytes[20..28].copy_from_slice(&type_hash.to_be_bytes());
         
@@ -231,29 +2732,2392 @@ impl IntegrationRouterClient {
     }
 }
 
-impl ContractClient for IntegrationRouterClient {
-    fn contract_address(&self) -> &Address {
-        &self.contract_address
+impl ContractClient for IntegrationRouterClient {
+    fn contract_address(&self) -> &Address {
+        &self.contract_address
+    }
+    
+    fn is_available(&self) -> bool {
+        // In a real implementation, this would ping the contract
+        true
+    }
+    
+    fn version(&self) -> ContractResult<String> {
+        // In a real implementation, this would query the contract version
+        Ok("1.0.0".to_string())
+    }
+}
+
+/// Router workflow that can be paused independently
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PauseWorkflow {
+    Deposits,
+    Withdrawals,
+    Exchanges,
+}
+
+/// System-wide and per-workflow pause flags
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PauseState {
+    pub system_paused: bool,
+    pub deposits_paused: bool,
+    pub withdrawals_paused: bool,
+    pub exchanges_paused: bool,
+}
+
+impl PauseState {
+    /// Whether a workflow is blocked, either directly or by a system-wide pause
+    pub fn is_workflow_paused(&self, workflow: PauseWorkflow) -> bool {
+        self.system_paused || match workflow {
+            PauseWorkflow::Deposits => self.deposits_paused,
+            PauseWorkflow::Withdrawals => self.withdrawals_paused,
+            PauseWorkflow::Exchanges => self.exchanges_paused,
+        }
+    }
+}
+
+/// Maintenance window announced by the router
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaintenanceWindow {
+    pub start: u64,
+    pub end: u64,
+    /// Workflows that keep running during maintenance
+    pub allowed_workflows: Vec<PauseWorkflow>,
+    pub message: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub scheduled_by: Address,
+}
+
+impl MaintenanceWindow {
+    /// Whether the window is open at `now`
+    pub fn is_active(&self, now: u64) -> bool {
+        self.start <= now && now < self.end
+    }
+
+    /// Whether a workflow is blocked at `now`
+    pub fn blocks(&self, workflow: PauseWorkflow, now: u64) -> bool {
+        self.is_active(now) && !self.allowed_workflows.contains(&workflow)
+    }
+}
+
+/// Operator usage of the current rate limit window
+#[contracttype]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RateLimitStatus {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub operator: Address,
+    pub window_start: u64,
+    pub window_ends_at: u64,
+    pub deposits: u32,
+    pub withdrawals: u32,
+    pub deposits_remaining: u32,
+    pub withdrawals_remaining: u32,
+}
+
+/// Outcome of a single check in a dry run
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulationStep {
+    pub name: String,
+    pub passed: bool,
+    pub message: String,
+}
+
+impl SimulationStep {
+    /// Step whose message is only set when the check fails
+    pub fn check(name: &str, passed: bool, failure_message: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            passed,
+            message: if passed { String::new() } else { failure_message.to_string() },
+        }
+    }
+}
+
+/// Result of a dry-run workflow
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulationReport {
+    pub operation: String,
+    pub passed: bool,
+    pub steps: Vec<SimulationStep>,
+    pub input_amount: u64,
+    pub fee_amount: u64,
+    /// iSTSi for deposits, BTC for withdrawals, destination tokens for exchanges
+    pub projected_amount: u64,
+    /// Basis points; exchanges only
+    pub exchange_rate: u64,
+}
+
+impl SimulationReport {
+    /// Build a report that passes only if every step passed
+    pub fn new(
+        operation: &str,
+        steps: Vec<SimulationStep>,
+        input_amount: u64,
+        fee_amount: u64,
+        projected_amount: u64,
+        exchange_rate: u64,
+    ) -> Self {
+        Self {
+            operation: operation.to_string(),
+            passed: steps.iter().all(|step| step.passed),
+            steps,
+            input_amount,
+            fee_amount,
+            projected_amount,
+            exchange_rate,
+        }
+    }
+
+    /// Steps that would block the operation
+    pub fn failed_steps(&self) -> Vec<&SimulationStep> {
+        self.steps.iter().filter(|step| !step.passed).collect()
+    }
+}
+
+/// Market maker's exchange inventory in one token
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidityPosition {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub market_maker: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub token: Address,
+    pub amount: u64,
+    pub updated_at: u64,
+}
+
+/// Exchange inventory available for a token
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InventoryDepth {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub token: Address,
+    pub available: u64,
+    /// Largest amount a single exchange can currently draw
+    pub max_draw: u64,
+    pub provider_count: u32,
+}
+
+impl InventoryDepth {
+    /// Whether an exchange delivering `to_amount` fits within the utilization limits
+    pub fn can_fill(&self, to_amount: u64) -> bool {
+        to_amount > 0 && to_amount <= self.max_draw
+    }
+
+    /// Price impact in basis points, as quoted by the router
+    pub fn price_impact_bps(&self, to_amount: u64) -> u64 {
+        if self.available == 0 {
+            return 10_000;
+        }
+        (((to_amount as u128) * 10_000 / self.available as u128) as u64).min(10_000)
+    }
+}
+
+/// Lifecycle of a limit order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrderStatus {
+    Open,
+    PartiallyFilled,
+    Filled,
+    Cancelled,
+    Expired,
+}
+
+/// Registry entry for a tradable pair, covering both exchange directions
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenPairConfig {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub token_a: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub token_b: Address,
+    /// Exchange fee in basis points
+    pub fee_rate: u64,
+    pub min_trade_size: u64,
+    pub max_trade_size: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub oracle_address: Address,
+    pub enabled: bool,
+    pub registered_at: u64,
+    pub updated_at: u64,
+}
+
+impl TokenPairConfig {
+    /// Whether the router would accept an exchange or order of `amount` on this pair
+    pub fn is_tradable(&self, amount: u64) -> bool {
+        self.enabled && amount >= self.min_trade_size && amount <= self.max_trade_size
+    }
+}
+
+/// Resting or closed limit order
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LimitOrder {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub order_id: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub owner: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub sell_token: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub buy_token: Address,
+    pub sell_amount: u64,
+    /// Escrowed `sell_token` not yet filled
+    pub remaining: u64,
+    /// Minimum `buy_token` per `sell_token` in basis points
+    pub limit_price: u64,
+    /// `buy_token` delivered by fills
+    pub received: u64,
+    pub status: OrderStatus,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub expires_at: u64,
+}
+
+impl LimitOrder {
+    /// Whether the order is still on the book
+    pub fn is_open(&self) -> bool {
+        matches!(self.status, OrderStatus::Open | OrderStatus::PartiallyFilled)
+    }
+
+    /// Share of the order filled so far, in basis points
+    pub fn filled_bps(&self) -> u64 {
+        if self.sell_amount == 0 {
+            return 0;
+        }
+        let filled = self.sell_amount.saturating_sub(self.remaining) as u128;
+        (filled * 10_000 / self.sell_amount as u128) as u64
+    }
+}
+
+/// Importance tier of an event type; compliance events are kept in persistent storage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EventImportance {
+    Standard,
+    Operational,
+    Compliance,
+}
+
+/// How many events of a type the router retains and for how long
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventRetentionPolicy {
+    pub max_events: u32,
+    pub max_age_seconds: u64,
+    pub importance: EventImportance,
+}
+
+/// Counters for event history retention
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventRetentionMetrics {
+    pub events_recorded: u64,
+    pub pruned_by_count: u64,
+    pub pruned_by_age: u64,
+    pub prune_runs: u64,
+    pub last_pruned_at: u64,
+}
+
+/// Point-in-time system metrics retained by the router
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MetricsSnapshot {
+    pub sequence: u64,
+    pub ledger: u32,
+    pub timestamp: u64,
+    pub total_operations: u64,
+    pub successful_operations: u64,
+    pub failed_operations: u64,
+    /// Reserve ratio in basis points (10000 = 100%)
+    pub reserve_ratio: u64,
+    pub pending_operations: u64,
+    pub withdrawal_queue_depth: u64,
+}
+
+impl From<RouterMetricsSnapshot> for MetricsSnapshot {
+    fn from(snapshot: RouterMetricsSnapshot) -> Self {
+        let metrics = snapshot.metrics;
+        Self {
+            sequence: snapshot.sequence,
+            ledger: snapshot.ledger,
+            timestamp: metrics.last_updated,
+            total_operations: metrics.total_operations,
+            successful_operations: metrics.successful_operations,
+            failed_operations: metrics.failed_operations,
+            reserve_ratio: metrics.current_reserve_ratio,
+            pending_operations: metrics.pending_operations,
+            withdrawal_queue_depth: metrics.withdrawal_queue_depth,
+        }
+    }
+}
+
+/// Router `SystemMetrics` as stored in a snapshot
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+struct RouterSystemMetrics {
+    total_operations: u64,
+    successful_operations: u64,
+    failed_operations: u64,
+    average_processing_time: u64,
+    deposit_processing_time: u64,
+    withdrawal_processing_time: u64,
+    exchange_processing_time: u64,
+    batch_processing_time: u64,
+    current_reserve_ratio: u64,
+    active_users_24h: u64,
+    pending_operations: u64,
+    withdrawal_queue_depth: u64,
+    last_updated: u64,
+}
+
+/// Router `MetricsSnapshot` as returned by `get_metrics_history`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+struct RouterMetricsSnapshot {
+    sequence: u64,
+    ledger: u32,
+    metrics: RouterSystemMetrics,
+}
+
+impl MetricsSnapshot {
+    /// Operations still waiting on the router, including queued withdrawals
+    pub fn queue_depth(&self) -> u64 {
+        self.pending_operations.saturating_add(self.withdrawal_queue_depth)
+    }
+
+    /// Operations completed since an earlier snapshot
+    pub fn operations_since(&self, earlier: &MetricsSnapshot) -> u64 {
+        self.total_operations.saturating_sub(earlier.total_operations)
+    }
+}
+
+/// Operation types timed by the router
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProcessingOperation {
+    Deposit,
+    /// Timed until the Bitcoin payout confirms
+    Withdrawal,
+    Exchange,
+    Batch,
+}
+
+/// Processing time statistics for one operation type
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProcessingTimeStats {
+    pub operation: ProcessingOperation,
+    pub sample_count: u64,
+    /// Exponentially-weighted moving average in milliseconds
+    pub average_ms: u64,
+    pub last_ms: u64,
+    pub max_ms: u64,
+    pub updated_at: u64,
+}
+
+/// Window operator statistics are aggregated over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatsPeriod {
+    /// Current day
+    Day,
+    /// Current day and the six before it
+    Week,
+    /// Current day and the 29 before it
+    Month,
+    AllTime,
+}
+
+impl StatsPeriod {
+    /// Lowercase name used in reports
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatsPeriod::Day => "day",
+            StatsPeriod::Week => "week",
+            StatsPeriod::Month => "month",
+            StatsPeriod::AllTime => "all_time",
+        }
+    }
+}
+
+/// Operator throughput and error rates over a period
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperatorStats {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub operator: Address,
+    pub period: StatsPeriod,
+    /// 0 for all time
+    pub period_start: u64,
+    pub started: u64,
+    pub completed: u64,
+    pub failed: u64,
+    /// Basis points of finished operations
+    pub failure_rate: u64,
+    pub average_handling_seconds: u64,
+    /// Operations finished after their timeout
+    pub sla_breaches: u64,
+}
+
+/// Cross-contract call outcomes the router recorded for one contract
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContractCallStats {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub address: Address,
+    pub total_calls: u64,
+    pub failed_calls: u64,
+    /// Reset by the next successful call
+    pub consecutive_failures: u32,
+    pub last_called_at: u64,
+    pub last_success_at: u64,
+    pub last_error: String,
+}
+
+/// Router `ContractCallStats` as returned by `get_contract_call_stats`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+struct RouterContractCallStats {
+    address: Address,
+    total_calls: u64,
+    failed_calls: u64,
+    consecutive_failures: u32,
+    last_called_at: u64,
+    last_execution_time: u64,
+    last_success_at: u64,
+    last_error: SorobanString,
+}
+
+impl From<RouterContractCallStats> for ContractCallStats {
+    fn from(stats: RouterContractCallStats) -> Self {
+        Self {
+            address: stats.address,
+            total_calls: stats.total_calls,
+            failed_calls: stats.failed_calls,
+            consecutive_failures: stats.consecutive_failures,
+            last_called_at: stats.last_called_at,
+            last_success_at: stats.last_success_at,
+            last_error: stats.last_error.to_string(),
+        }
+    }
+}
+
+impl ContractCallStats {
+    /// Errors per 1000 calls
+    pub fn error_rate(&self) -> u64 {
+        if self.total_calls == 0 {
+            0
+        } else {
+            self.failed_calls * 1000 / self.total_calls
+        }
+    }
+}
+
+/// Calls an isolated contract still accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IsolationScope {
+    /// Queries only
+    ReadOnly,
+    /// No calls
+    Full,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IsolationStatus {
+    Isolated,
+    /// Read-only while its health is verified for re-integration
+    Probation,
+}
+
+/// Isolation of a contract from the router's call paths
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContractIsolation {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub contract: Address,
+    pub scope: IsolationScope,
+    pub status: IsolationStatus,
+    pub reason: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub isolated_by: Address,
+    pub isolated_at: u64,
+    /// 0 until re-integration begins
+    pub probation_started_at: u64,
+    /// Failed call count when probation began
+    pub failed_calls_at_probation: u64,
+}
+
+/// Longest temporary role grant the router accepts, in seconds
+pub const MAX_ROLE_GRANT_SECONDS: u64 = 7 * 86_400;
+
+/// Time-bounded role that overrides a user's assigned role until it expires
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoleGrant {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub user: Address,
+    pub role: UserRole,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub granted_by: Address,
+    pub granted_at: u64,
+    /// Ignored by permission checks from this timestamp on
+    pub expires_at: u64,
+}
+
+impl RoleGrant {
+    /// Whether the grant still applies at `now`
+    pub fn is_active(&self, now: u64) -> bool {
+        now < self.expires_at
+    }
+
+    /// Seconds the grant has left at `now`
+    pub fn remaining(&self, now: u64) -> u64 {
+        self.expires_at.saturating_sub(now)
+    }
+}
+
+/// Throttles applied while reserve protection mode is active
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReserveProtectionConfig {
+    /// Share of each user's withdrawal limits that stays available, in basis points
+    pub withdrawal_limit_bps: u32,
+    /// Seconds approvers have for withdrawals held under protection
+    pub approval_window: u64,
+    /// Seconds between automatic reconciliations under protection
+    pub reconciliation_frequency: u64,
+}
+
+impl Default for ReserveProtectionConfig {
+    fn default() -> Self {
+        Self { withdrawal_limit_bps: 2_500, approval_window: 3_600, reconciliation_frequency: 300 }
+    }
+}
+
+impl ReserveProtectionConfig {
+    /// A normal withdrawal limit reduced to its protected share
+    pub fn protected_limit(&self, limit: u64) -> u64 {
+        limit.saturating_mul(self.withdrawal_limit_bps as u64) / 10_000
+    }
+}
+
+/// Reserve protection mode, with the throttles it applied
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProtectionModeState {
+    pub active: bool,
+    /// Emergency response that activated the mode; resolving it lifts the mode
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub response_id: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
+    pub activated_by: Option<Address>,
+    pub activated_at: u64,
+    pub reason: String,
+    /// Throttles as configured at activation
+    pub config: ReserveProtectionConfig,
+    /// Exchanges were paused by the mode and resume when it lifts
+    pub exchanges_paused: bool,
+    /// 0 while active
+    pub lifted_at: u64,
+}
+
+/// Alert severity, in escalation order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlertSeverity {
+    Info,
+    Warning,
+    Critical,
+    Emergency,
+}
+
+/// Unresolved system alert
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemAlert {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub alert_id: BytesN<32>,
+    pub alert_type: String,
+    pub severity: AlertSeverity,
+    pub message: String,
+    pub triggered_at: u64,
+    pub acknowledged: bool,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
+    pub acknowledged_by: Option<Address>,
+    /// Steps taken along the escalation chain
+    pub escalation_level: u32,
+    /// Contact currently paged
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
+    pub assigned_to: Option<Address>,
+    pub last_escalated_at: u64,
+    /// Escalation is held until this timestamp (0 = not snoozed)
+    pub snoozed_until: u64,
+}
+
+impl SystemAlert {
+    /// Whether the alert is snoozed at `now`
+    pub fn is_snoozed(&self, now: u64) -> bool {
+        self.snoozed_until > now
+    }
+}
+
+/// Kind of emergency response
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmergencyResponseType {
+    SystemWideHalt,
+    AddressFreeze,
+    ContractIsolation,
+    ReserveProtection,
+}
+
+/// Channel an emergency contact can be reached on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContactChannel {
+    Sms,
+    Email,
+    Voice,
+    Webhook,
+}
+
+/// Emergency contact profile
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmergencyContact {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub contact: Address,
+    /// Lower priorities are notified first
+    pub priority: u32,
+    /// Preferred channels, most preferred first
+    pub channels: Vec<ContactChannel>,
+    /// Off-chain directory key for the phone number or mailbox
+    pub directory_ref: String,
+}
+
+/// Emergency contacts in notification order
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmergencyContactList {
+    /// Sorted by priority
+    pub contacts: Vec<EmergencyContact>,
+    /// Seconds a contact has to acknowledge before the next is notified
+    pub ack_timeout: u64,
+}
+
+/// Notification of one contact about an emergency response
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NotificationRecord {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub contact: Address,
+    /// Position in the contact list
+    pub level: u32,
+    pub sent_at: u64,
+    /// 0 = not acknowledged
+    pub acknowledged_at: u64,
+}
+
+/// Notifications sent for an emergency response
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmergencyNotification {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub response_id: BytesN<32>,
+    pub response_type: EmergencyResponseType,
+    pub reason: String,
+    pub drill: bool,
+    /// One per contact notified, in notification order
+    pub records: Vec<NotificationRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
+    pub acknowledged_by: Option<Address>,
+    pub last_sent_at: u64,
+}
+
+impl EmergencyNotification {
+    /// Most recently notified contact
+    pub fn latest(&self) -> Option<&NotificationRecord> {
+        self.records.last()
+    }
+}
+
+/// How iSTSi valuations round to whole satoshis (payouts always round down)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoundingPolicy {
+    Down,
+    /// Round half up
+    Nearest,
+    Up,
+}
+
+/// Router `RoundingPolicy` as returned within `get_conversion_config`
+#[contracttype]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RouterRoundingPolicy {
+    Down,
+    Nearest,
+    Up,
+}
+
+impl From<RouterRoundingPolicy> for RoundingPolicy {
+    fn from(rounding: RouterRoundingPolicy) -> Self {
+        match rounding {
+            RouterRoundingPolicy::Down => RoundingPolicy::Down,
+            RouterRoundingPolicy::Nearest => RoundingPolicy::Nearest,
+            RouterRoundingPolicy::Up => RoundingPolicy::Up,
+        }
+    }
+}
+
+/// Router `ConversionConfig` as returned by `get_conversion_config`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+struct RouterConversionConfig {
+    istsi_per_satoshi: u64,
+    rounding: RouterRoundingPolicy,
+    min_dust_sats: u64,
+}
+
+impl From<RouterConversionConfig> for ConversionConfig {
+    fn from(config: RouterConversionConfig) -> Self {
+        Self {
+            istsi_per_satoshi: config.istsi_per_satoshi,
+            rounding: config.rounding.into(),
+            min_dust_sats: config.min_dust_sats,
+        }
+    }
+}
+
+/// Satoshi/iSTSi conversion applied by every router workflow
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConversionConfig {
+    /// iSTSi base units minted per satoshi deposited
+    pub istsi_per_satoshi: u64,
+    pub rounding: RoundingPolicy,
+    /// Smallest Bitcoin payout a withdrawal may produce
+    pub min_dust_sats: u64,
+}
+
+impl Default for ConversionConfig {
+    /// The router's configuration until governance changes it
+    fn default() -> Self {
+        Self {
+            istsi_per_satoshi: 100_000_000,
+            rounding: RoundingPolicy::Down,
+            min_dust_sats: 0,
+        }
+    }
+}
+
+impl ConversionConfig {
+    /// iSTSi minted for a satoshi deposit
+    pub fn sats_to_istsi(&self, sats: u64) -> u64 {
+        sats.saturating_mul(self.istsi_per_satoshi)
+    }
+
+    /// Satoshis paid out for burned iSTSi, always rounding down
+    pub fn istsi_to_sats(&self, istsi_amount: u64) -> u64 {
+        istsi_amount / self.istsi_per_satoshi.max(1)
+    }
+
+    /// iSTSi valued in satoshis under the rounding policy, as the router checks limits
+    pub fn istsi_value_sats(&self, istsi_amount: u64) -> u64 {
+        let ratio = self.istsi_per_satoshi.max(1);
+        match self.rounding {
+            RoundingPolicy::Down => istsi_amount / ratio,
+            RoundingPolicy::Nearest => ((istsi_amount as u128 + ratio as u128 / 2) / ratio as u128) as u64,
+            RoundingPolicy::Up => istsi_amount.div_ceil(ratio),
+        }
+    }
+
+    /// Whether a withdrawal payout clears the dust limit
+    pub fn is_above_dust(&self, btc_amount: u64) -> bool {
+        btc_amount >= self.min_dust_sats
+    }
+}
+
+/// Conversion config proposal awaiting its governance timelock
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingConversionConfig {
+    pub config: ConversionConfig,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    /// Earliest time the proposal can be applied
+    pub effective_at: u64,
+}
+
+impl PendingConversionConfig {
+    /// Whether the timelock has elapsed
+    pub fn is_ready(&self, now: u64) -> bool {
+        now >= self.effective_at
+    }
+}
+
+/// Reserve ratio floor the router enforces on mints and reserve withdrawals
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReserveGuardrails {
+    /// Lowest reserve ratio, in basis points, an operation may leave behind
+    pub min_ratio_bps: u64,
+    /// Margin above the floor in which the router logs near misses
+    pub warning_band_bps: u64,
+}
+
+impl ReserveGuardrails {
+    /// Whether a reserve ratio is above the floor but inside the warning band
+    pub fn is_near_miss(&self, ratio_bps: u64) -> bool {
+        ratio_bps >= self.min_ratio_bps && ratio_bps < self.min_ratio_bps.saturating_add(self.warning_band_bps)
+    }
+}
+
+/// Router `ReserveGuardrails` as taken by `set_reserve_guardrails`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+struct RouterReserveGuardrails {
+    min_ratio_bps: u64,
+    warning_band_bps: u64,
+}
+
+impl From<ReserveGuardrails> for RouterReserveGuardrails {
+    fn from(guardrails: ReserveGuardrails) -> Self {
+        Self { min_ratio_bps: guardrails.min_ratio_bps, warning_band_bps: guardrails.warning_band_bps }
+    }
+}
+
+impl From<RouterReserveGuardrails> for ReserveGuardrails {
+    fn from(guardrails: RouterReserveGuardrails) -> Self {
+        Self { min_ratio_bps: guardrails.min_ratio_bps, warning_band_bps: guardrails.warning_band_bps }
+    }
+}
+
+/// Projected reserve ratio of an operation against the guardrails
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GuardrailCheck {
+    /// Reserve ratio, in basis points, the operation would leave behind (0 without guardrails)
+    pub ratio_bps: u64,
+    /// Guardrail floor (0 without guardrails)
+    pub min_ratio_bps: u64,
+    /// Whether the operation would pass the floor
+    pub allowed: bool,
+    /// Whether the operation passes but lands inside the warning band
+    pub near_floor: bool,
+}
+
+/// Router `GuardrailCheck` as returned by `check_reserve_guardrails`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+struct RouterGuardrailCheck {
+    ratio_bps: u64,
+    min_ratio_bps: u64,
+    allowed: bool,
+    near_floor: bool,
+}
+
+impl From<RouterGuardrailCheck> for GuardrailCheck {
+    fn from(check: RouterGuardrailCheck) -> Self {
+        Self {
+            ratio_bps: check.ratio_bps,
+            min_ratio_bps: check.min_ratio_bps,
+            allowed: check.allowed,
+            near_floor: check.near_floor,
+        }
+    }
+}
+
+/// Second-approval policy for large withdrawals
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawalApprovalConfig {
+    /// Payouts above this many satoshis wait for an approver (0 disables)
+    pub threshold_sats: u64,
+    /// Seconds an approver has before the withdrawal expires and is re-minted
+    pub approval_window: u64,
+}
+
+/// Burned withdrawal waiting for a second approval
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawalApproval {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub withdrawal_id: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub operation_id: BytesN<32>,
+    /// Operator that requested the withdrawal; may not approve it
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub initiator: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub user: Address,
+    /// Requested amount, fee included
+    pub istsi_amount: u64,
+    /// Amount burned and re-minted on expiry
+    pub net_istsi_amount: u64,
+    pub withdrawal_fee: u64,
+    pub btc_amount: u64,
+    pub btc_address: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub correlation_id: BytesN<32>,
+    pub requested_at: u64,
+    pub expires_at: u64,
+}
+
+/// Router `WithdrawalApprovalConfig`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+struct RouterWithdrawalApprovalConfig {
+    threshold_sats: u64,
+    approval_window: u64,
+}
+
+impl From<WithdrawalApprovalConfig> for RouterWithdrawalApprovalConfig {
+    fn from(config: WithdrawalApprovalConfig) -> Self {
+        Self { threshold_sats: config.threshold_sats, approval_window: config.approval_window }
+    }
+}
+
+impl From<RouterWithdrawalApprovalConfig> for WithdrawalApprovalConfig {
+    fn from(config: RouterWithdrawalApprovalConfig) -> Self {
+        Self { threshold_sats: config.threshold_sats, approval_window: config.approval_window }
+    }
+}
+
+/// Router `WithdrawalApproval` as returned by the approval getters
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+struct RouterWithdrawalApproval {
+    withdrawal_id: BytesN<32>,
+    operation_id: BytesN<32>,
+    initiator: Address,
+    user: Address,
+    istsi_amount: u64,
+    net_istsi_amount: u64,
+    withdrawal_fee: u64,
+    btc_amount: u64,
+    btc_address: SorobanString,
+    correlation_id: BytesN<32>,
+    requested_at: u64,
+    expires_at: u64,
+}
+
+impl From<RouterWithdrawalApproval> for WithdrawalApproval {
+    fn from(approval: RouterWithdrawalApproval) -> Self {
+        Self {
+            withdrawal_id: approval.withdrawal_id,
+            operation_id: approval.operation_id,
+            initiator: approval.initiator,
+            user: approval.user,
+            istsi_amount: approval.istsi_amount,
+            net_istsi_amount: approval.net_istsi_amount,
+            withdrawal_fee: approval.withdrawal_fee,
+            btc_amount: approval.btc_amount,
+            btc_address: approval.btc_address.to_string(),
+            correlation_id: approval.correlation_id,
+            requested_at: approval.requested_at,
+            expires_at: approval.expires_at,
+        }
+    }
+}
+
+impl WithdrawalApproval {
+    /// Whether the approval window has closed
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// Status lists operations move through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OperationList {
+    Pending,
+    Completed,
+    Failed,
+}
+
+/// One page of a status list, oldest first
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperationListPage {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes_vec"))]
+    pub operation_ids: Vec<BytesN<32>>,
+    pub has_more: bool,
+    /// Cursor for the next page when `has_more` is set
+    pub next_day: u64,
+    pub next_slot: u32,
+}
+
+/// Long-lived router entry that keepers extend before it expires
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TtlKey {
+    OperationTracker(#[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))] BytesN<32>),
+    BatchOperation(#[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))] BytesN<32>),
+    /// Keyed by BTC tx hash
+    DepositStatus(#[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))] BytesN<32>),
+    WithdrawalStatus(#[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))] BytesN<32>),
+    StoredProof(#[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))] BytesN<32>),
+    /// Keyed by archive period start
+    OperationArchive(u64),
+}
+
+/// Completed operations compacted into one daily archive period
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperationArchive {
+    pub period_start: u64,
+    pub operation_count: u32,
+    /// Operation type -> operations compacted
+    pub operation_types: Vec<(String, u32)>,
+    pub first_updated_at: u64,
+    pub last_updated_at: u64,
+    pub compacted_at: u64,
+}
+
+/// Lifecycle of a self-service withdrawal request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WithdrawalRequestStatus {
+    Pending,
+    Fulfilled,
+    Cancelled,
+    Rejected,
+}
+
+/// Withdrawal requested by the user's own wallet, fulfilled by an operator
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UserWithdrawalRequest {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub request_id: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub user: Address,
+    pub istsi_amount: u64,
+    pub btc_address: String,
+    pub status: WithdrawalRequestStatus,
+    /// Withdrawal started for the request, once fulfilled
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_bytes"))]
+    pub withdrawal_id: Option<BytesN<32>>,
+    /// Operator that fulfilled or rejected the request
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
+    pub handled_by: Option<Address>,
+    /// Rejection reason
+    pub reason: String,
+    pub requested_at: u64,
+    pub updated_at: u64,
+    /// Network fee tier the user chose
+    pub fee_tier: BtcFeeTier,
+}
+
+/// Estimated size of a withdrawal payout transaction, as used by the router
+pub const WITHDRAWAL_TX_VBYTES: u64 = 141;
+
+/// Age in seconds past which the router quotes published fee rates as stale
+pub const BTC_FEE_RATE_MAX_AGE: u64 = 3_600;
+
+/// Bitcoin network fee tier for a withdrawal payout
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BtcFeeTier {
+    Economy,
+    Standard,
+    Priority,
+}
+
+/// Published Bitcoin fee rates per tier, in sat/vB
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BtcFeeRates {
+    pub economy: u64,
+    pub standard: u64,
+    pub priority: u64,
+    /// Operator or fee oracle that published the rates
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub updated_by: Address,
+    pub updated_at: u64,
+}
+
+/// Router `BtcFeeRates` as returned by `get_btc_fee_rates` and `submit_btc_fee_rates`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+struct RouterBtcFeeRates {
+    economy: u64,
+    standard: u64,
+    priority: u64,
+    updated_by: Address,
+    updated_at: u64,
+}
+
+impl From<RouterBtcFeeRates> for BtcFeeRates {
+    fn from(rates: RouterBtcFeeRates) -> Self {
+        Self {
+            economy: rates.economy,
+            standard: rates.standard,
+            priority: rates.priority,
+            updated_by: rates.updated_by,
+            updated_at: rates.updated_at,
+        }
+    }
+}
+
+impl BtcFeeRates {
+    /// Rate for a tier, in sat/vB
+    pub fn rate(&self, tier: BtcFeeTier) -> u64 {
+        match tier {
+            BtcFeeTier::Economy => self.economy,
+            BtcFeeTier::Standard => self.standard,
+            BtcFeeTier::Priority => self.priority,
+        }
     }
-    
-    fn is_available(&self) -> bool {
-        // In a real implementation, this would ping the contract
-        true
+}
+
+/// Estimated network fee and net payout for a withdrawal
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawalFeeQuote {
+    pub tier: BtcFeeTier,
+    /// sat/vB, 0 if no rates were published
+    pub fee_rate: u64,
+    pub estimated_vbytes: u64,
+    pub network_fee_sats: u64,
+    pub btc_amount: u64,
+    /// What the user receives after the network fee
+    pub net_btc_amount: u64,
+    pub rates_updated_at: u64,
+    /// Rates missing or older than the router's freshness window
+    pub stale: bool,
+}
+
+/// How the router funds and draws on the insurance fund
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsuranceConfig {
+    /// Share of each accrued fee diverted to the fund, in basis points
+    pub contribution_bps: u64,
+    /// Largest reserve shortfall covered without admin action
+    pub auto_cover_max_sats: u64,
+    /// iSTSi fund balance considered fully healthy
+    pub target_balance: u64,
+}
+
+/// Insurance fund balance held by the router for one token
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsuranceFund {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub token: Address,
+    pub balance: u64,
+    /// Fee contributions and top-ups
+    pub total_contributed: u64,
+    /// Paid out to cover reserve shortfalls
+    pub total_drawn: u64,
+    /// Shortfall, in satoshis, retired by the most recent draw
+    pub covered_shortfall_sats: u64,
+    pub last_draw_at: u64,
+}
+
+/// Direction of a timelocked insurance fund transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InsuranceTransferKind {
+    TopUp,
+    Withdrawal,
+}
+
+/// Insurance fund transfer awaiting its timelock
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingInsuranceTransfer {
+    pub kind: InsuranceTransferKind,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub token: Address,
+    pub amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub counterparty: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    /// Earliest time the transfer can be executed
+    pub effective_at: u64,
+}
+
+impl PendingInsuranceTransfer {
+    /// Whether the timelock has elapsed
+    pub fn is_ready(&self, now: u64) -> bool {
+        now >= self.effective_at
     }
-    
-    fn version(&self) -> ContractResult<String> {
-        // In a real implementation, this would query the contract version
-        Ok("1.0.0".to_string())
+}
+
+/// Insurance fund health reported on the admin dashboard
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsuranceFundHealth {
+    pub balance: u64,
+    pub target_balance: u64,
+    /// Shortfall, in satoshis, the balance could cover
+    pub coverage_capacity_sats: u64,
+    pub covered_shortfall_sats: u64,
+    pub total_drawn: u64,
+    pub status: ComponentHealth,
+}
+
+impl InsuranceFundHealth {
+    /// Health of a fund balance against its target, as the router grades it
+    pub fn status_for(balance: u64, target_balance: u64) -> ComponentHealth {
+        if balance >= target_balance {
+            ComponentHealth::Healthy
+        } else if balance >= target_balance / 2 {
+            ComponentHealth::Degraded
+        } else {
+            ComponentHealth::Critical
+        }
+    }
+}
+
+/// Smallest operation each router workflow accepts
+/// 
+/// A zero minimum disables the check for that workflow.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AmountMinimums {
+    pub min_deposit_sats: u64,
+    /// Measured in satoshis before fees
+    pub min_withdrawal_sats: u64,
+    /// Measured in source token units
+    pub min_exchange_amount: u64,
+}
+
+impl AmountMinimums {
+    /// Whether an amount meets the minimum for a workflow
+    pub fn meets_minimum(&self, workflow: PauseWorkflow, amount: u64) -> bool {
+        let minimum = match workflow {
+            PauseWorkflow::Deposits => self.min_deposit_sats,
+            PauseWorkflow::Withdrawals => self.min_withdrawal_sats,
+            PauseWorkflow::Exchanges => self.min_exchange_amount,
+        };
+        amount >= minimum
+    }
+}
+
+/// Sub-minimum deposits the router holds for a user until consolidated
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DustAccount {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub user: Address,
+    pub pending_sats: u64,
+    /// Deposits folded into `pending_sats`
+    pub residual_count: u32,
+    /// Lifetime satoshis minted through consolidation
+    pub consolidated_sats: u64,
+    pub updated_at: u64,
+}
+
+impl DustAccount {
+    /// Whether the pending dust is large enough to mint as one deposit
+    pub fn can_consolidate(&self, minimums: &AmountMinimums) -> bool {
+        self.pending_sats > 0 && minimums.meets_minimum(PauseWorkflow::Deposits, self.pending_sats)
+    }
+}
+
+/// Outcome of the router's upgrade compatibility checks
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompatibilityCheck {
+    pub compatible: bool,
+    pub error_message: String,
+    /// Work needed before the upgrade can run, e.g. "missing_export:proc_wd"
+    pub required_migrations: Vec<String>,
+}
+
+/// Progress of an upgrade's state migration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MigrationStatus {
+    Running,
+    Failed,
+    Completed,
+}
+
+/// Checkpoint of an upgrade's state migration
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MigrationState {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub upgrade_id: BytesN<32>,
+    pub contract_name: String,
+    /// Next step to run
+    pub step_index: u32,
+    /// Items already migrated by the current step
+    pub cursor: u32,
+    pub total_steps: u32,
+    pub items_migrated: u64,
+    pub batches_run: u32,
+    pub status: MigrationStatus,
+    pub last_error: String,
+    pub started_at: u64,
+    pub updated_at: u64,
+}
+
+/// Lifecycle of a staged upgrade rollout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RolloutStatus {
+    Active,
+    Completed,
+    RolledBack,
+}
+
+/// Staged rollout routing a share of users to an upgraded contract
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CanaryRollout {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub upgrade_id: BytesN<32>,
+    pub contract_name: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub old_address: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub new_address: Address,
+    pub stage: u32,
+    /// Share of user buckets (0-99) routed to the new address
+    pub percentage: u32,
+    pub max_error_bps: u32,
+    pub min_calls: u32,
+    pub status: RolloutStatus,
+    pub started_at: u64,
+    pub updated_at: u64,
+}
+
+/// Call counters of one rollout stage
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RolloutStageMetrics {
+    pub stage: u32,
+    pub percentage: u32,
+    pub started_at: u64,
+    pub old_calls: u64,
+    pub old_errors: u64,
+    pub new_calls: u64,
+    pub new_errors: u64,
+}
+
+impl RolloutStageMetrics {
+    /// Error rate of the new contract in this stage, in basis points
+    pub fn new_error_bps(&self) -> u64 {
+        if self.new_calls == 0 {
+            return 0;
+        }
+        self.new_errors * 10_000 / self.new_calls
+    }
+
+    /// Error rate of the old contract in this stage, in basis points
+    pub fn old_error_bps(&self) -> u64 {
+        if self.old_calls == 0 {
+            return 0;
+        }
+        self.old_errors * 10_000 / self.old_calls
     }
 }
 
 /// Router configuration structure
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RouterConfig {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
     pub kyc_registry: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
     pub istsi_token: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
     pub fungible_token: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
     pub reserve_manager: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
     pub admin: Address,
     pub paused: bool,
-}
\ No newline at end of file
+}
+/// Address freeze record
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FreezeRecord {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub address: Address,
+    pub reason: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub frozen_by: Address,
+    pub frozen_at: u64,
+    pub expires_at: Option<u64>,
+}
+
+impl FreezeRecord {
+    /// Whether the freeze is still in effect at `now`
+    pub fn is_active(&self, now: u64) -> bool {
+        self.expires_at.map_or(true, |expires_at| now < expires_at)
+    }
+}
+
+/// Status of a clawback proposal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClawbackStatus {
+    Proposed,
+    Executed,
+    Rejected,
+}
+
+/// Compliance seizure proposed by a ComplianceOfficer
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClawbackProposal {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub clawback_id: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub target: Address,
+    pub amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub evidence_hash: BytesN<32>,
+    pub reason: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    pub status: ClawbackStatus,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
+    pub decided_by: Option<Address>,
+    pub decided_at: u64,
+    /// Entry in the token's clawback audit trail, 0 until executed
+    pub token_clawback_id: u64,
+}
+
+/// Stage of a tracked Bitcoin deposit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DepositProcessingStatus {
+    /// Seen but not yet processed; pre-registered deposits wait here for confirmations
+    Pending,
+    KYCVerifying,
+    ReserveValidating,
+    Registering,
+    Minting,
+    Completed,
+    Failed,
+    RolledBack,
+    /// Completed, then the Bitcoin transaction was reorged out
+    Reorged,
+    /// Pre-registered from the mempool but never confirmed
+    Expired,
+}
+
+/// Processing status of a Bitcoin deposit, keyed by its transaction
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositStatus {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub btc_tx_hash: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub user: Address,
+    pub btc_amount: u64,
+    pub istsi_amount: u64,
+    pub confirmations: u32,
+    pub status: DepositProcessingStatus,
+    /// All zeros while the deposit is pre-registered
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub operation_id: BytesN<32>,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub error_message: String,
+}
+
+/// Unconfirmed deposit registered from the mempool, awaiting its confirmations
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositPreRegistration {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub btc_tx_hash: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub user: Address,
+    pub btc_amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub registered_by: Address,
+    pub registered_at: u64,
+    pub expires_at: u64,
+}
+
+/// Router `DepositProcessingStatus`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+enum RouterDepositProcessingStatus {
+    Pending,
+    KYCVerifying,
+    ReserveValidating,
+    Registering,
+    Minting,
+    Completed,
+    Failed,
+    RolledBack,
+    Reorged,
+    Expired,
+}
+
+impl From<RouterDepositProcessingStatus> for DepositProcessingStatus {
+    fn from(status: RouterDepositProcessingStatus) -> Self {
+        match status {
+            RouterDepositProcessingStatus::Pending => DepositProcessingStatus::Pending,
+            RouterDepositProcessingStatus::KYCVerifying => DepositProcessingStatus::KYCVerifying,
+            RouterDepositProcessingStatus::ReserveValidating => DepositProcessingStatus::ReserveValidating,
+            RouterDepositProcessingStatus::Registering => DepositProcessingStatus::Registering,
+            RouterDepositProcessingStatus::Minting => DepositProcessingStatus::Minting,
+            RouterDepositProcessingStatus::Completed => DepositProcessingStatus::Completed,
+            RouterDepositProcessingStatus::Failed => DepositProcessingStatus::Failed,
+            RouterDepositProcessingStatus::RolledBack => DepositProcessingStatus::RolledBack,
+            RouterDepositProcessingStatus::Reorged => DepositProcessingStatus::Reorged,
+            RouterDepositProcessingStatus::Expired => DepositProcessingStatus::Expired,
+        }
+    }
+}
+
+/// Router `DepositStatus` as returned by the deposit status getters
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+struct RouterDepositStatus {
+    btc_tx_hash: BytesN<32>,
+    user: Address,
+    btc_amount: u64,
+    istsi_amount: u64,
+    confirmations: u32,
+    status: RouterDepositProcessingStatus,
+    operation_id: BytesN<32>,
+    created_at: u64,
+    updated_at: u64,
+    error_message: SorobanString,
+}
+
+impl From<RouterDepositStatus> for DepositStatus {
+    fn from(status: RouterDepositStatus) -> Self {
+        Self {
+            btc_tx_hash: status.btc_tx_hash,
+            user: status.user,
+            btc_amount: status.btc_amount,
+            istsi_amount: status.istsi_amount,
+            confirmations: status.confirmations,
+            status: status.status.into(),
+            operation_id: status.operation_id,
+            created_at: status.created_at,
+            updated_at: status.updated_at,
+            error_message: status.error_message.to_string(),
+        }
+    }
+}
+
+/// Router `DepositPreRegistration`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+struct RouterDepositPreRegistration {
+    btc_tx_hash: BytesN<32>,
+    user: Address,
+    btc_amount: u64,
+    registered_by: Address,
+    registered_at: u64,
+    expires_at: u64,
+}
+
+impl From<RouterDepositPreRegistration> for DepositPreRegistration {
+    fn from(registration: RouterDepositPreRegistration) -> Self {
+        Self {
+            btc_tx_hash: registration.btc_tx_hash,
+            user: registration.user,
+            btc_amount: registration.btc_amount,
+            registered_by: registration.registered_by,
+            registered_at: registration.registered_at,
+            expires_at: registration.expires_at,
+        }
+    }
+}
+
+/// Progress of a deposit reorg case
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReorgCaseStatus {
+    /// Minted amount seized back into the seizure escrow
+    ClawedBack,
+    /// Tokens moved or could not be seized; awaiting compliance
+    Escalated,
+    Resolved,
+}
+
+/// Completed deposit whose Bitcoin transaction was reorged out
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReorgCase {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub btc_tx_hash: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub user: Address,
+    pub btc_amount: u64,
+    /// Minted amount frozen when the case opened
+    pub istsi_amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub evidence_hash: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub reported_by: Address,
+    pub reported_at: u64,
+    pub status: ReorgCaseStatus,
+    /// Entry in the token's clawback audit trail, 0 unless clawed back
+    pub token_clawback_id: u64,
+    /// Alert raised for compliance on escalation
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_bytes"))]
+    pub alert_id: Option<BytesN<32>>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
+    pub resolved_by: Option<Address>,
+    pub resolved_at: u64,
+    pub resolution: String,
+}
+
+/// Kind of privileged action recorded in the operator audit log
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AuditAction {
+    RoleGranted,
+    RoleRevoked,
+    Paused,
+    Resumed,
+    Upgrade,
+    ParameterChange,
+    EmergencyResponse,
+}
+
+/// Entry in the router's hash-chained operator audit log
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuditLogEntry {
+    pub sequence: u64,
+    pub action: AuditAction,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub actor: Address,
+    pub target: String,
+    pub detail: String,
+    pub timestamp: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub prev_hash: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub entry_hash: BytesN<32>,
+}
+
+impl AuditLogEntry {
+    /// Whether this entry directly follows `previous` in the chain
+    pub fn follows(&self, previous: &AuditLogEntry) -> bool {
+        self.sequence == previous.sequence + 1 && self.prev_hash == previous.entry_hash
+    }
+}
+
+/// Length of the audit chain and hash of its latest entry
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuditChainHead {
+    pub length: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub head_hash: BytesN<32>,
+}
+
+/// Result of evaluating jurisdiction rules for an operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JurisdictionOutcome {
+    Allow,
+    Deny,
+    /// Allowed only for users at the enhanced KYC tier
+    ExtraVerification,
+}
+
+/// Outcome applying from `min_amount` up to the next band
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JurisdictionBand {
+    pub min_amount: u64,
+    pub outcome: JurisdictionOutcome,
+}
+
+/// Versioned rules for one jurisdiction and operation
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JurisdictionRuleSet {
+    pub jurisdiction: String,
+    pub op_code: u32,
+    pub bands: Vec<JurisdictionBand>,
+    pub version: u32,
+    pub updated_at: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub updated_by: Address,
+}
+
+impl JurisdictionRuleSet {
+    /// Outcome of the band containing `amount`, mirroring the router
+    pub fn outcome_for(&self, amount: u64) -> JurisdictionOutcome {
+        self.bands
+            .iter()
+            .take_while(|band| band.min_amount <= amount)
+            .last()
+            .map_or(JurisdictionOutcome::Allow, |band| band.outcome)
+    }
+}
+
+/// Verification status of a stored proof of reserves
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProofVerificationStatus {
+    Pending,
+    Verified,
+    Failed,
+    Expired,
+}
+
+/// Proof of reserves as stored and published by the router
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StoredProofOfReserves {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub proof_id: BytesN<32>,
+    pub timestamp: u64,
+    pub total_btc_reserves: u64,
+    pub total_token_supply: u64,
+    pub reserve_ratio: u64,            // Basis points
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub merkle_root: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub signature: BytesN<64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_bytes"))]
+    pub signer_key: Option<BytesN<32>>, // Custodian attestation key that signed the proof
+    pub verification_status: ProofVerificationStatus,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub generated_by: Address,
+}
+
+/// A user's exchange limits and headroom as the router applies them right now
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExchangeComplianceStatus {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub user: Address,
+    pub kyc_tier: u32,
+    pub daily_limit: u64,
+    pub monthly_limit: u64,
+    pub daily_used: u64,
+    pub monthly_used: u64,
+    pub daily_remaining: u64,
+    pub monthly_remaining: u64,
+    pub enhanced_verification_limit: u64,
+    pub daily_reset_in_seconds: u64,
+    pub monthly_reset_in_seconds: u64,
+    pub compliance_status: String, // verified, basic, frozen, pair_disabled or limit_reached
+}
+
+impl ExchangeComplianceStatus {
+    /// Whether the router would let a swap of `amount` past its pre-quote compliance gate
+    pub fn allows_exchange(&self, amount: u64) -> bool {
+        matches!(self.compliance_status.as_str(), "verified" | "basic")
+            && amount <= self.daily_remaining.min(self.monthly_remaining)
+    }
+}
+
+/// Operation type tracked by the velocity detector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VelocityOperation {
+    Deposit,    // Volume in satoshis
+    Withdrawal, // Volume in iSTSi base units
+}
+
+/// Hold placed on a user whose activity spiked above their baseline
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperationHold {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub user: Address,
+    pub operation: VelocityOperation,
+    pub reason: String, // "volume_spike" or "frequency_spike"
+    pub window_volume: u64,
+    pub window_count: u32,
+    pub baseline_volume: u64,
+    pub placed_at: u64,
+}
+
+/// Per-user limit record a change applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LimitKind {
+    Deposit,
+    Withdrawal,
+    Exchange,
+}
+
+/// Audit entry for an edit of a user's limits
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LimitChange {
+    pub kind: LimitKind,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub changed_by: Address,
+    pub changed_at: u64,
+    pub old_daily_limit: u64,
+    pub new_daily_limit: u64,
+    pub old_monthly_limit: u64,
+    pub new_monthly_limit: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{contract, contractimpl};
+
+    #[contract]
+    struct MockRouter;
+
+    #[contractimpl]
+    impl MockRouter {
+        pub fn get_rate_limit_status(_env: Env, operator: Address) -> RateLimitStatus {
+            RateLimitStatus {
+                operator,
+                window_start: 600,
+                window_ends_at: 660,
+                deposits: 3,
+                withdrawals: 1,
+                deposits_remaining: 2,
+                withdrawals_remaining: 4,
+            }
+        }
+
+        pub fn record_metrics_snapshot(_env: Env, _caller: Address) -> bool {
+            true
+        }
+
+        pub fn report_contract_failure(env: Env, _caller: Address, target: Address, function_name: SorobanString, error_message: SorobanString) {
+            env.storage().instance().set(&target, &(function_name, error_message));
+        }
+
+        pub fn set_reserve_guardrails(env: Env, _caller: Address, guardrails: RouterReserveGuardrails) {
+            env.storage().instance().set(&soroban_sdk::symbol_short!("guard"), &guardrails);
+        }
+
+        pub fn get_reserve_guardrails(env: Env) -> Option<RouterReserveGuardrails> {
+            env.storage().instance().get(&soroban_sdk::symbol_short!("guard"))
+        }
+
+        pub fn check_reserve_guardrails(env: Env, _caller: Address, _operation: SorobanString, btc_in: u64, btc_out: u64) -> RouterGuardrailCheck {
+            // 98 BTC of reserves backing 100 BTC of supply
+            let guardrails: RouterReserveGuardrails = env.storage().instance().get(&soroban_sdk::symbol_short!("guard")).unwrap();
+            let ratio_bps = (9_800_000_000 + btc_in - btc_out) * 10_000 / (10_000_000_000 + btc_in - btc_out);
+            RouterGuardrailCheck {
+                ratio_bps,
+                min_ratio_bps: guardrails.min_ratio_bps,
+                allowed: ratio_bps >= guardrails.min_ratio_bps,
+                near_floor: ratio_bps >= guardrails.min_ratio_bps && ratio_bps < guardrails.min_ratio_bps + guardrails.warning_band_bps,
+            }
+        }
+
+        pub fn get_contract_call_stats(env: Env, address: Address) -> RouterContractCallStats {
+            let reported: Option<(SorobanString, SorobanString)> = env.storage().instance().get(&address);
+            let failed = reported.is_some() as u64;
+            RouterContractCallStats {
+                address,
+                total_calls: 4,
+                failed_calls: failed,
+                consecutive_failures: failed as u32,
+                last_called_at: 900,
+                last_execution_time: 0,
+                last_success_at: 800,
+                last_error: reported.map(|(_, error)| error).unwrap_or(SorobanString::from_str(&env, "")),
+            }
+        }
+
+        /// 1,000 iSTSi units per satoshi, far from the launch ratio
+        pub fn get_conversion_config(_env: Env) -> RouterConversionConfig {
+            RouterConversionConfig { istsi_per_satoshi: 1_000, rounding: RouterRoundingPolicy::Nearest, min_dust_sats: 546 }
+        }
+
+        pub fn get_btc_fee_rates(env: Env) -> Option<RouterBtcFeeRates> {
+            env.storage().instance().get(&soroban_sdk::symbol_short!("fee_rates"))
+        }
+
+        pub fn submit_btc_fee_rates(env: Env, submitter: Address, economy: u64, standard: u64, priority: u64) -> RouterBtcFeeRates {
+            // Stands in for the router's operator-or-oracle check: once an
+            // oracle is set, only it may publish here
+            let oracle: Option<Address> = env.storage().instance().get(&soroban_sdk::symbol_short!("fee_orc"));
+            if oracle.is_some_and(|oracle| oracle != submitter) {
+                soroban_sdk::panic_with_error!(&env, shared::IntegrationError::InsufficientPermissions);
+            }
+            let rates = RouterBtcFeeRates { economy, standard, priority, updated_by: submitter, updated_at: env.ledger().timestamp() };
+            env.storage().instance().set(&soroban_sdk::symbol_short!("fee_rates"), &rates);
+            rates
+        }
+
+        pub fn set_btc_fee_oracle(env: Env, _caller: Address, oracle: Address) {
+            env.storage().instance().set(&soroban_sdk::symbol_short!("fee_orc"), &oracle);
+        }
+
+        pub fn get_operator_nonce(env: Env, operator: Address) -> u64 {
+            env.storage().instance().get(&(soroban_sdk::symbol_short!("nonce"), operator)).unwrap_or(0)
+        }
+
+        pub fn execute_bitcoin_deposit_nonced(
+            env: Env,
+            caller: Address,
+            nonce: u64,
+            _user: Address,
+            btc_amount: u64,
+            btc_tx_hash: BytesN<32>,
+            _btc_confirmations: u32,
+        ) -> BytesN<32> {
+            // Like the router, a replayed nonce is rejected on-chain too
+            if nonce <= Self::get_operator_nonce(env.clone(), caller.clone()) {
+                soroban_sdk::panic_with_error!(&env, shared::IntegrationError::DuplicateOperation);
+            }
+            env.storage().instance().set(&(soroban_sdk::symbol_short!("nonce"), caller), &nonce);
+            env.storage().instance().set(&btc_tx_hash, &btc_amount);
+            btc_tx_hash
+        }
+
+        pub fn execute_token_withdrawal_nonced(
+            env: Env,
+            caller: Address,
+            nonce: u64,
+            _user: Address,
+            istsi_amount: u64,
+            _btc_address: SorobanString,
+        ) -> BytesN<32> {
+            env.storage().instance().set(&(soroban_sdk::symbol_short!("nonce"), caller), &nonce);
+            BytesN::from_array(&env, &[istsi_amount as u8; 32])
+        }
+
+        pub fn pre_register_deposit(env: Env, caller: Address, user: Address, btc_amount: u64, btc_tx_hash: BytesN<32>) -> RouterDepositStatus {
+            if Self::get_deposit_status_by_tx_hash(env.clone(), btc_tx_hash.clone()).is_some() {
+                soroban_sdk::panic_with_error!(&env, shared::IntegrationError::DuplicateOperation);
+            }
+            let now = env.ledger().timestamp();
+            let mut registrations = Self::get_pre_registered_deposits(env.clone());
+            registrations.push_back(RouterDepositPreRegistration {
+                btc_tx_hash: btc_tx_hash.clone(),
+                user: user.clone(),
+                btc_amount,
+                registered_by: caller,
+                registered_at: now,
+                expires_at: now + 3_600,
+            });
+            env.storage().instance().set(&soroban_sdk::symbol_short!("prereg"), &registrations);
+            let status = RouterDepositStatus {
+                btc_tx_hash: btc_tx_hash.clone(),
+                user,
+                btc_amount,
+                istsi_amount: 0,
+                confirmations: 0,
+                status: RouterDepositProcessingStatus::Pending,
+                operation_id: BytesN::from_array(&env, &[0u8; 32]),
+                created_at: now,
+                updated_at: now,
+                error_message: SorobanString::from_str(&env, ""),
+            };
+            env.storage().instance().set(&btc_tx_hash, &status);
+            status
+        }
+
+        /// Mints once three confirmations arrive
+        pub fn update_deposit_confirmations(env: Env, _caller: Address, btc_tx_hash: BytesN<32>, confirmations: u32) -> RouterDepositStatus {
+            let Some(mut status) = Self::get_deposit_status_by_tx_hash(env.clone(), btc_tx_hash.clone()) else {
+                soroban_sdk::panic_with_error!(&env, shared::IntegrationError::InvalidOperationState);
+            };
+            status.confirmations = confirmations;
+            if confirmations >= 3 {
+                status.status = RouterDepositProcessingStatus::Completed;
+                status.istsi_amount = status.btc_amount * 100_000_000;
+                status.operation_id = BytesN::from_array(&env, &[7u8; 32]);
+                let mut remaining = soroban_sdk::Vec::new(&env);
+                for registration in Self::get_pre_registered_deposits(env.clone()).iter() {
+                    if registration.btc_tx_hash != btc_tx_hash {
+                        remaining.push_back(registration);
+                    }
+                }
+                env.storage().instance().set(&soroban_sdk::symbol_short!("prereg"), &remaining);
+            }
+            env.storage().instance().set(&btc_tx_hash, &status);
+            status
+        }
+
+        pub fn expire_pre_registered_deposits(env: Env, _caller: Address) -> u32 {
+            let now = env.ledger().timestamp();
+            let mut remaining = soroban_sdk::Vec::new(&env);
+            let mut expired = 0u32;
+            for registration in Self::get_pre_registered_deposits(env.clone()).iter() {
+                if now < registration.expires_at {
+                    remaining.push_back(registration);
+                    continue;
+                }
+                let mut status = Self::get_deposit_status_by_tx_hash(env.clone(), registration.btc_tx_hash.clone()).unwrap();
+                status.status = RouterDepositProcessingStatus::Expired;
+                status.error_message = SorobanString::from_str(&env, "Deposit was not confirmed before pre-registration expired");
+                env.storage().instance().set(&registration.btc_tx_hash, &status);
+                expired += 1;
+            }
+            env.storage().instance().set(&soroban_sdk::symbol_short!("prereg"), &remaining);
+            expired
+        }
+
+        pub fn get_deposit_pre_registration(env: Env, btc_tx_hash: BytesN<32>) -> Option<RouterDepositPreRegistration> {
+            Self::get_pre_registered_deposits(env).iter().find(|registration| registration.btc_tx_hash == btc_tx_hash)
+        }
+
+        pub fn get_pre_registered_deposits(env: Env) -> soroban_sdk::Vec<RouterDepositPreRegistration> {
+            env.storage().instance().get(&soroban_sdk::symbol_short!("prereg")).unwrap_or(soroban_sdk::Vec::new(&env))
+        }
+
+        pub fn get_deposit_status_by_tx_hash(env: Env, btc_tx_hash: BytesN<32>) -> Option<RouterDepositStatus> {
+            env.storage().instance().get(&btc_tx_hash)
+        }
+
+        pub fn set_withdrawal_approval_config(env: Env, _caller: Address, config: RouterWithdrawalApprovalConfig) {
+            env.storage().instance().set(&soroban_sdk::symbol_short!("wd_cfg"), &config);
+        }
+
+        pub fn get_withdrawal_approval_config(env: Env) -> Option<RouterWithdrawalApprovalConfig> {
+            env.storage().instance().get(&soroban_sdk::symbol_short!("wd_cfg"))
+        }
+
+        /// Holds a withdrawal for approval the way the router does above the threshold
+        pub fn hold_withdrawal(env: Env, initiator: Address, user: Address, btc_amount: u64) -> BytesN<32> {
+            let config = Self::get_withdrawal_approval_config(env.clone()).unwrap();
+            let now = env.ledger().timestamp();
+            let mut approvals = Self::get_pending_withdrawal_approvals(env.clone());
+            let withdrawal_id = BytesN::from_array(&env, &[approvals.len() as u8 + 1; 32]);
+            approvals.push_back(RouterWithdrawalApproval {
+                withdrawal_id: withdrawal_id.clone(),
+                operation_id: withdrawal_id.clone(),
+                initiator,
+                user,
+                istsi_amount: btc_amount * 100_000_000,
+                net_istsi_amount: btc_amount * 100_000_000,
+                withdrawal_fee: 0,
+                btc_amount,
+                btc_address: SorobanString::from_str(&env, "bc1qapproval"),
+                correlation_id: withdrawal_id.clone(),
+                requested_at: now,
+                expires_at: now + config.approval_window,
+            });
+            env.storage().instance().set(&soroban_sdk::symbol_short!("wd_held"), &approvals);
+            withdrawal_id
+        }
+
+        fn release_withdrawal(env: &Env, withdrawal_id: &BytesN<32>) {
+            let mut remaining = soroban_sdk::Vec::new(env);
+            for approval in Self::get_pending_withdrawal_approvals(env.clone()).iter() {
+                if approval.withdrawal_id != *withdrawal_id {
+                    remaining.push_back(approval);
+                }
+            }
+            env.storage().instance().set(&soroban_sdk::symbol_short!("wd_held"), &remaining);
+        }
+
+        pub fn approve_withdrawal(env: Env, approver: Address, withdrawal_id: BytesN<32>) {
+            let Some(approval) = Self::get_withdrawal_approval(env.clone(), withdrawal_id.clone()) else {
+                soroban_sdk::panic_with_error!(&env, shared::IntegrationError::InvalidOperationState);
+            };
+            if approver == approval.initiator {
+                soroban_sdk::panic_with_error!(&env, shared::IntegrationError::InsufficientPermissions);
+            }
+            if env.ledger().timestamp() >= approval.expires_at {
+                soroban_sdk::panic_with_error!(&env, shared::IntegrationError::OperationTimeout);
+            }
+            Self::release_withdrawal(&env, &withdrawal_id);
+        }
+
+        pub fn expire_withdrawal_approvals(env: Env, _caller: Address, max_items: u32) -> u32 {
+            let now = env.ledger().timestamp();
+            let mut expired = 0u32;
+            for approval in Self::get_pending_withdrawal_approvals(env.clone()).iter() {
+                if expired < max_items && now >= approval.expires_at {
+                    Self::release_withdrawal(&env, &approval.withdrawal_id);
+                    expired += 1;
+                }
+            }
+            expired
+        }
+
+        pub fn get_withdrawal_approval(env: Env, withdrawal_id: BytesN<32>) -> Option<RouterWithdrawalApproval> {
+            Self::get_pending_withdrawal_approvals(env).iter().find(|approval| approval.withdrawal_id == withdrawal_id)
+        }
+
+        pub fn get_pending_withdrawal_approvals(env: Env) -> soroban_sdk::Vec<RouterWithdrawalApproval> {
+            env.storage().instance().get(&soroban_sdk::symbol_short!("wd_held")).unwrap_or(soroban_sdk::Vec::new(&env))
+        }
+
+        /// Snapshots every 100s from 100 to 500, two per page like a capped router
+        pub fn get_metrics_history(env: Env, from: u64, to: u64, resolution: u64) -> soroban_sdk::Vec<RouterMetricsSnapshot> {
+            let mut page = soroban_sdk::Vec::new(&env);
+            let mut last: Option<u64> = None;
+            for sequence in 0..5u64 {
+                let timestamp = (sequence + 1) * 100;
+                let spaced = last.is_none_or(|last| timestamp >= last + resolution);
+                if timestamp < from || timestamp > to || !spaced || page.len() == 2 {
+                    continue;
+                }
+                last = Some(timestamp);
+                page.push_back(RouterMetricsSnapshot {
+                    sequence,
+                    ledger: sequence as u32 * 20,
+                    metrics: RouterSystemMetrics {
+                        total_operations: sequence * 10,
+                        successful_operations: sequence * 9,
+                        failed_operations: sequence,
+                        average_processing_time: 0,
+                        deposit_processing_time: 0,
+                        withdrawal_processing_time: 0,
+                        exchange_processing_time: 0,
+                        batch_processing_time: 0,
+                        current_reserve_ratio: 10_000,
+                        active_users_24h: 0,
+                        pending_operations: 2,
+                        withdrawal_queue_depth: 1,
+                        last_updated: timestamp,
+                    },
+                });
+            }
+            page
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_status_reads_the_contract() {
+        let env = Env::default();
+        let client = IntegrationRouterClient::new(env.clone(), env.register(MockRouter, ()));
+
+        let operator = Address::generate(&env);
+        let status = client.get_rate_limit_status(&operator).unwrap();
+        assert_eq!(status.operator, operator);
+        assert_eq!((status.window_start, status.window_ends_at), (600, 660));
+        assert_eq!((status.deposits_remaining, status.withdrawals_remaining), (2, 4));
+
+        // An address with no router behind it is a call failure, not defaults
+        let unreachable = IntegrationRouterClient::new(env.clone(), Address::generate(&env));
+        assert!(matches!(unreachable.get_rate_limit_status(&operator), Err(ContractError::NetworkError(_))));
+    }
+
+    #[test]
+    fn test_reported_failures_show_in_contract_stats() {
+        let env = Env::default();
+        let client = IntegrationRouterClient::new(env.clone(), env.register(MockRouter, ()));
+        let ctx = OperationContext { caller: Address::generate(&env), ..OperationContext::default() };
+        let kyc = Address::generate(&env);
+
+        assert_eq!(client.get_contract_call_stats(&kyc).unwrap().failed_calls, 0);
+        client.report_contract_failure(&ctx, &kyc, "verify_ic", "reverted: ComplianceCheckFailed").unwrap();
+
+        let stats = client.get_contract_call_stats(&kyc).unwrap();
+        assert_eq!((stats.total_calls, stats.failed_calls, stats.consecutive_failures), (4, 1, 1));
+        assert_eq!(stats.last_error, "reverted: ComplianceCheckFailed");
+        assert_eq!(stats.error_rate(), 250);
+    }
+
+    #[test]
+    fn test_guardrail_checks_reach_the_router() {
+        let env = Env::default();
+        let client = IntegrationRouterClient::new(env.clone(), env.register(MockRouter, ()));
+        let ctx = OperationContext { caller: Address::generate(&env), ..OperationContext::default() };
+
+        assert_eq!(client.get_reserve_guardrails().unwrap(), None);
+        let guardrails = ReserveGuardrails { min_ratio_bps: 9900, warning_band_bps: 50 };
+        client.set_reserve_guardrails(&ctx, &guardrails).unwrap();
+        assert_eq!(client.get_reserve_guardrails().unwrap(), Some(guardrails.clone()));
+
+        // Minting 50 BTC leaves 148 BTC backing 150 BTC of supply
+        let check = client.check_reserve_guardrails(&ctx, "bitcoin_deposit", 5_000_000_000, 0).unwrap();
+        assert_eq!(check, GuardrailCheck { ratio_bps: 9866, min_ratio_bps: 9900, allowed: false, near_floor: false });
+
+        let check = client.check_reserve_guardrails(&ctx, "bitcoin_deposit", 10_000_000_000, 0).unwrap();
+        assert!(check.allowed && check.near_floor && guardrails.is_near_miss(check.ratio_bps));
+    }
+
+    #[test]
+    fn test_metrics_history_follows_router_pages() {
+        let env = Env::default();
+        let client = IntegrationRouterClient::new(env.clone(), env.register(MockRouter, ()));
+        let ctx = OperationContext { caller: Address::generate(&env), ..OperationContext::default() };
+
+        assert!(client.record_metrics_snapshot(&ctx).unwrap());
+
+        let history = client.get_metrics_history(0, u64::MAX, 0).unwrap();
+        let timestamps: Vec<u64> = history.iter().map(|snapshot| snapshot.timestamp).collect();
+        assert_eq!(timestamps, alloc::vec![100, 200, 300, 400, 500]);
+        assert_eq!(history[4].operations_since(&history[0]), 40);
+        assert_eq!(history[4].queue_depth(), 3);
+
+        let coarse = client.get_metrics_history(150, 500, 200).unwrap();
+        let timestamps: Vec<u64> = coarse.iter().map(|snapshot| snapshot.timestamp).collect();
+        assert_eq!(timestamps, alloc::vec![200, 400]);
+        assert!(client.get_metrics_history(500, 100, 0).is_err());
+    }
+
+    #[test]
+    fn test_nonced_calls_check_and_advance_the_router_nonce() {
+        let env = Env::default();
+        let client = IntegrationRouterClient::new(env.clone(), env.register(MockRouter, ()));
+        let ctx = OperationContext { caller: Address::generate(&env), ..OperationContext::default() };
+        let user = Address::generate(&env);
+        let tx_hash = BytesN::from_array(&env, &[7u8; 32]);
+
+        assert_eq!(client.get_operator_nonce(&ctx.caller).unwrap(), 0);
+        assert_eq!(client.execute_bitcoin_deposit_nonced(&ctx, 1, &user, 50_000, &tx_hash, 6).unwrap(), tx_hash);
+        assert_eq!(client.get_operator_nonce(&ctx.caller).unwrap(), 1);
+
+        // A replay is refused before it is submitted
+        assert!(matches!(
+            client.execute_bitcoin_deposit_nonced(&ctx, 1, &user, 50_000, &tx_hash, 6),
+            Err(ContractError::Integration(shared::IntegrationError::DuplicateOperation))
+        ));
+        client.execute_token_withdrawal_nonced(&ctx, 5, &user, 9, "bc1qexample").unwrap();
+        assert_eq!(client.get_operator_nonce(&ctx.caller).unwrap(), 5);
+        assert!(client.execute_token_withdrawal_nonced(&ctx, 4, &user, 9, "bc1qexample").is_err());
+
+        // Other operators keep their own sequence
+        let other = OperationContext { caller: Address::generate(&env), ..OperationContext::default() };
+        assert_eq!(client.get_operator_nonce(&other.caller).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_withdrawal_fee_quotes_use_router_conversion_and_rates() {
+        let env = Env::default();
+        let client = IntegrationRouterClient::new(env.clone(), env.register(MockRouter, ()));
+        let ctx = OperationContext { caller: Address::generate(&env), ..OperationContext::default() };
+
+        let config = client.get_conversion_config().unwrap();
+        assert_eq!((config.istsi_per_satoshi, config.rounding, config.min_dust_sats), (1_000, RoundingPolicy::Nearest, 546));
+
+        // Without published rates the quote is free but flagged stale
+        let quote = client.quote_withdrawal_fee(50_000_000, BtcFeeTier::Standard).unwrap();
+        assert_eq!((quote.btc_amount, quote.network_fee_sats, quote.stale), (50_000, 0, true));
+
+        env.ledger().with_mut(|li| li.timestamp = 10_000);
+        let rates = client.submit_btc_fee_rates(&ctx, 5, 10, 20).unwrap();
+        assert_eq!((rates.updated_by.clone(), rates.updated_at), (ctx.caller.clone(), 10_000));
+        assert_eq!(client.get_btc_fee_rates().unwrap(), Some(rates));
+
+        let quote = client.quote_withdrawal_fee(50_000_000, BtcFeeTier::Priority).unwrap();
+        assert_eq!((quote.fee_rate, quote.network_fee_sats, quote.stale), (20, 20 * WITHDRAWAL_TX_VBYTES, false));
+        assert_eq!(quote.net_btc_amount, 50_000 - 20 * WITHDRAWAL_TX_VBYTES);
+
+        env.ledger().with_mut(|li| li.timestamp = 10_000 + BTC_FEE_RATE_MAX_AGE + 1);
+        assert!(client.quote_withdrawal_fee(50_000_000, BtcFeeTier::Priority).unwrap().stale);
+
+        // Router rejections come back as errors rather than echoed rates
+        let oracle = OperationContext { caller: Address::generate(&env), ..OperationContext::default() };
+        client.set_btc_fee_oracle(&ctx, &oracle.caller).unwrap();
+        assert!(matches!(
+            client.submit_btc_fee_rates(&ctx, 5, 10, 20),
+            Err(ContractError::Integration(shared::IntegrationError::InsufficientPermissions))
+        ));
+        assert_eq!(client.submit_btc_fee_rates(&oracle, 6, 12, 24).unwrap().updated_by, oracle.caller);
+    }
+
+    #[test]
+    fn test_pre_registered_deposits_follow_router_state() {
+        let env = Env::default();
+        let client = IntegrationRouterClient::new(env.clone(), env.register(MockRouter, ()));
+        let ctx = OperationContext { caller: Address::generate(&env), ..OperationContext::default() };
+        let user = Address::generate(&env);
+        let confirmed = BytesN::from_array(&env, &[1u8; 32]);
+        let abandoned = BytesN::from_array(&env, &[2u8; 32]);
+
+        assert!(matches!(
+            client.pre_register_deposit(&ctx, &user, 0, &confirmed),
+            Err(ContractError::Validation(shared::ValidationError::InvalidAmount))
+        ));
+        assert_eq!(client.get_deposit_status_by_tx_hash(&confirmed).unwrap(), None);
+
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let status = client.pre_register_deposit(&ctx, &user, 50_000, &confirmed).unwrap();
+        assert_eq!((status.status, status.created_at), (DepositProcessingStatus::Pending, 1_000));
+        client.pre_register_deposit(&ctx, &user, 20_000, &abandoned).unwrap();
+        assert!(matches!(
+            client.pre_register_deposit(&ctx, &user, 50_000, &confirmed),
+            Err(ContractError::Integration(shared::IntegrationError::DuplicateOperation))
+        ));
+
+        let registration = client.get_deposit_pre_registration(&confirmed).unwrap().unwrap();
+        assert_eq!((registration.registered_by, registration.expires_at), (ctx.caller.clone(), 4_600));
+        assert_eq!(client.get_pre_registered_deposits().unwrap().len(), 2);
+
+        assert_eq!(client.update_deposit_confirmations(&ctx, &confirmed, 1).unwrap().confirmations, 1);
+        let status = client.update_deposit_confirmations(&ctx, &confirmed, 3).unwrap();
+        assert_eq!((status.status, status.istsi_amount), (DepositProcessingStatus::Completed, 5_000_000_000_000));
+        assert_eq!(client.get_deposit_status_by_tx_hash(&confirmed).unwrap(), Some(status));
+        assert_eq!(client.get_deposit_pre_registration(&confirmed).unwrap(), None);
+        assert!(matches!(
+            client.update_deposit_confirmations(&ctx, &BytesN::from_array(&env, &[3u8; 32]), 1),
+            Err(ContractError::Integration(shared::IntegrationError::InvalidOperationState))
+        ));
+
+        assert_eq!(client.expire_pre_registered_deposits(&ctx).unwrap(), 0);
+        env.ledger().with_mut(|li| li.timestamp = 4_600);
+        assert_eq!(client.expire_pre_registered_deposits(&ctx).unwrap(), 1);
+        let status = client.get_deposit_status_by_tx_hash(&abandoned).unwrap().unwrap();
+        assert_eq!(status.status, DepositProcessingStatus::Expired);
+        assert!(!status.error_message.is_empty());
+        assert!(client.get_pre_registered_deposits().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_withdrawal_approvals_are_decided_by_the_router() {
+        let env = Env::default();
+        let router = env.register(MockRouter, ());
+        let client = IntegrationRouterClient::new(env.clone(), router.clone());
+        let mock = MockRouterClient::new(&env, &router);
+        let operator = OperationContext { caller: Address::generate(&env), ..OperationContext::default() };
+        let approver = OperationContext { caller: Address::generate(&env), ..OperationContext::default() };
+        let user = Address::generate(&env);
+
+        assert_eq!(client.get_withdrawal_approval_config().unwrap(), None);
+        assert!(matches!(
+            client.set_withdrawal_approval_config(&operator, &WithdrawalApprovalConfig { threshold_sats: 1_000_000, approval_window: 0 }),
+            Err(ContractError::Validation(shared::ValidationError::InvalidParameters))
+        ));
+        let config = WithdrawalApprovalConfig { threshold_sats: 1_000_000, approval_window: 3_600 };
+        client.set_withdrawal_approval_config(&operator, &config).unwrap();
+        assert_eq!(client.get_withdrawal_approval_config().unwrap(), Some(config));
+
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let approved = mock.hold_withdrawal(&operator.caller, &user, &2_000_000);
+        let abandoned = mock.hold_withdrawal(&operator.caller, &user, &3_000_000);
+        let held = client.get_withdrawal_approval(&approved).unwrap().unwrap();
+        assert_eq!((held.initiator, held.expires_at, held.btc_address.as_str()), (operator.caller.clone(), 4_600, "bc1qapproval"));
+        assert_eq!(client.get_pending_withdrawal_approvals().unwrap().len(), 2);
+
+        assert!(matches!(
+            client.approve_withdrawal(&operator, &approved),
+            Err(ContractError::Integration(shared::IntegrationError::InsufficientPermissions))
+        ));
+        client.approve_withdrawal(&approver, &approved).unwrap();
+        assert_eq!(client.get_withdrawal_approval(&approved).unwrap(), None);
+        assert!(matches!(
+            client.approve_withdrawal(&approver, &approved),
+            Err(ContractError::Integration(shared::IntegrationError::InvalidOperationState))
+        ));
+
+        assert_eq!(client.expire_withdrawal_approvals(&operator, 10).unwrap(), 0);
+        env.ledger().with_mut(|li| li.timestamp = 4_600);
+        assert!(matches!(
+            client.approve_withdrawal(&approver, &abandoned),
+            Err(ContractError::Integration(shared::IntegrationError::OperationTimeout))
+        ));
+        assert_eq!(client.expire_withdrawal_approvals(&operator, 10).unwrap(), 1);
+        assert!(client.get_pending_withdrawal_approvals().unwrap().is_empty());
+    }
+}
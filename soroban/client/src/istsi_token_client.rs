@@ -1,6 +1,34 @@
 use soroban_sdk::{Address, Env, BytesN};
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use crate::{ContractClient, ContractResult, ContractError, OperationContext};
+use crate::reporting::{address_to_string, BalanceStatement};
+
+/// Functions of the SEP-41 token interface wallets call on a Soroban token
+pub const SEP41_FUNCTIONS: &[&str] = &[
+    "allowance",
+    "approve",
+    "balance",
+    "transfer",
+    "transfer_from",
+    "burn",
+    "burn_from",
+    "decimals",
+    "name",
+    "symbol",
+];
+
+/// SEP-41 functions missing from a contract's exported function names
+/// 
+/// Run against the exports registered for a token Wasm before pointing
+/// wallets at it; an empty result means the interface is complete.
+pub fn missing_sep41_functions(exports: &[&str]) -> Vec<&'static str> {
+    SEP41_FUNCTIONS
+        .iter()
+        .copied()
+        .filter(|function| !exports.contains(function))
+        .collect()
+}
 
 /// Client interface for the iSTSi Token contract
 /// 
@@ -35,6 +63,21 @@ impl IstsiTokenClient {
         Ok(1_000_000_000) // 10 tokens with 8 decimals
     }
 
+    /// Get the token name (SEP-41)
+    pub fn name(&self) -> ContractResult<String> {
+        Ok(self.get_metadata()?.name)
+    }
+
+    /// Get the token symbol (SEP-41)
+    pub fn symbol(&self) -> ContractResult<String> {
+        Ok(self.get_metadata()?.symbol)
+    }
+
+    /// Get the number of decimals in token amounts (SEP-41)
+    pub fn decimals(&self) -> ContractResult<u32> {
+        Ok(self.get_metadata()?.decimals)
+    }
+
     /// Get total token supply
     /// 
     /// # Returns
@@ -86,6 +129,149 @@ impl IstsiTokenClient {
         Ok(())
     }
 
+    /// Allow `spender` to transfer up to `amount` of the owner's tokens
+    /// 
+    /// The approval lapses at `live_until_ledger`, which the contract caps at
+    /// `get_max_approval_ledgers` ledgers from the current one. An amount of 0
+    /// revokes the approval.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller is the token owner)
+    /// * `spender` - Address allowed to spend
+    /// * `amount` - Maximum amount the spender may transfer
+    /// * `live_until_ledger` - Ledger after which the approval expires
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn approve(
+        &self,
+        ctx: &OperationContext,
+        spender: &Address,
+        amount: u64,
+        live_until_ledger: u32,
+    ) -> ContractResult<()> {
+        if *spender == ctx.caller {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+        if amount > 0 && live_until_ledger < self.env.ledger().sequence() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("approve"), ctx.caller.clone(), spender.clone()),
+            (amount, live_until_ledger)
+        );
+        
+        Ok(())
+    }
+
+    /// Get the amount `spender` may still transfer from `owner` (0 once expired)
+    pub fn allowance(&self, owner: &Address, spender: &Address) -> ContractResult<u64> {
+        // In a real implementation, this would query the contract
+        Ok(0)
+    }
+
+    /// Get the ledger an allowance stays live until (0 when there is no live allowance)
+    pub fn get_allowance_expiry(&self, owner: &Address, spender: &Address) -> ContractResult<u32> {
+        // In a real implementation, this would query the contract
+        Ok(0)
+    }
+
+    /// Get the longest an approval may stay live, in ledgers
+    pub fn get_max_approval_ledgers(&self) -> ContractResult<u32> {
+        // In a real implementation, this would query the contract
+        Ok(30 * 17_280)
+    }
+
+    /// Transfer tokens on behalf of an owner under an approval
+    /// 
+    /// The spender, owner and recipient all go through the same freeze and
+    /// compliance checks as a direct transfer.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller is the spender)
+    /// * `from` - Owner whose allowance is spent
+    /// * `to` - Destination address
+    /// * `amount` - Amount to transfer
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn transfer_from(
+        &self,
+        ctx: &OperationContext,
+        from: &Address,
+        to: &Address,
+        amount: u64,
+    ) -> ContractResult<()> {
+        if amount == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidAmount
+            ));
+        }
+        
+        if from == to {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("txf_from"), from.clone(), to.clone()),
+            (ctx.caller.clone(), amount)
+        );
+        
+        Ok(())
+    }
+
+    /// Burn the caller's tokens (SEP-41)
+    /// 
+    /// This does not release Bitcoin; use `burn_for_btc_withdrawal` for that.
+    pub fn burn(&self, ctx: &OperationContext, amount: u64) -> ContractResult<()> {
+        if amount == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidAmount
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("burn"), ctx.caller.clone()),
+            amount
+        );
+        
+        Ok(())
+    }
+
+    /// Burn tokens on behalf of an owner under an approval (SEP-41)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context (caller is the spender)
+    /// * `from` - Owner whose allowance is spent
+    /// * `amount` - Amount to burn
+    pub fn burn_from(&self, ctx: &OperationContext, from: &Address, amount: u64) -> ContractResult<()> {
+        if amount == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidAmount
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("burn"), from.clone()),
+            (ctx.caller.clone(), amount)
+        );
+        
+        Ok(())
+    }
+
     /// Mint tokens with Bitcoin transaction linking
     /// 
     /// # Arguments
@@ -303,6 +489,56 @@ impl IstsiTokenClient {
         })
     }
 
+    /// Get an account's balance at a point in time
+    /// 
+    /// # Returns
+    /// * `Ok(Some(balance))` - Balance as of the latest snapshot at or before `timestamp`
+    /// * `Ok(None)` - `timestamp` predates the account's retained snapshots
+    /// * `Err(ContractError)` - Error details
+    pub fn balance_at(&self, account: &Address, timestamp: u64) -> ContractResult<Option<u64>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get an account's balance snapshots taken in `[start, end)`, oldest first
+    pub fn get_balance_snapshots(&self, account: &Address, start: u64, end: u64) -> ContractResult<Vec<BalanceSnapshot>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Build an account statement for `[period_start, period_end)` from balance snapshots
+    pub fn balance_statement(&self, account: &Address, period_start: u64, period_end: u64) -> ContractResult<BalanceStatement> {
+        let opening_balance = self.balance_at(account, period_start.saturating_sub(1))?;
+        let snapshots = self.get_balance_snapshots(account, period_start, period_end)?;
+        Ok(BalanceStatement::from_snapshots(
+            &address_to_string(account),
+            (period_start, period_end),
+            opening_balance,
+            &snapshots,
+        ))
+    }
+
+    /// Get the account seized tokens are moved into
+    pub fn get_seizure_escrow(&self) -> ContractResult<Option<Address>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get a clawback from the token's public audit trail
+    /// 
+    /// # Arguments
+    /// * `clawback_id` - Clawback ID, starting at 1
+    pub fn get_clawback(&self, clawback_id: u64) -> ContractResult<Option<ClawbackRecord>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Page through executed clawbacks, oldest first
+    pub fn get_clawbacks(&self, start_id: u64, limit: u32) -> ContractResult<Vec<ClawbackRecord>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
     /// Helper function to generate request IDs
     fn generate_request_id(&self, operation_type: &str, amount: u64) -> BytesN<32> {
         let timestamp = self.env.ledger().timestamp();
@@ -339,43 +575,126 @@ impl ContractClient for IstsiTokenClient {
 
 /// Integrated mint record structure
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntegratedMintRecord {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
     pub btc_tx_hash: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
     pub recipient: Address,
     pub amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
     pub compliance_proof: BytesN<32>,
     pub reserve_validation: bool,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
     pub correlation_id: BytesN<32>,
     pub timestamp: u64,
 }
 
 /// Integrated burn record structure
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntegratedBurnRecord {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
     pub request_id: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
     pub from_address: Address,
     pub amount: u64,
     pub btc_address: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
     pub compliance_proof: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
     pub correlation_id: BytesN<32>,
     pub timestamp: u64,
 }
 
+/// Clawback record from the token's audit trail
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClawbackRecord {
+    pub clawback_id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub target: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub escrow: Address,
+    pub amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub evidence_hash: BytesN<32>,
+    /// Router clawback proposal that authorized the seizure
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub case_id: BytesN<32>,
+    pub executed_at: u64,
+}
+
+/// Account balance as of the last change in a snapshot epoch
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BalanceSnapshot {
+    pub epoch: u64,
+    pub timestamp: u64,
+    pub balance: u64,
+}
+
 /// Integration status structure
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntegrationStatus {
     pub router_set: bool,
     pub auto_compliance: bool,
     pub cross_contract: bool,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
     pub integration_router: Option<Address>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
     pub reserve_manager: Option<Address>,
 }
 
 /// Token metadata structure
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenMetadata {
     pub name: String,
     pub symbol: String,
     pub decimals: u32,
     pub total_supply: u64,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    // Entry points of the iSTSi token Wasm relevant to wallets and the router
+    const ISTSI_EXPORTS: &[&str] = &[
+        "allowance", "approve", "balance", "burn", "burn_for_btc_withdrawal", "burn_from", "clawback",
+        "compliance_transfer", "decimals", "get_allowance_expiry", "mint_with_btc_link", "name", "symbol",
+        "total_supply", "transfer", "transfer_from",
+    ];
+
+    #[test]
+    fn test_token_exports_cover_sep41() {
+        assert!(missing_sep41_functions(ISTSI_EXPORTS).is_empty());
+
+        let without_delegation: Vec<&str> = ISTSI_EXPORTS
+            .iter()
+            .copied()
+            .filter(|function| !function.ends_with("_from"))
+            .collect();
+        assert_eq!(missing_sep41_functions(&without_delegation), ["transfer_from", "burn_from"]);
+    }
+
+    #[test]
+    fn test_client_sep41_calls_match_contract_shapes() {
+        let env = Env::default();
+        let client = IstsiTokenClient::new(env.clone(), Address::generate(&env));
+        let metadata = client.get_metadata().unwrap();
+        assert_eq!(client.name().unwrap(), metadata.name);
+        assert_eq!(client.symbol().unwrap(), metadata.symbol);
+        assert_eq!(client.decimals().unwrap(), 8);
+
+        let ctx = OperationContext { caller: Address::generate(&env), ..OperationContext::default() };
+        let owner = Address::generate(&env);
+        assert!(client.burn(&ctx, 0).is_err());
+        assert!(client.burn_from(&ctx, &owner, 100).is_ok());
+        assert!(client.approve(&ctx, &ctx.caller, 100, 10).is_err());
+        assert!(client.approve(&ctx, &owner, 0, 0).is_ok());
+    }
+}
@@ -0,0 +1,497 @@
+//! Scheduled keeper framework
+//!
+//! The router exposes several maintenance entry points (auto-reconciliation,
+//! scheduled proof generation, timeout sweeping, limit resets, order matching,
+//! maintenance window transitions, event history pruning) that only run when someone calls them.
+//! `KeeperService` tracks which of these tasks are due and executes them with
+//! the operator's context. It does not own a timer: the host scheduler calls
+//! `run_due` periodically and can use `next_due_at` to decide when to wake up.
+//!
+//! `TtlBumpScheduler` covers the storage side: it tracks when long-lived
+//! router entries expire and extends them through `extend_ttl_batch` before
+//! they are archived.
+
+use alloc::collections::BTreeMap as HashMap;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+use alloc::format;
+use crate::{ContractResult, ContractError, ContractManager, OperationContext};
+use crate::integration_router_client::TtlKey;
+
+/// Ledgers per day at the 5-second close time
+pub const DAY_IN_LEDGERS: u32 = 17280;
+/// Lifetime the router gives an entry it extends
+pub const ROUTER_TTL_EXTEND_TO: u32 = 120 * DAY_IN_LEDGERS;
+
+/// Periodic maintenance task executed against the router
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeeperTask {
+    Reconciliation,
+    ProofGeneration,
+    TimeoutSweep,
+    LimitReset,
+    OrderMatching,
+    MetricsSnapshot,
+    AlertEscalation,
+    MaintenanceStatus,
+    EventPruning,
+}
+
+impl KeeperTask {
+    /// Stable task name for logs and metrics
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeeperTask::Reconciliation => "reconciliation",
+            KeeperTask::ProofGeneration => "proof_generation",
+            KeeperTask::TimeoutSweep => "timeout_sweep",
+            KeeperTask::LimitReset => "limit_reset",
+            KeeperTask::OrderMatching => "order_matching",
+            KeeperTask::MetricsSnapshot => "metrics_snapshot",
+            KeeperTask::AlertEscalation => "alert_escalation",
+            KeeperTask::MaintenanceStatus => "maintenance_status",
+            KeeperTask::EventPruning => "event_pruning",
+        }
+    }
+}
+
+/// Scheduling configuration for a keeper task
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeeperTaskConfig {
+    pub interval_seconds: u64,
+    pub enabled: bool,
+}
+
+/// Last-run state for a keeper task
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeeperTaskState {
+    pub last_run_at: Option<u64>,
+    pub last_success_at: Option<u64>,
+    pub run_count: u64,
+    pub failure_count: u64,
+    pub consecutive_failures: u32,
+    pub items_processed: u64,
+    pub last_error: Option<ContractError>,
+}
+
+/// Outcome of a single keeper task execution
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeeperTaskReport {
+    pub task: KeeperTask,
+    pub started_at: u64,
+    pub items_processed: u32,
+    pub error: Option<ContractError>,
+}
+
+impl KeeperTaskReport {
+    /// Whether the task completed without error
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Aggregate keeper metrics
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeeperMetrics {
+    pub total_runs: u64,
+    pub total_failures: u64,
+    pub total_items_processed: u64,
+    pub tasks_failing: u32,
+}
+
+/// Keeper service that executes due maintenance tasks against the router
+pub struct KeeperService {
+    ctx: OperationContext,
+    sweep_batch_size: u32,
+    match_batch_size: u32,
+    pruned_event_types: Vec<&'static str>,
+    tasks: HashMap<KeeperTask, (KeeperTaskConfig, KeeperTaskState)>,
+    hooks: Vec<Box<dyn Fn(&KeeperTaskReport)>>,
+}
+
+impl KeeperService {
+    /// Create a keeper service that acts with the given operator context
+    ///
+    /// # Arguments
+    /// * `ctx` - Operation context carrying the operator key
+    pub fn new(ctx: OperationContext) -> Self {
+        Self {
+            ctx,
+            sweep_batch_size: 50,
+            match_batch_size: 25,
+            pruned_event_types: alloc::vec![
+                "BitcoinDeposit",
+                "TokenWithdrawal",
+                "ComplianceAction",
+                "ReserveUpdate",
+                "CrossTokenExchange",
+                "LimitOrderFill",
+                "SystemStateChange",
+                "ContractInteraction",
+            ],
+            tasks: HashMap::new(),
+            hooks: Vec::new(),
+        }
+    }
+
+    /// Create a keeper service with all tasks registered at default intervals
+    pub fn with_default_tasks(ctx: OperationContext) -> Self {
+        let mut keeper = Self::new(ctx);
+        keeper.register_task(KeeperTask::Reconciliation, 3600);
+        keeper.register_task(KeeperTask::ProofGeneration, 86400);
+        keeper.register_task(KeeperTask::TimeoutSweep, 60);
+        keeper.register_task(KeeperTask::LimitReset, 3600);
+        keeper.register_task(KeeperTask::OrderMatching, 30);
+        keeper.register_task(KeeperTask::MetricsSnapshot, 300);
+        keeper.register_task(KeeperTask::AlertEscalation, 60);
+        keeper.register_task(KeeperTask::MaintenanceStatus, 60);
+        keeper.register_task(KeeperTask::EventPruning, 3600);
+        keeper
+    }
+
+    /// Set the batch size used for each timeout sweep call
+    pub fn with_sweep_batch_size(mut self, batch_size: u32) -> Self {
+        self.sweep_batch_size = batch_size;
+        self
+    }
+
+    /// Set the maximum number of fills executed by each order matching call
+    pub fn with_match_batch_size(mut self, batch_size: u32) -> Self {
+        self.match_batch_size = batch_size;
+        self
+    }
+
+    /// Set the event types whose expired history is pruned by the event pruning task
+    pub fn with_pruned_event_types(mut self, event_types: Vec<&'static str>) -> Self {
+        self.pruned_event_types = event_types;
+        self
+    }
+
+    /// Register (or reschedule) a periodic task
+    ///
+    /// # Arguments
+    /// * `task` - Task to run
+    /// * `interval_seconds` - Minimum time between runs
+    pub fn register_task(&mut self, task: KeeperTask, interval_seconds: u64) {
+        let config = KeeperTaskConfig {
+            interval_seconds,
+            enabled: true,
+        };
+
+        match self.tasks.get_mut(&task) {
+            Some(entry) => entry.0 = config,
+            None => {
+                self.tasks.insert(task, (config, KeeperTaskState::default()));
+            }
+        }
+    }
+
+    /// Remove a task from the schedule
+    pub fn unregister_task(&mut self, task: KeeperTask) {
+        self.tasks.remove(&task);
+    }
+
+    /// Enable or disable a registered task without losing its state
+    ///
+    /// # Returns
+    /// * `Ok(())` - Task updated
+    /// * `Err(ContractError)` - Task is not registered
+    pub fn set_task_enabled(&mut self, task: KeeperTask, enabled: bool) -> ContractResult<()> {
+        match self.tasks.get_mut(&task) {
+            Some(entry) => {
+                entry.0.enabled = enabled;
+                Ok(())
+            }
+            None => Err(ContractError::ParseError(format!("keeper task not registered: {}", task.name()))),
+        }
+    }
+
+    /// Register a hook called after every task execution
+    pub fn on_task_complete<F>(&mut self, hook: F)
+    where
+        F: Fn(&KeeperTaskReport) + 'static,
+    {
+        self.hooks.push(Box::new(hook));
+    }
+
+    /// Get the last-run state for a task
+    pub fn task_state(&self, task: KeeperTask) -> Option<&KeeperTaskState> {
+        self.tasks.get(&task).map(|(_, state)| state)
+    }
+
+    /// List tasks that are due at `now`
+    pub fn due_tasks(&self, now: u64) -> Vec<KeeperTask> {
+        self.tasks
+            .iter()
+            .filter(|(_, (config, state))| Self::is_due(config, state, now))
+            .map(|(task, _)| *task)
+            .collect()
+    }
+
+    /// Earliest time any enabled task becomes due
+    pub fn next_due_at(&self) -> Option<u64> {
+        self.tasks
+            .values()
+            .filter(|(config, _)| config.enabled)
+            .map(|(config, state)| match state.last_run_at {
+                Some(last_run) => last_run.saturating_add(config.interval_seconds),
+                None => 0,
+            })
+            .min()
+    }
+
+    /// Execute every task that is due
+    ///
+    /// Failures are recorded per task and reported to hooks; one failing task
+    /// does not prevent the others from running.
+    ///
+    /// # Arguments
+    /// * `manager` - Contract manager used to reach the router
+    /// * `now` - Current time in seconds
+    ///
+    /// # Returns
+    /// * Reports for each task executed
+    pub fn run_due(&mut self, manager: &ContractManager, now: u64) -> Vec<KeeperTaskReport> {
+        let mut reports = Vec::new();
+
+        for task in self.due_tasks(now) {
+            let report = self.run_task(manager, task, now);
+            reports.push(report);
+        }
+
+        reports
+    }
+
+    /// Execute a single task immediately, regardless of its schedule
+    pub fn run_task(&mut self, manager: &ContractManager, task: KeeperTask, now: u64) -> KeeperTaskReport {
+        let result = self.execute(manager, task);
+
+        let report = KeeperTaskReport {
+            task,
+            started_at: now,
+            items_processed: *result.as_ref().unwrap_or(&0),
+            error: result.err(),
+        };
+
+        if let Some((_, state)) = self.tasks.get_mut(&task) {
+            state.last_run_at = Some(now);
+            state.run_count += 1;
+            state.items_processed += report.items_processed as u64;
+
+            match &report.error {
+                None => {
+                    state.last_success_at = Some(now);
+                    state.consecutive_failures = 0;
+                    state.last_error = None;
+                }
+                Some(error) => {
+                    state.failure_count += 1;
+                    state.consecutive_failures += 1;
+                    state.last_error = Some(error.clone());
+                }
+            }
+        }
+
+        for hook in self.hooks.iter() {
+            hook(&report);
+        }
+
+        report
+    }
+
+    /// Get aggregate metrics across all tasks
+    pub fn metrics(&self) -> KeeperMetrics {
+        let mut metrics = KeeperMetrics::default();
+
+        for (_, state) in self.tasks.values() {
+            metrics.total_runs += state.run_count;
+            metrics.total_failures += state.failure_count;
+            metrics.total_items_processed += state.items_processed;
+            if state.consecutive_failures > 0 {
+                metrics.tasks_failing += 1;
+            }
+        }
+
+        metrics
+    }
+
+    fn execute(&self, manager: &ContractManager, task: KeeperTask) -> ContractResult<u32> {
+        let router = manager.integration_router();
+
+        match task {
+            KeeperTask::Reconciliation => router.trigger_auto_reconciliation(&self.ctx).map(|ran| ran as u32),
+            KeeperTask::ProofGeneration => router.trigger_scheduled_proof_gen(&self.ctx).map(|ran| ran as u32),
+            KeeperTask::TimeoutSweep => manager.sweep_timed_out_operations(&self.ctx, self.sweep_batch_size, 10),
+            KeeperTask::LimitReset => router.reset_expired_limits(&self.ctx),
+            KeeperTask::OrderMatching => router.match_limit_orders(&self.ctx, self.match_batch_size),
+            KeeperTask::MetricsSnapshot => router.record_metrics_snapshot(&self.ctx).map(|taken| taken as u32),
+            KeeperTask::AlertEscalation => router.escalate_alerts(&self.ctx),
+            KeeperTask::MaintenanceStatus => router.update_maintenance_status().map(|active| active as u32),
+            KeeperTask::EventPruning => self.pruned_event_types
+                .iter()
+                .try_fold(0u32, |pruned, event_type| {
+                    Ok(pruned + router.prune_event_history(&self.ctx, event_type, self.sweep_batch_size)?)
+                }),
+        }
+    }
+
+    fn is_due(config: &KeeperTaskConfig, state: &KeeperTaskState, now: u64) -> bool {
+        if !config.enabled {
+            return false;
+        }
+
+        match state.last_run_at {
+            Some(last_run) => now >= last_run.saturating_add(config.interval_seconds),
+            None => true,
+        }
+    }
+}
+
+/// Tracks the live-until ledger of router entries and extends them before expiry
+pub struct TtlBumpScheduler {
+    threshold_ledgers: u32,
+    batch_size: u32,
+    entries: Vec<(TtlKey, u32)>,
+}
+
+impl TtlBumpScheduler {
+    /// Create a scheduler that bumps entries within `threshold_ledgers` of expiry
+    ///
+    /// # Arguments
+    /// * `threshold_ledgers` - Remaining lifetime at which an entry becomes due
+    /// * `batch_size` - Keys per `extend_ttl_batch` call (at most 100)
+    pub fn new(threshold_ledgers: u32, batch_size: u32) -> Self {
+        Self {
+            threshold_ledgers,
+            batch_size: batch_size.clamp(1, 100),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Track an entry, or update the live-until ledger of a tracked one
+    pub fn track(&mut self, key: TtlKey, live_until_ledger: u32) {
+        match self.entries.iter_mut().find(|(tracked, _)| *tracked == key) {
+            Some(entry) => entry.1 = live_until_ledger,
+            None => self.entries.push((key, live_until_ledger)),
+        }
+    }
+
+    /// Stop tracking an entry, e.g. after it was compacted into an archive
+    pub fn untrack(&mut self, key: &TtlKey) {
+        self.entries.retain(|(tracked, _)| tracked != key);
+    }
+
+    /// Number of tracked entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no entries are tracked
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Ledger at which the next tracked entry becomes due
+    pub fn next_bump_ledger(&self) -> Option<u32> {
+        self.entries
+            .iter()
+            .map(|(_, live_until)| live_until.saturating_sub(self.threshold_ledgers))
+            .min()
+    }
+
+    /// Plan the extension calls due at `current_ledger`, soonest expiry first
+    ///
+    /// Entries whose live-until ledger has already passed are archived and
+    /// can no longer be extended, so they are left out of the plan.
+    pub fn plan(&self, current_ledger: u32) -> Vec<Vec<TtlKey>> {
+        let mut due: Vec<&(TtlKey, u32)> = self.entries
+            .iter()
+            .filter(|(_, live_until)| {
+                *live_until >= current_ledger && live_until - current_ledger < self.threshold_ledgers
+            })
+            .collect();
+        due.sort_by_key(|(_, live_until)| *live_until);
+
+        due.chunks(self.batch_size as usize)
+            .map(|chunk| chunk.iter().map(|(key, _)| key.clone()).collect())
+            .collect()
+    }
+
+    /// Entries whose live-until ledger has passed without an extension
+    pub fn expired(&self, current_ledger: u32) -> Vec<TtlKey> {
+        self.entries
+            .iter()
+            .filter(|(_, live_until)| *live_until < current_ledger)
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    /// Submit every planned batch and record the new live-until ledgers
+    ///
+    /// A failed batch stops the run; batches already submitted keep their
+    /// updated ledgers and the rest stay due for the next run.
+    ///
+    /// # Returns
+    /// * `Ok(count)` - Number of entries the router extended
+    /// * `Err(ContractError)` - Error from the first failing batch
+    pub fn run(&mut self, manager: &ContractManager, current_ledger: u32) -> ContractResult<u32> {
+        let router = manager.integration_router();
+        let mut extended = 0u32;
+
+        for batch in self.plan(current_ledger) {
+            extended += router.extend_ttl_batch(&batch)?;
+            for key in batch {
+                self.track(key, current_ledger.saturating_add(ROUTER_TTL_EXTEND_TO));
+            }
+        }
+
+        Ok(extended)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_scheduling() {
+        let mut keeper = KeeperService::new(OperationContext::default());
+        keeper.register_task(KeeperTask::TimeoutSweep, 60);
+        keeper.register_task(KeeperTask::Reconciliation, 3600);
+
+        assert_eq!(keeper.due_tasks(0).len(), 2);
+        assert_eq!(keeper.next_due_at(), Some(0));
+
+        keeper.set_task_enabled(KeeperTask::Reconciliation, false).unwrap();
+        assert_eq!(keeper.due_tasks(0), alloc::vec![KeeperTask::TimeoutSweep]);
+        assert!(keeper.set_task_enabled(KeeperTask::LimitReset, true).is_err());
+    }
+
+    #[test]
+    fn test_ttl_bumps_are_planned_before_expiry() {
+        let env = soroban_sdk::Env::default();
+        let key = |seed: u8| TtlKey::OperationTracker(soroban_sdk::BytesN::from_array(&env, &[seed; 32]));
+        let mut scheduler = TtlBumpScheduler::new(1_000, 2);
+        scheduler.track(key(1), 10_500);
+        scheduler.track(key(2), 10_200);
+        scheduler.track(key(3), 10_900);
+        scheduler.track(key(4), 50_000);
+        scheduler.track(key(5), 9_000);
+
+        assert_eq!(scheduler.next_bump_ledger(), Some(8_000));
+        assert_eq!(scheduler.expired(10_000), alloc::vec![key(5)]);
+
+        // Due entries are grouped soonest expiry first; the archived one is skipped
+        let plan = scheduler.plan(10_000);
+        assert_eq!(plan, alloc::vec![alloc::vec![key(2), key(1)], alloc::vec![key(3)]]);
+
+        scheduler.track(key(2), 10_000 + ROUTER_TTL_EXTEND_TO);
+        scheduler.untrack(&key(5));
+        assert_eq!(scheduler.len(), 4);
+        assert_eq!(scheduler.plan(10_000).concat(), alloc::vec![key(1), key(3)]);
+    }
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env, String as SorobanString};
+use soroban_sdk::{Address, BytesN, Env, String as SorobanString};
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use alloc::format;
@@ -167,6 +167,38 @@ impl KycRegistryClient {
         Ok(())
     }
 
+    /// Move a customer to another jurisdiction (admin only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `customer_id` - Customer identifier
+    /// * `jurisdiction` - New jurisdiction code
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn set_customer_jurisdiction(
+        &self,
+        ctx: &OperationContext,
+        customer_id: &str,
+        jurisdiction: &str,
+    ) -> ContractResult<()> {
+        if customer_id.is_empty() || jurisdiction.is_empty() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get the jurisdiction of the customer owning an address
+    pub fn get_jurisdiction_by_address(&self, address: &Address) -> ContractResult<Option<String>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
     /// Add approved address to customer
     /// 
     /// # Arguments
@@ -291,14 +323,440 @@ impl KycRegistryClient {
         &self,
         operations: &[(Address, u32, u64)],
     ) -> ContractResult<Vec<bool>> {
-        let mut results = Vec::new();
+        Ok(self.verify_compliance_batch(operations)?
+            .into_iter()
+            .map(|outcome| outcome.approved)
+            .collect())
+    }
+
+    /// Check many operations in one `verify_compliance_batch` call
+    /// 
+    /// # Arguments
+    /// * `operations` - List of (address, operation_code, amount) tuples
+    /// 
+    /// # Returns
+    /// * `Ok(outcomes)` - One outcome per operation, with the refusal reason
+    /// * `Err(ContractError)` - Error details
+    pub fn verify_compliance_batch(
+        &self,
+        operations: &[(Address, u32, u64)],
+    ) -> ContractResult<Vec<ComplianceOutcome>> {
+        let mut outcomes = Vec::new();
         
         for (address, operation_code, amount) in operations {
+            // In a real implementation, this would be a single contract call
             let approved = self.is_approved_for_operation(address, *operation_code, *amount)?;
-            results.push(approved);
+            outcomes.push(ComplianceOutcome {
+                user: address.clone(),
+                op_code: *operation_code,
+                amount: *amount,
+                approved,
+                reason_code: if approved { 0 } else { 7 },
+            });
+        }
+        
+        Ok(outcomes)
+    }
+
+    /// Request a tier change for the customer owning `user`
+    /// 
+    /// # Arguments
+    /// * `user` - Registered customer address (must authorize)
+    /// * `requested_tier` - Requested KYC tier (0-4)
+    /// * `documents_hash` - Hash of the supporting documents submitted off-chain
+    /// 
+    /// # Returns
+    /// * `Ok(request_id)` - ID of the pending review request
+    /// * `Err(ContractError)` - Error details
+    pub fn request_tier_upgrade(
+        &self,
+        user: &Address,
+        requested_tier: u32,
+        documents_hash: &BytesN<32>,
+    ) -> ContractResult<u64> {
+        if requested_tier > 4 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        let request_id = self.env.ledger().sequence() as u64;
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("kyc_req"), user.clone()),
+            (request_id, requested_tier, documents_hash.clone())
+        );
+        
+        Ok(request_id)
+    }
+
+    /// Approve a pending tier change (compliance officer only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `request_id` - Pending request ID
+    /// * `notes` - Review notes
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn approve_tier_change(
+        &self,
+        ctx: &OperationContext,
+        request_id: u64,
+        notes: &str,
+    ) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("kyc_rev"), ctx.caller.clone()),
+            (request_id, true, SorobanString::from_str(&self.env, notes))
+        );
+        
+        Ok(())
+    }
+
+    /// Reject a pending tier change (compliance officer only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `request_id` - Pending request ID
+    /// * `notes` - Reason for the rejection
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn reject_tier_change(
+        &self,
+        ctx: &OperationContext,
+        request_id: u64,
+        notes: &str,
+    ) -> ContractResult<()> {
+        if notes.is_empty() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("kyc_rev"), ctx.caller.clone()),
+            (request_id, false, SorobanString::from_str(&self.env, notes))
+        );
+        
+        Ok(())
+    }
+
+    /// Get a tier change request by ID
+    pub fn get_tier_change_request(&self, request_id: u64) -> ContractResult<Option<TierChangeRequest>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// List tier change requests awaiting compliance review
+    pub fn get_pending_tier_changes(&self) -> ContractResult<Vec<TierChangeRequest>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Get the tier currently applied to a customer's compliance checks
+    /// 
+    /// While a tier change is pending this is the lower of the current and
+    /// requested tier.
+    pub fn get_effective_tier(&self, customer_id: &str) -> ContractResult<Option<u32>> {
+        // In a real implementation, this would query the contract
+        Ok(self.get_customer_record(customer_id)?.map(|record| record.kyc_tier))
+    }
+
+    /// Record a document on file for a customer (compliance officer only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `customer_id` - Customer the document belongs to
+    /// * `document_type` - Kind of document
+    /// * `document_hash` - Hash of the document file kept off-chain
+    /// * `issued_at` - Issue date of the document
+    /// * `expires_at` - Expiry date of the document (0 = does not expire)
+    /// 
+    /// # Returns
+    /// * `Ok(document_id)` - ID of the new document record
+    /// * `Err(ContractError)` - Error details
+    pub fn add_document(
+        &self,
+        ctx: &OperationContext,
+        customer_id: &str,
+        document_type: DocumentType,
+        document_hash: &BytesN<32>,
+        issued_at: u64,
+        expires_at: u64,
+    ) -> ContractResult<u64> {
+        if customer_id.is_empty()
+            || document_hash.to_array() == [0u8; 32]
+            || (expires_at != 0 && expires_at <= issued_at)
+        {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(1)
+    }
+
+    /// Remove a revoked or replaced document from file (compliance officer only)
+    pub fn remove_document(&self, ctx: &OperationContext, document_id: u64) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get a document record by ID
+    pub fn get_document(&self, document_id: u64) -> ContractResult<Option<KycDocument>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// List the documents on file for a customer, oldest first
+    pub fn get_customer_documents(&self, customer_id: &str) -> ContractResult<Vec<KycDocument>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// List documents expiring within `within` seconds, soonest first
+    /// 
+    /// Used by the compliance team to send renewal reminders; already
+    /// expired documents are not included.
+    pub fn get_expiring_documents(&self, within: u64) -> ContractResult<Vec<KycDocument>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Record the tier downgrade of a customer whose identity documents have all expired
+    /// 
+    /// # Returns
+    /// * `Ok(true)` - The customer was downgraded to no tier
+    /// * `Ok(false)` - The customer still has a valid identity document or no tier
+    /// * `Err(ContractError)` - Error details
+    pub fn enforce_document_expiry(&self, customer_id: &str) -> ContractResult<bool> {
+        // In a real implementation, this would call the contract
+        Ok(false)
+    }
+
+    /// Add an address to the blacklist (compliance officer only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `address` - Address to blacklist
+    /// * `reason_code` - Why the address is listed (1=sanctions, 2=fraud, 3=law enforcement, 4=internal)
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn add_to_blacklist(
+        &self,
+        ctx: &OperationContext,
+        address: &Address,
+        reason_code: u32,
+    ) -> ContractResult<()> {
+        if reason_code == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("kyc_bl"), soroban_sdk::symbol_short!("add")),
+            (address.clone(), reason_code)
+        );
+        
+        Ok(())
+    }
+
+    /// Remove an address from the blacklist (compliance officer only)
+    pub fn remove_from_blacklist(&self, ctx: &OperationContext, address: &Address) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("kyc_bl"), soroban_sdk::symbol_short!("remove")),
+            address.clone()
+        );
+        
+        Ok(())
+    }
+
+    /// Apply an update from an external sanctions list
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `list_version` - Hash identifying the new list version (each version imports once)
+    /// * `previous_version` - Version the delta applies to (None for the first import)
+    /// * `added` - Addresses that appear on the new list version
+    /// * `removed` - Addresses dropped from the list since `previous_version`
+    /// * `reason_code` - Reason code applied to every newly listed entry
+    /// 
+    /// # Returns
+    /// * `Ok((listed, delisted))` - Number of newly blacklisted and delisted addresses
+    /// * `Err(ContractError)` - Error details
+    pub fn import_blacklist(
+        &self,
+        ctx: &OperationContext,
+        list_version: &BytesN<32>,
+        previous_version: Option<&BytesN<32>>,
+        added: &[Address],
+        removed: &[Address],
+        reason_code: u32,
+    ) -> ContractResult<(u32, u32)> {
+        if (added.is_empty() && removed.is_empty()) || reason_code == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+        if previous_version == Some(list_version) {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("kyc_bl"), soroban_sdk::symbol_short!("import")),
+            (list_version.clone(), added.len() as u32, removed.len() as u32)
+        );
+        
+        Ok((added.len() as u32, removed.len() as u32))
+    }
+
+    /// Get the blacklist entry for an address
+    /// 
+    /// # Returns
+    /// * `Ok(Some(entry))` - Address is blacklisted
+    /// * `Ok(None)` - Address is not listed
+    /// * `Err(ContractError)` - Error details
+    pub fn get_blacklist_status(&self, address: &Address) -> ContractResult<Option<BlacklistEntry>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get the version hash of the most recently imported external list
+    pub fn get_blacklist_version(&self) -> ContractResult<Option<BytesN<32>>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Screen an address before submitting an operation on its behalf
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Address is not blacklisted
+    /// * `Err(ContractError::Integration(AddressBlacklisted))` - Address is listed
+    pub fn screen_address(&self, address: &Address) -> ContractResult<()> {
+        match self.get_blacklist_status(address)? {
+            Some(_) => Err(ContractError::Integration(
+                shared::IntegrationError::AddressBlacklisted
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Screen both parties of a transfer
+    pub fn screen_transfer(&self, from: &Address, to: &Address) -> ContractResult<()> {
+        self.screen_address(from)?;
+        self.screen_address(to)
+    }
+
+    /// Return the subset of `addresses` that are blacklisted
+    pub fn screen_addresses(&self, addresses: &[Address]) -> ContractResult<Vec<BlacklistEntry>> {
+        let mut listed = Vec::new();
+        
+        for address in addresses {
+            if let Some(entry) = self.get_blacklist_status(address)? {
+                listed.push(entry);
+            }
+        }
+        
+        Ok(listed)
+    }
+
+    /// Set the travel-rule withdrawal threshold for a jurisdiction (admin only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `jurisdiction` - Jurisdiction code
+    /// * `threshold` - Amount at or above which a travel-rule payload is required
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn set_travel_rule_threshold(
+        &self,
+        ctx: &OperationContext,
+        jurisdiction: &str,
+        threshold: u64,
+    ) -> ContractResult<()> {
+        if jurisdiction.is_empty() || threshold == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
         }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("kyc_trv"), soroban_sdk::symbol_short!("set")),
+            (SorobanString::from_str(&self.env, jurisdiction), threshold)
+        );
         
-        Ok(results)
+        Ok(())
+    }
+
+    /// Get the travel-rule threshold for a jurisdiction
+    pub fn get_travel_rule_threshold(&self, jurisdiction: &str) -> ContractResult<Option<u64>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Check whether a withdrawal needs a travel-rule payload hash
+    pub fn requires_travel_rule(&self, address: &Address, amount: u64) -> ContractResult<bool> {
+        // In a real implementation, this would query the contract
+        Ok(false)
+    }
+
+    /// Replace the limit policy for a KYC tier (admin only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `tier` - Tier code (0 = None .. 4 = Institutional)
+    /// * `policy` - New limits; `version`, `updated_at` and `updated_by` are set by the contract
+    /// 
+    /// # Returns
+    /// * `Ok(u32)` - Version assigned to the policy
+    /// * `Err(ContractError)` - Error details
+    pub fn set_tier_policy(
+        &self,
+        ctx: &OperationContext,
+        tier: u32,
+        policy: &TierPolicy,
+    ) -> ContractResult<u32> {
+        if tier > 4
+            || policy.daily_deposit_limit > policy.monthly_deposit_limit
+            || policy.daily_withdrawal_limit > policy.monthly_withdrawal_limit
+            || policy.daily_exchange_limit > policy.monthly_exchange_limit
+        {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(policy.version + 1)
+    }
+
+    /// Get the current limit policy for a KYC tier
+    pub fn get_tier_policy(&self, tier: u32) -> ContractResult<Option<TierPolicy>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Get a past version of a tier's limit policy
+    pub fn get_tier_policy_version(&self, tier: u32, version: u32) -> ContractResult<Option<TierPolicy>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
     }
 
     /// Check if registry is enabled
@@ -338,9 +796,11 @@ impl ContractClient for KycRegistryClient {
 
 /// Customer record structure
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CustomerRecord {
     pub customer_id: String,
     pub kyc_tier: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address_vec"))]
     pub approved_addresses: Vec<Address>,
     pub jurisdiction: String,
     pub created_at: u64,
@@ -352,10 +812,141 @@ pub struct CustomerRecord {
 
 /// Global KYC settings
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GlobalSettings {
     pub registry_enabled: bool,
     pub strict_mode: bool,
     pub auto_expire_days: u64,
     pub sanctions_required: bool,
     pub audit_enabled: bool,
-}
\ No newline at end of file
+}
+
+/// Review state of a tier change request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TierChangeStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// Tier change request structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TierChangeRequest {
+    pub request_id: u64,
+    pub customer_id: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub requester: Address,
+    pub current_tier: u32,
+    pub requested_tier: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub documents_hash: BytesN<32>,
+    pub status: TierChangeStatus,
+    pub requested_at: u64,
+    pub reviewed_at: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
+    pub reviewer: Option<Address>,
+    pub review_notes: String,
+}
+
+impl TierChangeRequest {
+    /// Tier applied while the request is pending review
+    pub fn effective_tier(&self) -> u32 {
+        match self.status {
+            TierChangeStatus::Pending => self.current_tier.min(self.requested_tier),
+            TierChangeStatus::Approved => self.requested_tier,
+            TierChangeStatus::Rejected => self.current_tier,
+        }
+    }
+}
+
+/// Kind of KYC document on file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DocumentType {
+    Passport,
+    NationalId,
+    DriversLicense,
+    ProofOfAddress,
+    SourceOfFunds,
+}
+
+impl DocumentType {
+    /// Whether the document proves identity; a customer loses their tier once all of these expire
+    pub fn is_identity(&self) -> bool {
+        matches!(self, DocumentType::Passport | DocumentType::NationalId | DocumentType::DriversLicense)
+    }
+}
+
+/// Customer document record
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KycDocument {
+    pub document_id: u64,
+    pub customer_id: String,
+    pub document_type: DocumentType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub document_hash: BytesN<32>,
+    pub issued_at: u64,
+    pub expires_at: u64, // 0 = does not expire
+    pub recorded_at: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub recorded_by: Address,
+}
+
+impl KycDocument {
+    /// Whether the document has expired at `now`
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at != 0 && self.expires_at <= now
+    }
+}
+
+/// Result of one request in a batch compliance check
+/// 
+/// Reason codes: 0 = approved, 1 = blacklisted, 2 = address not registered,
+/// 3 = KYC expired, 4 = sanctions not cleared, 5 = tier too low, 6 = operation
+/// disabled for the tier, 7 = above the single transaction limit, 8 = unknown
+/// operation code.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComplianceOutcome {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub user: Address,
+    pub op_code: u32,
+    pub amount: u64,
+    pub approved: bool,
+    pub reason_code: u32,
+}
+
+/// Blacklist entry structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlacklistEntry {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub address: Address,
+    pub reason_code: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_bytes"))]
+    pub list_version: Option<BytesN<32>>,
+    pub added_at: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub added_by: Address,
+}
+
+/// Per-tier limit policy, amounts in satoshis
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TierPolicy {
+    pub daily_deposit_limit: u64,
+    pub monthly_deposit_limit: u64,
+    pub daily_withdrawal_limit: u64,
+    pub monthly_withdrawal_limit: u64,
+    pub daily_exchange_limit: u64,
+    pub monthly_exchange_limit: u64,
+    pub min_confirmations: u32,
+    pub enhanced_verification_limit: u64,
+    pub version: u32,
+    pub updated_at: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub updated_by: Address,
+}
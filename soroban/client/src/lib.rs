@@ -13,7 +13,7 @@
 //! 
 //! # Quick Start
 //! 
-//! ```rust
+//! ```rust,ignore
 //! use soroban_client::{ContractManager, ContractAddresses, NetworkConfig};
 //! use soroban_sdk::Env;
 //! 
@@ -40,11 +40,25 @@
 //! 
 //! - `integration_router_client`: Client for the Integration Router contract
 //! - `kyc_registry_client`: Client for the KYC Registry contract
-//! - `istsi_token_client`: Client for the iSTSi Token contract
+//! - `istsi_token_client`: Client for the iSTSi Token contract and its SEP-41 interface
 //! - `reserve_manager_client`: Client for the Reserve Manager contract
 //! - `contract_manager`: Unified manager for all contract interactions
 //! - `event_monitor`: Event monitoring and processing utilities
-//! - `address_config`: Contract address and network configuration management
+//! - `address_config`: Contract address, network and parameter schema configuration
+//! - `circuit_breaker`: Per-contract circuit breakers for failing downstream contracts
+//! - `keeper`: Scheduled maintenance tasks and storage TTL bumps executed against the router
+//! - `access_control`: Role capability introspection and authorization pre-checks
+//! - `webhook`: Signed webhook fan-out of contract events with retries
+//! - `reporting`: Settlement reports aggregated per user and asset, and account balance statements, with CSV/JSON export
+//! - `regulatory`: Threshold-based regulator filings (large transactions, structuring, SAR candidates) with redaction
+//! - `accounting`: Double-entry journal of contract events with balance anomaly checks
+//! - `alerting`: Paging of router alerts through severity and type routes, and delivery of emergency notifications to SMS/email senders
+//! - `transaction`: Simulated, fee-buffered transaction assembly and submission for contract calls
+//! - `signer`: Pluggable transaction signers for HSM, KMS and hardware wallet keys
+//! - `codec`: Canonical XDR and compact JSON encodings of event payloads
+//! - `proof_verifier`: Independent re-verification of published proofs of reserves
+//! - `service`: REST/gRPC facade over the manager's workflows (`service` feature)
+//! - `serde_support`: Strkey and hex encodings behind the `serde` feature's Serialize/Deserialize impls
 
 #![no_std]
 
@@ -57,15 +71,71 @@ pub mod reserve_manager_client;
 pub mod contract_manager;
 pub mod event_monitor;
 pub mod address_config;
+pub mod circuit_breaker;
+pub mod keeper;
+pub mod access_control;
+pub mod webhook;
+pub mod reporting;
+pub mod regulatory;
+pub mod accounting;
+pub mod alerting;
+pub mod transaction;
+pub mod signer;
+pub mod codec;
+pub mod proof_verifier;
+#[cfg(feature = "service")]
+pub mod service;
+#[cfg(feature = "serde")]
+pub mod serde_support;
 
 // Re-export commonly used items
-pub use integration_router_client::IntegrationRouterClient;
-pub use kyc_registry_client::KycRegistryClient;
-pub use istsi_token_client::IstsiTokenClient;
+pub use integration_router_client::{
+    AlertSeverity, AmountMinimums, AuditAction, AuditChainHead, AuditLogEntry, BtcFeeRates, BtcFeeTier, CanaryRollout, ClawbackProposal, ClawbackStatus, CompatibilityCheck, ContactChannel, ContractCallStats, ContractIsolation, ConversionConfig, DepositPreRegistration, DepositProcessingStatus, DepositStatus, DustAccount, EmergencyContact, EmergencyContactList, EmergencyNotification, EmergencyResponseType, EventImportance, EventRetentionMetrics, EventRetentionPolicy, ExchangeComplianceStatus, GuardrailCheck, InsuranceConfig, InsuranceFund, InsuranceFundHealth, InsuranceTransferKind, IntegrationRouterClient, InventoryDepth, IsolationScope, IsolationStatus, JurisdictionBand, JurisdictionOutcome, JurisdictionRuleSet, LimitChange, LimitKind, LimitOrder, LiquidityPosition, MaintenanceWindow, MetricsSnapshot,
+    MigrationState, MigrationStatus, NotificationRecord, OperationHold, OrderStatus, PauseState, PauseWorkflow, PendingConversionConfig, PendingInsuranceTransfer, OperationArchive, OperationList, OperationListPage, OperatorStats, ProcessingOperation, ProofVerificationStatus, ProcessingTimeStats, ProtectionModeState, ReorgCase, ReorgCaseStatus, ReserveGuardrails, ReserveProtectionConfig, RoleGrant, RolloutStageMetrics, RolloutStatus, RoundingPolicy, SimulationReport, SimulationStep, StatsPeriod, StoredProofOfReserves, SystemAlert,
+    TokenPairConfig, TtlKey, UserWithdrawalRequest, VelocityOperation, WithdrawalApproval, WithdrawalApprovalConfig, WithdrawalFeeQuote, WithdrawalRequestStatus
+};
+pub use kyc_registry_client::{ComplianceOutcome, DocumentType, KycDocument, KycRegistryClient};
+pub use istsi_token_client::{missing_sep41_functions, BalanceSnapshot, ClawbackRecord, IstsiTokenClient, SEP41_FUNCTIONS};
 pub use reserve_manager_client::ReserveManagerClient;
-pub use contract_manager::{ContractManager, SystemHealth, SystemStatus};
-pub use event_monitor::{EventMonitor, ContractEvent, EventData, EventFilter};
-pub use address_config::{ContractAddresses, NetworkConfig, AddressRegistry};
+pub use contract_manager::{
+    ContractManager, SystemHealth, SystemStatus, ComponentHealth, HealthTransition, HealthWatcher
+};
+pub use event_monitor::{
+    EventMonitor, ContractEvent, EventData, EventFilter, EventSource, EventPage, RpcEvent, BackfillCheckpoint,
+    EventPosition, EventCheckpoint, CheckpointStore, InMemoryCheckpointStore, OperationTrace, TraceNode, assemble_trace
+};
+pub use address_config::{
+    ContractAddresses, NetworkConfig, AddressRegistry, DeploymentEnvironment, FromParamValue, ParamType, ParamValue, ParameterRegistry, ParameterSchema,
+    PromotionRecord
+};
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitBreakerRegistry, CircuitState};
+pub use keeper::{KeeperService, KeeperTask, KeeperTaskConfig, KeeperTaskState, KeeperTaskReport, KeeperMetrics, TtlBumpScheduler};
+pub use access_control::{AccessControl, AuthorizationDecision, CapabilitySet, RoleRequirement, UserRole};
+pub use webhook::{WebhookDispatcher, WebhookEndpoint, WebhookRetryPolicy, WebhookDelivery, WebhookMetrics, WebhookTransport};
+pub use reporting::{
+    ReportGenerator, SettlementReport, SettlementLine, SettledOperation, SettlementKind, AssetSummary, OperationSource,
+    BalanceStatement, StatementLine, OperatorScoreboard, ScoreboardLine
+};
+pub use regulatory::{
+    RegulatoryReportBuilder, RegulatoryReport, ReportingThresholds, Redaction, RedactionPolicy, LargeTransaction,
+    StructuringPattern, SarCandidate
+};
+pub use accounting::{
+    AccountingLedger, JournalEntry, JournalLine, LedgerAccount, LedgerAnomaly, EntrySide
+};
+pub use alerting::{
+    AlertManager, AlertManagerMetrics, ContactDirectory, EmergencyMessage, EmergencyNotifier, EmergencyNotifierMetrics, NotificationSender,
+    Page, PagingIntegration, PagingRoute
+};
+pub use transaction::{
+    ContractInvocation, FeeBufferConfig, PreparedTransaction, SimulationResponse, SubmissionResponse, SubmissionStatus, TransactionBuilder,
+    TransactionSimulator, TransactionSubmitter
+};
+pub use signer::{AsyncSigner, InMemorySigner, SignedTransaction, Signer, SignerFuture};
+pub use proof_verifier::{ProofCheckFailure, ProofVerificationReport, ProofVerifier};
+pub use codec::{decode_event_json, decode_xdr, decode_xdr_base64, encode_event_json, encode_xdr, encode_xdr_base64};
+#[cfg(feature = "service")]
+pub use service::{ContractService, ErrorResponse, RestResponse};
 
 use soroban_sdk::Address;
 
@@ -74,13 +144,156 @@ pub type ContractResult<T> = Result<T, ContractError>;
 
 /// Contract operation errors
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ContractError {
-    Integration(shared::IntegrationError),
-    Validation(shared::ValidationError),
+    Integration(
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::integration_error"))]
+        shared::IntegrationError,
+    ),
+    Validation(
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::validation_error"))]
+        shared::ValidationError,
+    ),
     NetworkError(alloc::string::String),
     ParseError(alloc::string::String),
     Timeout(alloc::string::String),
     ContractNotFound(alloc::string::String),
+    /// Call rejected because the contract's circuit breaker is open
+    CircuitOpen(alloc::string::String),
+    /// Transaction simulation predicts the call will fail
+    SimulationFailed(alloc::string::String),
+}
+
+/// Machine-readable error category used by retry policies and alerting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ErrorCategory {
+    /// Caller lacks the required role or authorization
+    Auth,
+    /// KYC, blacklist or other compliance rejection
+    Compliance,
+    /// Reserve shortfall or Bitcoin-side failure
+    Reserve,
+    /// Price data unavailable or stale
+    Oracle,
+    /// Caller exceeded a rate limit
+    RateLimit,
+    /// Temporary condition; the same call may succeed later
+    Transient,
+    /// The call is invalid and will fail again unchanged
+    Permanent,
+}
+
+impl ContractError {
+    /// Categorize the error
+    pub fn category(&self) -> ErrorCategory {
+        use shared::IntegrationError as E;
+
+        match self {
+            ContractError::Integration(error) => match error {
+                E::Unauthorized | E::InsufficientPermissions => ErrorCategory::Auth,
+                E::ComplianceCheckFailed
+                | E::InsufficientKYCTier
+                | E::AddressBlacklisted
+                | E::JurisdictionRestricted => ErrorCategory::Compliance,
+                E::InsufficientReserves | E::ReserveRatioTooLow | E::BitcoinTransactionFailed => ErrorCategory::Reserve,
+                E::OracleStale => ErrorCategory::Oracle,
+                E::RateLimited => ErrorCategory::RateLimit,
+                E::ContractCallFailed
+                | E::OperationTimeout
+                | E::SystemPaused
+                | E::EmergencyMode
+                | E::MaintenanceMode
+                | E::WorkflowPaused
+                | E::MigrationInProgress
+                | E::ContractIsolated => ErrorCategory::Transient,
+                E::ContractNotFound
+                | E::InvalidContractResponse
+                | E::InvalidOperationState
+                | E::DuplicateOperation
+                | E::DustAmount => ErrorCategory::Permanent,
+            },
+            ContractError::Validation(_) => ErrorCategory::Permanent,
+            ContractError::NetworkError(_) | ContractError::Timeout(_) | ContractError::CircuitOpen(_) => ErrorCategory::Transient,
+            ContractError::ParseError(_) | ContractError::ContractNotFound(_) | ContractError::SimulationFailed(_) => ErrorCategory::Permanent,
+        }
+    }
+
+    /// Whether retrying the same call can succeed
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.category(),
+            ErrorCategory::Transient | ErrorCategory::RateLimit | ErrorCategory::Oracle
+        )
+    }
+
+    /// Numeric error code
+    ///
+    /// On-chain errors keep their contract codes; client-side failures use
+    /// the 900 range.
+    pub fn error_code(&self) -> u32 {
+        match self {
+            ContractError::Integration(error) => *error as u32,
+            ContractError::Validation(error) => *error as u32,
+            ContractError::NetworkError(_) => 900,
+            ContractError::ParseError(_) => 901,
+            ContractError::Timeout(_) => 902,
+            ContractError::ContractNotFound(_) => 903,
+            ContractError::CircuitOpen(_) => 904,
+            ContractError::SimulationFailed(_) => 905,
+        }
+    }
+
+    /// Convert an on-chain contract error code
+    pub fn from_code(code: u32) -> Option<Self> {
+        use shared::IntegrationError as E;
+        use shared::ValidationError as V;
+
+        let error = match code {
+            1 => ContractError::Integration(E::Unauthorized),
+            2 => ContractError::Integration(E::InsufficientPermissions),
+            10 => ContractError::Integration(E::ContractNotFound),
+            11 => ContractError::Integration(E::ContractCallFailed),
+            12 => ContractError::Integration(E::InvalidContractResponse),
+            13 => ContractError::Integration(E::OracleStale),
+            14 => ContractError::Integration(E::ContractIsolated),
+            20 => ContractError::Integration(E::ComplianceCheckFailed),
+            21 => ContractError::Integration(E::InsufficientKYCTier),
+            22 => ContractError::Integration(E::AddressBlacklisted),
+            23 => ContractError::Integration(E::JurisdictionRestricted),
+            30 => ContractError::Integration(E::InsufficientReserves),
+            31 => ContractError::Integration(E::ReserveRatioTooLow),
+            32 => ContractError::Integration(E::BitcoinTransactionFailed),
+            40 => ContractError::Integration(E::OperationTimeout),
+            41 => ContractError::Integration(E::InvalidOperationState),
+            42 => ContractError::Integration(E::DuplicateOperation),
+            43 => ContractError::Integration(E::RateLimited),
+            44 => ContractError::Integration(E::DustAmount),
+            50 => ContractError::Integration(E::SystemPaused),
+            51 => ContractError::Integration(E::EmergencyMode),
+            52 => ContractError::Integration(E::MaintenanceMode),
+            53 => ContractError::Integration(E::WorkflowPaused),
+            54 => ContractError::Integration(E::MigrationInProgress),
+            100 => ContractError::Validation(V::InvalidAddress),
+            101 => ContractError::Validation(V::InvalidAmount),
+            102 => ContractError::Validation(V::InvalidTimestamp),
+            103 => ContractError::Validation(V::InvalidSignature),
+            104 => ContractError::Validation(V::InvalidParameters),
+            _ => return None,
+        };
+        Some(error)
+    }
+}
+
+impl From<soroban_sdk::Error> for ContractError {
+    fn from(err: soroban_sdk::Error) -> Self {
+        if err.is_type(soroban_sdk::xdr::ScErrorType::Contract) {
+            if let Some(error) = ContractError::from_code(err.get_code()) {
+                return error;
+            }
+        }
+        ContractError::ParseError(alloc::format!("unrecognized contract error: {:?}", err))
+    }
 }
 
 impl From<shared::IntegrationError> for ContractError {
@@ -109,11 +322,70 @@ pub trait ContractClient {
 
 /// Contract operation context
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OperationContext {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
     pub caller: Address,
     pub operation_id: alloc::string::String,
     pub timeout_seconds: u64,
     pub retry_count: u32,
+    /// Distributed trace ID shared by every call in one request
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_hex_array"))]
+    pub trace_id: Option<[u8; 16]>,
+    /// Span ID of the current client call within the trace
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_hex_array"))]
+    pub span_id: Option<[u8; 8]>,
+}
+
+impl OperationContext {
+    /// Attach trace and span IDs to the context
+    pub fn with_trace(mut self, trace_id: [u8; 16], span_id: [u8; 8]) -> Self {
+        self.trace_id = Some(trace_id);
+        self.span_id = Some(span_id);
+        self
+    }
+
+    /// Attach the trace from a W3C `traceparent` header (`00-<trace>-<span>-<flags>`)
+    pub fn with_traceparent(self, traceparent: &str) -> ContractResult<Self> {
+        let invalid = || ContractError::ParseError(alloc::format!("invalid traceparent: {}", traceparent));
+        let parts: alloc::vec::Vec<&str> = traceparent.split('-').collect();
+        if parts.len() != 4 || parts[0] != "00" {
+            return Err(invalid());
+        }
+
+        let mut trace_id = [0u8; 16];
+        let mut span_id = [0u8; 8];
+        hex::decode_to_slice(parts[1], &mut trace_id).map_err(|_| invalid())?;
+        hex::decode_to_slice(parts[2], &mut span_id).map_err(|_| invalid())?;
+        Ok(self.with_trace(trace_id, span_id))
+    }
+
+    /// Context for a child call in the same trace
+    pub fn child_span(&self, span_id: [u8; 8]) -> Self {
+        Self {
+            span_id: Some(span_id),
+            ..self.clone()
+        }
+    }
+
+    /// W3C `traceparent` header for backend logs and outbound requests
+    pub fn traceparent(&self) -> Option<alloc::string::String> {
+        let trace_id = self.trace_id?;
+        let span_id = self.span_id.unwrap_or([0u8; 8]);
+        Some(alloc::format!("00-{}-{}-01", hex::encode(trace_id), hex::encode(span_id)))
+    }
+
+    /// Correlation ID seed passed with integration events
+    ///
+    /// The router keeps the trace ID in bytes 16..32 of the correlation IDs it
+    /// derives, so contract events can be joined back to this trace.
+    pub fn correlation_seed(&self, env: &soroban_sdk::Env) -> soroban_sdk::BytesN<32> {
+        let mut seed = [0u8; 32];
+        if let Some(trace_id) = self.trace_id {
+            seed[16..32].copy_from_slice(&trace_id);
+        }
+        soroban_sdk::BytesN::from_array(env, &seed)
+    }
 }
 
 impl Default for OperationContext {
@@ -121,11 +393,13 @@ impl Default for OperationContext {
         Self {
             caller: Address::from_string(&soroban_sdk::String::from_str(
                 &soroban_sdk::Env::default(), 
-                "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+                "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF"
             )),
             operation_id: alloc::string::String::new(),
             timeout_seconds: 30,
             retry_count: 3,
+            trace_id: None,
+            span_id: None,
         }
     }
 }
@@ -164,5 +438,43 @@ mod tests {
         let ctx = OperationContext::default();
         assert_eq!(ctx.timeout_seconds, 30);
         assert_eq!(ctx.retry_count, 3);
+        assert!(ctx.traceparent().is_none());
+    }
+
+    #[test]
+    fn test_error_taxonomy() {
+        let rate_limited = ContractError::from_code(43).unwrap();
+        assert_eq!(rate_limited.category(), ErrorCategory::RateLimit);
+        assert!(rate_limited.is_retryable());
+        assert_eq!(rate_limited.error_code(), 43);
+
+        let blacklisted = ContractError::Integration(shared::IntegrationError::AddressBlacklisted);
+        assert_eq!(blacklisted.category(), ErrorCategory::Compliance);
+        assert!(!blacklisted.is_retryable());
+
+        assert!(ContractError::CircuitOpen(alloc::string::String::new()).is_retryable());
+        assert_eq!(ContractError::from_code(104).unwrap().category(), ErrorCategory::Permanent);
+        assert!(ContractError::from_code(7).is_none());
+
+        let on_chain = soroban_sdk::Error::from_contract_error(20);
+        assert_eq!(
+            ContractError::from(on_chain),
+            ContractError::Integration(shared::IntegrationError::ComplianceCheckFailed)
+        );
+    }
+
+    #[test]
+    fn test_trace_context_propagation() {
+        let header = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let ctx = OperationContext::default().with_traceparent(header).unwrap();
+        assert_eq!(ctx.traceparent().as_deref(), Some(header));
+
+        let child = ctx.child_span([1u8; 8]);
+        assert_eq!(child.trace_id, ctx.trace_id);
+        assert_ne!(child.span_id, ctx.span_id);
+
+        let seed = ctx.correlation_seed(&Env::default()).to_array();
+        assert_eq!(event_monitor::extract_trace_id(&seed), ctx.trace_id);
+        assert!(OperationContext::default().with_traceparent("garbage").is_err());
     }
 }
\ No newline at end of file
@@ -0,0 +1,289 @@
+//! Independent proof-of-reserves verification
+//!
+//! Auditors should not have to trust our backend to accept a published proof.
+//! `ProofVerifier` re-checks a proof returned by the router's public
+//! `get_latest_verified_proof` using only the proof itself and the custodian
+//! attestation public keys: the ed25519 signature over the router's canonical
+//! payload, the merkle root over the reserve UTXO set (when the auditor holds
+//! it), and the reserve ratio arithmetic.
+
+use alloc::vec::Vec;
+use ed25519_dalek::{Signature, VerifyingKey};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
+use crate::integration_router_client::StoredProofOfReserves;
+
+/// Reason a proof failed independent verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProofCheckFailure {
+    /// The proof carries no attestation signature
+    Unsigned,
+    /// The signer is not one of the trusted attestation keys
+    UntrustedSigner,
+    /// The signature does not match the proof contents
+    InvalidSignature,
+    /// The merkle root does not match the supplied reserve entries
+    MerkleRootMismatch,
+    /// The reserve ratio does not follow from reserves and supply
+    RatioMismatch,
+    /// Tokens are outstanding against zero reserves
+    NoReserves,
+}
+
+/// Outcome of verifying one proof
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProofVerificationReport {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub proof_id: BytesN<32>,
+    pub merkle_root_checked: bool, // False when no reserve entries were supplied
+    pub failures: Vec<ProofCheckFailure>,
+}
+
+impl ProofVerificationReport {
+    /// Whether every check that ran passed
+    pub fn is_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Verifies published proofs of reserves against trusted attestation keys
+pub struct ProofVerifier {
+    env: Env,
+    router: Address,
+    attestation_keys: Vec<[u8; 32]>,
+}
+
+impl ProofVerifier {
+    /// Create a verifier for proofs published by `router`, trusting the given
+    /// custodian attestation public keys
+    pub fn new(env: Env, router: Address, attestation_keys: &[[u8; 32]]) -> Self {
+        Self {
+            env,
+            router,
+            attestation_keys: attestation_keys.to_vec(),
+        }
+    }
+
+    /// Run every check on a proof
+    ///
+    /// # Arguments
+    /// * `proof` - Proof returned by `get_latest_verified_proof`
+    /// * `reserve_entries` - Full reserve set as `(utxo_id, amount)` in contract order, if available
+    pub fn verify(
+        &self,
+        proof: &StoredProofOfReserves,
+        reserve_entries: Option<&[(BytesN<32>, u64)]>,
+    ) -> ProofVerificationReport {
+        let mut failures = Vec::new();
+
+        if let Err(failure) = self.verify_signature(proof) {
+            failures.push(failure);
+        }
+        if let Some(entries) = reserve_entries {
+            if let Err(failure) = self.verify_merkle_root(proof, entries) {
+                failures.push(failure);
+            }
+        }
+        if let Err(failure) = Self::verify_ratio(proof) {
+            failures.push(failure);
+        }
+
+        ProofVerificationReport {
+            proof_id: proof.proof_id.clone(),
+            merkle_root_checked: reserve_entries.is_some(),
+            failures,
+        }
+    }
+
+    /// Check the custodian signature over the proof's canonical payload
+    pub fn verify_signature(&self, proof: &StoredProofOfReserves) -> Result<(), ProofCheckFailure> {
+        let signer_key = match &proof.signer_key {
+            Some(key) => key.to_array(),
+            None => return Err(ProofCheckFailure::Unsigned),
+        };
+        if !self.attestation_keys.contains(&signer_key) {
+            return Err(ProofCheckFailure::UntrustedSigner);
+        }
+
+        let verifying_key = VerifyingKey::from_bytes(&signer_key)
+            .map_err(|_| ProofCheckFailure::UntrustedSigner)?;
+        let signature = Signature::from_bytes(&proof.signature.to_array());
+        verifying_key
+            .verify_strict(&self.signing_payload(proof), &signature)
+            .map_err(|_| ProofCheckFailure::InvalidSignature)
+    }
+
+    /// Check the merkle root against the full reserve set
+    pub fn verify_merkle_root(
+        &self,
+        proof: &StoredProofOfReserves,
+        reserve_entries: &[(BytesN<32>, u64)],
+    ) -> Result<(), ProofCheckFailure> {
+        if self.compute_merkle_root(reserve_entries) == proof.merkle_root {
+            Ok(())
+        } else {
+            Err(ProofCheckFailure::MerkleRootMismatch)
+        }
+    }
+
+    /// Check `reserve_ratio == reserves * 10000 / supply` (zero when there is no supply)
+    pub fn verify_ratio(proof: &StoredProofOfReserves) -> Result<(), ProofCheckFailure> {
+        if proof.total_btc_reserves == 0 && proof.total_token_supply > 0 {
+            return Err(ProofCheckFailure::NoReserves);
+        }
+
+        let expected = if proof.total_token_supply > 0 {
+            proof.total_btc_reserves as u128 * 10_000 / proof.total_token_supply as u128
+        } else {
+            0
+        };
+        if expected == proof.reserve_ratio as u128 {
+            Ok(())
+        } else {
+            Err(ProofCheckFailure::RatioMismatch)
+        }
+    }
+
+    /// Message the custodian signs, identical to the router's payload:
+    /// "ISTSI_POR_V1" || router address (XDR) || proof_id || reserves || supply
+    /// || ratio || timestamp (big-endian) || merkle_root
+    pub fn signing_payload(&self, proof: &StoredProofOfReserves) -> Vec<u8> {
+        let mut payload = Vec::from(&b"ISTSI_POR_V1"[..]);
+        let router = self.router.clone().to_xdr(&self.env);
+        payload.extend(router.iter());
+        payload.extend_from_slice(&proof.proof_id.to_array());
+        payload.extend_from_slice(&proof.total_btc_reserves.to_be_bytes());
+        payload.extend_from_slice(&proof.total_token_supply.to_be_bytes());
+        payload.extend_from_slice(&proof.reserve_ratio.to_be_bytes());
+        payload.extend_from_slice(&proof.timestamp.to_be_bytes());
+        payload.extend_from_slice(&proof.merkle_root.to_array());
+        payload
+    }
+
+    /// Recompute the reserve merkle root the way the Reserve Manager does
+    ///
+    /// Leaves are `sha256(0x00 || utxo_id || amount_be)`, interior nodes are
+    /// `sha256(0x01 || left || right)`, an odd last node is paired with itself,
+    /// and an empty set has an all-zero root.
+    pub fn compute_merkle_root(&self, reserve_entries: &[(BytesN<32>, u64)]) -> BytesN<32> {
+        if reserve_entries.is_empty() {
+            return BytesN::from_array(&self.env, &[0u8; 32]);
+        }
+
+        let mut level: Vec<BytesN<32>> = reserve_entries
+            .iter()
+            .map(|(utxo_id, amount)| {
+                let mut data = Bytes::new(&self.env);
+                data.push_back(0x00);
+                data.extend_from_array(&utxo_id.to_array());
+                data.extend_from_array(&amount.to_be_bytes());
+                self.env.crypto().sha256(&data).into()
+            })
+            .collect();
+
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| {
+                    let mut data = Bytes::new(&self.env);
+                    data.push_back(0x01);
+                    data.extend_from_array(&pair[0].to_array());
+                    data.extend_from_array(&pair.get(1).unwrap_or(&pair[0]).to_array());
+                    self.env.crypto().sha256(&data).into()
+                })
+                .collect();
+        }
+
+        level.remove(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integration_router_client::ProofVerificationStatus;
+    use ed25519_dalek::{Signer as _, SigningKey};
+    use soroban_sdk::{testutils::Address as _, Address};
+
+    fn signed_proof(env: &Env, verifier: &ProofVerifier, key: &SigningKey) -> StoredProofOfReserves {
+        let entries = [
+            (BytesN::from_array(env, &[1u8; 32]), 60_000_000),
+            (BytesN::from_array(env, &[2u8; 32]), 30_000_000),
+            (BytesN::from_array(env, &[3u8; 32]), 10_000_000),
+        ];
+        let mut proof = StoredProofOfReserves {
+            proof_id: BytesN::from_array(env, &[9u8; 32]),
+            timestamp: 1_000_000,
+            total_btc_reserves: 100_000_000,
+            total_token_supply: 80_000_000,
+            reserve_ratio: 12_500,
+            merkle_root: verifier.compute_merkle_root(&entries),
+            signature: BytesN::from_array(env, &[0u8; 64]),
+            signer_key: Some(BytesN::from_array(env, &key.verifying_key().to_bytes())),
+            verification_status: ProofVerificationStatus::Verified,
+            generated_by: Address::generate(env),
+        };
+        let signature = key.sign(&verifier.signing_payload(&proof));
+        proof.signature = BytesN::from_array(env, &signature.to_bytes());
+        proof
+    }
+
+    #[test]
+    fn test_signed_proof_verifies_and_tampering_is_detected() {
+        let env = Env::default();
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let verifier = ProofVerifier::new(env.clone(), Address::generate(&env), &[key.verifying_key().to_bytes()]);
+        let proof = signed_proof(&env, &verifier, &key);
+        let entries = [
+            (BytesN::from_array(&env, &[1u8; 32]), 60_000_000),
+            (BytesN::from_array(&env, &[2u8; 32]), 30_000_000),
+            (BytesN::from_array(&env, &[3u8; 32]), 10_000_000),
+        ];
+
+        let report = verifier.verify(&proof, Some(&entries));
+        assert!(report.is_valid());
+        assert!(report.merkle_root_checked);
+
+        // Inflating reserves breaks both the signature and the ratio
+        let mut inflated = proof.clone();
+        inflated.total_btc_reserves = 200_000_000;
+        assert_eq!(
+            verifier.verify(&inflated, None).failures,
+            [ProofCheckFailure::InvalidSignature, ProofCheckFailure::RatioMismatch]
+        );
+
+        // A dropped UTXO no longer hashes to the signed root
+        assert_eq!(
+            verifier.verify(&proof, Some(&entries[..2])).failures,
+            [ProofCheckFailure::MerkleRootMismatch]
+        );
+
+        // The signature covers the proof ID and the publishing router
+        let mut renamed = proof.clone();
+        renamed.proof_id = BytesN::from_array(&env, &[10u8; 32]);
+        assert_eq!(verifier.verify_signature(&renamed), Err(ProofCheckFailure::InvalidSignature));
+
+        let other_router = ProofVerifier::new(env.clone(), Address::generate(&env), &[key.verifying_key().to_bytes()]);
+        assert_eq!(other_router.verify_signature(&proof), Err(ProofCheckFailure::InvalidSignature));
+    }
+
+    #[test]
+    fn test_unsigned_and_untrusted_proofs_are_rejected() {
+        let env = Env::default();
+        let trusted = SigningKey::from_bytes(&[7u8; 32]);
+        let rogue = SigningKey::from_bytes(&[8u8; 32]);
+        let verifier = ProofVerifier::new(env.clone(), Address::generate(&env), &[trusted.verifying_key().to_bytes()]);
+
+        let forged = signed_proof(&env, &verifier, &rogue);
+        assert_eq!(verifier.verify_signature(&forged), Err(ProofCheckFailure::UntrustedSigner));
+
+        let mut unsigned = signed_proof(&env, &verifier, &trusted);
+        unsigned.signer_key = None;
+        assert_eq!(verifier.verify_signature(&unsigned), Err(ProofCheckFailure::Unsigned));
+
+        unsigned.total_btc_reserves = 0;
+        unsigned.reserve_ratio = 0;
+        assert_eq!(ProofVerifier::verify_ratio(&unsigned), Err(ProofCheckFailure::NoReserves));
+    }
+}
@@ -0,0 +1,535 @@
+//! Regulator filings
+//!
+//! `RegulatoryReportBuilder` aggregates settled operations and velocity holds
+//! into a threshold-based report in the style of FinCEN/BSA filings:
+//!
+//! - large transactions at or above the reporting threshold (USD equivalent)
+//! - structuring patterns, where one user's sub-threshold operations add up
+//!   to the threshold within a rolling window
+//! - SAR candidates, taken from the router's velocity holds
+//!
+//! Amounts are converted with per-asset USD prices supplied by the caller;
+//! operations in an unpriced asset are counted but not evaluated. A
+//! `RedactionPolicy` masks or pseudonymizes user identifiers and operation
+//! IDs before the report leaves the compliance team.
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::format;
+use crate::ContractResult;
+use crate::integration_router_client::{OperationHold, VelocityOperation};
+use crate::reporting::{address_to_string, OperationSource, SettledOperation, SettlementKind};
+
+/// Reporting thresholds, in USD cents
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReportingThresholds {
+    /// Operations at or above this value are reported individually
+    pub large_transaction_usd_cents: u64,
+    /// Length of the rolling window sub-threshold operations are summed over
+    pub structuring_window: u64,
+    /// Sub-threshold operations needed in one window to form a pattern
+    pub structuring_min_operations: u32,
+    /// Operations below this value are too small to count towards structuring
+    pub structuring_floor_usd_cents: u64,
+}
+
+impl Default for ReportingThresholds {
+    /// $10,000 reports, with $3,000+ operations summed over 24 hours for structuring
+    fn default() -> Self {
+        Self {
+            large_transaction_usd_cents: 1_000_000,
+            structuring_window: 86_400,
+            structuring_min_operations: 2,
+            structuring_floor_usd_cents: 300_000,
+        }
+    }
+}
+
+/// How a PII-adjacent field appears in the report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Redaction {
+    /// Shown as recorded
+    Clear,
+    /// First and last four characters kept, the rest replaced with `*`
+    Mask,
+    /// Replaced with a label stable within the report, e.g. `subject-3`
+    Pseudonymize,
+}
+
+/// Redaction of the report's PII-adjacent fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RedactionPolicy {
+    pub subjects: Redaction,
+    pub operation_ids: Redaction,
+}
+
+impl RedactionPolicy {
+    /// Everything shown as recorded
+    pub fn clear() -> Self {
+        Self { subjects: Redaction::Clear, operation_ids: Redaction::Clear }
+    }
+
+    /// Subjects pseudonymized and operation IDs masked, for sharing outside compliance
+    pub fn external() -> Self {
+        Self { subjects: Redaction::Pseudonymize, operation_ids: Redaction::Mask }
+    }
+}
+
+/// Operation at or above the reporting threshold
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LargeTransaction {
+    pub operation_id: String,
+    pub subject: String,
+    pub kind: SettlementKind,
+    pub asset: String,
+    pub amount: u64,
+    pub usd_cents: u64,
+    pub completed_at: u64,
+}
+
+/// Sub-threshold operations by one subject that together reach the threshold
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StructuringPattern {
+    pub subject: String,
+    pub operation_ids: Vec<String>,
+    pub total_usd_cents: u64,
+    pub window_start: u64,
+    pub window_end: u64,
+}
+
+/// Subject flagged by the velocity detector for suspicious activity review
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SarCandidate {
+    pub subject: String,
+    pub operation: String,
+    pub reason: String,
+    pub window_volume: u64,
+    pub baseline_volume: u64,
+    pub window_count: u32,
+    pub flagged_at: u64,
+}
+
+/// Regulator report for a period
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegulatoryReport {
+    pub period_start: u64,
+    pub period_end: u64,
+    pub thresholds: ReportingThresholds,
+    pub redaction: RedactionPolicy,
+    pub operation_count: u64,
+    /// Operations in an asset without a USD price, left out of every section
+    pub unpriced_count: u64,
+    pub large_transactions: Vec<LargeTransaction>,
+    pub structuring_patterns: Vec<StructuringPattern>,
+    pub sar_candidates: Vec<SarCandidate>,
+}
+
+impl RegulatoryReport {
+    /// Serialize the report as JSON
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> ContractResult<String> {
+        serde_json::to_string(self)
+            .map_err(|e| crate::ContractError::ParseError(format!("report serialization failed: {}", e)))
+    }
+
+    /// Render the large transactions as CSV with a header row
+    pub fn large_transactions_to_csv(&self) -> Vec<u8> {
+        let mut csv = String::from("operation_id,subject,kind,asset,amount,usd_cents,completed_at\n");
+        for transaction in &self.large_transactions {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{}\n",
+                transaction.operation_id,
+                transaction.subject,
+                transaction.kind.name(),
+                transaction.asset,
+                transaction.amount,
+                transaction.usd_cents,
+                transaction.completed_at
+            ));
+        }
+        csv.into_bytes()
+    }
+
+    /// Whether any section needs a filing
+    pub fn has_findings(&self) -> bool {
+        !self.large_transactions.is_empty() || !self.structuring_patterns.is_empty() || !self.sar_candidates.is_empty()
+    }
+}
+
+/// Priced operation kept for structuring detection
+struct PricedOperation {
+    operation_id: String,
+    usd_cents: u64,
+    completed_at: u64,
+}
+
+/// Builder aggregating operations and holds into a `RegulatoryReport`
+pub struct RegulatoryReportBuilder {
+    period_start: u64,
+    period_end: u64,
+    page_size: u32,
+    thresholds: ReportingThresholds,
+    redaction: RedactionPolicy,
+    prices: BTreeMap<String, (u64, u32)>,
+    operation_count: u64,
+    unpriced_count: u64,
+    large_transactions: Vec<LargeTransaction>,
+    sub_threshold: BTreeMap<String, Vec<PricedOperation>>,
+    holds: Vec<SarCandidate>,
+}
+
+impl RegulatoryReportBuilder {
+    /// Create a builder for operations completed in `[period_start, period_end)`
+    pub fn new(period_start: u64, period_end: u64) -> Self {
+        Self {
+            period_start,
+            period_end,
+            page_size: 100,
+            thresholds: ReportingThresholds::default(),
+            redaction: RedactionPolicy::clear(),
+            prices: BTreeMap::new(),
+            operation_count: 0,
+            unpriced_count: 0,
+            large_transactions: Vec::new(),
+            sub_threshold: BTreeMap::new(),
+            holds: Vec::new(),
+        }
+    }
+
+    /// Replace the default thresholds
+    pub fn with_thresholds(mut self, thresholds: ReportingThresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Set the redaction applied by `finish`
+    pub fn with_redaction(mut self, redaction: RedactionPolicy) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Set how many operations are requested per source page
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Price an asset: `usd_cents` per whole unit of `10^decimals` base units
+    pub fn with_usd_price(mut self, asset: &str, usd_cents: u64, decimals: u32) -> Self {
+        self.prices.insert(asset.to_string(), (usd_cents, decimals));
+        self
+    }
+
+    /// USD value of an amount in cents, if the asset is priced
+    pub fn usd_cents(&self, asset: &str, amount: u64) -> Option<u64> {
+        let (usd_cents, decimals) = self.prices.get(asset)?;
+        let value = amount as u128 * *usd_cents as u128 / 10u128.checked_pow(*decimals)?;
+        Some(value.min(u64::MAX as u128) as u64)
+    }
+
+    /// Walk every page of a source and add its operations
+    pub fn load(&mut self, source: &dyn OperationSource) -> ContractResult<u64> {
+        let mut cursor = 0u32;
+        let mut loaded = 0u64;
+
+        loop {
+            let page = source.completed_operations(self.period_start, self.period_end, cursor, self.page_size)?;
+            let page_len = page.len() as u32;
+            for operation in page {
+                self.record(operation);
+                loaded += 1;
+            }
+
+            if page_len < self.page_size {
+                return Ok(loaded);
+            }
+            cursor += page_len;
+        }
+    }
+
+    /// Add a completed operation; operations outside the period are ignored
+    ///
+    /// The value of an operation is the value of what the user gave up.
+    pub fn record(&mut self, operation: SettledOperation) {
+        if operation.completed_at < self.period_start || operation.completed_at >= self.period_end {
+            return;
+        }
+        self.operation_count += 1;
+
+        let usd_cents = match self.usd_cents(&operation.asset, operation.amount) {
+            Some(usd_cents) => usd_cents,
+            None => {
+                self.unpriced_count += 1;
+                return;
+            },
+        };
+
+        if usd_cents >= self.thresholds.large_transaction_usd_cents {
+            self.large_transactions.push(LargeTransaction {
+                operation_id: operation.operation_id,
+                subject: operation.user,
+                kind: operation.kind,
+                asset: operation.asset,
+                amount: operation.amount,
+                usd_cents,
+                completed_at: operation.completed_at,
+            });
+        } else if usd_cents >= self.thresholds.structuring_floor_usd_cents {
+            self.sub_threshold.entry(operation.user).or_default().push(PricedOperation {
+                operation_id: operation.operation_id,
+                usd_cents,
+                completed_at: operation.completed_at,
+            });
+        }
+    }
+
+    /// Add a velocity hold as a SAR candidate; holds placed outside the period are ignored
+    pub fn record_hold(&mut self, hold: &OperationHold) {
+        if hold.placed_at < self.period_start || hold.placed_at >= self.period_end {
+            return;
+        }
+        let operation = match hold.operation {
+            VelocityOperation::Deposit => "deposit",
+            VelocityOperation::Withdrawal => "withdrawal",
+        };
+        self.holds.push(SarCandidate {
+            subject: address_to_string(&hold.user),
+            operation: operation.to_string(),
+            reason: hold.reason.clone(),
+            window_volume: hold.window_volume,
+            baseline_volume: hold.baseline_volume,
+            window_count: hold.window_count,
+            flagged_at: hold.placed_at,
+        });
+    }
+
+    /// Build the report from everything recorded so far
+    pub fn finish(&self) -> RegulatoryReport {
+        let mut redactor = Redactor::new(self.redaction);
+
+        let mut large_transactions = self.large_transactions.clone();
+        large_transactions.sort_by_key(|transaction| transaction.completed_at);
+        for transaction in large_transactions.iter_mut() {
+            transaction.subject = redactor.subject(&transaction.subject);
+            transaction.operation_id = redactor.operation_id(&transaction.operation_id);
+        }
+
+        let mut structuring_patterns = Vec::new();
+        for (subject, operations) in &self.sub_threshold {
+            for mut pattern in self.structuring_patterns(subject, operations) {
+                pattern.subject = redactor.subject(&pattern.subject);
+                for operation_id in pattern.operation_ids.iter_mut() {
+                    *operation_id = redactor.operation_id(operation_id);
+                }
+                structuring_patterns.push(pattern);
+            }
+        }
+
+        let mut sar_candidates = self.holds.clone();
+        for candidate in sar_candidates.iter_mut() {
+            candidate.subject = redactor.subject(&candidate.subject);
+        }
+
+        RegulatoryReport {
+            period_start: self.period_start,
+            period_end: self.period_end,
+            thresholds: self.thresholds.clone(),
+            redaction: self.redaction,
+            operation_count: self.operation_count,
+            unpriced_count: self.unpriced_count,
+            large_transactions,
+            structuring_patterns,
+            sar_candidates,
+        }
+    }
+
+    /// Non-overlapping windows of one subject's sub-threshold operations that reach the threshold
+    fn structuring_patterns(&self, subject: &str, operations: &[PricedOperation]) -> Vec<StructuringPattern> {
+        let mut sorted: Vec<&PricedOperation> = operations.iter().collect();
+        sorted.sort_by_key(|operation| operation.completed_at);
+
+        let mut patterns = Vec::new();
+        let mut start = 0;
+        while start < sorted.len() {
+            let window_start = sorted[start].completed_at;
+            let window: Vec<&PricedOperation> = sorted[start..]
+                .iter()
+                .take_while(|operation| operation.completed_at - window_start < self.thresholds.structuring_window)
+                .copied()
+                .collect();
+            let total_usd_cents: u64 = window.iter().map(|operation| operation.usd_cents).sum();
+
+            if window.len() as u32 >= self.thresholds.structuring_min_operations
+                && total_usd_cents >= self.thresholds.large_transaction_usd_cents
+            {
+                patterns.push(StructuringPattern {
+                    subject: subject.to_string(),
+                    operation_ids: window.iter().map(|operation| operation.operation_id.clone()).collect(),
+                    total_usd_cents,
+                    window_start,
+                    window_end: window[window.len() - 1].completed_at,
+                });
+                start += window.len();
+            } else {
+                start += 1;
+            }
+        }
+        patterns
+    }
+}
+
+/// Applies a redaction policy, keeping pseudonyms stable across sections
+struct Redactor {
+    policy: RedactionPolicy,
+    subjects: BTreeMap<String, String>,
+    operation_ids: BTreeMap<String, String>,
+}
+
+impl Redactor {
+    fn new(policy: RedactionPolicy) -> Self {
+        Self { policy, subjects: BTreeMap::new(), operation_ids: BTreeMap::new() }
+    }
+
+    fn subject(&mut self, value: &str) -> String {
+        redact(self.policy.subjects, &mut self.subjects, value, "subject")
+    }
+
+    fn operation_id(&mut self, value: &str) -> String {
+        redact(self.policy.operation_ids, &mut self.operation_ids, value, "operation")
+    }
+}
+
+fn redact(redaction: Redaction, pseudonyms: &mut BTreeMap<String, String>, value: &str, prefix: &str) -> String {
+    match redaction {
+        Redaction::Clear => value.to_string(),
+        Redaction::Mask => mask(value),
+        Redaction::Pseudonymize => {
+            let next = pseudonyms.len() + 1;
+            pseudonyms
+                .entry(value.to_string())
+                .or_insert_with(|| format!("{}-{}", prefix, next))
+                .clone()
+        },
+    }
+}
+
+/// Keep the first and last four characters of a value
+fn mask(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    if chars.len() <= 8 {
+        return "*".repeat(chars.len());
+    }
+    let mut masked: String = chars[..4].iter().collect();
+    masked.push_str(&"*".repeat(chars.len() - 8));
+    masked.extend(&chars[chars.len() - 4..]);
+    masked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporting::{BTC_ASSET, ISTSI_ASSET};
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    fn deposit(user: &str, sats: u64, completed_at: u64) -> SettledOperation {
+        SettledOperation {
+            operation_id: format!("op-{}-{}", user, completed_at),
+            kind: SettlementKind::Deposit,
+            user: user.to_string(),
+            asset: BTC_ASSET.to_string(),
+            amount: sats,
+            counter_asset: ISTSI_ASSET.to_string(),
+            counter_amount: sats,
+            fee: 0,
+            completed_at,
+        }
+    }
+
+    fn builder() -> RegulatoryReportBuilder {
+        // $50,000 per BTC, so $10,000 is 20,000,000 sats
+        RegulatoryReportBuilder::new(0, 7 * 86_400).with_usd_price(BTC_ASSET, 5_000_000, 8)
+    }
+
+    #[test]
+    fn test_large_transactions_and_structuring_are_flagged() {
+        let mut builder = builder();
+        assert_eq!(builder.usd_cents(BTC_ASSET, 20_000_000), Some(1_000_000));
+
+        builder.record(deposit("alice", 25_000_000, 100));
+        // Three $4,000 deposits within a day, then a fourth a day later
+        builder.record(deposit("bob", 8_000_000, 1_000));
+        builder.record(deposit("bob", 8_000_000, 20_000));
+        builder.record(deposit("bob", 8_000_000, 80_000));
+        builder.record(deposit("bob", 8_000_000, 200_000));
+        // Small deposits stay under the structuring floor
+        for i in 0..10 {
+            builder.record(deposit("carol", 1_000_000, 1_000 + i));
+        }
+        let mut unpriced = deposit("dave", 1, 500);
+        unpriced.asset = "USDC".to_string();
+        builder.record(unpriced);
+        builder.record(deposit("erin", 30_000_000, 8 * 86_400));
+
+        let report = builder.finish();
+        assert_eq!((report.operation_count, report.unpriced_count), (16, 1));
+        assert_eq!(report.large_transactions.len(), 1);
+        assert_eq!(report.large_transactions[0].usd_cents, 1_250_000);
+        assert_eq!(report.structuring_patterns.len(), 1);
+        let pattern = &report.structuring_patterns[0];
+        assert_eq!((pattern.subject.as_str(), pattern.operation_ids.len()), ("bob", 3));
+        assert_eq!((pattern.total_usd_cents, pattern.window_start, pattern.window_end), (1_200_000, 1_000, 80_000));
+        assert!(report.has_findings());
+
+        let csv = String::from_utf8(report.large_transactions_to_csv()).unwrap();
+        assert_eq!(
+            csv,
+            "operation_id,subject,kind,asset,amount,usd_cents,completed_at\nop-alice-100,alice,deposit,BTC,25000000,1250000,100\n"
+        );
+    }
+
+    #[test]
+    fn test_velocity_holds_become_redacted_sar_candidates() {
+        let env = Env::default();
+        let held = Address::generate(&env);
+        let hold = OperationHold {
+            user: held.clone(),
+            operation: VelocityOperation::Withdrawal,
+            reason: "volume_spike".to_string(),
+            window_volume: 90_000_000,
+            window_count: 4,
+            baseline_volume: 10_000_000,
+            placed_at: 5_000,
+        };
+        let held_id = address_to_string(&held);
+
+        let mut builder = builder().with_redaction(RedactionPolicy::external());
+        builder.record(deposit(&held_id, 25_000_000, 100));
+        builder.record_hold(&hold);
+        builder.record_hold(&OperationHold { placed_at: 30 * 86_400, ..hold.clone() });
+
+        let report = builder.finish();
+        assert_eq!(report.sar_candidates.len(), 1);
+        let candidate = &report.sar_candidates[0];
+        assert_eq!((candidate.operation.as_str(), candidate.reason.as_str()), ("withdrawal", "volume_spike"));
+
+        // The same subject gets the same pseudonym in every section
+        assert_eq!(candidate.subject, "subject-1");
+        assert_eq!(report.large_transactions[0].subject, "subject-1");
+        let masked = &report.large_transactions[0].operation_id;
+        assert_eq!(masked.len(), format!("op-{}-100", held_id).len());
+        assert!(masked.starts_with("op-") && masked.ends_with("*-100") && !masked.contains(&held_id[4..]));
+        #[cfg(feature = "serde")]
+        assert!(!report.to_json().unwrap().contains(&held_id));
+        #[cfg(feature = "serde")]
+        assert!(report.to_json().unwrap().contains("\"subjects\":\"pseudonymize\""));
+    }
+}
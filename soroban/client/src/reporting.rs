@@ -0,0 +1,673 @@
+//! Settlement reports for finance teams
+//!
+//! `ReportGenerator` folds completed operations for a reporting period into
+//! per-user and per-asset totals. Operations are fed one at a time, either
+//! from an `OperationSource` walked page by page or from contract events
+//! received through the `EventMonitor`, so a day's activity never has to be
+//! held in memory. The finished `SettlementReport` renders as CSV for import
+//! into accounting systems, and as JSON with the `serde` feature.
+//!
+//! `BalanceStatement` turns an account's token balance snapshots into a
+//! support-facing statement of balance changes over a period.
+//!
+//! `OperatorScoreboard` ranks the router's per-operator statistics for the
+//! admin dashboard and renders them as CSV.
+
+use alloc::collections::BTreeMap as HashMap;
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::format;
+use soroban_sdk::Address;
+use crate::ContractResult;
+use crate::event_monitor::{ContractEvent, EventData};
+use crate::istsi_token_client::BalanceSnapshot;
+use crate::integration_router_client::{OperatorStats, StatsPeriod};
+
+/// Asset label used for Bitcoin amounts (satoshis)
+pub const BTC_ASSET: &str = "BTC";
+
+/// Asset label used for iSTSi amounts
+pub const ISTSI_ASSET: &str = "iSTSi";
+
+/// Kind of settled operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SettlementKind {
+    Deposit,
+    Withdrawal,
+    Exchange,
+}
+
+impl SettlementKind {
+    /// Name used in CSV output
+    pub fn name(&self) -> &'static str {
+        match self {
+            SettlementKind::Deposit => "deposit",
+            SettlementKind::Withdrawal => "withdrawal",
+            SettlementKind::Exchange => "exchange",
+        }
+    }
+}
+
+/// Completed operation as seen by the report
+///
+/// `asset` is what the user gave up (BTC for deposits, iSTSi for withdrawals,
+/// the source token for exchanges) and `counter_asset` what they received.
+/// Fees are charged in `asset`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SettledOperation {
+    pub operation_id: String,
+    pub kind: SettlementKind,
+    pub user: String,
+    pub asset: String,
+    pub amount: u64,
+    pub counter_asset: String,
+    pub counter_amount: u64,
+    pub fee: u64,
+    pub completed_at: u64,
+}
+
+/// Totals for one user, operation kind and asset pair
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SettlementLine {
+    pub user: String,
+    pub kind: SettlementKind,
+    pub asset: String,
+    pub counter_asset: String,
+    pub count: u64,
+    pub volume: u64,
+    pub counter_volume: u64,
+    pub fees: u64,
+}
+
+/// Platform-side flows for one asset
+///
+/// Inflows are assets received by the platform (BTC on deposit, iSTSi on
+/// withdrawal, the source token on exchange); outflows are assets delivered
+/// to users.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssetSummary {
+    pub asset: String,
+    pub operation_count: u64,
+    pub inflow: u64,
+    pub outflow: u64,
+    pub fees: u64,
+    pub user_count: u32,
+}
+
+impl AssetSummary {
+    /// Inflow minus outflow; positive when the platform received more than it paid out
+    pub fn net_flow(&self) -> i128 {
+        self.inflow as i128 - self.outflow as i128
+    }
+}
+
+/// Settlement report for a period
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SettlementReport {
+    pub period_start: u64,
+    pub period_end: u64,
+    pub operation_count: u64,
+    pub skipped_count: u64,
+    pub lines: Vec<SettlementLine>,
+    pub assets: Vec<AssetSummary>,
+}
+
+impl SettlementReport {
+    /// Serialize the report as JSON
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> ContractResult<String> {
+        serde_json::to_string(self)
+            .map_err(|e| crate::ContractError::ParseError(format!("report serialization failed: {}", e)))
+    }
+
+    /// Render per-user lines as CSV with a header row
+    pub fn to_csv(&self) -> Vec<u8> {
+        let mut csv = String::from("user,kind,asset,counter_asset,count,volume,counter_volume,fees\n");
+        for line in &self.lines {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\n",
+                csv_field(&line.user),
+                line.kind.name(),
+                csv_field(&line.asset),
+                csv_field(&line.counter_asset),
+                line.count,
+                line.volume,
+                line.counter_volume,
+                line.fees
+            ));
+        }
+        csv.into_bytes()
+    }
+
+    /// Render per-asset totals as CSV with a header row
+    pub fn assets_to_csv(&self) -> Vec<u8> {
+        let mut csv = String::from("asset,operation_count,inflow,outflow,net_flow,fees,user_count\n");
+        for asset in &self.assets {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{}\n",
+                csv_field(&asset.asset),
+                asset.operation_count,
+                asset.inflow,
+                asset.outflow,
+                asset.net_flow(),
+                asset.fees,
+                asset.user_count
+            ));
+        }
+        csv.into_bytes()
+    }
+}
+
+/// One balance change on an account statement
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatementLine {
+    pub timestamp: u64,
+    pub balance: u64,
+    /// Change from the previous line, or from the opening balance for the first line
+    pub change: i128,
+}
+
+/// Balance history of one account over a period, built from token balance snapshots
+///
+/// Snapshots keep the last balance of each epoch, so a line can stand for
+/// several transfers made within the same epoch.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BalanceStatement {
+    pub account: String,
+    pub period_start: u64,
+    pub period_end: u64,
+    /// None when the period starts before the account's retained history
+    pub opening_balance: Option<u64>,
+    pub closing_balance: Option<u64>,
+    pub lines: Vec<StatementLine>,
+}
+
+impl BalanceStatement {
+    /// Build a statement from the snapshots taken in `period` (`[start, end)`)
+    pub fn from_snapshots(
+        account: &str,
+        period: (u64, u64),
+        opening_balance: Option<u64>,
+        snapshots: &[BalanceSnapshot],
+    ) -> Self {
+        let mut previous = opening_balance;
+        let mut lines = Vec::new();
+        for snapshot in snapshots.iter().filter(|snapshot| snapshot.timestamp >= period.0 && snapshot.timestamp < period.1) {
+            lines.push(StatementLine {
+                timestamp: snapshot.timestamp,
+                balance: snapshot.balance,
+                change: snapshot.balance as i128 - previous.unwrap_or(0) as i128,
+            });
+            previous = Some(snapshot.balance);
+        }
+
+        BalanceStatement {
+            account: account.to_string(),
+            period_start: period.0,
+            period_end: period.1,
+            opening_balance,
+            closing_balance: previous,
+            lines,
+        }
+    }
+
+    /// Render the statement lines as CSV with a header row
+    pub fn to_csv(&self) -> Vec<u8> {
+        let mut csv = String::from("account,timestamp,change,balance\n");
+        for line in &self.lines {
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                csv_field(&self.account),
+                line.timestamp,
+                line.change,
+                line.balance
+            ));
+        }
+        csv.into_bytes()
+    }
+}
+
+/// One operator's row on the scoreboard
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScoreboardLine {
+    /// 1 for the operator with the most completed operations
+    pub rank: u32,
+    pub operator: String,
+    pub started: u64,
+    pub completed: u64,
+    pub failed: u64,
+    pub failure_rate_bps: u64,
+    pub average_handling_seconds: u64,
+    pub sla_breaches: u64,
+}
+
+/// Operators ranked by completed operations over a statistics period
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperatorScoreboard {
+    pub period: String,
+    pub lines: Vec<ScoreboardLine>,
+}
+
+impl OperatorScoreboard {
+    /// Rank operator statistics; ties on completed operations go to the lower failure rate
+    pub fn from_stats(period: StatsPeriod, stats: &[OperatorStats]) -> Self {
+        let mut ranked: Vec<&OperatorStats> = stats.iter().collect();
+        ranked.sort_by(|a, b| b.completed.cmp(&a.completed).then(a.failure_rate.cmp(&b.failure_rate)));
+
+        OperatorScoreboard {
+            period: period.name().to_string(),
+            lines: ranked
+                .into_iter()
+                .enumerate()
+                .map(|(index, stats)| ScoreboardLine {
+                    rank: index as u32 + 1,
+                    operator: address_to_string(&stats.operator),
+                    started: stats.started,
+                    completed: stats.completed,
+                    failed: stats.failed,
+                    failure_rate_bps: stats.failure_rate,
+                    average_handling_seconds: stats.average_handling_seconds,
+                    sla_breaches: stats.sla_breaches,
+                })
+                .collect(),
+        }
+    }
+
+    /// Total SLA breaches across all operators
+    pub fn total_sla_breaches(&self) -> u64 {
+        self.lines.iter().map(|line| line.sla_breaches).sum()
+    }
+
+    /// Serialize the scoreboard as JSON
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> ContractResult<String> {
+        serde_json::to_string(self)
+            .map_err(|e| crate::ContractError::ParseError(format!("scoreboard serialization failed: {}", e)))
+    }
+
+    /// Render the scoreboard as CSV with a header row
+    pub fn to_csv(&self) -> Vec<u8> {
+        let mut csv = String::from("rank,operator,started,completed,failed,failure_rate_bps,average_handling_seconds,sla_breaches\n");
+        for line in &self.lines {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\n",
+                line.rank,
+                csv_field(&line.operator),
+                line.started,
+                line.completed,
+                line.failed,
+                line.failure_rate_bps,
+                line.average_handling_seconds,
+                line.sla_breaches
+            ));
+        }
+        csv.into_bytes()
+    }
+}
+
+/// Paged source of completed operations
+pub trait OperationSource {
+    /// Fetch up to `limit` operations completed in `[start, end)`, starting at `cursor`
+    fn completed_operations(&self, start: u64, end: u64, cursor: u32, limit: u32) -> ContractResult<Vec<SettledOperation>>;
+}
+
+/// Streaming aggregator for settlement reports
+pub struct ReportGenerator {
+    period_start: u64,
+    period_end: u64,
+    page_size: u32,
+    asset_labels: HashMap<Address, String>,
+    lines: HashMap<(String, SettlementKind, String, String), SettlementLine>,
+    assets: HashMap<String, AssetSummary>,
+    asset_users: BTreeSet<(String, String)>,
+    operation_count: u64,
+    skipped_count: u64,
+}
+
+impl ReportGenerator {
+    /// Create a generator for operations completed in `[period_start, period_end)`
+    pub fn new(period_start: u64, period_end: u64) -> Self {
+        Self {
+            period_start,
+            period_end,
+            page_size: 100,
+            asset_labels: HashMap::new(),
+            lines: HashMap::new(),
+            assets: HashMap::new(),
+            asset_users: BTreeSet::new(),
+            operation_count: 0,
+            skipped_count: 0,
+        }
+    }
+
+    /// Create a generator for the UTC day containing `timestamp`
+    pub fn for_day(timestamp: u64) -> Self {
+        let start = timestamp - timestamp % 86_400;
+        Self::new(start, start + 86_400)
+    }
+
+    /// Set how many operations are requested per source page
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Label a token contract in the report (defaults to its strkey)
+    pub fn with_asset_label(mut self, token: Address, label: &str) -> Self {
+        self.asset_labels.insert(token, label.to_string());
+        self
+    }
+
+    /// Walk every page of a source and add its operations
+    pub fn load(&mut self, source: &dyn OperationSource) -> ContractResult<u64> {
+        let mut cursor = 0u32;
+        let mut loaded = 0u64;
+
+        loop {
+            let page = source.completed_operations(self.period_start, self.period_end, cursor, self.page_size)?;
+            let page_len = page.len() as u32;
+            for operation in page {
+                self.record(operation);
+                loaded += 1;
+            }
+
+            if page_len < self.page_size {
+                return Ok(loaded);
+            }
+            cursor += page_len;
+        }
+    }
+
+    /// Add a completed operation; operations outside the period are counted as skipped
+    pub fn record(&mut self, operation: SettledOperation) {
+        if operation.completed_at < self.period_start || operation.completed_at >= self.period_end {
+            self.skipped_count += 1;
+            return;
+        }
+
+        let key = (
+            operation.user.clone(),
+            operation.kind,
+            operation.asset.clone(),
+            operation.counter_asset.clone(),
+        );
+        let line = self.lines.entry(key).or_insert_with(|| SettlementLine {
+            user: operation.user.clone(),
+            kind: operation.kind,
+            asset: operation.asset.clone(),
+            counter_asset: operation.counter_asset.clone(),
+            count: 0,
+            volume: 0,
+            counter_volume: 0,
+            fees: 0,
+        });
+        line.count += 1;
+        line.volume += operation.amount;
+        line.counter_volume += operation.counter_amount;
+        line.fees += operation.fee;
+
+        let inflow = self.asset_summary(&operation.asset, &operation.user);
+        inflow.operation_count += 1;
+        inflow.inflow += operation.amount;
+        inflow.fees += operation.fee;
+
+        let outflow = self.asset_summary(&operation.counter_asset, &operation.user);
+        outflow.operation_count += 1;
+        outflow.outflow += operation.counter_amount;
+
+        self.operation_count += 1;
+    }
+
+    /// Add a deposit, withdrawal or exchange event; returns false for other events
+    pub fn record_event(&mut self, event: &ContractEvent) -> bool {
+        match self.settled_operation(event) {
+            Some(operation) => {
+                self.record(operation);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Build the report from everything recorded so far
+    pub fn finish(&self) -> SettlementReport {
+        SettlementReport {
+            period_start: self.period_start,
+            period_end: self.period_end,
+            operation_count: self.operation_count,
+            skipped_count: self.skipped_count,
+            lines: self.lines.values().cloned().collect(),
+            assets: self.assets.values().cloned().collect(),
+        }
+    }
+
+    /// Convert a contract event into a settled operation
+    pub fn settled_operation(&self, event: &ContractEvent) -> Option<SettledOperation> {
+        let operation_id = event.correlation_id()
+            .map(hex::encode)
+            .unwrap_or_else(|| event.transaction_hash.clone());
+
+        let (kind, user, asset, amount, counter_asset, counter_amount, fee) = match &event.data {
+            EventData::BitcoinDeposit { user, btc_amount, istsi_amount, .. } => (
+                SettlementKind::Deposit, user, String::from(BTC_ASSET), *btc_amount, String::from(ISTSI_ASSET), *istsi_amount, 0,
+            ),
+            EventData::TokenWithdrawal { user, istsi_amount, btc_amount, .. } => (
+                SettlementKind::Withdrawal, user, String::from(ISTSI_ASSET), *istsi_amount, String::from(BTC_ASSET), *btc_amount, 0,
+            ),
+            EventData::CrossTokenExchange { user, from_token, to_token, from_amount, to_amount, fee, .. } => (
+                SettlementKind::Exchange, user, self.asset_label(from_token), *from_amount, self.asset_label(to_token), *to_amount, *fee,
+            ),
+            _ => return None,
+        };
+
+        Some(SettledOperation {
+            operation_id,
+            kind,
+            user: address_to_string(user),
+            asset,
+            amount,
+            counter_asset,
+            counter_amount,
+            fee,
+            completed_at: event.timestamp,
+        })
+    }
+
+    fn asset_label(&self, token: &Address) -> String {
+        self.asset_labels.get(token).cloned().unwrap_or_else(|| address_to_string(token))
+    }
+
+    fn asset_summary(&mut self, asset: &str, user: &str) -> &mut AssetSummary {
+        let summary = self.assets.entry(asset.to_string()).or_insert_with(|| AssetSummary {
+            asset: asset.to_string(),
+            ..AssetSummary::default()
+        });
+        if self.asset_users.insert((asset.to_string(), user.to_string())) {
+            summary.user_count += 1;
+        }
+        summary
+    }
+}
+
+/// Render an address as its strkey
+pub(crate) fn address_to_string(address: &Address) -> String {
+    let strkey = address.to_string();
+    let mut bytes = alloc::vec![0u8; strkey.len() as usize];
+    strkey.copy_into_slice(&mut bytes);
+    String::from_utf8(bytes).unwrap_or_default()
+}
+
+/// Quote a CSV field when it contains a separator, quote or newline
+fn csv_field(value: &str) -> String {
+    if value.contains(|c| c == ',' || c == '"' || c == '\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn operation(kind: SettlementKind, user: &str, amount: u64, counter_amount: u64, fee: u64, completed_at: u64) -> SettledOperation {
+        let (asset, counter_asset) = match kind {
+            SettlementKind::Deposit => (BTC_ASSET, ISTSI_ASSET),
+            SettlementKind::Withdrawal => (ISTSI_ASSET, BTC_ASSET),
+            SettlementKind::Exchange => (ISTSI_ASSET, "USDC"),
+        };
+        SettledOperation {
+            operation_id: format!("{}-{}", user, completed_at),
+            kind,
+            user: user.to_string(),
+            asset: asset.to_string(),
+            amount,
+            counter_asset: counter_asset.to_string(),
+            counter_amount,
+            fee,
+            completed_at,
+        }
+    }
+
+    struct PagedSource(Vec<SettledOperation>);
+
+    impl OperationSource for PagedSource {
+        fn completed_operations(&self, _start: u64, _end: u64, cursor: u32, limit: u32) -> ContractResult<Vec<SettledOperation>> {
+            Ok(self.0.iter().skip(cursor as usize).take(limit as usize).cloned().collect())
+        }
+    }
+
+    #[test]
+    fn test_report_aggregates_per_user_and_asset() {
+        let mut generator = ReportGenerator::for_day(86_400 + 500).with_page_size(2);
+        let source = PagedSource(alloc::vec![
+            operation(SettlementKind::Deposit, "alice", 100_000, 100_000, 0, 86_400),
+            operation(SettlementKind::Deposit, "alice", 50_000, 50_000, 0, 90_000),
+            operation(SettlementKind::Withdrawal, "bob", 30_000, 29_700, 300, 100_000),
+            operation(SettlementKind::Exchange, "bob", 10_000, 9_970, 30, 120_000),
+            operation(SettlementKind::Deposit, "carol", 1, 1, 0, 172_800),
+        ]);
+        assert_eq!(generator.load(&source).unwrap(), 5);
+
+        let report = generator.finish();
+        assert_eq!(report.operation_count, 4);
+        assert_eq!(report.skipped_count, 1);
+
+        let alice = report.lines.iter().find(|line| line.user == "alice").unwrap();
+        assert_eq!((alice.count, alice.volume, alice.counter_volume), (2, 150_000, 150_000));
+
+        let istsi = report.assets.iter().find(|asset| asset.asset == ISTSI_ASSET).unwrap();
+        assert_eq!(istsi.outflow, 150_000);
+        assert_eq!(istsi.inflow, 40_000);
+        assert_eq!(istsi.fees, 330);
+        assert_eq!(istsi.user_count, 2);
+        assert_eq!(istsi.net_flow(), -110_000);
+
+        let csv = String::from_utf8(report.to_csv()).unwrap();
+        assert!(csv.starts_with("user,kind,asset,counter_asset,count,volume,counter_volume,fees\n"));
+        assert!(csv.contains("bob,withdrawal,iSTSi,BTC,1,30000,29700,300\n"));
+        assert_eq!(csv.lines().count(), 4);
+
+        #[cfg(feature = "serde")]
+        assert!(report.to_json().unwrap().contains("\"kind\":\"exchange\""));
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+    }
+
+    #[test]
+    fn test_balance_statement_from_snapshots() {
+        let snapshots = [
+            BalanceSnapshot { epoch: 1, timestamp: 86_400, balance: 150 },
+            BalanceSnapshot { epoch: 2, timestamp: 172_900, balance: 120 },
+            BalanceSnapshot { epoch: 3, timestamp: 259_300, balance: 500 },
+        ];
+
+        let statement = BalanceStatement::from_snapshots("alice", (86_400, 259_200), Some(100), &snapshots);
+        assert_eq!(statement.lines.len(), 2);
+        assert_eq!((statement.lines[0].change, statement.lines[1].change), (50, -30));
+        assert_eq!(statement.closing_balance, Some(120));
+        let csv = String::from_utf8(statement.to_csv()).unwrap();
+        assert_eq!(csv, "account,timestamp,change,balance\nalice,86400,50,150\nalice,172900,-30,120\n");
+
+        // Without an opening balance the first change counts from zero
+        let statement = BalanceStatement::from_snapshots("alice", (0, 86_401), None, &snapshots);
+        assert_eq!((statement.lines[0].change, statement.closing_balance), (150, Some(150)));
+        assert_eq!(BalanceStatement::from_snapshots("alice", (0, 10), Some(7), &snapshots).closing_balance, Some(7));
+    }
+
+    #[test]
+    fn test_exchange_event_fee_reaches_the_report() {
+        use soroban_sdk::{testutils::Address as _, Env};
+
+        let env = Env::default();
+        let usdc = Address::generate(&env);
+        let mut generator = ReportGenerator::new(0, 86_400).with_asset_label(usdc.clone(), "USDC");
+        let event = ContractEvent {
+            contract_address: Address::generate(&env),
+            event_type: String::from("exchange"),
+            topics: Vec::new(),
+            data: EventData::CrossTokenExchange {
+                user: Address::generate(&env),
+                from_token: usdc,
+                to_token: Address::generate(&env),
+                from_amount: 10_000,
+                to_amount: 9_970,
+                exchange_rate: 10_000,
+                fee: 30,
+            },
+            timestamp: 1_000,
+            block_number: 1,
+            transaction_hash: String::from("ab"),
+        };
+
+        assert_eq!(generator.settled_operation(&event).unwrap().fee, 30);
+        assert!(generator.record_event(&event));
+        let report = generator.finish();
+        assert_eq!(report.lines[0].fees, 30);
+        assert_eq!(report.assets.iter().find(|asset| asset.asset == "USDC").unwrap().fees, 30);
+    }
+
+    #[test]
+    fn test_operator_scoreboard_ranks_and_renders() {
+        use soroban_sdk::{testutils::Address as _, Env};
+
+        let env = Env::default();
+        let stats = |completed: u64, failed: u64, sla_breaches: u64| OperatorStats {
+            operator: Address::generate(&env),
+            period: StatsPeriod::Week,
+            period_start: 0,
+            started: completed + failed,
+            completed,
+            failed,
+            failure_rate: failed * 10_000 / (completed + failed),
+            average_handling_seconds: 40,
+            sla_breaches,
+        };
+        let operators = [stats(3, 1, 1), stats(5, 0, 0), stats(3, 0, 2)];
+
+        let scoreboard = OperatorScoreboard::from_stats(StatsPeriod::Week, &operators);
+        assert_eq!(scoreboard.period, "week");
+        let order: Vec<u64> = scoreboard.lines.iter().map(|line| line.failure_rate_bps).collect();
+        assert_eq!(order, alloc::vec![0, 0, 2_500]);
+        assert_eq!(scoreboard.lines[1].sla_breaches, 2);
+        assert_eq!(scoreboard.total_sla_breaches(), 3);
+
+        let csv = String::from_utf8(scoreboard.to_csv()).unwrap();
+        let mut rows = csv.lines();
+        assert_eq!(rows.next(), Some("rank,operator,started,completed,failed,failure_rate_bps,average_handling_seconds,sla_breaches"));
+        assert!(rows.nth(2).unwrap().starts_with("3,"));
+        assert!(csv.ends_with(",4,3,1,2500,40,1\n"));
+        #[cfg(feature = "serde")]
+        assert!(scoreboard.to_json().unwrap().contains("\"rank\":1"));
+    }
+}
@@ -1,5 +1,6 @@
-use soroban_sdk::{Address, Env, BytesN, String as SorobanString};
+use soroban_sdk::{Address, Env, Bytes, BytesN, String as SorobanString};
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use crate::{ContractClient, ContractResult, ContractError, OperationContext};
 
 /// Client interface for the Reserve Manager contract
@@ -357,6 +358,619 @@ impl ReserveManagerClient {
         Ok(())
     }
 
+    /// Register a Bitcoin UTXO held in custody
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `outpoint` - Transaction ID and output index
+    /// * `amount` - Amount in satoshis
+    /// * `custody_address` - Bitcoin address holding the output
+    /// * `confirmation_height` - Block height the output was confirmed at
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn register_utxo(
+        &self,
+        ctx: &OperationContext,
+        outpoint: &UtxoOutpoint,
+        amount: u64,
+        custody_address: &str,
+        confirmation_height: u64,
+    ) -> ContractResult<()> {
+        if amount == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidAmount
+            ));
+        }
+
+        if custody_address.is_empty() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("utxo_reg"), outpoint.txid.clone()),
+            (outpoint.vout, amount, confirmation_height)
+        );
+
+        Ok(())
+    }
+
+    /// Mark UTXOs as spent by a withdrawal
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `withdrawal_id` - Withdrawal request that spent the outputs
+    /// * `outpoints` - Outputs consumed by the withdrawal transaction
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn mark_utxos_spent(
+        &self,
+        ctx: &OperationContext,
+        withdrawal_id: &BytesN<32>,
+        outpoints: &[UtxoOutpoint],
+    ) -> ContractResult<()> {
+        if outpoints.is_empty() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("utxo_spnt"), withdrawal_id.clone()),
+            outpoints.len() as u32
+        );
+
+        Ok(())
+    }
+
+    /// Get all unspent UTXOs
+    /// 
+    /// # Returns
+    /// * `Ok(utxos)` - Unspent UTXOs in registration order
+    /// * `Err(ContractError)` - Error details
+    pub fn get_unspent_utxos(&self) -> ContractResult<Vec<Utxo>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Get the sum of unspent UTXO amounts
+    /// 
+    /// # Returns
+    /// * `Ok(total)` - Total unspent satoshis
+    /// * `Err(ContractError)` - Error details
+    pub fn get_unspent_utxo_total(&self) -> ContractResult<u64> {
+        // In a real implementation, this would query the contract
+        self.get_total_reserves()
+    }
+
+    /// Get the unspent UTXO total, or `None` until the first UTXO is registered
+    /// 
+    /// Router reconciliation compares this against the custodied part of the
+    /// reserve counter (total reserves minus allocations) once the ledger is in use.
+    /// 
+    /// # Returns
+    /// * `Ok(total)` - Total unspent satoshis, if the UTXO ledger is in use
+    /// * `Err(ContractError)` - Error details
+    pub fn get_utxo_ledger_total(&self) -> ContractResult<Option<u64>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Register a yield venue or update its allocation cap (admin only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `name` - Venue name (at most 32 bytes)
+    /// * `cap_sats` - Maximum satoshis deployable to the venue
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn register_allocation_venue(
+        &self,
+        ctx: &OperationContext,
+        name: &str,
+        cap_sats: u64,
+    ) -> ContractResult<()> {
+        if name.is_empty() || name.len() > 32 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("venue_reg"), SorobanString::from_str(&self.env, name)),
+            cap_sats
+        );
+
+        Ok(())
+    }
+
+    /// Record BTC deployed from reserves to a venue
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `venue` - Venue name
+    /// * `amount` - Amount in satoshis, within the venue cap and undeployed reserves
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn allocate_reserves(
+        &self,
+        ctx: &OperationContext,
+        venue: &str,
+        amount: u64,
+    ) -> ContractResult<()> {
+        if amount == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidAmount
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("alloc"), SorobanString::from_str(&self.env, venue)),
+            amount
+        );
+
+        Ok(())
+    }
+
+    /// Record BTC returned from a venue to custody
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `venue` - Venue name
+    /// * `amount` - Amount in satoshis, at most the venue allocation
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn recall_allocation(
+        &self,
+        ctx: &OperationContext,
+        venue: &str,
+        amount: u64,
+    ) -> ContractResult<()> {
+        if amount == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidAmount
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("recall"), SorobanString::from_str(&self.env, venue)),
+            amount
+        );
+
+        Ok(())
+    }
+
+    /// Attest yield earned by a venue over a reporting period
+    /// 
+    /// The caller must be a registered allocation operator. Periods are
+    /// attested in order and the yield is reported as revenue, not reserves.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `venue` - Venue name
+    /// * `yield_sats` - Yield earned in the period, in satoshis
+    /// * `period_end` - End of the reporting period
+    /// * `report_hash` - Hash of the venue statement
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn attest_venue_yield(
+        &self,
+        ctx: &OperationContext,
+        venue: &str,
+        yield_sats: u64,
+        period_end: u64,
+        report_hash: &BytesN<32>,
+    ) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("yield_att"), SorobanString::from_str(&self.env, venue), ctx.caller.clone()),
+            (yield_sats, period_end, report_hash.clone())
+        );
+
+        Ok(())
+    }
+
+    /// Get the satoshis currently deployed across all venues
+    /// 
+    /// # Returns
+    /// * `Ok(total)` - Total allocated satoshis
+    /// * `Err(ContractError)` - Error details
+    pub fn get_total_allocated(&self) -> ContractResult<u64> {
+        // In a real implementation, this would query the contract
+        Ok(0)
+    }
+
+    /// Break reserves down into on-chain custody and venue allocations
+    /// 
+    /// # Returns
+    /// * `Ok(report)` - Reserve categories, per-venue allocations and accrued yield
+    /// * `Err(ContractError)` - Error details
+    pub fn get_allocation_report(&self) -> ContractResult<AllocationReport> {
+        // In a real implementation, this would query the contract
+        let total_reserves = self.get_total_reserves()?;
+        let onchain_sats = self.get_unspent_utxo_total()?;
+        let allocated_sats = self.get_total_allocated()?;
+
+        Ok(AllocationReport {
+            total_reserves,
+            onchain_sats,
+            allocated_sats,
+            unreconciled_sats: total_reserves as i64 - onchain_sats as i64 - allocated_sats as i64,
+            accrued_yield_sats: 0,
+            venues: Vec::new(),
+            generated_at: self.env.ledger().timestamp(),
+        })
+    }
+
+    /// Set the target allocation across hot, warm and cold wallets (admin only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `policy` - Per-bucket targets summing to 10000 basis points
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn set_custody_policy(
+        &self,
+        ctx: &OperationContext,
+        policy: &CustodyPolicy,
+    ) -> ContractResult<()> {
+        if policy.hot_target_bps + policy.warm_target_bps + policy.cold_target_bps != 10_000
+            || policy.drift_tolerance_bps > 10_000
+        {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("custody"), ctx.caller.clone()),
+            (policy.hot_target_bps, policy.warm_target_bps, policy.cold_target_bps)
+        );
+
+        Ok(())
+    }
+
+    /// Get the custody policy
+    /// 
+    /// # Returns
+    /// * `Ok(policy)` - Target allocation and drift tolerance
+    /// * `Err(ContractError)` - Error details
+    pub fn get_custody_policy(&self) -> ContractResult<CustodyPolicy> {
+        // In a real implementation, this would query the contract
+        Ok(CustodyPolicy {
+            hot_target_bps: 500,
+            warm_target_bps: 1_500,
+            cold_target_bps: 8_000,
+            drift_tolerance_bps: 500,
+        })
+    }
+
+    /// Request a sweep of BTC between wallet buckets
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `from_bucket` - Bucket the BTC leaves
+    /// * `to_bucket` - Bucket the BTC arrives in
+    /// * `amount` - Amount in satoshis, at most the source bucket balance
+    /// 
+    /// # Returns
+    /// * `Ok(request_id)` - Rebalance request ID
+    /// * `Err(ContractError)` - Error details
+    pub fn request_rebalance(
+        &self,
+        ctx: &OperationContext,
+        from_bucket: WalletBucket,
+        to_bucket: WalletBucket,
+        amount: u64,
+    ) -> ContractResult<u64> {
+        if amount == 0 {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidAmount
+            ));
+        }
+        if from_bucket == to_bucket {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(1)
+    }
+
+    /// Record a confirmed sweep, moving the balance between buckets
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `request_id` - Pending rebalance request
+    /// * `btc_tx_hash` - Sweep transaction hash
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn complete_rebalance(
+        &self,
+        ctx: &OperationContext,
+        request_id: u64,
+        btc_tx_hash: &BytesN<32>,
+    ) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("rebal_ok"), request_id),
+            btc_tx_hash.clone()
+        );
+
+        Ok(())
+    }
+
+    /// Cancel a pending rebalance request
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `request_id` - Pending rebalance request
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn cancel_rebalance(&self, ctx: &OperationContext, request_id: u64) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        Ok(())
+    }
+
+    /// Get the rebalance requests still awaiting a sweep
+    /// 
+    /// Replenishment requests are queued automatically whenever the hot
+    /// wallet cannot float the pending withdrawals.
+    /// 
+    /// # Returns
+    /// * `Ok(requests)` - Pending requests, oldest first
+    /// * `Err(ContractError)` - Error details
+    pub fn get_pending_rebalances(&self) -> ContractResult<Vec<RebalanceRequest>> {
+        // In a real implementation, this would query the contract
+        Ok(Vec::new())
+    }
+
+    /// Break custodied reserves down by wallet bucket against the policy targets
+    /// 
+    /// # Returns
+    /// * `Ok(report)` - Bucket balances, drift and the hot wallet float
+    /// * `Err(ContractError)` - Error details
+    pub fn get_custody_report(&self) -> ContractResult<CustodyReport> {
+        // In a real implementation, this would query the contract
+        let policy = self.get_custody_policy()?;
+        let buckets = [
+            (WalletBucket::Hot, policy.hot_target_bps),
+            (WalletBucket::Warm, policy.warm_target_bps),
+            (WalletBucket::Cold, policy.cold_target_bps),
+        ]
+        .into_iter()
+        .map(|(bucket, target_bps)| BucketAllocation {
+            bucket,
+            balance_sats: 0,
+            actual_bps: 0,
+            target_bps,
+            drifted: false,
+        })
+        .collect();
+
+        Ok(CustodyReport {
+            total_reserves: self.get_total_reserves()?,
+            custodied_sats: 0,
+            pending_withdrawal_sats: 0,
+            hot_shortfall_sats: 0,
+            queued_replenishment_sats: 0,
+            buckets,
+            generated_at: self.env.ledger().timestamp(),
+        })
+    }
+
+    /// Allow or revoke a custodian attesting wallet balances (admin only)
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `custodian` - Custodian address
+    /// * `enabled` - Whether the custodian may attest
+    /// 
+    /// # Returns
+    /// * `Ok(())` - Success
+    /// * `Err(ContractError)` - Error details
+    pub fn set_custodian(
+        &self,
+        ctx: &OperationContext,
+        custodian: &Address,
+        enabled: bool,
+    ) -> ContractResult<()> {
+        // In a real implementation, this would call the contract
+        self.env.events().publish(
+            (soroban_sdk::symbol_short!("custodian"), custodian.clone()),
+            enabled
+        );
+
+        Ok(())
+    }
+
+    /// Attest a wallet bucket's balance and the movements behind it
+    /// 
+    /// The caller must be a registered custodian. Movements matching a pending
+    /// rebalance request settle it; unmatched movements raise an alert.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Operation context
+    /// * `bucket` - Wallet bucket the balance refers to
+    /// * `balance_sats` - Attested balance in satoshis
+    /// * `movements` - Off-chain movements since the previous attestation
+    /// * `evidence_hash` - Hash of the signed custodian statement
+    /// * `as_of` - Time the balance refers to, after the bucket's previous attestation
+    /// 
+    /// # Returns
+    /// * `Ok(attestation)` - Recorded attestation with its matching outcome
+    /// * `Err(ContractError)` - Error details
+    pub fn submit_custodian_attestation(
+        &self,
+        ctx: &OperationContext,
+        bucket: WalletBucket,
+        balance_sats: u64,
+        movements: Vec<CustodyMovement>,
+        evidence_hash: &BytesN<32>,
+        as_of: u64,
+    ) -> ContractResult<CustodianAttestation> {
+        if evidence_hash.to_array() == [0u8; 32] || as_of > self.env.ledger().timestamp() {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        // In a real implementation, this would call the contract
+        Ok(CustodianAttestation {
+            attestation_id: 1,
+            custodian: ctx.caller.clone(),
+            bucket,
+            balance_sats,
+            recorded_sats: balance_sats,
+            movements,
+            matched_requests: Vec::new(),
+            unmatched_movements: 0,
+            evidence_hash: evidence_hash.clone(),
+            as_of,
+            submitted_at: self.env.ledger().timestamp(),
+        })
+    }
+
+    /// Reserves according to the latest custodian attestations
+    /// 
+    /// Reconciliation reports these next to the reserve counter and only
+    /// flags a gap while every bucket's attestation is within the max age.
+    /// 
+    /// # Returns
+    /// * `Ok(attested)` - Attested bucket balances and their freshness
+    /// * `Err(ContractError)` - Error details
+    pub fn get_attested_reserves(&self) -> ContractResult<AttestedReserves> {
+        // In a real implementation, this would query the contract
+        Ok(AttestedReserves {
+            hot_sats: 0,
+            warm_sats: 0,
+            cold_sats: 0,
+            total_sats: 0,
+            attested_buckets: 0,
+            oldest_as_of: 0,
+            max_age: 86_400,
+            fresh: false,
+        })
+    }
+
+    /// Get a merkle inclusion proof for a UTXO against the latest proof of reserves
+    /// 
+    /// # Arguments
+    /// * `utxo_id` - Bitcoin txid of the output
+    /// * `vout` - Output index within the transaction
+    /// 
+    /// # Returns
+    /// * `Ok(Some(proof))` - Inclusion proof if the UTXO was unspent when the proof was published
+    /// * `Ok(None)` - No proof published or UTXO not included
+    /// * `Err(ContractError)` - Error details
+    pub fn get_reserve_merkle_proof(&self, utxo_id: &BytesN<32>, vout: u32) -> ContractResult<Option<ReserveMerkleProof>> {
+        // In a real implementation, this would query the contract
+        Ok(None)
+    }
+
+    /// Verify a merkle inclusion proof locally, without trusting the contract
+    /// 
+    /// Uses the same hashing as the Reserve Manager: leaves are
+    /// `sha256(0x00 || txid || vout_be || amount_be)`, interior nodes are
+    /// `sha256(0x01 || left || right)`, and an odd last node is paired with itself.
+    /// 
+    /// # Arguments
+    /// * `proof` - Inclusion proof obtained from `get_reserve_merkle_proof`
+    /// * `root` - Merkle root published in the proof of reserves
+    /// 
+    /// # Returns
+    /// * `true` if the proof links the UTXO to `root`
+    pub fn verify_reserve_inclusion(&self, proof: &ReserveMerkleProof, root: &BytesN<32>) -> bool {
+        let mut hash = self.reserve_merkle_leaf(&proof.utxo_id, proof.vout, proof.amount);
+        let mut index = proof.leaf_index;
+
+        for sibling in proof.siblings.iter() {
+            hash = if index % 2 == 0 {
+                self.reserve_merkle_node(&hash, sibling)
+            } else {
+                self.reserve_merkle_node(sibling, &hash)
+            };
+            index /= 2;
+        }
+
+        hash == *root
+    }
+
+    /// Recompute the merkle root over a full list of reserve entries
+    /// 
+    /// Auditors holding the complete unspent UTXO set can use this to check the
+    /// published root independently.
+    /// 
+    /// # Arguments
+    /// * `entries` - Unspent outputs as `(txid, vout, amount)` in contract order
+    /// 
+    /// # Returns
+    /// * Merkle root (all zeros for an empty set)
+    pub fn compute_reserve_merkle_root(&self, entries: &[(BytesN<32>, u32, u64)]) -> BytesN<32> {
+        if entries.is_empty() {
+            return BytesN::from_array(&self.env, &[0u8; 32]);
+        }
+
+        let mut level: Vec<BytesN<32>> = entries
+            .iter()
+            .map(|(utxo_id, vout, amount)| self.reserve_merkle_leaf(utxo_id, *vout, *amount))
+            .collect();
+
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| self.reserve_merkle_node(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+                .collect();
+        }
+
+        level.remove(0)
+    }
+
+    /// Helper function to hash a reserve entry into a merkle leaf
+    fn reserve_merkle_leaf(&self, utxo_id: &BytesN<32>, vout: u32, amount: u64) -> BytesN<32> {
+        let mut data = Bytes::new(&self.env);
+        data.push_back(0x00);
+        data.extend_from_array(&utxo_id.to_array());
+        data.extend_from_array(&vout.to_be_bytes());
+        data.extend_from_array(&amount.to_be_bytes());
+        self.env.crypto().sha256(&data).into()
+    }
+
+    /// Helper function to hash two child nodes into their parent
+    fn reserve_merkle_node(&self, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+        let mut data = Bytes::new(&self.env);
+        data.push_back(0x01);
+        data.extend_from_array(&left.to_array());
+        data.extend_from_array(&right.to_array());
+        self.env.crypto().sha256(&data).into()
+    }
+
     /// Helper function to generate withdrawal IDs
     fn generate_withdrawal_id(&self, user: &Address, amount: u64) -> BytesN<32> {
         let timestamp = self.env.ledger().timestamp();
@@ -423,31 +1037,39 @@ impl ContractClient for ReserveManagerClient {
 
 /// Bitcoin transaction structure
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BitcoinTransaction {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
     pub tx_hash: BytesN<32>,
     pub amount: u64,
     pub confirmations: u32,
     pub timestamp: u64,
     pub processed: bool,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
     pub user: Address,
     pub block_height: u64,
 }
 
 /// Withdrawal request structure
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawalRequest {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
     pub withdrawal_id: BytesN<32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
     pub user: Address,
     pub amount: u64,
     pub btc_address: String,
     pub timestamp: u64,
     pub processed: bool,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_bytes"))]
     pub btc_tx_hash: Option<BytesN<32>>,
     pub status: WithdrawalStatus,
 }
 
 /// Withdrawal status enumeration
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WithdrawalStatus {
     Pending,
     Processing,
@@ -458,6 +1080,7 @@ pub enum WithdrawalStatus {
 
 /// Reserve thresholds structure
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReserveThresholds {
     pub minimum_ratio: u64,     // Basis points (10000 = 100%)
     pub warning_ratio: u64,     // Basis points
@@ -467,11 +1090,234 @@ pub struct ReserveThresholds {
 
 /// Proof of reserves structure
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProofOfReserves {
     pub total_btc_reserves: u64,
     pub total_token_supply: u64,
     pub reserve_ratio: u64,      // Basis points
     pub timestamp: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
     pub merkle_root: BytesN<32>, // Merkle root of all deposits
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
     pub signature: BytesN<64>,   // Cryptographic proof
-}
\ No newline at end of file
+}
+
+/// Bitcoin transaction output reference
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UtxoOutpoint {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub txid: BytesN<32>,
+    pub vout: u32,
+}
+
+/// UTXO ledger entry
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Utxo {
+    pub outpoint: UtxoOutpoint,
+    pub amount: u64,                       // Satoshis
+    pub custody_address: String,
+    pub confirmation_height: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_address"))]
+    pub depositor: Option<Address>,        // Set when txid matches a registered deposit
+    pub registered_at: u64,
+    pub spent: bool,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_bytes"))]
+    pub spent_by: Option<BytesN<32>>,      // Withdrawal ID that spent this UTXO
+    pub spent_at: Option<u64>,
+}
+
+/// Merkle inclusion proof for a reserve UTXO
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReserveMerkleProof {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub utxo_id: BytesN<32>,
+    pub vout: u32,
+    pub amount: u64,
+    pub leaf_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes_vec"))]
+    pub siblings: Vec<BytesN<32>>, // Sibling hashes from leaf level up to the root
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub root: BytesN<32>,          // Root the proof was built against
+}
+
+/// Reserves deployed to a yield venue
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AllocationVenue {
+    pub name: String,
+    pub cap_sats: u64,
+    pub allocated_sats: u64,
+    pub accrued_yield_sats: u64,
+    pub last_attested_at: u64,                // End of the last attested period
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_bytes"))]
+    pub last_report_hash: Option<BytesN<32>>, // Venue statement behind the last attestation
+    pub registered_at: u64,
+}
+
+/// Reserve breakdown by custody category
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AllocationReport {
+    pub total_reserves: u64,
+    pub onchain_sats: u64,          // Unspent custody UTXOs
+    pub allocated_sats: u64,        // Deployed to venues, not on-chain
+    pub unreconciled_sats: i64,     // Reserves explained by neither category
+    pub accrued_yield_sats: u64,
+    pub venues: Vec<AllocationVenue>,
+    pub generated_at: u64,
+}
+
+/// Custody wallet tier
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WalletBucket {
+    Hot,
+    Warm,
+    Cold,
+}
+
+/// Target share of reserves per wallet bucket
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustodyPolicy {
+    pub hot_target_bps: u32,
+    pub warm_target_bps: u32,
+    pub cold_target_bps: u32,
+    pub drift_tolerance_bps: u32,   // Allowed deviation before a drift alert
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RebalanceStatus {
+    Pending,
+    Completed,
+    Cancelled,
+}
+
+/// Sweep of BTC between wallet buckets
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RebalanceRequest {
+    pub request_id: u64,
+    pub from_bucket: WalletBucket,
+    pub to_bucket: WalletBucket,
+    pub amount: u64,
+    pub replenishment: bool,            // Queued automatically to refill the hot float
+    pub requested_at: u64,
+    pub status: RebalanceStatus,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::option_bytes"))]
+    pub btc_tx_hash: Option<BytesN<32>>,
+    pub settled_at: u64,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BucketAllocation {
+    pub bucket: WalletBucket,
+    pub balance_sats: u64,
+    pub actual_bps: u32,
+    pub target_bps: u32,
+    pub drifted: bool,
+}
+
+/// Reserve breakdown by custody wallet bucket
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustodyReport {
+    pub total_reserves: u64,
+    pub custodied_sats: u64,
+    pub pending_withdrawal_sats: u64,   // Float the hot bucket must cover
+    pub hot_shortfall_sats: u64,
+    pub queued_replenishment_sats: u64,
+    pub buckets: Vec<BucketAllocation>,
+    pub generated_at: u64,
+}
+
+/// Off-chain movement between wallet buckets reported by a custodian
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustodyMovement {
+    pub from_bucket: WalletBucket,
+    pub to_bucket: WalletBucket,
+    pub amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub btc_tx_hash: BytesN<32>,
+}
+
+/// Custodian attestation of a wallet bucket balance
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustodianAttestation {
+    pub attestation_id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::address"))]
+    pub custodian: Address,
+    pub bucket: WalletBucket,
+    pub balance_sats: u64,
+    pub recorded_sats: u64,             // Bucket balance on record once movements settled
+    pub movements: Vec<CustodyMovement>,
+    pub matched_requests: Vec<u64>,
+    pub unmatched_movements: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::bytes"))]
+    pub evidence_hash: BytesN<32>,
+    pub as_of: u64,
+    pub submitted_at: u64,
+}
+
+/// Reserves according to the latest custodian attestations
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttestedReserves {
+    pub hot_sats: u64,
+    pub warm_sats: u64,
+    pub cold_sats: u64,
+    pub total_sats: u64,
+    pub attested_buckets: u32,
+    pub oldest_as_of: u64,              // 0 when no bucket has been attested
+    pub max_age: u64,
+    pub fresh: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_verify_reserve_inclusion() {
+        let env = Env::default();
+        let client = ReserveManagerClient::new(env.clone(), Address::generate(&env));
+
+        let entries = [
+            (BytesN::from_array(&env, &[1u8; 32]), 0u32, 10_000_000u64),
+            (BytesN::from_array(&env, &[2u8; 32]), 0u32, 20_000_000u64),
+            (BytesN::from_array(&env, &[2u8; 32]), 1u32, 30_000_000u64),
+        ];
+        let root = client.compute_reserve_merkle_root(&entries);
+
+        // Third leaf is paired with itself, then with the hash of the first two
+        let first_pair = client.reserve_merkle_node(
+            &client.reserve_merkle_leaf(&entries[0].0, entries[0].1, entries[0].2),
+            &client.reserve_merkle_leaf(&entries[1].0, entries[1].1, entries[1].2),
+        );
+        let mut proof = ReserveMerkleProof {
+            utxo_id: entries[2].0.clone(),
+            vout: entries[2].1,
+            amount: entries[2].2,
+            leaf_index: 2,
+            siblings: alloc::vec![client.reserve_merkle_leaf(&entries[2].0, entries[2].1, entries[2].2), first_pair],
+            root: root.clone(),
+        };
+        assert!(client.verify_reserve_inclusion(&proof, &root));
+
+        proof.amount += 1;
+        assert!(!client.verify_reserve_inclusion(&proof, &root));
+
+        // Same txid at a different output index is a different leaf
+        proof.amount -= 1;
+        proof.vout = 0;
+        assert!(!client.verify_reserve_inclusion(&proof, &root));
+    }
+}
@@ -0,0 +1,275 @@
+//! Serde encodings for Soroban host types
+//!
+//! `Address` and `BytesN` are host handles and carry no serde support of
+//! their own. The `with` modules below give them stable string forms:
+//! addresses as strkeys (`G...` or `C...`) and fixed-size byte arrays as
+//! lowercase hex, accepting an optional `0x` prefix on input. Decoded values
+//! are bound to a fresh `Env`, the same way `ContractAddresses::from_config`
+//! parses configured addresses. Contract errors travel as their numeric codes.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use soroban_sdk::{Address, BytesN, Env};
+use crate::reporting::address_to_string;
+
+fn parse_address<E: serde::de::Error>(strkey: &str) -> Result<Address, E> {
+    // Reject malformed strkeys before the SDK traps on them
+    let valid = stellar_strkey::ed25519::PublicKey::from_string(strkey).is_ok()
+        || stellar_strkey::Contract::from_string(strkey).is_ok();
+    if !valid {
+        return Err(E::custom(alloc::format!("invalid address strkey: {}", strkey)));
+    }
+    Ok(Address::from_string(&soroban_sdk::String::from_str(&Env::default(), strkey)))
+}
+
+fn parse_hex<const N: usize, E: serde::de::Error>(value: &str) -> Result<[u8; N], E> {
+    let mut bytes = [0u8; N];
+    hex::decode_to_slice(value.trim_start_matches("0x"), &mut bytes)
+        .map_err(|_| E::custom(alloc::format!("expected {} hex-encoded bytes", N)))?;
+    Ok(bytes)
+}
+
+/// `Address` as a strkey
+pub mod address {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &Address, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&address_to_string(value))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Address, D::Error> {
+        parse_address(&String::deserialize(deserializer)?)
+    }
+}
+
+/// `Option<Address>` as a nullable strkey
+pub mod option_address {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &Option<Address>, serializer: S) -> Result<S::Ok, S::Error> {
+        value.as_ref().map(address_to_string).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Address>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|strkey| parse_address(&strkey))
+            .transpose()
+    }
+}
+
+/// `Vec<Address>` as a list of strkeys
+pub mod address_vec {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &[Address], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(value.iter().map(address_to_string))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Address>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|strkey| parse_address(strkey))
+            .collect()
+    }
+}
+
+/// `BytesN<N>` as hex
+pub mod bytes {
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(value: &BytesN<N>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(value.to_array()))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(deserializer: D) -> Result<BytesN<N>, D::Error> {
+        let bytes = parse_hex::<N, D::Error>(&String::deserialize(deserializer)?)?;
+        Ok(BytesN::from_array(&Env::default(), &bytes))
+    }
+}
+
+/// `Option<BytesN<N>>` as nullable hex
+pub mod option_bytes {
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(value: &Option<BytesN<N>>, serializer: S) -> Result<S::Ok, S::Error> {
+        value.as_ref().map(|bytes| hex::encode(bytes.to_array())).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(deserializer: D) -> Result<Option<BytesN<N>>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| parse_hex::<N, D::Error>(&value).map(|bytes| BytesN::from_array(&Env::default(), &bytes)))
+            .transpose()
+    }
+}
+
+/// `Vec<BytesN<N>>` as a list of hex strings
+pub mod bytes_vec {
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(value: &[BytesN<N>], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(value.iter().map(|bytes| hex::encode(bytes.to_array())))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(deserializer: D) -> Result<Vec<BytesN<N>>, D::Error> {
+        let env = Env::default();
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|value| parse_hex::<N, D::Error>(value).map(|bytes| BytesN::from_array(&env, &bytes)))
+            .collect()
+    }
+}
+
+/// `Option<[u8; N]>` as nullable hex (trace and span IDs)
+pub mod option_hex_array {
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(value: &Option<[u8; N]>, serializer: S) -> Result<S::Ok, S::Error> {
+        value.map(hex::encode).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(deserializer: D) -> Result<Option<[u8; N]>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| parse_hex::<N, D::Error>(&value))
+            .transpose()
+    }
+}
+
+/// Variable-length byte strings as hex
+pub mod hex_bytes {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(value))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        hex::decode(value.trim_start_matches("0x")).map_err(D::Error::custom)
+    }
+}
+
+/// `IntegrationError` as its numeric code
+pub mod integration_error {
+    use super::*;
+    use shared::IntegrationError;
+
+    pub fn serialize<S: Serializer>(value: &IntegrationError, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(*value as u32)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<IntegrationError, D::Error> {
+        let code = u32::deserialize(deserializer)?;
+        IntegrationError::try_from(soroban_sdk::Error::from_contract_error(code))
+            .map_err(|_| D::Error::custom(alloc::format!("unknown integration error code: {}", code)))
+    }
+}
+
+/// `ValidationError` as its numeric code
+pub mod validation_error {
+    use super::*;
+    use shared::ValidationError;
+
+    pub fn serialize<S: Serializer>(value: &ValidationError, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(*value as u32)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ValidationError, D::Error> {
+        let code = u32::deserialize(deserializer)?;
+        ValidationError::try_from(soroban_sdk::Error::from_contract_error(code))
+            .map_err(|_| D::Error::custom(alloc::format!("unknown validation error code: {}", code)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use crate::event_monitor::{ContractEvent, EventData};
+    use crate::integration_router_client::{AlertSeverity, SystemAlert};
+    use crate::{ContractError, OperationContext};
+
+    #[test]
+    fn test_events_round_trip_with_strkeys_and_hex_hashes() {
+        let env = Env::default();
+        let user = Address::generate(&env);
+        let event = ContractEvent {
+            contract_address: Address::generate(&env),
+            event_type: String::from("btc_dep"),
+            topics: alloc::vec![String::from("btc_dep")],
+            data: EventData::BitcoinDeposit {
+                user: user.clone(),
+                btc_amount: 100_000,
+                istsi_amount: 10_000_000,
+                btc_tx_hash: BytesN::from_array(&env, &[0xab; 32]),
+                confirmations: 6,
+            },
+            timestamp: 1_700_000_000,
+            block_number: 42,
+            transaction_hash: String::from("deadbeef"),
+        };
+
+        let json = serde_json::to_value(&event).unwrap();
+        let deposit = &json["data"]["BitcoinDeposit"];
+        assert_eq!(deposit["user"], address_to_string(&user));
+        assert_eq!(deposit["btc_tx_hash"], hex::encode([0xab; 32]));
+
+        let decoded: ContractEvent = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), json);
+        let EventData::BitcoinDeposit { btc_tx_hash, .. } = decoded.data else { panic!("unexpected event data") };
+        assert_eq!(btc_tx_hash.to_array(), [0xab; 32]);
+    }
+
+    #[test]
+    fn test_contexts_alerts_and_errors_round_trip() {
+        let env = Env::default();
+        let ctx = OperationContext {
+            caller: Address::generate(&env),
+            operation_id: String::from("op-1"),
+            timeout_seconds: 30,
+            retry_count: 3,
+            trace_id: None,
+            span_id: None,
+        }.with_trace([1u8; 16], [2u8; 8]);
+        let json = serde_json::to_value(&ctx).unwrap();
+        assert_eq!(json["trace_id"], hex::encode([1u8; 16]));
+        let decoded: OperationContext = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.trace_id, Some([1u8; 16]));
+        assert_eq!(decoded.span_id, Some([2u8; 8]));
+        assert_eq!(address_to_string(&decoded.caller), address_to_string(&ctx.caller));
+
+        let alert = SystemAlert {
+            alert_id: BytesN::from_array(&env, &[3u8; 32]),
+            alert_type: String::from("reserve_ratio"),
+            severity: AlertSeverity::Critical,
+            message: String::from("Reserve ratio below minimum"),
+            triggered_at: 10,
+            acknowledged: false,
+            acknowledged_by: None,
+            escalation_level: 0,
+            assigned_to: Some(Address::generate(&env)),
+            last_escalated_at: 0,
+            snoozed_until: 0,
+        };
+        let json = serde_json::to_value(&alert).unwrap();
+        assert!(json["acknowledged_by"].is_null());
+        let decoded: SystemAlert = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), json);
+
+        let error = ContractError::Integration(shared::IntegrationError::InsufficientReserves);
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(json, alloc::format!("{{\"Integration\":{}}}", shared::IntegrationError::InsufficientReserves as u32));
+        assert_eq!(serde_json::from_str::<ContractError>(&json).unwrap(), error);
+    }
+
+    #[test]
+    fn test_rejects_malformed_addresses_and_hashes() {
+        let json = r#"{"caller":"not-a-strkey","operation_id":"op","timeout_seconds":30,"retry_count":3,"trace_id":null,"span_id":null}"#;
+        assert!(serde_json::from_str::<OperationContext>(json).is_err());
+
+        assert!(serde_json::from_str::<ContractError>(r#"{"Integration":999999}"#).is_err());
+        let short: Result<Option<[u8; 16]>, _> = option_hex_array::deserialize(serde_json::json!("0x0102"));
+        assert!(short.is_err());
+    }
+}
@@ -0,0 +1,334 @@
+//! API facade over `ContractManager` workflows
+//!
+//! Non-Rust services reach the contracts through a gRPC or REST server that
+//! the host mounts (tonic, axum). This module is the transport-neutral part of
+//! that server: serde request/response DTOs mirroring `proto/istsi_service.proto`,
+//! a `ContractService` mapping each DTO onto the matching workflow, and a REST
+//! dispatcher that turns a method, path and JSON body into a status code and
+//! JSON response. Hosts only wire their router to `handle_rest`, or their
+//! generated tonic service to the typed methods.
+//!
+//! Addresses travel as Stellar strkeys and 32-byte IDs and hashes as hex.
+
+use alloc::string::{String, ToString};
+use serde::{Deserialize, Serialize};
+use soroban_sdk::{Address, BytesN};
+use crate::{ContractError, ContractManager, ContractResult, ErrorCategory, OperationContext};
+
+/// Protobuf definition of the service, for hosts generating gRPC stubs
+pub const PROTO_DEFINITION: &str = include_str!("../proto/istsi_service.proto");
+
+/// Default timeout for workflow calls made through the service
+const SERVICE_TIMEOUT_SECONDS: u64 = 30;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DepositRequest {
+    pub caller: String,
+    pub user: String,
+    pub btc_amount: u64,
+    pub btc_tx_hash: String,
+    pub confirmations: u32,
+    pub block_height: u64,
+    #[serde(default)]
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DepositResponse {
+    pub operation_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WithdrawalRequest {
+    pub caller: String,
+    pub user: String,
+    pub istsi_amount: u64,
+    pub btc_address: String,
+    #[serde(default)]
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WithdrawalResponse {
+    pub withdrawal_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeRequest {
+    pub caller: String,
+    pub user: String,
+    pub from_token: String,
+    pub to_token: String,
+    pub from_amount: u64,
+    #[serde(default)]
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeResponse {
+    pub operation_id: String,
+    pub to_amount: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperationStatusRequest {
+    pub operation_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperationStatusResponse {
+    pub operation_id: String,
+    pub status: String,
+}
+
+/// Error body shared by the gRPC and REST surfaces
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub code: u32,
+    pub category: String,
+    pub retryable: bool,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    /// HTTP status for the REST surface
+    pub fn http_status(&self) -> u16 {
+        match self.category.as_str() {
+            "auth" => 403,
+            "compliance" => 422,
+            "reserve" => 409,
+            "rate_limit" => 429,
+            "oracle" | "transient" => 503,
+            _ => 400,
+        }
+    }
+}
+
+impl From<ContractError> for ErrorResponse {
+    fn from(error: ContractError) -> Self {
+        let category = match error.category() {
+            ErrorCategory::Auth => "auth",
+            ErrorCategory::Compliance => "compliance",
+            ErrorCategory::Reserve => "reserve",
+            ErrorCategory::Oracle => "oracle",
+            ErrorCategory::RateLimit => "rate_limit",
+            ErrorCategory::Transient => "transient",
+            ErrorCategory::Permanent => "permanent",
+        };
+        ErrorResponse {
+            code: error.error_code(),
+            category: category.to_string(),
+            retryable: error.is_retryable(),
+            message: alloc::format!("{:?}", error),
+        }
+    }
+}
+
+/// Response produced by the REST dispatcher
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestResponse {
+    pub status: u16,
+    /// JSON body
+    pub body: String,
+}
+
+/// Service implementation over a `ContractManager`
+pub struct ContractService<'a> {
+    manager: &'a ContractManager,
+}
+
+impl<'a> ContractService<'a> {
+    pub fn new(manager: &'a ContractManager) -> Self {
+        Self { manager }
+    }
+
+    /// Execute a Bitcoin deposit
+    pub fn execute_deposit(&self, request: &DepositRequest) -> Result<DepositResponse, ErrorResponse> {
+        let ctx = self.context(&request.caller, &request.request_id)?;
+        let operation_id = self.manager.execute_bitcoin_deposit_workflow(
+            &ctx,
+            &self.parse_address(&request.user)?,
+            request.btc_amount,
+            &self.parse_hash(&request.btc_tx_hash)?,
+            request.confirmations,
+            request.block_height,
+        )?;
+        Ok(DepositResponse { operation_id: hex::encode(operation_id.to_array()) })
+    }
+
+    /// Execute a token withdrawal
+    pub fn execute_withdrawal(&self, request: &WithdrawalRequest) -> Result<WithdrawalResponse, ErrorResponse> {
+        let ctx = self.context(&request.caller, &request.request_id)?;
+        let withdrawal_id = self.manager.execute_token_withdrawal_workflow(
+            &ctx,
+            &self.parse_address(&request.user)?,
+            request.istsi_amount,
+            &request.btc_address,
+        )?;
+        Ok(WithdrawalResponse { withdrawal_id: hex::encode(withdrawal_id.to_array()) })
+    }
+
+    /// Execute a cross-token exchange
+    pub fn execute_exchange(&self, request: &ExchangeRequest) -> Result<ExchangeResponse, ErrorResponse> {
+        let ctx = self.context(&request.caller, &request.request_id)?;
+        let (operation_id, to_amount) = self.manager.execute_cross_token_exchange_workflow(
+            &ctx,
+            &self.parse_address(&request.user)?,
+            &self.parse_address(&request.from_token)?,
+            &self.parse_address(&request.to_token)?,
+            request.from_amount,
+        )?;
+        Ok(ExchangeResponse { operation_id: hex::encode(operation_id.to_array()), to_amount })
+    }
+
+    /// Get the status of a router operation
+    pub fn get_operation_status(&self, request: &OperationStatusRequest) -> Result<OperationStatusResponse, ErrorResponse> {
+        let operation_id = self.parse_hash(&request.operation_id)?;
+        let status = self.manager.integration_router().get_operation_status(&operation_id)?;
+        Ok(OperationStatusResponse { operation_id: request.operation_id.clone(), status })
+    }
+
+    /// Dispatch a REST request
+    ///
+    /// # Routes
+    /// * `POST /v1/deposits` - `DepositRequest`
+    /// * `POST /v1/withdrawals` - `WithdrawalRequest`
+    /// * `POST /v1/exchanges` - `ExchangeRequest`
+    /// * `GET /v1/operations/{operation_id}` - operation status
+    pub fn handle_rest(&self, method: &str, path: &str, body: &str) -> RestResponse {
+        let path = path.trim_end_matches('/');
+        match (method, path) {
+            ("POST", "/v1/deposits") => Self::respond(Self::parse_body(body).and_then(|request| self.execute_deposit(&request))),
+            ("POST", "/v1/withdrawals") => Self::respond(Self::parse_body(body).and_then(|request| self.execute_withdrawal(&request))),
+            ("POST", "/v1/exchanges") => Self::respond(Self::parse_body(body).and_then(|request| self.execute_exchange(&request))),
+            ("GET", _) if path.starts_with("/v1/operations/") => {
+                let operation_id = path.trim_start_matches("/v1/operations/").to_string();
+                Self::respond(self.get_operation_status(&OperationStatusRequest { operation_id }))
+            },
+            _ => RestResponse {
+                status: 404,
+                body: Self::to_json(&ErrorResponse {
+                    code: 404,
+                    category: "permanent".to_string(),
+                    retryable: false,
+                    message: alloc::format!("no route for {} {}", method, path),
+                }),
+            },
+        }
+    }
+
+    fn respond<T: Serialize>(result: Result<T, ErrorResponse>) -> RestResponse {
+        match result {
+            Ok(response) => RestResponse { status: 200, body: Self::to_json(&response) },
+            Err(error) => RestResponse { status: error.http_status(), body: Self::to_json(&error) },
+        }
+    }
+
+    fn to_json<T: Serialize>(value: &T) -> String {
+        serde_json::to_string(value).unwrap_or_default()
+    }
+
+    fn parse_body<T: for<'de> Deserialize<'de>>(body: &str) -> Result<T, ErrorResponse> {
+        serde_json::from_str(body)
+            .map_err(|err| ContractError::ParseError(alloc::format!("invalid request body: {}", err)).into())
+    }
+
+    fn context(&self, caller: &str, request_id: &str) -> ContractResult<OperationContext> {
+        Ok(OperationContext {
+            caller: self.parse_address(caller)?,
+            operation_id: request_id.to_string(),
+            timeout_seconds: SERVICE_TIMEOUT_SECONDS,
+            retry_count: 0,
+            trace_id: None,
+            span_id: None,
+        })
+    }
+
+    fn parse_address(&self, strkey: &str) -> ContractResult<Address> {
+        // Reject malformed strkeys before the SDK traps on them
+        let valid = stellar_strkey::ed25519::PublicKey::from_string(strkey).is_ok()
+            || stellar_strkey::Contract::from_string(strkey).is_ok();
+        if !valid {
+            return Err(ContractError::Validation(shared::ValidationError::InvalidAddress));
+        }
+        Ok(Address::from_string(&soroban_sdk::String::from_str(self.manager.env(), strkey)))
+    }
+
+    fn parse_hash(&self, value: &str) -> ContractResult<BytesN<32>> {
+        let mut bytes = [0u8; 32];
+        hex::decode_to_slice(value.trim_start_matches("0x"), &mut bytes)
+            .map_err(|_| ContractError::Validation(shared::ValidationError::InvalidParameters))?;
+        Ok(BytesN::from_array(self.manager.env(), &bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::Env;
+    use crate::{ContractAddresses, NetworkConfig};
+    use crate::reporting::address_to_string;
+
+    fn manager(env: &Env) -> ContractManager {
+        let mut addresses = ContractAddresses::new();
+        addresses.integration_router = Some(Address::generate(env));
+        addresses.kyc_registry = Some(Address::generate(env));
+        addresses.istsi_token = Some(Address::generate(env));
+        addresses.reserve_manager = Some(Address::generate(env));
+        ContractManager::new(env.clone(), addresses, NetworkConfig::testnet()).unwrap()
+    }
+
+    #[test]
+    fn test_rest_routes_map_onto_workflows() {
+        let env = Env::default();
+        let manager = manager(&env);
+        let service = ContractService::new(&manager);
+        let caller = address_to_string(&Address::generate(&env));
+        let user = address_to_string(&Address::generate(&env));
+
+        let body = serde_json::json!({
+            "caller": caller,
+            "user": user,
+            "btc_amount": 0,
+            "btc_tx_hash": hex::encode([7u8; 32]),
+            "confirmations": 6,
+            "block_height": 800_000,
+        });
+        let response = service.handle_rest("POST", "/v1/deposits", &body.to_string());
+        assert_eq!(response.status, 400);
+        let error: ErrorResponse = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(error.code, 101);
+        assert!(!error.retryable);
+
+        let status = service.handle_rest("GET", &alloc::format!("/v1/operations/{}", hex::encode([1u8; 32])), "");
+        assert_eq!(status.status, 200);
+        let status: OperationStatusResponse = serde_json::from_str(&status.body).unwrap();
+        assert_eq!(status.status, "completed");
+
+        assert_eq!(service.handle_rest("DELETE", "/v1/deposits", "").status, 404);
+        assert!(PROTO_DEFINITION.contains("rpc ExecuteDeposit(DepositRequest)"));
+    }
+
+    #[test]
+    fn test_malformed_requests_are_rejected_before_contract_calls() {
+        let env = Env::default();
+        let manager = manager(&env);
+        let service = ContractService::new(&manager);
+
+        assert_eq!(service.handle_rest("POST", "/v1/exchanges", "{").status, 400);
+
+        let request = WithdrawalRequest {
+            caller: "not-an-address".to_string(),
+            user: address_to_string(&Address::generate(&env)),
+            istsi_amount: 1,
+            btc_address: "bc1qservice".to_string(),
+            request_id: String::new(),
+        };
+        let error = service.execute_withdrawal(&request).unwrap_err();
+        assert_eq!(error.code, shared::ValidationError::InvalidAddress as u32);
+
+        let status = service.get_operation_status(&OperationStatusRequest { operation_id: "zz".to_string() });
+        assert_eq!(status.unwrap_err().http_status(), 400);
+    }
+}
@@ -0,0 +1,305 @@
+//! Transaction signing backends
+//!
+//! Keys that sign router transactions usually live outside the backend
+//! process, in an HSM, a cloud KMS or a hardware wallet. `Signer` is the
+//! seam for those backends: it exposes the ed25519 public key and signs the
+//! 32-byte transaction hash produced by `PreparedTransaction::hash`. Remote
+//! key services implement `AsyncSigner` instead; every `Signer` is also an
+//! `AsyncSigner`, so hosts can drive both through the async path. The client
+//! bundles only `InMemorySigner`, meant for tests and local networks.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::future::Future;
+use core::pin::Pin;
+use ed25519_dalek::{Signer as _, SigningKey};
+use soroban_sdk::xdr::{
+    DecoratedSignature, Limits, MuxedAccount, Signature, SignatureHint, TransactionEnvelope, Uint256, WriteXdr,
+};
+use soroban_sdk::Env;
+use crate::{ContractError, ContractResult, PreparedTransaction};
+
+/// Synchronous signing backend
+pub trait Signer {
+    /// Ed25519 public key of the signing account
+    fn public_key(&self) -> ContractResult<[u8; 32]>;
+
+    /// Sign a 32-byte transaction hash
+    fn sign_payload(&self, payload: &[u8; 32]) -> ContractResult<[u8; 64]>;
+}
+
+/// Boxed future returned by asynchronous signers
+pub type SignerFuture<'a, T> = Pin<Box<dyn Future<Output = ContractResult<T>> + 'a>>;
+
+/// Signing backend reached over the network (AWS KMS, HashiCorp Vault, Ledger bridges)
+pub trait AsyncSigner {
+    /// Ed25519 public key of the signing account
+    fn public_key_async(&self) -> SignerFuture<'_, [u8; 32]>;
+
+    /// Sign a 32-byte transaction hash
+    fn sign_payload_async<'a>(&'a self, payload: &'a [u8; 32]) -> SignerFuture<'a, [u8; 64]>;
+}
+
+impl<S: Signer + ?Sized> AsyncSigner for S {
+    fn public_key_async(&self) -> SignerFuture<'_, [u8; 32]> {
+        Box::pin(core::future::ready(self.public_key()))
+    }
+
+    fn sign_payload_async<'a>(&'a self, payload: &'a [u8; 32]) -> SignerFuture<'a, [u8; 64]> {
+        Box::pin(core::future::ready(self.sign_payload(payload)))
+    }
+}
+
+/// Signer holding an ed25519 secret key in memory (tests and local networks only)
+pub struct InMemorySigner {
+    key: SigningKey,
+}
+
+impl InMemorySigner {
+    /// Create a signer from a raw 32-byte secret seed
+    pub fn from_seed(seed: &[u8; 32]) -> Self {
+        Self { key: SigningKey::from_bytes(seed) }
+    }
+
+    /// Create a signer from a secret strkey (`S...`)
+    pub fn from_secret(secret: &str) -> ContractResult<Self> {
+        let seed = stellar_strkey::ed25519::PrivateKey::from_string(secret)
+            .map_err(|_| ContractError::Validation(shared::ValidationError::InvalidParameters))?;
+        Ok(Self::from_seed(&seed.0))
+    }
+
+    /// Account strkey (`G...`) of the signer
+    pub fn account_id(&self) -> String {
+        stellar_strkey::ed25519::PublicKey(self.key.verifying_key().to_bytes()).to_string()
+    }
+}
+
+impl Signer for InMemorySigner {
+    fn public_key(&self) -> ContractResult<[u8; 32]> {
+        Ok(self.key.verifying_key().to_bytes())
+    }
+
+    fn sign_payload(&self, payload: &[u8; 32]) -> ContractResult<[u8; 64]> {
+        Ok(self.key.sign(payload).to_bytes())
+    }
+}
+
+/// Envelope carrying the source account's signature
+#[derive(Debug, Clone)]
+pub struct SignedTransaction {
+    pub envelope: TransactionEnvelope,
+    /// Transaction hash that was signed
+    pub hash: [u8; 32],
+}
+
+impl SignedTransaction {
+    /// Base64 XDR for `sendTransaction`
+    pub fn to_xdr_base64(&self) -> ContractResult<String> {
+        self.envelope.to_xdr_base64(Limits::none())
+            .map_err(|err| ContractError::ParseError(alloc::format!("failed to encode envelope: {:?}", err)))
+    }
+}
+
+/// Sign a prepared transaction with a synchronous signer
+///
+/// # Returns
+/// * `Ok(signed)` - Envelope with the signature attached
+/// * `Err(ContractError)` - The signer failed or holds a key other than the source account's
+pub fn sign_transaction(env: &Env, prepared: &PreparedTransaction, signer: &dyn Signer) -> ContractResult<SignedTransaction> {
+    let public_key = signer.public_key()?;
+    require_source_account(prepared, &public_key)?;
+
+    let hash = prepared.hash(env)?;
+    let signature = signer.sign_payload(&hash)?;
+    attach_signature(prepared, hash, &public_key, &signature)
+}
+
+/// Sign a prepared transaction with an asynchronous signer
+pub async fn sign_transaction_async(
+    env: &Env,
+    prepared: &PreparedTransaction,
+    signer: &dyn AsyncSigner,
+) -> ContractResult<SignedTransaction> {
+    let public_key = signer.public_key_async().await?;
+    require_source_account(prepared, &public_key)?;
+
+    let hash = prepared.hash(env)?;
+    let signature = signer.sign_payload_async(&hash).await?;
+    attach_signature(prepared, hash, &public_key, &signature)
+}
+
+fn require_source_account(prepared: &PreparedTransaction, public_key: &[u8; 32]) -> ContractResult<()> {
+    match &prepared.envelope {
+        TransactionEnvelope::Tx(envelope) if envelope.tx.source_account == MuxedAccount::Ed25519(Uint256(*public_key)) => Ok(()),
+        _ => Err(ContractError::Validation(shared::ValidationError::InvalidSignature)),
+    }
+}
+
+fn attach_signature(
+    prepared: &PreparedTransaction,
+    hash: [u8; 32],
+    public_key: &[u8; 32],
+    signature: &[u8; 64],
+) -> ContractResult<SignedTransaction> {
+    let mut envelope = prepared.envelope.clone();
+    let TransactionEnvelope::Tx(inner) = &mut envelope else {
+        return Err(ContractError::ParseError(String::from("unsupported envelope type")));
+    };
+
+    // The hint is the last four bytes of the signing key
+    let decorated = DecoratedSignature {
+        hint: SignatureHint([public_key[28], public_key[29], public_key[30], public_key[31]]),
+        signature: Signature(signature.try_into().map_err(|_| ContractError::Validation(shared::ValidationError::InvalidSignature))?),
+    };
+    let mut signatures = inner.signatures.to_vec();
+    signatures.push(decorated);
+    inner.signatures = signatures.try_into()
+        .map_err(|_| ContractError::Validation(shared::ValidationError::InvalidSignature))?;
+
+    Ok(SignedTransaction { envelope, hash })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use ed25519_dalek::{Signature as Ed25519Signature, VerifyingKey};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::Address;
+    use soroban_sdk::xdr::{ExtensionPoint, LedgerFootprint, SorobanResources, SorobanTransactionData};
+    use crate::{
+        ContractAddresses, ContractManager, ContractInvocation, FeeBufferConfig, NetworkConfig, SimulationResponse,
+        SubmissionResponse, SubmissionStatus, TransactionBuilder, TransactionSimulator, TransactionSubmitter,
+    };
+    use core::cell::RefCell;
+
+    struct StaticSimulator;
+
+    impl TransactionSimulator for StaticSimulator {
+        fn simulate_transaction(&self, _envelope: &TransactionEnvelope) -> ContractResult<SimulationResponse> {
+            Ok(SimulationResponse {
+                min_resource_fee: 1_000,
+                transaction_data: Some(SorobanTransactionData {
+                    ext: ExtensionPoint::V0,
+                    resources: SorobanResources {
+                        footprint: LedgerFootprint { read_only: Default::default(), read_write: Default::default() },
+                        instructions: 1_000_000,
+                        read_bytes: 0,
+                        write_bytes: 0,
+                    },
+                    resource_fee: 1_000,
+                }),
+                ..Default::default()
+            })
+        }
+    }
+
+    fn prepare(env: &Env, account_id: &str) -> PreparedTransaction {
+        let invocation = ContractInvocation::new(&Address::generate(env), "get_reserve_ratio");
+        TransactionBuilder::new(&NetworkConfig::testnet(), account_id, 7).unwrap()
+            .build(&invocation, &StaticSimulator)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_signs_prepared_transactions_for_the_source_account() {
+        let env = Env::default();
+        let signer = InMemorySigner::from_seed(&[7u8; 32]);
+        let prepared = prepare(&env, &signer.account_id());
+
+        let signed = sign_transaction(&env, &prepared, &signer).unwrap();
+        assert_eq!(signed.hash, prepared.hash(&env).unwrap());
+        assert!(signed.to_xdr_base64().is_ok());
+
+        let TransactionEnvelope::Tx(envelope) = &signed.envelope else { panic!("unexpected envelope") };
+        let signatures: Vec<_> = envelope.signatures.to_vec();
+        assert_eq!(signatures.len(), 1);
+
+        let public_key = signer.public_key().unwrap();
+        assert_eq!(signatures[0].hint.0, public_key[28..32]);
+        let signature = Ed25519Signature::from_slice(signatures[0].signature.as_slice()).unwrap();
+        assert!(VerifyingKey::from_bytes(&public_key).unwrap().verify_strict(&signed.hash, &signature).is_ok());
+
+        // Every synchronous signer can be driven through the async path
+        let signed_async = tokio_test::block_on(sign_transaction_async(&env, &prepared, &signer)).unwrap();
+        assert_eq!(signed_async.envelope, signed.envelope);
+    }
+
+    #[test]
+    fn test_rejects_signers_for_other_accounts() {
+        let env = Env::default();
+        let signer = InMemorySigner::from_seed(&[7u8; 32]);
+        let other = InMemorySigner::from_seed(&[8u8; 32]);
+        let prepared = prepare(&env, &signer.account_id());
+
+        assert_eq!(
+            sign_transaction(&env, &prepared, &other).unwrap_err(),
+            ContractError::Validation(shared::ValidationError::InvalidSignature)
+        );
+        assert!(InMemorySigner::from_secret("not-a-secret").is_err());
+    }
+
+    /// Records submitted envelopes and answers with a fixed status
+    struct RecordingSubmitter {
+        status: SubmissionStatus,
+        submitted: RefCell<Vec<SignedTransaction>>,
+    }
+
+    impl TransactionSubmitter for RecordingSubmitter {
+        fn send_transaction(&self, signed: &SignedTransaction) -> ContractResult<SubmissionResponse> {
+            self.submitted.borrow_mut().push(signed.clone());
+            Ok(SubmissionResponse { hash: signed.hash, status: self.status.clone(), latest_ledger: 100 })
+        }
+    }
+
+    fn manager(env: &Env) -> ContractManager {
+        let mut addresses = ContractAddresses::new();
+        addresses.integration_router = Some(Address::generate(env));
+        addresses.kyc_registry = Some(Address::generate(env));
+        addresses.istsi_token = Some(Address::generate(env));
+        addresses.reserve_manager = Some(Address::generate(env));
+        ContractManager::new(env.clone(), addresses, NetworkConfig::testnet()).unwrap()
+    }
+
+    #[test]
+    fn test_manager_submits_with_the_configured_signer() {
+        let env = Env::default();
+        let invocation = ContractInvocation::new(&Address::generate(&env), "get_reserve_ratio");
+        let submitter = RecordingSubmitter { status: SubmissionStatus::Pending, submitted: RefCell::new(Vec::new()) };
+
+        // Nothing is submitted without a signer
+        assert_eq!(
+            manager(&env).submit_transaction(7, &invocation, FeeBufferConfig::default(), &StaticSimulator, &submitter).unwrap_err(),
+            ContractError::Validation(shared::ValidationError::InvalidSignature)
+        );
+        assert!(submitter.submitted.borrow().is_empty());
+
+        let signer = InMemorySigner::from_seed(&[7u8; 32]);
+        let public_key = signer.public_key().unwrap();
+        let manager = manager(&env).with_signer(Box::new(signer));
+        let response = manager.submit_transaction(7, &invocation, FeeBufferConfig::default(), &StaticSimulator, &submitter).unwrap();
+
+        let submitted = submitter.submitted.borrow()[0].clone();
+        assert_eq!(response.hash, submitted.hash);
+        let TransactionEnvelope::Tx(envelope) = &submitted.envelope else { panic!("unexpected envelope") };
+        assert_eq!(envelope.tx.source_account, MuxedAccount::Ed25519(Uint256(public_key)));
+        assert_eq!(envelope.signatures.len(), 1);
+
+        // Remote signers go through the same path
+        let remote = InMemorySigner::from_seed(&[9u8; 32]);
+        let response = tokio_test::block_on(
+            manager.submit_transaction_async(8, &invocation, FeeBufferConfig::default(), &StaticSimulator, &submitter, &remote)
+        ).unwrap();
+        assert_eq!(response.hash, submitter.submitted.borrow()[1].hash);
+
+        // Rejections surface as errors
+        let rejecting = RecordingSubmitter {
+            status: SubmissionStatus::Error(String::from("HostError: Error(Contract, #31)")),
+            submitted: RefCell::new(Vec::new()),
+        };
+        assert_eq!(
+            manager.submit_transaction(7, &invocation, FeeBufferConfig::default(), &StaticSimulator, &rejecting).unwrap_err(),
+            ContractError::Integration(shared::IntegrationError::ReserveRatioTooLow)
+        );
+    }
+}
@@ -0,0 +1,455 @@
+//! Transaction assembly for contract invocations
+//!
+//! `TransactionBuilder` turns a contract call into a transaction envelope the
+//! host can sign and submit. The call is first run through Soroban RPC
+//! `simulateTransaction`, which predicts its result and returns the ledger
+//! footprint, authorization entries and minimum resource fee. The builder
+//! attaches those to the transaction, pads the instruction budget and the
+//! resource fee by configurable buffers, and returns a `PreparedTransaction`.
+//! A simulation that predicts failure is surfaced as a typed `ContractError`
+//! instead of an envelope that would fail on chain. Like the event monitor,
+//! the builder owns no network stack: simulation goes through a
+//! `TransactionSimulator`, signing through a `Signer` and submission
+//! through a `TransactionSubmitter`.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use soroban_sdk::xdr::{
+    Hash, HostFunction, InvokeContractArgs, InvokeHostFunctionOp, Limits, Memo, MuxedAccount, Operation,
+    OperationBody, Preconditions, ScAddress, ScSymbol, ScVal, SequenceNumber, SorobanAuthorizationEntry,
+    SorobanTransactionData, TimeBounds, TimePoint, Transaction, TransactionEnvelope, TransactionExt,
+    TransactionSignaturePayload, TransactionSignaturePayloadTaggedTransaction, TransactionV1Envelope, Uint256,
+    WriteXdr,
+};
+use soroban_sdk::{Address, Bytes, Env};
+use crate::{ContractError, ContractResult, NetworkConfig, SignedTransaction};
+
+/// Contract call wrapped by a transaction
+#[derive(Debug, Clone)]
+pub struct ContractInvocation {
+    pub contract: Address,
+    pub function: String,
+    pub args: Vec<ScVal>,
+}
+
+impl ContractInvocation {
+    /// Call `function` on `contract` with no arguments
+    pub fn new(contract: &Address, function: &str) -> Self {
+        Self {
+            contract: contract.clone(),
+            function: function.to_string(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Append an argument
+    pub fn arg(mut self, value: ScVal) -> Self {
+        self.args.push(value);
+        self
+    }
+}
+
+/// Result of Soroban RPC `simulateTransaction`
+#[derive(Debug, Clone, Default)]
+pub struct SimulationResponse {
+    /// Minimum resource fee in stroops
+    pub min_resource_fee: i64,
+    /// Footprint and resource limits to attach to the transaction
+    pub transaction_data: Option<SorobanTransactionData>,
+    /// Authorization entries the invocation requires
+    pub auth: Vec<SorobanAuthorizationEntry>,
+    /// Predicted return value
+    pub result: Option<ScVal>,
+    /// Host error when the simulated invocation failed
+    pub error: Option<String>,
+    /// Archived ledger entries must be restored before the call can succeed
+    pub restore_required: bool,
+    pub latest_ledger: u32,
+}
+
+/// Simulation backend (Soroban RPC or a local host)
+pub trait TransactionSimulator {
+    /// Simulate an unsigned transaction envelope
+    fn simulate_transaction(&self, envelope: &TransactionEnvelope) -> ContractResult<SimulationResponse>;
+}
+
+/// Status of a transaction handed to Soroban RPC `sendTransaction`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionStatus {
+    /// Accepted into the queue; poll `getTransaction` for the outcome
+    Pending,
+    /// Already submitted
+    Duplicate,
+    /// Rejected for now, e.g. the queue is full; resubmit later
+    TryAgainLater,
+    /// Rejected; the error carries the decoded result
+    Error(String),
+}
+
+/// Result of Soroban RPC `sendTransaction`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmissionResponse {
+    /// Hash of the submitted transaction
+    pub hash: [u8; 32],
+    pub status: SubmissionStatus,
+    pub latest_ledger: u32,
+}
+
+/// Submission backend (Soroban RPC or a local host)
+pub trait TransactionSubmitter {
+    /// Submit a signed transaction envelope
+    fn send_transaction(&self, signed: &SignedTransaction) -> ContractResult<SubmissionResponse>;
+}
+
+/// Submit a signed transaction and check that the network accepted it
+///
+/// # Returns
+/// * `Ok(response)` - The transaction is pending or was already submitted
+/// * `Err(ContractError)` - The network rejected it or reported another hash
+pub fn submit_transaction(signed: &SignedTransaction, submitter: &dyn TransactionSubmitter) -> ContractResult<SubmissionResponse> {
+    let response = submitter.send_transaction(signed)?;
+    if response.hash != signed.hash {
+        return Err(ContractError::ParseError("sendTransaction reported another transaction hash".to_string()));
+    }
+
+    match &response.status {
+        SubmissionStatus::Pending | SubmissionStatus::Duplicate => Ok(response),
+        SubmissionStatus::TryAgainLater => Err(ContractError::NetworkError("sendTransaction asked to try again later".to_string())),
+        SubmissionStatus::Error(error) => Err(simulation_error(error)),
+    }
+}
+
+/// Safety margins applied on top of simulated resources
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeBufferConfig {
+    /// Inclusion fee per operation in stroops
+    pub base_fee: u32,
+    /// Extra resource fee in basis points of the simulated minimum
+    pub resource_fee_buffer_bps: u32,
+    /// Extra CPU instructions in basis points of the simulated usage
+    pub instruction_buffer_bps: u32,
+    /// Upper bound on the total fee in stroops
+    pub max_fee: Option<u32>,
+}
+
+impl Default for FeeBufferConfig {
+    fn default() -> Self {
+        Self {
+            base_fee: 100,
+            resource_fee_buffer_bps: 1500,
+            instruction_buffer_bps: 1000,
+            max_fee: None,
+        }
+    }
+}
+
+/// Transaction ready for the host's signer
+#[derive(Debug, Clone)]
+pub struct PreparedTransaction {
+    /// Unsigned envelope including footprint, auth entries and fees
+    pub envelope: TransactionEnvelope,
+    /// Total fee in stroops (inclusion plus buffered resource fee)
+    pub fee: u32,
+    /// Buffered resource fee in stroops
+    pub resource_fee: i64,
+    /// Return value predicted by simulation
+    pub simulated_result: Option<ScVal>,
+    /// Ledger the simulation ran against
+    pub latest_ledger: u32,
+    network_passphrase: String,
+}
+
+impl PreparedTransaction {
+    /// Base64 XDR of the unsigned envelope
+    pub fn to_xdr_base64(&self) -> ContractResult<String> {
+        self.envelope.to_xdr_base64(Limits::none())
+            .map_err(|err| ContractError::ParseError(format!("failed to encode envelope: {:?}", err)))
+    }
+
+    /// Hash the signer must sign (network ID and transaction)
+    pub fn hash(&self, env: &Env) -> ContractResult<[u8; 32]> {
+        let TransactionEnvelope::Tx(envelope) = &self.envelope else {
+            return Err(ContractError::ParseError("unsupported envelope type".to_string()));
+        };
+
+        let payload = TransactionSignaturePayload {
+            network_id: Hash(sha256(env, self.network_passphrase.as_bytes())),
+            tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(envelope.tx.clone()),
+        };
+        let bytes = payload.to_xdr(Limits::none())
+            .map_err(|err| ContractError::ParseError(format!("failed to encode payload: {:?}", err)))?;
+        Ok(sha256(env, &bytes))
+    }
+}
+
+/// Assembles simulated, fee-buffered transactions for contract invocations
+pub struct TransactionBuilder {
+    network_passphrase: String,
+    source_account: [u8; 32],
+    sequence: i64,
+    fees: FeeBufferConfig,
+    valid_until: Option<u64>,
+}
+
+impl TransactionBuilder {
+    /// Create a builder for a source account
+    ///
+    /// # Arguments
+    /// * `network` - Network whose passphrase the transaction is bound to
+    /// * `source_account` - Strkey (`G...`) of the account paying fees
+    /// * `sequence` - Sequence number for the transaction (current account sequence + 1)
+    pub fn new(network: &NetworkConfig, source_account: &str, sequence: i64) -> ContractResult<Self> {
+        let source = stellar_strkey::ed25519::PublicKey::from_string(source_account)
+            .map_err(|_| ContractError::Validation(shared::ValidationError::InvalidAddress))?;
+
+        Ok(Self {
+            network_passphrase: network.network_passphrase.clone(),
+            source_account: source.0,
+            sequence,
+            fees: FeeBufferConfig::default(),
+            valid_until: None,
+        })
+    }
+
+    /// Override the fee buffers
+    pub fn with_fee_buffers(mut self, fees: FeeBufferConfig) -> Self {
+        self.fees = fees;
+        self
+    }
+
+    /// Reject the transaction if it is not included by `valid_until` (unix seconds)
+    pub fn with_valid_until(mut self, valid_until: u64) -> Self {
+        self.valid_until = Some(valid_until);
+        self
+    }
+
+    /// Simulate an invocation and assemble the transaction
+    ///
+    /// # Arguments
+    /// * `invocation` - Contract call to wrap
+    /// * `simulator` - Simulation backend
+    ///
+    /// # Returns
+    /// * `Ok(prepared)` - Envelope ready for signing
+    /// * `Err(ContractError)` - The call is predicted to fail or exceeds the fee limit
+    pub fn build(
+        &self,
+        invocation: &ContractInvocation,
+        simulator: &dyn TransactionSimulator,
+    ) -> ContractResult<PreparedTransaction> {
+        let operation = Self::invoke_operation(invocation, Vec::new())?;
+        let draft = self.envelope(self.fees.base_fee, operation, TransactionExt::V0)?;
+        let simulation = simulator.simulate_transaction(&draft)?;
+
+        if let Some(error) = &simulation.error {
+            return Err(simulation_error(error));
+        }
+        if simulation.restore_required {
+            return Err(ContractError::SimulationFailed(
+                "archived ledger entries must be restored first".to_string()
+            ));
+        }
+
+        let mut transaction_data = simulation.transaction_data.clone().ok_or_else(|| {
+            ContractError::SimulationFailed("simulation returned no transaction data".to_string())
+        })?;
+
+        let resources = &mut transaction_data.resources;
+        resources.instructions = apply_buffer(resources.instructions as u64, self.fees.instruction_buffer_bps)
+            .min(u32::MAX as u64) as u32;
+        let resource_fee = apply_buffer(simulation.min_resource_fee.max(0) as u64, self.fees.resource_fee_buffer_bps);
+        transaction_data.resource_fee = resource_fee as i64;
+
+        let fee = self.fees.base_fee as u64 + resource_fee;
+        let max_fee = self.fees.max_fee.unwrap_or(u32::MAX);
+        if fee > max_fee as u64 {
+            return Err(ContractError::SimulationFailed(format!(
+                "fee of {} stroops exceeds the {} stroop limit", fee, max_fee
+            )));
+        }
+
+        let operation = Self::invoke_operation(invocation, simulation.auth.clone())?;
+        let envelope = self.envelope(fee as u32, operation, TransactionExt::V1(transaction_data))?;
+
+        Ok(PreparedTransaction {
+            envelope,
+            fee: fee as u32,
+            resource_fee: resource_fee as i64,
+            simulated_result: simulation.result,
+            latest_ledger: simulation.latest_ledger,
+            network_passphrase: self.network_passphrase.clone(),
+        })
+    }
+
+    fn invoke_operation(invocation: &ContractInvocation, auth: Vec<SorobanAuthorizationEntry>) -> ContractResult<Operation> {
+        let invalid = || ContractError::Validation(shared::ValidationError::InvalidParameters);
+
+        let host_function = HostFunction::InvokeContract(InvokeContractArgs {
+            contract_address: ScAddress::from(&invocation.contract),
+            function_name: ScSymbol(invocation.function.as_str().try_into().map_err(|_| invalid())?),
+            args: invocation.args.clone().try_into().map_err(|_| invalid())?,
+        });
+
+        Ok(Operation {
+            source_account: None,
+            body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+                host_function,
+                auth: auth.try_into().map_err(|_| invalid())?,
+            }),
+        })
+    }
+
+    fn envelope(&self, fee: u32, operation: Operation, ext: TransactionExt) -> ContractResult<TransactionEnvelope> {
+        let cond = match self.valid_until {
+            Some(max_time) => Preconditions::Time(TimeBounds { min_time: TimePoint(0), max_time: TimePoint(max_time) }),
+            None => Preconditions::None,
+        };
+
+        let tx = Transaction {
+            source_account: MuxedAccount::Ed25519(Uint256(self.source_account)),
+            fee,
+            seq_num: SequenceNumber(self.sequence),
+            cond,
+            memo: Memo::None,
+            operations: alloc::vec![operation].try_into()
+                .map_err(|_| ContractError::Validation(shared::ValidationError::InvalidParameters))?,
+            ext,
+        };
+
+        Ok(TransactionEnvelope::Tx(TransactionV1Envelope { tx, signatures: Default::default() }))
+    }
+}
+
+/// Map a simulation host error onto a typed error
+///
+/// Contract errors (`Error(Contract, #N)`) keep their on-chain meaning when
+/// the code is known; anything else is reported as a simulation failure.
+pub fn simulation_error(error: &str) -> ContractError {
+    const MARKER: &str = "Error(Contract, #";
+
+    error.find(MARKER)
+        .and_then(|start| {
+            let code = &error[start + MARKER.len()..];
+            let end = code.find(')')?;
+            code[..end].parse::<u32>().ok()
+        })
+        .and_then(ContractError::from_code)
+        .unwrap_or_else(|| ContractError::SimulationFailed(error.to_string()))
+}
+
+fn apply_buffer(value: u64, buffer_bps: u32) -> u64 {
+    value + (value * buffer_bps as u64).div_ceil(10000)
+}
+
+fn sha256(env: &Env, data: &[u8]) -> [u8; 32] {
+    env.crypto().sha256(&Bytes::from_slice(env, data)).to_array()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::RefCell;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::xdr::{ExtensionPoint, LedgerFootprint, SorobanResources};
+
+    const SOURCE: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+
+    struct MockSimulator {
+        response: SimulationResponse,
+        seen: RefCell<Vec<TransactionEnvelope>>,
+    }
+
+    impl TransactionSimulator for MockSimulator {
+        fn simulate_transaction(&self, envelope: &TransactionEnvelope) -> ContractResult<SimulationResponse> {
+            self.seen.borrow_mut().push(envelope.clone());
+            Ok(self.response.clone())
+        }
+    }
+
+    fn simulator(response: SimulationResponse) -> MockSimulator {
+        MockSimulator { response, seen: RefCell::new(Vec::new()) }
+    }
+
+    fn successful_simulation() -> SimulationResponse {
+        SimulationResponse {
+            min_resource_fee: 10_000,
+            transaction_data: Some(SorobanTransactionData {
+                ext: ExtensionPoint::V0,
+                resources: SorobanResources {
+                    footprint: LedgerFootprint { read_only: Default::default(), read_write: Default::default() },
+                    instructions: 2_000_000,
+                    read_bytes: 1_000,
+                    write_bytes: 200,
+                },
+                resource_fee: 10_000,
+            }),
+            result: Some(ScVal::U64(42)),
+            latest_ledger: 1_234,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_applies_simulated_resources_and_buffers() {
+        let env = Env::default();
+        let invocation = ContractInvocation::new(&Address::generate(&env), "get_reserve_ratio").arg(ScVal::U32(7));
+        let mock = simulator(successful_simulation());
+
+        let builder = TransactionBuilder::new(&NetworkConfig::testnet(), SOURCE, 101).unwrap()
+            .with_valid_until(1_700_000_000);
+        let prepared = builder.build(&invocation, &mock).unwrap();
+
+        // The draft sent for simulation carries no resources yet
+        let TransactionEnvelope::Tx(draft) = &mock.seen.borrow()[0] else { panic!("unexpected envelope") };
+        assert_eq!(draft.tx.ext, TransactionExt::V0);
+
+        assert_eq!(prepared.resource_fee, 11_500);
+        assert_eq!(prepared.fee, 11_600);
+        assert_eq!(prepared.simulated_result, Some(ScVal::U64(42)));
+
+        let TransactionEnvelope::Tx(envelope) = &prepared.envelope else { panic!("unexpected envelope") };
+        assert_eq!(envelope.tx.seq_num, SequenceNumber(101));
+        assert_eq!(envelope.tx.fee, 11_600);
+        let TransactionExt::V1(data) = &envelope.tx.ext else { panic!("missing soroban data") };
+        assert_eq!(data.resources.instructions, 2_200_000);
+        assert_eq!(data.resource_fee, 11_500);
+
+        // The signing hash binds the transaction to its network
+        assert!(prepared.to_xdr_base64().is_ok());
+        let mainnet = TransactionBuilder::new(&NetworkConfig::mainnet(), SOURCE, 101).unwrap()
+            .with_valid_until(1_700_000_000)
+            .build(&invocation, &simulator(successful_simulation()))
+            .unwrap();
+        assert_eq!(mainnet.envelope, prepared.envelope);
+        assert_ne!(mainnet.hash(&env).unwrap(), prepared.hash(&env).unwrap());
+    }
+
+    #[test]
+    fn test_build_reports_predicted_failures() {
+        let env = Env::default();
+        let invocation = ContractInvocation::new(&Address::generate(&env), "execute_bitcoin_deposit");
+        let builder = TransactionBuilder::new(&NetworkConfig::testnet(), SOURCE, 1).unwrap();
+
+        let failed = SimulationResponse {
+            error: Some("HostError: Error(Contract, #43)".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            builder.build(&invocation, &simulator(failed)).unwrap_err(),
+            ContractError::Integration(shared::IntegrationError::RateLimited)
+        );
+
+        let trapped = SimulationResponse { error: Some("HostError: Error(WasmVm, InvalidAction)".to_string()), ..Default::default() };
+        assert!(matches!(builder.build(&invocation, &simulator(trapped)), Err(ContractError::SimulationFailed(_))));
+
+        let archived = SimulationResponse { restore_required: true, ..successful_simulation() };
+        assert!(matches!(builder.build(&invocation, &simulator(archived)), Err(ContractError::SimulationFailed(_))));
+
+        let capped = builder.with_fee_buffers(FeeBufferConfig { max_fee: Some(11_000), ..Default::default() });
+        assert!(matches!(capped.build(&invocation, &simulator(successful_simulation())), Err(ContractError::SimulationFailed(_))));
+
+        assert!(TransactionBuilder::new(&NetworkConfig::testnet(), "not-an-account", 1).is_err());
+    }
+}
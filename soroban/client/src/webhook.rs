@@ -0,0 +1,459 @@
+//! Webhook dispatcher for contract event fan-out
+//!
+//! Backends register a URL and an `EventFilter`; matching events received from
+//! the `EventMonitor` are serialized, signed with the endpoint's secret
+//! (HMAC-SHA256) and queued for delivery. Like the keeper, the dispatcher does
+//! not own a timer or an HTTP stack: the host calls `deliver_due` periodically
+//! with a `WebhookTransport`. Failed deliveries are retried with exponential
+//! backoff and moved to a dead-letter queue once the retry budget is spent.
+
+use alloc::collections::BTreeMap as HashMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::rc::Rc;
+use alloc::format;
+use core::cell::RefCell;
+use soroban_sdk::{Bytes, Env};
+use crate::{codec, ContractResult, ContractError};
+use crate::event_monitor::{ContractEvent, EventFilter, EventMonitor};
+
+/// Header carrying the payload signature (`t=<timestamp>,v1=<hex hmac>`)
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+/// Header carrying the delivery ID, stable across retries
+pub const DELIVERY_ID_HEADER: &str = "X-Webhook-Delivery";
+
+/// HTTP transport used to deliver webhook payloads
+pub trait WebhookTransport {
+    /// POST a payload and return the response status code
+    fn post(&self, url: &str, headers: &[(String, String)], body: &str) -> Result<u16, String>;
+}
+
+/// Registered webhook endpoint
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WebhookEndpoint {
+    pub id: String,
+    pub url: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::hex_bytes"))]
+    pub secret: Vec<u8>,
+    pub filter: EventFilter,
+    pub active: bool,
+}
+
+/// Retry behaviour for failed deliveries
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WebhookRetryPolicy {
+    /// Attempts before a delivery is dead-lettered
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub initial_backoff_seconds: u64,
+    /// Upper bound on the delay between retries
+    pub max_backoff_seconds: u64,
+}
+
+impl Default for WebhookRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff_seconds: 10,
+            max_backoff_seconds: 3600,
+        }
+    }
+}
+
+/// Signed payload queued for an endpoint
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WebhookDelivery {
+    pub delivery_id: u64,
+    pub endpoint_id: String,
+    pub event_type: String,
+    pub payload: String,
+    pub signature: String,
+    pub attempts: u32,
+    pub created_at: u64,
+    pub next_attempt_at: u64,
+    pub last_error: Option<String>,
+}
+
+/// Delivery counters
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WebhookMetrics {
+    pub enqueued: u64,
+    pub delivered: u64,
+    pub failed_attempts: u64,
+    pub dead_lettered: u64,
+    pub pending: u32,
+}
+
+/// Dispatcher that fans contract events out to registered webhooks
+pub struct WebhookDispatcher {
+    env: Env,
+    retry_policy: WebhookRetryPolicy,
+    endpoints: HashMap<String, WebhookEndpoint>,
+    queue: Vec<WebhookDelivery>,
+    dead_letters: Vec<WebhookDelivery>,
+    next_delivery_id: u64,
+    metrics: WebhookMetrics,
+}
+
+impl WebhookDispatcher {
+    /// Create a dispatcher with the default retry policy
+    pub fn new(env: Env) -> Self {
+        Self {
+            env,
+            retry_policy: WebhookRetryPolicy::default(),
+            endpoints: HashMap::new(),
+            queue: Vec::new(),
+            dead_letters: Vec::new(),
+            next_delivery_id: 1,
+            metrics: WebhookMetrics::default(),
+        }
+    }
+
+    /// Set the retry policy used for failed deliveries
+    pub fn with_retry_policy(mut self, policy: WebhookRetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Register (or replace) a webhook endpoint
+    ///
+    /// # Arguments
+    /// * `id` - Endpoint identifier
+    /// * `url` - HTTP(S) URL receiving the callbacks
+    /// * `secret` - Shared secret used to sign payloads
+    /// * `filter` - Events delivered to this endpoint
+    pub fn register_endpoint(&mut self, id: &str, url: &str, secret: &[u8], filter: EventFilter) -> ContractResult<()> {
+        if id.is_empty() || secret.is_empty() || !(url.starts_with("https://") || url.starts_with("http://")) {
+            return Err(ContractError::Validation(
+                shared::ValidationError::InvalidParameters
+            ));
+        }
+
+        self.endpoints.insert(id.to_string(), WebhookEndpoint {
+            id: id.to_string(),
+            url: url.to_string(),
+            secret: secret.to_vec(),
+            filter,
+            active: true,
+        });
+        Ok(())
+    }
+
+    /// Remove an endpoint and drop its queued deliveries
+    pub fn remove_endpoint(&mut self, id: &str) {
+        self.endpoints.remove(id);
+        self.queue.retain(|delivery| delivery.endpoint_id != id);
+    }
+
+    /// Enable or disable an endpoint without losing its registration
+    pub fn set_endpoint_active(&mut self, id: &str, active: bool) -> ContractResult<()> {
+        match self.endpoints.get_mut(id) {
+            Some(endpoint) => {
+                endpoint.active = active;
+                Ok(())
+            }
+            None => Err(ContractError::ParseError(format!("webhook endpoint not registered: {}", id))),
+        }
+    }
+
+    /// Forward every event processed by `monitor` to the dispatcher
+    pub fn attach(dispatcher: &Rc<RefCell<Self>>, monitor: &mut EventMonitor, subscription_id: &str) -> ContractResult<()> {
+        let dispatcher = dispatcher.clone();
+        monitor.subscribe(subscription_id.to_string(), EventFilter::new(), move |event| {
+            dispatcher.borrow_mut().handle_event(event);
+            Ok(())
+        })
+    }
+
+    /// Queue a signed delivery for every active endpoint whose filter matches
+    ///
+    /// # Returns
+    /// * Number of deliveries queued
+    pub fn handle_event(&mut self, event: &ContractEvent) -> u32 {
+        let now = self.env.ledger().timestamp();
+        let payload = codec::encode_contract_event_json(&self.env, event);
+        let mut queued = 0;
+
+        for endpoint in self.endpoints.values() {
+            if !endpoint.active || !endpoint.filter.matches(event) {
+                continue;
+            }
+
+            self.queue.push(WebhookDelivery {
+                delivery_id: self.next_delivery_id,
+                endpoint_id: endpoint.id.clone(),
+                event_type: event.event_type.clone(),
+                signature: sign_payload(&self.env, &endpoint.secret, now, &payload),
+                payload: payload.clone(),
+                attempts: 0,
+                created_at: now,
+                next_attempt_at: now,
+                last_error: None,
+            });
+            self.next_delivery_id += 1;
+            queued += 1;
+        }
+
+        self.metrics.enqueued += queued as u64;
+        queued
+    }
+
+    /// Attempt every delivery that is due at `now`
+    ///
+    /// Non-2xx responses and transport errors are retried with exponential
+    /// backoff; deliveries that exhaust `max_attempts` move to the dead-letter
+    /// queue.
+    ///
+    /// # Returns
+    /// * Number of deliveries that succeeded
+    pub fn deliver_due(&mut self, transport: &dyn WebhookTransport, now: u64) -> u32 {
+        let mut delivered = 0;
+        let mut remaining = Vec::new();
+
+        for mut delivery in core::mem::take(&mut self.queue) {
+            if delivery.next_attempt_at > now {
+                remaining.push(delivery);
+                continue;
+            }
+
+            let url = match self.endpoints.get(&delivery.endpoint_id) {
+                Some(endpoint) => {
+                    // Sign each attempt afresh so a retry is not rejected as a
+                    // stale timestamp by receivers enforcing a replay window
+                    delivery.signature = sign_payload(&self.env, &endpoint.secret, now, &delivery.payload);
+                    endpoint.url.clone()
+                }
+                None => continue,
+            };
+            let headers = [
+                (String::from("Content-Type"), String::from("application/json")),
+                (String::from(DELIVERY_ID_HEADER), delivery.delivery_id.to_string()),
+                (String::from(SIGNATURE_HEADER), delivery.signature.clone()),
+            ];
+
+            delivery.attempts += 1;
+            let error = match transport.post(&url, &headers, &delivery.payload) {
+                Ok(status) if (200..300).contains(&status) => None,
+                Ok(status) => Some(format!("HTTP {}", status)),
+                Err(error) => Some(error),
+            };
+
+            match error {
+                None => {
+                    delivered += 1;
+                    self.metrics.delivered += 1;
+                }
+                Some(error) => {
+                    self.metrics.failed_attempts += 1;
+                    delivery.last_error = Some(error);
+
+                    if delivery.attempts >= self.retry_policy.max_attempts {
+                        self.metrics.dead_lettered += 1;
+                        self.dead_letters.push(delivery);
+                    } else {
+                        delivery.next_attempt_at = now.saturating_add(self.backoff(delivery.attempts));
+                        remaining.push(delivery);
+                    }
+                }
+            }
+        }
+
+        self.queue = remaining;
+        delivered
+    }
+
+    /// Move a dead-lettered delivery back onto the queue
+    pub fn replay_dead_letter(&mut self, delivery_id: u64, now: u64) -> ContractResult<()> {
+        let index = self.dead_letters
+            .iter()
+            .position(|delivery| delivery.delivery_id == delivery_id)
+            .ok_or_else(|| ContractError::ParseError(format!("dead letter not found: {}", delivery_id)))?;
+
+        let mut delivery = self.dead_letters.remove(index);
+        delivery.attempts = 0;
+        delivery.next_attempt_at = now;
+        self.queue.push(delivery);
+        Ok(())
+    }
+
+    /// Deliveries waiting to be attempted or retried
+    pub fn pending(&self) -> &[WebhookDelivery] {
+        &self.queue
+    }
+
+    /// Deliveries that exhausted their retries
+    pub fn dead_letters(&self) -> &[WebhookDelivery] {
+        &self.dead_letters
+    }
+
+    /// Get delivery metrics
+    pub fn metrics(&self) -> WebhookMetrics {
+        WebhookMetrics {
+            pending: self.queue.len() as u32,
+            ..self.metrics.clone()
+        }
+    }
+
+    fn backoff(&self, attempts: u32) -> u64 {
+        let factor = 1u64.checked_shl(attempts.saturating_sub(1)).unwrap_or(u64::MAX);
+        self.retry_policy.initial_backoff_seconds
+            .saturating_mul(factor)
+            .min(self.retry_policy.max_backoff_seconds)
+    }
+}
+
+/// Sign a payload as `t=<timestamp>,v1=<hex HMAC-SHA256 of "<timestamp>.<payload>">`
+///
+/// Receivers recompute the HMAC with their copy of the secret and should reject
+/// stale timestamps to prevent replays.
+pub fn sign_payload(env: &Env, secret: &[u8], timestamp: u64, payload: &str) -> String {
+    let message = format!("{}.{}", timestamp, payload);
+    let mac = hmac_sha256(env, secret, message.as_bytes());
+    format!("t={},v1={}", timestamp, hex::encode(mac))
+}
+
+fn hmac_sha256(env: &Env, key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..32].copy_from_slice(&sha256(env, key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Vec::with_capacity(BLOCK_SIZE + message.len());
+    inner.extend(block.iter().map(|b| b ^ 0x36));
+    inner.extend_from_slice(message);
+    let inner_hash = sha256(env, &inner);
+
+    let mut outer = Vec::with_capacity(BLOCK_SIZE + 32);
+    outer.extend(block.iter().map(|b| b ^ 0x5c));
+    outer.extend_from_slice(&inner_hash);
+    sha256(env, &outer)
+}
+
+fn sha256(env: &Env, data: &[u8]) -> [u8; 32] {
+    env.crypto().sha256(&Bytes::from_slice(env, data)).to_array()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_monitor::EventData;
+    use soroban_sdk::{testutils::Address as _, Address};
+
+    struct FlakyTransport {
+        responses: RefCell<Vec<Result<u16, String>>>,
+    }
+
+    impl WebhookTransport for FlakyTransport {
+        fn post(&self, _url: &str, _headers: &[(String, String)], _body: &str) -> Result<u16, String> {
+            self.responses.borrow_mut().remove(0)
+        }
+    }
+
+    struct RecordingTransport {
+        status: u16,
+        signatures: RefCell<Vec<String>>,
+    }
+
+    impl WebhookTransport for RecordingTransport {
+        fn post(&self, _url: &str, headers: &[(String, String)], _body: &str) -> Result<u16, String> {
+            let signature = headers.iter().find(|(name, _)| name == SIGNATURE_HEADER).unwrap();
+            self.signatures.borrow_mut().push(signature.1.clone());
+            Ok(self.status)
+        }
+    }
+
+    #[test]
+    fn test_hmac_and_retry_to_dead_letter() {
+        let env = Env::default();
+
+        // RFC 4231 test case 2
+        let mac = hmac_sha256(&env, b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(
+            hex::encode(mac),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+
+        let mut dispatcher = WebhookDispatcher::new(env.clone()).with_retry_policy(WebhookRetryPolicy {
+            max_attempts: 2,
+            initial_backoff_seconds: 10,
+            max_backoff_seconds: 60,
+        });
+        assert!(dispatcher.register_endpoint("ops", "ftp://example.com", b"secret", EventFilter::new()).is_err());
+        dispatcher
+            .register_endpoint(
+                "ops",
+                "https://ops.example.com/hooks",
+                b"secret",
+                EventFilter::new().for_event_types(alloc::vec![String::from("emergency")]),
+            )
+            .unwrap();
+
+        let event = ContractEvent {
+            contract_address: Address::generate(&env),
+            event_type: String::from("emergency"),
+            topics: Vec::new(),
+            data: EventData::Generic { data: HashMap::new() },
+            timestamp: 0,
+            block_number: 1,
+            transaction_hash: String::new(),
+        };
+        assert_eq!(dispatcher.handle_event(&event), 1);
+
+        let transport = FlakyTransport {
+            responses: RefCell::new(alloc::vec![Ok(500), Err(String::from("timeout"))]),
+        };
+        assert_eq!(dispatcher.deliver_due(&transport, 0), 0);
+        assert_eq!(dispatcher.pending()[0].next_attempt_at, 10);
+        assert_eq!(dispatcher.deliver_due(&transport, 5), 0);
+        assert_eq!(dispatcher.deliver_due(&transport, 10), 0);
+
+        let metrics = dispatcher.metrics();
+        assert_eq!(metrics.failed_attempts, 2);
+        assert_eq!(metrics.dead_lettered, 1);
+        assert_eq!(metrics.pending, 0);
+        assert_eq!(dispatcher.dead_letters()[0].last_error, Some(String::from("timeout")));
+
+        let delivery_id = dispatcher.dead_letters()[0].delivery_id;
+        dispatcher.replay_dead_letter(delivery_id, 20).unwrap();
+        let transport = FlakyTransport { responses: RefCell::new(alloc::vec![Ok(204)]) };
+        assert_eq!(dispatcher.deliver_due(&transport, 20), 1);
+        assert_eq!(dispatcher.metrics().delivered, 1);
+    }
+
+    #[test]
+    fn test_retries_are_signed_at_attempt_time() {
+        let env = Env::default();
+        let mut dispatcher = WebhookDispatcher::new(env.clone());
+        dispatcher.register_endpoint("ops", "https://ops.example.com/hooks", b"secret", EventFilter::new()).unwrap();
+
+        let event = ContractEvent {
+            contract_address: Address::generate(&env),
+            event_type: String::from("emergency"),
+            topics: Vec::new(),
+            data: EventData::Generic { data: HashMap::new() },
+            timestamp: 0,
+            block_number: 1,
+            transaction_hash: String::new(),
+        };
+        dispatcher.handle_event(&event);
+        let payload = dispatcher.pending()[0].payload.clone();
+
+        let transport = RecordingTransport { status: 503, signatures: RefCell::new(Vec::new()) };
+        dispatcher.deliver_due(&transport, 100);
+        dispatcher.deliver_due(&transport, 110);
+
+        let signatures = transport.signatures.borrow();
+        assert_eq!(signatures[0], sign_payload(&env, b"secret", 100, &payload));
+        assert_eq!(signatures[1], sign_payload(&env, b"secret", 110, &payload));
+        assert_eq!(dispatcher.pending()[0].signature, signatures[1]);
+    }
+}
@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as TestAddress, Ledger},
+    vec, Address, Env
+};
+
+/// Reserve manager answering the health probe
+#[contract]
+pub struct MockAlertReserve;
+
+#[contractimpl]
+impl MockAlertReserve {
+    pub fn get_ratio(_env: Env) -> u64 {
+        10000
+    }
+}
+
+fn setup(env: &Env) -> (IntegrationRouterClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &env.register(MockAlertReserve, ()),
+    );
+
+    (client, admin)
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += seconds;
+    });
+}
+
+#[test]
+fn test_unacknowledged_critical_alert_escalates_along_chain() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let (first, second, third) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    client.set_escalation_policy(&admin, &600u64, &vec![&env, first.clone(), second.clone(), third.clone()], &0u64);
+
+    let critical = client.raise_alert(&admin, &String::from_str(&env, "bridge_stalled"), &AlertSeverity::Critical, &String::from_str(&env, "No deposits processed"));
+    client.raise_alert(&admin, &String::from_str(&env, "slow_oracle"), &AlertSeverity::Warning, &String::from_str(&env, "Oracle lagging"));
+    assert_eq!(client.get_alert(&critical).unwrap().assigned_to, Some(first.clone()));
+
+    advance(&env, 599);
+    assert_eq!(client.escalate_alerts(&admin), 0);
+    advance(&env, 1);
+    assert_eq!(client.escalate_alerts(&admin), 1);
+
+    let alert = client.get_alert(&critical).unwrap();
+    assert_eq!(alert.severity, AlertSeverity::Emergency);
+    assert_eq!(alert.assigned_to, Some(second.clone()));
+    assert_eq!(alert.escalation_level, 1);
+
+    // A snooze holds escalation, and the escalation period restarts when it expires
+    client.snooze_alert(&second, &critical, &(1_000_600 + 1200));
+    advance(&env, 1200);
+    assert_eq!(client.escalate_alerts(&admin), 0);
+    advance(&env, 599);
+    assert_eq!(client.escalate_alerts(&admin), 0);
+    advance(&env, 1);
+    assert_eq!(client.escalate_alerts(&admin), 1);
+    assert_eq!(client.get_alert(&critical).unwrap().assigned_to, Some(third.clone()));
+
+    // The whole chain has been paged
+    advance(&env, 600);
+    assert_eq!(client.escalate_alerts(&admin), 0);
+
+    assert!(client.try_acknowledge_alert(&Address::generate(&env), &critical).is_err());
+    client.acknowledge_alert(&third, &critical);
+    assert_eq!(client.get_alert(&critical).unwrap().acknowledged_by, Some(third.clone()));
+    assert_eq!(client.try_acknowledge_alert(&third, &critical), Err(Ok(IntegrationError::InvalidOperationState)));
+
+    assert_eq!(client.get_alert_resolution(&critical), None);
+    client.resolve_alert(&third, &critical);
+    assert_eq!(
+        client.get_alert_resolution(&critical),
+        Some(AlertResolution { resolved_by: third.clone(), resolved_at: env.ledger().timestamp() })
+    );
+    assert_eq!(client.try_resolve_alert(&admin, &critical), Err(Ok(IntegrationError::InvalidOperationState)));
+    let active = client.get_system_alerts();
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap().severity, AlertSeverity::Warning);
+    assert_eq!(client.get_system_health(&admin).active_alerts.len(), 1);
+}
+
+#[test]
+fn test_on_call_rotation_and_validation() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let (first, second) = (Address::generate(&env), Address::generate(&env));
+
+    assert_eq!(
+        client.try_set_escalation_policy(&admin, &600u64, &Vec::new(&env), &0u64),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    client.set_escalation_policy(&admin, &600u64, &vec![&env, first.clone(), second.clone()], &3600u64);
+
+    assert_eq!(client.get_on_call(), Some(first.clone()));
+    advance(&env, 3600);
+    assert_eq!(client.get_on_call(), Some(second.clone()));
+
+    // Escalation wraps around from the on-call contact
+    let alert_id = client.raise_alert(&admin, &String::from_str(&env, "queue_backlog"), &AlertSeverity::Critical, &String::from_str(&env, "Withdrawal queue backlog"));
+    advance(&env, 600);
+    assert_eq!(client.escalate_alerts(&admin), 1);
+    assert_eq!(client.get_alert(&alert_id).unwrap().assigned_to, Some(first.clone()));
+
+    assert_eq!(
+        client.try_snooze_alert(&first, &alert_id, &(1_004_200 + 8 * 86400)),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert_eq!(
+        client.try_snooze_alert(&first, &alert_id, &1_004_200u64),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    // A resolved alert can no longer be acknowledged or snoozed
+    client.resolve_alert(&first, &alert_id);
+    assert_eq!(client.try_acknowledge_alert(&first, &alert_id), Err(Ok(IntegrationError::InvalidOperationState)));
+    assert_eq!(
+        client.try_snooze_alert(&first, &alert_id, &(1_004_200 + 600)),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    advance(&env, 3000);
+    assert_eq!(client.get_on_call(), Some(first.clone()));
+}
@@ -317,6 +317,8 @@ impl ApiLayer {
             IntegrationError::ContractNotFound => (404, String::from_str(env, "Contract not found")),
             IntegrationError::ContractCallFailed => (500, String::from_str(env, "Contract call failed")),
             IntegrationError::InvalidContractResponse => (502, String::from_str(env, "Invalid contract response")),
+            IntegrationError::OracleStale => (503, String::from_str(env, "Oracle data is stale")),
+            IntegrationError::ContractIsolated => (503, String::from_str(env, "Contract is isolated")),
             IntegrationError::ComplianceCheckFailed => (422, String::from_str(env, "Compliance check failed")),
             IntegrationError::InsufficientKYCTier => (403, String::from_str(env, "Insufficient KYC tier")),
             IntegrationError::AddressBlacklisted => (403, String::from_str(env, "Address is blacklisted")),
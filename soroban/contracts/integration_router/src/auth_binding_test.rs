@@ -0,0 +1,119 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::TestHarness;
+use soroban_sdk::{
+    testutils::{Address as TestAddress, MockAuth, MockAuthInvoke},
+    xdr::{ScErrorCode, ScErrorType},
+    Address, Env, IntoVal
+};
+
+/// Error surfaced to the caller when no signed authorization matches the call
+fn auth_rejected() -> soroban_sdk::Error {
+    soroban_sdk::Error::from_type_and_code(ScErrorType::Context, ScErrorCode::InvalidAction)
+}
+
+fn compliance_failed() -> soroban_sdk::Error {
+    soroban_sdk::Error::from_contract_error(IntegrationError::ComplianceCheckFailed as u32)
+}
+
+/// Authorize a single call to `fn_name` on the router with the given bound arguments
+fn sign(h: &TestHarness, signer: &Address, fn_name: &str, args: Vec<Val>) {
+    h.env.mock_auths(&[MockAuth {
+        address: signer,
+        invoke: &MockAuthInvoke {
+            contract: &h.router.address,
+            fn_name,
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+#[test]
+fn test_role_grant_cannot_be_replayed_with_another_role() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = Address::generate(&env);
+    let args: Vec<Val> = (user.clone(), UserRole::Operator).into_val(&env);
+
+    sign(&h, &h.admin, "set_user_role", args.clone());
+    assert_eq!(
+        h.router.try_set_user_role(&h.admin, &user, &UserRole::SuperAdmin),
+        Err(Ok(auth_rejected()))
+    );
+    assert_eq!(h.router.get_user_role(&user), UserRole::User);
+
+    sign(&h, &h.admin, "set_user_role", args);
+    h.router.set_user_role(&h.admin, &user, &UserRole::Operator);
+    assert_eq!(h.router.get_user_role(&user), UserRole::Operator);
+
+    // Removal is bound to the role being removed
+    env.mock_all_auths();
+    h.router.set_user_role(&h.admin, &user, &UserRole::SystemAdmin);
+    sign(&h, &h.admin, "remove_user_role", (user.clone(), UserRole::Operator).into_val(&env));
+    assert_eq!(h.router.try_remove_user_role(&h.admin, &user), Err(Ok(auth_rejected())));
+    assert_eq!(h.router.get_user_role(&user), UserRole::SystemAdmin);
+}
+
+#[test]
+fn test_deposit_and_withdrawal_auth_cannot_be_replayed_with_altered_amounts() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    let btc_tx_hash = BytesN::from_array(&env, &[4u8; 32]);
+    let btc_address = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
+    // Stop both workflows at their first step so an authorized call fails with a contract error
+    h.router.set_fault_injection_enabled(&h.admin, &true);
+    h.router.inject_fault(&h.admin, &FaultStep::DepositKyc);
+    h.router.inject_fault(&h.admin, &FaultStep::WithdrawalKyc);
+
+    let deposit_args: Vec<Val> = (user.clone(), 100_000u64, btc_tx_hash.clone(), 6u32).into_val(&env);
+    sign(&h, &h.operator, "execute_bitcoin_deposit", deposit_args.clone());
+    assert_eq!(
+        h.router.try_execute_bitcoin_deposit(&h.operator, &user, &1_000_000, &btc_tx_hash, &6),
+        Err(Ok(auth_rejected()))
+    );
+    sign(&h, &h.operator, "execute_bitcoin_deposit", deposit_args);
+    assert_eq!(
+        h.router.try_execute_bitcoin_deposit(&h.operator, &user, &100_000, &btc_tx_hash, &6),
+        Err(Ok(compliance_failed()))
+    );
+
+    let withdrawal_args: Vec<Val> = (user.clone(), 10_000_000u64, btc_address.clone(), None::<BytesN<32>>).into_val(&env);
+    sign(&h, &h.operator, "execute_token_withdrawal", withdrawal_args.clone());
+    assert_eq!(
+        h.router.try_execute_token_withdrawal(&h.operator, &user, &90_000_000, &btc_address),
+        Err(Ok(auth_rejected()))
+    );
+    sign(&h, &h.operator, "execute_token_withdrawal", withdrawal_args.clone());
+    assert_eq!(
+        h.router.try_execute_token_withdrawal(
+            &h.operator, &user, &10_000_000, &String::from_str(&env, "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq")
+        ),
+        Err(Ok(auth_rejected()))
+    );
+    sign(&h, &h.operator, "execute_token_withdrawal", withdrawal_args);
+    assert_eq!(
+        h.router.try_execute_token_withdrawal(&h.operator, &user, &10_000_000, &btc_address),
+        Err(Ok(compliance_failed()))
+    );
+}
+
+#[test]
+fn test_contract_registry_update_is_bound_to_the_new_address() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let name = String::from_str(&env, "kyc_registry");
+    let signed = Address::generate(&env);
+
+    sign(&h, &h.admin, "update_contract_address", (name.clone(), signed.clone()).into_val(&env));
+    assert_eq!(
+        h.router.try_update_contract_address(&h.admin, &name, &Address::generate(&env)),
+        Err(Ok(auth_rejected()))
+    );
+
+    sign(&h, &h.admin, "update_contract_address", (name.clone(), signed.clone()).into_val(&env));
+    h.router.update_contract_address(&h.admin, &name, &signed);
+    assert_eq!(h.router.get_contract_address(&name), Some(signed));
+}
@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use super::*;
+use crate::testing::{mock_contracts, MockKycRegistryClient, MockTokenClient};
 use soroban_sdk::{
     testutils::{Address as TestAddress, Ledger, LedgerInfo},
     Address, Env, BytesN
@@ -10,6 +11,7 @@ use soroban_sdk::{
 #[test]
 fn test_complete_bitcoin_deposit_workflow() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
@@ -17,10 +19,7 @@ fn test_complete_bitcoin_deposit_workflow() {
     let admin = Address::generate(&env);
     let user = Address::generate(&env);
     let operator = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
+    let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
     
     // Initialize the contract
     client.initialize(
@@ -33,6 +32,9 @@ fn test_complete_bitcoin_deposit_workflow() {
     
     // Set operator role
     client.set_user_role(&admin, &operator, &UserRole::Operator);
+    let kyc = MockKycRegistryClient::new(&env, &kyc_registry);
+    kyc.set_router(&contract_id);
+    kyc.set_tier(&user, &1);
     
     // Test data
     let btc_amount = 100_000_000u64; // 1 BTC in satoshis
@@ -60,13 +62,17 @@ fn test_complete_bitcoin_deposit_workflow() {
     assert_eq!(status.istsi_amount, btc_amount * 100_000_000); // 1:100M ratio
     assert_eq!(status.user, user);
     assert_eq!(status.btc_tx_hash, btc_tx_hash);
+    
+    // The tracker is resolved through the transaction hash, not a list scan
+    assert_eq!(client.get_bitcoin_deposit_status(&btc_tx_hash).unwrap().operation_id, operation_id);
+    assert!(client.get_bitcoin_deposit_status(&BytesN::from_array(&env, &[9u8; 32])).is_none());
 }
 
 /// Test Bitcoin deposit with insufficient KYC compliance
 #[test]
-#[should_panic(expected = "ComplianceCheckFailed")]
 fn test_bitcoin_deposit_kyc_failure() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
@@ -74,10 +80,7 @@ fn test_bitcoin_deposit_kyc_failure() {
     let admin = Address::generate(&env);
     let user = Address::generate(&env);
     let operator = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
+    let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
     
     // Initialize the contract
     client.initialize(
@@ -90,13 +93,17 @@ fn test_bitcoin_deposit_kyc_failure() {
     
     // Set operator role
     client.set_user_role(&admin, &operator, &UserRole::Operator);
+    let kyc = MockKycRegistryClient::new(&env, &kyc_registry);
+    kyc.set_router(&contract_id);
+    kyc.set_tier(&user, &1);
+    kyc.set_compliance_approval(&false);
     
     // Test data - large amount that should fail KYC
     let btc_amount = 1_000_000_000_000u64; // Very large amount
     let btc_tx_hash = BytesN::from_array(&env, &[2u8; 32]);
     let btc_confirmations = 6u32;
     
-    // This should fail due to KYC compliance check
+    // The KYC compliance check fails, so the deposit is recorded as failed without minting
     client.execute_btc_deposit_tracked(
         &operator,
         &user,
@@ -104,13 +111,17 @@ fn test_bitcoin_deposit_kyc_failure() {
         &btc_tx_hash,
         &btc_confirmations
     );
+    
+    let status = client.get_deposit_status_by_tx_hash(&btc_tx_hash).unwrap();
+    assert_eq!(status.status, DepositProcessingStatus::Failed);
+    assert_eq!(MockTokenClient::new(&env, &istsi_token).balance(&user), 0);
 }
 
 /// Test Bitcoin deposit with insufficient confirmations
 #[test]
-#[should_panic(expected = "BitcoinTransactionFailed")]
 fn test_bitcoin_deposit_insufficient_confirmations() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
@@ -118,10 +129,7 @@ fn test_bitcoin_deposit_insufficient_confirmations() {
     let admin = Address::generate(&env);
     let user = Address::generate(&env);
     let operator = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
+    let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
     
     // Initialize the contract
     client.initialize(
@@ -134,27 +142,35 @@ fn test_bitcoin_deposit_insufficient_confirmations() {
     
     // Set operator role
     client.set_user_role(&admin, &operator, &UserRole::Operator);
+    let kyc = MockKycRegistryClient::new(&env, &kyc_registry);
+    kyc.set_router(&contract_id);
+    kyc.set_tier(&user, &1);
     
     // Test data with insufficient confirmations
     let btc_amount = 100_000_000u64;
     let btc_tx_hash = BytesN::from_array(&env, &[3u8; 32]);
     let btc_confirmations = 1u32; // Less than required minimum of 3
     
-    // This should fail due to insufficient confirmations
-    client.execute_btc_deposit_tracked(
+    // The deposit is held until it reaches its confirmations, with no operation started
+    let operation_id = client.execute_btc_deposit_tracked(
         &operator,
         &user,
         &btc_amount,
         &btc_tx_hash,
         &btc_confirmations
     );
+    assert_eq!(operation_id, BytesN::from_array(&env, &[0u8; 32]));
+    
+    let status = client.get_deposit_status_by_tx_hash(&btc_tx_hash).unwrap();
+    assert_eq!(status.status, DepositProcessingStatus::Pending);
+    assert_eq!(MockTokenClient::new(&env, &istsi_token).balance(&user), 0);
 }
 
 /// Test Bitcoin deposit with duplicate transaction hash
 #[test]
-#[should_panic(expected = "BitcoinTransactionFailed")]
 fn test_bitcoin_deposit_duplicate_transaction() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
@@ -162,10 +178,7 @@ fn test_bitcoin_deposit_duplicate_transaction() {
     let admin = Address::generate(&env);
     let user = Address::generate(&env);
     let operator = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
+    let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
     
     // Initialize the contract
     client.initialize(
@@ -178,6 +191,9 @@ fn test_bitcoin_deposit_duplicate_transaction() {
     
     // Set operator role
     client.set_user_role(&admin, &operator, &UserRole::Operator);
+    let kyc = MockKycRegistryClient::new(&env, &kyc_registry);
+    kyc.set_router(&contract_id);
+    kyc.set_tier(&user, &1);
     
     // Test data
     let btc_amount = 100_000_000u64;
@@ -193,30 +209,36 @@ fn test_bitcoin_deposit_duplicate_transaction() {
         &btc_confirmations
     );
     
-    // Second deposit with same tx hash should fail
-    client.execute_btc_deposit_tracked(
+    let minted = MockTokenClient::new(&env, &istsi_token).balance(&user);
+    assert!(minted > 0);
+    
+    // Second deposit with same tx hash should fail without touching the first
+    let result = client.try_execute_btc_deposit_tracked(
         &operator,
         &user,
         &btc_amount,
         &btc_tx_hash,
         &btc_confirmations
     );
+    assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(IntegrationError::DuplicateOperation as u32))));
+    
+    assert_eq!(MockTokenClient::new(&env, &istsi_token).balance(&user), minted);
+    let status = client.get_deposit_status_by_tx_hash(&btc_tx_hash).unwrap();
+    assert_eq!(status.status, DepositProcessingStatus::Completed);
 }
 
 /// Test deposit limits checking functionality
 #[test]
 fn test_deposit_limits_checking() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
     // Set up test addresses
     let admin = Address::generate(&env);
     let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
+    let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
     
     // Initialize the contract
     client.initialize(
@@ -239,16 +261,14 @@ fn test_deposit_limits_checking() {
 #[test]
 fn test_confirmation_requirements() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
     // Set up test addresses
     let admin = Address::generate(&env);
     let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
+    let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
     
     // Initialize the contract
     client.initialize(
@@ -275,16 +295,14 @@ fn test_confirmation_requirements() {
 #[test]
 fn test_get_pending_deposits() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
     // Set up test addresses
     let admin = Address::generate(&env);
     let operator = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
+    let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
     
     // Initialize the contract
     client.initialize(
@@ -299,16 +317,16 @@ fn test_get_pending_deposits() {
     client.set_user_role(&admin, &operator, &UserRole::Operator);
     
     // Get pending deposits (should be empty initially)
-    let pending_deposits = client.get_pending_deposits(&operator);
+    let pending_deposits = client.get_pending_deposits(&operator, &0, &0, &100).deposits;
     
-    // Should return a vector (empty or with deposits)
-    assert!(pending_deposits.len() >= 0);
+    assert_eq!(pending_deposits.len(), 0);
 }
 
 /// Test deposit status tracking throughout the workflow
 #[test]
 fn test_deposit_status_tracking() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
@@ -316,10 +334,7 @@ fn test_deposit_status_tracking() {
     let admin = Address::generate(&env);
     let user = Address::generate(&env);
     let operator = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
+    let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
     
     // Initialize the contract
     client.initialize(
@@ -332,6 +347,9 @@ fn test_deposit_status_tracking() {
     
     // Set operator role
     client.set_user_role(&admin, &operator, &UserRole::Operator);
+    let kyc = MockKycRegistryClient::new(&env, &kyc_registry);
+    kyc.set_router(&contract_id);
+    kyc.set_tier(&user, &1);
     
     // Test data
     let btc_amount = 100_000_000u64;
@@ -374,6 +392,7 @@ fn test_deposit_status_tracking() {
 #[test]
 fn test_atomic_rollback_on_mint_failure() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
@@ -381,10 +400,7 @@ fn test_atomic_rollback_on_mint_failure() {
     let admin = Address::generate(&env);
     let user = Address::generate(&env);
     let operator = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
+    let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
     
     // Initialize the contract
     client.initialize(
@@ -397,6 +413,9 @@ fn test_atomic_rollback_on_mint_failure() {
     
     // Set operator role
     client.set_user_role(&admin, &operator, &UserRole::Operator);
+    let kyc = MockKycRegistryClient::new(&env, &kyc_registry);
+    kyc.set_router(&contract_id);
+    kyc.set_tier(&user, &1);
     
     // Test data that might cause minting to fail
     let btc_amount = 100_000_000u64;
@@ -422,9 +441,10 @@ fn test_atomic_rollback_on_mint_failure() {
 
 /// Test system pause functionality during deposit
 #[test]
-#[should_panic(expected = "SystemPaused")]
+#[should_panic(expected = "Error(Contract, #50)")]
 fn test_deposit_when_system_paused() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
@@ -432,10 +452,7 @@ fn test_deposit_when_system_paused() {
     let admin = Address::generate(&env);
     let user = Address::generate(&env);
     let operator = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
+    let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
     
     // Initialize the contract
     client.initialize(
@@ -448,6 +465,9 @@ fn test_deposit_when_system_paused() {
     
     // Set operator role
     client.set_user_role(&admin, &operator, &UserRole::Operator);
+    let kyc = MockKycRegistryClient::new(&env, &kyc_registry);
+    kyc.set_router(&contract_id);
+    kyc.set_tier(&user, &1);
     
     // Pause the system
     client.emergency_pause(&admin, &String::from_str(&env, "Testing pause functionality"));
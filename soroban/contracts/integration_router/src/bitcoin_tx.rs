@@ -0,0 +1,126 @@
+//! Raw Bitcoin transaction parsing
+//!
+//! Just enough of the legacy (non-witness) serialization to identify a
+//! transaction by txid and compare the outpoints it spends and the outputs
+//! it creates. Segwit transactions are passed in stripped form, which is
+//! also what their txid commits to. Hashes use Bitcoin's internal byte order.
+
+use soroban_sdk::{contracttype, Bytes, BytesN, Env, Vec};
+
+/// Output spent by a transaction input, mirroring the reserve manager's type
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UtxoOutpoint {
+    pub txid: BytesN<32>,
+    pub vout: u32,
+}
+
+/// Inputs and outputs of a parsed transaction
+pub struct ParsedTransaction {
+    pub inputs: Vec<UtxoOutpoint>,
+    pub outputs: Vec<(u64, Bytes)>, // (value in satoshis, scriptPubKey)
+}
+
+/// Double SHA-256 of the serialization
+pub fn txid(env: &Env, raw: &Bytes) -> BytesN<32> {
+    let first = env.crypto().sha256(raw).to_array();
+    env.crypto().sha256(&Bytes::from_array(env, &first)).into()
+}
+
+/// Parse a non-witness serialization; None when it is malformed, carries a
+/// witness marker or has trailing bytes
+pub fn parse_transaction(env: &Env, raw: &Bytes) -> Option<ParsedTransaction> {
+    let mut cursor = 4u32; // version
+
+    let input_count = read_varint(raw, &mut cursor)?;
+    if input_count == 0 {
+        return None;
+    }
+    let mut inputs = Vec::new(env);
+    for _ in 0..input_count {
+        let txid = BytesN::<32>::try_from(read_bytes(raw, &mut cursor, 32)?).ok()?;
+        let vout = u32::from_le_bytes(read_array::<4>(raw, &mut cursor)?);
+        let script_len = read_varint(raw, &mut cursor)?;
+        read_bytes(raw, &mut cursor, u32::try_from(script_len).ok()?)?;
+        read_array::<4>(raw, &mut cursor)?; // sequence
+        inputs.push_back(UtxoOutpoint { txid, vout });
+    }
+
+    let output_count = read_varint(raw, &mut cursor)?;
+    let mut outputs = Vec::new(env);
+    for _ in 0..output_count {
+        let value = u64::from_le_bytes(read_array::<8>(raw, &mut cursor)?);
+        let script_len = read_varint(raw, &mut cursor)?;
+        let script = read_bytes(raw, &mut cursor, u32::try_from(script_len).ok()?)?;
+        outputs.push_back((value, script));
+    }
+
+    read_array::<4>(raw, &mut cursor)?; // locktime
+    if cursor != raw.len() {
+        return None;
+    }
+
+    Some(ParsedTransaction { inputs, outputs })
+}
+
+/// Whether `replacement` is a fee bump of `original`
+///
+/// It must spend at least one of the same outpoints, so the two can never
+/// both confirm, and keep every original output unchanged except one that
+/// may shrink to pay the higher fee. No new recipients may be added.
+pub fn is_fee_bump(original: &ParsedTransaction, replacement: &ParsedTransaction) -> bool {
+    if !replacement.inputs.iter().any(|input| original.inputs.contains(&input)) {
+        return false;
+    }
+    if replacement.outputs.len() != original.outputs.len()
+        || !replacement.outputs.iter().all(|(_, script)| original.outputs.iter().any(|(_, other)| other == script))
+    {
+        return false;
+    }
+
+    let mut shrunk = 0u32;
+    let mut original_total = 0u128;
+    let mut replacement_total = 0u128;
+    for (value, script) in original.outputs.iter() {
+        let replaced = match replacement.outputs.iter().find(|(_, other)| *other == script) {
+            Some((new_value, _)) => new_value,
+            None => return false,
+        };
+        if replaced > value {
+            return false;
+        }
+        if replaced < value {
+            shrunk += 1;
+        }
+        original_total += value as u128;
+        replacement_total += replaced as u128;
+    }
+
+    shrunk <= 1 && replacement_total < original_total
+}
+
+fn read_bytes(raw: &Bytes, cursor: &mut u32, len: u32) -> Option<Bytes> {
+    let end = cursor.checked_add(len)?;
+    if end > raw.len() {
+        return None;
+    }
+    let bytes = raw.slice(*cursor..end);
+    *cursor = end;
+    Some(bytes)
+}
+
+fn read_array<const N: usize>(raw: &Bytes, cursor: &mut u32) -> Option<[u8; N]> {
+    let mut array = [0u8; N];
+    read_bytes(raw, cursor, N as u32)?.copy_into_slice(&mut array);
+    Some(array)
+}
+
+fn read_varint(raw: &Bytes, cursor: &mut u32) -> Option<u64> {
+    let prefix = read_array::<1>(raw, cursor)?[0];
+    match prefix {
+        0xfd => Some(u16::from_le_bytes(read_array::<2>(raw, cursor)?) as u64),
+        0xfe => Some(u32::from_le_bytes(read_array::<4>(raw, cursor)?) as u64),
+        0xff => Some(u64::from_le_bytes(read_array::<8>(raw, cursor)?)),
+        value => Some(value as u64),
+    }
+}
@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as TestAddress, Address, BytesN, Env};
+
+/// Minimal KYC registry exposing only the blacklist lookup
+#[contract]
+pub struct MockBlacklistRegistry;
+
+#[contractimpl]
+impl MockBlacklistRegistry {
+    pub fn list(env: Env, address: Address) {
+        env.storage().instance().set(&address, &true);
+    }
+
+    pub fn is_blacklisted(env: Env, address: Address) -> bool {
+        env.storage().instance().has(&address)
+    }
+}
+
+#[test]
+fn test_blacklisted_user_is_rejected_for_deposit_and_withdrawal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+    let registry_id = env.register(MockBlacklistRegistry, ());
+    let registry = MockBlacklistRegistryClient::new(&env, &registry_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &registry_id,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+
+    let user = Address::generate(&env);
+    registry.list(&user);
+
+    let deposit = client.try_execute_bitcoin_deposit(
+        &admin,
+        &user,
+        &100_000u64,
+        &BytesN::from_array(&env, &[4u8; 32]),
+        &6u32,
+    );
+    assert_eq!(deposit, Err(Ok(IntegrationError::AddressBlacklisted.into())));
+
+    let withdrawal = client.try_execute_token_withdrawal(
+        &admin,
+        &user,
+        &100_000u64,
+        &String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh"),
+    );
+    assert_eq!(withdrawal, Err(Ok(IntegrationError::AddressBlacklisted.into())));
+}
+
+#[test]
+fn test_blacklist_screening_fails_closed_when_registry_is_unreachable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    // The configured KYC registry is not a deployed contract
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+
+    let deposit = client.try_execute_bitcoin_deposit(
+        &admin,
+        &Address::generate(&env),
+        &100_000u64,
+        &BytesN::from_array(&env, &[5u8; 32]),
+        &6u32,
+    );
+    assert_eq!(deposit, Err(Ok(IntegrationError::ComplianceCheckFailed.into())));
+}
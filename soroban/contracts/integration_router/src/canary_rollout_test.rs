@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::Address as TestAddress,
+    Address, BytesN, Env
+};
+
+/// Minimal KYC registry approving every deposit
+#[contract]
+pub struct MockCanaryKycRegistry;
+
+#[contractimpl]
+impl MockCanaryKycRegistry {
+    pub fn verify_ic(_env: Env, _user: String, _operation: String, _amount: String) -> bool {
+        true
+    }
+
+    pub fn is_blacklisted(_env: Env, _address: Address) -> bool {
+        false
+    }
+}
+
+/// Under-collateralized reserve manager, so each deposit makes exactly one reserve call
+#[contract]
+pub struct MockCanaryReserve;
+
+#[contractimpl]
+impl MockCanaryReserve {
+    pub fn get_ratio(_env: Env) -> u64 {
+        5000
+    }
+}
+
+struct Setup<'a> {
+    client: IntegrationRouterClient<'a>,
+    admin: Address,
+    reserve_v1: Address,
+    reserve_v2: Address,
+    reserve_name: String,
+    upgrade_id: BytesN<32>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+    let reserve_v1 = env.register(MockCanaryReserve, ());
+    let reserve_v2 = env.register(MockCanaryReserve, ());
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &env.register(MockCanaryKycRegistry, ()),
+        &Address::generate(env),
+        &Address::generate(env),
+        &reserve_v1,
+    );
+
+    let reserve_name = String::from_str(env, "reserve_manager");
+    let hash = BytesN::from_array(env, &[3u8; 32]);
+    client.register_deployed_wasm(&admin, &reserve_v2, &hash);
+    let upgrade_id = client.plan_contract_upgrade(&admin, &reserve_name, &reserve_v2, &hash);
+
+    Setup { client, admin, reserve_v1, reserve_v2, reserve_name, upgrade_id }
+}
+
+/// Generate a user whose operations are routed to `target`
+fn user_routed_to(env: &Env, s: &Setup, target: &Address) -> Address {
+    loop {
+        let user = Address::generate(env);
+        if s.client.get_canary_route(&s.reserve_name, &user) == Some(target.clone()) {
+            return user;
+        }
+    }
+}
+
+fn deposit(env: &Env, s: &Setup, user: &Address, seed: u8) {
+    s.client.execute_btc_deposit_tracked(&s.admin, user, &100_000u64, &BytesN::from_array(env, &[seed; 32]), &6u32);
+}
+
+#[test]
+fn test_canary_routes_user_buckets_and_rolls_back_on_errors() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_start_canary_rollout(&s.admin, &s.upgrade_id, &0u32, &5000u32, &2u32),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    let rollout = s.client.start_canary_rollout(&s.admin, &s.upgrade_id, &30u32, &5000u32, &2u32);
+    assert_eq!(rollout.status, RolloutStatus::Active);
+    assert_eq!(s.client.get_upgrade_plan(&s.upgrade_id).unwrap().status, UpgradeStatus::InProgress);
+
+    // The registry keeps the old address while the rollout is staged
+    assert_eq!(s.client.get_contract_address(&s.reserve_name), Some(s.reserve_v1.clone()));
+    let second_plan = s.client.plan_contract_upgrade(&s.admin, &s.reserve_name, &s.reserve_v2, &BytesN::from_array(&env, &[3u8; 32]));
+    assert_eq!(
+        s.client.try_start_canary_rollout(&s.admin, &second_plan, &30u32, &5000u32, &2u32),
+        Err(Ok(IntegrationError::DuplicateOperation))
+    );
+
+    let old_user = user_routed_to(&env, &s, &s.reserve_v1);
+    let new_user = user_routed_to(&env, &s, &s.reserve_v2);
+
+    deposit(&env, &s, &old_user, 1);
+    deposit(&env, &s, &new_user, 2);
+    let stage = s.client.get_canary_stage_metrics(&s.reserve_name).get(0).unwrap();
+    assert_eq!((stage.old_calls, stage.old_errors, stage.new_calls, stage.new_errors), (1, 0, 1, 0));
+    assert_eq!(s.client.get_contract_call_stats(&s.reserve_v2).total_calls, 2); // health probe + deposit
+
+    assert_eq!(
+        s.client.try_promote_canary_rollout(&s.admin, &s.reserve_name, &20u32),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    let promoted = s.client.promote_canary_rollout(&s.admin, &s.reserve_name, &60u32);
+    assert_eq!((promoted.stage, promoted.percentage), (1, 60));
+
+    // Failed operations on the new contract are reported by monitors; two
+    // errors over three calls in the new stage exceed the 50% threshold
+    deposit(&env, &s, &new_user, 3);
+    s.client.report_canary_outcome(&s.admin, &s.reserve_name, &new_user, &false);
+    assert_eq!(s.client.get_canary_rollout(&s.reserve_name).unwrap().status, RolloutStatus::Active);
+    s.client.report_canary_outcome(&s.admin, &s.reserve_name, &new_user, &false);
+
+    let rolled_back = s.client.get_canary_rollout(&s.reserve_name).unwrap();
+    assert_eq!(rolled_back.status, RolloutStatus::RolledBack);
+    assert_eq!(s.client.get_upgrade_plan(&s.upgrade_id).unwrap().status, UpgradeStatus::RolledBack);
+
+    let stages = s.client.get_canary_stage_metrics(&s.reserve_name);
+    assert_eq!(stages.len(), 2);
+    let stage = stages.get(1).unwrap();
+    assert_eq!((stage.percentage, stage.new_calls, stage.new_errors), (60, 3, 2));
+
+    // All traffic is back on the old contract
+    assert_eq!(s.client.get_canary_route(&s.reserve_name, &new_user), Some(s.reserve_v1.clone()));
+    deposit(&env, &s, &new_user, 4);
+    assert_eq!(s.client.get_contract_call_stats(&s.reserve_v2).total_calls, 3);
+    assert_eq!(s.client.get_contract_address(&s.reserve_name), Some(s.reserve_v1));
+}
+
+#[test]
+fn test_canary_promotion_to_full_traffic_completes_upgrade() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.start_canary_rollout(&s.admin, &s.upgrade_id, &10u32, &2000u32, &3u32);
+    let old_user = user_routed_to(&env, &s, &s.reserve_v1);
+    let new_user = user_routed_to(&env, &s, &s.reserve_v2);
+
+    // Outcomes reported by monitors count toward the stage they were routed to
+    s.client.report_canary_outcome(&s.admin, &s.reserve_name, &new_user, &false);
+    s.client.report_canary_outcome(&s.admin, &s.reserve_name, &new_user, &true);
+    s.client.report_canary_outcome(&s.admin, &s.reserve_name, &old_user, &false);
+    let stage = s.client.get_canary_stage_metrics(&s.reserve_name).get(0).unwrap();
+    assert_eq!((stage.old_calls, stage.old_errors, stage.new_calls, stage.new_errors), (1, 1, 2, 1));
+    assert_eq!(s.client.get_canary_rollout(&s.reserve_name).unwrap().status, RolloutStatus::Active);
+
+    let completed = s.client.promote_canary_rollout(&s.admin, &s.reserve_name, &100u32);
+    assert_eq!(completed.status, RolloutStatus::Completed);
+    assert_eq!(s.client.get_contract_address(&s.reserve_name), Some(s.reserve_v2.clone()));
+    assert_eq!(s.client.get_upgrade_plan(&s.upgrade_id).unwrap().status, UpgradeStatus::Completed);
+    assert_eq!(s.client.get_canary_route(&s.reserve_name, &old_user), Some(s.reserve_v2));
+
+    assert_eq!(
+        s.client.try_report_canary_outcome(&s.admin, &s.reserve_name, &new_user, &false),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert_eq!(
+        s.client.try_rollback_canary_rollout(&s.admin, &s.reserve_name),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
@@ -0,0 +1,105 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{MockContract, TestHarness};
+use soroban_sdk::Env;
+
+fn setup(h: &TestHarness) -> (Address, Address, Address, Address) {
+    let officer = Address::generate(&h.env);
+    h.router.set_user_role(&h.admin, &officer, &UserRole::ComplianceOfficer);
+    let approver = Address::generate(&h.env);
+    h.router.set_user_role(&h.admin, &approver, &UserRole::SuperAdmin);
+
+    let escrow = Address::generate(&h.env);
+    h.istsi.set_seizure_escrow(&escrow);
+    let target = h.user(1);
+    h.istsi.mint(&target, &500_000);
+    (officer, approver, escrow, target)
+}
+
+#[test]
+fn test_clawback_needs_officer_proposal_and_separate_super_admin() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let (officer, approver, escrow, target) = setup(&h);
+    let evidence = BytesN::from_array(&env, &[5u8; 32]);
+    let reason = String::from_str(&env, "OFAC designation");
+
+    // Only a compliance officer proposes, and only with evidence
+    assert!(h.router.try_propose_clawback(&h.operator, &target, &200_000, &evidence, &reason).is_err());
+    assert_eq!(
+        h.router.try_propose_clawback(&officer, &target, &200_000, &BytesN::from_array(&env, &[0u8; 32]), &reason),
+        Err(Ok(IntegrationError::ComplianceCheckFailed))
+    );
+    let clawback_id = h.router.propose_clawback(&officer, &target, &200_000, &evidence, &reason);
+    assert_eq!(h.router.get_pending_clawbacks().len(), 1);
+
+    // Officers cannot approve, and nothing moves until a SuperAdmin does
+    assert!(h.router.try_approve_clawback(&officer, &clawback_id).is_err());
+    assert_eq!(h.istsi.balance(&target), 500_000);
+
+    assert_eq!(h.router.approve_clawback(&approver, &clawback_id), 1);
+    assert_eq!((h.istsi.balance(&target), h.istsi.balance(&escrow)), (300_000, 200_000));
+
+    let proposal = h.router.get_clawback_proposal(&clawback_id).unwrap();
+    assert_eq!(proposal.status, ClawbackStatus::Executed);
+    assert_eq!((proposal.decided_by, proposal.token_clawback_id), (Some(approver.clone()), 1));
+    assert_eq!(h.router.get_pending_clawbacks().len(), 0);
+    assert_eq!(
+        h.router.try_approve_clawback(&approver, &clawback_id),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
+
+#[test]
+fn test_rejected_and_failed_clawbacks_leave_balances() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let (officer, approver, escrow, target) = setup(&h);
+    let evidence = BytesN::from_array(&env, &[5u8; 32]);
+    let reason = String::from_str(&env, "Court order");
+
+    let rejected = h.router.propose_clawback(&officer, &target, &100_000, &evidence, &reason);
+    h.router.reject_clawback(&approver, &rejected);
+    assert_eq!(
+        h.router.try_approve_clawback(&approver, &rejected),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    // A failed seizure stays pending for another attempt
+    let failed = h.router.propose_clawback(&officer, &target, &100_000, &evidence, &reason);
+    h.fail(MockContract::IstsiToken, "clawback");
+    assert_eq!(
+        h.router.try_approve_clawback(&approver, &failed),
+        Err(Ok(IntegrationError::ContractCallFailed))
+    );
+    assert_eq!(h.router.get_pending_clawbacks(), soroban_sdk::vec![&env, failed.clone()]);
+    assert_eq!((h.istsi.balance(&target), h.istsi.balance(&escrow)), (500_000, 0));
+
+    let history = h.router.get_clawback_history(&0, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get_unchecked(0).status, ClawbackStatus::Rejected);
+    assert_eq!(history.get_unchecked(1).status, ClawbackStatus::Proposed);
+}
+
+#[test]
+fn test_two_super_admins_cannot_seize() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let (_, approver, escrow, target) = setup(&h);
+    let evidence = BytesN::from_array(&env, &[5u8; 32]);
+    let reason = String::from_str(&env, "Court order");
+
+    // SuperAdmin passes every role check, but is not a compliance officer
+    assert_eq!(
+        h.router.try_propose_clawback(&approver, &target, &100_000, &evidence, &reason),
+        Err(Ok(IntegrationError::InsufficientPermissions))
+    );
+    assert_eq!(
+        h.router.try_propose_clawback(&h.admin, &target, &100_000, &evidence, &reason),
+        Err(Ok(IntegrationError::InsufficientPermissions))
+    );
+    assert_eq!(h.router.get_pending_clawbacks().len(), 0);
+    assert_eq!(h.router.get_clawback_history(&0, &10).len(), 0);
+    assert_eq!((h.istsi.balance(&target), h.istsi.balance(&escrow)), (500_000, 0));
+}
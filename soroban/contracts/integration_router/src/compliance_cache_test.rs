@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{MockContract, TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::Ledger, vec, Env};
+
+fn reasons(outcomes: &Vec<ComplianceOutcome>) -> Vec<u32> {
+    let mut reasons = Vec::new(&outcomes.env());
+    for outcome in outcomes.iter() {
+        reasons.push_back(outcome.reason_code);
+    }
+    reasons
+}
+
+#[test]
+fn test_batch_serves_cache_hits_and_revalidates_changed_users() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let approved = h.user(2);
+    let unregistered = h.user(0);
+    h.kyc.set_tier_limit(&2, &1_000_000);
+
+    let requests = vec![&env, (approved.clone(), 3u32, 500_000u64), (unregistered.clone(), 3, 100), (approved.clone(), 3, 2_000_000)];
+    assert!(h.router.try_verify_compliance_batch(&h.user(2), &requests).is_err());
+    assert_eq!(reasons(&h.router.verify_compliance_batch(&h.operator, &requests)), vec![&env, 0u32, 2, 7]);
+
+    // Smaller approved and larger refused amounts in the same band never reach the registry
+    h.fail(MockContract::KycRegistry, "verify_compliance_batch");
+    let cached = vec![&env, (approved.clone(), 3u32, 400_000u64), (unregistered.clone(), 3, 120)];
+    let outcomes = h.router.verify_compliance_batch(&h.operator, &cached);
+    assert_eq!(reasons(&outcomes), vec![&env, 0u32, 2]);
+    assert_eq!(outcomes.get_unchecked(0).amount, 400_000);
+    assert_eq!(
+        h.router.try_verify_compliance_batch(&h.operator, &vec![&env, (approved.clone(), 3u32, 600_000u64)]),
+        Err(Ok(IntegrationError::ContractCallFailed))
+    );
+
+    // A KYC change for one user only drops that user's answers
+    h.kyc.set_tier(&unregistered, &2);
+    assert!(h.router.try_verify_compliance_batch(&h.operator, &cached).is_err());
+    h.router.verify_compliance_batch(&h.operator, &vec![&env, (approved.clone(), 3u32, 400_000u64)]);
+    h.restore(MockContract::KycRegistry, "verify_compliance_batch");
+    assert_eq!(reasons(&h.router.verify_compliance_batch(&h.operator, &cached)), vec![&env, 0u32, 0]);
+
+    // Entries expire after the cache TTL
+    h.fail(MockContract::KycRegistry, "verify_compliance_batch");
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + COMPLIANCE_CACHE_TTL);
+    assert!(h.router.try_verify_compliance_batch(&h.operator, &cached).is_err());
+}
+
+#[test]
+fn test_single_checks_are_cached_until_the_registry_notifies() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    let is_approved = |amount: u64| {
+        env.as_contract(&h.router.address, || IntegrationRouter::is_kyc_approved_for(&env, &user, 3, amount))
+    };
+    assert!(is_approved(1_000));
+
+    h.fail(MockContract::KycRegistry, "is_approved_simple");
+    assert!(is_approved(900));
+
+    // Registry-wide changes invalidate every user's answers
+    h.kyc.set_tier_limit(&2, &500);
+    assert!(!is_approved(900));
+    h.restore(MockContract::KycRegistry, "is_approved_simple");
+    assert!(!is_approved(900));
+    assert!(is_approved(500));
+
+    // Only the registry may invalidate
+    h.router.notify_kyc_change(&vec![&env, user.clone()]);
+    assert_eq!(env.auths().get(0).map(|(address, _)| address.clone()), Some(h.kyc.address.clone()));
+}
@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as TestAddress, Address, Env};
+
+#[test]
+fn test_backup_diff_and_restore() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+
+    let istsi = String::from_str(&env, "istsi_token");
+    let max_mint = String::from_str(&env, "max_mint");
+    let slow_oracle = String::from_str(&env, "slow_oracle");
+    client.set_contract_limit(&admin, &istsi, &max_mint, &1_000_000u64);
+    client.configure_alert(&admin, &slow_oracle, &300u64, &Vec::new(&env), &true);
+
+    let backup_id = client.create_configuration_backup(&admin);
+    let backup = client.get_configuration_backup(&backup_id).unwrap();
+    assert_eq!(backup.version, 1);
+    assert_eq!(backup.contract_limits.get((istsi.clone(), max_mint.clone())), Some(1_000_000));
+    assert_eq!(backup.alert_configs.len(), 1);
+    assert!(backup.rate_limit_config.is_empty());
+    assert!(client.diff_configuration_backup(&backup_id).is_empty());
+
+    // Drift the configuration
+    let original_kyc = client.get_config().kyc_registry;
+    client.update_contract_address(&admin, &String::from_str(&env, "kyc_registry"), &Address::generate(&env));
+    client.set_contract_limit(&admin, &istsi, &max_mint, &5u64);
+    client.set_contract_limit(&admin, &istsi, &String::from_str(&env, "max_burn"), &7u64);
+    client.set_rate_limit_config(&admin, &RateLimitConfig {
+        enabled: true,
+        window_seconds: 60,
+        max_deposits_per_window: 1,
+        max_withdrawals_per_window: 1,
+    });
+    client.configure_alert(&admin, &String::from_str(&env, "queue_backlog"), &10u64, &Vec::new(&env), &true);
+    client.emergency_pause(&admin, &String::from_str(&env, "Incident"));
+
+    let changed = client.diff_configuration_backup(&backup_id);
+    assert_eq!(changed, vec![
+        &env,
+        String::from_str(&env, "router_config"),
+        String::from_str(&env, "contracts"),
+        String::from_str(&env, "contract_limits"),
+        String::from_str(&env, "rate_limit_config"),
+        String::from_str(&env, "alert_configs"),
+    ]);
+
+    assert!(client.restore_configuration_backup(&admin, &backup_id));
+    assert!(client.diff_configuration_backup(&backup_id).is_empty());
+    assert_eq!(client.get_config().kyc_registry, original_kyc);
+    assert_eq!(client.get_contract_limit(&istsi, &max_mint), Some(1_000_000));
+    assert_eq!(client.get_contract_limit(&istsi, &String::from_str(&env, "max_burn")), None);
+    assert_eq!(client.get_rate_limit_config().window_seconds, 300);
+    // The pause survives the restore
+    assert!(client.is_paused());
+
+    // The drifted configuration was kept as a safety backup
+    let backups = client.list_configuration_backups();
+    assert_eq!(backups.len(), 2);
+    let safety = client.get_configuration_backup(&backups.get(1).unwrap()).unwrap();
+    assert_eq!(safety.label, String::from_str(&env, "pre_restore"));
+    assert_eq!(safety.contract_limits.get((istsi, max_mint)), Some(5));
+    assert_eq!(safety.alert_configs.len(), 2);
+
+    assert!(!client.restore_configuration_backup(&admin, &BytesN::from_array(&env, &[9u8; 32])));
+}
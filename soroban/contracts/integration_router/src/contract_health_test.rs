@@ -0,0 +1,131 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as TestAddress, Ledger},
+    Address, Env
+};
+
+/// Reserve manager answering the ratio probe
+#[contract]
+pub struct MockHealthReserve;
+
+#[contractimpl]
+impl MockHealthReserve {
+    pub fn get_ratio(_env: Env) -> u64 {
+        10000
+    }
+}
+
+fn call(env: &Env, target: &Address, function_name: &str) -> ContractCall {
+    ContractCall {
+        target_contract: target.clone(),
+        function_name: String::from_str(env, function_name),
+        parameters: Vec::new(env),
+        expected_return_type: String::from_str(env, "u64"),
+        timeout: 30,
+        retry_count: 0,
+    }
+}
+
+#[test]
+fn test_call_outcomes_feed_contract_health() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+    let reserve = env.register(MockHealthReserve, ());
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &reserve,
+    );
+
+    for _ in 0..3 {
+        assert!(!client.execute_contract_call(&admin, &call(&env, &reserve, "fail_test")).success);
+    }
+    let stats = client.get_contract_call_stats(&reserve);
+    assert_eq!(stats.failed_calls, 3);
+    assert_eq!(stats.consecutive_failures, 3);
+    assert_eq!(stats.last_error, String::from_str(&env, "Contract call failed after max retries"));
+
+    env.as_contract(&contract_id, || {
+        let health = IntegrationRouter::build_contract_health_info(&env, &reserve, true);
+        assert_eq!(health.status, HealthStatus::Critical);
+        assert_eq!(health.address, reserve);
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 60;
+    });
+    assert!(client.execute_contract_call(&admin, &call(&env, &reserve, "get_ratio")).success);
+
+    let stats = client.get_contract_call_stats(&reserve);
+    assert_eq!(stats.total_calls, 4);
+    assert_eq!(stats.consecutive_failures, 0);
+    assert_eq!(stats.last_success_at, 1_000_060);
+
+    // A recovered contract with a 75% error rate is degraded rather than critical
+    env.as_contract(&contract_id, || {
+        let health = IntegrationRouter::build_contract_health_info(&env, &reserve, true);
+        assert_eq!(health.status, HealthStatus::Warning);
+        assert_eq!(health.error_rate, 750);
+        assert_eq!(health.uptime_percentage, 2500);
+        assert_eq!(IntegrationRouter::build_contract_health_info(&env, &reserve, false).status, HealthStatus::Offline);
+    });
+
+    // Error history keeps the ten most recent failures
+    for _ in 0..9 {
+        client.execute_contract_call(&admin, &call(&env, &reserve, "unknown_fn"));
+    }
+    let history = client.get_contract_error_history(&reserve);
+    assert_eq!(history.len(), 10);
+    assert_eq!(history.get(0).unwrap().function_name, String::from_str(&env, "fail_test"));
+    assert_eq!(history.get(0).unwrap().occurred_at, 1_000_000);
+    assert_eq!(history.get(9).unwrap().function_name, String::from_str(&env, "unknown_fn"));
+
+    // Contracts never called report empty counters
+    let untouched = client.get_contract_call_stats(&admin);
+    assert_eq!(untouched.total_calls, 0);
+}
+
+#[test]
+fn test_failures_from_reverted_workflows_are_reported() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+    let kyc = Address::generate(&env);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &kyc, &Address::generate(&env), &Address::generate(&env), &Address::generate(&env));
+
+    // A deposit failing at the KYC call reverts along with its call stats
+    let user = Address::generate(&env);
+    assert!(client.try_execute_bitcoin_deposit(&admin, &user, &100_000u64, &BytesN::from_array(&env, &[7; 32]), &6u32).is_err());
+    assert_eq!(client.get_contract_call_stats(&kyc).total_calls, 0);
+
+    // The relayer that saw the revert reports the failing call
+    let reported = String::from_str(&env, "reverted: ComplianceCheckFailed");
+    client.report_contract_failure(&admin, &kyc, &String::from_str(&env, "verify_ic"), &reported);
+    let stats = client.get_contract_call_stats(&kyc);
+    assert_eq!((stats.total_calls, stats.failed_calls, stats.consecutive_failures), (1, 1, 1));
+    assert_eq!(stats.last_error, reported);
+    assert_eq!(client.get_contract_error_history(&kyc).get(0).unwrap().function_name, String::from_str(&env, "verify_ic"));
+
+    assert_eq!(
+        client.try_report_contract_failure(&admin, &contract_id, &String::from_str(&env, "verify_ic"), &reported),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert!(client.try_report_contract_failure(&user, &kyc, &String::from_str(&env, "verify_ic"), &reported).is_err());
+}
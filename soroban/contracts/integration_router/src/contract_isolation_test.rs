@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::Ledger, BytesN, Env};
+
+fn call(h: &TestHarness, function_name: &str) -> ContractCall {
+    ContractCall {
+        target_contract: h.reserve.address.clone(),
+        function_name: String::from_str(&h.env, function_name),
+        parameters: Vec::new(&h.env),
+        expected_return_type: String::from_str(&h.env, "u64"),
+        timeout: 30,
+        retry_count: 0,
+    }
+}
+
+#[test]
+fn test_isolation_scope_decides_which_calls_reach_the_contract() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let reason = String::from_str(&env, "unexpected supply updates");
+    let isolated: soroban_sdk::Error = IntegrationError::ContractIsolated.into();
+
+    assert!(h.router.try_isolate_contract(&h.operator, &h.reserve.address, &IsolationScope::Full, &reason).is_err());
+    assert_eq!(
+        h.router.try_isolate_contract(&h.admin, &h.router.address, &IsolationScope::Full, &reason),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    h.router.isolate_contract(&h.admin, &h.reserve.address, &IsolationScope::Full, &reason);
+    assert_eq!(h.router.try_execute_contract_call(&h.operator, &call(&h, "get_ratio")), Err(Ok(isolated)));
+    assert_eq!(h.router.try_execute_contract_call(&h.operator, &call(&h, "upd_supp")), Err(Ok(isolated)));
+
+    // Workflows touching the contract are refused outright
+    let deposit = h.router.try_execute_bitcoin_deposit(
+        &h.operator, &h.user(2), &100_000u64, &BytesN::from_array(&env, &[7u8; 32]), &6u32
+    );
+    assert_eq!(deposit, Err(Ok(isolated)));
+
+    // Read-only isolation lets queries through
+    h.router.isolate_contract(&h.admin, &h.reserve.address, &IsolationScope::ReadOnly, &reason);
+    assert!(h.router.execute_contract_call(&h.operator, &call(&h, "get_ratio")).success);
+    assert_eq!(h.router.try_execute_contract_call(&h.operator, &call(&h, "upd_supp")), Err(Ok(isolated)));
+
+    let isolations = h.router.get_isolated_contracts();
+    assert_eq!(isolations.len(), 1);
+    let isolation = isolations.get_unchecked(0);
+    assert_eq!((isolation.scope, isolation.status), (IsolationScope::ReadOnly, IsolationStatus::Isolated));
+    assert_eq!(isolation.isolated_by, h.admin);
+    assert!(h.router.get_contract_isolation(&h.kyc.address).is_none());
+}
+
+#[test]
+fn test_reintegration_requires_a_clean_probation() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let reason = String::from_str(&env, "oracle divergence");
+
+    assert_eq!(
+        h.router.try_begin_reintegration(&h.admin, &h.reserve.address),
+        Err(Ok(IntegrationError::ContractNotFound))
+    );
+    h.router.isolate_contract(&h.admin, &h.reserve.address, &IsolationScope::Full, &reason);
+    assert_eq!(
+        h.router.try_complete_reintegration(&h.admin, &h.reserve.address),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    h.router.begin_reintegration(&h.admin, &h.reserve.address);
+    let isolation = h.router.get_contract_isolation(&h.reserve.address).unwrap();
+    assert_eq!((isolation.scope, isolation.status), (IsolationScope::ReadOnly, IsolationStatus::Probation));
+    assert_eq!(isolation.probation_started_at, HARNESS_START_TIME);
+    assert_eq!(
+        h.router.try_complete_reintegration(&h.admin, &h.reserve.address),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    // A failed call during probation fails health verification
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 3_600);
+    env.as_contract(&h.router.address, || {
+        let failed = CallResult {
+            success: false,
+            return_data: String::from_str(&env, ""),
+            error_message: String::from_str(&env, "Contract call failed after max retries"),
+            gas_used: 0,
+            mem_bytes: 0,
+            ledger_entries: 0,
+            execution_time: 0,
+        };
+        IntegrationRouter::record_contract_call_outcome(&env, &call(&h, "get_ratio"), &failed);
+    });
+    assert_eq!(
+        h.router.try_complete_reintegration(&h.admin, &h.reserve.address),
+        Err(Ok(IntegrationError::ContractCallFailed))
+    );
+
+    // Probation restarts once the contract recovers
+    h.router.isolate_contract(&h.admin, &h.reserve.address, &IsolationScope::ReadOnly, &reason);
+    h.router.begin_reintegration(&h.admin, &h.reserve.address);
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 7_200);
+    let health = h.router.complete_reintegration(&h.admin, &h.reserve.address);
+    assert_eq!(health.address, h.reserve.address);
+    assert!(h.router.get_contract_isolation(&h.reserve.address).is_none());
+    assert_eq!(h.router.get_isolated_contracts().len(), 0);
+    assert!(h.router.execute_contract_call(&h.operator, &call(&h, "get_ratio")).success);
+}
@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as TestAddress, Ledger},
+    Address, BytesN, Env
+};
+
+fn setup(env: &Env) -> (IntegrationRouterClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+
+    (client, admin)
+}
+
+#[test]
+fn test_conversion_config_changes_only_after_timelock() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    // Defaults to the historical 1:100,000,000 ratio
+    assert_eq!(client.convert_sats_to_istsi(&3u64), 300_000_000);
+    assert_eq!(client.convert_istsi_to_sats(&250_000_000u64), 2);
+
+    let config = ConversionConfig { istsi_per_satoshi: 1_000, rounding: RoundingPolicy::Nearest, min_dust_sats: 546 };
+    assert_eq!(
+        client.try_propose_conversion_config(&admin, &ConversionConfig { istsi_per_satoshi: 0, ..config.clone() }),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert_eq!(
+        client.try_propose_conversion_config(&Address::generate(&env), &config),
+        Err(Ok(IntegrationError::InsufficientPermissions))
+    );
+
+    let pending = client.propose_conversion_config(&admin, &config);
+    assert_eq!(pending.effective_at, 1_000_000 + 172_800);
+    assert_eq!(
+        client.try_propose_conversion_config(&admin, &config),
+        Err(Ok(IntegrationError::DuplicateOperation))
+    );
+
+    // Proposals have no effect until applied after the timelock
+    assert_eq!(
+        client.try_apply_conversion_config(&admin),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert_eq!(client.convert_sats_to_istsi(&3u64), 300_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = pending.effective_at;
+    });
+    assert_eq!(client.apply_conversion_config(&admin), config);
+    assert_eq!(client.get_conversion_config(), config);
+    assert_eq!(client.get_pending_conversion_config(), None);
+
+    assert_eq!(client.convert_sats_to_istsi(&3u64), 3_000);
+    // Payouts round down whatever the policy
+    assert_eq!(client.convert_istsi_to_sats(&1_500u64), 1);
+    assert_eq!(client.convert_istsi_to_sats(&1_999u64), 1);
+
+    // Every workflow converts through the active config
+    let report = client.simulate_bitcoin_deposit(&admin, &Address::generate(&env), &1_000u64, &BytesN::from_array(&env, &[1u8; 32]), &6u32);
+    assert_eq!(report.projected_amount, 1_000_000);
+}
+
+#[test]
+fn test_conversion_proposal_can_be_cancelled_and_dust_is_rejected() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    let config = ConversionConfig { istsi_per_satoshi: 100_000_000, rounding: RoundingPolicy::Up, min_dust_sats: 546 };
+    client.propose_conversion_config(&admin, &config);
+    client.cancel_conversion_config(&admin);
+    assert_eq!(client.get_pending_conversion_config(), None);
+    assert_eq!(
+        client.try_cancel_conversion_config(&admin),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    let pending = client.propose_conversion_config(&admin, &config);
+    env.ledger().with_mut(|li| {
+        li.timestamp = pending.effective_at;
+    });
+    client.apply_conversion_config(&admin);
+    assert_eq!(client.convert_istsi_to_sats(&250_000_000u64), 2);
+
+    let dust_ok = |istsi_amount: u64| {
+        let report = client.simulate_token_withdrawal(&admin, &Address::generate(&env), &istsi_amount, &String::from_str(&env, "bc1qdust"), &None);
+        report.steps.iter().any(|step| step.name == String::from_str(&env, "dust") && step.passed)
+    };
+    // Rounding up would let 545.000...1 satoshis clear the limit; payouts round down
+    assert!(!dust_ok(545 * 100_000_000 + 1));
+    assert!(!dust_ok(546 * 100_000_000 - 1));
+    assert!(dust_ok(546 * 100_000_000));
+}
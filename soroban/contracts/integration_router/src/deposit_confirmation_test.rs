@@ -0,0 +1,53 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::TestHarness;
+use soroban_sdk::{testutils::Events, Env, TryFromVal};
+
+const BTC_AMOUNT: u64 = 100_000;
+
+/// Confirmation progress `(count, required)` of the last `dep_conf` event
+fn progression(env: &Env) -> Option<(u32, u32)> {
+    env.events().all().iter().find_map(|(_, topics, data)| {
+        let topic = Symbol::try_from_val(env, &topics.get(0)?).ok()?;
+        if topic != Symbol::new(env, "dep_conf") {
+            return None;
+        }
+        let (_, confirmations, required) = <(BytesN<32>, u32, u32)>::try_from_val(env, &data).ok()?;
+        Some((confirmations, required))
+    })
+}
+
+#[test]
+fn test_under_confirmed_deposit_advances_without_resubmission() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    let btc_tx_hash = BytesN::from_array(&env, &[5u8; 32]);
+    let (required, _) = h.router.get_deposit_conf_requirements(&user, &BTC_AMOUNT);
+    assert!(required >= 3);
+
+    // Submitting short of the requirement holds the deposit instead of failing it
+    let operation_id = h.router.execute_btc_deposit_tracked(&h.operator, &user, &BTC_AMOUNT, &btc_tx_hash, &1);
+    assert_eq!(operation_id, BytesN::from_array(&env, &[0u8; 32]));
+    let status = h.router.get_deposit_status_by_tx_hash(&btc_tx_hash).unwrap();
+    assert_eq!((status.status, status.confirmations), (DepositProcessingStatus::Pending, 1));
+
+    h.router.update_deposit_confirmations(&h.operator, &btc_tx_hash, &2);
+    assert_eq!(progression(&env), Some((2, required)));
+    assert_eq!(
+        h.router.try_update_deposit_confirmations(&h.operator, &btc_tx_hash, &2),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    // The final update runs the deposit workflow
+    let status = h.router.update_deposit_confirmations(&h.operator, &btc_tx_hash, &(required + 2));
+    assert_eq!(progression(&env), Some((required, required)));
+    assert_ne!(status.status, DepositProcessingStatus::Pending);
+    assert_eq!(status.confirmations, required + 2);
+    assert!(h.router.get_deposit_pre_registration(&btc_tx_hash).is_none());
+
+    // A processed deposit is never put back to waiting
+    assert!(h.router.try_execute_btc_deposit_tracked(&h.operator, &user, &BTC_AMOUNT, &btc_tx_hash, &1).is_err());
+    assert_eq!(h.router.get_deposit_status_by_tx_hash(&btc_tx_hash).unwrap().status, status.status);
+}
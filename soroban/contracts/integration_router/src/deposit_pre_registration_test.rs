@@ -0,0 +1,67 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::Ledger, Env};
+
+const BTC_AMOUNT: u64 = 100_000;
+
+#[test]
+fn test_pre_registered_deposit_joins_the_workflow_once_confirmed() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    let btc_tx_hash = BytesN::from_array(&env, &[3u8; 32]);
+
+    assert!(h.router.try_pre_register_deposit(&user, &user, &BTC_AMOUNT, &btc_tx_hash).is_err());
+    let status = h.router.pre_register_deposit(&h.operator, &user, &BTC_AMOUNT, &btc_tx_hash);
+    assert_eq!((status.status, status.confirmations, status.user), (DepositProcessingStatus::Pending, 0, user.clone()));
+    assert_eq!(
+        h.router.try_pre_register_deposit(&h.operator, &user, &BTC_AMOUNT, &btc_tx_hash),
+        Err(Ok(IntegrationError::DuplicateOperation))
+    );
+    let registration = h.router.get_deposit_pre_registration(&btc_tx_hash).unwrap();
+    assert_eq!(registration.expires_at, HARNESS_START_TIME + 86_400);
+
+    // Confirmations below the requirement only update what the user sees
+    let status = h.router.update_deposit_confirmations(&h.operator, &btc_tx_hash, &1);
+    assert_eq!((status.status, status.confirmations), (DepositProcessingStatus::Pending, 1));
+    assert_eq!(h.router.get_pre_registered_deposits().len(), 1);
+
+    let status = h.router.update_deposit_confirmations(&h.operator, &btc_tx_hash, &6);
+    assert_ne!(status.status, DepositProcessingStatus::Pending);
+    assert_eq!(status.confirmations, 6);
+    assert!(h.router.get_deposit_pre_registration(&btc_tx_hash).is_none());
+    assert_eq!(h.router.get_pre_registered_deposits().len(), 0);
+    assert_eq!(
+        h.router.try_update_deposit_confirmations(&h.operator, &btc_tx_hash, &7),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
+
+#[test]
+fn test_unconfirmed_pre_registration_expires() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    let stale = BytesN::from_array(&env, &[3u8; 32]);
+    let fresh = BytesN::from_array(&env, &[4u8; 32]);
+
+    h.router.pre_register_deposit(&h.operator, &user, &BTC_AMOUNT, &stale);
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 3_600);
+    h.router.pre_register_deposit(&h.operator, &user, &BTC_AMOUNT, &fresh);
+
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 86_400);
+    assert_eq!(
+        h.router.try_update_deposit_confirmations(&h.operator, &stale, &6),
+        Err(Ok(IntegrationError::OperationTimeout))
+    );
+    assert_eq!(h.router.expire_pre_registered_deposits(&h.operator), 1);
+    assert_eq!(h.router.expire_pre_registered_deposits(&h.operator), 0);
+
+    let expired = h.router.get_deposit_status_by_tx_hash(&stale).unwrap();
+    assert_eq!(expired.status, DepositProcessingStatus::Expired);
+    let remaining = h.router.get_pre_registered_deposits();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining.get_unchecked(0).btc_tx_hash, fresh);
+}
@@ -0,0 +1,90 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::Env;
+
+const MINTED: u64 = 1_000 * 100_000_000;
+
+/// Record a completed deposit of 1,000 sats and mint its iSTSi to `user`
+fn completed_deposit(h: &TestHarness, user: &Address, id: u8) -> BytesN<32> {
+    let env = &h.env;
+    let btc_tx_hash = BytesN::from_array(env, &[id; 32]);
+    env.as_contract(&h.router.address, || {
+        IntegrationRouter::initialize_deposit_status(env, &btc_tx_hash, user, 1_000, 6, &BytesN::from_array(env, &[0u8; 32]));
+        IntegrationRouter::update_deposit_status(env, &btc_tx_hash, DepositProcessingStatus::Completed, None);
+    });
+    h.istsi.mint(user, &(MINTED as i128));
+    btc_tx_hash
+}
+
+#[test]
+fn test_reorg_claws_back_unmoved_tokens() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let escrow = Address::generate(&env);
+    h.istsi.set_seizure_escrow(&escrow);
+    let user = h.user(2);
+    let btc_tx_hash = completed_deposit(&h, &user, 1);
+    let evidence = BytesN::from_array(&env, &[9u8; 32]);
+
+    assert!(h.router.try_report_deposit_reorg(&h.user(1), &btc_tx_hash, &evidence).is_err());
+    assert_eq!(
+        h.router.try_report_deposit_reorg(&h.operator, &btc_tx_hash, &BytesN::from_array(&env, &[0u8; 32])),
+        Err(Ok(IntegrationError::ComplianceCheckFailed))
+    );
+    assert_eq!(
+        h.router.try_report_deposit_reorg(&h.operator, &BytesN::from_array(&env, &[2u8; 32]), &evidence),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    let case = h.router.report_deposit_reorg(&h.operator, &btc_tx_hash, &evidence);
+    assert_eq!((case.status, case.token_clawback_id, case.alert_id), (ReorgCaseStatus::ClawedBack, 1, None));
+    assert_eq!((case.istsi_amount, case.reported_at), (MINTED, HARNESS_START_TIME));
+    assert_eq!((h.istsi.balance(&user), h.istsi.balance(&escrow)), (0, MINTED as i128));
+    assert_eq!(h.router.get_reorg_frozen_amount(&user), 0);
+    assert_eq!(h.router.get_deposit_status_by_tx_hash(&btc_tx_hash).unwrap().status, DepositProcessingStatus::Reorged);
+    assert_eq!(
+        h.router.try_report_deposit_reorg(&h.operator, &btc_tx_hash, &evidence),
+        Err(Ok(IntegrationError::DuplicateOperation))
+    );
+
+    // Clawed-back cases stay open until compliance signs off
+    assert_eq!(h.router.get_open_reorg_cases().len(), 1);
+    let resolution = String::from_str(&env, "reversal confirmed");
+    assert!(h.router.try_resolve_reorg_case(&h.operator, &btc_tx_hash, &resolution).is_err());
+    h.router.resolve_reorg_case(&h.admin, &btc_tx_hash, &resolution);
+    let case = h.router.get_reorg_case(&btc_tx_hash).unwrap();
+    assert_eq!((case.status, case.resolved_by), (ReorgCaseStatus::Resolved, Some(h.admin.clone())));
+    assert_eq!(h.router.get_open_reorg_cases().len(), 0);
+}
+
+#[test]
+fn test_reorg_of_moved_tokens_freezes_and_escalates() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    let btc_tx_hash = completed_deposit(&h, &user, 1);
+    h.istsi.transfer(&user, &h.user(1), &((MINTED / 2) as i128));
+    h.istsi.mint(&user, &1_000);
+
+    let case = h.router.report_deposit_reorg(&h.operator, &btc_tx_hash, &BytesN::from_array(&env, &[9u8; 32]));
+    assert_eq!(case.status, ReorgCaseStatus::Escalated);
+    let alert = h.router.get_alert(&case.alert_id.unwrap()).unwrap();
+    assert_eq!(alert.severity, AlertSeverity::Critical);
+    assert_eq!(h.router.get_reorg_frozen_amount(&user), MINTED);
+    assert_eq!(h.istsi.balance(&user), (MINTED / 2 + 1_000) as i128);
+
+    // Nothing the user holds can leave while the minted amount is frozen
+    let refused = IntegrationError::ComplianceCheckFailed;
+    let btc_address = String::from_str(&env, "bc1qreorgtest");
+    assert_eq!(h.router.try_user_request_withdrawal(&user, &1_000, &btc_address), Err(Ok(refused)));
+
+    h.router.resolve_reorg_case(&h.admin, &btc_tx_hash, &String::from_str(&env, "shortfall recovered off-chain"));
+    assert_eq!(h.router.get_reorg_frozen_amount(&user), 0);
+    assert_ne!(h.router.try_user_request_withdrawal(&user, &1_000, &btc_address), Err(Ok(refused)));
+    assert_eq!(
+        h.router.try_resolve_reorg_case(&h.admin, &btc_tx_hash, &String::from_str(&env, "again")),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
@@ -0,0 +1,147 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::Address as TestAddress,
+    Address, BytesN, Env
+};
+
+/// Minimal KYC registry approving every operation
+#[contract]
+pub struct MockDustKycRegistry;
+
+#[contractimpl]
+impl MockDustKycRegistry {
+    pub fn verify_ic(_env: Env, _user: String, _operation: String, _amount: String) -> bool {
+        true
+    }
+
+    pub fn is_blacklisted(_env: Env, _address: Address) -> bool {
+        false
+    }
+}
+
+/// Reserve manager reporting full backing
+#[contract]
+pub struct MockDustReserve;
+
+#[contractimpl]
+impl MockDustReserve {
+    pub fn get_ratio(_env: Env) -> u64 {
+        10000
+    }
+}
+
+struct Setup<'a> {
+    client: IntegrationRouterClient<'a>,
+    admin: Address,
+    user: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &env.register(MockDustKycRegistry, ()),
+        &Address::generate(env),
+        &Address::generate(env),
+        &env.register(MockDustReserve, ()),
+    );
+    client.set_amount_minimums(&admin, &AmountMinimums {
+        min_deposit_sats: 10_000,
+        min_withdrawal_sats: 5_000,
+        min_exchange_amount: 1_000,
+    });
+
+    Setup { client, admin, user: Address::generate(env) }
+}
+
+fn tx(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+/// Error surfaced by entry points that panic rather than return a Result
+fn dust_error() -> soroban_sdk::Error {
+    soroban_sdk::Error::from_contract_error(IntegrationError::DustAmount as u32)
+}
+
+#[test]
+fn test_undersized_operations_are_rejected_per_workflow() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_set_amount_minimums(&Address::generate(&env), &s.client.get_amount_minimums()),
+        Err(Ok(IntegrationError::InsufficientPermissions))
+    );
+
+    assert_eq!(
+        s.client.try_execute_bitcoin_deposit(&s.admin, &s.user, &9_999u64, &tx(&env, 1), &6u32),
+        Err(Ok(dust_error()))
+    );
+    assert_eq!(
+        s.client.try_execute_btc_deposit_tracked(&s.admin, &s.user, &9_999u64, &tx(&env, 1), &6u32),
+        Err(Ok(dust_error()))
+    );
+
+    // Withdrawal minimums are measured in satoshis before fees
+    assert_eq!(
+        s.client.try_execute_token_withdrawal(&s.admin, &s.user, &(4_999 * 100_000_000u64), &String::from_str(&env, "bc1qdust")),
+        Err(Ok(dust_error()))
+    );
+
+    let from_token = Address::generate(&env);
+    let to_token = Address::generate(&env);
+    assert_eq!(
+        s.client.try_execute_cross_token_exchange(&s.user, &from_token, &to_token, &999u64, &500u64),
+        Err(Ok(IntegrationError::DustAmount))
+    );
+
+    // Simulations flag the minimum without executing
+    let minimum_ok = |report: SimulationReport| {
+        report.steps.iter().any(|step| step.name == String::from_str(&env, "minimum") && step.passed)
+    };
+    assert!(!minimum_ok(s.client.simulate_bitcoin_deposit(&s.admin, &s.user, &9_999u64, &tx(&env, 2), &6u32)));
+    assert!(minimum_ok(s.client.simulate_bitcoin_deposit(&s.admin, &s.user, &10_000u64, &tx(&env, 2), &6u32)));
+    assert!(minimum_ok(s.client.simulate_token_withdrawal(&s.admin, &s.user, &(5_000 * 100_000_000u64), &String::from_str(&env, "bc1qdust"), &None)));
+    assert!(!minimum_ok(s.client.simulate_exchange(&s.user, &from_token, &to_token, &999u64, &500u64)));
+}
+
+#[test]
+fn test_dust_deposits_accumulate_until_consolidated() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    // Deposits meeting the minimum are not dust
+    assert_eq!(
+        s.client.try_record_dust_deposit(&s.admin, &s.user, &10_000u64, &tx(&env, 1), &6u32),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    s.client.record_dust_deposit(&s.admin, &s.user, &4_000u64, &tx(&env, 1), &6u32);
+    assert_eq!(
+        s.client.try_record_dust_deposit(&s.admin, &s.user, &4_000u64, &tx(&env, 1), &6u32),
+        Err(Ok(IntegrationError::BitcoinTransactionFailed))
+    );
+    assert_eq!(
+        s.client.try_consolidate_dust(&s.admin, &s.user, &tx(&env, 9), &6u32),
+        Err(Ok(IntegrationError::DustAmount))
+    );
+
+    let account = s.client.record_dust_deposit(&s.admin, &s.user, &7_000u64, &tx(&env, 2), &6u32);
+    assert_eq!(account.pending_sats, 11_000);
+    assert_eq!(account.residual_count, 2);
+
+    // A failed consolidation keeps the residuals pending for the next attempt
+    let operation_id = s.client.consolidate_dust(&s.admin, &s.user, &tx(&env, 9), &6u32);
+    assert_eq!(s.client.get_operation_status(&operation_id).unwrap().status, OperationStatus::Failed);
+    let account = s.client.get_dust_account(&s.user);
+    assert_eq!(account.pending_sats, 11_000);
+    assert_eq!(account.consolidated_sats, 0);
+}
@@ -0,0 +1,106 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::Ledger, vec, Env};
+
+fn contact(env: &Env, address: &Address, priority: u32, directory_ref: &str) -> EmergencyContact {
+    EmergencyContact {
+        contact: address.clone(),
+        priority,
+        channels: vec![env, ContactChannel::Sms, ContactChannel::Email],
+        directory_ref: String::from_str(env, directory_ref),
+    }
+}
+
+fn halt(h: &TestHarness, drill: bool) -> BytesN<32> {
+    h.router.execute_emergency_response(
+        &h.admin,
+        &EmergencyResponseType::ReserveProtection,
+        &String::from_str(&h.env, "reserve shortfall"),
+        &Vec::new(&h.env),
+        &drill
+    ).response_id
+}
+
+#[test]
+fn test_contacts_are_notified_in_priority_order_until_acknowledged() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let primary = Address::generate(&env);
+    let secondary = Address::generate(&env);
+    let backup = Address::generate(&env);
+
+    let duplicated = vec![&env, contact(&env, &primary, 1, "ops-1"), contact(&env, &primary, 2, "ops-1")];
+    assert_eq!(
+        h.router.try_set_emergency_contacts(&h.admin, &duplicated, &600),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert!(h.router.try_set_emergency_contacts(&h.operator, &Vec::new(&env), &600).is_err());
+    h.router.set_emergency_contacts(
+        &h.admin,
+        &vec![&env, contact(&env, &backup, 9, "ops-3"), contact(&env, &primary, 1, "ops-1"), contact(&env, &secondary, 5, "ops-2")],
+        &600
+    );
+    let list = h.router.get_emergency_contacts();
+    assert_eq!(list.contacts.get_unchecked(0).contact, primary);
+    assert_eq!(list.contacts.get_unchecked(2).contact, backup);
+
+    // Only the first contact is notified until the timeout passes
+    let response_id = halt(&h, false);
+    let notification = h.router.get_emergency_notification(&response_id).unwrap();
+    assert_eq!(notification.records.len(), 1);
+    assert_eq!(notification.records.get_unchecked(0).contact, primary);
+    assert_eq!(h.router.escalate_emergency_notifications(&h.operator), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 600);
+    assert_eq!(h.router.escalate_emergency_notifications(&h.operator), 1);
+    let notification = h.router.get_emergency_notification(&response_id).unwrap();
+    assert_eq!(notification.records.get_unchecked(1).contact, secondary);
+    assert_eq!(notification.records.get_unchecked(1).level, 1);
+
+    // Contacts that were not notified cannot acknowledge
+    assert_eq!(
+        h.router.try_ack_emergency_notification(&backup, &response_id),
+        Err(Ok(IntegrationError::InsufficientPermissions))
+    );
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 700);
+    h.router.ack_emergency_notification(&primary, &response_id);
+    let notification = h.router.get_emergency_notification(&response_id).unwrap();
+    assert_eq!(notification.acknowledged_by, Some(primary.clone()));
+    assert_eq!(notification.records.get_unchecked(0).acknowledged_at, HARNESS_START_TIME + 700);
+    assert_eq!(
+        h.router.try_ack_emergency_notification(&secondary, &response_id),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    // Acknowledged notifications no longer escalate
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 5_000);
+    assert_eq!(h.router.get_pending_notifications().len(), 0);
+    assert_eq!(h.router.escalate_emergency_notifications(&h.operator), 0);
+}
+
+#[test]
+fn test_escalation_stops_at_the_end_of_the_contact_list() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let only = Address::generate(&env);
+
+    // Without contacts nothing is tracked
+    assert!(h.router.get_emergency_notification(&halt(&h, false)).is_none());
+
+    h.router.set_emergency_contacts(&h.admin, &vec![&env, contact(&env, &only, 0, "ops-1")], &300);
+    h.router.start_emergency_drill(&h.admin, &EmergencyResponseType::ReserveProtection, &String::from_str(&env, "drill"));
+    let response_id = halt(&h, true);
+
+    let pending = h.router.get_pending_notifications();
+    assert_eq!(pending.len(), 1);
+    assert!(pending.get_unchecked(0).drill);
+    assert_eq!(h.router.get_emergency_drill(&0).unwrap().contacts_notified, 1);
+
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 10_000);
+    assert_eq!(h.router.escalate_emergency_notifications(&h.operator), 0);
+    assert_eq!(h.router.get_emergency_notification(&response_id).unwrap().records.len(), 1);
+    h.router.ack_emergency_notification(&only, &response_id);
+    assert_eq!(h.router.get_pending_notifications().len(), 0);
+}
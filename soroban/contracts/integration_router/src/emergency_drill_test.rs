@@ -0,0 +1,97 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::Ledger, vec, Env};
+
+fn respond(h: &TestHarness, response_type: EmergencyResponseType, affected: Vec<Address>) -> EmergencyResponseResult {
+    h.router.execute_emergency_response(
+        &h.admin,
+        &response_type,
+        &String::from_str(&h.env, "quarterly drill"),
+        &affected,
+        &true
+    )
+}
+
+#[test]
+fn test_drills_run_the_response_path_without_mutating_state() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let scenario = String::from_str(&env, "custodian key compromise");
+    let suspect = h.user(2);
+
+    // A drill response needs an announced drill
+    assert!(h.router.try_execute_emergency_response(
+        &h.admin, &EmergencyResponseType::SystemWideHalt, &scenario, &Vec::new(&env), &true
+    ).is_err());
+    assert!(h.router.try_start_emergency_drill(&h.operator, &EmergencyResponseType::SystemWideHalt, &scenario).is_err());
+
+    let audited = h.router.get_audit_chain_head().length;
+    let first = h.router.start_emergency_drill(&h.admin, &EmergencyResponseType::SystemWideHalt, &scenario);
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 300);
+    let result = respond(&h, EmergencyResponseType::SystemWideHalt, Vec::new(&env));
+    assert!(result.success);
+    assert_eq!(result.actions_taken.len(), 3);
+
+    // Nothing was paused, stored or audited
+    assert!(!h.router.is_paused());
+    assert_eq!(h.router.get_audit_chain_head().length, audited);
+    assert!(env.as_contract(&h.router.address, || {
+        !env.storage().persistent().has(&DataKey::EmergencyResponse(result.response_id.clone()))
+    }));
+
+    let drill = h.router.get_emergency_drill(&first).unwrap();
+    assert_eq!(drill.status, DrillStatus::Completed);
+    assert_eq!(drill.responder, Some(h.admin.clone()));
+    assert_eq!((drill.response_seconds, drill.sla_seconds, drill.within_sla), (300, 900, true));
+    assert_eq!(drill.actions, result.actions_taken);
+
+    // Freezing in drill mode leaves the address usable, and the type must match the drill
+    h.router.start_emergency_drill(&h.admin, &EmergencyResponseType::AddressFreeze, &scenario);
+    assert!(h.router.try_execute_emergency_response(
+        &h.admin, &EmergencyResponseType::ReserveProtection, &scenario, &Vec::new(&env), &true
+    ).is_err());
+    respond(&h, EmergencyResponseType::AddressFreeze, vec![&env, suspect.clone()]);
+    assert_eq!(h.router.get_freeze_status(&suspect), None);
+}
+
+#[test]
+fn test_drill_report_compares_response_times_to_sla_targets() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let scenario = String::from_str(&env, "reserve shortfall");
+    let targets = EmergencySlaTargets { system_wide_halt: 600, address_freeze: 600, contract_isolation: 600, reserve_protection: 1_200 };
+    assert_eq!(
+        h.router.try_set_emergency_sla_targets(&h.admin, &EmergencySlaTargets { system_wide_halt: 0, ..targets.clone() }),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    h.router.set_emergency_sla_targets(&h.admin, &targets);
+    assert_eq!(h.router.get_emergency_sla_targets(), targets);
+
+    // On time, late, abandoned, then left open past its target
+    let mut now = HARNESS_START_TIME;
+    for (response_seconds, respond_to_drill) in [(1_000, true), (2_000, true), (0, false)] {
+        h.router.start_emergency_drill(&h.admin, &EmergencyResponseType::ReserveProtection, &scenario);
+        now += response_seconds;
+        env.ledger().with_mut(|li| li.timestamp = now);
+        if respond_to_drill {
+            respond(&h, EmergencyResponseType::ReserveProtection, Vec::new(&env));
+        }
+    }
+    let open = h.router.start_emergency_drill(&h.admin, &EmergencyResponseType::SystemWideHalt, &scenario);
+    assert_eq!(h.router.get_emergency_drill(&(open - 1)).unwrap().status, DrillStatus::Abandoned);
+    env.ledger().with_mut(|li| li.timestamp = now + 601);
+
+    let report = h.router.get_drill_report(&0, &u64::MAX);
+    assert_eq!((report.completed, report.open, report.abandoned), (2, 1, 1));
+    assert_eq!((report.within_sla, report.sla_breaches), (1, 3));
+    assert_eq!(report.by_type.len(), 1);
+    let summary = report.by_type.get_unchecked(0);
+    assert_eq!(summary.response_type, EmergencyResponseType::ReserveProtection);
+    assert_eq!((summary.sla_seconds, summary.drills, summary.within_sla), (1_200, 2, 1));
+    assert_eq!((summary.average_response_seconds, summary.slowest_response_seconds), (1_500, 2_000));
+
+    // The window is applied to drill start times
+    assert_eq!(h.router.get_drill_report(&(HARNESS_START_TIME + 1), &u64::MAX).completed, 1);
+}
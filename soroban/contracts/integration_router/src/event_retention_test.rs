@@ -0,0 +1,106 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::Ledger, Env};
+
+fn emit(h: &TestHarness, event_type: &str, data1: u64) -> BytesN<32> {
+    let event = IntegrationEvent {
+        event_type: String::from_str(&h.env, event_type),
+        user: h.operator.clone(),
+        data1,
+        data2: 0,
+        data3: 0,
+        address1: h.operator.clone(),
+        address2: h.operator.clone(),
+        hash_data: BytesN::from_array(&h.env, &[0u8; 32]),
+        text_data: String::from_str(&h.env, ""),
+        timestamp: h.env.ledger().timestamp(),
+        correlation_id: BytesN::from_array(&h.env, &[0u8; 32]),
+        parent_correlation_id: None,
+    };
+    h.router.emit_integration_event(&h.operator, &event)
+}
+
+fn is_persistent(h: &TestHarness, event_id: &BytesN<32>) -> bool {
+    h.env.as_contract(&h.router.address, || {
+        h.env.storage().persistent().has(&DataKey::EventHistory(event_id.clone()))
+    })
+}
+
+#[test]
+fn test_count_limits_prune_oldest_events_on_write() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let withdrawals = String::from_str(&env, "TokenWithdrawal");
+    assert_eq!(h.router.get_event_retention_policy(&withdrawals).max_events, 100);
+
+    let policy = EventRetentionPolicy { max_events: 3, max_age_seconds: 3_600, importance: EventImportance::Standard };
+    assert_eq!(
+        h.router.try_set_event_retention_policy(&h.admin, &withdrawals, &EventRetentionPolicy { max_events: 0, ..policy.clone() }),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert!(h.router.try_set_event_retention_policy(&h.operator, &withdrawals, &policy).is_err());
+    h.router.set_event_retention_policy(&h.admin, &withdrawals, &policy);
+
+    for data1 in 1..=5 {
+        emit(&h, "TokenWithdrawal", data1);
+    }
+    assert_eq!(h.router.get_retained_event_count(&withdrawals), 3);
+    let history = h.router.get_event_history(&EventFilter::ByEventType(withdrawals.clone()), &10);
+    assert_eq!(history.len(), 3);
+    assert_eq!((history.get_unchecked(0).data1, history.get_unchecked(2).data1), (3, 5));
+
+    let metrics = h.router.get_event_retention_metrics();
+    assert_eq!(metrics.events_recorded, 5);
+    assert_eq!(metrics.pruned_by_count, 2);
+
+    // Lowering the limit trims the retained events immediately
+    h.router.set_event_retention_policy(&h.admin, &withdrawals, &EventRetentionPolicy { max_events: 1, ..policy });
+    assert_eq!(h.router.get_retained_event_count(&withdrawals), 1);
+    assert_eq!(h.router.get_event_retention_metrics().pruned_by_count, 4);
+}
+
+#[test]
+fn test_compliance_events_are_persistent_and_pruned_by_age() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let compliance = String::from_str(&env, "ComplianceAction");
+    h.router.set_event_retention_policy(
+        &h.admin,
+        &compliance,
+        &EventRetentionPolicy { max_events: 10, max_age_seconds: 1_000, importance: EventImportance::Compliance }
+    );
+
+    let first = emit(&h, "ComplianceAction", 1);
+    let second = emit(&h, "ComplianceAction", 2);
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 600);
+    let third = emit(&h, "ComplianceAction", 3);
+    assert!(is_persistent(&h, &first));
+
+    // Nothing is past the window yet
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 900);
+    assert_eq!(h.router.prune_event_history(&h.operator, &compliance, &10), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 1_200);
+    assert!(h.router.try_prune_event_history(&h.user(1), &compliance, &10).is_err());
+    assert_eq!(h.router.prune_event_history(&h.operator, &compliance, &1), 1);
+    assert_eq!(h.router.prune_event_history(&h.operator, &compliance, &10), 1);
+    assert!(!is_persistent(&h, &second));
+    assert_eq!(h.router.get_retained_event_count(&compliance), 1);
+
+    let metrics = h.router.get_event_retention_metrics();
+    assert_eq!(metrics.pruned_by_age, 2);
+    assert_eq!(metrics.prune_runs, 3);
+    assert_eq!(metrics.last_pruned_at, HARNESS_START_TIME + 1_200);
+
+    // Leaving the compliance tier moves retained events to temporary storage
+    h.router.set_event_retention_policy(
+        &h.admin,
+        &compliance,
+        &EventRetentionPolicy { max_events: 10, max_age_seconds: 1_000, importance: EventImportance::Standard }
+    );
+    assert!(!is_persistent(&h, &third));
+    let history = h.router.get_event_history(&EventFilter::ByCorrelationId(third), &1);
+    assert_eq!(history.get_unchecked(0).data1, 3);
+}
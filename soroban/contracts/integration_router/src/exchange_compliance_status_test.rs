@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{ExchangeScenario, TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::Ledger, Env};
+
+#[test]
+fn test_status_reports_headroom_and_reset_countdowns() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let (istsi, fungible) = (h.istsi.address.clone(), h.fungible.address.clone());
+
+    let exchange = ExchangeScenario::new(&h, 100_000).run();
+    assert!(exchange.succeeded());
+    let user = exchange.user;
+    let status = h.router.get_exchange_compliance_status(&user, &istsi, &fungible);
+    assert_eq!(status.compliance_status, String::from_str(&env, "basic"));
+
+    assert_eq!(
+        h.router.try_get_exchange_compliance_status(&user, &istsi, &h.reserve.address),
+        Err(Ok(IntegrationError::ContractNotFound))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 3_600);
+    let status = h.router.get_exchange_compliance_status(&user, &istsi, &fungible);
+    assert_eq!(status.daily_used, 100_000);
+    assert_eq!(status.daily_remaining, status.daily_limit - 100_000);
+    assert_eq!(status.daily_reset_in_seconds, LIMIT_DAILY_WINDOW - 3_600);
+    assert_eq!(status.monthly_reset_in_seconds, LIMIT_MONTHLY_WINDOW - 3_600);
+
+    // An elapsed daily window is reported as fresh headroom before anything is stored
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + LIMIT_DAILY_WINDOW);
+    let status = h.router.get_exchange_compliance_status(&user, &istsi, &fungible);
+    assert_eq!((status.daily_used, status.monthly_used), (0, 100_000));
+    assert_eq!(status.daily_reset_in_seconds, LIMIT_DAILY_WINDOW);
+
+    h.router.freeze_address(&h.admin, &user, &String::from_str(&env, "review"), &0);
+    let status = h.router.get_exchange_compliance_status(&user, &istsi, &fungible);
+    assert_eq!(status.compliance_status, String::from_str(&env, "frozen"));
+}
+
+#[test]
+fn test_exhausted_limits_reject_swaps_before_quoting() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let (istsi, fungible) = (h.istsi.address.clone(), h.fungible.address.clone());
+    h.fund_exchange(10000, 1_000_000);
+    let user = h.user(2);
+    h.istsi.mint(&user, &200_000);
+    h.router.set_exchange_limits(&h.admin, &user, &50_000, &1_000_000, &1_000_000);
+
+    h.router.execute_cross_token_exchange(&user, &istsi, &fungible, &50_000, &500);
+    let status = h.router.get_exchange_compliance_status(&user, &istsi, &fungible);
+    assert_eq!(status.daily_remaining, 0);
+    assert_eq!(status.compliance_status, String::from_str(&env, "limit_reached"));
+
+    let refused = h.router.execute_cross_token_exchange(&user, &istsi, &fungible, &1_000, &500);
+    assert_eq!(refused.status, ExchangeStatus::Failed);
+    assert_eq!(h.router.get_exchange_operation(&refused.operation_id), Some(refused));
+    assert_eq!(h.istsi.balance(&user), 150_000);
+
+    // The next day's window restores the headroom
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + LIMIT_DAILY_WINDOW);
+    let status = h.router.get_exchange_compliance_status(&user, &istsi, &fungible);
+    assert_eq!(status.daily_remaining, 50_000);
+}
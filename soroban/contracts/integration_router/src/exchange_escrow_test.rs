@@ -0,0 +1,155 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as TestAddress, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env
+};
+
+/// Minimal KYC registry approving every exchange
+#[contract]
+pub struct MockExchangeKycRegistry;
+
+#[contractimpl]
+impl MockExchangeKycRegistry {
+    pub fn verify_ic(_env: Env, _user: String, _operation: String, _amount: String) -> bool {
+        true
+    }
+
+    pub fn reg_event(_env: Env, _user: String, _event: String, _amount: String, _a: String, _b: String) -> bool {
+        true
+    }
+}
+
+struct Setup<'a> {
+    contract_id: Address,
+    client: IntegrationRouterClient<'a>,
+    admin: Address,
+    user: Address,
+    from_token: Address,
+    to_token: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+    let kyc_registry = env.register(MockExchangeKycRegistry, ());
+
+    let issuer = Address::generate(env);
+    let from_token = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    let to_token = env.register_stellar_asset_contract_v2(issuer).address();
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &kyc_registry,
+        &from_token,
+        &to_token,
+        &Address::generate(env),
+    );
+    client.configure_oracle(&admin, &from_token, &to_token, &Address::generate(env), &300u64, &500u64, &10000u64);
+
+    let user = Address::generate(env);
+    StellarAssetClient::new(env, &from_token).mint(&user, &1_000_000i128);
+
+    Setup { contract_id, client, admin, user, from_token, to_token }
+}
+
+#[test]
+fn test_exchange_escrows_and_settles_funds() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let market_maker = Address::generate(&env);
+    StellarAssetClient::new(&env, &s.to_token).mint(&market_maker, &1_000_000i128);
+    s.client.set_market_maker(&s.admin, &market_maker, &true);
+    s.client.deposit_liquidity(&market_maker, &s.to_token, &1_000_000u64);
+
+    let exchange = s.client.execute_cross_token_exchange(&s.user, &s.from_token, &s.to_token, &100_000u64, &500u64);
+    assert_eq!(exchange.status, ExchangeStatus::Completed);
+    assert_eq!(exchange.fee_amount, 300);
+    assert_eq!(exchange.to_amount, 100_697);
+
+    let from = TokenClient::new(&env, &s.from_token);
+    let to = TokenClient::new(&env, &s.to_token);
+    assert_eq!(from.balance(&s.user), 900_000);
+    assert_eq!(from.balance(&s.contract_id), 100_000);
+    assert_eq!(to.balance(&s.user), 100_697);
+    assert_eq!(to.balance(&s.contract_id), 1_000_000 - 100_697);
+
+    let escrow = s.client.get_exchange_escrow(&exchange.operation_id).unwrap();
+    assert_eq!(escrow.status, EscrowStatus::Settled);
+    assert_eq!(escrow.amount, 100_000);
+    assert_eq!(s.client.get_fee_treasury(&s.from_token).accrued, 300);
+    assert_eq!(s.client.get_exchange_limits(&s.user).daily_used, 100_000);
+
+    // Proceeds net of the fee belong to the market maker that supplied the inventory
+    assert_eq!(s.client.get_liquidity_position(&market_maker, &s.from_token).amount, 99_700);
+    assert_eq!(s.client.get_liquidity_position(&market_maker, &s.to_token).amount, 1_000_000 - 100_697);
+}
+
+#[test]
+fn test_exchange_without_liquidity_leaves_balances_untouched() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let exchange = s.client.execute_cross_token_exchange(&s.user, &s.from_token, &s.to_token, &100_000u64, &500u64);
+    assert_eq!(exchange.status, ExchangeStatus::RolledBack);
+
+    // The rollback is recorded rather than reverted with the call
+    assert_eq!(s.client.get_exchange_operation(&exchange.operation_id), Some(exchange.clone()));
+    assert_eq!(s.client.get_exchange_escrow(&exchange.operation_id).unwrap().status, EscrowStatus::Released);
+
+    let from = TokenClient::new(&env, &s.from_token);
+    assert_eq!(from.balance(&s.user), 1_000_000);
+    assert_eq!(from.balance(&s.contract_id), 0);
+    assert_eq!(s.client.get_exchange_limits(&s.user).daily_used, 0);
+}
+
+#[test]
+fn test_failed_exchange_releases_escrow() {
+    let env = Env::default();
+    let s = setup(&env);
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let operation_id = BytesN::from_array(&env, &[7u8; 32]);
+    let mut exchange_op = ExchangeOperation {
+        operation_id: operation_id.clone(),
+        user: s.user.clone(),
+        from_token: s.from_token.clone(),
+        to_token: s.to_token.clone(),
+        from_amount: 250_000,
+        to_amount: 0,
+        exchange_rate: 0,
+        fee_amount: 0,
+        status: ExchangeStatus::Pending,
+        created_at: 1_000_000,
+        updated_at: 1_000_000,
+        expires_at: 1_000_300,
+        error_message: String::from_str(&env, ""),
+    };
+
+    let from = TokenClient::new(&env, &s.from_token);
+    env.as_contract(&s.contract_id, || {
+        IntegrationRouter::escrow_exchange_funds(&env, &exchange_op).unwrap();
+        assert_eq!(from.balance(&s.user), 750_000);
+
+        let error = IntegrationRouter::roll_back_exchange(&env, &mut exchange_op, IntegrationError::ContractCallFailed);
+        assert_eq!(error, IntegrationError::ContractCallFailed);
+    });
+
+    assert_eq!(from.balance(&s.user), 1_000_000);
+    assert_eq!(from.balance(&s.contract_id), 0);
+    assert_eq!(s.client.get_exchange_escrow(&operation_id).unwrap().status, EscrowStatus::Released);
+
+    let stored = s.client.get_exchange_operation(&operation_id).unwrap();
+    assert_eq!(stored.status, ExchangeStatus::RolledBack);
+    assert_eq!(stored.error_message, String::from_str(&env, "Exchange failed; escrow released"));
+}
@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use super::*;
+use crate::testing::mock_contracts;
 use soroban_sdk::{
     testutils::{Address as TestAddress, Ledger, LedgerInfo},
     Address, Env, String, BytesN
@@ -11,47 +12,50 @@ use soroban_sdk::{
 fn test_kyc_tier_based_exchange_limits() {
     let env = Env::default();
     env.mock_all_auths();
-
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-
-    // Initialize the contract
-    IntegrationRouter::initialize(
-        env.clone(),
-        admin.clone(),
-        kyc_registry.clone(),
-        istsi_token.clone(),
-        fungible_token.clone(),
-        reserve_manager.clone()
-    );
-
-    // Test Tier 1 limits (basic)
-    let tier1_limits = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 1);
-    assert_eq!(tier1_limits.daily_limit, 1_000_000);
-    assert_eq!(tier1_limits.monthly_limit, 10_000_000);
-    assert_eq!(tier1_limits.enhanced_verification_limit, 500_000);
-
-    // Test Tier 2 limits (intermediate)
-    let tier2_limits = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 2);
-    assert_eq!(tier2_limits.daily_limit, 5_000_000);
-    assert_eq!(tier2_limits.monthly_limit, 50_000_000);
-    assert_eq!(tier2_limits.enhanced_verification_limit, 2_000_000);
-
-    // Test Tier 3 limits (high)
-    let tier3_limits = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 3);
-    assert_eq!(tier3_limits.daily_limit, 20_000_000);
-    assert_eq!(tier3_limits.monthly_limit, 200_000_000);
-    assert_eq!(tier3_limits.enhanced_verification_limit, 10_000_000);
-
-    // Test Tier 4 limits (premium)
-    let tier4_limits = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 4);
-    assert_eq!(tier4_limits.daily_limit, 100_000_000);
-    assert_eq!(tier4_limits.monthly_limit, 1_000_000_000);
-    assert_eq!(tier4_limits.enhanced_verification_limit, 50_000_000);
+    let router = env.register(IntegrationRouter, ());
+    env.as_contract(&router, || {
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
+
+        // Initialize the contract
+        IntegrationRouter::initialize(
+            env.clone(),
+            admin.clone(),
+            kyc_registry.clone(),
+            istsi_token.clone(),
+            fungible_token.clone(),
+            reserve_manager.clone()
+        );
+
+        // Test Tier 1 limits (basic)
+        let mut tier1_limits = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 1);
+        IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut tier1_limits, 1);
+        assert_eq!(tier1_limits.daily_limit, 1_000_000);
+        assert_eq!(tier1_limits.monthly_limit, 10_000_000);
+        assert_eq!(tier1_limits.enhanced_verification_limit, 500_000);
+
+        // Test Tier 2 limits (intermediate)
+        let mut tier2_limits = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 2);
+        IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut tier2_limits, 2);
+        assert_eq!(tier2_limits.daily_limit, 5_000_000);
+        assert_eq!(tier2_limits.monthly_limit, 50_000_000);
+        assert_eq!(tier2_limits.enhanced_verification_limit, 2_000_000);
+
+        // Test Tier 3 limits (high)
+        let mut tier3_limits = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 3);
+        IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut tier3_limits, 3);
+        assert_eq!(tier3_limits.daily_limit, 20_000_000);
+        assert_eq!(tier3_limits.monthly_limit, 200_000_000);
+        assert_eq!(tier3_limits.enhanced_verification_limit, 10_000_000);
+
+        // Test Tier 4 limits (premium)
+        let mut tier4_limits = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 4);
+        IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut tier4_limits, 4);
+        assert_eq!(tier4_limits.daily_limit, 100_000_000);
+        assert_eq!(tier4_limits.monthly_limit, 1_000_000_000);
+        assert_eq!(tier4_limits.enhanced_verification_limit, 50_000_000);
+    });
 }
 
 /// Test daily exchange limit enforcement
@@ -59,52 +63,51 @@ fn test_kyc_tier_based_exchange_limits() {
 fn test_daily_exchange_limit_enforcement() {
     let env = Env::default();
     env.mock_all_auths();
-
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-
-    // Initialize the contract
-    IntegrationRouter::initialize(
-        env.clone(),
-        admin.clone(),
-        kyc_registry.clone(),
-        istsi_token.clone(),
-        fungible_token.clone(),
-        reserve_manager.clone()
-    );
-
-    // Set up user with Tier 1 limits (1M daily)
-    let mut limit_info = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 1);
-    IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut limit_info, 1);
-    env.storage().persistent().set(&DataKey::ExchangeLimits(user.clone()), &limit_info);
-
-    // Test exchange within daily limit (should succeed)
-    let result1 = IntegrationRouter::verify_exchange_limits(
-        &env, &user, &istsi_token, &fungible_token, 500_000
-    );
-    assert!(result1.is_ok());
-    assert!(result1.unwrap().0); // Should be true (allowed)
-
-    // Update usage to simulate previous exchange
-    let mut updated_limits = IntegrationRouter::get_exchange_limit_info(&env, &user);
-    updated_limits.daily_used = 800_000;
-    env.storage().persistent().set(&DataKey::ExchangeLimits(user.clone()), &updated_limits);
-
-    // Test exchange that would exceed daily limit (should fail)
-    let result2 = IntegrationRouter::verify_exchange_limits(
-        &env, &user, &istsi_token, &fungible_token, 300_000
-    );
-    assert!(result2.is_ok());
-    let (allowed, error_msg) = result2.unwrap();
-    assert!(!allowed); // Should be false (not allowed)
-    let _expected_msg = String::from_str(&env, "Daily exchange limit exceeded");
-    // In a real test, we would check if the error message contains the expected text
-    // For now, we just verify it's not empty
-    assert!(!error_msg.is_empty());
+    let router = env.register(IntegrationRouter, ());
+    env.as_contract(&router, || {
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
+
+        // Initialize the contract
+        IntegrationRouter::initialize(
+            env.clone(),
+            admin.clone(),
+            kyc_registry.clone(),
+            istsi_token.clone(),
+            fungible_token.clone(),
+            reserve_manager.clone()
+        );
+
+        // Set up user with Tier 1 limits (1M daily)
+        let mut limit_info = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 1);
+        IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut limit_info, 1);
+        env.storage().persistent().set(&DataKey::ExchangeLimits(user.clone()), &limit_info);
+
+        // Test exchange within daily limit (should succeed)
+        let result1 = IntegrationRouter::verify_exchange_limits(
+            &env, &user, &istsi_token, &fungible_token, 500_000
+        );
+        assert!(result1.is_ok());
+        assert!(result1.unwrap().0); // Should be true (allowed)
+
+        // Update usage to simulate previous exchange
+        let mut updated_limits = IntegrationRouter::get_exchange_limit_info(&env, &user);
+        updated_limits.daily_used = 800_000;
+        env.storage().persistent().set(&DataKey::ExchangeLimits(user.clone()), &updated_limits);
+
+        // Test exchange that would exceed daily limit (should fail)
+        let result2 = IntegrationRouter::verify_exchange_limits(
+            &env, &user, &istsi_token, &fungible_token, 300_000
+        );
+        assert!(result2.is_ok());
+        let (allowed, error_msg) = result2.unwrap();
+        assert!(!allowed); // Should be false (not allowed)
+        let _expected_msg = String::from_str(&env, "Daily exchange limit exceeded");
+        // In a real test, we would check if the error message contains the expected text
+        // For now, we just verify it's not empty
+        assert!(!error_msg.is_empty());
+    });
 }
 
 /// Test monthly exchange limit enforcement
@@ -112,43 +115,42 @@ fn test_daily_exchange_limit_enforcement() {
 fn test_monthly_exchange_limit_enforcement() {
     let env = Env::default();
     env.mock_all_auths();
-
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-
-    // Initialize the contract
-    IntegrationRouter::initialize(
-        env.clone(),
-        admin.clone(),
-        kyc_registry.clone(),
-        istsi_token.clone(),
-        fungible_token.clone(),
-        reserve_manager.clone()
-    );
-
-    // Set up user with Tier 1 limits (10M monthly)
-    let mut limit_info = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 1);
-    IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut limit_info, 1);
-    
-    // Simulate high monthly usage
-    limit_info.monthly_used = 9_500_000;
-    env.storage().persistent().set(&DataKey::ExchangeLimits(user.clone()), &limit_info);
-
-    // Test exchange that would exceed monthly limit (should fail)
-    let result = IntegrationRouter::verify_exchange_limits(
-        &env, &user, &istsi_token, &fungible_token, 600_000
-    );
-    assert!(result.is_ok());
-    let (allowed, error_msg) = result.unwrap();
-    assert!(!allowed); // Should be false (not allowed)
-    let _expected_msg = String::from_str(&env, "Monthly exchange limit exceeded");
-    // In a real test, we would check if the error message contains the expected text
-    // For now, we just verify it's not empty
-    assert!(!error_msg.is_empty());
+    let router = env.register(IntegrationRouter, ());
+    env.as_contract(&router, || {
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
+
+        // Initialize the contract
+        IntegrationRouter::initialize(
+            env.clone(),
+            admin.clone(),
+            kyc_registry.clone(),
+            istsi_token.clone(),
+            fungible_token.clone(),
+            reserve_manager.clone()
+        );
+
+        // Set up user with Tier 1 limits (10M monthly)
+        let mut limit_info = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 1);
+        IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut limit_info, 1);
+        
+        // Simulate high monthly usage
+        limit_info.monthly_used = 9_500_000;
+        env.storage().persistent().set(&DataKey::ExchangeLimits(user.clone()), &limit_info);
+
+        // Test exchange that would exceed monthly limit (should fail)
+        let result = IntegrationRouter::verify_exchange_limits(
+            &env, &user, &istsi_token, &fungible_token, 600_000
+        );
+        assert!(result.is_ok());
+        let (allowed, error_msg) = result.unwrap();
+        assert!(!allowed); // Should be false (not allowed)
+        let _expected_msg = String::from_str(&env, "Monthly exchange limit exceeded");
+        // In a real test, we would check if the error message contains the expected text
+        // For now, we just verify it's not empty
+        assert!(!error_msg.is_empty());
+    });
 }
 
 /// Test enhanced verification requirements for large exchanges
@@ -156,36 +158,35 @@ fn test_monthly_exchange_limit_enforcement() {
 fn test_enhanced_verification_requirements() {
     let env = Env::default();
     env.mock_all_auths();
-
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-
-    // Initialize the contract
-    IntegrationRouter::initialize(
-        env.clone(),
-        admin.clone(),
-        kyc_registry.clone(),
-        istsi_token.clone(),
-        fungible_token.clone(),
-        reserve_manager.clone()
-    );
-
-    // Set up user with Tier 1 limits (500K enhanced verification limit)
-    let mut limit_info = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 1);
-    IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut limit_info, 1);
-    env.storage().persistent().set(&DataKey::ExchangeLimits(user.clone()), &limit_info);
-
-    // Test exchange above enhanced verification limit
-    let result = IntegrationRouter::check_enhanced_verification_requirements(
-        &env, &user, 600_000, 1
-    );
-    assert!(result.is_ok());
-    // Note: This will fail in test environment since we don't have real KYC registry
-    // In production, this would check with the actual KYC registry
+    let router = env.register(IntegrationRouter, ());
+    env.as_contract(&router, || {
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
+
+        // Initialize the contract
+        IntegrationRouter::initialize(
+            env.clone(),
+            admin.clone(),
+            kyc_registry.clone(),
+            istsi_token.clone(),
+            fungible_token.clone(),
+            reserve_manager.clone()
+        );
+
+        // Set up user with Tier 1 limits (500K enhanced verification limit)
+        let mut limit_info = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 1);
+        IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut limit_info, 1);
+        env.storage().persistent().set(&DataKey::ExchangeLimits(user.clone()), &limit_info);
+
+        // Test exchange above enhanced verification limit
+        let result = IntegrationRouter::check_enhanced_verification_requirements(
+            &env, &user, 600_000, 1
+        );
+        assert!(result.is_ok());
+        // Note: This will fail in test environment since we don't have real KYC registry
+        // In production, this would check with the actual KYC registry
+    });
 }
 
 /// Test time-based limit resets
@@ -193,63 +194,62 @@ fn test_enhanced_verification_requirements() {
 fn test_time_based_limit_resets() {
     let env = Env::default();
     env.mock_all_auths();
-
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-
-    // Initialize the contract
-    IntegrationRouter::initialize(
-        env.clone(),
-        admin.clone(),
-        kyc_registry.clone(),
-        istsi_token.clone(),
-        fungible_token.clone(),
-        reserve_manager.clone()
-    );
-
-    // Set up initial time
-    let initial_time = 1000000;
-    env.ledger().with_mut(|li| {
-        li.timestamp = initial_time;
-    });
-
-    // Set up user with some usage
-    let mut limit_info = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 1);
-    IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut limit_info, 1);
-    limit_info.daily_used = 500_000;
-    limit_info.monthly_used = 2_000_000;
-    limit_info.last_reset_daily = initial_time;
-    limit_info.last_reset_monthly = initial_time;
-
-    // Advance time by 25 hours (should reset daily)
-    let new_time = initial_time + 25 * 3600;
-    env.ledger().with_mut(|li| {
-        li.timestamp = new_time;
+    let router = env.register(IntegrationRouter, ());
+    env.as_contract(&router, || {
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
+
+        // Initialize the contract
+        IntegrationRouter::initialize(
+            env.clone(),
+            admin.clone(),
+            kyc_registry.clone(),
+            istsi_token.clone(),
+            fungible_token.clone(),
+            reserve_manager.clone()
+        );
+
+        // Set up initial time
+        let initial_time = 1000000;
+        env.ledger().with_mut(|li| {
+            li.timestamp = initial_time;
+        });
+
+        // Set up user with some usage
+        let mut limit_info = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 1);
+        IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut limit_info, 1);
+        limit_info.daily_used = 500_000;
+        limit_info.monthly_used = 2_000_000;
+        limit_info.last_reset_daily = initial_time;
+        limit_info.last_reset_monthly = initial_time;
+
+        // Advance time by 25 hours (should reset daily)
+        let new_time = initial_time + 25 * 3600;
+        env.ledger().with_mut(|li| {
+            li.timestamp = new_time;
+        });
+
+        // Reset limits
+        IntegrationRouter::reset_time_based_limits(&mut limit_info, new_time);
+
+        // Daily usage should be reset, monthly should remain
+        assert_eq!(limit_info.daily_used, 0);
+        assert_eq!(limit_info.monthly_used, 2_000_000);
+        assert_eq!(limit_info.last_reset_daily, new_time);
+
+        // Advance time by 31 days (should reset monthly)
+        let monthly_reset_time = initial_time + 31 * 24 * 3600;
+        env.ledger().with_mut(|li| {
+            li.timestamp = monthly_reset_time;
+        });
+
+        IntegrationRouter::reset_time_based_limits(&mut limit_info, monthly_reset_time);
+
+        // Monthly usage should now be reset
+        assert_eq!(limit_info.monthly_used, 0);
+        assert_eq!(limit_info.last_reset_monthly, monthly_reset_time);
     });
-
-    // Reset limits
-    IntegrationRouter::reset_time_based_limits(&mut limit_info, new_time);
-
-    // Daily usage should be reset, monthly should remain
-    assert_eq!(limit_info.daily_used, 0);
-    assert_eq!(limit_info.monthly_used, 2_000_000);
-    assert_eq!(limit_info.last_reset_daily, new_time);
-
-    // Advance time by 31 days (should reset monthly)
-    let monthly_reset_time = initial_time + 31 * 24 * 3600;
-    env.ledger().with_mut(|li| {
-        li.timestamp = monthly_reset_time;
-    });
-
-    IntegrationRouter::reset_time_based_limits(&mut limit_info, monthly_reset_time);
-
-    // Monthly usage should now be reset
-    assert_eq!(limit_info.monthly_used, 0);
-    assert_eq!(limit_info.last_reset_monthly, monthly_reset_time);
 }
 
 /// Test exchange compliance status retrieval
@@ -257,40 +257,39 @@ fn test_time_based_limit_resets() {
 fn test_exchange_compliance_status() {
     let env = Env::default();
     env.mock_all_auths();
-
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-
-    // Initialize the contract
-    IntegrationRouter::initialize(
-        env.clone(),
-        admin.clone(),
-        kyc_registry.clone(),
-        istsi_token.clone(),
-        fungible_token.clone(),
-        reserve_manager.clone()
-    );
-
-    // Note: This test will use default KYC tier (1) since we can't mock the KYC registry call
-    // In a real environment, this would fetch the actual tier from the KYC registry
-
-    let status_result = IntegrationRouter::get_exchange_compliance_status(env.clone(), user.clone());
-    
-    // The function should return an error or default values since we can't reach the KYC registry
-    // This is expected behavior in the test environment
-    match status_result {
-        Ok(status) => {
-            assert_eq!(status.user, user);
-            assert_eq!(status.kyc_tier, 1); // Default tier
-        },
-        Err(_) => {
-            // Expected in test environment without real KYC registry
+    let router = env.register(IntegrationRouter, ());
+    env.as_contract(&router, || {
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
+
+        // Initialize the contract
+        IntegrationRouter::initialize(
+            env.clone(),
+            admin.clone(),
+            kyc_registry.clone(),
+            istsi_token.clone(),
+            fungible_token.clone(),
+            reserve_manager.clone()
+        );
+
+        // Note: This test will use default KYC tier (1) since we can't mock the KYC registry call
+        // In a real environment, this would fetch the actual tier from the KYC registry
+
+        let status_result = IntegrationRouter::get_exchange_compliance_status(env.clone(), user.clone(), istsi_token.clone(), fungible_token.clone());
+        
+        // The function should return an error or default values since we can't reach the KYC registry
+        // This is expected behavior in the test environment
+        match status_result {
+            Ok(status) => {
+                assert_eq!(status.user, user);
+                assert_eq!(status.kyc_tier, 1); // Default tier
+            },
+            Err(_) => {
+                // Expected in test environment without real KYC registry
+            }
         }
-    }
+    });
 }
 
 /// Test exchange limits usage tracking with warnings
@@ -298,41 +297,40 @@ fn test_exchange_compliance_status() {
 fn test_exchange_limits_usage_tracking() {
     let env = Env::default();
     env.mock_all_auths();
-
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-
-    // Initialize the contract
-    IntegrationRouter::initialize(
-        env.clone(),
-        admin.clone(),
-        kyc_registry.clone(),
-        istsi_token.clone(),
-        fungible_token.clone(),
-        reserve_manager.clone()
-    );
-
-    // Set up user with Tier 1 limits
-    let mut limit_info = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 1);
-    IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut limit_info, 1);
-    env.storage().persistent().set(&DataKey::ExchangeLimits(user.clone()), &limit_info);
-
-    // Test usage update
-    let result = IntegrationRouter::update_exchange_limits_usage_enhanced(
-        &env, &user, &istsi_token, &fungible_token, 100_000
-    );
-
-    // Should succeed (though KYC registry calls will fail in test environment)
-    assert!(result.is_ok());
-
-    // Verify usage was updated
-    let updated_limits = IntegrationRouter::get_exchange_limit_info(&env, &user);
-    assert_eq!(updated_limits.daily_used, 100_000);
-    assert_eq!(updated_limits.monthly_used, 100_000);
+    let router = env.register(IntegrationRouter, ());
+    env.as_contract(&router, || {
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
+
+        // Initialize the contract
+        IntegrationRouter::initialize(
+            env.clone(),
+            admin.clone(),
+            kyc_registry.clone(),
+            istsi_token.clone(),
+            fungible_token.clone(),
+            reserve_manager.clone()
+        );
+
+        // Set up user with Tier 1 limits
+        let mut limit_info = IntegrationRouter::get_exchange_limit_info_with_kyc_tier(&env, &user, 1);
+        IntegrationRouter::update_limits_based_on_kyc_tier(&env, &mut limit_info, 1);
+        env.storage().persistent().set(&DataKey::ExchangeLimits(user.clone()), &limit_info);
+
+        // Test usage update
+        let result = IntegrationRouter::update_exchange_limits_usage_enhanced(
+            &env, &user, &istsi_token, &fungible_token, 100_000
+        );
+
+        // Should succeed (though KYC registry calls will fail in test environment)
+        assert!(result.is_ok());
+
+        // Verify usage was updated
+        let updated_limits = IntegrationRouter::get_exchange_limit_info(&env, &user);
+        assert_eq!(updated_limits.daily_used, 100_000);
+        assert_eq!(updated_limits.monthly_used, 100_000);
+    });
 }
 
 /// Test compliance event logging
@@ -340,35 +338,34 @@ fn test_exchange_limits_usage_tracking() {
 fn test_compliance_event_logging() {
     let env = Env::default();
     env.mock_all_auths();
-
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-
-    // Initialize the contract
-    IntegrationRouter::initialize(
-        env.clone(),
-        admin.clone(),
-        kyc_registry.clone(),
-        istsi_token.clone(),
-        fungible_token.clone(),
-        reserve_manager.clone()
-    );
-
-    // Test limit violation logging
-    let result1 = IntegrationRouter::log_exchange_limit_violation(
-        &env, &user, "daily_limit_exceeded", 1_500_000, 1_000_000
-    );
-    assert!(result1.is_ok());
-
-    // Test compliance check logging
-    let result2 = IntegrationRouter::log_exchange_compliance_check(
-        &env, &user, "limits_verified", 500_000, 2
-    );
-    assert!(result2.is_ok());
+    let router = env.register(IntegrationRouter, ());
+    env.as_contract(&router, || {
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
+
+        // Initialize the contract
+        IntegrationRouter::initialize(
+            env.clone(),
+            admin.clone(),
+            kyc_registry.clone(),
+            istsi_token.clone(),
+            fungible_token.clone(),
+            reserve_manager.clone()
+        );
+
+        // Test limit violation logging
+        let result1 = IntegrationRouter::log_exchange_limit_violation(
+            &env, &user, "daily_limit_exceeded", 1_500_000, 1_000_000
+        );
+        assert!(result1.is_ok());
+
+        // Test compliance check logging
+        let result2 = IntegrationRouter::log_exchange_compliance_check(
+            &env, &user, "limits_verified", 500_000, 2
+        );
+        assert!(result2.is_ok());
+    });
 }
 
 /// Test admin exchange limits management
@@ -376,37 +373,27 @@ fn test_compliance_event_logging() {
 fn test_admin_exchange_limits_management() {
     let env = Env::default();
     env.mock_all_auths();
+    let client = IntegrationRouterClient::new(&env, &env.register(IntegrationRouter, ()));
 
     let admin = Address::generate(&env);
     let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
+    let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
 
     // Initialize the contract
-    IntegrationRouter::initialize(
-        env.clone(),
-        admin.clone(),
-        kyc_registry.clone(),
-        istsi_token.clone(),
-        fungible_token.clone(),
-        reserve_manager.clone()
-    );
+    client.initialize(&admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
 
     // Test setting custom exchange limits (admin function)
-    let result = IntegrationRouter::set_exchange_limits(
-        env.clone(),
-        admin.clone(),
-        user.clone(),
-        5_000_000,  // daily_limit
-        50_000_000, // monthly_limit
-        2_500_000   // enhanced_verification_limit
+    let result = client.try_set_exchange_limits(
+        &admin,
+        &user,
+        &5_000_000,  // daily_limit
+        &50_000_000, // monthly_limit
+        &2_500_000   // enhanced_verification_limit
     );
     assert!(result.is_ok());
 
     // Verify limits were set
-    let limits = IntegrationRouter::get_exchange_limits(env.clone(), user.clone());
+    let limits = client.get_exchange_limits(&user);
     assert_eq!(limits.daily_limit, 5_000_000);
     assert_eq!(limits.monthly_limit, 50_000_000);
     assert_eq!(limits.enhanced_verification_limit, 2_500_000);
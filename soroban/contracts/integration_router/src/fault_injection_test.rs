@@ -0,0 +1,132 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{DepositScenario, TestHarness, WithdrawalScenario};
+use soroban_sdk::{testutils::Address as TestAddress, Address, Env};
+
+#[test]
+fn test_faults_require_enabled_injection_and_super_admin() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+
+    assert!(!h.router.is_fault_injection_enabled());
+    assert_eq!(
+        h.router.try_inject_fault(&h.admin, &FaultStep::Minting),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert!(h.router.try_set_fault_injection_enabled(&Address::generate(&env), &true).is_err());
+
+    h.router.set_fault_injection_enabled(&h.admin, &true);
+    h.router.inject_fault(&h.admin, &FaultStep::Minting);
+    h.router.inject_fault(&h.admin, &FaultStep::Burning);
+    h.router.inject_fault(&h.admin, &FaultStep::Minting);
+    assert_eq!(h.router.get_injected_faults(), vec![&env, FaultStep::Minting, FaultStep::Burning]);
+
+    h.router.clear_fault(&h.admin, &FaultStep::Minting);
+    assert_eq!(h.router.get_injected_faults(), vec![&env, FaultStep::Burning]);
+
+    // Disabling drops every armed fault
+    h.router.set_fault_injection_enabled(&h.admin, &false);
+    assert_eq!(h.router.get_injected_faults().len(), 0);
+}
+
+#[test]
+fn test_injected_faults_fail_the_targeted_workflow_step() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+
+    h.fail_at(FaultStep::DepositKyc);
+    let deposit = DepositScenario::new(&h, 50_000).run();
+    assert!(deposit.report.passed);
+    assert_eq!(deposit.error(), Some(IntegrationError::ComplianceCheckFailed));
+
+    h.router.clear_fault(&h.admin, &FaultStep::DepositKyc);
+    h.fail_at(FaultStep::BitcoinValidation);
+    let deposit = DepositScenario::new(&h, 50_000).btc_tx_hash(BytesN::from_array(&env, &[8u8; 32])).run();
+    assert_eq!(deposit.error(), Some(IntegrationError::BitcoinTransactionFailed));
+
+    h.fail_at(FaultStep::WithdrawalKyc);
+    let withdrawal = WithdrawalScenario::new(&h, 5_000_000_000_000).run();
+    assert!(withdrawal.report.passed);
+    assert_eq!(withdrawal.error(), Some(IntegrationError::ComplianceCheckFailed));
+
+    // Without injection the same withdrawal gets past the KYC step
+    h.router.set_fault_injection_enabled(&h.admin, &false);
+    let withdrawal = WithdrawalScenario::new(&h, 5_000_000_000_000).run();
+    assert_ne!(withdrawal.error(), Some(IntegrationError::ComplianceCheckFailed));
+}
+
+#[test]
+fn test_tracked_deposit_commits_the_minting_rollback() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let btc_tx_hash = BytesN::from_array(&env, &[9u8; 32]);
+
+    h.fail_at(FaultStep::Minting);
+    let deposit = DepositScenario::new(&h, 50_000)
+        .reserves(1_000_000, 1_000_000)
+        .btc_tx_hash(btc_tx_hash.clone())
+        .tracked()
+        .run();
+    let operation_id = deposit.result.clone().unwrap();
+
+    // The registration was reversed and nothing was minted
+    let status = h.router.get_deposit_status_by_tx_hash(&btc_tx_hash).unwrap();
+    assert_eq!(status.status, DepositProcessingStatus::RolledBack);
+    assert_eq!(status.error_message, String::from_str(&env, "Fault injected at Minting"));
+    assert_eq!(h.router.get_operation_status(&operation_id).unwrap().status, OperationStatus::RolledBack);
+    assert_eq!(h.istsi.balance(&deposit.user), 0);
+
+    // A pre-registration fault fails without a rollback
+    h.router.clear_fault(&h.admin, &FaultStep::Minting);
+    h.fail_at(FaultStep::DepositRegistration);
+    let btc_tx_hash = BytesN::from_array(&env, &[10u8; 32]);
+    let deposit = DepositScenario::new(&h, 50_000).btc_tx_hash(btc_tx_hash.clone()).tracked().run();
+    let operation_id = deposit.result.unwrap();
+    assert_eq!(h.router.get_deposit_status_by_tx_hash(&btc_tx_hash).unwrap().status, DepositProcessingStatus::Failed);
+    assert_eq!(h.router.get_operation_status(&operation_id).unwrap().status, OperationStatus::Failed);
+}
+
+#[test]
+fn test_tracked_withdrawal_commits_post_burn_rollbacks() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let amount = 5_000_000_000_000u64;
+
+    for step in [FaultStep::ReserveProcessing, FaultStep::BitcoinInitiation] {
+        h.fail_at(step.clone());
+        let withdrawal = WithdrawalScenario::new(&h, amount).tracked().run();
+        let withdrawal_id = withdrawal.result.clone().unwrap();
+
+        // The burned iSTSi was re-minted and the rollback is observable
+        let status = h.router.get_withdrawal_status(&withdrawal_id).unwrap();
+        assert_eq!(status.status, WithdrawalProcessingStatus::RolledBack);
+        assert_eq!(h.router.get_operation_status(&status.operation_id).unwrap().status, OperationStatus::RolledBack);
+        assert_eq!(h.istsi.balance(&withdrawal.user), amount as i128);
+        h.router.clear_fault(&h.admin, &step);
+    }
+
+    // A fault at the burn itself fails the withdrawal with the balance untouched
+    h.fail_at(FaultStep::Burning);
+    let withdrawal = WithdrawalScenario::new(&h, amount).tracked().run();
+    let status = h.router.get_withdrawal_status(&withdrawal.result.unwrap()).unwrap();
+    assert_eq!(status.status, WithdrawalProcessingStatus::Failed);
+    assert_eq!(status.error_message, String::from_str(&env, "Fault injected at Burning"));
+    assert_eq!(h.istsi.balance(&withdrawal.user), amount as i128);
+}
+
+#[test]
+fn test_untracked_post_state_faults_revert_the_whole_call() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+
+    h.fail_at(FaultStep::Minting);
+    let deposit = DepositScenario::new(&h, 50_000).reserves(1_000_000, 1_000_000).run();
+    assert_eq!(deposit.error(), Some(IntegrationError::ContractCallFailed));
+    assert_eq!(h.istsi.balance(&deposit.user), 0);
+
+    h.fail_at(FaultStep::ReserveProcessing);
+    let withdrawal = WithdrawalScenario::new(&h, 5_000_000_000_000).run();
+    assert_eq!(withdrawal.error(), Some(IntegrationError::ContractCallFailed));
+    assert_eq!(h.istsi.balance(&withdrawal.user), 5_000_000_000_000);
+}
@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as TestAddress, vec, Address, BytesN, Env};
+
+#[test]
+fn test_fee_schedule_and_treasury_collection() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasurer = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token = Address::generate(&env);
+    let destination = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+    client.set_user_role(&admin, &treasurer, &UserRole::Treasury);
+
+    // No schedule means no fee
+    assert_eq!(client.quote_fee(&user, &FeeOperation::Withdrawal, &1000u64), 0);
+
+    let tiers = vec![
+        &env,
+        FeeTier { min_kyc_tier: 0, flat_fee: 10, bps: 50 },
+        FeeTier { min_kyc_tier: 2, flat_fee: 0, bps: 10 },
+    ];
+    client.set_fee_schedule(&admin, &FeeOperation::Withdrawal, &tiers);
+    assert_eq!(client.get_fee_schedule(&FeeOperation::Withdrawal).len(), 2);
+
+    // Unverified users fall into the base tier: 10 flat + 0.5%
+    assert_eq!(client.quote_fee(&user, &FeeOperation::Withdrawal, &1000u64), 15);
+
+    assert_eq!(client.get_fee_destination(&token), admin);
+    client.set_fee_destination(&admin, &token, &destination);
+    assert_eq!(client.get_fee_destination(&token), destination);
+
+    // Nothing accrued yet
+    assert_eq!(client.get_fee_treasury(&token).accrued, 0);
+    assert_eq!(client.collect_fees(&treasurer, &token), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_collect_fees_requires_treasury_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+
+    let operator = Address::generate(&env);
+    client.set_user_role(&admin, &operator, &UserRole::Operator);
+    client.collect_fees(&operator, &Address::generate(&env));
+}
+
+#[test]
+fn test_operation_fee_collection_reports_failed_transfers() {
+    use crate::testing::{MockContract, TestHarness};
+
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    // The fee transfer is authorized by the user below the router's frame
+    env.mock_all_auths_allowing_non_root_auth();
+    let user = h.user(2);
+    h.istsi.mint(&user, &1_000i128);
+    let correlation_id = BytesN::from_array(&env, &[7u8; 32]);
+
+    let collected = env.as_contract(&h.router.address, || {
+        IntegrationRouter::collect_operation_fee(&env, &user, &h.istsi.address, 400, &FeeOperation::Withdrawal, &correlation_id)
+    });
+    assert!(collected.0);
+    assert_eq!(h.istsi.balance(&user), 600);
+    assert_eq!(h.istsi.balance(&h.router.address), 400);
+    assert_eq!(h.router.get_fee_treasury(&h.istsi.address).accrued, 400);
+
+    // A failed transfer accrues nothing and is reported to the workflow
+    h.fail(MockContract::IstsiToken, "transfer");
+    let collected = env.as_contract(&h.router.address, || {
+        IntegrationRouter::collect_operation_fee(&env, &user, &h.istsi.address, 400, &FeeOperation::Withdrawal, &correlation_id)
+    });
+    assert!(!collected.0);
+    assert_eq!(h.router.get_fee_treasury(&h.istsi.address).accrued, 400);
+}
+
@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{MockKycRegistry, MockToken, MockTokenClient};
+use soroban_sdk::{
+    testutils::{Address as TestAddress, Ledger},
+    Address, BytesN, Env
+};
+
+fn setup(env: &Env) -> (IntegrationRouterClient, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &env.register(MockKycRegistry, ()),
+        &env.register(MockToken, ()),
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+
+    (client, admin)
+}
+
+#[test]
+fn test_freeze_blocks_workflows_until_unfrozen() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let reason = String::from_str(&env, "Court order 2024-117");
+
+    // Operators cannot freeze addresses
+    let operator = Address::generate(&env);
+    client.set_user_role(&admin, &operator, &UserRole::Operator);
+    assert!(client.try_freeze_address(&operator, &user, &reason, &0u64).is_err());
+
+    let officer = Address::generate(&env);
+    client.set_user_role(&admin, &officer, &UserRole::ComplianceOfficer);
+    client.freeze_address(&officer, &user, &reason, &0u64);
+
+    let record = client.get_freeze_status(&user).unwrap();
+    assert_eq!(record.reason, reason);
+    assert_eq!(record.frozen_by, officer);
+    assert_eq!(record.expires_at, None);
+    assert!(client.is_address_frozen(&user));
+    assert_eq!(client.get_frozen_addresses().len(), 1);
+
+    // The freeze is mirrored into the token so direct transfers are blocked too
+    let token = MockTokenClient::new(&env, &client.get_config().istsi_token);
+    assert!(token.is_address_frozen(&user));
+
+    let deposit = client.try_execute_bitcoin_deposit(
+        &admin,
+        &user,
+        &1_000u64,
+        &BytesN::from_array(&env, &[3u8; 32]),
+        &6u32,
+    );
+    assert_eq!(deposit, Err(Ok(IntegrationError::ComplianceCheckFailed.into())));
+
+    let withdrawal = client.try_execute_token_withdrawal(
+        &admin,
+        &user,
+        &1_000u64,
+        &String::from_str(&env, "bc1qfrozen"),
+    );
+    assert_eq!(withdrawal, Err(Ok(IntegrationError::ComplianceCheckFailed.into())));
+
+    let exchange = client.try_execute_cross_token_exchange(
+        &user,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &1_000u64,
+        &100u64,
+    );
+    assert_eq!(exchange, Err(Ok(IntegrationError::ComplianceCheckFailed)));
+
+    client.unfreeze_address(&officer, &user);
+    assert!(!client.is_address_frozen(&user));
+    assert!(!token.is_address_frozen(&user));
+    assert_eq!(client.get_frozen_addresses().len(), 0);
+    assert_eq!(
+        client.try_unfreeze_address(&officer, &user),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
+
+#[test]
+fn test_freeze_expires_automatically() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+
+    client.freeze_address(&admin, &user, &String::from_str(&env, "Pending review"), &3600u64);
+    assert_eq!(client.get_freeze_status(&user).unwrap().expires_at, Some(1_003_600));
+    assert!(client.is_address_frozen(&user));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 3600;
+    });
+    assert!(!client.is_address_frozen(&user));
+    assert!(client.get_freeze_status(&user).is_none());
+    assert_eq!(client.get_frozen_addresses().len(), 0);
+}
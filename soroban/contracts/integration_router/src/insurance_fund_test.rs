@@ -0,0 +1,193 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as TestAddress, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env
+};
+
+/// Minimal KYC registry approving every exchange
+#[contract]
+pub struct MockInsuranceKycRegistry;
+
+#[contractimpl]
+impl MockInsuranceKycRegistry {
+    pub fn verify_ic(_env: Env, _user: String, _operation: String, _amount: String) -> bool {
+        true
+    }
+
+    pub fn reg_event(_env: Env, _user: String, _event: String, _amount: String, _a: String, _b: String) -> bool {
+        true
+    }
+}
+
+/// Reserve manager reporting a settable reserve position
+#[contract]
+pub struct MockInsuranceReserve;
+
+#[contractimpl]
+impl MockInsuranceReserve {
+    pub fn set_position(env: Env, reserves: u64, supply: u64) {
+        env.storage().instance().set(&symbol_short!("reserves"), &reserves);
+        env.storage().instance().set(&symbol_short!("supply"), &supply);
+    }
+
+    pub fn get_total_reserves(env: Env) -> u64 {
+        env.storage().instance().get(&symbol_short!("reserves")).unwrap_or(0)
+    }
+
+    pub fn get_total_token_supply(env: Env) -> u64 {
+        env.storage().instance().get(&symbol_short!("supply")).unwrap_or(0)
+    }
+
+    pub fn set_locked(env: Env, locked: bool) {
+        env.storage().instance().set(&symbol_short!("locked"), &locked);
+    }
+
+    pub fn update_token_supply(env: Env, _caller: Address, new_supply: u64) {
+        if env.storage().instance().get(&symbol_short!("locked")).unwrap_or(false) {
+            panic_with_error!(&env, IntegrationError::Unauthorized);
+        }
+        env.storage().instance().set(&symbol_short!("supply"), &new_supply);
+    }
+}
+
+struct Setup<'a> {
+    client: IntegrationRouterClient<'a>,
+    reserve: MockInsuranceReserveClient<'a>,
+    admin: Address,
+    istsi_token: Address,
+    fungible_token: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+    let reserve_id = env.register(MockInsuranceReserve, ());
+
+    let issuer = Address::generate(env);
+    let istsi_token = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    let fungible_token = env.register_stellar_asset_contract_v2(issuer).address();
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &env.register(MockInsuranceKycRegistry, ()),
+        &istsi_token,
+        &fungible_token,
+        &reserve_id,
+    );
+
+    Setup { client, reserve: MockInsuranceReserveClient::new(env, &reserve_id), admin, istsi_token, fungible_token }
+}
+
+/// Fund the iSTSi insurance fund through a timelocked top-up
+fn top_up(env: &Env, s: &Setup, amount: u64) -> InsuranceFund {
+    StellarAssetClient::new(env, &s.istsi_token).mint(&s.admin, &(amount as i128));
+    let pending = s.client.propose_insurance_transfer(&s.admin, &InsuranceTransferKind::TopUp, &s.istsi_token, &amount, &s.admin);
+    env.ledger().with_mut(|li| {
+        li.timestamp = pending.effective_at;
+    });
+    s.client.execute_insurance_transfer(&s.admin)
+}
+
+#[test]
+fn test_fund_collects_fee_share_and_moves_only_after_timelock() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_set_insurance_config(&s.admin, &InsuranceConfig { contribution_bps: 10001, auto_cover_max_sats: 0, target_balance: 0 }),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    s.client.set_insurance_config(&s.admin, &InsuranceConfig { contribution_bps: 5000, auto_cover_max_sats: 0, target_balance: 1_000 });
+
+    // Half of the 300 exchange fee goes to the fund, half to the treasury
+    s.client.configure_oracle(&s.admin, &s.istsi_token, &s.fungible_token, &Address::generate(&env), &300u64, &500u64, &10000u64);
+    let maker = Address::generate(&env);
+    StellarAssetClient::new(&env, &s.fungible_token).mint(&maker, &800_000i128);
+    s.client.set_market_maker(&s.admin, &maker, &true);
+    s.client.deposit_liquidity(&maker, &s.fungible_token, &800_000u64);
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &s.istsi_token).mint(&user, &100_000i128);
+    s.client.execute_cross_token_exchange(&user, &s.istsi_token, &s.fungible_token, &100_000u64, &500u64);
+
+    assert_eq!(s.client.get_insurance_fund(&s.istsi_token).balance, 150);
+    assert_eq!(s.client.get_fee_treasury(&s.istsi_token).accrued, 150);
+    assert_eq!(s.client.get_insurance_fund_health().status, HealthStatus::Critical);
+
+    // Withdrawals cannot exceed the fund and wait out the timelock
+    let recipient = Address::generate(&env);
+    assert_eq!(
+        s.client.try_propose_insurance_transfer(&s.admin, &InsuranceTransferKind::Withdrawal, &s.istsi_token, &151u64, &recipient),
+        Err(Ok(IntegrationError::InsufficientReserves))
+    );
+    s.client.propose_insurance_transfer(&s.admin, &InsuranceTransferKind::Withdrawal, &s.istsi_token, &100u64, &recipient);
+    assert_eq!(
+        s.client.try_propose_insurance_transfer(&s.admin, &InsuranceTransferKind::TopUp, &s.istsi_token, &100u64, &s.admin),
+        Err(Ok(IntegrationError::DuplicateOperation))
+    );
+    assert_eq!(s.client.try_execute_insurance_transfer(&s.admin), Err(Ok(IntegrationError::InvalidOperationState)));
+    s.client.cancel_insurance_transfer(&s.admin);
+    assert_eq!(s.client.get_pending_insurance_transfer(), None);
+
+    let fund = top_up(&env, &s, 450);
+    assert_eq!(fund.balance, 600);
+    assert_eq!(fund.total_contributed, 600);
+    assert_eq!(TokenClient::new(&env, &s.istsi_token).balance(&s.admin), 0);
+    assert_eq!(s.client.get_insurance_fund_health().status, HealthStatus::Warning);
+}
+
+#[test]
+fn test_reconciliation_shortfalls_are_retired_once_below_threshold() {
+    let env = Env::default();
+    let s = setup(&env);
+    let istsi = TokenClient::new(&env, &s.istsi_token);
+
+    s.client.set_insurance_config(&s.admin, &InsuranceConfig { contribution_bps: 0, auto_cover_max_sats: 2_500_000, target_balance: 0 });
+    top_up(&env, &s, 300_000_000_000_000);
+
+    // A 2M sat shortfall is covered by burning the fund's iSTSi and retiring the supply
+    s.reserve.set_position(&98_000_000u64, &100_000_000u64);
+    s.client.execute_reconciliation_check(&s.admin);
+    assert_eq!(istsi.balance(&s.client.address), 100_000_000_000_000);
+    assert_eq!(istsi.balance(&s.admin), 0);
+    assert_eq!(s.reserve.get_total_token_supply(), 98_000_000);
+    let fund = s.client.get_insurance_fund(&s.istsi_token);
+    assert_eq!((fund.balance, fund.total_drawn, fund.covered_shortfall_sats), (100_000_000_000_000, 200_000_000_000_000, 2_000_000));
+
+    // The retired shortfall is gone, so nothing more is drawn
+    s.client.execute_reconciliation_check(&s.admin);
+    assert_eq!(s.client.get_insurance_fund(&s.istsi_token).balance, 100_000_000_000_000);
+
+    // A reserve manager refusing the supply update leaves the fund untouched
+    top_up(&env, &s, 100_000_000_000_000);
+    s.reserve.set_position(&96_000_000u64, &98_000_000u64);
+    s.reserve.set_locked(&true);
+    s.client.execute_reconciliation_check(&s.admin);
+    assert_eq!(s.client.get_insurance_fund(&s.istsi_token).balance, 200_000_000_000_000);
+    assert_eq!(istsi.balance(&s.client.address), 200_000_000_000_000);
+    s.reserve.set_locked(&false);
+    s.client.execute_reconciliation_check(&s.admin);
+    assert_eq!(istsi.balance(&s.client.address), 0);
+    assert_eq!(s.reserve.get_total_token_supply(), 96_000_000);
+
+    // A shortfall larger than the fund is not covered
+    s.reserve.set_position(&94_000_000u64, &96_000_000u64);
+    s.client.execute_reconciliation_check(&s.admin);
+    assert_eq!(s.reserve.get_total_token_supply(), 96_000_000);
+
+    // Shortfalls over the threshold are left to the alerts
+    top_up(&env, &s, 900_000_000_000_000);
+    s.reserve.set_position(&97_000_000u64, &100_000_000u64);
+    s.client.execute_reconciliation_check(&s.admin);
+    assert_eq!(s.client.get_insurance_fund(&s.istsi_token).balance, 900_000_000_000_000);
+    assert_eq!(s.reserve.get_total_token_supply(), 100_000_000);
+}
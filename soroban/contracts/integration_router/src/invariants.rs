@@ -0,0 +1,105 @@
+//! Reserve and supply invariants
+//!
+//! Definitions of the properties that must hold between the router, the
+//! reserve manager and the iSTSi token, with pure evaluation helpers. The
+//! router gathers the inputs from contract state; tests call the helpers
+//! directly or go through `check_invariants`.
+
+use soroban_sdk::{contracttype, BytesN, Env, Map, String, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Invariant {
+    ReserveBacking,            // Reserves cover the supply at the required ratio
+    SupplyConsistency,         // Holder balances sum to the supply recorded by the reserve manager
+    OperationStatusExclusive,  // Status-list slots agree with their operations' listings
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvariantViolation {
+    pub invariant: Invariant,
+    pub message: String,
+    pub expected: i128,        // Bound the invariant requires
+    pub actual: i128,          // Value observed in contract state
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvariantReport {
+    pub passed: bool,
+    pub checked: Vec<Invariant>,
+    pub violations: Vec<InvariantViolation>,
+    pub checked_at: u64,
+}
+
+/// Reserves must back the supply at `min_ratio_bps`
+///
+/// `None` reserves or supply means the reserve manager could not be read,
+/// which is reported as a violation rather than assumed healthy.
+pub fn check_reserve_backing(
+    env: &Env,
+    reserves: Option<u64>,
+    supply: Option<u64>,
+    min_ratio_bps: u64
+) -> Option<InvariantViolation> {
+    let (Some(reserves), Some(supply)) = (reserves, supply) else {
+        return Some(InvariantViolation {
+            invariant: Invariant::ReserveBacking,
+            message: String::from_str(env, "Reserve manager unavailable"),
+            expected: min_ratio_bps as i128,
+            actual: 0,
+        });
+    };
+
+    let required = supply as i128 * min_ratio_bps as i128;
+    if (reserves as i128) * 10000 >= required {
+        return None;
+    }
+    Some(InvariantViolation {
+        invariant: Invariant::ReserveBacking,
+        message: String::from_str(env, "Reserves below the required backing ratio"),
+        expected: required / 10000,
+        actual: reserves as i128,
+    })
+}
+
+/// Holder balances must sum to the expected supply
+pub fn check_supply_consistency(env: &Env, balance_sum: i128, expected_supply: i128) -> Option<InvariantViolation> {
+    if balance_sum == expected_supply {
+        return None;
+    }
+    Some(InvariantViolation {
+        invariant: Invariant::SupplyConsistency,
+        message: String::from_str(env, "Holder balances do not match the recorded supply"),
+        expected: expected_supply,
+        actual: balance_sum,
+    })
+}
+
+/// Every sampled status-list slot must hold an operation whose listing
+/// points back at that slot, and no operation may fill two slots
+///
+/// `sampled` pairs each operation ID found in a slot with whether its
+/// listing entry names that same list, day and slot. A stale slot left behind
+/// by an interrupted move shows up as an unmatched listing or a duplicate.
+pub fn check_operation_status_exclusive(env: &Env, sampled: &Vec<(BytesN<32>, bool)>) -> Option<InvariantViolation> {
+    let mut seen: Map<BytesN<32>, ()> = Map::new(env);
+    let mut misfiled = 0i128;
+    for (operation_id, listed_here) in sampled.iter() {
+        if !listed_here || seen.contains_key(operation_id.clone()) {
+            misfiled += 1;
+        }
+        seen.set(operation_id, ());
+    }
+
+    if misfiled == 0 {
+        return None;
+    }
+    Some(InvariantViolation {
+        invariant: Invariant::OperationStatusExclusive,
+        message: String::from_str(env, "Operations recorded under more than one status"),
+        expected: 0,
+        actual: misfiled,
+    })
+}
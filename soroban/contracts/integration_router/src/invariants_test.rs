@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{DepositScenario, TestHarness, HARNESS_RESERVES_SATS};
+use soroban_sdk::{testutils::Address as TestAddress, Address, Env};
+
+#[test]
+fn test_invariant_helpers_flag_violations() {
+    let env = Env::default();
+
+    assert_eq!(invariants::check_reserve_backing(&env, Some(100), Some(100), 10000), None);
+    assert_eq!(invariants::check_reserve_backing(&env, Some(0), Some(0), 10000), None);
+    let violation = invariants::check_reserve_backing(&env, Some(99), Some(100), 10000).unwrap();
+    assert_eq!((violation.expected, violation.actual), (100, 99));
+    assert!(invariants::check_reserve_backing(&env, None, Some(100), 10000).is_some());
+
+    assert_eq!(invariants::check_supply_consistency(&env, 500, 500), None);
+    assert_eq!(invariants::check_supply_consistency(&env, 499, 500).unwrap().invariant, Invariant::SupplyConsistency);
+
+    let a = BytesN::from_array(&env, &[1u8; 32]);
+    let b = BytesN::from_array(&env, &[2u8; 32]);
+    let consistent = vec![&env, (a.clone(), true), (b.clone(), true)];
+    assert_eq!(invariants::check_operation_status_exclusive(&env, &consistent), None);
+    let stale = vec![&env, (a.clone(), true), (b.clone(), true), (b.clone(), false), (a, true)];
+    assert_eq!(invariants::check_operation_status_exclusive(&env, &stale).unwrap().actual, 2);
+}
+
+#[test]
+fn test_reserve_backing_holds_exactly_when_ratio_meets_the_floor() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    h.router.set_reserve_guardrails(&h.admin, &ReserveGuardrails { min_ratio_bps: 10500, warning_band_bps: 0 });
+
+    // Positions on both sides of the 105% floor, including the exact boundary
+    let positions = [
+        (105_000u64, 100_000u64, true),
+        (104_999, 100_000, false),
+        (21, 20, true),
+        (20, 20, false),
+        (1_050_001, 1_000_000, true),
+        (0, 1, false),
+        (0, 0, true),
+    ];
+    for (reserves, supply, passes) in positions {
+        h.reserve.set_position(&reserves, &supply);
+        assert_eq!(h.router.check_invariants(&Vec::new(&env)).passed, passes);
+    }
+}
+
+#[test]
+fn test_runtime_assertions_abort_workflows_on_misfiled_operations() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    h.router.set_invariant_assertions(&h.admin, &true);
+
+    let deposit = DepositScenario::new(&h, 50_000).run();
+    let operation_id = deposit.result.unwrap();
+    assert!(h.router.check_invariants(&Vec::new(&env)).passed);
+
+    // Point the completed operation's listing away from the slot that holds it
+    env.as_contract(&h.router.address, || {
+        let misfiled = OperationListEntry { list: OperationList::Failed, day: 0, slot: 0 };
+        env.storage().persistent().set(&WorkflowKey::OperationListing(operation_id.clone()), &misfiled);
+    });
+    let report = h.router.check_invariants(&Vec::new(&env));
+    assert_eq!(report.violations.get_unchecked(0).invariant, Invariant::OperationStatusExclusive);
+
+    // The next workflow is rolled back rather than committed on top of it
+    let blocked = DepositScenario::new(&h, 50_000).btc_tx_hash(BytesN::from_array(&env, &[8u8; 32])).run();
+    assert_eq!(blocked.error(), Some(IntegrationError::InvalidOperationState));
+    assert_eq!(h.istsi.balance(&blocked.user), 0);
+
+    h.router.set_invariant_assertions(&h.admin, &false);
+    let allowed = DepositScenario::new(&h, 50_000).btc_tx_hash(BytesN::from_array(&env, &[9u8; 32])).run();
+    assert!(allowed.succeeded());
+
+    // Holder lists are bounded
+    let mut holders = Vec::new(&env);
+    for _ in 0..=MAX_INVARIANT_HOLDERS {
+        holders.push_back(Address::generate(&env));
+    }
+    assert!(h.router.try_check_invariants(&holders).is_err());
+}
+
+#[test]
+fn test_violations_raise_alerts_and_supply_is_checked_against_holders() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+
+    let report = h.assert_invariants(&Vec::new(&env));
+    assert_eq!(report.checked, vec![&env, Invariant::ReserveBacking, Invariant::OperationStatusExclusive]);
+
+    // Holder balances must add up to the reserve manager's supply in iSTSi
+    let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+    let holders = vec![&env, alice.clone(), bob.clone()];
+    let supply_istsi = h.router.convert_sats_to_istsi(&HARNESS_RESERVES_SATS) as i128;
+    h.istsi.mint(&alice, &(supply_istsi - 1_000));
+    h.istsi.mint(&bob, &1_000);
+    assert_eq!(h.assert_invariants(&holders).checked.len(), 3);
+    h.istsi.transfer(&alice, &bob, &5_000);
+    h.assert_invariants(&holders);
+
+    h.istsi.mint(&bob, &1);
+    h.reserve.set_position(&(HARNESS_RESERVES_SATS - 1), &HARNESS_RESERVES_SATS);
+    assert!(h.router.try_run_invariant_check(&Address::generate(&env), &holders).is_err());
+    let report = h.router.run_invariant_check(&h.operator, &holders);
+    assert!(!report.passed);
+    assert_eq!(report.violations.len(), 2);
+    assert_eq!(h.router.get_system_alerts().len(), 2);
+
+    assert!(h.router.try_set_invariant_assertions(&h.operator, &true).is_err());
+    h.router.set_invariant_assertions(&h.admin, &true);
+    assert!(h.router.is_invariant_assertions_enabled());
+}
@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::TestHarness;
+use soroban_sdk::{vec, Env};
+
+// The harness has no reserves, so deposits that clear the rules fail later in the workflow
+fn restricted(h: &TestHarness, user: &Address, btc_amount: u64, seed: u8) -> bool {
+    let btc_tx_hash = BytesN::from_array(&h.env, &[seed; 32]);
+    h.router.try_execute_bitcoin_deposit(&h.operator, user, &btc_amount, &btc_tx_hash, &6)
+        == Err(Ok(soroban_sdk::Error::from_contract_error(IntegrationError::JurisdictionRestricted as u32)))
+}
+
+#[test]
+fn test_rules_are_validated_and_versioned() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let us = String::from_str(&env, "US");
+    let bands = vec![
+        &env,
+        JurisdictionBand { min_amount: 1_000_000, outcome: JurisdictionOutcome::ExtraVerification },
+        JurisdictionBand { min_amount: 5_000_000, outcome: JurisdictionOutcome::Deny },
+    ];
+
+    assert!(h.router.try_set_jurisdiction_rules(&h.operator, &us, &3, &bands).is_err());
+    assert_eq!(
+        h.router.try_set_jurisdiction_rules(&h.admin, &us, &9, &bands),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    let unordered = vec![&env, bands.get_unchecked(1), bands.get_unchecked(0)];
+    assert_eq!(
+        h.router.try_set_jurisdiction_rules(&h.admin, &us, &3, &unordered),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    assert_eq!(h.router.set_jurisdiction_rules(&h.admin, &us, &3, &bands), 1);
+    assert_eq!(h.router.evaluate_jurisdiction(&us, &3, &999_999), JurisdictionOutcome::Allow);
+    assert_eq!(h.router.evaluate_jurisdiction(&us, &3, &1_000_000), JurisdictionOutcome::ExtraVerification);
+    assert_eq!(h.router.evaluate_jurisdiction(&us, &3, &9_000_000), JurisdictionOutcome::Deny);
+    assert_eq!(h.router.evaluate_jurisdiction(&us, &4, &9_000_000), JurisdictionOutcome::Allow);
+
+    assert_eq!(h.router.set_jurisdiction_rules(&h.admin, &us, &3, &Vec::new(&env)), 2);
+    assert_eq!(h.router.evaluate_jurisdiction(&us, &3, &9_000_000), JurisdictionOutcome::Allow);
+    let first = h.router.get_jurisdiction_rules_version(&us, &3, &1).unwrap();
+    assert_eq!((first.bands, first.updated_by), (bands, h.admin.clone()));
+    assert_eq!(h.router.get_jurisdiction_rules(&us, &3).unwrap().version, 2);
+}
+
+#[test]
+fn test_deposits_follow_the_users_jurisdiction() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let us = String::from_str(&env, "US");
+    h.router.set_jurisdiction_rules(&h.admin, &us, &3, &vec![
+        &env,
+        JurisdictionBand { min_amount: 1_000_000, outcome: JurisdictionOutcome::ExtraVerification },
+        JurisdictionBand { min_amount: 5_000_000, outcome: JurisdictionOutcome::Deny },
+    ]);
+
+    // The registry reports each user's effective tier alongside its policy
+    for tier in [2, 3] {
+        h.kyc.set_tier_policy(&tier, &TierPolicy {
+            daily_deposit_limit: 100_000_000,
+            monthly_deposit_limit: 1_000_000_000,
+            daily_withdrawal_limit: 100_000_000,
+            monthly_withdrawal_limit: 1_000_000_000,
+            daily_exchange_limit: 100_000_000,
+            monthly_exchange_limit: 1_000_000_000,
+            min_confirmations: 6,
+            enhanced_verification_limit: 100_000_000,
+            version: 1,
+            updated_at: 0,
+            updated_by: h.admin.clone(),
+        });
+    }
+    let verified = h.user(2);
+    h.kyc.set_jurisdiction(&verified, &us);
+    let enhanced = h.user(3);
+    h.kyc.set_jurisdiction(&enhanced, &us);
+    let elsewhere = h.user(2);
+    h.kyc.set_jurisdiction(&elsewhere, &String::from_str(&env, "SG"));
+
+    assert!(!restricted(&h, &verified, 500_000, 1));
+    assert!(restricted(&h, &verified, 2_000_000, 2));
+    assert!(!restricted(&h, &enhanced, 2_000_000, 3));
+    assert!(restricted(&h, &enhanced, 6_000_000, 4));
+    assert!(!restricted(&h, &elsewhere, 6_000_000, 5));
+    assert!(!restricted(&h, &h.user(2), 6_000_000, 6));
+
+    // Lifting the rules lets the same deposit through
+    h.router.set_jurisdiction_rules(&h.admin, &us, &3, &Vec::new(&env));
+    assert!(!restricted(&h, &verified, 6_000_000, 7));
+}
@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::Ledger, Env};
+
+#[test]
+fn test_limit_edits_are_audited_per_user() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    assert_eq!(h.router.get_deposit_limits(&user).daily_limit, 100_000_000);
+
+    assert!(h.router.try_set_deposit_limits(&h.operator, &user, &1_000, &10_000).is_err());
+    assert_eq!(
+        h.router.try_set_deposit_limits(&h.admin, &user, &20_000, &10_000),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert_eq!(h.router.get_limit_change_count(&user), 0);
+
+    h.router.set_deposit_limits(&h.admin, &user, &1_000, &10_000);
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 60);
+    h.router.set_deposit_limits(&h.admin, &user, &2_000, &10_000);
+    h.router.set_withdrawal_limits(&h.admin, &user, &5_000, &50_000, &4_000);
+    h.router.set_exchange_limits(&h.admin, &user, &7_000, &70_000, &6_000);
+    assert_eq!(h.router.get_withdrawal_limits(&user).enhanced_verification_limit, 4_000);
+
+    let changes = h.router.get_limit_changes(&user, &1, &10);
+    assert_eq!(changes.len(), 3);
+    assert_eq!(
+        changes.get_unchecked(0),
+        LimitChange {
+            kind: LimitKind::Deposit,
+            changed_by: h.admin.clone(),
+            changed_at: HARNESS_START_TIME + 60,
+            old_daily_limit: 1_000,
+            new_daily_limit: 2_000,
+            old_monthly_limit: 10_000,
+            new_monthly_limit: 10_000,
+        }
+    );
+    assert_eq!(changes.get_unchecked(1).kind, LimitKind::Withdrawal);
+    assert_eq!(changes.get_unchecked(2).old_daily_limit, 1_000_000);
+
+    // Other users have their own log
+    assert_eq!(h.router.get_limit_change_count(&user), 4);
+    assert_eq!(h.router.get_limit_changes(&h.user(1), &0, &10).len(), 0);
+}
+
+#[test]
+fn test_windows_reset_lazily_and_by_keeper_sweep() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    assert_eq!(h.router.reset_expired_limits(), 0);
+
+    let user = h.user(2);
+    env.as_contract(&h.router.address, || {
+        IntegrationRouter::record_deposit_limit_usage(&env, &user, 100_000);
+        IntegrationRouter::record_withdrawal_limit_usage(&env, &user, 40_000);
+    });
+    let limits = h.router.get_deposit_limits(&user);
+    assert_eq!((limits.daily_used, limits.monthly_used), (100_000, 100_000));
+
+    // Nothing has elapsed yet
+    assert_eq!(h.router.reset_expired_limits(), 0);
+
+    // Reads see the rolled-over daily window before anything is written
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + LIMIT_DAILY_WINDOW);
+    let limits = h.router.get_deposit_limits(&user);
+    assert_eq!((limits.daily_used, limits.monthly_used), (0, 100_000));
+    assert_eq!(limits.last_reset_daily, HARNESS_START_TIME + LIMIT_DAILY_WINDOW);
+    let stored: DepositLimitInfo = env.as_contract(&h.router.address, || {
+        env.storage().persistent().get(&DataKey::DepositLimits(user.clone())).unwrap()
+    });
+    assert_eq!(stored.daily_used, 100_000);
+
+    // The keeper sweep persists the reset of both records
+    assert_eq!(h.router.reset_expired_limits(), 2);
+    assert_eq!(h.router.get_withdrawal_limits(&user).monthly_used, 40_000);
+    let stored: DepositLimitInfo = env.as_contract(&h.router.address, || {
+        env.storage().persistent().get(&DataKey::DepositLimits(user.clone())).unwrap()
+    });
+    assert_eq!((stored.daily_used, stored.monthly_used), (0, 100_000));
+    assert_eq!(h.router.reset_expired_limits(), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + LIMIT_MONTHLY_WINDOW);
+    assert_eq!(h.router.get_deposit_limits(&user).monthly_used, 0);
+}
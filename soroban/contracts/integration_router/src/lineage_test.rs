@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::TestHarness;
+use soroban_sdk::{testutils::Events, vec, Env};
+
+fn event(h: &TestHarness, parent_correlation_id: Option<BytesN<32>>) -> IntegrationEvent {
+    IntegrationEvent {
+        event_type: String::from_str(&h.env, "ReconciliationNote"),
+        user: h.operator.clone(),
+        data1: 0,
+        data2: 0,
+        data3: 0,
+        address1: h.operator.clone(),
+        address2: h.operator.clone(),
+        hash_data: BytesN::from_array(&h.env, &[0u8; 32]),
+        text_data: String::from_str(&h.env, ""),
+        timestamp: h.env.ledger().timestamp(),
+        correlation_id: BytesN::from_array(&h.env, &[0u8; 32]),
+        parent_correlation_id,
+    }
+}
+
+fn tracked_deposit(h: &TestHarness, seed: u8) -> BytesN<32> {
+    let user = h.user(2);
+    h.router.execute_btc_deposit_tracked(&h.operator, &user, &100_000, &BytesN::from_array(&h.env, &[seed; 32]), &6)
+}
+
+#[test]
+fn test_contract_calls_are_linked_under_the_workflow() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    assert_eq!(h.router.get_operation_trace(&BytesN::from_array(&env, &[1u8; 32])), None);
+
+    let operation_id = tracked_deposit(&h, 7);
+    let trace = h.router.get_operation_trace(&operation_id).unwrap();
+    assert_eq!(Some(trace.root_correlation_id.clone()), h.router.get_operation_lineage(&operation_id));
+    assert!(!trace.truncated);
+
+    // Every contract call of the completed deposit and its event, oldest first
+    assert_eq!(trace.nodes.len(), 6);
+    let first = trace.nodes.get_unchecked(0);
+    assert_eq!(first.depth, 1);
+    assert_eq!(first.parent_correlation_id, Some(trace.root_correlation_id.clone()));
+    assert_eq!(first.event.event_type, String::from_str(&env, "ContractCall"));
+    assert_eq!(first.event.text_data, String::from_str(&env, "verify_ic"));
+    assert_eq!(first.event.address1, h.kyc.address);
+    let mut calls = Vec::new(&env);
+    trace.nodes.iter().take(5).for_each(|node| calls.push_back(node.event.text_data));
+    assert_eq!(calls, vec![
+        &env,
+        String::from_str(&env, "verify_ic"),
+        String::from_str(&env, "get_ratio"),
+        String::from_str(&env, "reg_dep"),
+        String::from_str(&env, "int_mint"),
+        String::from_str(&env, "reg_event"),
+    ]);
+    assert_eq!(trace.nodes.get_unchecked(5).event.event_type, String::from_str(&env, "BitcoinDeposit"));
+    assert_eq!(h.router.get_lineage_children(&trace.root_correlation_id).len(), 6);
+
+    // The lineage closes with the workflow, and each workflow gets its own root
+    let unlinked = h.router.emit_integration_event(&h.operator, &event(&h, None));
+    let history = h.router.get_event_history(&EventFilter::ByCorrelationId(unlinked), &1);
+    assert_eq!(history.get_unchecked(0).parent_correlation_id, None);
+    let second = h.router.get_operation_trace(&tracked_deposit(&h, 8)).unwrap();
+    assert_ne!(second.root_correlation_id, trace.root_correlation_id);
+    assert_eq!(second.nodes.len(), 6);
+}
+
+#[test]
+fn test_emitters_can_extend_the_tree_below_any_event() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let operation_id = tracked_deposit(&h, 7);
+    let trace = h.router.get_operation_trace(&operation_id).unwrap();
+    let kyc_call = trace.nodes.get_unchecked(0).correlation_id;
+
+    let note = h.router.emit_integration_event(&h.operator, &event(&h, Some(kyc_call.clone())));
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(topics.len(), 4);
+    let reply = h.router.emit_integration_event(&h.operator, &event(&h, Some(note.clone())));
+
+    // Depth-first: the KYC call, its note and the note's reply, then the later calls
+    let trace = h.router.get_operation_trace(&operation_id).unwrap();
+    assert_eq!(trace.nodes.len(), 8);
+    let mut depths = Vec::new(&env);
+    trace.nodes.iter().for_each(|node| depths.push_back(node.depth));
+    assert_eq!(depths, vec![&env, 1u32, 2, 3, 1, 1, 1, 1, 1]);
+    assert_eq!(trace.nodes.get_unchecked(1).correlation_id, note);
+    assert_eq!(trace.nodes.get_unchecked(1).parent_correlation_id, Some(kyc_call));
+    assert_eq!(trace.nodes.get_unchecked(2).correlation_id, reply.clone());
+
+    // Pruned events drop out of the trace but their children are still reached
+    h.router.set_event_retention_policy(
+        &h.admin,
+        &String::from_str(&env, "ReconciliationNote"),
+        &EventRetentionPolicy { max_events: 1, max_age_seconds: 3_600, importance: EventImportance::Standard }
+    );
+    let trace = h.router.get_operation_trace(&operation_id).unwrap();
+    assert_eq!(trace.nodes.len(), 7);
+    assert_eq!(trace.nodes.get_unchecked(1).correlation_id, reply);
+    assert_eq!(trace.nodes.get_unchecked(1).parent_correlation_id, Some(note));
+}
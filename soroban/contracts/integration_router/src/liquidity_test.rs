@@ -0,0 +1,197 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as TestAddress, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env
+};
+
+/// Minimal KYC registry approving every exchange
+#[contract]
+pub struct MockLiquidityKycRegistry;
+
+#[contractimpl]
+impl MockLiquidityKycRegistry {
+    pub fn verify_ic(_env: Env, _user: String, _operation: String, _amount: String) -> bool {
+        true
+    }
+
+    pub fn reg_event(_env: Env, _user: String, _event: String, _amount: String, _a: String, _b: String) -> bool {
+        true
+    }
+}
+
+struct Setup<'a> {
+    contract_id: Address,
+    client: IntegrationRouterClient<'a>,
+    admin: Address,
+    user: Address,
+    from_token: Address,
+    to_token: Address,
+    large_maker: Address,
+    small_maker: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+    let kyc_registry = env.register(MockLiquidityKycRegistry, ());
+
+    let issuer = Address::generate(env);
+    let from_token = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    let to_token = env.register_stellar_asset_contract_v2(issuer).address();
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &kyc_registry,
+        &from_token,
+        &to_token,
+        &Address::generate(env),
+    );
+    client.configure_oracle(&admin, &from_token, &to_token, &Address::generate(env), &300u64, &500u64, &10000u64);
+
+    let user = Address::generate(env);
+    StellarAssetClient::new(env, &from_token).mint(&user, &1_000_000i128);
+
+    // Two market makers supply the destination token 3:1
+    let large_maker = Address::generate(env);
+    let small_maker = Address::generate(env);
+    for (maker, amount) in [(&large_maker, 600_000u64), (&small_maker, 200_000u64)] {
+        StellarAssetClient::new(env, &to_token).mint(maker, &(amount as i128));
+        client.set_market_maker(&admin, maker, &true);
+        client.deposit_liquidity(maker, &to_token, &amount);
+    }
+
+    Setup { contract_id, client, admin, user, from_token, to_token, large_maker, small_maker }
+}
+
+#[test]
+fn test_market_makers_supply_exchange_inventory() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    // Unregistered makers and non-exchange tokens are rejected
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        s.client.try_deposit_liquidity(&outsider, &s.to_token, &1_000u64),
+        Err(Ok(IntegrationError::InsufficientPermissions))
+    );
+    assert_eq!(
+        s.client.try_deposit_liquidity(&s.large_maker, &Address::generate(&env), &1_000u64),
+        Err(Ok(IntegrationError::ContractNotFound))
+    );
+
+    let depth = s.client.get_inventory_depth(&s.to_token);
+    assert_eq!(depth.available, 800_000);
+    assert_eq!(depth.max_draw, 400_000);
+    assert_eq!(depth.provider_count, 2);
+
+    // Quotes price the trade against inventory depth
+    let quote = s.client.calculate_exchange_amount(&s.from_token, &s.to_token, &100_000u64, &500u64);
+    assert_eq!(quote.price_impact, 101_000 * 10000 / 800_000);
+
+    let exchange = s.client.execute_cross_token_exchange(&s.user, &s.from_token, &s.to_token, &100_000u64, &500u64);
+    assert_eq!(exchange.to_amount, 100_697);
+    assert_eq!(TokenClient::new(&env, &s.to_token).balance(&s.user), 100_697);
+
+    // The draw and the 99_700 proceeds are split pro rata; the rounding unit comes from the first maker
+    assert_eq!(s.client.get_liquidity_position(&s.large_maker, &s.to_token).amount, 600_000 - 75_523);
+    assert_eq!(s.client.get_liquidity_position(&s.small_maker, &s.to_token).amount, 200_000 - 25_174);
+    assert_eq!(s.client.get_liquidity_position(&s.large_maker, &s.from_token).amount, 74_775);
+    assert_eq!(s.client.get_liquidity_position(&s.small_maker, &s.from_token).amount, 24_925);
+
+    let to_inventory = s.client.get_token_inventory(&s.to_token);
+    assert_eq!(to_inventory.available, 800_000 - 100_697);
+    assert_eq!(to_inventory.total_drawn, 100_697);
+    assert_eq!(s.client.get_token_inventory(&s.from_token).total_credited, 99_700);
+}
+
+#[test]
+fn test_exchange_respects_utilization_limit() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_liquidity_config(&s.admin, &LiquidityConfig { max_draw_bps: 1000, min_inventory: 0 });
+    assert_eq!(s.client.get_inventory_depth(&s.to_token).max_draw, 80_000);
+
+    let exchange = s.client.execute_cross_token_exchange(&s.user, &s.from_token, &s.to_token, &100_000u64, &500u64);
+    assert_eq!(exchange.status, ExchangeStatus::RolledBack);
+    assert_eq!(TokenClient::new(&env, &s.from_token).balance(&s.user), 1_000_000);
+    assert_eq!(s.client.get_token_inventory(&s.to_token).available, 800_000);
+
+    // A floor keeps inventory in reserve regardless of the utilization share
+    s.client.set_liquidity_config(&s.admin, &LiquidityConfig { max_draw_bps: 10000, min_inventory: 750_000 });
+    assert_eq!(s.client.get_inventory_depth(&s.to_token).max_draw, 50_000);
+
+    assert_eq!(
+        s.client.try_set_liquidity_config(&s.admin, &LiquidityConfig { max_draw_bps: 0, min_inventory: 0 }),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
+
+#[test]
+fn test_market_maker_withdrawal_settles_proceeds() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.execute_cross_token_exchange(&s.user, &s.from_token, &s.to_token, &100_000u64, &500u64);
+
+    assert_eq!(
+        s.client.try_withdraw_liquidity(&s.small_maker, &s.to_token, &200_000u64),
+        Err(Ok(IntegrationError::InsufficientReserves))
+    );
+
+    // Revoked makers can still exit with their inventory and exchange proceeds
+    s.client.set_market_maker(&s.admin, &s.small_maker, &false);
+    let settled = s.client.settle_market_maker(&s.small_maker);
+    assert_eq!(settled.len(), 2);
+
+    assert_eq!(TokenClient::new(&env, &s.to_token).balance(&s.small_maker), 200_000 - 25_174);
+    assert_eq!(TokenClient::new(&env, &s.from_token).balance(&s.small_maker), 24_925);
+    assert_eq!(s.client.get_liquidity_position(&s.small_maker, &s.to_token).amount, 0);
+    assert_eq!(s.client.get_inventory_depth(&s.to_token).provider_count, 1);
+
+    s.client.withdraw_liquidity(&s.large_maker, &s.from_token, &74_775u64);
+    assert_eq!(TokenClient::new(&env, &s.from_token).balance(&s.large_maker), 74_775);
+
+    // Only the fee stays behind in the router
+    assert_eq!(TokenClient::new(&env, &s.from_token).balance(&s.contract_id), 300);
+    assert_eq!(s.client.get_token_inventory(&s.from_token).available, 0);
+}
+
+#[test]
+fn test_liquidity_provider_pool_is_capped() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    // Two makers are already in the pool; fill it to the cap
+    for _ in 2..MAX_LIQUIDITY_PROVIDERS {
+        let maker = Address::generate(&env);
+        StellarAssetClient::new(&env, &s.to_token).mint(&maker, &1_000i128);
+        s.client.set_market_maker(&s.admin, &maker, &true);
+        s.client.deposit_liquidity(&maker, &s.to_token, &1_000u64);
+    }
+    assert_eq!(s.client.get_inventory_depth(&s.to_token).provider_count, MAX_LIQUIDITY_PROVIDERS);
+
+    let late_maker = Address::generate(&env);
+    StellarAssetClient::new(&env, &s.to_token).mint(&late_maker, &1_000i128);
+    s.client.set_market_maker(&s.admin, &late_maker, &true);
+    assert_eq!(
+        s.client.try_deposit_liquidity(&late_maker, &s.to_token, &1_000u64),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    // Existing providers can still top up, and a full exit frees a slot
+    StellarAssetClient::new(&env, &s.to_token).mint(&s.small_maker, &1_000i128);
+    s.client.deposit_liquidity(&s.small_maker, &s.to_token, &1_000u64);
+    s.client.withdraw_liquidity(&s.small_maker, &s.to_token, &201_000u64);
+    s.client.deposit_liquidity(&late_maker, &s.to_token, &1_000u64);
+    assert_eq!(s.client.get_inventory_depth(&s.to_token).provider_count, MAX_LIQUIDITY_PROVIDERS);
+}
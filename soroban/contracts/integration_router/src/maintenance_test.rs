@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as TestAddress, Ledger},
+    Address, BytesN, Env
+};
+
+#[test]
+fn test_maintenance_window_whitelists_workflows() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+
+    let message = String::from_str(&env, "Reserve migration");
+    let operator = Address::generate(&env);
+    client.set_user_role(&admin, &operator, &UserRole::Operator);
+    let withdrawals_only = vec![&env, PauseWorkflow::Withdrawals];
+    assert!(client.try_schedule_maintenance(&operator, &1_000_100, &1_000_200, &withdrawals_only, &message).is_err());
+    assert_eq!(
+        client.try_schedule_maintenance(&admin, &1_000_200, &1_000_100, &withdrawals_only, &message),
+        Err(Ok(IntegrationError::InvalidOperationState.into()))
+    );
+
+    client.schedule_maintenance(&admin, &1_000_100, &1_000_200, &withdrawals_only, &message);
+    assert!(!client.is_maintenance_active());
+
+    let user = Address::generate(&env);
+    let deposit = |seed: u8| client.try_execute_bitcoin_deposit(
+        &admin,
+        &user,
+        &1_000u64,
+        &BytesN::from_array(&env, &[seed; 32]),
+        &6u32,
+    );
+    assert_ne!(deposit(1), Err(Ok(IntegrationError::MaintenanceMode.into())));
+
+    // Inside the window only whitelisted workflows run
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_150;
+    });
+    assert!(client.is_maintenance_active());
+    assert_eq!(deposit(2), Err(Ok(IntegrationError::MaintenanceMode.into())));
+    let withdrawal = client.try_execute_token_withdrawal(
+        &admin,
+        &user,
+        &1_000u64,
+        &String::from_str(&env, "bc1qmaintenance"),
+    );
+    assert_ne!(withdrawal, Err(Ok(IntegrationError::MaintenanceMode.into())));
+
+    // The window closes on its own
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_200;
+    });
+    assert!(!client.update_maintenance_status());
+    assert_eq!(client.get_maintenance_window(), None);
+    assert_ne!(deposit(3), Err(Ok(IntegrationError::MaintenanceMode.into())));
+
+    // Maintenance starting now can be ended early
+    client.schedule_maintenance(&admin, &1_000_200, &1_100_000, &Vec::new(&env), &message);
+    assert!(client.update_maintenance_status());
+    assert_eq!(deposit(4), Err(Ok(IntegrationError::MaintenanceMode.into())));
+    client.end_maintenance(&admin);
+    assert!(!client.is_maintenance_active());
+    assert_eq!(
+        client.try_end_maintenance(&admin),
+        Err(Ok(IntegrationError::InvalidOperationState.into()))
+    );
+}
+
+#[test]
+fn test_direct_calls_and_batches_respect_maintenance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let kyc = Address::generate(&env);
+    client.initialize(&admin, &kyc, &Address::generate(&env), &Address::generate(&env), &Address::generate(&env));
+
+    let call = |function_name: &str| ContractCall {
+        target_contract: kyc.clone(),
+        function_name: String::from_str(&env, function_name),
+        parameters: Vec::new(&env),
+        expected_return_type: String::from_str(&env, "bool"),
+        timeout: 30,
+        retry_count: 0,
+    };
+    let batch = |calls: Vec<ContractCall>| BatchOperation {
+        operation_id: BytesN::from_array(&env, &[9; 32]),
+        calls,
+        rollback_calls: Vec::new(&env),
+        timeout: 300,
+        atomic: false,
+        created_at: 1_000_000,
+        status: OperationStatus::Pending,
+    };
+
+    client.schedule_maintenance(&admin, &1_000_000, &1_100_000, &vec![&env, PauseWorkflow::Deposits], &String::from_str(&env, "Upgrade"));
+
+    // State-changing calls stop even when a workflow is whitelisted
+    assert_eq!(
+        client.try_execute_contract_call(&admin, &call("set_tier")),
+        Err(Ok(IntegrationError::MaintenanceMode.into()))
+    );
+    assert_eq!(
+        client.try_execute_batch_operation(&admin, &batch(vec![&env, call("verify_ic"), call("set_tier")])),
+        Err(Ok(IntegrationError::MaintenanceMode.into()))
+    );
+
+    // Reads keep running
+    assert!(client.try_execute_contract_call(&admin, &call("verify_ic")).is_ok());
+    assert!(client.try_execute_batch_operation(&admin, &batch(vec![&env, call("verify_ic")])).is_ok());
+
+    client.end_maintenance(&admin);
+    assert_ne!(
+        client.try_execute_contract_call(&admin, &call("set_tier")),
+        Err(Ok(IntegrationError::MaintenanceMode.into()))
+    );
+}
@@ -0,0 +1,191 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as TestAddress, Ledger},
+    Address, Env
+};
+
+fn setup(env: &Env) -> (IntegrationRouterClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 100;
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+
+    (client, admin)
+}
+
+fn advance(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| {
+        li.sequence_number += ledgers;
+        li.timestamp += ledgers as u64 * 5;
+    });
+}
+
+#[test]
+fn test_snapshots_follow_interval_and_retention() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_metrics_retention(&admin, &MetricsRetentionConfig { interval_ledgers: 10, max_snapshots: 3 });
+
+    assert!(client.record_metrics_snapshot(&admin));
+    assert!(!client.record_metrics_snapshot(&admin));
+    advance(&env, 5);
+    assert!(!client.record_metrics_snapshot(&admin));
+
+    // Snapshots at ledgers 110..=140; only the newest three are kept
+    for _ in 0..4 {
+        advance(&env, 5);
+        client.record_metrics_snapshot(&admin);
+        advance(&env, 5);
+    }
+    let history = client.get_metrics_history(&0u64, &u64::MAX, &0u64);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap().sequence, 2);
+    assert_eq!(history.get(0).unwrap().ledger, 120);
+    assert_eq!(history.get(2).unwrap().metrics.last_updated, 1_000_200);
+    assert_eq!(history.get(2).unwrap().metrics.current_reserve_ratio, 10000);
+
+    // Snapshots 50s apart charted at 100s resolution, then a single-point range
+    let coarse = client.get_metrics_history(&0u64, &u64::MAX, &100u64);
+    assert_eq!(coarse.len(), 2);
+    assert_eq!(coarse.get(1).unwrap().metrics.last_updated, 1_000_200);
+    assert_eq!(client.get_metrics_history(&1_000_150u64, &1_000_150u64, &0u64).len(), 1);
+    assert_eq!(client.get_metrics_history(&1_000_300u64, &1_000_100u64, &0u64).len(), 0);
+
+    // Shrinking retention evicts the oldest snapshots immediately
+    client.set_metrics_retention(&admin, &MetricsRetentionConfig { interval_ledgers: 10, max_snapshots: 1 });
+    let history = client.get_metrics_history(&0u64, &u64::MAX, &0u64);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().sequence, 4);
+}
+
+#[test]
+fn test_metrics_retention_access_and_validation() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    assert_eq!(client.get_metrics_retention(), MetricsRetentionConfig { interval_ledgers: 720, max_snapshots: 720 });
+    assert_eq!(
+        client.try_set_metrics_retention(&admin, &MetricsRetentionConfig { interval_ledgers: 0, max_snapshots: 10 }),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert_eq!(
+        client.try_set_metrics_retention(&admin, &MetricsRetentionConfig { interval_ledgers: 10, max_snapshots: 5000 }),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    let outsider = Address::generate(&env);
+    assert!(client.try_record_metrics_snapshot(&outsider).is_err());
+    assert!(client.try_set_metrics_retention(&outsider, &MetricsRetentionConfig { interval_ledgers: 10, max_snapshots: 10 }).is_err());
+    assert_eq!(client.get_metrics_history(&0u64, &u64::MAX, &0u64).len(), 0);
+}
+
+#[test]
+fn test_history_pages_and_retention_drains_in_bounded_steps() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_metrics_retention(&admin, &MetricsRetentionConfig { interval_ledgers: 1, max_snapshots: 300 });
+
+    let total = MAX_METRICS_HISTORY_POINTS + 5;
+    for _ in 0..total {
+        client.record_metrics_snapshot(&admin);
+        advance(&env, 1);
+    }
+
+    // One call returns a capped page; the next page starts after its last point
+    let page = client.get_metrics_history(&0u64, &u64::MAX, &0u64);
+    assert_eq!(page.len(), MAX_METRICS_HISTORY_POINTS);
+    let resume_from = page.last().unwrap().metrics.last_updated + 1;
+    let rest = client.get_metrics_history(&resume_from, &u64::MAX, &0u64);
+    assert_eq!(rest.len(), 5);
+    assert_eq!(rest.get(0).unwrap().sequence, MAX_METRICS_HISTORY_POINTS as u64);
+
+    // Range starts inside the buffer are found without reading earlier points
+    let middle = client.get_metrics_history(&(1_000_000 + 100 * 5), &(1_000_000 + 102 * 5), &0u64);
+    assert_eq!(middle.len(), 3);
+    assert_eq!(middle.get(0).unwrap().sequence, 100);
+
+    let state = |env: &Env| env.as_contract(&client.address, || IntegrationRouter::get_metrics_history_state(env));
+
+    // Shrinking retention hides the surplus at once but evicts a bounded batch
+    client.set_metrics_retention(&admin, &MetricsRetentionConfig { interval_ledgers: 1, max_snapshots: 1 });
+    assert_eq!(state(&env).oldest, MAX_METRICS_EVICTIONS as u64);
+    let history = client.get_metrics_history(&0u64, &u64::MAX, &0u64);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().sequence, total as u64 - 1);
+
+    // Each later snapshot drains another batch until only the retained one is left
+    for _ in 0..5 {
+        client.record_metrics_snapshot(&admin);
+        advance(&env, 1);
+    }
+    let drained = state(&env);
+    assert_eq!(drained.next - drained.oldest, 1);
+    assert_eq!(client.get_metrics_history(&0u64, &u64::MAX, &0u64).len(), 1);
+}
+
+#[test]
+fn test_snapshots_stored_before_processing_times_are_migrated() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    // Two snapshots written with the original layout, then one current one
+    env.as_contract(&client.address, || {
+        for sequence in 0..2u64 {
+            let legacy = MetricsSnapshotV1 {
+                sequence,
+                ledger: 100 + sequence as u32,
+                metrics: SystemMetricsV1 {
+                    total_operations: 10 + sequence,
+                    successful_operations: 9,
+                    failed_operations: 1,
+                    average_processing_time: 500,
+                    current_reserve_ratio: 10000,
+                    active_users_24h: 3,
+                    pending_operations: 0,
+                    withdrawal_queue_depth: 0,
+                    last_updated: 1_000_000 + sequence,
+                },
+            };
+            env.storage().persistent().set(&DataKey::SystemMetricsHistory(sequence), &legacy);
+        }
+        env.storage().persistent().set(
+            &AdminKey::MetricsHistoryState,
+            &MetricsHistoryState { oldest: 0, next: 2, last_ledger: 0 },
+        );
+    });
+    advance(&env, 720);
+    assert!(client.record_metrics_snapshot(&admin));
+
+    // Old entries read through the upgrade until they are rewritten
+    let history = client.get_metrics_history(&0u64, &u64::MAX, &0u64);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(1).unwrap().metrics.total_operations, 11);
+    assert_eq!(history.get(1).unwrap().metrics.deposit_processing_time, 0);
+
+    assert_eq!(client.migrate_metrics_history(&admin, &0u32, &2u32), 2);
+    assert_eq!(client.migrate_metrics_history(&admin, &2u32, &2u32), 1);
+    env.as_contract(&client.address, || {
+        let raw: Val = env.storage().persistent().get(&DataKey::SystemMetricsHistory(0)).unwrap();
+        let migrated = MetricsSnapshot::try_from_val(&env, &raw).unwrap();
+        assert_eq!(migrated.metrics.average_processing_time, 500);
+    });
+    assert_eq!(client.get_metrics_history(&0u64, &u64::MAX, &0u64), history);
+
+    let outsider = Address::generate(&env);
+    assert!(client.try_migrate_metrics_history(&outsider, &0u32, &2u32).is_err());
+}
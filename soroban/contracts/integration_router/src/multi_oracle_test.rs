@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as TestAddress, Ledger},
+    Address, Env
+};
+
+fn setup(env: &Env) -> (IntegrationRouterClient, Address, Address, Address) {
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_a = Address::generate(env);
+    let token_b = Address::generate(env);
+
+    client.initialize(
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+
+    client.configure_oracle(
+        &admin,
+        &token_a,
+        &token_b,
+        &Address::generate(env),
+        &300u64,
+        &500u64,   // 5% max deviation
+        &10000u64,
+    );
+
+    (client, admin, token_a, token_b)
+}
+
+#[test]
+fn test_median_aggregation_rejects_outlier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let (client, admin, token_a, token_b) = setup(&env);
+
+    let oracle_1 = Address::generate(&env);
+    let oracle_2 = Address::generate(&env);
+    let oracle_3 = Address::generate(&env);
+    client.add_oracle_source(&admin, &token_a, &token_b, &oracle_1, &1u32);
+    client.add_oracle_source(&admin, &token_a, &token_b, &oracle_2, &1u32);
+    client.add_oracle_source(&admin, &token_a, &token_b, &oracle_3, &1u32);
+
+    client.submit_oracle_rate(&oracle_1, &token_a, &token_b, &10_050u64);
+    client.submit_oracle_rate(&oracle_2, &token_a, &token_b, &10_100u64);
+    client.submit_oracle_rate(&oracle_3, &token_a, &token_b, &15_000u64); // Outlier
+
+    let rate = client.get_exchange_rate(&token_a, &token_b);
+    assert_eq!(rate.oracle_source, String::from_str(&env, "oracle"));
+    assert_eq!(rate.rate, 10_050);
+}
+
+#[test]
+fn test_source_health_downgrades_on_missed_updates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let (client, admin, token_a, token_b) = setup(&env);
+
+    let oracle_1 = Address::generate(&env);
+    let oracle_2 = Address::generate(&env);
+    client.add_oracle_source(&admin, &token_a, &token_b, &oracle_1, &1u32);
+    client.add_oracle_source(&admin, &token_a, &token_b, &oracle_2, &1u32);
+    client.submit_oracle_rate(&oracle_1, &token_a, &token_b, &10_000u64);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_400;
+    });
+    client.submit_oracle_rate(&oracle_2, &token_a, &token_b, &10_000u64);
+    client.get_exchange_rate(&token_a, &token_b);
+
+    let sources = client.get_oracle_sources(&token_a, &token_b);
+    assert_eq!(sources.get(0).unwrap().health_status, OracleHealthStatus::Degraded);
+    assert_eq!(sources.get(1).unwrap().health_status, OracleHealthStatus::Healthy);
+
+    client.remove_oracle_source(&admin, &token_a, &token_b, &oracle_1);
+    assert_eq!(client.get_oracle_sources(&token_a, &token_b).len(), 1);
+}
@@ -0,0 +1,168 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::Ledger, vec, Env};
+
+fn create_operation(h: &TestHarness) -> BytesN<32> {
+    let calls = Vec::new(&h.env);
+    h.router.create_batch_operation(&h.operator, &calls, &Vec::new(&h.env), &300, &false)
+}
+
+#[test]
+fn test_status_lists_page_across_daily_buckets() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let first = create_operation(&h);
+    let second = create_operation(&h);
+    let third = create_operation(&h);
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + OPERATION_BUCKET_PERIOD);
+    let fourth = create_operation(&h);
+    let fifth = create_operation(&h);
+    assert_eq!(h.router.get_operation_list_count(&OperationList::Pending), 5);
+
+    // Cancelling moves the operation to the failed list and the day's last operation into its slot
+    assert!(h.router.cancel_operation(&h.operator, &second));
+    assert_eq!(h.router.get_operation_list(&second), Some(OperationList::Failed));
+    assert_eq!(h.router.get_operation_list_count(&OperationList::Pending), 4);
+    assert_eq!(h.router.get_failed_operations(), vec![&env, second]);
+
+    let page = h.router.get_operation_list_page(&OperationList::Pending, &0, &0, &2);
+    assert_eq!(page.operation_ids, vec![&env, first.clone(), third.clone()]);
+    assert!(page.has_more);
+    let page = h.router.get_operation_list_page(&OperationList::Pending, &page.next_day, &page.next_slot, &2);
+    assert_eq!(page.operation_ids, vec![&env, fourth.clone(), fifth.clone()]);
+    assert!(!page.has_more);
+
+    assert_eq!(h.router.get_pending_operations(), vec![&env, first, third, fourth, fifth]);
+}
+
+#[test]
+fn test_emptied_buckets_are_dropped_and_days_reused() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let first = create_operation(&h);
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + OPERATION_BUCKET_PERIOD);
+    let second = create_operation(&h);
+
+    h.router.cancel_operation(&h.operator, &first);
+    let index: OperationListIndex = env.as_contract(&h.router.address, || {
+        IntegrationRouter::operation_list_index(&env, OperationList::Pending)
+    });
+    assert_eq!(index.count, 1);
+    assert_eq!(index.days.len(), 1);
+
+    // The current day's bucket is emptied, then reused by the next operation
+    h.router.cancel_operation(&h.operator, &second);
+    assert_eq!(h.router.get_operation_list_count(&OperationList::Pending), 0);
+    let third = create_operation(&h);
+    assert_eq!(h.router.get_pending_operations(), vec![&env, third]);
+    assert_eq!(h.router.get_operation_list(&first), Some(OperationList::Failed));
+    assert_eq!(h.router.get_operation_list_count(&OperationList::Failed), 2);
+}
+
+#[test]
+fn test_removal_compacts_slots_and_drops_middle_days() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let first = create_operation(&h);
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + OPERATION_BUCKET_PERIOD);
+    let second = create_operation(&h);
+    let third = create_operation(&h);
+    let fourth = create_operation(&h);
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 2 * OPERATION_BUCKET_PERIOD);
+    let fifth = create_operation(&h);
+
+    // The day's last operation takes the freed slot, so its listing follows it
+    h.router.cancel_operation(&h.operator, &second);
+    let day = HARNESS_START_TIME + OPERATION_BUCKET_PERIOD;
+    let day = day - day % OPERATION_BUCKET_PERIOD;
+    env.as_contract(&h.router.address, || {
+        let bucket: OperationBucket = env.storage().persistent()
+            .get(&WorkflowKey::OperationBucket(OperationList::Pending, day))
+            .unwrap();
+        assert_eq!(bucket.live, 2);
+        assert!(!env.storage().persistent().has(&WorkflowKey::OperationSlot(OperationList::Pending, day, 2)));
+        let moved: OperationListEntry = env.storage().persistent()
+            .get(&WorkflowKey::OperationListing(fourth.clone()))
+            .unwrap();
+        assert_eq!(moved, OperationListEntry { list: OperationList::Pending, day, slot: 0 });
+    });
+
+    // Emptying the middle day removes it from the index, not only leading days
+    h.router.cancel_operation(&h.operator, &third);
+    h.router.cancel_operation(&h.operator, &fourth);
+    let index: OperationListIndex = env.as_contract(&h.router.address, || {
+        IntegrationRouter::operation_list_index(&env, OperationList::Pending)
+    });
+    assert_eq!(index.days.len(), 2);
+    assert!(!index.days.contains(day));
+
+    let page = h.router.get_operation_list_page(&OperationList::Pending, &0, &0, &1);
+    assert_eq!(page.operation_ids, vec![&env, first.clone()]);
+    let page = h.router.get_operation_list_page(&OperationList::Pending, &page.next_day, &page.next_slot, &1);
+    assert_eq!(page.operation_ids, vec![&env, fifth.clone()]);
+    assert!(!page.has_more);
+    assert_eq!(h.router.get_pending_operations(), vec![&env, first, fifth]);
+}
+
+#[test]
+fn test_sweep_reaches_operations_moved_into_swept_slots() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    for _ in 0..4 {
+        create_operation(&h);
+    }
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 301);
+
+    // Each swept operation pulls the day's last one into its slot; all are still reached
+    assert_eq!(h.router.sweep_timed_out_operations(&h.operator, &10), 4);
+    assert_eq!(h.router.get_operation_list_count(&OperationList::Pending), 0);
+    assert_eq!(h.router.get_operation_list_count(&OperationList::Failed), 4);
+}
+
+#[test]
+fn test_pending_deposits_and_withdrawals_page_past_the_first_hundred() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    for _ in 0..OPERATION_PAGE_LIMIT {
+        create_operation(&h);
+    }
+
+    // Queue a deposit and a withdrawal behind a full page of other operations
+    let mut tracked = Vec::new(&env);
+    for operation_type in ["bitcoin_deposit", "token_withdrawal"] {
+        let operation_id = BytesN::from_array(&env, &[tracked.len() as u8 + 1; 32]);
+        env.as_contract(&h.router.address, || {
+            let tracker = OperationTracker {
+                operation_id: operation_id.clone(),
+                operation_type: String::from_str(&env, operation_type),
+                status: OperationStatus::InProgress,
+                created_at: HARNESS_START_TIME,
+                updated_at: HARNESS_START_TIME,
+                timeout_at: HARNESS_START_TIME + 3_600,
+                retry_count: 0,
+                error_message: String::from_str(&env, ""),
+            };
+            env.storage().persistent().set(&DataKey::OperationTracker(operation_id.clone()), &tracker);
+            IntegrationRouter::list_operation(&env, &operation_id, OperationList::Pending);
+        });
+        tracked.push_back(operation_id);
+    }
+
+    let page = h.router.get_pending_deposits(&h.operator, &0, &0, &500);
+    assert_eq!(page.deposits.len(), 0);
+    assert!(page.has_more);
+    let page = h.router.get_pending_deposits(&h.operator, &page.next_day, &page.next_slot, &500);
+    assert_eq!(page.deposits.len(), 1);
+    assert_eq!(page.deposits.get_unchecked(0).operation_id, tracked.get_unchecked(0));
+    assert!(!page.has_more);
+
+    let page = h.router.get_pending_withdrawals(&h.operator, &0, &0, &OPERATION_PAGE_LIMIT);
+    assert_eq!((page.withdrawals.len(), page.has_more), (0, true));
+    let page = h.router.get_pending_withdrawals(&h.operator, &page.next_day, &page.next_slot, &OPERATION_PAGE_LIMIT);
+    assert_eq!(page.withdrawals.len(), 1);
+    assert_eq!(page.withdrawals.get_unchecked(0).withdrawal_id, tracked.get_unchecked(1));
+
+    assert!(h.router.try_get_pending_deposits(&h.user(1), &0, &0, &10).is_err());
+}
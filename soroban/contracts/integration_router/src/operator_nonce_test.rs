@@ -0,0 +1,124 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::TestHarness;
+use soroban_sdk::{testutils::Address as TestAddress, Address, Env};
+
+fn duplicate() -> soroban_sdk::Error {
+    soroban_sdk::Error::from_contract_error(IntegrationError::DuplicateOperation as u32)
+}
+
+#[test]
+fn test_nonces_must_increase_per_operator() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    let btc_tx_hash = BytesN::from_array(&env, &[5u8; 32]);
+    let btc_address = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
+
+    // A one-satoshi cap ends both workflows at the allowance check without reverting;
+    // the breach suspends the operator, so they are reinstated after each accepted call
+    h.router.set_operator_spending_cap(
+        &h.admin,
+        &h.operator,
+        &OperatorSpendingCap { deposit_daily_cap: 1, withdrawal_daily_cap: 1 }
+    );
+    assert_eq!(h.router.get_operator_nonce(&h.operator), 0);
+
+    h.router.execute_bitcoin_deposit_nonced(&h.operator, &1, &user, &100_000, &btc_tx_hash, &6);
+    h.router.reinstate_operator(&h.admin, &h.operator);
+    assert_eq!(h.router.get_operator_nonce(&h.operator), 1);
+
+    // Replays and stale nonces are rejected on both workflows
+    assert_eq!(
+        h.router.try_execute_bitcoin_deposit_nonced(&h.operator, &1, &user, &100_000, &btc_tx_hash, &6),
+        Err(Ok(duplicate()))
+    );
+    assert_eq!(
+        h.router.try_execute_token_withdrawal_nonced(&h.operator, &0, &user, &10_000_000, &btc_address),
+        Err(Ok(duplicate()))
+    );
+
+    // Gaps are allowed, going back afterwards is not
+    h.router.execute_bitcoin_deposit_nonced(&h.operator, &5, &user, &100_000, &btc_tx_hash, &6);
+    h.router.reinstate_operator(&h.admin, &h.operator);
+    assert_eq!(h.router.get_operator_nonce(&h.operator), 5);
+    assert_eq!(
+        h.router.try_execute_bitcoin_deposit_nonced(&h.operator, &3, &user, &100_000, &btc_tx_hash, &6),
+        Err(Ok(duplicate()))
+    );
+
+    // Each operator has its own channel
+    let second_operator = Address::generate(&env);
+    h.router.set_user_role(&h.admin, &second_operator, &UserRole::Operator);
+    assert_eq!(h.router.get_operator_nonce(&second_operator), 0);
+}
+
+#[test]
+fn test_failed_call_does_not_consume_its_nonce() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    let btc_tx_hash = BytesN::from_array(&env, &[6u8; 32]);
+    h.router.set_fault_injection_enabled(&h.admin, &true);
+    h.router.inject_fault(&h.admin, &FaultStep::DepositKyc);
+
+    assert_eq!(
+        h.router.try_execute_bitcoin_deposit_nonced(&h.operator, &1, &user, &100_000, &btc_tx_hash, &6),
+        Err(Ok(soroban_sdk::Error::from_contract_error(IntegrationError::ComplianceCheckFailed as u32)))
+    );
+    assert_eq!(h.router.get_operator_nonce(&h.operator), 0);
+
+    // The retry reuses the nonce and is then protected against replay
+    h.router.clear_fault(&h.admin, &FaultStep::DepositKyc);
+    h.router.set_operator_spending_cap(
+        &h.admin,
+        &h.operator,
+        &OperatorSpendingCap { deposit_daily_cap: 1, withdrawal_daily_cap: 1 }
+    );
+    h.router.execute_bitcoin_deposit_nonced(&h.operator, &1, &user, &100_000, &btc_tx_hash, &6);
+    assert_eq!(h.router.get_operator_nonce(&h.operator), 1);
+}
+
+#[test]
+fn test_nonce_required_operator_rejects_unnonced_entry_points() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    let btc_tx_hash = BytesN::from_array(&env, &[7u8; 32]);
+    let btc_address = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
+    let travel_rule_hash = BytesN::from_array(&env, &[8u8; 32]);
+    let forbidden = Err(Ok(soroban_sdk::Error::from_contract_error(IntegrationError::InsufficientPermissions as u32)));
+
+    h.router.set_operator_nonce_required(&h.admin, &h.operator, &true);
+    assert!(h.router.is_operator_nonce_required(&h.operator));
+
+    assert_eq!(h.router.try_execute_bitcoin_deposit(&h.operator, &user, &100_000, &btc_tx_hash, &6), forbidden);
+    assert_eq!(h.router.try_execute_btc_deposit_tracked(&h.operator, &user, &100_000, &btc_tx_hash, &6), forbidden);
+    assert_eq!(h.router.try_execute_token_withdrawal(&h.operator, &user, &10_000_000, &btc_address), forbidden);
+    assert_eq!(h.router.try_execute_token_withdrawal_tracked(&h.operator, &user, &10_000_000, &btc_address), forbidden);
+    assert_eq!(
+        h.router.try_execute_travel_rule_withdrawal(&h.operator, &user, &10_000_000, &btc_address, &travel_rule_hash),
+        forbidden
+    );
+
+    // The nonced entry point still works, and other operators are unaffected
+    let operation_id = h.router.execute_bitcoin_deposit_nonced(&h.operator, &1, &user, &100_000, &btc_tx_hash, &6);
+    assert_ne!(operation_id, BytesN::from_array(&env, &[0u8; 32]));
+    assert_eq!(h.router.get_operator_nonce(&h.operator), 1);
+    assert_ne!(
+        h.router.try_execute_travel_rule_nonced(&h.operator, &2, &user, &10_000_000, &btc_address, &travel_rule_hash),
+        forbidden
+    );
+    let second_operator = Address::generate(&env);
+    h.router.set_user_role(&h.admin, &second_operator, &UserRole::Operator);
+    assert!(!h.router.is_operator_nonce_required(&second_operator));
+
+    // Clearing the flag reopens the un-nonced entry points
+    h.router.set_operator_nonce_required(&h.admin, &h.operator, &false);
+    assert!(!h.router.is_operator_nonce_required(&h.operator));
+    assert_ne!(
+        h.router.try_execute_bitcoin_deposit(&h.operator, &user, &100_000, &BytesN::from_array(&env, &[9u8; 32]), &6),
+        forbidden
+    );
+}
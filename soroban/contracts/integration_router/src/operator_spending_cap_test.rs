@@ -0,0 +1,130 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::TestHarness;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as TestAddress, Ledger},
+    Address, BytesN, Env
+};
+
+const ISTSI_PER_SAT: u64 = 100_000_000;
+
+/// KYC registry that screens every address as not blacklisted
+#[contract]
+pub struct MockCapKycRegistry;
+
+#[contractimpl]
+impl MockCapKycRegistry {
+    pub fn is_blacklisted(_env: Env, _address: Address) -> bool {
+        false
+    }
+}
+
+#[test]
+fn test_operator_cap_suspends_and_reinstates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &env.register(MockCapKycRegistry, ()),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+
+    let operator = Address::generate(&env);
+    client.set_user_role(&admin, &operator, &UserRole::Operator);
+
+    // Only SuperAdmin manages caps
+    let cap = OperatorSpendingCap { deposit_daily_cap: 1_000, withdrawal_daily_cap: 0 };
+    assert!(client.try_set_operator_spending_cap(&operator, &operator, &cap).is_err());
+    client.set_operator_spending_cap(&admin, &operator, &cap);
+    assert_eq!(client.get_operator_spending_cap(&operator), Some(cap));
+
+    env.as_contract(&contract_id, || {
+        assert!(IntegrationRouter::check_operator_allowance(&env, &operator, VelocityOperation::Deposit, 600));
+        assert!(IntegrationRouter::check_operator_allowance(&env, &operator, VelocityOperation::Withdrawal, 50_000));
+    });
+    assert_eq!(client.get_operator_spending(&operator).deposit_volume, 600);
+
+    // The deposit that would exceed the cap is recorded as failed and suspends the operator
+    let user = Address::generate(&env);
+    let operation_id = client.execute_bitcoin_deposit(
+        &operator,
+        &user,
+        &500u64,
+        &BytesN::from_array(&env, &[4u8; 32]),
+        &6u32,
+    );
+    assert_eq!(client.get_operation_status(&operation_id).unwrap().status, OperationStatus::Failed);
+
+    let suspension = client.get_operator_suspension(&operator).unwrap();
+    assert_eq!(suspension.daily_volume, 600);
+    assert_eq!(suspension.daily_cap, 1_000);
+    assert_eq!(client.get_suspended_operators().len(), 1);
+
+    let retry = client.try_execute_bitcoin_deposit(
+        &operator,
+        &user,
+        &1u64,
+        &BytesN::from_array(&env, &[5u8; 32]),
+        &6u32,
+    );
+    assert_eq!(retry, Err(Ok(IntegrationError::InsufficientPermissions.into())));
+
+    // Reinstated operators resume with a fresh allowance the next day
+    client.reinstate_operator(&admin, &operator);
+    assert!(client.get_operator_suspension(&operator).is_none());
+    assert_eq!(
+        client.try_reinstate_operator(&admin, &operator),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86_400;
+    });
+    assert_eq!(client.get_operator_spending(&operator).deposit_volume, 0);
+}
+
+#[test]
+fn test_failed_operations_release_their_reserved_allowance() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    h.router.set_operator_spending_cap(
+        &h.admin,
+        &h.operator,
+        &OperatorSpendingCap { deposit_daily_cap: 0, withdrawal_daily_cap: 100_000 },
+    );
+    h.router.set_withdrawal_approval_config(
+        &h.admin,
+        &WithdrawalApprovalConfig { threshold_sats: 50_000, approval_window: 3_600 },
+    );
+    let user = h.user(2);
+    h.istsi.mint(&user, &((100_000 * ISTSI_PER_SAT) as i128));
+    let btc_address = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
+
+    // A withdrawal held for approval keeps its amount reserved
+    let held = h.router.execute_token_withdrawal(&h.operator, &user, &(60_000 * ISTSI_PER_SAT), &btc_address);
+    assert!(h.router.get_withdrawal_approval(&held).is_some());
+    assert_eq!(h.router.get_operator_spending(&h.operator).withdrawal_volume, 60_000);
+
+    // Once it expires unapproved the reservation is released
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    assert_eq!(h.router.expire_withdrawal_approvals(&h.operator, &10), 1);
+    assert_eq!(h.router.get_operator_spending(&h.operator).withdrawal_volume, 0);
+
+    // Completed withdrawals keep their charge
+    let completed = h.router.execute_token_withdrawal(&h.operator, &user, &(20_000 * ISTSI_PER_SAT), &btc_address);
+    assert_eq!(h.router.get_withdrawal_status(&completed).unwrap().status, WithdrawalProcessingStatus::Completed);
+    assert_eq!(h.router.get_operator_spending(&h.operator).withdrawal_volume, 20_000);
+    assert!(h.router.get_operator_suspension(&h.operator).is_none());
+}
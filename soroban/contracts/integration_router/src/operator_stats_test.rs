@@ -0,0 +1,86 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::Ledger, Env};
+
+/// Start an operation for `operator` with a 60 second timeout
+fn start(h: &TestHarness, operator: &Address, id: u8) -> BytesN<32> {
+    let env = &h.env;
+    let operation_id = BytesN::from_array(env, &[id; 32]);
+    let now = env.ledger().timestamp();
+    env.as_contract(&h.router.address, || {
+        IntegrationRouter::attribute_operation(env, operator, &operation_id);
+        let tracker = OperationTracker {
+            operation_id: operation_id.clone(),
+            operation_type: String::from_str(env, "bitcoin_deposit"),
+            status: OperationStatus::InProgress,
+            created_at: now,
+            updated_at: now,
+            timeout_at: now + 60,
+            retry_count: 0,
+            error_message: String::from_str(env, ""),
+        };
+        env.storage().persistent().set(&DataKey::OperationTracker(operation_id.clone()), &tracker);
+        IntegrationRouter::list_operation(env, &operation_id, OperationList::Pending);
+    });
+    operation_id
+}
+
+fn finish(h: &TestHarness, operation_id: &BytesN<32>, list: OperationList) {
+    h.env.as_contract(&h.router.address, || IntegrationRouter::list_operation(&h.env, operation_id, list));
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+#[test]
+fn test_operator_stats_track_outcomes_handling_time_and_sla_breaches() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+
+    let quick = start(&h, &h.operator, 1);
+    let slow = start(&h, &h.operator, 2);
+    let failed = start(&h, &h.operator, 3);
+    start(&h, &h.operator, 4);
+    advance(&env, 30);
+    finish(&h, &quick, OperationList::Completed);
+    finish(&h, &failed, OperationList::Failed);
+    advance(&env, 60);
+    finish(&h, &slow, OperationList::Completed);
+    // Each operation counts once
+    finish(&h, &slow, OperationList::Failed);
+
+    let stats = h.router.get_operator_stats(&h.operator, &StatsPeriod::Day);
+    assert_eq!((stats.started, stats.completed, stats.failed), (4, 2, 1));
+    assert_eq!(stats.failure_rate, 3_333);
+    assert_eq!(stats.average_handling_seconds, (30 + 30 + 90) / 3);
+    assert_eq!(stats.sla_breaches, 1);
+    assert_eq!(stats.period_start, HARNESS_START_TIME - HARNESS_START_TIME % 86_400);
+
+    // Older days fall out of the shorter periods
+    advance(&env, 8 * 86_400);
+    assert_eq!(h.router.get_operator_stats(&h.operator, &StatsPeriod::Week).started, 0);
+    assert_eq!(h.router.get_operator_stats(&h.operator, &StatsPeriod::Month).started, 4);
+    assert_eq!(h.router.get_operator_stats(&h.operator, &StatsPeriod::AllTime).completed, 2);
+}
+
+#[test]
+fn test_scoreboard_ranks_operators_by_completed_operations() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let night_shift = Address::generate(&env);
+    h.router.set_user_role(&h.admin, &night_shift, &UserRole::Operator);
+
+    finish(&h, &start(&h, &h.operator, 1), OperationList::Failed);
+    for id in 2..4 {
+        finish(&h, &start(&h, &night_shift, id), OperationList::Completed);
+    }
+
+    assert!(h.router.try_get_operator_scoreboard(&h.operator, &StatsPeriod::Day).is_err());
+    let scoreboard = h.router.get_operator_scoreboard(&h.admin, &StatsPeriod::Day);
+    assert_eq!(scoreboard.len(), 2);
+    assert_eq!(scoreboard.get_unchecked(0).operator, night_shift);
+    assert_eq!(scoreboard.get_unchecked(1).failure_rate, 10_000);
+}
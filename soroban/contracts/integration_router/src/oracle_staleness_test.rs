@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as TestAddress, Ledger},
+    Address, Env
+};
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp = timestamp;
+    });
+}
+
+#[test]
+fn test_stale_oracle_disables_pair_and_recovers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_time(&env, 1_000_000);
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let token_a = Address::generate(&env);
+    let token_b = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+    client.configure_oracle(&admin, &token_a, &token_b, &oracle, &300u64, &500u64, &10000u64);
+    client.add_oracle_source(&admin, &token_a, &token_b, &oracle, &1u32);
+    client.submit_oracle_rate(&oracle, &token_a, &token_b, &10_000u64);
+    client.get_exchange_rate(&token_a, &token_b);
+
+    // Oracle stops updating for longer than update_frequency * 3
+    set_time(&env, 1_001_000);
+    let result = client.try_get_exchange_rate(&token_a, &token_b);
+    assert_eq!(result, Err(Ok(IntegrationError::OracleStale)));
+    assert!(client.get_pair_oracle_state(&token_a, &token_b).exchange_disabled);
+
+    // The rejected quote rolled back its disable flag; the keeper call persists it
+    let state = client.record_pair_staleness(&token_a, &token_b);
+    assert_eq!(state.disabled_at, 1_001_000);
+    set_time(&env, 1_001_500);
+    assert_eq!(client.get_pair_oracle_state(&token_a, &token_b).disabled_at, 1_001_000);
+
+    // Admin allows fallback quotes; the exchange path stays disabled
+    client.set_staleness_policy(&admin, &token_a, &token_b, &3u32, &true);
+    let rate = client.get_exchange_rate(&token_a, &token_b);
+    assert_eq!(rate.oracle_source, String::from_str(&env, "fallback"));
+    assert!(client.get_pair_oracle_state(&token_a, &token_b).exchange_disabled);
+
+    // Fresh oracle data re-enables the pair
+    client.submit_oracle_rate(&oracle, &token_a, &token_b, &10_000u64);
+    client.get_exchange_rate(&token_a, &token_b);
+    assert!(!client.get_pair_oracle_state(&token_a, &token_b).exchange_disabled);
+}
@@ -0,0 +1,193 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as TestAddress, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, BytesN, Env
+};
+
+/// Minimal KYC registry approving every user
+#[contract]
+pub struct MockOrderKycRegistry;
+
+#[contractimpl]
+impl MockOrderKycRegistry {
+    pub fn is_approved_simple(_env: Env, _user: Address, _operation: u32, _amount: i128) -> bool {
+        true
+    }
+
+    pub fn is_blacklisted(_env: Env, _address: Address) -> bool {
+        false
+    }
+}
+
+struct Setup<'a> {
+    contract_id: Address,
+    client: IntegrationRouterClient<'a>,
+    admin: Address,
+    base: Address,
+    quote: Address,
+    alice: Address,
+    bob: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+    let kyc_registry = env.register(MockOrderKycRegistry, ());
+
+    let issuer = Address::generate(env);
+    let base = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    let quote = env.register_stellar_asset_contract_v2(issuer).address();
+
+    let admin = Address::generate(env);
+    client.initialize(&admin, &kyc_registry, &base, &quote, &Address::generate(env));
+    client.register_token_pair(&admin, &base, &quote, &0u64, &0u64, &u64::MAX, &Address::generate(env));
+
+    // Alice sells the base token, Bob sells the quote token
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+    StellarAssetClient::new(env, &base).mint(&alice, &1_000_000i128);
+    StellarAssetClient::new(env, &quote).mint(&bob, &1_000_000i128);
+
+    Setup { contract_id, client, admin, base, quote, alice, bob }
+}
+
+#[test]
+fn test_crossing_orders_fill_partially_at_maker_price() {
+    let env = Env::default();
+    let s = setup(&env);
+    let expires_at = 1_003_600u64;
+
+    // Alice asks 2 quote per base; Bob pays up to 2 quote per base (0.5 base per quote)
+    let ask = s.client.place_limit_order(&s.alice, &s.base, &s.quote, &100_000u64, &20_000u64, &expires_at);
+    let bid = s.client.place_limit_order(&s.bob, &s.quote, &s.base, &150_000u64, &5_000u64, &expires_at);
+    assert_eq!(TokenClient::new(&env, &s.base).balance(&s.alice), 900_000);
+    assert_eq!(s.client.get_order_book(&s.base).len(), 1);
+
+    assert_eq!(s.client.match_limit_orders(&s.admin, &10u32), 1);
+
+    let ask = s.client.get_limit_order(&ask.order_id).unwrap();
+    assert_eq!(ask.status, OrderStatus::PartiallyFilled);
+    assert_eq!(ask.remaining, 25_000);
+    assert_eq!(ask.received, 150_000);
+
+    let bid = s.client.get_limit_order(&bid.order_id).unwrap();
+    assert_eq!(bid.status, OrderStatus::Filled);
+    assert_eq!(bid.received, 75_000);
+
+    assert_eq!(TokenClient::new(&env, &s.quote).balance(&s.alice), 150_000);
+    assert_eq!(TokenClient::new(&env, &s.base).balance(&s.bob), 75_000);
+    assert_eq!(s.client.get_open_orders(&s.alice).len(), 1);
+    assert_eq!(s.client.get_open_orders(&s.bob).len(), 0);
+
+    // Each side of the fill is recorded as an integration event for its owner
+    let fills = s.client.get_event_history(&EventFilter::ByEventType(String::from_str(&env, "LimitOrderFill")), &10u32);
+    assert_eq!(fills.len(), 2);
+
+    // Nothing left to match; cancelling refunds the remainder
+    assert_eq!(s.client.match_limit_orders(&s.admin, &10u32), 0);
+    let cancelled = s.client.cancel_limit_order(&s.alice, &ask.order_id);
+    assert_eq!(cancelled.status, OrderStatus::Cancelled);
+    assert_eq!(TokenClient::new(&env, &s.base).balance(&s.alice), 925_000);
+    assert_eq!(s.client.get_open_orders(&s.alice).len(), 0);
+    assert_eq!(
+        s.client.try_cancel_limit_order(&s.alice, &ask.order_id),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
+
+#[test]
+fn test_best_price_matches_first() {
+    let env = Env::default();
+    let s = setup(&env);
+    let expires_at = 1_003_600u64;
+
+    let carol = Address::generate(&env);
+    StellarAssetClient::new(&env, &s.base).mint(&carol, &1_000_000i128);
+
+    let alice_ask = s.client.place_limit_order(&s.alice, &s.base, &s.quote, &50_000u64, &20_000u64, &expires_at);
+    let carol_ask = s.client.place_limit_order(&carol, &s.base, &s.quote, &50_000u64, &19_000u64, &expires_at);
+    s.client.place_limit_order(&s.bob, &s.quote, &s.base, &19_000u64, &5_000u64, &expires_at);
+
+    assert_eq!(s.client.match_limit_orders(&s.admin, &10u32), 1);
+
+    // Carol's cheaper ask fills at her own price; Alice's stays untouched
+    let carol_ask = s.client.get_limit_order(&carol_ask.order_id).unwrap();
+    assert_eq!(carol_ask.remaining, 40_000);
+    assert_eq!(carol_ask.received, 19_000);
+    assert_eq!(s.client.get_limit_order(&alice_ask.order_id).unwrap().status, OrderStatus::Open);
+    assert_eq!(TokenClient::new(&env, &s.base).balance(&s.bob), 10_000);
+}
+
+#[test]
+fn test_orders_expire_and_validate() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_place_limit_order(&s.alice, &s.base, &s.quote, &100_000u64, &20_000u64, &1_000_000u64),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert_eq!(
+        s.client.try_place_limit_order(&s.alice, &s.base, &Address::generate(&env), &100_000u64, &20_000u64, &1_000_600u64),
+        Err(Ok(IntegrationError::ContractNotFound))
+    );
+
+    // Orders that do not cross stay on the book
+    let ask = s.client.place_limit_order(&s.alice, &s.base, &s.quote, &100_000u64, &20_000u64, &1_000_600u64);
+    let bid = s.client.place_limit_order(&s.bob, &s.quote, &s.base, &100_000u64, &6_000u64, &1_003_600u64);
+    assert_eq!(s.client.match_limit_orders(&s.admin, &10u32), 0);
+
+    assert_eq!(
+        s.client.try_cancel_limit_order(&s.alice, &bid.order_id),
+        Err(Ok(IntegrationError::Unauthorized))
+    );
+    assert!(s.client.try_match_limit_orders(&s.alice, &10u32).is_err());
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_600;
+    });
+    assert_eq!(s.client.match_limit_orders(&s.admin, &10u32), 0);
+
+    assert_eq!(s.client.get_limit_order(&ask.order_id).unwrap().status, OrderStatus::Expired);
+    assert_eq!(TokenClient::new(&env, &s.base).balance(&s.alice), 1_000_000);
+    assert_eq!(s.client.get_open_orders(&s.bob).len(), 1);
+    assert!(s.client.get_limit_order(&BytesN::from_array(&env, &[9u8; 32])).is_none());
+}
+
+#[test]
+fn test_unfillable_order_at_the_head_does_not_stall_matching() {
+    let env = Env::default();
+    let s = setup(&env);
+    let expires_at = 1_003_600u64;
+
+    let ask = s.client.place_limit_order(&s.alice, &s.base, &s.quote, &100_000u64, &20_000u64, &expires_at);
+    let stuck = s.client.place_limit_order(&s.bob, &s.quote, &s.base, &150_000u64, &5_000u64, &expires_at);
+    let carol = Address::generate(&env);
+    StellarAssetClient::new(&env, &s.quote).mint(&carol, &40_000i128);
+    let behind = s.client.place_limit_order(&carol, &s.quote, &s.base, &40_000u64, &5_000u64, &expires_at);
+
+    // A stored remainder of one quote unit cannot buy any base at Alice's price
+    env.as_contract(&s.contract_id, || {
+        let mut order = IntegrationRouter::get_limit_order(env.clone(), stuck.order_id.clone()).unwrap();
+        order.remaining = 1;
+        env.storage().persistent().set(&ExchangeKey::LimitOrder(order.order_id.clone()), &order);
+    });
+
+    // The dust order is closed and refunded, and the order behind it fills
+    assert_eq!(s.client.match_limit_orders(&s.admin, &10u32), 1);
+    let stuck = s.client.get_limit_order(&stuck.order_id).unwrap();
+    assert_eq!(stuck.status, OrderStatus::Cancelled);
+    assert_eq!(stuck.remaining, 0);
+    assert_eq!(s.client.get_limit_order(&behind.order_id).unwrap().status, OrderStatus::Filled);
+    assert_eq!(s.client.get_limit_order(&ask.order_id).unwrap().remaining, 80_000);
+    assert_eq!(TokenClient::new(&env, &s.quote).balance(&s.bob), 850_001);
+}
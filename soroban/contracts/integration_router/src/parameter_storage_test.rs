@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as TestAddress, Address, Env};
+
+fn setup(env: &Env) -> (Address, IntegrationRouterClient<'_>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+
+    (contract_id, client, admin)
+}
+
+#[test]
+fn test_parameters_cannot_clobber_registry() {
+    let env = Env::default();
+    let (_, client, admin) = setup(&env);
+
+    let kyc_name = String::from_str(&env, "kyc_registry");
+    let istsi_name = String::from_str(&env, "istsi_token");
+    let kyc_registry = client.get_contract_address(&kyc_name).unwrap();
+
+    client.set_system_parameter(&admin, &kyc_name, &String::from_str(&env, "v2"));
+    client.set_contract_parameter(&admin, &istsi_name, &kyc_name, &String::from_str(&env, "strict"));
+    client.set_contract_limit(&admin, &istsi_name, &kyc_name, &10u64);
+    client.set_contract_limit(&admin, &kyc_name, &kyc_name, &20u64);
+
+    assert_eq!(client.get_contract_address(&kyc_name), Some(kyc_registry));
+    assert_eq!(client.get_system_parameter(&kyc_name), Some(String::from_str(&env, "v2")));
+    assert_eq!(client.get_contract_parameter(&istsi_name, &kyc_name), Some(String::from_str(&env, "strict")));
+    assert_eq!(client.get_contract_parameter(&kyc_name, &kyc_name), None);
+    assert_eq!(client.get_contract_limit(&istsi_name, &kyc_name), Some(10));
+    assert_eq!(client.get_contract_limit(&kyc_name, &kyc_name), Some(20));
+}
+
+#[test]
+fn test_migrates_legacy_values() {
+    let env = Env::default();
+    let (contract_id, client, admin) = setup(&env);
+
+    let timeout = String::from_str(&env, "timeout");
+    let max_tier = String::from_str(&env, "max_tier");
+    let max_mint = String::from_str(&env, "max_mint");
+    let kyc_name = String::from_str(&env, "kyc_registry");
+    let istsi_name = String::from_str(&env, "istsi_token");
+    let kyc_registry = client.get_config().kyc_registry;
+
+    // Values written by the old shared-key storage, including a parameter that clobbered the registry
+    env.as_contract(&contract_id, || {
+        let persistent = env.storage().persistent();
+        persistent.set(&DataKey::ContractAddress(timeout.clone()), &String::from_str(&env, "600"));
+        persistent.set(&DataKey::ContractAddress(max_tier.clone()), &String::from_str(&env, "4"));
+        persistent.set(&DataKey::ContractAddress(max_mint.clone()), &1_000u64);
+        persistent.set(&DataKey::ContractAddress(kyc_name.clone()), &String::from_str(&env, "v1"));
+    });
+
+    let migrated = client.migrate_legacy_parameters(
+        &admin,
+        &vec![&env, timeout.clone(), kyc_name.clone()],
+        &vec![&env, (kyc_name.clone(), max_tier.clone())],
+        &vec![&env, (istsi_name.clone(), max_mint.clone())],
+    );
+    assert_eq!(migrated, 4);
+
+    assert_eq!(client.get_system_parameter(&timeout), Some(String::from_str(&env, "600")));
+    assert_eq!(client.get_system_parameter(&kyc_name), Some(String::from_str(&env, "v1")));
+    assert_eq!(client.get_contract_parameter(&kyc_name, &max_tier), Some(String::from_str(&env, "4")));
+    assert_eq!(client.get_contract_limit(&istsi_name, &max_mint), Some(1_000));
+    assert_eq!(client.get_contract_address(&kyc_name), Some(kyc_registry));
+    assert_eq!(client.get_contract_address(&timeout), None);
+
+    // Legacy keys are gone, so a second run migrates nothing
+    assert_eq!(
+        client.migrate_legacy_parameters(&admin, &vec![&env, timeout], &Vec::new(&env), &Vec::new(&env)),
+        0
+    );
+}
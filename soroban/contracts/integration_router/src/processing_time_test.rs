@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{raw_bitcoin_tx, MockReserveManager};
+use soroban_sdk::{
+    testutils::{Address as TestAddress, Ledger},
+    Address, Bytes, BytesN, Env
+};
+
+fn setup(env: &Env) -> (Address, Address, IntegrationRouterClient<'_>) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &env.register(MockReserveManager, ()),
+    );
+
+    (admin, contract_id, client)
+}
+
+fn seed_withdrawal(env: &Env, contract_id: &Address, seed: u8, created_at: u64) -> BytesN<32> {
+    let withdrawal_id = BytesN::from_array(env, &[seed; 32]);
+    env.as_contract(contract_id, || {
+        let status = WithdrawalStatus {
+            withdrawal_id: withdrawal_id.clone(),
+            user: Address::generate(env),
+            istsi_amount: 100_000,
+            btc_amount: 100_000,
+            btc_address: String::from_str(env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh"),
+            status: WithdrawalProcessingStatus::Completed,
+            operation_id: withdrawal_id.clone(),
+            btc_tx_hash: None,
+            created_at,
+            updated_at: created_at,
+            error_message: String::from_str(env, ""),
+            fee_tier: BtcFeeTier::Standard,
+            fee_rate: 0,
+            network_fee_sats: 0,
+        };
+        env.storage().persistent().set(&DataKey::WithdrawalStatus(withdrawal_id.clone()), &status);
+    });
+    withdrawal_id
+}
+
+#[test]
+fn test_withdrawals_timed_until_payout_confirms() {
+    let env = Env::default();
+    let (admin, contract_id, client) = setup(&env);
+
+    let first = seed_withdrawal(&env, &contract_id, 1, 999_400);
+    let second = seed_withdrawal(&env, &contract_id, 2, 999_700);
+    let payout = |input: u8| raw_bitcoin_tx(
+        &env,
+        &[(BytesN::from_array(&env, &[input; 32]), 0)],
+        &[(100_000, Bytes::from_array(&env, &[0x00, 0x14, input]))],
+    );
+
+    client.confirm_withdrawal_btc_tx(&admin, &first, &payout(7));
+    // Re-confirming the same payout is not a new sample
+    client.confirm_withdrawal_btc_tx(&admin, &first, &payout(7));
+    client.confirm_withdrawal_btc_tx(&admin, &second, &payout(8));
+
+    let breakdown = client.get_processing_times();
+    assert_eq!(breakdown.len(), 4);
+    let withdrawals = breakdown.get(1).unwrap();
+    assert_eq!(withdrawals.operation, ProcessingOperation::Withdrawal);
+    assert_eq!(withdrawals.sample_count, 2);
+    assert_eq!(withdrawals.last_ms, 300_000);
+    assert_eq!(withdrawals.max_ms, 600_000);
+    // 80% of the previous 600s average plus 20% of the 300s sample
+    assert_eq!(withdrawals.average_ms, 540_000);
+    assert_eq!(breakdown.get(0).unwrap().sample_count, 0);
+}
+
+#[test]
+fn test_system_metrics_weight_averages_by_sample_count() {
+    let env = Env::default();
+    let (_admin, contract_id, client) = setup(&env);
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(IntegrationRouter::get_system_metrics(&env).average_processing_time, 0);
+
+        IntegrationRouter::record_processing_time(&env, ProcessingOperation::Deposit, 999_940);
+        IntegrationRouter::record_processing_time(&env, ProcessingOperation::Exchange, 1_000_000);
+        IntegrationRouter::record_processing_time(&env, ProcessingOperation::Exchange, 999_990);
+    });
+
+    let exchanges = client.get_processing_times().get(2).unwrap();
+    assert_eq!(exchanges.average_ms, 2_000);
+    assert_eq!(exchanges.max_ms, 10_000);
+
+    env.as_contract(&contract_id, || {
+        let metrics = IntegrationRouter::get_system_metrics(&env);
+        assert_eq!(metrics.deposit_processing_time, 60_000);
+        assert_eq!(metrics.exchange_processing_time, 2_000);
+        assert_eq!(metrics.withdrawal_processing_time, 0);
+        assert_eq!(metrics.average_processing_time, (60_000 + 2_000 * 2) / 3);
+    });
+}
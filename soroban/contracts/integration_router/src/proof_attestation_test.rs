@@ -0,0 +1,136 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as TestAddress, Address, Bytes, BytesN, Env};
+
+fn setup(env: &Env) -> (Address, IntegrationRouterClient<'_>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+
+    (admin, client)
+}
+
+#[test]
+fn test_attestation_key_rotation() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+
+    let old_key = BytesN::from_array(&env, &[1u8; 32]);
+    let new_key = BytesN::from_array(&env, &[2u8; 32]);
+
+    client.register_attestation_key(&admin, &old_key);
+    assert_eq!(
+        client.try_register_attestation_key(&admin, &old_key),
+        Err(Ok(IntegrationError::DuplicateOperation))
+    );
+
+    client.rotate_attestation_key(&admin, &old_key, &new_key);
+
+    let keys = client.get_attestation_keys();
+    assert_eq!(keys.len(), 2);
+    assert!(client.get_attestation_key(&old_key).unwrap().revoked_at.is_some());
+    assert!(client.get_attestation_key(&new_key).unwrap().revoked_at.is_none());
+
+    // Revoking twice is rejected
+    assert_eq!(
+        client.try_revoke_attestation_key(&admin, &old_key),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
+
+#[test]
+fn test_proofs_require_active_signer_once_keys_registered() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+
+    let mut schedule = client.get_proof_schedule();
+    schedule.auto_verify = false;
+    client.configure_proof_schedule(&admin, &schedule);
+
+    let proof = client.generate_auto_proof_of_reserves(&admin);
+    assert!(client.get_proof_signing_payload(&proof.proof_id).is_some());
+
+    // Without registered keys only the arithmetic checks apply
+    assert_eq!(client.verify_proof_of_reserves(&admin, &proof.proof_id), ProofVerificationStatus::Verified);
+
+    let key = BytesN::from_array(&env, &[7u8; 32]);
+    client.register_attestation_key(&admin, &key);
+
+    // Unsigned proofs now fail
+    assert_eq!(client.verify_proof_of_reserves(&admin, &proof.proof_id), ProofVerificationStatus::Failed);
+
+    // Revoked keys cannot attest
+    client.revoke_attestation_key(&admin, &key);
+    assert_eq!(
+        client.try_submit_proof_attestation(&admin, &proof.proof_id, &key, &BytesN::from_array(&env, &[0u8; 64])),
+        Err(Ok(IntegrationError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_latest_verified_proof_skips_unverified_proofs() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    assert_eq!(client.get_latest_verified_proof(), None);
+
+    let mut schedule = client.get_proof_schedule();
+    schedule.auto_verify = false;
+    client.configure_proof_schedule(&admin, &schedule);
+
+    let first = client.generate_auto_proof_of_reserves(&admin);
+    client.verify_proof_of_reserves(&admin, &first.proof_id);
+    let second = client.generate_auto_proof_of_reserves(&admin);
+    client.verify_proof_of_reserves(&admin, &second.proof_id);
+    assert_eq!(client.get_latest_verified_proof().unwrap().proof_id, second.proof_id);
+
+    // Pending and failed proofs are never published
+    client.register_attestation_key(&admin, &BytesN::from_array(&env, &[7u8; 32]));
+    let failed = client.generate_auto_proof_of_reserves(&admin);
+    assert_eq!(client.verify_proof_of_reserves(&admin, &failed.proof_id), ProofVerificationStatus::Failed);
+    client.generate_auto_proof_of_reserves(&admin);
+    assert_eq!(client.get_latest_verified_proof().unwrap().proof_id, second.proof_id);
+
+    // A proof that fails re-verification falls back to the previous verified one
+    client.verify_proof_of_reserves(&admin, &second.proof_id);
+    let latest = client.get_latest_verified_proof().unwrap();
+    assert_eq!(latest.proof_id, first.proof_id);
+    assert_eq!(latest.verification_status, ProofVerificationStatus::Verified);
+}
+
+#[test]
+fn test_proof_signing_payload_is_domain_separated_and_bound_to_proof_id() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+
+    let mut schedule = client.get_proof_schedule();
+    schedule.auto_verify = false;
+    client.configure_proof_schedule(&admin, &schedule);
+
+    // Two proofs over identical reserve figures in the same ledger
+    let first = client.generate_auto_proof_of_reserves(&admin);
+    let second = client.generate_auto_proof_of_reserves(&admin);
+    assert_eq!(first.merkle_root, second.merkle_root);
+    assert_eq!(first.timestamp, second.timestamp);
+
+    let first_payload = client.get_proof_signing_payload(&first.proof_id).unwrap();
+    let second_payload = client.get_proof_signing_payload(&second.proof_id).unwrap();
+    assert_ne!(first_payload, second_payload);
+
+    let domain = Bytes::from_slice(&env, b"ISTSI_POR_V1");
+    assert_eq!(first_payload.slice(0..domain.len()), domain);
+
+    // The router address follows the tag, so another deployment signs different bytes
+    let router = client.address.clone().to_xdr(&env);
+    assert_eq!(first_payload.slice(domain.len()..domain.len() + router.len()), router);
+}
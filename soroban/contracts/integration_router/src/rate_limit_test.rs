@@ -0,0 +1,120 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as TestAddress, Ledger},
+    Address, BytesN, Env
+};
+
+#[test]
+fn test_operator_rate_limit_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_200;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+
+    let operator = Address::generate(&env);
+    client.set_user_role(&admin, &operator, &UserRole::Operator);
+    assert!(client.try_set_rate_limit_config(&operator, &client.get_rate_limit_config()).is_err());
+
+    client.set_rate_limit_config(&admin, &RateLimitConfig {
+        enabled: true,
+        window_seconds: 300,
+        max_deposits_per_window: 2,
+        max_withdrawals_per_window: 1,
+    });
+
+    env.as_contract(&contract_id, || {
+        IntegrationRouter::check_rate_limit(&env, &operator, VelocityOperation::Deposit);
+        IntegrationRouter::check_rate_limit(&env, &operator, VelocityOperation::Deposit);
+    });
+
+    let status = client.get_rate_limit_status(&operator);
+    assert_eq!(status.window_start, 1_000_200);
+    assert_eq!(status.window_ends_at, 1_000_500);
+    assert_eq!(status.deposits, 2);
+    assert_eq!(status.deposits_remaining, 0);
+    assert_eq!(status.withdrawals_remaining, 1);
+
+    let user = Address::generate(&env);
+    let deposit = client.try_execute_bitcoin_deposit(
+        &operator,
+        &user,
+        &1_000u64,
+        &BytesN::from_array(&env, &[7u8; 32]),
+        &6u32,
+    );
+    assert_eq!(deposit, Err(Ok(IntegrationError::RateLimited.into())));
+
+    // SuperAdmin keys are exempt
+    env.as_contract(&contract_id, || {
+        IntegrationRouter::check_rate_limit(&env, &admin, VelocityOperation::Deposit);
+        IntegrationRouter::check_rate_limit(&env, &admin, VelocityOperation::Deposit);
+        IntegrationRouter::check_rate_limit(&env, &admin, VelocityOperation::Deposit);
+    });
+
+    // The bucket resets with the next window
+    env.ledger().with_mut(|li| {
+        li.timestamp += 300;
+    });
+    let status = client.get_rate_limit_status(&operator);
+    assert_eq!(status.deposits, 0);
+    assert_eq!(status.deposits_remaining, 2);
+}
+
+#[test]
+fn test_rate_limit_bucket_lives_as_long_as_its_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 864_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+    client.set_rate_limit_config(&admin, &RateLimitConfig {
+        enabled: true,
+        window_seconds: 86_400,
+        max_deposits_per_window: 5,
+        max_withdrawals_per_window: 5,
+    });
+
+    let operator = Address::generate(&env);
+    client.set_user_role(&admin, &operator, &UserRole::Operator);
+    env.as_contract(&contract_id, || {
+        IntegrationRouter::check_rate_limit(&env, &operator, VelocityOperation::Withdrawal);
+        env.storage().instance().extend_ttl(DAY_IN_LEDGERS, DAY_IN_LEDGERS);
+    });
+
+    // An hour later, far past the minimum temporary TTL, the count holds
+    env.ledger().with_mut(|li| {
+        li.timestamp += 3_600;
+        li.sequence_number += 720;
+    });
+    let status = client.get_rate_limit_status(&operator);
+    assert_eq!(status.window_start, 864_000);
+    assert_eq!(status.withdrawals, 1);
+    assert_eq!(status.withdrawals_remaining, 4);
+}
@@ -0,0 +1,169 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{raw_bitcoin_tx, MockReserveManager, MockReserveManagerClient};
+use soroban_sdk::{testutils::Address as TestAddress, Address, Bytes, BytesN, Env};
+
+fn setup(env: &Env) -> (Address, Address, IntegrationRouterClient<'_>, MockReserveManagerClient<'_>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+    let reserve = MockReserveManagerClient::new(env, &env.register(MockReserveManager, ()));
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &reserve.address,
+    );
+
+    (admin, contract_id, client, reserve)
+}
+
+/// Payout to the user plus change back to custody, spending one reserve UTXO
+fn payout_tx(env: &Env, input: u8, payout: u64, change: u64) -> Bytes {
+    raw_bitcoin_tx(
+        env,
+        &[(BytesN::from_array(env, &[input; 32]), 0)],
+        &[
+            (payout, Bytes::from_array(env, &[0x00, 0x14, 0xaa])),
+            (change, Bytes::from_array(env, &[0x00, 0x14, 0xcc])),
+        ],
+    )
+}
+
+fn seed_withdrawal(env: &Env, contract_id: &Address, btc_tx_hash: Option<BytesN<32>>) -> BytesN<32> {
+    let withdrawal_id = BytesN::from_array(env, &[9u8; 32]);
+    env.as_contract(contract_id, || {
+        let status = WithdrawalStatus {
+            withdrawal_id: withdrawal_id.clone(),
+            user: Address::generate(env),
+            istsi_amount: 100_000_000,
+            btc_amount: 1,
+            btc_address: String::from_str(env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh"),
+            status: WithdrawalProcessingStatus::Completed,
+            operation_id: withdrawal_id.clone(),
+            btc_tx_hash,
+            created_at: 0,
+            updated_at: 0,
+            error_message: String::from_str(env, ""),
+            fee_tier: BtcFeeTier::Standard,
+            fee_rate: 0,
+            network_fee_sats: 0,
+        };
+        env.storage().persistent().set(&DataKey::WithdrawalStatus(withdrawal_id.clone()), &status);
+    });
+    withdrawal_id
+}
+
+#[test]
+fn test_replacement_chain_and_original_confirming() {
+    let env = Env::default();
+    let (admin, contract_id, client, reserve) = setup(&env);
+
+    let original = payout_tx(&env, 1, 90_000_000, 9_990_000);
+    let bump_1 = payout_tx(&env, 1, 90_000_000, 9_980_000);
+    let bump_2 = payout_tx(&env, 1, 90_000_000, 9_970_000);
+    let (original_id, bump_1_id, bump_2_id) = (
+        bitcoin_tx::txid(&env, &original),
+        bitcoin_tx::txid(&env, &bump_1),
+        bitcoin_tx::txid(&env, &bump_2),
+    );
+    let withdrawal_id = seed_withdrawal(&env, &contract_id, Some(original_id.clone()));
+
+    client.record_btc_tx_replacement(&admin, &withdrawal_id, &original, &bump_1, &10u64);
+
+    // Fee rate must increase, and only the live transaction can be replaced
+    assert_eq!(
+        client.try_record_btc_tx_replacement(&admin, &withdrawal_id, &bump_1, &bump_2, &10u64),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert_eq!(
+        client.try_record_btc_tx_replacement(&admin, &withdrawal_id, &original, &bump_2, &20u64),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    client.record_btc_tx_replacement(&admin, &withdrawal_id, &bump_1, &bump_2, &20u64);
+    assert_eq!(client.get_withdrawal_status(&withdrawal_id).unwrap().btc_tx_hash, Some(bump_2_id.clone()));
+
+    let history = client.get_withdrawal_tx_history(&withdrawal_id);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap().state, BtcTxState::Replaced);
+    assert_eq!(history.get(2).unwrap().replaces, Some(bump_1_id.clone()));
+    assert_eq!(client.get_withdrawal_by_btc_tx(&original_id), Some(withdrawal_id.clone()));
+
+    // The original wins the race; it becomes the payout of record and its input leaves the UTXO ledger
+    client.confirm_withdrawal_btc_tx(&admin, &withdrawal_id, &original);
+    let history = client.get_withdrawal_tx_history(&withdrawal_id);
+    assert_eq!(history.get(0).unwrap().state, BtcTxState::Confirmed);
+    assert_eq!(history.get(1).unwrap().state, BtcTxState::Dropped);
+    assert_eq!(history.get(2).unwrap().state, BtcTxState::Dropped);
+    assert_eq!(client.get_withdrawal_status(&withdrawal_id).unwrap().btc_tx_hash, Some(original_id));
+    assert_eq!(
+        reserve.get_payout_spends(&withdrawal_id),
+        Vec::from_array(&env, [UtxoOutpoint { txid: BytesN::from_array(&env, &[1u8; 32]), vout: 0 }])
+    );
+
+    assert_eq!(
+        client.try_confirm_withdrawal_btc_tx(&admin, &withdrawal_id, &bump_2),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
+
+#[test]
+fn test_replacements_must_be_fee_bumps_of_the_live_transaction() {
+    let env = Env::default();
+    let (admin, contract_id, client, _) = setup(&env);
+
+    let original = payout_tx(&env, 1, 90_000_000, 9_990_000);
+    let withdrawal_id = seed_withdrawal(&env, &contract_id, Some(bitcoin_tx::txid(&env, &original)));
+
+    let rejected = [
+        // Spends a different output, so both could confirm and pay twice
+        payout_tx(&env, 2, 90_000_000, 9_980_000),
+        // Pays the same or more in total
+        payout_tx(&env, 1, 90_000_000, 9_990_000),
+        // Takes the fee from both outputs
+        payout_tx(&env, 1, 89_999_000, 9_989_000),
+        // Redirects the change to a new script
+        raw_bitcoin_tx(
+            &env,
+            &[(BytesN::from_array(&env, &[1u8; 32]), 0)],
+            &[
+                (90_000_000, Bytes::from_array(&env, &[0x00, 0x14, 0xaa])),
+                (9_980_000, Bytes::from_array(&env, &[0x00, 0x14, 0xee])),
+            ],
+        ),
+        // Not a transaction
+        Bytes::from_array(&env, &[1u8; 40]),
+    ];
+    for replacement in rejected.iter() {
+        assert_eq!(
+            client.try_record_btc_tx_replacement(&admin, &withdrawal_id, &original, replacement, &10u64),
+            Err(Ok(IntegrationError::BitcoinTransactionFailed))
+        );
+    }
+
+    // The fee may come out of the payout when there is no change to shrink
+    let payout_only = raw_bitcoin_tx(
+        &env,
+        &[(BytesN::from_array(&env, &[1u8; 32]), 0)],
+        &[(90_000_000, Bytes::from_array(&env, &[0x00, 0x14, 0xaa]))],
+    );
+    let bumped = raw_bitcoin_tx(
+        &env,
+        &[(BytesN::from_array(&env, &[1u8; 32]), 0), (BytesN::from_array(&env, &[3u8; 32]), 1)],
+        &[(89_990_000, Bytes::from_array(&env, &[0x00, 0x14, 0xaa]))],
+    );
+    let other_id = BytesN::from_array(&env, &[8u8; 32]);
+    env.as_contract(&contract_id, || {
+        let mut status: WithdrawalStatus = env.storage().persistent().get(&DataKey::WithdrawalStatus(withdrawal_id.clone())).unwrap();
+        status.withdrawal_id = other_id.clone();
+        status.btc_tx_hash = Some(bitcoin_tx::txid(&env, &payout_only));
+        env.storage().persistent().set(&DataKey::WithdrawalStatus(other_id.clone()), &status);
+    });
+    client.record_btc_tx_replacement(&admin, &other_id, &payout_only, &bumped, &10u64);
+}
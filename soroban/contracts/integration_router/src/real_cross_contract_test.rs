@@ -10,6 +10,7 @@ use soroban_sdk::{
 #[test]
 fn test_real_cross_contract_call_execution() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
@@ -60,14 +61,15 @@ fn test_real_cross_contract_call_execution() {
     
     let result = client.execute_contract_call(&operator, &call);
     
-    // Verify the call was executed (even if it fails due to no actual contract)
-    assert!(result.execution_time > 0);
+    // Verify the call was executed (even if it fails due to no actual contract).
+    // Ledger time does not advance within one invocation, so only resources are recorded.
     assert!(result.gas_used > 0);
 }
 
 #[test]
 fn test_batch_operation_with_real_calls() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
@@ -149,15 +151,18 @@ fn test_batch_operation_with_real_calls() {
     
     let result = client.execute_batch_operation(&operator, &batch);
     
-    // Verify batch execution
+    // Verify batch execution: the first call has no contract behind it,
+    // so the atomic batch stops there and rolls back
     assert_eq!(result.operation_id, operation_id);
-    assert_eq!(result.call_results.len(), 2);
-    assert!(result.total_execution_time > 0);
+    assert_eq!(result.call_results.len(), 1);
+    assert!(!result.overall_success);
+    assert!(result.rollback_executed);
 }
 
 #[test]
 fn test_gas_estimation_and_optimization() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
@@ -224,6 +229,7 @@ fn test_gas_estimation_and_optimization() {
 #[test]
 fn test_parameter_parsing_and_serialization() {
     let env = Env::default();
+    env.mock_all_auths();
     
     // Test parameter parsing
     let params = vec![
@@ -243,6 +249,7 @@ fn test_parameter_parsing_and_serialization() {
 #[test]
 fn test_retry_logic_with_failures() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
@@ -280,13 +287,14 @@ fn test_retry_logic_with_failures() {
     
     // Should fail but still record execution details
     assert!(!result.success);
-    assert!(result.execution_time > 0);
+    assert!(result.gas_used > 0);
     assert!(!result.error_message.is_empty());
 }
 
 #[test]
 fn test_timeout_handling() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
@@ -328,12 +336,13 @@ fn test_timeout_handling() {
     let result = client.execute_contract_call(&operator, &timeout_call);
     
     // Should handle timeout gracefully
-    assert!(result.execution_time >= 0);
+    assert!(result.execution_time <= timeout_call.timeout);
 }
 
 #[test]
 fn test_cross_contract_config_management() {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, IntegrationRouter);
     let client = IntegrationRouterClient::new(&env, &contract_id);
     
@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_RESERVES_SATS};
+use soroban_sdk::Env;
+
+/// Seed a withdrawal status for `btc_amount` satoshis, then move it to `status`
+fn seed_withdrawal(h: &TestHarness, seed: u8, btc_amount: u64, status: WithdrawalProcessingStatus) -> BytesN<32> {
+    let withdrawal_id = BytesN::from_array(&h.env, &[seed; 32]);
+    h.env.as_contract(&h.router.address, || {
+        IntegrationRouter::initialize_withdrawal_status(
+            &h.env,
+            &withdrawal_id,
+            &h.user(2),
+            IntegrationRouter::sats_to_istsi(&h.env, btc_amount),
+            &String::from_str(&h.env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh"),
+            &withdrawal_id,
+            BtcFeeTier::Standard
+        );
+        IntegrationRouter::update_withdrawal_status(&h.env, &withdrawal_id, status, None);
+    });
+    withdrawal_id
+}
+
+fn delta(breakdown: &DiscrepancyBreakdown, category: ReconciliationCategory) -> i64 {
+    breakdown.category_deltas.iter().find(|d| d.category == category).unwrap().delta
+}
+
+#[test]
+fn test_early_counted_deposit_is_localized() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let first = h.router.execute_reconciliation_check(&h.operator);
+    assert_eq!(first.breakdown.previous_reconciliation_id, None);
+    assert_eq!(first.breakdown.diverged_category, ReconciliationCategory::Unattributed);
+    assert_eq!(first.snapshot.btc_reserves, HARNESS_RESERVES_SATS);
+
+    // A deposit minted before its reserves were registered moves supply alone
+    env.as_contract(&h.router.address, || IntegrationRouter::record_confirmed_deposit(&env, 2_000_000));
+    h.reserve.set_position(&HARNESS_RESERVES_SATS, &(HARNESS_RESERVES_SATS + 2_000_000));
+    let second = h.router.execute_reconciliation_check(&h.operator);
+    assert_eq!(second.breakdown.previous_reconciliation_id, Some(first.reconciliation_id));
+    assert_eq!(second.breakdown.discrepancy_delta, -2_000_000);
+    assert_eq!(second.breakdown.supply_delta, 2_000_000);
+    assert_eq!(delta(&second.breakdown, ReconciliationCategory::ConfirmedDeposits), 2_000_000);
+    assert_eq!(second.breakdown.diverged_category, ReconciliationCategory::ConfirmedDeposits);
+
+    // The stored result carries the breakdown
+    let stored = h.router.get_reconciliation_result(&second.reconciliation_id).unwrap();
+    assert_eq!(stored.breakdown, second.breakdown);
+    assert_eq!(stored.snapshot.confirmed_deposits, 2_000_000);
+
+    // Balanced movement leaves nothing to attribute
+    env.as_contract(&h.router.address, || IntegrationRouter::record_confirmed_deposit(&env, 1_000_000));
+    h.reserve.set_position(&(HARNESS_RESERVES_SATS + 1_000_000), &(HARNESS_RESERVES_SATS + 3_000_000));
+    let third = h.router.execute_reconciliation_check(&h.operator);
+    assert_eq!(third.breakdown.discrepancy_delta, 0);
+    assert_eq!(third.breakdown.diverged_category, ReconciliationCategory::Unattributed);
+}
+
+#[test]
+fn test_pending_withdrawals_are_tracked_and_diffed() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    h.router.execute_reconciliation_check(&h.operator);
+
+    // Withdrawals count as pending from the burn until they are paid out or reversed
+    let held = seed_withdrawal(&h, 1, 3_000_000, WithdrawalProcessingStatus::ApprovalPending);
+    seed_withdrawal(&h, 2, 500_000, WithdrawalProcessingStatus::Burning);
+    assert_eq!(h.router.get_reconciliation_totals().pending_withdrawals, 3_000_000);
+
+    // Burned supply with reserves not yet paid out
+    h.reserve.set_position(&HARNESS_RESERVES_SATS, &(HARNESS_RESERVES_SATS - 3_000_000));
+    let result = h.router.execute_reconciliation_check(&h.operator);
+    assert_eq!(result.breakdown.discrepancy_delta, 3_000_000);
+    assert_eq!(result.breakdown.diverged_category, ReconciliationCategory::PendingWithdrawals);
+    assert_eq!(delta(&result.breakdown, ReconciliationCategory::FeesAccrued), 0);
+
+    // An unexplained movement is not pinned on any category
+    env.as_contract(&h.router.address, || {
+        IntegrationRouter::update_withdrawal_status(&env, &held, WithdrawalProcessingStatus::Completed, None);
+    });
+    assert_eq!(h.router.get_reconciliation_totals().pending_withdrawals, 0);
+    h.reserve.set_position(&(HARNESS_RESERVES_SATS - 1_000_000), &(HARNESS_RESERVES_SATS - 3_000_000));
+    let result = h.router.execute_reconciliation_check(&h.operator);
+    assert_eq!(result.breakdown.discrepancy_delta, -1_000_000);
+    assert_eq!(delta(&result.breakdown, ReconciliationCategory::PendingWithdrawals), -3_000_000);
+    assert_eq!(result.breakdown.diverged_category, ReconciliationCategory::Unattributed);
+}
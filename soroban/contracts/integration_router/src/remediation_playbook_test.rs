@@ -0,0 +1,102 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_RESERVES_SATS};
+use soroban_sdk::{testutils::Address as TestAddress, vec, Address, Env};
+
+fn ratio_call(h: &TestHarness) -> ContractCall {
+    ContractCall {
+        target_contract: h.reserve.address.clone(),
+        function_name: String::from_str(&h.env, "get_ratio"),
+        parameters: Vec::new(&h.env),
+        expected_return_type: String::from_str(&h.env, "u64"),
+        timeout: 300,
+        retry_count: 0,
+    }
+}
+
+/// Raise an alert for a deposit minted before its reserves were registered
+fn early_deposit_alert(h: &TestHarness) -> BytesN<32> {
+    h.router.execute_reconciliation_check(&h.operator);
+    h.env.as_contract(&h.router.address, || IntegrationRouter::record_confirmed_deposit(&h.env, 2_000_000));
+    h.reserve.set_position(&HARNESS_RESERVES_SATS, &(HARNESS_RESERVES_SATS + 2_000_000));
+    h.router.execute_reconciliation_check(&h.operator);
+
+    let alert = h.router.get_active_discrepancy_alerts().get_unchecked(0);
+    assert_eq!(alert.category, ReconciliationCategory::ConfirmedDeposits);
+    alert.alert_id
+}
+
+fn compliance_officer(h: &TestHarness) -> Address {
+    let officer = Address::generate(&h.env);
+    h.router.set_user_role(&h.admin, &officer, &UserRole::ComplianceOfficer);
+    officer
+}
+
+#[test]
+fn test_playbook_preview_execution_and_verification() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let alert_id = early_deposit_alert(&h);
+    let officer = compliance_officer(&h);
+    let class = DiscrepancyClass::UnconfirmedDepositCountedEarly;
+    let description = String::from_str(&env, "Re-read the reserve position after registering the deposit");
+
+    assert_eq!(h.router.try_preview_remediation(&alert_id), Err(Ok(IntegrationError::InvalidOperationState)));
+    assert!(h.router.try_register_remediation_playbook(&h.operator, &class, &vec![&env, ratio_call(&h)], &description).is_err());
+    assert_eq!(
+        h.router.try_register_remediation_playbook(&h.admin, &class, &Vec::new(&env), &description),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    h.router.register_remediation_playbook(&h.admin, &class, &vec![&env, ratio_call(&h)], &description);
+
+    let preview = h.router.preview_remediation(&alert_id);
+    assert_eq!(preview.class, class);
+    assert_eq!(preview.calls.len(), 1);
+    assert_eq!(preview.discrepancy_amount, -2_000_000);
+    assert!(preview.within_limits);
+    assert_eq!(h.router.get_remediation_record(&alert_id), None);
+
+    // Verification still sees the shortfall, so the alert stays open
+    assert!(h.router.try_execute_remediation(&h.operator, &alert_id).is_err());
+    let record = h.router.execute_remediation(&officer, &alert_id);
+    assert!(record.call_results.get_unchecked(0).success);
+    assert!(!record.verified);
+    assert_eq!(h.router.get_active_discrepancy_alerts().len(), 2);
+
+    // Once the reserves are registered the retry verifies and closes the alert
+    h.reserve.set_position(&(HARNESS_RESERVES_SATS + 2_000_000), &(HARNESS_RESERVES_SATS + 2_000_000));
+    let record = h.router.execute_remediation(&officer, &alert_id);
+    assert!(record.verified);
+    assert_eq!(h.router.get_reconciliation_result(&record.verification_id).unwrap().status, ReconciliationStatus::Completed);
+    assert_eq!(h.router.get_remediation_record(&alert_id), Some(record));
+    assert!(!h.router.get_active_discrepancy_alerts().iter().any(|alert| alert.alert_id == alert_id));
+    assert_eq!(h.router.try_execute_remediation(&officer, &alert_id), Err(Ok(IntegrationError::DuplicateOperation)));
+}
+
+#[test]
+fn test_failed_calls_stop_the_playbook_and_fail_verification() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let alert_id = early_deposit_alert(&h);
+    let officer = compliance_officer(&h);
+    assert_eq!(
+        h.router.try_execute_remediation(&officer, &BytesN::from_array(&env, &[9u8; 32])),
+        Err(Ok(IntegrationError::ContractNotFound))
+    );
+
+    let missing = ContractCall { function_name: String::from_str(&env, "not_a_function"), ..ratio_call(&h) };
+    h.router.register_remediation_playbook(
+        &h.admin,
+        &DiscrepancyClass::UnconfirmedDepositCountedEarly,
+        &vec![&env, missing, ratio_call(&h)],
+        &String::from_str(&env, "")
+    );
+
+    // The reserves are fine again, but the failed call keeps the alert open
+    h.reserve.set_position(&(HARNESS_RESERVES_SATS + 2_000_000), &(HARNESS_RESERVES_SATS + 2_000_000));
+    let record = h.router.execute_remediation(&officer, &alert_id);
+    assert_eq!(record.call_results.len(), 1);
+    assert!(!record.verified);
+    assert!(h.router.get_active_discrepancy_alerts().iter().any(|alert| alert.alert_id == alert_id));
+}
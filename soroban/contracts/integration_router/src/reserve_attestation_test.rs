@@ -0,0 +1,65 @@
+#![cfg(test)]
+
+use crate::testing::{TestHarness, HARNESS_RESERVES_SATS, HARNESS_START_TIME};
+use crate::ReconciliationStatus;
+use soroban_sdk::{testutils::Events, Env, Symbol, TryFromVal};
+
+fn published_attestation_gap(env: &Env) -> bool {
+    env.events().all().iter().any(|(_, topics, _)| {
+        Symbol::try_from_val(env, &topics.get_unchecked(0)) == Ok(Symbol::new(env, "recon_att"))
+    })
+}
+
+#[test]
+fn test_reconciliation_reports_attested_reserves_as_a_separate_source() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+
+    // Managers without attestations report none
+    let result = h.router.execute_reconciliation_check(&h.operator);
+    assert_eq!((result.attested_reserves, result.attested_as_of, result.attestation_fresh), (0, 0, false));
+
+    // A stale attestation is carried but does not flag the gap
+    let as_of = HARNESS_START_TIME - 3_600;
+    h.reserve.set_attested(&(HARNESS_RESERVES_SATS - 5_000_000, as_of, false));
+    let result = h.router.execute_reconciliation_check(&h.operator);
+    assert_eq!(result.attested_reserves, HARNESS_RESERVES_SATS - 5_000_000);
+    assert!(!published_attestation_gap(&env));
+
+    // A fresh attestation that disagrees with the reserve counter is flagged
+    h.reserve.set_attested(&(HARNESS_RESERVES_SATS - 5_000_000, as_of, true));
+    let result = h.router.execute_reconciliation_check(&h.operator);
+    assert!(result.attestation_fresh);
+    assert_eq!(result.attested_as_of, as_of);
+    assert_eq!(result.btc_reserves, HARNESS_RESERVES_SATS);
+    assert!(published_attestation_gap(&env));
+}
+
+#[test]
+fn test_reconciliation_compares_reserves_with_the_utxo_ledger() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+
+    // No UTXO ledger in use: nothing to compare
+    let result = h.router.execute_reconciliation_check(&h.operator);
+    assert_eq!((result.utxo_reserves, result.utxo_discrepancy), (0, 0));
+    assert_eq!(result.status, ReconciliationStatus::Completed);
+
+    // Allocated reserves are off-chain, so the ledger only has to cover the rest
+    h.reserve.set_allocated(&20_000_000);
+    h.reserve.set_utxo_total(&Some(HARNESS_RESERVES_SATS - 20_000_000));
+    let result = h.router.execute_reconciliation_check(&h.operator);
+    assert_eq!(result.utxo_reserves, HARNESS_RESERVES_SATS - 20_000_000);
+    assert_eq!(result.utxo_discrepancy, 0);
+    assert_eq!(result.status, ReconciliationStatus::Completed);
+
+    // The counter claims 5% more than the chain holds, even though the ratio is 1:1
+    h.reserve.set_utxo_total(&Some(HARNESS_RESERVES_SATS - 25_000_000));
+    let result = h.router.execute_reconciliation_check(&h.operator);
+    assert_eq!(result.discrepancy, 0);
+    assert_eq!(result.utxo_discrepancy, -5_000_000);
+    assert_eq!(result.status, ReconciliationStatus::DiscrepancyDetected);
+    assert!(env.events().all().iter().any(|(_, topics, _)| {
+        Symbol::try_from_val(&env, &topics.get_unchecked(0)) == Ok(Symbol::new(&env, "recon_utx"))
+    }));
+}
@@ -0,0 +1,177 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as TestAddress, Events},
+    Address, BytesN, Env, TryFromVal
+};
+
+/// Minimal KYC registry approving every operation
+#[contract]
+pub struct MockGuardrailKycRegistry;
+
+#[contractimpl]
+impl MockGuardrailKycRegistry {
+    pub fn verify_ic(_env: Env, _user: String, _operation: String, _amount: String) -> bool {
+        true
+    }
+
+    pub fn is_blacklisted(_env: Env, _address: Address) -> bool {
+        false
+    }
+}
+
+/// Reserve manager reporting a settable reserve position; unreadable until set
+#[contract]
+pub struct MockGuardrailReserve;
+
+#[contractimpl]
+impl MockGuardrailReserve {
+    pub fn set_position(env: Env, reserves: u64, supply: u64) {
+        env.storage().instance().set(&symbol_short!("reserves"), &reserves);
+        env.storage().instance().set(&symbol_short!("supply"), &supply);
+    }
+
+    pub fn get_ratio(_env: Env) -> u64 {
+        10000
+    }
+
+    pub fn get_total_reserves(env: Env) -> u64 {
+        env.storage().instance().get(&symbol_short!("reserves"))
+            .unwrap_or_else(|| panic_with_error!(&env, IntegrationError::ContractCallFailed))
+    }
+
+    pub fn get_total_token_supply(env: Env) -> u64 {
+        env.storage().instance().get(&symbol_short!("supply"))
+            .unwrap_or_else(|| panic_with_error!(&env, IntegrationError::ContractCallFailed))
+    }
+}
+
+struct Setup<'a> {
+    client: IntegrationRouterClient<'a>,
+    admin: Address,
+    user: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+    let reserve_id = env.register(MockGuardrailReserve, ());
+    let reserve = MockGuardrailReserveClient::new(env, &reserve_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &env.register(MockGuardrailKycRegistry, ()),
+        &Address::generate(env),
+        &Address::generate(env),
+        &reserve_id,
+    );
+
+    // 98% of the outstanding supply is backed
+    reserve.set_position(&98_000_000u64, &100_000_000u64);
+
+    Setup { client, admin, user: Address::generate(env) }
+}
+
+fn published(env: &Env, topic: &str) -> bool {
+    let topic = Symbol::new(env, topic);
+    env.events().all().iter().any(|(_, topics, _)| {
+        topics.get(0).is_some_and(|first| Symbol::try_from_val(env, &first).is_ok_and(|symbol| symbol == topic))
+    })
+}
+
+/// Run a tracked deposit, returning its error and whether it logged a near miss or a block
+fn deposit(env: &Env, s: &Setup, btc_amount: u64, seed: u8) -> (String, bool, bool) {
+    let operation_id = s.client.execute_btc_deposit_tracked(&s.admin, &s.user, &btc_amount, &BytesN::from_array(env, &[seed; 32]), &6u32);
+    let (warned, blocked) = (published(env, "rsv_warn"), published(env, "rsv_blk"));
+    (s.client.get_operation_status(&operation_id).unwrap().error_message, warned, blocked)
+}
+
+#[test]
+fn test_guardrails_reject_mints_below_floor_and_log_near_misses() {
+    let env = Env::default();
+    let s = setup(&env);
+    let floor_error = String::from_str(&env, "Reserve ratio would fall below the guardrail floor");
+
+    assert_eq!(s.client.get_reserve_guardrails(), None);
+    assert_eq!(
+        s.client.try_set_reserve_guardrails(&s.admin, &ReserveGuardrails { min_ratio_bps: 0, warning_band_bps: 50 }),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    let guardrails = ReserveGuardrails { min_ratio_bps: 9900, warning_band_bps: 50 };
+    s.client.set_reserve_guardrails(&s.admin, &guardrails);
+    assert_eq!(s.client.get_reserve_guardrails(), Some(guardrails));
+
+    // Minting 0.5 BTC leaves 1.48 BTC backing 1.5 BTC of supply (98.66%)
+    assert_eq!(deposit(&env, &s, 50_000_000, 1), (floor_error.clone(), false, true));
+    let report = s.client.simulate_bitcoin_deposit(&s.admin, &s.user, &50_000_000u64, &BytesN::from_array(&env, &[2u8; 32]), &6u32);
+    assert!(!report.steps.iter().any(|step| step.name == String::from_str(&env, "ratio_floor") && step.passed));
+
+    // A 1 BTC mint lands on the floor (99%) and is logged as a near miss
+    let (error, warned, blocked) = deposit(&env, &s, 100_000_000, 3);
+    assert!(error != floor_error && warned && !blocked);
+
+    // A 3 BTC mint clears the warning band (99.5%)
+    let (error, warned, blocked) = deposit(&env, &s, 300_000_000, 4);
+    assert!(error != floor_error && !warned && !blocked);
+}
+
+#[test]
+fn test_guardrails_project_withdrawals_and_fail_closed() {
+    let env = Env::default();
+    let s = setup(&env);
+    let btc_address = String::from_str(&env, "bc1qguardrail");
+
+    let floor_ok = |istsi_amount: u64| {
+        let report = s.client.simulate_token_withdrawal(&s.admin, &s.user, &istsi_amount, &btc_address, &None);
+        report.steps.iter().any(|step| step.name == String::from_str(&env, "ratio_floor") && step.passed)
+    };
+
+    // Without guardrails every projection passes
+    assert!(floor_ok(90_000_000 * 100_000_000));
+
+    // Withdrawals shrink both sides of an under-reserved book, lowering the ratio
+    s.client.set_reserve_guardrails(&s.admin, &ReserveGuardrails { min_ratio_bps: 9500, warning_band_bps: 0 });
+    assert!(floor_ok(60_000_000 * 100_000_000));
+    assert!(!floor_ok(70_000_000 * 100_000_000));
+
+    // A reserve manager that cannot report its position blocks mints
+    s.client.update_contract_address(&s.admin, &String::from_str(&env, "reserve_manager"), &env.register(MockGuardrailReserve, ()));
+    assert_eq!(deposit(&env, &s, 100_000_000, 1).0, String::from_str(&env, "Failed to read reserve ratio"));
+}
+
+#[test]
+fn test_guardrail_checks_record_blocks_that_workflows_revert() {
+    let env = Env::default();
+    let s = setup(&env);
+    let operation = String::from_str(&env, "bitcoin_deposit");
+
+    // Without guardrails the check passes without logging
+    let check = s.client.check_reserve_guardrails(&s.admin, &operation, &50_000_000u64, &0u64);
+    assert!(check.allowed && !check.near_floor && check.min_ratio_bps == 0);
+
+    s.client.set_reserve_guardrails(&s.admin, &ReserveGuardrails { min_ratio_bps: 9900, warning_band_bps: 50 });
+
+    // The untracked deposit reverts, taking its block log with it
+    assert!(s.client.try_execute_bitcoin_deposit(&s.admin, &s.user, &50_000_000u64, &BytesN::from_array(&env, &[1u8; 32]), &6u32).is_err());
+
+    // The check commits its log and reports the projection
+    let check = s.client.check_reserve_guardrails(&s.admin, &operation, &50_000_000u64, &0u64);
+    assert_eq!(check, GuardrailCheck { ratio_bps: 9866, min_ratio_bps: 9900, allowed: false, near_floor: false });
+    assert!(published(&env, "rsv_blk"));
+
+    let check = s.client.check_reserve_guardrails(&s.admin, &operation, &100_000_000u64, &0u64);
+    assert!(check.allowed && check.near_floor);
+    assert!(published(&env, "rsv_warn"));
+
+    // An unreadable reserve manager fails the check
+    s.client.update_contract_address(&s.admin, &String::from_str(&env, "reserve_manager"), &env.register(MockGuardrailReserve, ()));
+    assert_eq!(
+        s.client.try_check_reserve_guardrails(&s.admin, &operation, &50_000_000u64, &0u64),
+        Err(Ok(IntegrationError::ContractCallFailed))
+    );
+}
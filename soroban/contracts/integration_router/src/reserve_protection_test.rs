@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, WithdrawalScenario, HARNESS_START_TIME};
+use soroban_sdk::{testutils::Ledger, Env};
+
+const ISTSI_PER_SAT: u64 = 100_000_000;
+
+fn protect(h: &TestHarness, drill: bool) -> EmergencyResponseResult {
+    h.router.execute_emergency_response(
+        &h.admin,
+        &EmergencyResponseType::ReserveProtection,
+        &String::from_str(&h.env, "custodian outage"),
+        &Vec::new(&h.env),
+        &drill
+    )
+}
+
+fn requires_approval(h: &TestHarness, btc_amount: u64) -> bool {
+    h.env.as_contract(&h.router.address, || IntegrationRouter::requires_withdrawal_approval(&h.env, btc_amount))
+}
+
+#[test]
+fn test_protection_mode_throttles_withdrawals_exchanges_and_reconciliation() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let config = ReserveProtectionConfig { withdrawal_limit_bps: 1_000, approval_window: 1_800, reconciliation_frequency: 600 };
+    assert_eq!(
+        h.router.try_set_reserve_protection_config(&h.admin, &ReserveProtectionConfig { withdrawal_limit_bps: 10_001, ..config.clone() }),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert!(h.router.try_set_reserve_protection_config(&h.operator, &config).is_err());
+    h.router.set_reserve_protection_config(&h.admin, &config);
+    assert!(!h.router.get_protection_mode_state().active);
+
+    let response = protect(&h, false);
+    assert_eq!(response.actions_taken.len(), 4);
+    let state = h.router.get_protection_mode_state();
+    assert!(state.active && state.exchanges_paused);
+    assert_eq!((state.response_id, state.config, state.activated_at), (response.response_id.clone(), config, HARNESS_START_TIME));
+    assert!(h.router.is_workflow_paused(&PauseWorkflow::Exchanges));
+
+    // Every withdrawal waits for a second approval, within a tenth of the 1 BTC daily limit
+    assert!(requires_approval(&h, 1));
+    let within_limit = WithdrawalScenario::new(&h, 10_000_000 * ISTSI_PER_SAT).run();
+    assert_ne!(within_limit.result, Err(Some(IntegrationError::RateLimited)));
+    let over_limit = WithdrawalScenario::new(&h, 10_000_001 * ISTSI_PER_SAT).run();
+    assert_eq!(over_limit.result, Err(Some(IntegrationError::RateLimited)));
+
+    // Reconciliation runs on the shorter protection schedule
+    h.router.execute_reconciliation_check(&h.operator);
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 600);
+    assert!(h.router.trigger_auto_reconciliation().is_some());
+
+    // Resolving the response lifts the mode and restores normal parameters
+    h.router.resolve_emergency_response(&h.admin, &response.response_id, &String::from_str(&env, "custodian back"));
+    let state = h.router.get_protection_mode_state();
+    assert!(!state.active);
+    assert_eq!(state.lifted_at, HARNESS_START_TIME + 600);
+    assert!(!h.router.is_workflow_paused(&PauseWorkflow::Exchanges));
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 1_200);
+    assert!(h.router.trigger_auto_reconciliation().is_none());
+    assert!(!requires_approval(&h, 1));
+    let over_protected_limit = WithdrawalScenario::new(&h, 10_000_001 * ISTSI_PER_SAT).run();
+    assert_ne!(over_protected_limit.result, Err(Some(IntegrationError::RateLimited)));
+}
+
+#[test]
+fn test_protection_leaves_an_existing_exchange_pause_in_place() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    h.router.pause_workflow(&h.admin, &PauseWorkflow::Exchanges, &String::from_str(&env, "pair review"));
+
+    // Drills plan the actions without activating the mode
+    h.router.start_emergency_drill(&h.admin, &EmergencyResponseType::ReserveProtection, &String::from_str(&env, "drill"));
+    assert_eq!(protect(&h, true).actions_taken.len(), 3);
+    assert!(!h.router.get_protection_mode_state().active);
+
+    let response = protect(&h, false);
+    assert!(!h.router.get_protection_mode_state().exchanges_paused);
+    h.router.resolve_emergency_response(&h.admin, &response.response_id, &String::from_str(&env, "resolved"));
+    assert!(h.router.is_workflow_paused(&PauseWorkflow::Exchanges));
+}
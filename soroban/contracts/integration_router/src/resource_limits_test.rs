@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::TestHarness;
+use soroban_sdk::{vec, Env};
+
+fn call(h: &TestHarness, function_name: &str) -> ContractCall {
+    ContractCall {
+        target_contract: h.kyc.address.clone(),
+        function_name: String::from_str(&h.env, function_name),
+        parameters: Vec::new(&h.env),
+        expected_return_type: String::from_str(&h.env, "u32"),
+        timeout: 300,
+        retry_count: 0,
+    }
+}
+
+fn profile(cpu_insns: u64) -> ResourceProfile {
+    ResourceProfile { cpu_insns, mem_bytes: 1_000, ledger_entries: 1 }
+}
+
+#[test]
+fn test_profiles_and_limits_drive_batch_splitting() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let light = String::from_str(&env, "get_tier");
+    let heavy = String::from_str(&env, "set_tier");
+
+    assert_eq!(h.router.get_call_resource_profile(&light).cpu_insns, 10_000_000);
+    h.router.set_call_resource_profile(&h.admin, &light, &profile(20_000_000));
+    h.router.set_call_resource_profile(&h.admin, &heavy, &profile(60_000_000));
+    assert_eq!(h.router.get_call_resource_profile(&heavy), profile(60_000_000));
+
+    let invalid = ResourceLimits {
+        max_call_cpu_insns: 80_000_000,
+        max_batch_cpu_insns: 50_000_000,
+        max_batch_mem_bytes: 1_000_000,
+        max_batch_ledger_entries: 10,
+    };
+    assert_eq!(
+        h.router.try_set_resource_limits(&h.admin, &invalid),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert!(h.router.try_set_resource_limits(&h.operator, &ResourceLimits { max_call_cpu_insns: 50_000_000, ..invalid.clone() }).is_err());
+    h.router.set_resource_limits(&h.admin, &ResourceLimits { max_batch_cpu_insns: 100_000_000, ..invalid });
+
+    let calls = vec![&env, call(&h, "get_tier"), call(&h, "set_tier"), call(&h, "get_tier"), call(&h, "get_tier")];
+    assert_eq!(h.router.estimate_batch_resources(&calls).cpu_insns, 120_000_000);
+
+    // 20M + 60M + 20M fits the 100M batch ceiling; the last call starts a new chunk
+    let chunks = h.router.split_batch_by_resources(&calls);
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks.get_unchecked(0).len(), 3);
+    assert_eq!(chunks.get_unchecked(1).len(), 1);
+}
+
+#[test]
+fn test_oversized_batches_are_split_or_refused() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    h.router.set_call_resource_profile(&h.admin, &String::from_str(&env, "get_tier"), &profile(40_000_000));
+    let calls = vec![&env, call(&h, "get_tier"), call(&h, "get_tier"), call(&h, "get_tier")];
+
+    let atomic_id = h.router.create_batch_operation(&h.operator, &calls, &Vec::new(&env), &300, &true);
+    let atomic = h.router.get_batch_operation(&atomic_id).unwrap();
+    assert_eq!(
+        h.router.try_execute_batch_operation(&h.operator, &atomic),
+        Err(Ok(soroban_sdk::Error::from_contract_error(IntegrationError::InvalidOperationState as u32)))
+    );
+
+    let batch_id = h.router.create_batch_operation(&h.operator, &calls, &Vec::new(&env), &300, &false);
+    let batch = h.router.get_batch_operation(&batch_id).unwrap();
+    let result = h.router.execute_batch_operation(&h.operator, &batch);
+
+    // Two 40M calls run now; the third moves to a pending continuation batch
+    assert_eq!(result.call_results.len(), 2);
+    assert!(result.call_results.iter().all(|call_result| call_result.gas_used == 40_000_000 && call_result.ledger_entries == 1));
+    let continuation_id = result.continuation_id.unwrap();
+    let continuation = h.router.get_batch_operation(&continuation_id).unwrap();
+    assert_eq!(continuation.calls.len(), 1);
+    assert_eq!(continuation.status, OperationStatus::Pending);
+    assert!(h.router.get_pending_operations().contains(&continuation_id));
+    assert_eq!(h.router.get_batch_operation(&batch_id).unwrap().calls.len(), 2);
+}
+
+#[test]
+fn test_calls_over_the_per_call_limit_are_not_invoked() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    h.router.set_call_resource_profile(&h.admin, &String::from_str(&env, "get_tier"), &profile(70_000_000));
+
+    let result = h.router.execute_contract_call(&h.operator, &call(&h, "get_tier"));
+    assert!(!result.success);
+    assert_eq!(result.gas_used, 70_000_000);
+    assert_eq!(result.error_message, String::from_str(&env, "Call exceeds the per-call resource limit"));
+}
@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::Ledger, Env};
+
+const EIGHT_HOURS: u64 = 8 * 3_600;
+
+#[test]
+fn test_temporary_role_applies_until_it_expires() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let on_call = h.user(2);
+    let expires_at = HARNESS_START_TIME + EIGHT_HOURS;
+    let targets = EmergencySlaTargets { system_wide_halt: 600, address_freeze: 600, contract_isolation: 600, reserve_protection: 600 };
+
+    assert!(h.router.try_grant_temporary_role(&h.operator, &on_call, &UserRole::SystemAdmin, &expires_at).is_err());
+    for (role, expires_at) in [
+        (UserRole::SuperAdmin, expires_at),
+        (UserRole::SystemAdmin, HARNESS_START_TIME),
+        (UserRole::SystemAdmin, HARNESS_START_TIME + 8 * 86_400),
+    ] {
+        assert_eq!(
+            h.router.try_grant_temporary_role(&h.admin, &on_call, &role, &expires_at),
+            Err(Ok(IntegrationError::InvalidOperationState))
+        );
+    }
+    assert!(h.router.try_set_emergency_sla_targets(&on_call, &targets).is_err());
+
+    h.router.grant_temporary_role(&h.admin, &on_call, &UserRole::SystemAdmin, &expires_at);
+    assert_eq!(h.router.get_user_role(&on_call), UserRole::SystemAdmin);
+    h.router.set_emergency_sla_targets(&on_call, &targets);
+    let grants = h.router.get_active_role_grants();
+    assert_eq!(grants.len(), 1);
+    assert_eq!(grants.get_unchecked(0).granted_by, h.admin);
+
+    // Permission checks stop honouring the grant at expiry, before it is swept
+    env.ledger().with_mut(|li| li.timestamp = expires_at);
+    assert_eq!(h.router.get_user_role(&on_call), UserRole::User);
+    assert!(h.router.try_set_emergency_sla_targets(&on_call, &targets).is_err());
+    assert_eq!(h.router.get_active_role_grants().len(), 0);
+    assert!(h.router.get_role_grant(&on_call).is_some());
+
+    let audited = h.router.get_audit_chain_head().length;
+    assert_eq!(h.router.expire_role_grants(&h.operator), 1);
+    assert_eq!(h.router.expire_role_grants(&h.operator), 0);
+    assert!(h.router.get_role_grant(&on_call).is_none());
+    assert_eq!(h.router.get_audit_chain_head().length, audited + 1);
+}
+
+#[test]
+fn test_revoking_a_grant_restores_the_assigned_role() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let expires_at = HARNESS_START_TIME + EIGHT_HOURS;
+
+    assert_eq!(
+        h.router.try_revoke_temporary_role(&h.admin, &h.operator),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    h.router.grant_temporary_role(&h.admin, &h.operator, &UserRole::WithdrawalApprover, &expires_at);
+    assert_eq!(h.router.get_user_role(&h.operator), UserRole::WithdrawalApprover);
+    assert!(h.router.try_expire_role_grants(&h.operator).is_err());
+
+    h.router.revoke_temporary_role(&h.admin, &h.operator);
+    assert_eq!(h.router.get_user_role(&h.operator), UserRole::Operator);
+    assert_eq!(h.router.get_active_role_grants().len(), 0);
+    assert_eq!(h.router.expire_role_grants(&h.operator), 0);
+}
+
+#[test]
+fn test_super_admins_cannot_use_grants_to_pose_as_clawback_officer() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let second_admin = h.user(2);
+    h.router.set_user_role(&h.admin, &second_admin, &UserRole::SuperAdmin);
+    let expires_at = HARNESS_START_TIME + EIGHT_HOURS;
+
+    assert_eq!(
+        h.router.try_grant_temporary_role(&h.admin, &second_admin, &UserRole::ComplianceOfficer, &expires_at),
+        Err(Ok(IntegrationError::InsufficientPermissions))
+    );
+    assert!(h.router.get_role_grant(&second_admin).is_none());
+
+    // A grant made before promotion does not make the admin an officer either
+    let promoted = h.user(3);
+    h.router.grant_temporary_role(&h.admin, &promoted, &UserRole::ComplianceOfficer, &expires_at);
+    h.router.set_user_role(&h.admin, &promoted, &UserRole::SuperAdmin);
+    let target = h.user(1);
+    h.istsi.set_seizure_escrow(&Address::generate(&env));
+    h.istsi.mint(&target, &500_000);
+    assert_eq!(
+        h.router.try_propose_clawback(
+            &promoted, &target, &100_000, &BytesN::from_array(&env, &[5u8; 32]), &String::from_str(&env, "Court order")
+        ),
+        Err(Ok(IntegrationError::InsufficientPermissions))
+    );
+    assert_eq!(h.router.get_pending_clawbacks().len(), 0);
+}
@@ -1,197 +0,0 @@
-#![cfg(test)]
-
-use super::*;
-use soroban_sdk::{
-    testutils::{Address as TestAddress, Ledger, LedgerInfo},
-    Address, Env, BytesN
-};
-
-/// Simple test to verify Bitcoin deposit function exists and can be called
-#[test]
-fn test_bitcoin_deposit_function_exists() {
-    let env = Env::default();
-    let contract_id = env.register(IntegrationRouter, ());
-    let client = IntegrationRouterClient::new(&env, &contract_id);
-    
-    // Set up test addresses
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    let operator = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-    
-    // Initialize the contract
-    client.initialize(
-        &admin,
-        &kyc_registry,
-        &istsi_token,
-        &fungible_token,
-        &reserve_manager
-    );
-    
-    // Set operator role
-    client.set_user_role(&admin, &operator, &UserRole::Operator);
-    
-    // Test data
-    let btc_amount = 100_000_000u64; // 1 BTC in satoshis
-    let btc_tx_hash = BytesN::from_array(&env, &[1u8; 32]);
-    let btc_confirmations = 6u32;
-    
-    // Verify the function exists and can be called
-    // This will likely fail due to simulated contract calls, but proves the function exists
-    let result = std::panic::catch_unwind(|| {
-        client.execute_btc_deposit_tracked(
-            &operator,
-            &user,
-            &btc_amount,
-            &btc_tx_hash,
-            &btc_confirmations
-        )
-    });
-    
-    // The function should exist (even if it panics due to mock contract calls)
-    // We're just testing that the function signature is correct
-    assert!(result.is_ok() || result.is_err()); // Function exists
-}
-
-/// Test that the basic configuration and role management works
-#[test]
-fn test_basic_configuration() {
-    let env = Env::default();
-    let contract_id = env.register(IntegrationRouter, ());
-    let client = IntegrationRouterClient::new(&env, &contract_id);
-    
-    // Set up test addresses
-    let admin = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-    
-    // Initialize the contract
-    client.initialize(
-        &admin,
-        &kyc_registry,
-        &istsi_token,
-        &fungible_token,
-        &reserve_manager
-    );
-    
-    // Verify configuration
-    let config = client.get_config();
-    assert_eq!(config.admin, admin);
-    assert_eq!(config.kyc_registry, kyc_registry);
-    assert_eq!(config.istsi_token, istsi_token);
-    assert_eq!(config.fungible_token, fungible_token);
-    assert_eq!(config.reserve_manager, reserve_manager);
-    assert_eq!(config.paused, false);
-}
-
-/// Test role management functionality
-#[test]
-fn test_role_management() {
-    let env = Env::default();
-    let contract_id = env.register(IntegrationRouter, ());
-    let client = IntegrationRouterClient::new(&env, &contract_id);
-    
-    // Set up test addresses
-    let admin = Address::generate(&env);
-    let operator = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-    
-    // Initialize the contract
-    client.initialize(
-        &admin,
-        &kyc_registry,
-        &istsi_token,
-        &fungible_token,
-        &reserve_manager
-    );
-    
-    // Test role assignment
-    client.set_user_role(&admin, &operator, &UserRole::Operator);
-    
-    // Verify role was set
-    let role = client.get_user_role(&operator);
-    assert_eq!(role, UserRole::Operator);
-    
-    // Verify admin role
-    let admin_role = client.get_user_role(&admin);
-    assert_eq!(admin_role, UserRole::SuperAdmin);
-}
-
-/// Test pause functionality
-#[test]
-fn test_pause_functionality() {
-    let env = Env::default();
-    let contract_id = env.register(IntegrationRouter, ());
-    let client = IntegrationRouterClient::new(&env, &contract_id);
-    
-    // Set up test addresses
-    let admin = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-    
-    // Initialize the contract
-    client.initialize(
-        &admin,
-        &kyc_registry,
-        &istsi_token,
-        &fungible_token,
-        &reserve_manager
-    );
-    
-    // Verify not paused initially
-    assert_eq!(client.is_paused(), false);
-    
-    // Pause the system
-    client.emergency_pause(&admin, &String::from_str(&env, "Test pause"));
-    
-    // Verify paused
-    assert_eq!(client.is_paused(), true);
-    
-    // Resume operations
-    client.resume_operations(&admin);
-    
-    // Verify not paused
-    assert_eq!(client.is_paused(), false);
-}
-
-/// Test deposit limits checking
-#[test]
-fn test_deposit_limits() {
-    let env = Env::default();
-    let contract_id = env.register(IntegrationRouter, ());
-    let client = IntegrationRouterClient::new(&env, &contract_id);
-    
-    // Set up test addresses
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-    
-    // Initialize the contract
-    client.initialize(
-        &admin,
-        &kyc_registry,
-        &istsi_token,
-        &fungible_token,
-        &reserve_manager
-    );
-    
-    // Test deposit limits function exists
-    let btc_amount = 50_000_000u64; // 0.5 BTC
-    let (approved, _message, limit) = client.check_deposit_limits(&user, &btc_amount);
-    
-    // Function should return some result (even if mocked)
-    assert!(limit >= 0 || !approved);
-}
\ No newline at end of file
@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use super::*;
+use crate::testing::mock_contracts;
 use soroban_sdk::{
     testutils::{Address as TestAddress, Ledger, LedgerInfo},
     Address, Env, String, BytesN
@@ -80,54 +81,53 @@ fn test_time_based_reset() {
 fn test_limit_validation() {
     let env = Env::default();
     env.mock_all_auths();
-
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-
-    // Initialize the contract
-    IntegrationRouter::initialize(
-        env.clone(),
-        admin.clone(),
-        kyc_registry.clone(),
-        istsi_token.clone(),
-        fungible_token.clone(),
-        reserve_manager.clone()
-    );
-
-    // Set up user limits
-    let limit_info = ExchangeLimitInfo {
-        user: user.clone(),
-        kyc_tier: 1,
-        daily_limit: 1_000_000,
-        monthly_limit: 10_000_000,
-        daily_used: 0,
-        monthly_used: 0,
-        last_reset_daily: env.ledger().timestamp(),
-        last_reset_monthly: env.ledger().timestamp(),
-        enhanced_verification_limit: 500_000,
-    };
-    
-    env.storage().persistent().set(&DataKey::ExchangeLimits(user.clone()), &limit_info);
-
-    // Test exchange within limits - this will fail due to KYC registry call in test environment
-    // but we can verify the function executes without panicking
-    let result = IntegrationRouter::verify_exchange_limits(
-        &env, &user, &istsi_token, &fungible_token, 100_000
-    );
-    
-    // In test environment, this may fail due to KYC registry calls, but should not panic
-    match result {
-        Ok(_) => {
-            // Success case
-        },
-        Err(_) => {
-            // Expected in test environment without real KYC registry
+    let router = env.register(IntegrationRouter, ());
+    env.as_contract(&router, || {
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
+
+        // Initialize the contract
+        IntegrationRouter::initialize(
+            env.clone(),
+            admin.clone(),
+            kyc_registry.clone(),
+            istsi_token.clone(),
+            fungible_token.clone(),
+            reserve_manager.clone()
+        );
+
+        // Set up user limits
+        let limit_info = ExchangeLimitInfo {
+            user: user.clone(),
+            kyc_tier: 1,
+            daily_limit: 1_000_000,
+            monthly_limit: 10_000_000,
+            daily_used: 0,
+            monthly_used: 0,
+            last_reset_daily: env.ledger().timestamp(),
+            last_reset_monthly: env.ledger().timestamp(),
+            enhanced_verification_limit: 500_000,
+        };
+        
+        env.storage().persistent().set(&DataKey::ExchangeLimits(user.clone()), &limit_info);
+
+        // Test exchange within limits - this will fail due to KYC registry call in test environment
+        // but we can verify the function executes without panicking
+        let result = IntegrationRouter::verify_exchange_limits(
+            &env, &user, &istsi_token, &fungible_token, 100_000
+        );
+        
+        // In test environment, this may fail due to KYC registry calls, but should not panic
+        match result {
+            Ok(_) => {
+                // Success case
+            },
+            Err(_) => {
+                // Expected in test environment without real KYC registry
+            }
         }
-    }
+    });
 }
 
 /// Test enhanced verification threshold checking
@@ -135,25 +135,30 @@ fn test_limit_validation() {
 fn test_enhanced_verification_threshold() {
     let env = Env::default();
     env.mock_all_auths();
-
-    let user = Address::generate(&env);
-    
-    // Test amounts above and below enhanced verification limits for different tiers
-    
-    // Tier 1: 500K limit
-    let result1 = IntegrationRouter::check_enhanced_verification_requirements(
-        &env, &user, 400_000, 1 // Below limit
-    );
-    // This will fail in test environment, but should not panic
-    
-    let result2 = IntegrationRouter::check_enhanced_verification_requirements(
-        &env, &user, 600_000, 1 // Above limit
-    );
-    // This will also fail in test environment, but should not panic
-    
-    // Both should return errors in test environment due to missing KYC registry
-    assert!(result1.is_ok() || result1.is_err());
-    assert!(result2.is_ok() || result2.is_err());
+    let router = env.register(IntegrationRouter, ());
+    env.as_contract(&router, || {
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
+        IntegrationRouter::initialize(env.clone(), admin, kyc_registry, istsi_token, fungible_token, reserve_manager);
+        
+        // Test amounts above and below enhanced verification limits for different tiers
+        
+        // Tier 1: 500K limit
+        let result1 = IntegrationRouter::check_enhanced_verification_requirements(
+            &env, &user, 400_000, 1 // Below limit
+        );
+        // This will fail in test environment, but should not panic
+        
+        let result2 = IntegrationRouter::check_enhanced_verification_requirements(
+            &env, &user, 600_000, 1 // Above limit
+        );
+        // This will also fail in test environment, but should not panic
+        
+        // Both should return errors in test environment due to missing KYC registry
+        assert!(result1.is_ok() || result1.is_err());
+        assert!(result2.is_ok() || result2.is_err());
+    });
 }
 
 /// Test compliance logging functions
@@ -161,32 +166,31 @@ fn test_enhanced_verification_threshold() {
 fn test_compliance_logging() {
     let env = Env::default();
     env.mock_all_auths();
-
-    let admin = Address::generate(&env);
-    let user = Address::generate(&env);
-    let kyc_registry = Address::generate(&env);
-    let istsi_token = Address::generate(&env);
-    let fungible_token = Address::generate(&env);
-    let reserve_manager = Address::generate(&env);
-
-    // Initialize the contract
-    IntegrationRouter::initialize(
-        env.clone(),
-        admin.clone(),
-        kyc_registry.clone(),
-        istsi_token.clone(),
-        fungible_token.clone(),
-        reserve_manager.clone()
-    );
-
-    // Test logging functions - these should not panic even if KYC registry calls fail
-    let result1 = IntegrationRouter::log_exchange_limit_violation(
-        &env, &user, "test_violation", 1_000_000, 500_000
-    );
-    assert!(result1.is_ok());
-
-    let result2 = IntegrationRouter::log_exchange_compliance_check(
-        &env, &user, "test_check", 100_000, 2
-    );
-    assert!(result2.is_ok());
+    let router = env.register(IntegrationRouter, ());
+    env.as_contract(&router, || {
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let (kyc_registry, istsi_token, fungible_token, reserve_manager) = mock_contracts(&env);
+
+        // Initialize the contract
+        IntegrationRouter::initialize(
+            env.clone(),
+            admin.clone(),
+            kyc_registry.clone(),
+            istsi_token.clone(),
+            fungible_token.clone(),
+            reserve_manager.clone()
+        );
+
+        // Test logging functions - these should not panic even if KYC registry calls fail
+        let result1 = IntegrationRouter::log_exchange_limit_violation(
+            &env, &user, "test_violation", 1_000_000, 500_000
+        );
+        assert!(result1.is_ok());
+
+        let result2 = IntegrationRouter::log_exchange_compliance_check(
+            &env, &user, "test_check", 100_000, 2
+        );
+        assert!(result2.is_ok());
+    });
 }
\ No newline at end of file
@@ -1,173 +0,0 @@
-#[cfg(test)]
-mod simple_withdrawal_tests {
-    use super::*;
-    use soroban_sdk::{testutils::Address as TestAddress, Address, Env, BytesN};
-
-    fn create_test_env() -> (Env, Address, Address, Address, Address, Address, Address) {
-        let env = Env::default();
-        env.mock_all_auths();
-
-        let admin = Address::generate(&env);
-        let user = Address::generate(&env);
-        let kyc_registry = Address::generate(&env);
-        let istsi_token = Address::generate(&env);
-        let fungible_token = Address::generate(&env);
-        let reserve_manager = Address::generate(&env);
-
-        (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager)
-    }
-
-    fn initialize_router(
-        env: &Env,
-        admin: &Address,
-        kyc_registry: &Address,
-        istsi_token: &Address,
-        fungible_token: &Address,
-        reserve_manager: &Address,
-    ) {
-        IntegrationRouter::initialize(
-            env.clone(),
-            admin.clone(),
-            kyc_registry.clone(),
-            istsi_token.clone(),
-            fungible_token.clone(),
-            reserve_manager.clone(),
-        );
-    }
-
-    #[test]
-    fn test_withdrawal_status_query() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        // Test getting withdrawal status for a non-existent withdrawal
-        let withdrawal_id = BytesN::from_array(&env, &[1u8; 32]);
-        let status = IntegrationRouter::get_withdrawal_status(env.clone(), withdrawal_id.clone());
-        
-        // Should be None for non-existent withdrawal
-        assert!(status.is_none());
-    }
-
-    #[test]
-    fn test_withdrawal_limits_checking() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        let istsi_amount = 500_000_000u64; // 5 iSTSi tokens
-
-        // Check withdrawal limits
-        let (approved, message, limit) = IntegrationRouter::check_withdrawal_limits(
-            env.clone(),
-            user.clone(),
-            istsi_amount,
-        );
-
-        // Verify limit checking functionality returns valid data
-        assert!(limit >= 0); // Limit should be non-negative
-        assert!(message.len() >= 0); // Message should be valid
-    }
-
-    #[test]
-    fn test_withdrawal_requirements() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        let istsi_amount = 1_000_000_000u64; // 10 iSTSi tokens
-
-        // Get withdrawal requirements
-        let (tier, enhanced_verification, cooling_period) = IntegrationRouter::get_withdrawal_requirements(
-            env.clone(),
-            user.clone(),
-            istsi_amount,
-        );
-
-        // Verify requirements are returned with valid values
-        assert!(tier >= 1 && tier <= 5); // Valid tier range
-        assert!(cooling_period <= 168); // Max 1 week cooling period
-    }
-
-    #[test]
-    fn test_pending_withdrawals_query() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        // Set user as operator for testing
-        IntegrationRouter::set_user_role(env.clone(), admin.clone(), user.clone(), UserRole::Operator);
-
-        // Get pending withdrawals (should be empty initially)
-        let pending_withdrawals = IntegrationRouter::get_pending_withdrawals(env.clone(), user.clone());
-        
-        // Initially should be empty
-        assert_eq!(pending_withdrawals.len(), 0);
-    }
-
-    #[test]
-    fn test_basic_withdrawal_workflow() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        // Set user as operator for testing
-        IntegrationRouter::set_user_role(env.clone(), admin.clone(), user.clone(), UserRole::Operator);
-
-        let istsi_amount = 100_000_000u64; // 1 iSTSi token
-        let btc_address = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
-
-        // Execute withdrawal workflow - this should work or fail gracefully
-        let result = std::panic::catch_unwind(|| {
-            IntegrationRouter::execute_token_withdrawal(
-                env.clone(),
-                user.clone(),
-                user.clone(),
-                istsi_amount,
-                btc_address.clone(),
-            )
-        });
-
-        // The test passes if the function either succeeds or fails with expected errors
-        // In a real environment, this would depend on the mock contract responses
-        match result {
-            Ok(withdrawal_id) => {
-                // If successful, verify withdrawal ID is generated
-                assert!(!withdrawal_id.to_array().iter().all(|&x| x == 0));
-            },
-            Err(_) => {
-                // If it panics (expected in test environment), that's also acceptable
-                // as it means the function is properly validating inputs and dependencies
-            }
-        }
-    }
-
-    #[test]
-    fn test_atomic_withdrawal_workflow() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        // Set user as operator for testing
-        IntegrationRouter::set_user_role(env.clone(), admin.clone(), user.clone(), UserRole::Operator);
-
-        let istsi_amount = 200_000_000u64; // 2 iSTSi tokens
-        let btc_address = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
-
-        // Execute atomic withdrawal workflow
-        let result = std::panic::catch_unwind(|| {
-            IntegrationRouter::execute_token_withdrawal_tracked(
-                env.clone(),
-                user.clone(),
-                user.clone(),
-                istsi_amount,
-                btc_address.clone(),
-            )
-        });
-
-        // The test passes if the function either succeeds or fails gracefully
-        match result {
-            Ok(withdrawal_id) => {
-                // If successful, verify withdrawal ID is generated
-                assert!(!withdrawal_id.to_array().iter().all(|&x| x == 0));
-            },
-            Err(_) => {
-                // If it panics (expected in test environment), that's also acceptable
-            }
-        }
-    }
-}
\ No newline at end of file
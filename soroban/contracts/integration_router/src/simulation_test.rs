@@ -0,0 +1,157 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{MockKycRegistry, MockKycRegistryClient, MockToken};
+use soroban_sdk::{
+    testutils::{Address as TestAddress, Ledger},
+    vec, Address, BytesN, Env
+};
+
+fn setup(env: &Env) -> (Address, IntegrationRouterClient, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &env.register(MockKycRegistry, ()),
+        &env.register(MockToken, ()),
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+
+    (contract_id, client, admin)
+}
+
+fn step(report: &SimulationReport, name: &str) -> SimulationStep {
+    let env = report.steps.env();
+    report.steps
+        .iter()
+        .find(|step| step.name == String::from_str(env, name))
+        .unwrap()
+}
+
+#[test]
+fn test_deposit_simulation_reports_checks_without_mutating_state() {
+    let env = Env::default();
+    let (contract_id, client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let btc_tx_hash = BytesN::from_array(&env, &[5u8; 32]);
+
+    let operator = Address::generate(&env);
+    client.set_user_role(&admin, &operator, &UserRole::Operator);
+
+    let report = client.simulate_bitcoin_deposit(&operator, &user, &2u64, &btc_tx_hash, &6u32);
+    assert_eq!(report.operation, String::from_str(&env, "bitcoin_deposit"));
+    assert_eq!(report.projected_amount, 200_000_000);
+    assert_eq!(report.fee_amount, 0);
+    assert!(step(&report, "paused").passed);
+    assert!(step(&report, "role").passed);
+    assert!(step(&report, "frozen").passed);
+    assert!(step(&report, "btc_tx").passed);
+
+    // Nothing was counted or recorded by the dry run
+    assert_eq!(client.get_rate_limit_status(&operator).deposits, 0);
+    assert_eq!(client.get_operator_spending(&operator).deposit_volume, 0);
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().persistent().has(&DataKey::PendingOperation(btc_tx_hash.clone())));
+    });
+
+    // An approved KYC answer is not cached by the dry run either
+    let kyc = MockKycRegistryClient::new(&env, &client.get_config().kyc_registry);
+    kyc.set_router(&contract_id);
+    kyc.set_tier(&user, &1);
+    let report = client.simulate_bitcoin_deposit(&operator, &user, &2u64, &btc_tx_hash, &6u32);
+    assert!(step(&report, "kyc").passed);
+    env.as_contract(&contract_id, || {
+        let band = IntegrationRouter::compliance_band(2);
+        assert!(!env.storage().temporary().has(&ComplianceKey::ComplianceCache(user.clone(), 3, band)));
+    });
+
+    // Every blocking condition is reported, not just the first
+    client.pause_workflow(&admin, &PauseWorkflow::Deposits, &String::from_str(&env, "Maintenance"));
+    client.freeze_address(&admin, &user, &String::from_str(&env, "Under review"), &0u64);
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::PendingOperation(btc_tx_hash.clone()), &true);
+    });
+
+    let report = client.simulate_bitcoin_deposit(&Address::generate(&env), &user, &2u64, &btc_tx_hash, &6u32);
+    assert!(!report.passed);
+    assert!(!step(&report, "paused").passed);
+    assert!(!step(&report, "role").passed);
+    assert!(!step(&report, "frozen").passed);
+    assert_eq!(step(&report, "btc_tx").message, String::from_str(&env, "Duplicate Bitcoin transaction"));
+}
+
+#[test]
+fn test_withdrawal_simulation_projects_fee_and_allowance() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let btc_address = String::from_str(&env, "bc1qsimulated");
+
+    let operator = Address::generate(&env);
+    client.set_user_role(&admin, &operator, &UserRole::Operator);
+
+    client.set_fee_schedule(&admin, &FeeOperation::Withdrawal, &vec![
+        &env,
+        FeeTier { min_kyc_tier: 0, flat_fee: 0, bps: 100 },
+    ]);
+
+    let report = client.simulate_token_withdrawal(&operator, &user, &300_000_000u64, &btc_address, &None);
+    assert_eq!(report.fee_amount, 3_000_000);
+    assert_eq!(report.projected_amount, 2);
+    assert!(step(&report, "allowance").passed);
+
+    // A cap below the withdrawal is reported without suspending the operator
+    client.set_operator_spending_cap(&admin, &operator, &OperatorSpendingCap {
+        deposit_daily_cap: 0,
+        withdrawal_daily_cap: 2,
+    });
+    let report = client.simulate_token_withdrawal(&operator, &user, &300_000_000u64, &btc_address, &None);
+    assert!(!report.passed);
+    assert!(!step(&report, "allowance").passed);
+    assert!(client.get_operator_suspension(&operator).is_none());
+
+    let report = client.simulate_token_withdrawal(&operator, &user, &100_000_000u64, &String::from_str(&env, ""), &None);
+    assert!(step(&report, "allowance").passed);
+    assert!(!step(&report, "btc_address").passed);
+}
+
+#[test]
+fn test_exchange_simulation_quotes_stored_rate() {
+    let env = Env::default();
+    let (_contract_id, client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let token_a = Address::generate(&env);
+    let token_b = Address::generate(&env);
+
+    // Without an oracle there is no rate to quote
+    let report = client.simulate_exchange(&user, &token_a, &token_b, &10_000u64, &100u64);
+    assert!(!report.passed);
+    assert!(!step(&report, "rate").passed);
+    assert_eq!(report.projected_amount, 0);
+
+    client.configure_oracle(&admin, &token_a, &token_b, &Address::generate(&env), &300u64, &500u64, &20_000u64);
+
+    // 0.3% fee at a 2:1 rate
+    let report = client.simulate_exchange(&user, &token_a, &token_b, &10_000u64, &100u64);
+    assert!(step(&report, "rate").passed);
+    assert!(step(&report, "slippage").passed);
+    assert_eq!(report.exchange_rate, 20_000);
+    assert_eq!(report.fee_amount, 30);
+    assert_eq!(report.projected_amount, 19_940);
+
+    // Tighter slippage tolerance and a paused workflow are both reported
+    client.pause_workflow(&admin, &PauseWorkflow::Exchanges, &String::from_str(&env, "Maintenance"));
+    let report = client.simulate_exchange(&user, &token_a, &token_b, &10_000u64, &10u64);
+    assert!(!report.passed);
+    assert!(!step(&report, "paused").passed);
+    assert!(!step(&report, "slippage").passed);
+    assert_eq!(client.get_exchange_limits(&user).daily_used, 0);
+}
@@ -0,0 +1,38 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as TestAddress, Address, BytesN, Env};
+
+#[test]
+fn test_spv_requirement_and_failed_proof() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+
+    assert!(!client.is_spv_required());
+    client.set_spv_required(&admin, &true);
+    assert!(client.is_spv_required());
+
+    // The reserve manager address is not a deployed contract, so verification fails
+    let tx_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let result = client.try_submit_deposit_spv_proof(
+        &admin,
+        &tx_hash,
+        &Vec::new(&env),
+        &0u32,
+        &BytesN::from_array(&env, &[0u8; 80]),
+    );
+    assert_eq!(result, Err(Ok(IntegrationError::BitcoinTransactionFailed)));
+    assert_eq!(client.get_spv_verified_confirmations(&tx_hash), None);
+}
@@ -0,0 +1,157 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::Address as TestAddress,
+    Address, BytesN, Env
+};
+
+/// Upgraded reserve manager holding five user records to migrate
+#[contract]
+pub struct MockMigratingReserve;
+
+#[contractimpl]
+impl MockMigratingReserve {
+    pub fn get_ratio(_env: Env) -> u64 {
+        10000
+    }
+
+    pub fn migrate_users(_env: Env, cursor: u32, limit: u32) -> u32 {
+        limit.min(5 - cursor)
+    }
+
+    pub fn migrate_pools(env: Env, _cursor: u32, _limit: u32) -> u32 {
+        if env.storage().instance().has(&symbol_short!("fail")) {
+            panic_with_error!(&env, IntegrationError::ContractCallFailed);
+        }
+        0
+    }
+
+    pub fn set_failing(env: Env, failing: bool) {
+        if failing {
+            env.storage().instance().set(&symbol_short!("fail"), &true);
+        } else {
+            env.storage().instance().remove(&symbol_short!("fail"));
+        }
+    }
+}
+
+struct Setup<'a> {
+    client: IntegrationRouterClient<'a>,
+    admin: Address,
+    reserve_v1: Address,
+    reserve_v2: Address,
+    reserve_name: String,
+    upgrade_id: BytesN<32>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+    let reserve_v1 = env.register(MockMigratingReserve, ());
+    let reserve_v2 = env.register(MockMigratingReserve, ());
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &reserve_v1,
+    );
+
+    let reserve_name = String::from_str(env, "reserve_manager");
+    let hash = BytesN::from_array(env, &[5u8; 32]);
+    client.register_deployed_wasm(&admin, &reserve_v2, &hash);
+    let upgrade_id = client.plan_contract_upgrade(&admin, &reserve_name, &reserve_v2, &hash);
+
+    Setup { client, admin, reserve_v1, reserve_v2, reserve_name, upgrade_id }
+}
+
+fn try_deposit(env: &Env, s: &Setup) -> Result<(), IntegrationError> {
+    match s.client.try_execute_bitcoin_deposit(&s.admin, &Address::generate(env), &100_000u64, &BytesN::from_array(env, &[1u8; 32]), &6u32) {
+        Err(Ok(error)) => Err(error.try_into().unwrap_or(IntegrationError::ContractCallFailed)),
+        _ => Ok(()),
+    }
+}
+
+#[test]
+fn test_migration_runs_in_checkpointed_batches_and_blocks_workflows() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(s.client.register_migration_step(&s.admin, &s.upgrade_id, &Symbol::new(&env, "migrate_users"), &String::from_str(&env, "user balances"), &2u32), 1);
+    assert_eq!(s.client.register_migration_step(&s.admin, &s.upgrade_id, &Symbol::new(&env, "migrate_pools"), &String::from_str(&env, "liquidity pools"), &10u32), 2);
+    assert_eq!(
+        s.client.try_register_migration_step(&s.admin, &s.upgrade_id, &Symbol::new(&env, "migrate_users"), &String::from_str(&env, "noop"), &0u32),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    let check = s.client.check_upgrade_compatibility(&s.upgrade_id);
+    assert!(check.compatible);
+    assert_eq!(check.required_migrations, vec![
+        &env,
+        String::from_str(&env, "migration:user balances"),
+        String::from_str(&env, "migration:liquidity pools"),
+    ]);
+    assert_eq!(
+        s.client.try_start_canary_rollout(&s.admin, &s.upgrade_id, &10u32, &1000u32, &5u32),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    MockMigratingReserveClient::new(&env, &s.reserve_v2).set_failing(&true);
+    assert!(s.client.execute_contract_upgrade(&s.admin, &s.upgrade_id).success);
+    assert_eq!(s.client.get_upgrade_plan(&s.upgrade_id).unwrap().status, UpgradeStatus::InProgress);
+    assert_eq!(s.client.get_contract_address(&s.reserve_name), Some(s.reserve_v2.clone()));
+    assert_eq!(try_deposit(&env, &s), Err(IntegrationError::MigrationInProgress));
+
+    // Two batches of two users, checkpointed
+    let state = s.client.run_migration(&s.admin, &s.upgrade_id, &2u32);
+    assert_eq!((state.step_index, state.cursor, state.items_migrated, state.batches_run), (0, 4, 4, 2));
+    assert_eq!(state.status, MigrationStatus::Running);
+
+    // The short batch ends the first step; the second step fails and keeps its checkpoint
+    let state = s.client.run_migration(&s.admin, &s.upgrade_id, &5u32);
+    assert_eq!((state.step_index, state.cursor, state.items_migrated), (1, 0, 5));
+    assert_eq!(state.status, MigrationStatus::Failed);
+    assert_eq!(state.last_error, String::from_str(&env, "batch failed:liquidity pools"));
+    assert_eq!(s.client.get_migration_state(&s.upgrade_id), Some(state));
+    assert_eq!(try_deposit(&env, &s), Err(IntegrationError::MigrationInProgress));
+
+    MockMigratingReserveClient::new(&env, &s.reserve_v2).set_failing(&false);
+    let state = s.client.run_migration(&s.admin, &s.upgrade_id, &5u32);
+    assert_eq!(state.status, MigrationStatus::Completed);
+    assert_eq!(state.batches_run, 5);
+    assert_eq!(s.client.get_upgrade_plan(&s.upgrade_id).unwrap().status, UpgradeStatus::Completed);
+    assert_ne!(try_deposit(&env, &s), Err(IntegrationError::MigrationInProgress));
+
+    assert_eq!(
+        s.client.try_run_migration(&s.admin, &s.upgrade_id, &1u32),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
+
+#[test]
+fn test_failed_migration_can_be_rolled_back() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.register_migration_step(&s.admin, &s.upgrade_id, &Symbol::new(&env, "migrate_pools"), &String::from_str(&env, "liquidity pools"), &10u32);
+    MockMigratingReserveClient::new(&env, &s.reserve_v2).set_failing(&true);
+    s.client.execute_contract_upgrade(&s.admin, &s.upgrade_id);
+
+    // A running migration cannot be abandoned
+    assert_eq!(
+        s.client.try_rollback_contract_upgrade(&s.admin, &s.upgrade_id),
+        Err(Ok(IntegrationError::InvalidOperationState.into()))
+    );
+
+    assert_eq!(s.client.run_migration(&s.admin, &s.upgrade_id, &1u32).status, MigrationStatus::Failed);
+    assert!(s.client.rollback_contract_upgrade(&s.admin, &s.upgrade_id));
+    assert_eq!(s.client.get_upgrade_plan(&s.upgrade_id).unwrap().status, UpgradeStatus::RolledBack);
+    assert_eq!(s.client.get_contract_address(&s.reserve_name), Some(s.reserve_v1.clone()));
+    assert_ne!(try_deposit(&env, &s), Err(IntegrationError::MigrationInProgress));
+}
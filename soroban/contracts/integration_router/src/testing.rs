@@ -0,0 +1,844 @@
+//! Test harness for router workflows
+//!
+//! Mock implementations of the four contracts the router orchestrates, a
+//! `TestHarness` that registers them next to an initialized router, and
+//! scenario builders for deposit, withdrawal and exchange tests. Every mock
+//! can be told to fail a named entrypoint with `set_failure`, which panics
+//! with `IntegrationError::ContractCallFailed` the way an unavailable
+//! contract would.
+//!
+//! Compiled for the router's own tests and, for downstream crates, behind the
+//! `testutils` feature. The router also builds as an rlib so other crates can
+//! link the harness as a dev-dependency:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! integration_router = { path = "../contracts/integration_router", features = ["testutils"] }
+//! ```
+//!
+//! The deployed wasm never includes this module.
+
+use soroban_sdk::{
+    contracttype, symbol_short, vec,
+    testutils::{Address as _, Ledger},
+    Address, Bytes, BytesN, Env, String, Symbol, Vec
+};
+
+use crate::{
+    ComplianceOutcome, ExchangeOperation, FaultStep, IntegrationError, IntegrationRouter, IntegrationRouterClient, InvariantReport,
+    SimulationReport, TierPolicy, UserRole, UtxoOutpoint
+};
+
+pub use kyc::{MockKycRegistry, MockKycRegistryClient};
+pub use reserve::{MockReserveManager, MockReserveManagerClient};
+pub use token::{MockToken, MockTokenClient};
+
+/// Ledger timestamp the harness starts at
+pub const HARNESS_START_TIME: u64 = 1_000_000;
+
+/// Reserves and supply reported by a fresh harness (a 100% ratio)
+pub const HARNESS_RESERVES_SATS: u64 = 100_000_000;
+
+/// Panic if a failure was injected for `function`
+fn fail_if_injected(env: &Env, function: &str) {
+    let key = (symbol_short!("fail"), Symbol::new(env, function));
+    if env.storage().instance().get(&key).unwrap_or(false) {
+        soroban_sdk::panic_with_error!(env, IntegrationError::ContractCallFailed);
+    }
+}
+
+/// Decode a string-encoded token amount
+fn parse_amount(amount: &String) -> i128 {
+    let mut digits = [0u8; 39];
+    let len = amount.len() as usize;
+    amount.copy_into_slice(&mut digits[..len]);
+    digits[..len].iter().fold(0i128, |acc, digit| acc * 10 + (digit - b'0') as i128)
+}
+
+/// Record or clear an injected failure for `function`
+fn set_injected_failure(env: &Env, function: Symbol, fail: bool) {
+    env.storage().instance().set(&(symbol_short!("fail"), function), &fail);
+}
+
+/// Tell the router set with `set_router` that KYC state changed, ignoring failures like the registry
+fn notify_router(env: &Env, addresses: Vec<Address>) {
+    if let Some(router) = env.storage().instance().get::<_, Address>(&symbol_short!("router")) {
+        let _ = IntegrationRouterClient::new(env, &router).try_notify_kyc_change(&addresses);
+    }
+}
+
+// Each mock lives in its own module: contracts in one module cannot share
+// entrypoint names such as `set_failure`.
+mod kyc {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl};
+
+    /// KYC registry with per-user tiers, per-tier limits and policies, and a blacklist
+    ///
+    /// Users start at tier 0, which is never approved. A tier without a
+    /// configured limit approves any amount. Once a router is set, tier,
+    /// limit and blacklist edits notify it like the real registry does.
+    #[contract]
+    pub struct MockKycRegistry;
+
+    #[contractimpl]
+    impl MockKycRegistry {
+        pub fn set_router(env: Env, router: Address) {
+            env.storage().instance().set(&symbol_short!("router"), &router);
+        }
+
+        pub fn set_tier(env: Env, user: Address, tier: u32) {
+            env.storage().persistent().set(&(symbol_short!("tier"), user.clone()), &tier);
+            notify_router(&env, vec![&env, user]);
+        }
+
+        pub fn set_tier_limit(env: Env, tier: u32, max_amount: i128) {
+            env.storage().instance().set(&(symbol_short!("limit"), tier), &max_amount);
+            notify_router(&env, Vec::new(&env));
+        }
+
+        pub fn set_tier_policy(env: Env, tier: u32, policy: TierPolicy) {
+            env.storage().instance().set(&(symbol_short!("policy"), tier), &policy);
+        }
+
+        pub fn set_blacklisted(env: Env, user: Address, blacklisted: bool) {
+            env.storage().persistent().set(&(symbol_short!("black"), user.clone()), &blacklisted);
+            notify_router(&env, vec![&env, user]);
+        }
+
+        pub fn set_jurisdiction(env: Env, user: Address, jurisdiction: String) {
+            env.storage().persistent().set(&(symbol_short!("juris"), user), &jurisdiction);
+        }
+
+        /// Amounts at or above the threshold require a travel-rule payload (0 disables)
+        pub fn set_travel_rule_threshold(env: Env, threshold: i128) {
+            env.storage().instance().set(&symbol_short!("travel"), &threshold);
+        }
+
+        /// Answer for the string-based `verify_ic` checks, which carry no usable address
+        pub fn set_compliance_approval(env: Env, approved: bool) {
+            env.storage().instance().set(&symbol_short!("approved"), &approved);
+        }
+
+        pub fn set_failure(env: Env, function: Symbol, fail: bool) {
+            set_injected_failure(&env, function, fail);
+        }
+
+        pub fn get_tier(env: Env, user: Address) -> u32 {
+            env.storage().persistent().get(&(symbol_short!("tier"), user)).unwrap_or(0)
+        }
+
+        pub fn get_jurisdiction_by_address(env: Env, address: Address) -> Option<String> {
+            fail_if_injected(&env, "get_jurisdiction_by_address");
+            env.storage().persistent().get(&(symbol_short!("juris"), address))
+        }
+
+        /// Policy for the user's tier, if one was set for it
+        pub fn get_address_tier_policy(env: Env, address: Address) -> Option<(u32, TierPolicy)> {
+            let tier = Self::get_tier(env.clone(), address);
+            env.storage().instance()
+                .get(&(symbol_short!("policy"), tier))
+                .map(|policy| (tier, policy))
+        }
+
+        pub fn is_approved_simple(env: Env, user: Address, _op_code: u32, amount: i128) -> bool {
+            fail_if_injected(&env, "is_approved_simple");
+            if Self::is_blacklisted(env.clone(), user.clone()) {
+                return false;
+            }
+            let tier = Self::get_tier(env.clone(), user);
+            let limit: Option<i128> = env.storage().instance().get(&(symbol_short!("limit"), tier));
+            tier > 0 && limit.is_none_or(|max_amount| amount <= max_amount)
+        }
+
+        /// Batch check with the registry's reason codes (1 = blacklisted, 2 = tier 0, 7 = over limit)
+        pub fn verify_compliance_batch(env: Env, requests: Vec<(Address, u32, i128)>) -> Vec<ComplianceOutcome> {
+            fail_if_injected(&env, "verify_compliance_batch");
+            let mut outcomes = Vec::new(&env);
+            for (user, op_code, amount) in requests.iter() {
+                let tier = Self::get_tier(env.clone(), user.clone());
+                let limit: Option<i128> = env.storage().instance().get(&(symbol_short!("limit"), tier));
+                let reason_code = if Self::is_blacklisted(env.clone(), user.clone()) {
+                    1
+                } else if tier == 0 {
+                    2
+                } else if limit.is_some_and(|max_amount| amount > max_amount) {
+                    7
+                } else {
+                    0
+                };
+                outcomes.push_back(ComplianceOutcome { user, op_code, amount, approved: reason_code == 0, reason_code });
+            }
+            outcomes
+        }
+
+        pub fn is_blacklisted(env: Env, address: Address) -> bool {
+            fail_if_injected(&env, "is_blacklisted");
+            env.storage().persistent().get(&(symbol_short!("black"), address)).unwrap_or(false)
+        }
+
+        pub fn requires_travel_rule(env: Env, _user: Address, amount: i128) -> bool {
+            fail_if_injected(&env, "requires_travel_rule");
+            let threshold: i128 = env.storage().instance().get(&symbol_short!("travel")).unwrap_or(0);
+            threshold > 0 && amount >= threshold
+        }
+
+        pub fn verify_ic(env: Env, _user: String, _operation: String, _amount: String) -> bool {
+            fail_if_injected(&env, "verify_ic");
+            env.storage().instance().get(&symbol_short!("approved")).unwrap_or(true)
+        }
+
+        pub fn reg_event(env: Env, _user: String, _event: String, _amount: String, _a: String, _b: String) -> bool {
+            fail_if_injected(&env, "reg_event");
+            true
+        }
+    }
+}
+
+mod reserve {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl};
+
+    /// Reserve manager reporting a settable reserve position
+    #[contract]
+    pub struct MockReserveManager;
+
+    #[contractimpl]
+    impl MockReserveManager {
+        pub fn set_position(env: Env, reserves: u64, supply: u64) {
+            env.storage().instance().set(&symbol_short!("reserves"), &reserves);
+            env.storage().instance().set(&symbol_short!("supply"), &supply);
+        }
+
+        pub fn set_allocated(env: Env, allocated: u64) {
+            env.storage().instance().set(&symbol_short!("alloc"), &allocated);
+        }
+
+        /// Custodian-attested total, oldest attestation time and freshness
+        pub fn set_attested(env: Env, attested: (u64, u64, bool)) {
+            env.storage().instance().set(&symbol_short!("attested"), &attested);
+        }
+
+        /// Unspent UTXO ledger total; None leaves the ledger unused
+        pub fn set_utxo_total(env: Env, total: Option<u64>) {
+            env.storage().instance().set(&symbol_short!("utxo"), &total);
+        }
+
+        pub fn set_failure(env: Env, function: Symbol, fail: bool) {
+            set_injected_failure(&env, function, fail);
+        }
+
+        pub fn get_total_reserves(env: Env) -> u64 {
+            fail_if_injected(&env, "get_total_reserves");
+            env.storage().instance().get(&symbol_short!("reserves")).unwrap_or(0)
+        }
+
+        pub fn get_total_token_supply(env: Env) -> u64 {
+            fail_if_injected(&env, "get_total_token_supply");
+            env.storage().instance().get(&symbol_short!("supply")).unwrap_or(0)
+        }
+
+        pub fn get_total_allocated(env: Env) -> u64 {
+            fail_if_injected(&env, "get_total_allocated");
+            env.storage().instance().get(&symbol_short!("alloc")).unwrap_or(0)
+        }
+
+        pub fn get_attested_reserve_total(env: Env) -> (u64, u64, bool) {
+            fail_if_injected(&env, "get_attested_reserve_total");
+            env.storage().instance().get(&symbol_short!("attested")).unwrap_or((0, 0, false))
+        }
+
+        /// Records the outpoints a confirmed payout retired
+        pub fn mark_payout_utxos_spent(env: Env, _caller: Address, withdrawal_id: BytesN<32>, outpoints: Vec<UtxoOutpoint>) -> u64 {
+            fail_if_injected(&env, "mark_payout_utxos_spent");
+            env.storage().persistent().set(&(symbol_short!("payout"), withdrawal_id), &outpoints);
+            0
+        }
+
+        pub fn get_payout_spends(env: Env, withdrawal_id: BytesN<32>) -> Vec<UtxoOutpoint> {
+            env.storage().persistent().get(&(symbol_short!("payout"), withdrawal_id)).unwrap_or(Vec::new(&env))
+        }
+
+        /// Deposit registration as invoked by the router with string-encoded arguments
+        pub fn reg_dep(env: Env, _btc_tx_hash: String, _btc_amount: String, _confirmations: String) -> bool {
+            fail_if_injected(&env, "reg_dep");
+            true
+        }
+
+        /// Withdrawal request as invoked by the router with string-encoded arguments
+        pub fn create_wd(env: Env, _withdrawal_id: String, _user: String, _btc_amount: String, _btc_address: String) -> bool {
+            fail_if_injected(&env, "create_wd");
+            true
+        }
+
+        /// Payout initiation as invoked by the router with string-encoded arguments
+        pub fn proc_wd(env: Env, _withdrawal_id: String, _btc_amount: String) -> bool {
+            fail_if_injected(&env, "proc_wd");
+            true
+        }
+
+        pub fn get_utxo_ledger_total(env: Env) -> Option<u64> {
+            fail_if_injected(&env, "get_utxo_ledger_total");
+            env.storage().instance().get(&symbol_short!("utxo")).unwrap_or(None)
+        }
+
+        /// Ratio in basis points; 0 while no supply is outstanding, as in the real contract
+        pub fn get_reserve_ratio(env: Env) -> u64 {
+            fail_if_injected(&env, "get_reserve_ratio");
+            let reserves = Self::get_total_reserves(env.clone()) as u128;
+            let supply = Self::get_total_token_supply(env) as u128;
+            if supply == 0 {
+                return 0;
+            }
+            (reserves * 10000 / supply).min(u64::MAX as u128) as u64
+        }
+
+        /// Short alias used by the router's string-dispatched reserve calls
+        pub fn get_ratio(env: Env) -> u64 {
+            fail_if_injected(&env, "get_ratio");
+            Self::get_reserve_ratio(env)
+        }
+    }
+}
+
+mod token {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl};
+
+    /// Token with the balance, transfer, mint and burn entrypoints the router uses
+    #[contract]
+    pub struct MockToken;
+
+    #[contractimpl]
+    impl MockToken {
+        pub fn set_failure(env: Env, function: Symbol, fail: bool) {
+            set_injected_failure(&env, function, fail);
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            fail_if_injected(&env, "balance");
+            env.storage().persistent().get(&(symbol_short!("balance"), id)).unwrap_or(0)
+        }
+
+        pub fn mint(env: Env, to: Address, amount: i128) {
+            fail_if_injected(&env, "mint");
+            let balance = Self::balance(env.clone(), to.clone());
+            env.storage().persistent().set(&(symbol_short!("balance"), to), &(balance + amount));
+        }
+
+        /// Deposit mint as invoked by the router with string-encoded arguments
+        pub fn int_mint(env: Env, to: String, amount: String) -> bool {
+            fail_if_injected(&env, "int_mint");
+            Self::mint(env.clone(), Address::from_string(&to), parse_amount(&amount));
+            true
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            fail_if_injected(&env, "transfer");
+            from.require_auth();
+            let from_balance = Self::balance(env.clone(), from.clone());
+            if from_balance < amount {
+                soroban_sdk::panic_with_error!(&env, IntegrationError::InsufficientReserves);
+            }
+            env.storage().persistent().set(&(symbol_short!("balance"), from), &(from_balance - amount));
+            let to_balance = Self::balance(env.clone(), to.clone());
+            env.storage().persistent().set(&(symbol_short!("balance"), to), &(to_balance + amount));
+        }
+
+        pub fn burn(env: Env, from: Address, amount: i128) {
+            fail_if_injected(&env, "burn");
+            from.require_auth();
+            let balance = Self::balance(env.clone(), from.clone());
+            if balance < amount {
+                soroban_sdk::panic_with_error!(&env, IntegrationError::InsufficientReserves);
+            }
+            env.storage().persistent().set(&(symbol_short!("balance"), from), &(balance - amount));
+        }
+
+        pub fn set_address_freeze(env: Env, _caller: Address, address: Address, frozen: bool, expires_at: u64) {
+            fail_if_injected(&env, "set_address_freeze");
+            env.storage().persistent().set(&(symbol_short!("frozen"), address), &(frozen, expires_at));
+        }
+
+        pub fn is_address_frozen(env: Env, address: Address) -> bool {
+            match env.storage().persistent().get::<_, (bool, u64)>(&(symbol_short!("frozen"), address)) {
+                Some((frozen, expires_at)) => frozen && (expires_at == 0 || env.ledger().timestamp() < expires_at),
+                None => false,
+            }
+        }
+
+        /// Withdrawal burn as invoked by the router with string-encoded arguments
+        pub fn burn_btc(env: Env, from: String, amount: String, _btc_address: String, correlation_id: String) -> bool {
+            fail_if_injected(&env, "burn_btc");
+            let from = Address::from_string(&from);
+            let balance = Self::balance(env.clone(), from.clone());
+            let amount = parse_amount(&amount);
+            if balance < amount {
+                return false;
+            }
+            env.storage().persistent().set(&(symbol_short!("balance"), from), &(balance - amount));
+            env.storage().instance().set(&symbol_short!("burn_corr"), &correlation_id);
+            true
+        }
+
+        pub fn last_burn_correlation(env: Env) -> Option<String> {
+            env.storage().instance().get(&symbol_short!("burn_corr"))
+        }
+
+        pub fn set_seizure_escrow(env: Env, escrow: Address) {
+            env.storage().persistent().set(&symbol_short!("seiz_esc"), &escrow);
+        }
+
+        /// Seize into the escrow account, returning the running clawback count
+        pub fn clawback(env: Env, caller: Address, target: Address, amount: i128, _evidence: BytesN<32>, _case_id: BytesN<32>) -> u64 {
+            fail_if_injected(&env, "clawback");
+            caller.require_auth();
+            let escrow: Address = env.storage().persistent().get(&symbol_short!("seiz_esc")).unwrap();
+            let balance = Self::balance(env.clone(), target.clone());
+            if balance < amount {
+                soroban_sdk::panic_with_error!(&env, IntegrationError::InsufficientReserves);
+            }
+            env.storage().persistent().set(&(symbol_short!("balance"), target), &(balance - amount));
+            let escrow_balance = Self::balance(env.clone(), escrow.clone());
+            env.storage().persistent().set(&(symbol_short!("balance"), escrow), &(escrow_balance + amount));
+
+            let count: u64 = env.storage().persistent().get(&symbol_short!("claw_cnt")).unwrap_or(0) + 1;
+            env.storage().persistent().set(&symbol_short!("claw_cnt"), &count);
+            count
+        }
+    }
+}
+
+/// Register the four mock contracts with the reserve manager fully backed
+///
+/// Returns the KYC registry, iSTSi token, fungible token and reserve manager
+/// addresses in `initialize` order.
+pub fn mock_contracts(env: &Env) -> (Address, Address, Address, Address) {
+    let reserve = MockReserveManagerClient::new(env, &env.register(MockReserveManager, ()));
+    reserve.set_position(&HARNESS_RESERVES_SATS, &HARNESS_RESERVES_SATS);
+    (env.register(MockKycRegistry, ()), env.register(MockToken, ()), env.register(MockToken, ()), reserve.address)
+}
+
+/// Mock contract targeted by failure injection
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MockContract {
+    KycRegistry,
+    ReserveManager,
+    IstsiToken,
+    FungibleToken,
+}
+
+/// Router wired to the four mock contracts
+///
+/// All auths are mocked and the ledger starts at `HARNESS_START_TIME`. The
+/// reserve manager starts fully backed at `HARNESS_RESERVES_SATS`. State set
+/// by a scenario (reserve positions, injected failures) outlives it.
+pub struct TestHarness<'a> {
+    pub env: Env,
+    pub router: IntegrationRouterClient<'a>,
+    pub kyc: MockKycRegistryClient<'a>,
+    pub reserve: MockReserveManagerClient<'a>,
+    pub istsi: MockTokenClient<'a>,
+    pub fungible: MockTokenClient<'a>,
+    pub admin: Address,
+    pub operator: Address,
+}
+
+impl<'a> TestHarness<'a> {
+    pub fn new(env: &Env) -> Self {
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| {
+            li.timestamp = HARNESS_START_TIME;
+        });
+
+        let router = IntegrationRouterClient::new(env, &env.register(IntegrationRouter, ()));
+        let kyc = MockKycRegistryClient::new(env, &env.register(MockKycRegistry, ()));
+        let reserve = MockReserveManagerClient::new(env, &env.register(MockReserveManager, ()));
+        let istsi = MockTokenClient::new(env, &env.register(MockToken, ()));
+        let fungible = MockTokenClient::new(env, &env.register(MockToken, ()));
+        reserve.set_position(&HARNESS_RESERVES_SATS, &HARNESS_RESERVES_SATS);
+
+        let admin = Address::generate(env);
+        router.initialize(&admin, &kyc.address, &istsi.address, &fungible.address, &reserve.address);
+        kyc.set_router(&router.address);
+
+        let operator = Address::generate(env);
+        router.set_user_role(&admin, &operator, &UserRole::Operator);
+
+        TestHarness { env: env.clone(), router, kyc, reserve, istsi, fungible, admin, operator }
+    }
+
+    /// Generate a user at the given KYC tier
+    pub fn user(&self, tier: u32) -> Address {
+        let user = Address::generate(&self.env);
+        self.kyc.set_tier(&user, &tier);
+        user
+    }
+
+    /// Make `function` on a mock contract fail until restored
+    pub fn fail(&self, contract: MockContract, function: &str) {
+        self.set_failure(contract, &Symbol::new(&self.env, function), true);
+    }
+
+    /// Clear a failure injected with `fail`
+    pub fn restore(&self, contract: MockContract, function: &str) {
+        self.set_failure(contract, &Symbol::new(&self.env, function), false);
+    }
+
+    fn set_failure(&self, contract: MockContract, function: &Symbol, fail: bool) {
+        match contract {
+            MockContract::KycRegistry => self.kyc.set_failure(function, &fail),
+            MockContract::ReserveManager => self.reserve.set_failure(function, &fail),
+            MockContract::IstsiToken => self.istsi.set_failure(function, &fail),
+            MockContract::FungibleToken => self.fungible.set_failure(function, &fail),
+        }
+    }
+
+    /// Force a router workflow step to fail, enabling fault injection if needed
+    pub fn fail_at(&self, step: FaultStep) {
+        self.router.set_fault_injection_enabled(&self.admin, &true);
+        self.router.inject_fault(&self.admin, &step);
+    }
+
+    /// Check the router invariants, panicking with the report if any is violated
+    pub fn assert_invariants(&self, holders: &Vec<Address>) -> InvariantReport {
+        let report = self.router.check_invariants(holders);
+        assert!(report.passed, "invariants violated: {:?}", report.violations);
+        report
+    }
+
+    /// Configure the iSTSi → fungible pair at `rate` (basis points) and have a market maker supply `liquidity`
+    pub fn fund_exchange(&self, rate: u64, liquidity: u64) -> Address {
+        self.router.configure_oracle(&self.admin, &self.istsi.address, &self.fungible.address, &Address::generate(&self.env), &300u64, &500u64, &rate);
+
+        let maker = Address::generate(&self.env);
+        self.fungible.mint(&maker, &(liquidity as i128));
+        self.router.set_market_maker(&self.admin, &maker, &true);
+        self.router.deposit_liquidity(&maker, &self.fungible.address, &liquidity);
+        maker
+    }
+}
+
+/// Result of running a scenario: the dry run followed by the execution
+#[derive(Clone, Debug)]
+pub struct ScenarioOutcome<T> {
+    pub user: Address,
+    pub report: SimulationReport,
+    /// `Err(None)` when the router trapped without a contract error
+    pub result: Result<T, Option<IntegrationError>>,
+}
+
+impl<T> ScenarioOutcome<T> {
+    pub fn succeeded(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    pub fn error(&self) -> Option<IntegrationError> {
+        self.result.as_ref().err().copied().flatten()
+    }
+
+    /// Whether the named simulation step passed (`false` if the step is missing)
+    pub fn step_passed(&self, name: &str) -> bool {
+        let name = String::from_str(self.report.steps.env(), name);
+        self.report.steps.iter().any(|step| step.name == name && step.passed)
+    }
+}
+
+/// Collapse a `try_` client result into a scenario result
+fn scenario_result<T, C, E: TryInto<IntegrationError>, I>(
+    result: Result<Result<T, C>, Result<E, I>>
+) -> Result<T, Option<IntegrationError>> {
+    match result {
+        Ok(Ok(value)) => Ok(value),
+        Err(Ok(error)) => Err(error.try_into().ok()),
+        Ok(Err(_)) | Err(Err(_)) => Err(None),
+    }
+}
+
+/// Setup shared by the scenario builders
+#[derive(Clone)]
+struct ScenarioSetup {
+    tier: u32,
+    blacklisted: bool,
+    position: Option<(u64, u64)>,
+    failures: Vec<(MockContract, Symbol)>,
+}
+
+impl ScenarioSetup {
+    fn new(env: &Env) -> Self {
+        ScenarioSetup { tier: 2, blacklisted: false, position: None, failures: Vec::new(env) }
+    }
+
+    fn fail(&mut self, contract: MockContract, function: &str) {
+        let function = Symbol::new(self.failures.env(), function);
+        self.failures.push_back((contract, function));
+    }
+
+    /// Create the scenario user and apply the configured state to the mocks
+    fn apply(&self, harness: &TestHarness) -> Address {
+        let user = harness.user(self.tier);
+        if self.blacklisted {
+            harness.kyc.set_blacklisted(&user, &true);
+        }
+        if let Some((reserves, supply)) = self.position {
+            harness.reserve.set_position(&reserves, &supply);
+        }
+        for (contract, function) in self.failures.iter() {
+            harness.set_failure(contract, &function, true);
+        }
+        user
+    }
+}
+
+/// Builder for Bitcoin deposit scenarios
+///
+/// Defaults to the happy path: a tier-2 user, six confirmations and a fully
+/// backed reserve manager.
+pub struct DepositScenario<'h, 'a> {
+    harness: &'h TestHarness<'a>,
+    setup: ScenarioSetup,
+    btc_amount: u64,
+    confirmations: u32,
+    btc_tx_hash: BytesN<32>,
+    tracked: bool,
+}
+
+impl<'h, 'a> DepositScenario<'h, 'a> {
+    pub fn new(harness: &'h TestHarness<'a>, btc_amount: u64) -> Self {
+        DepositScenario {
+            harness,
+            setup: ScenarioSetup::new(&harness.env),
+            btc_amount,
+            confirmations: 6,
+            btc_tx_hash: BytesN::from_array(&harness.env, &[7u8; 32]),
+            tracked: false,
+        }
+    }
+
+    pub fn kyc_tier(mut self, tier: u32) -> Self {
+        self.setup.tier = tier;
+        self
+    }
+
+    pub fn blacklisted(mut self) -> Self {
+        self.setup.blacklisted = true;
+        self
+    }
+
+    pub fn reserves(mut self, reserves: u64, supply: u64) -> Self {
+        self.setup.position = Some((reserves, supply));
+        self
+    }
+
+    pub fn fail_on(mut self, contract: MockContract, function: &str) -> Self {
+        self.setup.fail(contract, function);
+        self
+    }
+
+    pub fn confirmations(mut self, confirmations: u32) -> Self {
+        self.confirmations = confirmations;
+        self
+    }
+
+    pub fn btc_tx_hash(mut self, btc_tx_hash: BytesN<32>) -> Self {
+        self.btc_tx_hash = btc_tx_hash;
+        self
+    }
+
+    /// Run through `execute_btc_deposit_tracked`, which commits failed and
+    /// rolled-back statuses instead of reverting them
+    pub fn tracked(mut self) -> Self {
+        self.tracked = true;
+        self
+    }
+
+    pub fn run(self) -> ScenarioOutcome<BytesN<32>> {
+        let h = self.harness;
+        let user = self.setup.apply(h);
+        let report = h.router.simulate_bitcoin_deposit(&h.operator, &user, &self.btc_amount, &self.btc_tx_hash, &self.confirmations);
+        let result = if self.tracked {
+            scenario_result(
+                h.router.try_execute_btc_deposit_tracked(&h.operator, &user, &self.btc_amount, &self.btc_tx_hash, &self.confirmations)
+            )
+        } else {
+            scenario_result(
+                h.router.try_execute_bitcoin_deposit(&h.operator, &user, &self.btc_amount, &self.btc_tx_hash, &self.confirmations)
+            )
+        };
+        ScenarioOutcome { user, report, result }
+    }
+}
+
+/// Builder for token withdrawal scenarios
+///
+/// Defaults to the happy path: a tier-2 user holding exactly the withdrawn
+/// iSTSi and a fully backed reserve manager.
+pub struct WithdrawalScenario<'h, 'a> {
+    harness: &'h TestHarness<'a>,
+    setup: ScenarioSetup,
+    istsi_amount: u64,
+    balance: Option<u64>,
+    btc_address: String,
+    tracked: bool,
+}
+
+impl<'h, 'a> WithdrawalScenario<'h, 'a> {
+    pub fn new(harness: &'h TestHarness<'a>, istsi_amount: u64) -> Self {
+        WithdrawalScenario {
+            harness,
+            setup: ScenarioSetup::new(&harness.env),
+            istsi_amount,
+            balance: None,
+            btc_address: String::from_str(&harness.env, "bc1qharnesswithdrawal"),
+            tracked: false,
+        }
+    }
+
+    pub fn kyc_tier(mut self, tier: u32) -> Self {
+        self.setup.tier = tier;
+        self
+    }
+
+    pub fn blacklisted(mut self) -> Self {
+        self.setup.blacklisted = true;
+        self
+    }
+
+    pub fn reserves(mut self, reserves: u64, supply: u64) -> Self {
+        self.setup.position = Some((reserves, supply));
+        self
+    }
+
+    pub fn fail_on(mut self, contract: MockContract, function: &str) -> Self {
+        self.setup.fail(contract, function);
+        self
+    }
+
+    /// iSTSi minted to the user before the withdrawal (defaults to the withdrawn amount)
+    pub fn balance(mut self, balance: u64) -> Self {
+        self.balance = Some(balance);
+        self
+    }
+
+    pub fn btc_address(mut self, btc_address: &str) -> Self {
+        self.btc_address = String::from_str(&self.harness.env, btc_address);
+        self
+    }
+
+    /// Run through `execute_token_withdrawal_tracked`, which commits failed
+    /// and rolled-back statuses instead of reverting them
+    pub fn tracked(mut self) -> Self {
+        self.tracked = true;
+        self
+    }
+
+    pub fn run(self) -> ScenarioOutcome<BytesN<32>> {
+        let h = self.harness;
+        let user = self.setup.apply(h);
+        h.istsi.mint(&user, &(self.balance.unwrap_or(self.istsi_amount) as i128));
+
+        let report = h.router.simulate_token_withdrawal(&h.operator, &user, &self.istsi_amount, &self.btc_address, &None);
+        let result = if self.tracked {
+            scenario_result(
+                h.router.try_execute_token_withdrawal_tracked(&h.operator, &user, &self.istsi_amount, &self.btc_address)
+            )
+        } else {
+            scenario_result(
+                h.router.try_execute_token_withdrawal(&h.operator, &user, &self.istsi_amount, &self.btc_address)
+            )
+        };
+        ScenarioOutcome { user, report, result }
+    }
+}
+
+/// Builder for iSTSi → fungible exchange scenarios
+///
+/// Defaults to the happy path: a tier-2 user holding the exchanged iSTSi,
+/// a 1:1 rate and market-maker liquidity for ten times the amount.
+pub struct ExchangeScenario<'h, 'a> {
+    harness: &'h TestHarness<'a>,
+    setup: ScenarioSetup,
+    from_amount: u64,
+    rate: u64,
+    liquidity: u64,
+    max_slippage_bps: u64,
+}
+
+impl<'h, 'a> ExchangeScenario<'h, 'a> {
+    pub fn new(harness: &'h TestHarness<'a>, from_amount: u64) -> Self {
+        ExchangeScenario {
+            harness,
+            setup: ScenarioSetup::new(&harness.env),
+            from_amount,
+            rate: 10000,
+            liquidity: from_amount.saturating_mul(10),
+            max_slippage_bps: 500,
+        }
+    }
+
+    pub fn kyc_tier(mut self, tier: u32) -> Self {
+        self.setup.tier = tier;
+        self
+    }
+
+    pub fn fail_on(mut self, contract: MockContract, function: &str) -> Self {
+        self.setup.fail(contract, function);
+        self
+    }
+
+    /// Exchange rate in basis points
+    pub fn rate(mut self, rate: u64) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    pub fn liquidity(mut self, liquidity: u64) -> Self {
+        self.liquidity = liquidity;
+        self
+    }
+
+    pub fn max_slippage_bps(mut self, max_slippage_bps: u64) -> Self {
+        self.max_slippage_bps = max_slippage_bps;
+        self
+    }
+
+    pub fn run(self) -> ScenarioOutcome<ExchangeOperation> {
+        let h = self.harness;
+        if self.liquidity > 0 {
+            h.fund_exchange(self.rate, self.liquidity);
+        }
+        let user = self.setup.apply(h);
+        h.istsi.mint(&user, &(self.from_amount as i128));
+
+        let (from_token, to_token) = (&h.istsi.address, &h.fungible.address);
+        let report = h.router.simulate_exchange(&user, from_token, to_token, &self.from_amount, &self.max_slippage_bps);
+        let result = scenario_result(
+            h.router.try_execute_cross_token_exchange(&user, from_token, to_token, &self.from_amount, &self.max_slippage_bps)
+        );
+        ScenarioOutcome { user, report, result }
+    }
+}
+
+/// Serialize a non-witness Bitcoin transaction for payout and replacement tests
+///
+/// Inputs are `(txid, vout)` and outputs `(value, scriptPubKey)`; counts and
+/// scripts must stay below 253 so every varint is a single byte.
+pub fn raw_bitcoin_tx(env: &Env, inputs: &[(BytesN<32>, u32)], outputs: &[(u64, Bytes)]) -> Bytes {
+    let mut raw = Bytes::from_array(env, &2u32.to_le_bytes());
+    raw.push_back(inputs.len() as u8);
+    for (txid, vout) in inputs {
+        raw.extend_from_array(&txid.to_array());
+        raw.extend_from_array(&vout.to_le_bytes());
+        raw.push_back(0); // empty scriptSig
+        raw.extend_from_array(&0xffff_fffdu32.to_le_bytes());
+    }
+    raw.push_back(outputs.len() as u8);
+    for (value, script) in outputs {
+        raw.extend_from_array(&value.to_le_bytes());
+        raw.push_back(script.len() as u8);
+        raw.append(script);
+    }
+    raw.extend_from_array(&0u32.to_le_bytes());
+    raw
+}
@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{DepositScenario, ExchangeScenario, MockContract, TestHarness, WithdrawalScenario, HARNESS_RESERVES_SATS};
+use soroban_sdk::Env;
+
+#[test]
+fn test_harness_scenarios_pass_on_the_happy_path() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+
+    let deposit = DepositScenario::new(&h, 50_000).run();
+    assert!(deposit.report.passed);
+    assert_eq!(deposit.report.projected_amount, 5_000_000_000_000);
+
+    let withdrawal = WithdrawalScenario::new(&h, 5_000_000_000_000).run();
+    assert!(withdrawal.report.passed);
+    assert_eq!(withdrawal.report.projected_amount, 50_000);
+
+    // Exchanges run end to end against the mock tokens
+    let exchange = ExchangeScenario::new(&h, 100_000).run();
+    assert!(exchange.report.passed);
+    let operation = exchange.result.unwrap();
+    assert_eq!(operation.status, ExchangeStatus::Completed);
+    assert_eq!(h.istsi.balance(&exchange.user), 0);
+    assert_eq!(h.fungible.balance(&exchange.user), operation.to_amount as i128);
+}
+
+#[test]
+fn test_harness_scenarios_surface_failure_paths() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+
+    // KYC tiers and limits drive the registry's answers
+    h.kyc.set_tier_limit(&1u32, &10_000i128);
+    assert!(!DepositScenario::new(&h, 50_000).kyc_tier(0).run().step_passed("kyc"));
+    assert!(!DepositScenario::new(&h, 50_000).kyc_tier(1).run().step_passed("kyc"));
+    assert!(DepositScenario::new(&h, 5_000).kyc_tier(1).run().step_passed("kyc"));
+
+    let blacklisted = DepositScenario::new(&h, 50_000).blacklisted().run();
+    assert!(!blacklisted.step_passed("blacklist"));
+    assert_eq!(blacklisted.error(), Some(IntegrationError::AddressBlacklisted));
+
+    let underbacked = DepositScenario::new(&h, 50_000).reserves(90_000_000, 100_000_000).run();
+    assert!(!underbacked.step_passed("reserves"));
+    assert!(!underbacked.succeeded());
+    h.reserve.set_position(&HARNESS_RESERVES_SATS, &HARNESS_RESERVES_SATS);
+
+    let short = WithdrawalScenario::new(&h, 5_000_000_000_000).balance(1_000).run();
+    assert!(!short.step_passed("balance"));
+
+    // Injected failures look like an unavailable contract and stay until restored
+    let unreadable = DepositScenario::new(&h, 50_000).fail_on(MockContract::ReserveManager, "get_reserve_ratio").run();
+    assert!(!unreadable.step_passed("reserves"));
+    assert!(!DepositScenario::new(&h, 50_000).run().step_passed("reserves"));
+    h.restore(MockContract::ReserveManager, "get_reserve_ratio");
+    assert!(DepositScenario::new(&h, 50_000).run().step_passed("reserves"));
+
+    let failed_payout = ExchangeScenario::new(&h, 100_000).fail_on(MockContract::FungibleToken, "transfer").run();
+    assert!(failed_payout.report.passed);
+    assert_eq!(failed_payout.result.unwrap().status, ExchangeStatus::RolledBack);
+    assert_eq!(h.istsi.balance(&failed_payout.user), 100_000);
+}
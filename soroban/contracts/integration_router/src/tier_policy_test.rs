@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::Env;
+
+fn policy(h: &TestHarness, daily: u64, monthly: u64, min_confirmations: u32, version: u32) -> TierPolicy {
+    TierPolicy {
+        daily_deposit_limit: daily,
+        monthly_deposit_limit: monthly,
+        daily_withdrawal_limit: daily / 2,
+        monthly_withdrawal_limit: monthly / 2,
+        daily_exchange_limit: daily,
+        monthly_exchange_limit: monthly,
+        min_confirmations,
+        enhanced_verification_limit: daily / 4,
+        version,
+        updated_at: HARNESS_START_TIME,
+        updated_by: h.admin.clone(),
+    }
+}
+
+#[test]
+fn test_limits_follow_tier_policy_until_overridden() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    h.kyc.set_tier(&user, &2);
+
+    // Without a policy the router keeps its built-in defaults
+    assert_eq!(h.router.get_deposit_limits(&user).daily_limit, 100_000_000);
+    assert_eq!(h.router.get_deposit_conf_requirements(&user, &1_000), (3, false));
+
+    h.kyc.set_tier_policy(&2, &policy(&h, 40_000, 400_000, 4, 1));
+    let limits = h.router.get_deposit_limits(&user);
+    assert_eq!((limits.kyc_tier, limits.daily_limit, limits.monthly_limit), (2, 40_000, 400_000));
+    let limits = h.router.get_withdrawal_limits(&user);
+    assert_eq!((limits.daily_limit, limits.enhanced_verification_limit), (20_000, 10_000));
+    assert_eq!(h.router.get_deposit_conf_requirements(&user, &9_999), (4, false));
+    assert_eq!(h.router.get_deposit_conf_requirements(&user, &10_000), (4, true));
+
+    // Policy updates reach users without any per-user write
+    h.kyc.set_tier_policy(&2, &policy(&h, 80_000, 800_000, 4, 2));
+    assert_eq!(h.router.get_deposit_limits(&user).daily_limit, 80_000);
+
+    // An admin override sticks through later policy changes
+    h.router.set_deposit_limits(&h.admin, &user, &5_000, &50_000);
+    h.kyc.set_tier_policy(&2, &policy(&h, 120_000, 1_200_000, 4, 3));
+    assert_eq!(h.router.get_deposit_limits(&user).daily_limit, 5_000);
+    assert_eq!(h.router.get_withdrawal_limits(&user).daily_limit, 60_000);
+}
+
+#[test]
+fn test_clearing_override_restores_tier_policy() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    h.kyc.set_tier(&user, &1);
+    h.kyc.set_tier_policy(&1, &policy(&h, 10_000, 100_000, 6, 1));
+
+    assert_eq!(
+        h.router.try_clear_limit_override(&h.admin, &user, &LimitKind::Deposit),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert_eq!(
+        h.router.try_clear_limit_override(&h.admin, &user, &LimitKind::Exchange),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    h.router.set_withdrawal_limits(&h.admin, &user, &1_000, &9_000, &500);
+    assert!(h.router.try_clear_limit_override(&h.operator, &user, &LimitKind::Withdrawal).is_err());
+    h.router.clear_limit_override(&h.admin, &user, &LimitKind::Withdrawal);
+
+    let limits = h.router.get_withdrawal_limits(&user);
+    assert_eq!((limits.daily_limit, limits.monthly_limit), (5_000, 50_000));
+    let changes = h.router.get_limit_changes(&user, &0, &10);
+    assert_eq!(changes.len(), 2);
+    let cleared = changes.get_unchecked(1);
+    assert_eq!((cleared.old_daily_limit, cleared.new_daily_limit), (1_000, 5_000));
+}
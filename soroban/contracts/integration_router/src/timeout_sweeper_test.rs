@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as TestAddress, Ledger},
+    Address, Env
+};
+
+#[test]
+fn test_sweep_marks_expired_operations_timed_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+
+    let calls = Vec::new(&env);
+    let rollback_calls = Vec::new(&env);
+    let short_op = client.create_batch_operation(&admin, &calls, &rollback_calls, &60u64, &true);
+    let long_op = client.create_batch_operation(&admin, &calls, &rollback_calls, &3600u64, &true);
+    assert_eq!(client.get_pending_operations().len(), 2);
+
+    // Nothing has expired yet
+    assert_eq!(client.sweep_timed_out_operations(&admin, &10u32), 0);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_061;
+    });
+
+    assert_eq!(client.sweep_timed_out_operations(&admin, &10u32), 1);
+    assert_eq!(client.get_operation_status(&short_op).unwrap().status, OperationStatus::TimedOut);
+    assert_eq!(client.get_batch_operation(&short_op).unwrap().status, OperationStatus::TimedOut);
+    assert_eq!(client.get_operation_status(&long_op).unwrap().status, OperationStatus::Pending);
+
+    let pending = client.get_pending_operations();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap(), long_op);
+    assert!(client.get_failed_operations().contains(&short_op));
+}
+
+#[test]
+fn test_sweep_respects_max_items() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+
+    let calls = Vec::new(&env);
+    for _ in 0..3 {
+        client.create_batch_operation(&admin, &calls, &calls, &0u64, &false);
+    }
+
+    assert_eq!(client.sweep_timed_out_operations(&admin, &2u32), 2);
+    assert_eq!(client.get_pending_operations().len(), 1);
+    assert_eq!(client.sweep_timed_out_operations(&admin, &2u32), 1);
+    assert_eq!(client.get_pending_operations().len(), 0);
+}
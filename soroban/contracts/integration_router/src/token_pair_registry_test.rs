@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::TestHarness;
+use soroban_sdk::Env;
+
+fn listing_message(env: &Env, report: &SimulationReport) -> String {
+    let listing = String::from_str(env, "listing");
+    report.steps.iter().find(|step| step.name == listing).unwrap().message
+}
+
+#[test]
+fn test_registered_pairs_carry_fee_and_trade_size() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let (istsi, fungible) = (h.istsi.address.clone(), h.fungible.address.clone());
+    let oracle = Address::generate(&env);
+    assert_eq!(h.router.list_pairs().len(), 0);
+
+    assert!(h.router.try_register_token_pair(&h.operator, &istsi, &fungible, &100, &1_000, &500_000, &oracle).is_err());
+    assert_eq!(
+        h.router.try_register_token_pair(&h.admin, &istsi, &fungible, &100, &600_000, &500_000, &oracle),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert_eq!(
+        h.router.try_register_token_pair(&h.admin, &istsi, &h.reserve.address, &100, &1_000, &500_000, &oracle),
+        Err(Ok(IntegrationError::ContractNotFound))
+    );
+    h.router.register_token_pair(&h.admin, &istsi, &fungible, &100, &1_000, &500_000, &oracle);
+
+    // Configuring the oracle rebinds the registered pair instead of listing a second one
+    h.fund_exchange(10000, 1_000_000);
+    let pairs = h.router.list_pairs();
+    assert_eq!(pairs.len(), 1);
+    let listed = pairs.get_unchecked(0);
+    assert_eq!((listed.fee_rate, listed.max_trade_size), (100, 500_000));
+    assert_ne!(listed.oracle_address, oracle);
+    assert_eq!(h.router.get_token_pair(&fungible, &istsi), Some(listed));
+
+    let user = h.user(2);
+    h.istsi.mint(&user, &1_000_000);
+    let exchange = h.router.execute_cross_token_exchange(&user, &istsi, &fungible, &100_000, &500);
+    assert_eq!(exchange.fee_amount, 1_000);
+    assert_eq!(
+        h.router.try_execute_cross_token_exchange(&user, &istsi, &fungible, &999, &500),
+        Err(Ok(IntegrationError::DustAmount))
+    );
+    assert_eq!(
+        h.router.try_execute_cross_token_exchange(&user, &istsi, &fungible, &500_001, &500),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
+
+#[test]
+fn test_unregistered_and_disabled_pairs_are_not_tradable() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let (istsi, fungible) = (h.istsi.address.clone(), h.fungible.address.clone());
+    let user = h.user(2);
+    h.istsi.mint(&user, &1_000_000);
+
+    assert_eq!(
+        h.router.try_execute_cross_token_exchange(&user, &istsi, &fungible, &100_000, &500),
+        Err(Ok(IntegrationError::ContractNotFound))
+    );
+    assert_eq!(
+        h.router.try_place_limit_order(&user, &istsi, &fungible, &100_000, &10_000, &(env.ledger().timestamp() + 60)),
+        Err(Ok(IntegrationError::ContractNotFound))
+    );
+    let report = h.router.simulate_exchange(&user, &istsi, &fungible, &100_000, &500);
+    assert_eq!(listing_message(&env, &report), String::from_str(&env, "Pair is not registered"));
+    assert_eq!(
+        h.router.try_set_token_pair_enabled(&h.admin, &istsi, &fungible, &false),
+        Err(Ok(IntegrationError::ContractNotFound))
+    );
+
+    h.fund_exchange(10000, 1_000_000);
+    h.router.set_token_pair_enabled(&h.admin, &fungible, &istsi, &false);
+    assert!(!h.router.get_token_pair(&istsi, &fungible).unwrap().enabled);
+    assert_eq!(
+        h.router.try_execute_cross_token_exchange(&user, &istsi, &fungible, &100_000, &500),
+        Err(Ok(IntegrationError::WorkflowPaused))
+    );
+    let report = h.router.simulate_exchange(&user, &istsi, &fungible, &100_000, &500);
+    assert!(!report.passed);
+    assert_eq!(listing_message(&env, &report), String::from_str(&env, "Pair is disabled"));
+    let status = h.router.get_exchange_compliance_status(&user, &istsi, &fungible);
+    assert_eq!(status.compliance_status, String::from_str(&env, "pair_disabled"));
+
+    h.router.set_token_pair_enabled(&h.admin, &istsi, &fungible, &true);
+    h.router.execute_cross_token_exchange(&user, &istsi, &fungible, &100_000, &500);
+}
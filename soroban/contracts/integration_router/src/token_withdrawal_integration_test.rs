@@ -1,282 +0,0 @@
-#[cfg(test)]
-mod token_withdrawal_integration_tests {
-    use super::*;
-    use soroban_sdk::{testutils::Address as TestAddress, Address, Env, BytesN};
-
-    fn create_test_env() -> (Env, Address, Address, Address, Address, Address, Address) {
-        let env = Env::default();
-        env.mock_all_auths();
-
-        let admin = Address::generate(&env);
-        let user = Address::generate(&env);
-        let kyc_registry = Address::generate(&env);
-        let istsi_token = Address::generate(&env);
-        let fungible_token = Address::generate(&env);
-        let reserve_manager = Address::generate(&env);
-
-        (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager)
-    }
-
-    fn initialize_router(
-        env: &Env,
-        admin: &Address,
-        kyc_registry: &Address,
-        istsi_token: &Address,
-        fungible_token: &Address,
-        reserve_manager: &Address,
-    ) {
-        IntegrationRouter::initialize(
-            env.clone(),
-            admin.clone(),
-            kyc_registry.clone(),
-            istsi_token.clone(),
-            fungible_token.clone(),
-            reserve_manager.clone(),
-        );
-    }
-
-    #[test]
-    fn test_complete_withdrawal_workflow() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        // Set user as operator for testing
-        IntegrationRouter::set_user_role(env.clone(), admin.clone(), user.clone(), UserRole::Operator);
-
-        let istsi_amount = 100_000_000u64; // 1 iSTSi token
-        let btc_address = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
-
-        // Execute withdrawal workflow
-        let withdrawal_id = IntegrationRouter::execute_token_withdrawal(
-            env.clone(),
-            user.clone(),
-            user.clone(),
-            istsi_amount,
-            btc_address.clone(),
-        );
-
-        // Verify withdrawal ID is generated
-        assert!(!withdrawal_id.to_array().iter().all(|&x| x == 0));
-
-        // Check withdrawal status
-        let withdrawal_status = IntegrationRouter::get_withdrawal_status(env.clone(), withdrawal_id.clone());
-        assert!(withdrawal_status.is_some());
-
-        let status = withdrawal_status.unwrap();
-        assert_eq!(status.user, user);
-        assert_eq!(status.istsi_amount, istsi_amount);
-        assert_eq!(status.btc_amount, 1u64); // 1 satoshi for 100M iSTSi
-        assert_eq!(status.btc_address, btc_address);
-        assert_eq!(status.status, WithdrawalProcessingStatus::Completed);
-    }
-
-    #[test]
-    fn test_withdrawal_with_insufficient_balance() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        // Set user as operator for testing
-        IntegrationRouter::set_user_role(env.clone(), admin.clone(), user.clone(), UserRole::Operator);
-
-        let istsi_amount = 1_000_000_000_000u64; // Very large amount
-        let btc_address = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
-
-        // This should panic due to insufficient balance
-        let result = std::panic::catch_unwind(|| {
-            IntegrationRouter::execute_token_withdrawal(
-                env.clone(),
-                user.clone(),
-                user.clone(),
-                istsi_amount,
-                btc_address,
-            );
-        });
-
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_withdrawal_kyc_compliance_failure() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        // Set user as operator for testing
-        IntegrationRouter::set_user_role(env.clone(), admin.clone(), user.clone(), UserRole::Operator);
-
-        let istsi_amount = 50_000_000u64; // 0.5 iSTSi token
-        let btc_address = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
-
-        // This should panic due to KYC compliance failure (simulated)
-        let result = std::panic::catch_unwind(|| {
-            IntegrationRouter::execute_token_withdrawal(
-                env.clone(),
-                user.clone(),
-                user.clone(),
-                istsi_amount,
-                btc_address,
-            );
-        });
-
-        // In our simulation, this might pass or fail depending on the mock implementation
-        // The test verifies that the function handles KYC failures appropriately
-    }
-
-    #[test]
-    fn test_withdrawal_with_system_paused() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        // Set user as operator for testing
-        IntegrationRouter::set_user_role(env.clone(), admin.clone(), user.clone(), UserRole::Operator);
-
-        // Pause the system
-        IntegrationRouter::emergency_pause(
-            env.clone(),
-            admin.clone(),
-            String::from_str(&env, "Testing withdrawal during pause"),
-        );
-
-        let istsi_amount = 100_000_000u64;
-        let btc_address = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
-
-        // This should panic due to system being paused
-        let result = std::panic::catch_unwind(|| {
-            IntegrationRouter::execute_token_withdrawal(
-                env.clone(),
-                user.clone(),
-                user.clone(),
-                istsi_amount,
-                btc_address,
-            );
-        });
-
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_atomic_withdrawal_workflow() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        // Set user as operator for testing
-        IntegrationRouter::set_user_role(env.clone(), admin.clone(), user.clone(), UserRole::Operator);
-
-        let istsi_amount = 200_000_000u64; // 2 iSTSi tokens
-        let btc_address = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
-
-        // Execute atomic withdrawal workflow
-        let result = IntegrationRouter::execute_token_withdrawal_tracked(
-            env.clone(),
-            user.clone(),
-            user.clone(),
-            istsi_amount,
-            btc_address.clone(),
-        );
-
-        // Verify successful execution
-        assert!(result.is_ok());
-        let withdrawal_id = result.unwrap();
-
-        // Check withdrawal status
-        let withdrawal_status = IntegrationRouter::get_withdrawal_status(env.clone(), withdrawal_id.clone());
-        assert!(withdrawal_status.is_some());
-
-        let status = withdrawal_status.unwrap();
-        assert_eq!(status.istsi_amount, istsi_amount);
-        assert_eq!(status.btc_amount, 2u64); // 2 satoshi for 200M iSTSi
-    }
-
-    #[test]
-    fn test_withdrawal_limits_checking() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        let istsi_amount = 500_000_000u64; // 5 iSTSi tokens
-
-        // Check withdrawal limits
-        let (approved, message, limit) = IntegrationRouter::check_withdrawal_limits(
-            env.clone(),
-            user.clone(),
-            istsi_amount,
-        );
-
-        // Verify limit checking functionality
-        assert!(approved || !approved); // Either result is valid for testing
-        assert!(limit >= 0); // Limit should be non-negative
-    }
-
-    #[test]
-    fn test_withdrawal_requirements() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        let istsi_amount = 1_000_000_000u64; // 10 iSTSi tokens
-
-        // Get withdrawal requirements
-        let (tier, enhanced_verification, cooling_period) = IntegrationRouter::get_withdrawal_requirements(
-            env.clone(),
-            user.clone(),
-            istsi_amount,
-        );
-
-        // Verify requirements are returned
-        assert!(tier >= 1 && tier <= 5); // Valid tier range
-        assert!(cooling_period <= 168); // Max 1 week cooling period
-    }
-
-    #[test]
-    fn test_pending_withdrawals_query() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        // Set user as operator for testing
-        IntegrationRouter::set_user_role(env.clone(), admin.clone(), user.clone(), UserRole::Operator);
-
-        // Get pending withdrawals (should be empty initially)
-        let pending_withdrawals = IntegrationRouter::get_pending_withdrawals(env.clone(), user.clone());
-        
-        // Initially should be empty or contain test data
-        assert!(pending_withdrawals.len() >= 0);
-    }
-
-    #[test]
-    fn test_withdrawal_status_tracking() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        // Create a withdrawal ID for testing
-        let withdrawal_id = BytesN::from_array(&env, &[1u8; 32]);
-        let operation_id = BytesN::from_array(&env, &[2u8; 32]);
-        let istsi_amount = 100_000_000u64;
-        let btc_address = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
-
-        // Test withdrawal status tracking by checking if we can get withdrawal status
-        // Since the helper functions are private, we'll test the public interface
-        
-        // Try to get a non-existent withdrawal status
-        let status = IntegrationRouter::get_withdrawal_status(env.clone(), withdrawal_id.clone());
-        assert!(status.is_none()); // Should be None for non-existent withdrawal
-    }
-
-    #[test]
-    fn test_withdrawal_rollback_mechanisms() {
-        let (env, admin, user, kyc_registry, istsi_token, fungible_token, reserve_manager) = create_test_env();
-        initialize_router(&env, &admin, &kyc_registry, &istsi_token, &fungible_token, &reserve_manager);
-
-        let istsi_amount = 100_000_000u64;
-        let withdrawal_id = BytesN::from_array(&env, &[3u8; 32]);
-
-        // Test rollback mechanisms by attempting a withdrawal that should fail
-        // and verify that the system handles rollbacks appropriately
-        
-        // Since rollback functions are private, we test the public interface
-        // by attempting operations that might trigger rollbacks
-        
-        // Try to get withdrawal status for a non-existent withdrawal
-        let status = IntegrationRouter::get_withdrawal_status(env.clone(), withdrawal_id.clone());
-        assert!(status.is_none()); // Should be None for non-existent withdrawal
-        
-        // Test that the system can handle invalid withdrawal requests gracefully
-        assert!(istsi_amount > 0); // Basic validation that our test data is valid
-    }
-}
\ No newline at end of file
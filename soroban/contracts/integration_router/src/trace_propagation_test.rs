@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as TestAddress, Address, BytesN, Env};
+use crate::testing::TestHarness;
+
+fn event(env: &Env, user: &Address, correlation_id: BytesN<32>) -> IntegrationEvent {
+    IntegrationEvent {
+        event_type: String::from_str(env, "BitcoinDeposit"),
+        user: user.clone(),
+        data1: 1_000,
+        data2: 0,
+        data3: 0,
+        address1: user.clone(),
+        address2: user.clone(),
+        hash_data: BytesN::from_array(env, &[0u8; 32]),
+        text_data: String::from_str(env, ""),
+        timestamp: 0,
+        correlation_id,
+        parent_correlation_id: None,
+    }
+}
+
+#[test]
+fn test_trace_id_carried_into_correlation_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+    let user = Address::generate(&env);
+
+    let trace_id = [0xabu8; 16];
+    let mut seed = [0u8; 32];
+    seed[16..32].copy_from_slice(&trace_id);
+
+    let first = client.emit_integration_event(&admin, &event(&env, &user, BytesN::from_array(&env, &seed)));
+    let second = client.emit_integration_event(&admin, &event(&env, &user, first.clone()));
+    assert_ne!(first, second);
+    assert_eq!(first.to_array()[16..32], trace_id);
+    assert_eq!(second.to_array()[16..32], trace_id);
+
+    // Events without a trace keep the untraced layout
+    let untraced = client.emit_integration_event(&admin, &event(&env, &user, BytesN::from_array(&env, &[0u8; 32])));
+    assert!(untraced.to_array()[20..32].iter().all(|b| *b == 0));
+}
+
+#[test]
+fn test_trace_context_reaches_the_token_burn() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+
+    let user = h.user(3);
+    h.istsi.mint(&user, &2_000_000_000i128);
+    let btc_address = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
+
+    assert_eq!(
+        h.router.try_set_trace_context(&h.operator, &BytesN::from_array(&env, &[0u8; 16])),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    h.router.set_trace_context(&h.operator, &BytesN::from_array(&env, &[0x5au8; 16]));
+    h.router.execute_token_withdrawal(&h.operator, &user, &100_000_000u64, &btc_address);
+
+    // The burn carries the workflow's correlation ID, whose tail is the trace
+    let correlation = h.istsi.last_burn_correlation().unwrap();
+    assert_eq!(correlation.len(), 64);
+    let mut hex = [0u8; 64];
+    correlation.copy_into_slice(&mut hex);
+    assert_eq!(&hex[32..64], b"5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a");
+
+    // The context is consumed by the workflow it was set for
+    h.router.execute_token_withdrawal(&h.operator, &user, &100_000_000u64, &btc_address);
+    let mut hex = [0u8; 64];
+    h.istsi.last_burn_correlation().unwrap().copy_into_slice(&mut hex);
+    assert!(hex[40..64].iter().all(|c| *c == b'0'));
+}
@@ -0,0 +1,146 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::{storage::Persistent, Ledger}, vec, Env};
+
+fn persistent_ttl<K: IntoVal<Env, Val>>(h: &TestHarness, key: &K) -> u32 {
+    h.env.as_contract(&h.router.address, || h.env.storage().persistent().get_ttl(key))
+}
+
+/// Store a completed operation tracker last updated at `updated_at`
+fn seed_completed(h: &TestHarness, seed: u8, operation_type: &str, updated_at: u64) -> BytesN<32> {
+    let operation_id = BytesN::from_array(&h.env, &[seed; 32]);
+    let tracker = OperationTracker {
+        operation_id: operation_id.clone(),
+        operation_type: String::from_str(&h.env, operation_type),
+        status: OperationStatus::Completed,
+        created_at: updated_at,
+        updated_at,
+        timeout_at: updated_at + 300,
+        retry_count: 0,
+        error_message: String::from_str(&h.env, ""),
+    };
+    h.env.as_contract(&h.router.address, || {
+        h.env.storage().persistent().set(&DataKey::OperationTracker(operation_id.clone()), &tracker);
+        IntegrationRouter::list_operation(&h.env, &operation_id, OperationList::Completed);
+    });
+    operation_id
+}
+
+#[test]
+fn test_entries_are_extended_by_keepers_not_reads() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let calls = Vec::new(&env);
+    h.router.extend_ttl_batch(&Vec::new(&env));
+
+    let operation_id = h.router.create_batch_operation(&h.operator, &calls, &Vec::new(&env), &300, &false);
+    let tracker_key = DataKey::OperationTracker(operation_id.clone());
+    assert_eq!(persistent_ttl(&h, &tracker_key), ENTRY_TTL_EXTEND_TO);
+
+    // Reads never write, so the TTL keeps running down
+    env.ledger().with_mut(|li| li.sequence_number += 95 * DAY_IN_LEDGERS);
+    assert!(h.router.get_operation_status(&operation_id).is_some());
+    assert_eq!(persistent_ttl(&h, &tracker_key), ENTRY_TTL_EXTEND_TO - 95 * DAY_IN_LEDGERS);
+
+    // Keepers extend listed entries and skip missing ones
+    let keys = vec![
+        &env,
+        TtlKey::OperationTracker(operation_id.clone()),
+        TtlKey::BatchOperation(operation_id.clone()),
+        TtlKey::DepositStatus(BytesN::from_array(&env, &[9u8; 32])),
+    ];
+    assert_eq!(h.router.extend_ttl_batch(&keys), 2);
+    assert_eq!(persistent_ttl(&h, &tracker_key), ENTRY_TTL_EXTEND_TO);
+    assert_eq!(persistent_ttl(&h, &DataKey::BatchOperation(operation_id)), ENTRY_TTL_EXTEND_TO);
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..101 {
+        too_many.push_back(TtlKey::OperationArchive(0));
+    }
+    assert_eq!(h.router.try_extend_ttl_batch(&too_many), Err(Ok(IntegrationError::InvalidOperationState)));
+}
+
+#[test]
+fn test_completed_operations_are_compacted_into_daily_archives() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let day = HARNESS_START_TIME - HARNESS_START_TIME % ARCHIVE_PERIOD;
+    seed_completed(&h, 1, "batch_operation", day + 100);
+    seed_completed(&h, 2, "bitcoin_deposit", day + 200);
+    seed_completed(&h, 3, "batch_operation", day + ARCHIVE_PERIOD + 50);
+    let recent = seed_completed(&h, 4, "batch_operation", day + 2 * ARCHIVE_PERIOD);
+    let cutoff = day + 2 * ARCHIVE_PERIOD;
+
+    assert!(h.router.try_compact_completed_operations(&h.operator, &cutoff, &10).is_err());
+
+    // The per-call limit leaves the rest for a later call
+    assert_eq!(h.router.compact_completed_operations(&h.admin, &cutoff, &2), 2);
+    assert_eq!(h.router.get_completed_operations().len(), 2);
+    assert_eq!(h.router.compact_completed_operations(&h.admin, &cutoff, &2), 1);
+    assert_eq!(h.router.get_completed_operations(), vec![&env, recent]);
+    assert_eq!(h.router.get_operation_status(&BytesN::from_array(&env, &[1u8; 32])), None);
+
+    assert_eq!(h.router.get_archived_periods(), vec![&env, day, day + ARCHIVE_PERIOD]);
+    let first = h.router.get_operation_archive(&day).unwrap();
+    assert_eq!(first.operation_count, 2);
+    assert_eq!(first.operation_types.get(String::from_str(&env, "batch_operation")), Some(1));
+    assert_eq!(first.operation_types.get(String::from_str(&env, "bitcoin_deposit")), Some(1));
+    assert_eq!((first.first_updated_at, first.last_updated_at), (day + 100, day + 200));
+    assert_eq!(h.router.get_operation_archive(&(day + ARCHIVE_PERIOD)).unwrap().operation_count, 1);
+
+    // Later compactions fold into the existing period record
+    seed_completed(&h, 5, "batch_operation", day + 300);
+    assert_eq!(h.router.compact_completed_operations(&h.admin, &cutoff, &10), 1);
+    let first = h.router.get_operation_archive(&day).unwrap();
+    assert_eq!(first.operation_count, 3);
+    assert_eq!(first.last_updated_at, day + 300);
+    assert_eq!(h.router.get_archived_periods().len(), 2);
+}
+
+#[test]
+fn test_compliance_events_live_and_are_extended_for_their_retention() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let compliance = String::from_str(&env, "ComplianceAction");
+    let policy = h.router.get_event_retention_policy(&compliance);
+    assert_eq!(policy.importance, EventImportance::Compliance);
+
+    let event = IntegrationEvent {
+        event_type: compliance.clone(),
+        user: h.operator.clone(),
+        data1: 1,
+        data2: 0,
+        data3: 0,
+        address1: h.operator.clone(),
+        address2: h.operator.clone(),
+        hash_data: BytesN::from_array(&env, &[0u8; 32]),
+        text_data: String::from_str(&env, ""),
+        timestamp: env.ledger().timestamp(),
+        correlation_id: BytesN::from_array(&env, &[0u8; 32]),
+        parent_correlation_id: None,
+    };
+    let event_id = h.router.emit_integration_event(&h.operator, &event);
+    let event_key = DataKey::EventHistory(event_id.clone());
+    let index_key = DataKey::EventIndex(compliance.clone());
+
+    // Multi-year retention outlasts the network's maximum TTL, so entries start at the cap
+    let max_ttl = env.as_contract(&h.router.address, || env.storage().max_ttl());
+    assert!(policy.max_age_seconds / 5 > max_ttl as u64);
+    assert_eq!(persistent_ttl(&h, &event_key), max_ttl);
+
+    // Keepers bring them back to the cap and the metrics count it
+    env.ledger().with_mut(|li| li.sequence_number += 1_000);
+    assert_eq!(persistent_ttl(&h, &event_key), max_ttl - 1_000);
+    let keys = vec![
+        &env,
+        TtlKey::RetainedEvent(event_id),
+        TtlKey::EventIndex(compliance),
+        TtlKey::EventIndex(String::from_str(&env, "TokenWithdrawal")),
+    ];
+    assert_eq!(h.router.extend_ttl_batch(&keys), 2);
+    assert_eq!(persistent_ttl(&h, &event_key), max_ttl);
+    assert_eq!(persistent_ttl(&h, &index_key), max_ttl);
+    assert_eq!(h.router.get_event_retention_metrics().ttl_extensions, 2);
+}
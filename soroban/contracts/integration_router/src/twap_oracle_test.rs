@@ -0,0 +1,132 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as TestAddress, Ledger},
+    Address, Env
+};
+
+fn setup_router_with_oracle(env: &Env) -> (IntegrationRouterClient, Address, Address, Address, Address) {
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let oracle = Address::generate(env);
+    let token_a = Address::generate(env);
+    let token_b = Address::generate(env);
+
+    client.initialize(
+        &admin,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+
+    client.configure_oracle(
+        &admin,
+        &token_a,
+        &token_b,
+        &oracle,
+        &300u64,
+        &500u64,   // 5% max deviation
+        &10000u64, // 1:1 fallback rate
+    );
+
+    (client, contract_id, admin, token_a, token_b)
+}
+
+#[test]
+fn test_twap_window_records_observations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let (client, _, admin, token_a, token_b) = setup_router_with_oracle(&env);
+    client.configure_twap_window(&admin, &token_a, &token_b, &600u64, &10u32);
+
+    let config = client.get_twap_config(&token_a, &token_b);
+    assert_eq!(config.window_seconds, 600);
+    assert_eq!(config.max_observations, 10);
+
+    let spot = client.get_exchange_rate(&token_a, &token_b);
+    assert_eq!(client.get_price_observations(&token_a, &token_b).len(), 1);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_100;
+    });
+
+    let twap = client.get_exchange_rate_with_mode(&token_a, &token_b, &RateMode::Twap);
+    assert_eq!(twap.rate, spot.rate);
+    assert_eq!(twap.oracle_source, String::from_str(&env, "twap"));
+
+    // Observations older than the window are pruned
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_001_000;
+    });
+    client.get_exchange_rate(&token_a, &token_b);
+    assert_eq!(client.get_price_observations(&token_a, &token_b).len(), 1);
+}
+
+#[test]
+fn test_spot_deviating_from_twap_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let (client, contract_id, _, token_a, token_b) = setup_router_with_oracle(&env);
+
+    // Seed a TWAP history well below the simulated oracle spot rate
+    env.as_contract(&contract_id, || {
+        let pair = TokenPair { token_a: token_a.clone(), token_b: token_b.clone() };
+        let mut observations = Vec::new(&env);
+        observations.push_back(PriceObservation { rate: 9000, timestamp: 999_900 });
+        env.storage().persistent().set(&ExchangeKey::PriceObservations(pair), &observations);
+    });
+
+    // Spot rate deviates > 5% from TWAP, so the router falls back
+    let rate = client.get_exchange_rate(&token_a, &token_b);
+    assert_eq!(rate.oracle_source, String::from_str(&env, "fallback"));
+    assert_eq!(client.get_price_observations(&token_a, &token_b).len(), 1);
+}
+
+#[test]
+fn test_reseeding_twap_unlocks_a_pair_stuck_on_stale_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let (client, contract_id, admin, token_a, token_b) = setup_router_with_oracle(&env);
+
+    env.as_contract(&contract_id, || {
+        let pair = TokenPair { token_a: token_a.clone(), token_b: token_b.clone() };
+        let mut observations = Vec::new(&env);
+        observations.push_back(PriceObservation { rate: 9000, timestamp: 999_900 });
+        env.storage().persistent().set(&ExchangeKey::PriceObservations(pair), &observations);
+    });
+    assert_eq!(client.get_exchange_rate(&token_a, &token_b).oracle_source, String::from_str(&env, "fallback"));
+
+    let operator = Address::generate(&env);
+    client.set_user_role(&admin, &operator, &UserRole::Operator);
+    assert!(client.try_reseed_twap_window(&operator, &token_a, &token_b, &10_100u64).is_err());
+    assert_eq!(
+        client.try_reseed_twap_window(&admin, &token_a, &token_b, &0u64),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    client.reseed_twap_window(&admin, &token_a, &token_b, &10_100u64);
+    let observations = client.get_price_observations(&token_a, &token_b);
+    assert_eq!(observations.len(), 1);
+    assert_eq!(observations.get(0).unwrap().rate, 10_100);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_010;
+    });
+    assert_eq!(client.get_exchange_rate(&token_a, &token_b).oracle_source, String::from_str(&env, "oracle"));
+}
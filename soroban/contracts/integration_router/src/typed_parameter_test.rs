@@ -0,0 +1,97 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as TestAddress, Address, Env};
+
+#[test]
+fn test_declared_parameters_are_validated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+
+    // A value stored before the declaration must fit the schema
+    let fee = String::from_str(&env, "withdrawal_fee");
+    client.set_system_parameter(&admin, &fee, &String::from_str(&env, "25"));
+    let fee_schema = ParamSchema {
+        name: fee.clone(),
+        param_type: ParamType::Bps,
+        min: 0,
+        max: 500,
+        description: String::from_str(&env, "Withdrawal fee"),
+    };
+    assert_eq!(
+        client.try_declare_parameter(&admin, &ParamSchema { max: 20_000, ..fee_schema.clone() }),
+        Err(Ok(IntegrationError::InvalidOperationState.into()))
+    );
+    assert_eq!(
+        client.try_declare_parameter(&admin, &ParamSchema { max: 10, ..fee_schema.clone() }),
+        Err(Ok(IntegrationError::InvalidOperationState.into()))
+    );
+    client.declare_parameter(&admin, &fee_schema);
+    assert_eq!(client.get_typed_parameter(&fee), Some(ParamValue::Bps(25)));
+
+    // String values are parsed and range checked
+    for bad in ["501", "12a", "", "99999999999999999999999"] {
+        assert_eq!(
+            client.try_set_system_parameter(&admin, &fee, &String::from_str(&env, bad)),
+            Err(Ok(IntegrationError::InvalidOperationState.into()))
+        );
+    }
+    client.set_system_parameter(&admin, &fee, &String::from_str(&env, "300"));
+    assert_eq!(client.get_typed_parameter(&fee), Some(ParamValue::Bps(300)));
+
+    // Typed values must match the declared type and keep the string form in sync
+    assert_eq!(
+        client.try_set_typed_parameter(&admin, &fee, &ParamValue::U64(100)),
+        Err(Ok(IntegrationError::InvalidOperationState.into()))
+    );
+    client.set_typed_parameter(&admin, &fee, &ParamValue::Bps(150));
+    assert_eq!(client.get_system_parameter(&fee), Some(String::from_str(&env, "150")));
+
+    let strict = String::from_str(&env, "strict_mode");
+    client.declare_parameter(&admin, &ParamSchema {
+        name: strict.clone(),
+        param_type: ParamType::Bool,
+        min: 0,
+        max: 0,
+        description: String::from_str(&env, "Reject unverified deposits"),
+    });
+    assert_eq!(
+        client.try_set_system_parameter(&admin, &strict, &String::from_str(&env, "yes")),
+        Err(Ok(IntegrationError::InvalidOperationState.into()))
+    );
+    client.set_system_parameter(&admin, &strict, &String::from_str(&env, "true"));
+    assert_eq!(client.get_typed_parameter(&strict), Some(ParamValue::Bool(true)));
+
+    let treasury = String::from_str(&env, "treasury");
+    client.declare_parameter(&admin, &ParamSchema {
+        name: treasury.clone(),
+        param_type: ParamType::Address,
+        min: 0,
+        max: 0,
+        description: String::from_str(&env, "Fee treasury"),
+    });
+    let treasury_address = Address::generate(&env);
+    client.set_typed_parameter(&admin, &treasury, &ParamValue::Address(treasury_address.clone()));
+    assert_eq!(client.get_system_parameter(&treasury), Some(treasury_address.to_string()));
+
+    // Undeclared parameters stay free-form
+    assert_eq!(
+        client.try_set_typed_parameter(&admin, &String::from_str(&env, "timeout"), &ParamValue::U64(1)),
+        Err(Ok(IntegrationError::ContractNotFound.into()))
+    );
+    client.set_system_parameter(&admin, &String::from_str(&env, "timeout"), &String::from_str(&env, "soon"));
+    assert_eq!(client.get_typed_parameter(&String::from_str(&env, "timeout")), None);
+    assert_eq!(client.list_parameter_schemas().len(), 3);
+}
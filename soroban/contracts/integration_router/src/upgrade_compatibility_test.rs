@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::Address as TestAddress,
+    Address, BytesN, Env
+};
+
+/// Reserve manager answering the upgrade health probe
+#[contract]
+pub struct MockCompatReserve;
+
+#[contractimpl]
+impl MockCompatReserve {
+    pub fn get_ratio(_env: Env) -> u64 {
+        10000
+    }
+}
+
+fn names(env: &Env, functions: &[&str]) -> Vec<String> {
+    let mut names = Vec::new(env);
+    for function in functions {
+        names.push_back(String::from_str(env, function));
+    }
+    names
+}
+
+#[test]
+fn test_upgrade_requires_attested_matching_wasm_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+    let reserve_v1 = env.register(MockCompatReserve, ());
+    let reserve_v2 = env.register(MockCompatReserve, ());
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &reserve_v1,
+    );
+
+    let reserve_name = String::from_str(&env, "reserve_manager");
+    let declared = BytesN::from_array(&env, &[1u8; 32]);
+    let upgrade_id = client.plan_contract_upgrade(&admin, &reserve_name, &reserve_v2, &declared);
+
+    let check = client.check_upgrade_compatibility(&upgrade_id);
+    assert!(!check.compatible);
+    assert_eq!(check.error_message, String::from_str(&env, "Deployed Wasm hash is not attested"));
+
+    // A different build was deployed at the new address
+    client.register_deployed_wasm(&admin, &reserve_v2, &BytesN::from_array(&env, &[2u8; 32]));
+    let result = client.execute_contract_upgrade(&admin, &upgrade_id);
+    assert!(!result.success);
+    assert_eq!(result.error_message, String::from_str(&env, "Deployed Wasm hash does not match compatibility hash"));
+    assert_eq!(client.get_upgrade_plan(&upgrade_id).unwrap().status, UpgradeStatus::Planned);
+    assert_eq!(client.get_contract_address(&reserve_name), Some(reserve_v1));
+
+    client.register_deployed_wasm(&admin, &reserve_v2, &declared);
+    assert_eq!(client.get_deployed_wasm(&reserve_v2), Some(declared));
+    assert!(client.check_upgrade_compatibility(&upgrade_id).compatible);
+    assert!(client.execute_contract_upgrade(&admin, &upgrade_id).success);
+    assert_eq!(client.get_contract_address(&reserve_name), Some(reserve_v2));
+}
+
+#[test]
+fn test_upgrade_blocked_until_wasm_exports_cover_manifest() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+    let reserve_v1 = env.register(MockCompatReserve, ());
+    let reserve_v2 = env.register(MockCompatReserve, ());
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &reserve_v1,
+    );
+
+    let reserve_name = String::from_str(&env, "reserve_manager");
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    client.register_deployed_wasm(&admin, &reserve_v2, &hash);
+    client.set_interface_manifest(&admin, &reserve_name, &names(&env, &["get_ratio", "reg_dep", "proc_wd"]));
+    assert_eq!(client.get_interface_manifest(&reserve_name).len(), 3);
+
+    let upgrade_id = client.plan_contract_upgrade(&admin, &reserve_name, &reserve_v2, &hash);
+
+    // No exports registered for the build, so every manifest entry is missing
+    let check = client.check_upgrade_compatibility(&upgrade_id);
+    assert!(!check.compatible);
+    assert_eq!(check.required_migrations, names(&env, &[
+        "missing_export:get_ratio",
+        "missing_export:reg_dep",
+        "missing_export:proc_wd",
+    ]));
+
+    client.register_wasm_exports(&admin, &hash, &names(&env, &["get_ratio", "reg_dep", "proc_dep"]));
+    let result = client.execute_contract_upgrade(&admin, &upgrade_id);
+    assert!(!result.success);
+    assert_eq!(result.error_message, String::from_str(&env, "New contract does not export the router interface"));
+    assert_eq!(
+        client.check_upgrade_compatibility(&upgrade_id).required_migrations,
+        names(&env, &["missing_export:proc_wd"])
+    );
+
+    client.register_wasm_exports(&admin, &hash, &names(&env, &["get_ratio", "reg_dep", "proc_dep", "proc_wd"]));
+    assert!(client.execute_contract_upgrade(&admin, &upgrade_id).success);
+
+    // Clearing the manifest disables the interface check
+    client.set_interface_manifest(&admin, &reserve_name, &Vec::new(&env));
+    assert_eq!(client.get_interface_manifest(&reserve_name).len(), 0);
+
+    assert_eq!(
+        client.try_check_upgrade_compatibility(&BytesN::from_array(&env, &[9u8; 32])),
+        Err(Ok(IntegrationError::InvalidOperationState.into()))
+    );
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::Address as TestAddress,
+    Address, BytesN, Env
+};
+
+/// Reserve manager answering the upgrade health probe
+#[contract]
+pub struct MockUpgradeReserve;
+
+#[contractimpl]
+impl MockUpgradeReserve {
+    pub fn get_ratio(_env: Env) -> u64 {
+        10000
+    }
+}
+
+#[test]
+fn test_upgrade_plans_are_indexed_by_status_and_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+    let reserve_v1 = env.register(MockUpgradeReserve, ());
+    let reserve_v2 = env.register(MockUpgradeReserve, ());
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &reserve_v1,
+    );
+
+    let reserve_name = String::from_str(&env, "reserve_manager");
+    let kyc_name = String::from_str(&env, "kyc_registry");
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+    client.register_deployed_wasm(&admin, &reserve_v2, &hash);
+
+    let executed = client.plan_contract_upgrade(&admin, &reserve_name, &reserve_v2, &hash);
+    assert!(client.execute_contract_upgrade(&admin, &executed).success);
+
+    let rolled_back = client.plan_contract_upgrade(&admin, &reserve_name, &Address::generate(&env), &hash);
+    assert!(client.cancel_upgrade_plan(&admin, &rolled_back));
+    assert_eq!(client.list_upgrade_plans(&admin, &Some(UpgradeStatus::Failed), &0, &10).len(), 1);
+    assert!(client.rollback_contract_upgrade(&admin, &rolled_back));
+
+    let planned = client.plan_contract_upgrade(&admin, &kyc_name, &Address::generate(&env), &hash);
+
+    let ids = |plans: Vec<UpgradePlan>| {
+        let mut ids = Vec::new(&env);
+        for plan in plans.iter() {
+            ids.push_back(plan.upgrade_id);
+        }
+        ids
+    };
+    assert_eq!(ids(client.list_upgrade_plans(&admin, &None, &0, &10)), vec![&env, executed.clone(), rolled_back.clone(), planned.clone()]);
+    assert_eq!(ids(client.list_upgrade_plans(&admin, &None, &1, &1)), vec![&env, rolled_back.clone()]);
+    assert_eq!(client.list_upgrade_plans(&admin, &None, &5, &10).len(), 0);
+    assert_eq!(ids(client.list_upgrade_plans(&admin, &Some(UpgradeStatus::Planned), &0, &10)), vec![&env, planned]);
+    assert_eq!(ids(client.list_upgrade_plans(&admin, &Some(UpgradeStatus::Completed), &0, &10)), vec![&env, executed.clone()]);
+    assert_eq!(ids(client.list_upgrade_plans(&admin, &Some(UpgradeStatus::RolledBack), &0, &10)), vec![&env, rolled_back.clone()]);
+    assert_eq!(client.list_upgrade_plans(&admin, &Some(UpgradeStatus::Failed), &0, &10).len(), 0);
+    assert_eq!(client.list_upgrade_plans(&admin, &Some(UpgradeStatus::InProgress), &0, &10).len(), 0);
+
+    // Lineage of the reserve manager: v1 -> v2, then a rolled back attempt
+    let history = client.get_upgrade_history(&reserve_name);
+    assert_eq!(ids(history.clone()), vec![&env, executed, rolled_back]);
+    assert_eq!(history.get(0).unwrap().old_address, reserve_v1);
+    assert_eq!(history.get(1).unwrap().old_address, reserve_v2);
+    assert_eq!(client.get_contract_address(&reserve_name), Some(reserve_v2));
+}
@@ -1,455 +0,0 @@
-#[cfg(test)]
-mod upgrade_tests {
-    use super::*;
-    use soroban_sdk::{
-        testutils::{Address as TestAddress, Ledger, LedgerInfo},
-        Address, Env, String as SorobanString, Vec as SorobanVec, BytesN,
-    };
-
-    fn create_test_env() -> Env {
-        Env::default()
-    }
-
-    fn setup_test_contracts(env: &Env) -> (Address, Address, Address, Address, Address, Address) {
-        let admin = Address::generate(env);
-        let kyc_registry = Address::generate(env);
-        let reserve_manager = Address::generate(env);
-        let fungible_token = Address::generate(env);
-        let istsi_token = Address::generate(env);
-        let integration_router = env.register_contract(None, IntegrationRouter);
-
-        (admin, kyc_registry, reserve_manager, fungible_token, istsi_token, integration_router)
-    }
-
-    #[test]
-    fn test_plan_contract_upgrade() {
-        let env = create_test_env();
-        let (admin, kyc_registry, reserve_manager, fungible_token, istsi_token, integration_router) = 
-            setup_test_contracts(&env);
-
-        let client = IntegrationRouterClient::new(&env, &integration_router);
-
-        // Initialize
-        client.initialize(
-            &admin,
-            &kyc_registry,
-            &istsi_token,
-            &fungible_token,
-            &reserve_manager,
-        );
-
-        // Plan an upgrade
-        let new_kyc_address = Address::generate(&env);
-        let compatibility_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let contract_name = SorobanString::from_str(&env, "kyc_registry");
-
-        let upgrade_id = client.plan_contract_upgrade(
-            &admin,
-            &contract_name,
-            &new_kyc_address,
-            &compatibility_hash
-        );
-
-        // Verify upgrade plan
-        let upgrade_plan = client.get_upgrade_plan(&upgrade_id);
-        assert!(upgrade_plan.is_some());
-        
-        let plan = upgrade_plan.unwrap();
-        assert_eq!(plan.contract_name, contract_name);
-        assert_eq!(plan.new_address, new_kyc_address);
-        assert_eq!(plan.old_address, kyc_registry);
-        assert_eq!(plan.status, UpgradeStatus::Planned);
-        assert_eq!(plan.compatibility_hash, compatibility_hash);
-    }
-
-    #[test]
-    fn test_execute_contract_upgrade() {
-        let env = create_test_env();
-        let (admin, kyc_registry, reserve_manager, fungible_token, istsi_token, integration_router) = 
-            setup_test_contracts(&env);
-
-        let client = IntegrationRouterClient::new(&env, &integration_router);
-
-        // Initialize
-        client.initialize(
-            &admin,
-            &kyc_registry,
-            &istsi_token,
-            &fungible_token,
-            &reserve_manager,
-        );
-
-        // Plan an upgrade
-        let new_kyc_address = Address::generate(&env);
-        let compatibility_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let contract_name = SorobanString::from_str(&env, "kyc_registry");
-
-        let upgrade_id = client.plan_contract_upgrade(
-            &admin,
-            &contract_name,
-            &new_kyc_address,
-            &compatibility_hash
-        );
-
-        // Execute the upgrade
-        let result = client.execute_contract_upgrade(&admin, &upgrade_id);
-
-        // Verify upgrade result
-        // Note: In this test environment, the upgrade will likely fail due to 
-        // contract health checks, but we can verify the process works
-        assert_eq!(result.upgrade_id, upgrade_id);
-
-        // Verify contract address was updated (if upgrade succeeded)
-        if result.success {
-            let updated_address = client.get_contract_address(&contract_name);
-            assert_eq!(updated_address, Some(new_kyc_address));
-        }
-    }
-
-    #[test]
-    fn test_rollback_contract_upgrade() {
-        let env = create_test_env();
-        let (admin, kyc_registry, reserve_manager, fungible_token, istsi_token, integration_router) = 
-            setup_test_contracts(&env);
-
-        let client = IntegrationRouterClient::new(&env, &integration_router);
-
-        // Initialize
-        client.initialize(
-            &admin,
-            &kyc_registry,
-            &istsi_token,
-            &fungible_token,
-            &reserve_manager,
-        );
-
-        // Plan and execute an upgrade that will fail
-        let new_kyc_address = Address::generate(&env);
-        let compatibility_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let contract_name = SorobanString::from_str(&env, "kyc_registry");
-
-        let upgrade_id = client.plan_contract_upgrade(
-            &admin,
-            &contract_name,
-            &new_kyc_address,
-            &compatibility_hash
-        );
-
-        let result = client.execute_contract_upgrade(&admin, &upgrade_id);
-
-        // If upgrade failed, test rollback
-        if !result.success && result.rollback_required {
-            // Manually set upgrade status to Failed for testing
-            // In a real scenario, this would be set by the failed upgrade
-            
-            let rollback_success = client.rollback_contract_upgrade(&admin, &upgrade_id);
-            assert_eq!(rollback_success, true);
-
-            // Verify original address is restored
-            let current_address = client.get_contract_address(&contract_name);
-            assert_eq!(current_address, Some(kyc_registry));
-        }
-    }
-
-    #[test]
-    fn test_cancel_upgrade_plan() {
-        let env = create_test_env();
-        let (admin, kyc_registry, reserve_manager, fungible_token, istsi_token, integration_router) = 
-            setup_test_contracts(&env);
-
-        let client = IntegrationRouterClient::new(&env, &integration_router);
-
-        // Initialize
-        client.initialize(
-            &admin,
-            &kyc_registry,
-            &istsi_token,
-            &fungible_token,
-            &reserve_manager,
-        );
-
-        // Plan an upgrade
-        let new_kyc_address = Address::generate(&env);
-        let compatibility_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let contract_name = SorobanString::from_str(&env, "kyc_registry");
-
-        let upgrade_id = client.plan_contract_upgrade(
-            &admin,
-            &contract_name,
-            &new_kyc_address,
-            &compatibility_hash
-        );
-
-        // Cancel the upgrade
-        let cancel_success = client.cancel_upgrade_plan(&admin, &upgrade_id);
-        assert_eq!(cancel_success, true);
-
-        // Verify upgrade plan status
-        let upgrade_plan = client.get_upgrade_plan(&upgrade_id);
-        assert!(upgrade_plan.is_some());
-        
-        let plan = upgrade_plan.unwrap();
-        assert_eq!(plan.status, UpgradeStatus::Failed); // Cancelled upgrades use Failed status
-    }
-
-    #[test]
-    fn test_batch_contract_upgrade() {
-        let env = create_test_env();
-        let (admin, kyc_registry, reserve_manager, fungible_token, istsi_token, integration_router) = 
-            setup_test_contracts(&env);
-
-        let client = IntegrationRouterClient::new(&env, &integration_router);
-
-        // Initialize
-        client.initialize(
-            &admin,
-            &kyc_registry,
-            &istsi_token,
-            &fungible_token,
-            &reserve_manager,
-        );
-
-        // Prepare batch upgrade
-        let new_kyc_address = Address::generate(&env);
-        let new_reserve_address = Address::generate(&env);
-        let compatibility_hash = BytesN::from_array(&env, &[1u8; 32]);
-
-        let mut upgrades = SorobanVec::new(&env);
-        upgrades.push_back((
-            SorobanString::from_str(&env, "kyc_registry"),
-            new_kyc_address.clone(),
-            compatibility_hash.clone()
-        ));
-        upgrades.push_back((
-            SorobanString::from_str(&env, "reserve_manager"),
-            new_reserve_address.clone(),
-            compatibility_hash.clone()
-        ));
-
-        // Execute batch upgrade planning
-        let upgrade_ids = client.batch_contract_upgrade(&admin, &upgrades);
-        assert_eq!(upgrade_ids.len(), 2);
-
-        // Verify both upgrade plans were created
-        for upgrade_id in upgrade_ids.iter() {
-            let plan = client.get_upgrade_plan(&upgrade_id);
-            assert!(plan.is_some());
-            assert_eq!(plan.unwrap().status, UpgradeStatus::Planned);
-        }
-    }
-
-    #[test]
-    fn test_execute_batch_upgrade() {
-        let env = create_test_env();
-        let (admin, kyc_registry, reserve_manager, fungible_token, istsi_token, integration_router) = 
-            setup_test_contracts(&env);
-
-        let client = IntegrationRouterClient::new(&env, &integration_router);
-
-        // Initialize
-        client.initialize(
-            &admin,
-            &kyc_registry,
-            &istsi_token,
-            &fungible_token,
-            &reserve_manager,
-        );
-
-        // Plan upgrades
-        let new_kyc_address = Address::generate(&env);
-        let compatibility_hash = BytesN::from_array(&env, &[1u8; 32]);
-
-        let upgrade_id = client.plan_contract_upgrade(
-            &admin,
-            &SorobanString::from_str(&env, "kyc_registry"),
-            &new_kyc_address,
-            &compatibility_hash
-        );
-
-        let mut upgrade_ids = SorobanVec::new(&env);
-        upgrade_ids.push_back(upgrade_id.clone());
-
-        // Execute batch upgrade
-        let results = client.execute_batch_upgrade(&admin, &upgrade_ids);
-        assert_eq!(results.len(), 1);
-
-        let result = results.get(0).unwrap();
-        assert_eq!(result.upgrade_id, upgrade_id);
-    }
-
-    #[test]
-    fn test_unauthorized_upgrade_operations() {
-        let env = create_test_env();
-        let (admin, kyc_registry, reserve_manager, fungible_token, istsi_token, integration_router) = 
-            setup_test_contracts(&env);
-
-        let client = IntegrationRouterClient::new(&env, &integration_router);
-        let unauthorized_user = Address::generate(&env);
-
-        // Initialize
-        client.initialize(
-            &admin,
-            &kyc_registry,
-            &istsi_token,
-            &fungible_token,
-            &reserve_manager,
-        );
-
-        let new_address = Address::generate(&env);
-        let compatibility_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let contract_name = SorobanString::from_str(&env, "kyc_registry");
-
-        // Test unauthorized upgrade planning
-        let result = std::panic::catch_unwind(|| {
-            client.plan_contract_upgrade(
-                &unauthorized_user,
-                &contract_name,
-                &new_address,
-                &compatibility_hash
-            );
-        });
-        assert!(result.is_err());
-
-        // Plan an upgrade as admin first
-        let upgrade_id = client.plan_contract_upgrade(
-            &admin,
-            &contract_name,
-            &new_address,
-            &compatibility_hash
-        );
-
-        // Test unauthorized upgrade execution
-        let result = std::panic::catch_unwind(|| {
-            client.execute_contract_upgrade(&unauthorized_user, &upgrade_id);
-        });
-        assert!(result.is_err());
-
-        // Test unauthorized upgrade cancellation
-        let result = std::panic::catch_unwind(|| {
-            client.cancel_upgrade_plan(&unauthorized_user, &upgrade_id);
-        });
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_upgrade_compatibility_validation() {
-        let env = create_test_env();
-        let (admin, kyc_registry, reserve_manager, fungible_token, istsi_token, integration_router) = 
-            setup_test_contracts(&env);
-
-        let client = IntegrationRouterClient::new(&env, &integration_router);
-
-        // Initialize
-        client.initialize(
-            &admin,
-            &kyc_registry,
-            &istsi_token,
-            &fungible_token,
-            &reserve_manager,
-        );
-
-        // Test with a non-responsive contract address (should fail compatibility)
-        let invalid_address = Address::generate(&env); // This won't be a real contract
-        let compatibility_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let contract_name = SorobanString::from_str(&env, "kyc_registry");
-
-        let upgrade_id = client.plan_contract_upgrade(
-            &admin,
-            &contract_name,
-            &invalid_address,
-            &compatibility_hash
-        );
-
-        let result = client.execute_contract_upgrade(&admin, &upgrade_id);
-        
-        // Should fail due to compatibility issues
-        assert_eq!(result.success, false);
-        assert!(result.error_message.len() > 0);
-    }
-
-    #[test]
-    fn test_upgrade_events() {
-        let env = create_test_env();
-        let (admin, kyc_registry, reserve_manager, fungible_token, istsi_token, integration_router) = 
-            setup_test_contracts(&env);
-
-        let client = IntegrationRouterClient::new(&env, &integration_router);
-
-        // Initialize
-        client.initialize(
-            &admin,
-            &kyc_registry,
-            &istsi_token,
-            &fungible_token,
-            &reserve_manager,
-        );
-
-        // Plan an upgrade
-        let new_address = Address::generate(&env);
-        let compatibility_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let contract_name = SorobanString::from_str(&env, "kyc_registry");
-
-        let upgrade_id = client.plan_contract_upgrade(
-            &admin,
-            &contract_name,
-            &new_address,
-            &compatibility_hash
-        );
-
-        // Check that events were emitted
-        let events = env.events().all();
-        assert!(events.len() > 0);
-
-        // Execute upgrade (will likely fail in test environment)
-        client.execute_contract_upgrade(&admin, &upgrade_id);
-
-        // Check for additional events
-        let events_after = env.events().all();
-        assert!(events_after.len() > events.len());
-    }
-
-    #[test]
-    fn test_upgrade_state_persistence() {
-        let env = create_test_env();
-        let (admin, kyc_registry, reserve_manager, fungible_token, istsi_token, integration_router) = 
-            setup_test_contracts(&env);
-
-        let client = IntegrationRouterClient::new(&env, &integration_router);
-
-        // Initialize
-        client.initialize(
-            &admin,
-            &kyc_registry,
-            &istsi_token,
-            &fungible_token,
-            &reserve_manager,
-        );
-
-        // Plan an upgrade
-        let new_address = Address::generate(&env);
-        let compatibility_hash = BytesN::from_array(&env, &[1u8; 32]);
-        let contract_name = SorobanString::from_str(&env, "kyc_registry");
-
-        let upgrade_id = client.plan_contract_upgrade(
-            &admin,
-            &contract_name,
-            &new_address,
-            &compatibility_hash
-        );
-
-        // Simulate ledger advancement
-        env.ledger().with_mut(|li| {
-            li.sequence_number += 100;
-            li.timestamp += 3600; // 1 hour later
-        });
-
-        // Verify upgrade plan persists
-        let upgrade_plan = client.get_upgrade_plan(&upgrade_id);
-        assert!(upgrade_plan.is_some());
-        
-        let plan = upgrade_plan.unwrap();
-        assert_eq!(plan.contract_name, contract_name);
-        assert_eq!(plan.new_address, new_address);
-        assert_eq!(plan.status, UpgradeStatus::Planned);
-    }
-}
\ No newline at end of file
@@ -0,0 +1,75 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as TestAddress, Address, BytesN, Env};
+
+/// Minimal KYC registry approving every deposit
+#[contract]
+pub struct MockOperationsKycRegistry;
+
+#[contractimpl]
+impl MockOperationsKycRegistry {
+    pub fn verify_ic(_env: Env, _user: String, _operation: String, _amount: String) -> bool {
+        true
+    }
+
+    pub fn reg_event(_env: Env, _user: String, _event: String, _amount: String, _a: String, _b: String) -> bool {
+        true
+    }
+
+    pub fn is_blacklisted(_env: Env, _address: Address) -> bool {
+        false
+    }
+}
+
+/// Under-collateralized reserve manager, so deposits fail after being indexed
+#[contract]
+pub struct MockOperationsReserve;
+
+#[contractimpl]
+impl MockOperationsReserve {
+    pub fn get_ratio(_env: Env) -> u64 {
+        5000
+    }
+}
+
+#[test]
+fn test_user_operations_are_indexed_and_paginated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let other_user = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &env.register(MockOperationsKycRegistry, ()),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &env.register(MockOperationsReserve, ()),
+    );
+
+    assert_eq!(client.get_user_operation_count(&user), 0);
+
+    let first = client.execute_btc_deposit_tracked(&admin, &user, &100_000u64, &BytesN::from_array(&env, &[1u8; 32]), &6u32);
+    let second = client.execute_btc_deposit_tracked(&admin, &user, &200_000u64, &BytesN::from_array(&env, &[2u8; 32]), &6u32);
+    client.execute_btc_deposit_tracked(&admin, &other_user, &300_000u64, &BytesN::from_array(&env, &[3u8; 32]), &6u32);
+
+    assert_eq!(client.get_user_operation_count(&user), 2);
+
+    let operations = client.get_user_operations(&user, &0u32, &10u32);
+    assert_eq!(operations.len(), 2);
+    assert_eq!(operations.get(0).unwrap().operation_id, first);
+    assert_eq!(operations.get(0).unwrap().amount, 100_000);
+    assert_eq!(operations.get(0).unwrap().operation_type, String::from_str(&env, "bitcoin_deposit"));
+
+    let page = client.get_user_operations(&user, &1u32, &10u32);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().operation_id, second);
+
+    assert_eq!(client.get_user_operations(&user, &2u32, &10u32).len(), 0);
+}
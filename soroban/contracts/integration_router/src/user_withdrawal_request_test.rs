@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::{Address as TestAddress, Ledger}, Address, Env};
+
+fn btc_address(env: &Env) -> String {
+    String::from_str(env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh")
+}
+
+#[test]
+fn test_user_requests_with_own_auth_and_limits_open_requests() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+
+    let request_id = h.router.user_request_withdrawal(&user, &10_000_000, &btc_address(&env));
+    // Authorized by the user alone, without any role
+    assert_eq!(env.auths()[0].0, user);
+    assert_eq!(h.router.get_user_role(&user), UserRole::User);
+
+    let request = h.router.get_withdrawal_request(&request_id).unwrap();
+    assert_eq!(request.user, user);
+    assert_eq!(request.status, WithdrawalRequestStatus::Pending);
+    assert_eq!(request.withdrawal_id, None);
+    assert_eq!(request.requested_at, HARNESS_START_TIME);
+    assert_eq!(h.router.get_pending_withdrawal_requests(), vec![&env, request.clone()]);
+    assert_eq!(h.router.get_user_withdrawal_requests(&user, &0, &10), vec![&env, request]);
+
+    assert_eq!(
+        h.router.try_user_request_withdrawal(&user, &0, &btc_address(&env)),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert_eq!(
+        h.router.try_user_request_withdrawal(&user, &10_000_000, &String::from_str(&env, "")),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    for _ in 0..4 {
+        h.router.user_request_withdrawal(&user, &10_000_000, &btc_address(&env));
+    }
+    assert_eq!(
+        h.router.try_user_request_withdrawal(&user, &10_000_000, &btc_address(&env)),
+        Err(Ok(IntegrationError::RateLimited))
+    );
+
+    // Closing a request frees a slot
+    h.router.cancel_withdrawal_request(&user, &request_id);
+    h.router.user_request_withdrawal(&user, &10_000_000, &btc_address(&env));
+    assert_eq!(h.router.get_user_withdrawal_requests(&user, &0, &10).len(), 6);
+    assert_eq!(h.router.get_user_withdrawal_requests(&user, &4, &10).len(), 2);
+    assert_eq!(h.router.get_pending_withdrawal_requests().len(), 5);
+}
+
+#[test]
+fn test_requests_are_cancelled_by_owner_and_rejected_or_fulfilled_by_operators() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    let other = Address::generate(&env);
+
+    let first = h.router.user_request_withdrawal(&user, &10_000_000, &btc_address(&env));
+    let second = h.router.user_request_withdrawal(&user, &20_000_000, &btc_address(&env));
+
+    assert_eq!(
+        h.router.try_cancel_withdrawal_request(&other, &first),
+        Err(Ok(IntegrationError::Unauthorized))
+    );
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + 60);
+    h.router.cancel_withdrawal_request(&user, &first);
+    let cancelled = h.router.get_withdrawal_request(&first).unwrap();
+    assert_eq!(cancelled.status, WithdrawalRequestStatus::Cancelled);
+    assert_eq!(cancelled.updated_at, HARNESS_START_TIME + 60);
+    assert_eq!(
+        h.router.try_cancel_withdrawal_request(&user, &first),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    assert_eq!(
+        h.router.try_fulfill_withdrawal_request(&h.operator, &first),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    // Fulfillment runs the operator workflow, so users cannot fulfill their own requests
+    assert!(h.router.try_fulfill_withdrawal_request(&user, &second).is_err());
+    assert!(h.router.try_reject_withdrawal_request(&user, &second, &String::from_str(&env, "no")).is_err());
+    assert_eq!(h.router.get_withdrawal_request(&second).unwrap().status, WithdrawalRequestStatus::Pending);
+
+    let reason = String::from_str(&env, "Destination address failed screening");
+    h.router.reject_withdrawal_request(&h.operator, &second, &reason);
+    let rejected = h.router.get_withdrawal_request(&second).unwrap();
+    assert_eq!(rejected.status, WithdrawalRequestStatus::Rejected);
+    assert_eq!(rejected.handled_by, Some(h.operator.clone()));
+    assert_eq!(rejected.reason, reason);
+    assert_eq!(h.router.get_pending_withdrawal_requests().len(), 0);
+    assert_eq!(
+        h.router.try_cancel_withdrawal_request(&user, &second),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
@@ -0,0 +1,150 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as TestAddress, Ledger},
+    Address, BytesN, Env
+};
+
+/// KYC registry that screens every address as not blacklisted
+#[contract]
+pub struct MockVelocityKycRegistry;
+
+#[contractimpl]
+impl MockVelocityKycRegistry {
+    pub fn is_blacklisted(_env: Env, _address: Address) -> bool {
+        false
+    }
+}
+
+fn setup(env: &Env) -> (Address, IntegrationRouterClient, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_000_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &env.register(MockVelocityKycRegistry, ()),
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+
+    (contract_id, client, admin)
+}
+
+fn record(env: &Env, contract_id: &Address, user: &Address, amount: u64) {
+    env.as_contract(contract_id, || {
+        IntegrationRouter::check_operation_velocity(env, user, VelocityOperation::Deposit, amount);
+    });
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += seconds;
+    });
+}
+
+#[test]
+fn test_volume_spike_places_hold() {
+    let env = Env::default();
+    let (contract_id, client, admin) = setup(&env);
+    let user = Address::generate(&env);
+
+    // Detection is off until an admin enables it
+    let mut config = client.get_velocity_config();
+    assert!(!config.enabled);
+    record(&env, &contract_id, &user, 1_000_000);
+    assert!(client.get_user_velocity(&user, &VelocityOperation::Deposit).is_none());
+    config.enabled = true;
+    client.set_velocity_config(&admin, &config);
+
+    // Build a baseline of 1_000 per hour
+    for _ in 0..4 {
+        record(&env, &contract_id, &user, 1_000);
+        advance(&env, 3600);
+    }
+    let velocity = client.get_user_velocity(&user, &VelocityOperation::Deposit).unwrap();
+    assert_eq!(velocity.baseline_volume, 1_000);
+    assert!(client.get_operation_hold(&user).is_none());
+
+    // Skip the overlap with the previous window, then spike to 20x
+    advance(&env, 3599);
+    record(&env, &contract_id, &user, 20_000);
+
+    let hold = client.get_operation_hold(&user).unwrap();
+    assert_eq!(hold.reason, String::from_str(&env, "volume_spike"));
+    assert_eq!(hold.operation, VelocityOperation::Deposit);
+    assert_eq!(client.get_operation_holds().len(), 1);
+
+    // Further operations are rejected while the hold is active
+    let deposit = client.try_execute_bitcoin_deposit(
+        &admin,
+        &user,
+        &1_000u64,
+        &BytesN::from_array(&env, &[8u8; 32]),
+        &6u32,
+    );
+    assert_eq!(deposit, Err(Ok(IntegrationError::ComplianceCheckFailed.into())));
+
+    // Operators cannot release holds; compliance officers can
+    let operator = Address::generate(&env);
+    client.set_user_role(&admin, &operator, &UserRole::Operator);
+    let notes = String::from_str(&env, "Customer confirmed treasury move");
+    assert!(client.try_release_operation_hold(&operator, &user, &notes).is_err());
+
+    let officer = Address::generate(&env);
+    client.set_user_role(&admin, &officer, &UserRole::ComplianceOfficer);
+    client.release_operation_hold(&officer, &user, &notes);
+    assert!(client.get_operation_hold(&user).is_none());
+    assert_eq!(client.get_operation_holds().len(), 0);
+    assert_eq!(client.get_user_velocity(&user, &VelocityOperation::Deposit).unwrap().volume, 0);
+    assert_eq!(
+        client.try_release_operation_hold(&officer, &user, &notes),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
+
+#[test]
+fn test_frequency_spike_and_disabled_detection() {
+    let env = Env::default();
+    let (contract_id, client, admin) = setup(&env);
+    let user = Address::generate(&env);
+
+    client.set_velocity_config(&admin, &VelocityConfig {
+        enabled: true,
+        window_seconds: 600,
+        volume_multiplier: 10,
+        max_operations_per_window: 3,
+        baseline_windows: 6,
+        min_baseline_windows: 3,
+    });
+
+    for _ in 0..3 {
+        record(&env, &contract_id, &user, 10);
+    }
+    assert!(client.get_operation_hold(&user).is_none());
+    record(&env, &contract_id, &user, 10);
+    assert_eq!(
+        client.get_operation_hold(&user).unwrap().reason,
+        String::from_str(&env, "frequency_spike")
+    );
+
+    // With detection disabled, nothing is tracked for other users
+    let mut config = client.get_velocity_config();
+    config.enabled = false;
+    client.set_velocity_config(&admin, &config);
+
+    let other = Address::generate(&env);
+    for _ in 0..10 {
+        record(&env, &contract_id, &other, 10);
+    }
+    assert!(client.get_operation_hold(&other).is_none());
+    assert!(client.get_user_velocity(&other, &VelocityOperation::Deposit).is_none());
+}
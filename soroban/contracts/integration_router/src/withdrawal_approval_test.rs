@@ -0,0 +1,221 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::{Address as TestAddress, Ledger, MockAuth, MockAuthInvoke}, Address, Env, IntoVal};
+
+const WINDOW: u64 = 3_600;
+
+/// Seed a withdrawal that has been burned and parked for approval by `initiator`
+fn seed_held_withdrawal(h: &TestHarness, initiator: &Address) -> WithdrawalApproval {
+    let env = &h.env;
+    let approval = WithdrawalApproval {
+        withdrawal_id: BytesN::from_array(env, &[7u8; 32]),
+        operation_id: BytesN::from_array(env, &[8u8; 32]),
+        initiator: initiator.clone(),
+        user: Address::generate(env),
+        istsi_amount: 50_000_000_000,
+        net_istsi_amount: 50_000_000_000,
+        withdrawal_fee: 0,
+        btc_amount: 500_000_000,
+        btc_address: String::from_str(env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh"),
+        correlation_id: BytesN::from_array(env, &[9u8; 32]),
+        requested_at: HARNESS_START_TIME,
+        expires_at: 0,
+    };
+
+    env.as_contract(&h.router.address, || {
+        let mut tracker = OperationTracker {
+            operation_id: approval.operation_id.clone(),
+            operation_type: String::from_str(env, "token_withdrawal"),
+            status: OperationStatus::InProgress,
+            created_at: HARNESS_START_TIME,
+            updated_at: HARNESS_START_TIME,
+            timeout_at: HARNESS_START_TIME + 60,
+            retry_count: 0,
+            error_message: String::from_str(env, ""),
+        };
+        IntegrationRouter::list_operation(env, &approval.operation_id, OperationList::Pending);
+        IntegrationRouter::initialize_withdrawal_status(
+            env, &approval.withdrawal_id, &approval.user, approval.istsi_amount, &approval.btc_address, &approval.operation_id, BtcFeeTier::Standard
+        );
+        IntegrationRouter::hold_withdrawal_for_approval(env, approval.clone(), &mut tracker);
+    });
+
+    h.router.get_withdrawal_approval(&approval.withdrawal_id).unwrap()
+}
+
+#[test]
+fn test_approval_config_requires_admin_and_a_window() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+
+    assert_eq!(h.router.get_withdrawal_approval_config(), None);
+    let config = WithdrawalApprovalConfig { threshold_sats: 100_000_000, approval_window: WINDOW };
+    assert!(h.router.try_set_withdrawal_approval_config(&h.operator, &config).is_err());
+    assert_eq!(
+        h.router.try_set_withdrawal_approval_config(
+            &h.admin,
+            &WithdrawalApprovalConfig { threshold_sats: 100_000_000, approval_window: 0 }
+        ),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    h.router.set_withdrawal_approval_config(&h.admin, &config);
+    assert_eq!(h.router.get_withdrawal_approval_config(), Some(config));
+}
+
+#[test]
+fn test_held_withdrawal_needs_a_distinct_approver_within_the_window() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    h.router.set_withdrawal_approval_config(
+        &h.admin,
+        &WithdrawalApprovalConfig { threshold_sats: 100_000_000, approval_window: WINDOW }
+    );
+    let approver = Address::generate(&env);
+    h.router.set_user_role(&h.admin, &approver, &UserRole::WithdrawalApprover);
+
+    let approval = seed_held_withdrawal(&h, &h.admin);
+    assert_eq!(approval.expires_at, HARNESS_START_TIME + WINDOW);
+    assert_eq!(h.router.get_pending_withdrawal_approvals(), vec![&env, approval.clone()]);
+    let status = h.router.get_withdrawal_status(&approval.withdrawal_id).unwrap();
+    assert_eq!(status.status, WithdrawalProcessingStatus::ApprovalPending);
+    // The sweeper leaves the operation alone until the window closes
+    assert_eq!(h.router.get_operation_status(&approval.operation_id).unwrap().timeout_at, approval.expires_at);
+
+    // Operators cannot approve, and neither can the initiator despite outranking the approver role
+    assert_eq!(
+        h.router.try_approve_withdrawal(&h.operator, &approval.withdrawal_id),
+        Err(Ok(IntegrationError::InsufficientPermissions))
+    );
+    assert_eq!(
+        h.router.try_approve_withdrawal(&h.admin, &approval.withdrawal_id),
+        Err(Ok(IntegrationError::InsufficientPermissions))
+    );
+    assert_eq!(
+        h.router.try_approve_withdrawal(&approver, &BytesN::from_array(&env, &[1u8; 32])),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    // Nothing expires early
+    assert_eq!(h.router.expire_withdrawal_approvals(&h.operator, &10), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = approval.expires_at);
+    assert_eq!(
+        h.router.try_approve_withdrawal(&approver, &approval.withdrawal_id),
+        Err(Ok(IntegrationError::OperationTimeout))
+    );
+
+    // Expiry cancels the withdrawal and retires its operation
+    assert_eq!(h.router.expire_withdrawal_approvals(&h.operator, &10), 1);
+    let status = h.router.get_withdrawal_status(&approval.withdrawal_id).unwrap();
+    assert_eq!(status.status, WithdrawalProcessingStatus::ApprovalExpired);
+    assert_eq!(h.router.get_withdrawal_approval(&approval.withdrawal_id), None);
+    assert_eq!(h.router.get_pending_withdrawal_approvals().len(), 0);
+    let tracker = h.router.get_operation_status(&approval.operation_id).unwrap();
+    assert_eq!(tracker.status, OperationStatus::RolledBack);
+    assert_eq!(h.router.expire_withdrawal_approvals(&h.operator, &10), 0);
+}
+
+#[test]
+fn test_approval_signature_is_bound_to_the_held_payout() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    h.router.set_withdrawal_approval_config(
+        &h.admin,
+        &WithdrawalApprovalConfig { threshold_sats: 100_000_000, approval_window: WINDOW }
+    );
+    let approver = Address::generate(&env);
+    h.router.set_user_role(&h.admin, &approver, &UserRole::WithdrawalApprover);
+    let approval = seed_held_withdrawal(&h, &h.admin);
+    let signed_args: soroban_sdk::Vec<Val> = (
+        approval.withdrawal_id.clone(), approval.user.clone(), approval.btc_amount, approval.btc_address.clone()
+    ).into_val(&env);
+
+    // The held record's payout address changes after the approver signed
+    env.as_contract(&h.router.address, || {
+        let mut changed = approval.clone();
+        changed.btc_address = String::from_str(&env, "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq");
+        env.storage().persistent().set(&WorkflowKey::WithdrawalApproval(approval.withdrawal_id.clone()), &changed);
+    });
+
+    env.mock_auths(&[MockAuth {
+        address: &approver,
+        invoke: &MockAuthInvoke {
+            contract: &h.router.address,
+            fn_name: "approve_withdrawal",
+            args: signed_args,
+            sub_invokes: &[],
+        },
+    }]);
+    // Rejected by the host's authorization check rather than with a contract error
+    assert!(matches!(h.router.try_approve_withdrawal(&approver, &approval.withdrawal_id), Err(Err(_))));
+    assert!(h.router.get_withdrawal_approval(&approval.withdrawal_id).is_some());
+}
+
+#[test]
+fn test_failed_remint_keeps_the_approval_open_for_a_retry() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    h.router.set_withdrawal_approval_config(
+        &h.admin,
+        &WithdrawalApprovalConfig { threshold_sats: 100_000_000, approval_window: WINDOW }
+    );
+    let approval = seed_held_withdrawal(&h, &h.admin);
+    env.ledger().with_mut(|li| li.timestamp = approval.expires_at);
+
+    // The token refuses the re-mint, so the burned amount must stay claimable
+    h.istsi.set_failure(&Symbol::new(&env, "int_mint"), &true);
+    assert_eq!(h.router.expire_withdrawal_approvals(&h.operator, &10), 0);
+    assert_eq!(h.router.get_withdrawal_approval(&approval.withdrawal_id), Some(approval.clone()));
+    assert_eq!(h.router.get_pending_withdrawal_approvals(), vec![&env, approval.clone()]);
+    let status = h.router.get_withdrawal_status(&approval.withdrawal_id).unwrap();
+    assert_eq!(status.status, WithdrawalProcessingStatus::ApprovalPending);
+    assert_eq!(h.istsi.balance(&approval.user), 0);
+
+    // Retried from the next bucket, not on every sweep in the same hour
+    h.istsi.set_failure(&Symbol::new(&env, "int_mint"), &false);
+    assert_eq!(h.router.expire_withdrawal_approvals(&h.operator, &10), 0);
+    env.ledger().with_mut(|li| li.timestamp = approval.expires_at + WINDOW);
+    assert_eq!(h.router.expire_withdrawal_approvals(&h.operator, &10), 1);
+
+    assert_eq!(h.istsi.balance(&approval.user), approval.net_istsi_amount as i128);
+    let status = h.router.get_withdrawal_status(&approval.withdrawal_id).unwrap();
+    assert_eq!(status.status, WithdrawalProcessingStatus::ApprovalExpired);
+    assert_eq!(h.router.get_pending_withdrawal_approvals().len(), 0);
+    env.as_contract(&h.router.address, || {
+        let periods = env.storage().persistent().get::<ApprovalKey, soroban_sdk::Vec<u64>>(&ApprovalKey::Periods);
+        assert_eq!(periods.map(|periods| periods.len()), Some(0));
+    });
+}
+
+#[test]
+fn test_flat_approval_list_migrates_into_expiry_buckets() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    h.router.set_withdrawal_approval_config(
+        &h.admin,
+        &WithdrawalApprovalConfig { threshold_sats: 100_000_000, approval_window: WINDOW }
+    );
+    let approval = seed_held_withdrawal(&h, &h.admin);
+
+    // Move the held withdrawal back into the pre-bucket layout
+    env.as_contract(&h.router.address, || {
+        assert!(IntegrationRouter::unfile_withdrawal_approval(&env, &approval.withdrawal_id));
+        env.storage().persistent().set(
+            &WorkflowKey::PendingWithdrawalApprovals,
+            &vec![&env, approval.withdrawal_id.clone()]
+        );
+    });
+    assert_eq!(h.router.get_pending_withdrawal_approvals(), vec![&env, approval.clone()]);
+
+    assert!(h.router.try_migrate_withdrawal_approvals(&h.operator, &10).is_err());
+    assert_eq!(h.router.migrate_withdrawal_approvals(&h.admin, &10), 1);
+    assert_eq!(h.router.migrate_withdrawal_approvals(&h.admin, &10), 0);
+    assert_eq!(h.router.get_pending_withdrawal_approvals(), vec![&env, approval.clone()]);
+
+    env.ledger().with_mut(|li| li.timestamp = approval.expires_at);
+    assert_eq!(h.router.expire_withdrawal_approvals(&h.operator, &10), 1);
+    assert_eq!(h.router.get_pending_withdrawal_approvals().len(), 0);
+}
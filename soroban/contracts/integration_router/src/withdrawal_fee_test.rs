@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+use super::*;
+use crate::testing::{TestHarness, HARNESS_START_TIME};
+use soroban_sdk::{testutils::{Address as TestAddress, Ledger}, Address, Env};
+
+const ISTSI_PER_SAT: u64 = 100_000_000;
+
+fn btc_address(env: &Env) -> String {
+    String::from_str(env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh")
+}
+
+#[test]
+fn test_fee_rates_come_from_operators_or_the_fee_oracle() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let oracle = Address::generate(&env);
+
+    // Without published rates withdrawals quote no network fee
+    let quote = h.router.quote_withdrawal_fee(&(50_000 * ISTSI_PER_SAT), &BtcFeeTier::Priority);
+    assert_eq!((quote.network_fee_sats, quote.net_btc_amount, quote.stale), (0, 50_000, true));
+
+    assert!(h.router.try_submit_btc_fee_rates(&oracle, &2, &5, &20).is_err());
+    assert_eq!(h.router.try_submit_btc_fee_rates(&h.operator, &5, &2, &20), Err(Ok(IntegrationError::InvalidOperationState)));
+    assert_eq!(h.router.try_submit_btc_fee_rates(&h.operator, &0, &2, &20), Err(Ok(IntegrationError::InvalidOperationState)));
+    assert!(h.router.try_set_btc_fee_oracle(&h.operator, &oracle).is_err());
+    h.router.set_btc_fee_oracle(&h.admin, &oracle);
+    h.router.submit_btc_fee_rates(&oracle, &2, &5, &20);
+    assert_eq!(h.router.get_btc_fee_rates().unwrap().updated_by, oracle);
+
+    let quote = h.router.quote_withdrawal_fee(&(50_000 * ISTSI_PER_SAT), &BtcFeeTier::Priority);
+    assert_eq!(quote.fee_rate, 20);
+    assert_eq!(quote.network_fee_sats, 20 * WITHDRAWAL_TX_VBYTES);
+    assert_eq!(quote.net_btc_amount, 50_000 - 20 * WITHDRAWAL_TX_VBYTES);
+    assert!(!quote.stale);
+    assert_eq!(h.router.quote_withdrawal_fee(&(50_000 * ISTSI_PER_SAT), &BtcFeeTier::Economy).network_fee_sats, 2 * WITHDRAWAL_TX_VBYTES);
+
+    env.ledger().with_mut(|li| li.timestamp = HARNESS_START_TIME + BTC_FEE_RATE_MAX_AGE + 1);
+    assert!(h.router.quote_withdrawal_fee(&(50_000 * ISTSI_PER_SAT), &BtcFeeTier::Standard).stale);
+    h.router.submit_btc_fee_rates(&h.operator, &3, &6, &30);
+    assert_eq!(h.router.get_btc_fee_rates().unwrap().updated_by, h.operator);
+}
+
+#[test]
+fn test_chosen_fee_tier_is_kept_on_the_request_and_withdrawal_status() {
+    let env = Env::default();
+    let h = TestHarness::new(&env);
+    let user = h.user(2);
+    h.router.submit_btc_fee_rates(&h.operator, &2, &5, &20);
+
+    // A fee that would swallow the payout is refused
+    assert_eq!(
+        h.router.try_user_request_withdrawal_with_fee(&user, &(20 * WITHDRAWAL_TX_VBYTES * ISTSI_PER_SAT), &btc_address(&env), &BtcFeeTier::Priority),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    let request_id = h.router.user_request_withdrawal_with_fee(&user, &(50_000 * ISTSI_PER_SAT), &btc_address(&env), &BtcFeeTier::Economy);
+    assert_eq!(h.router.get_withdrawal_request(&request_id).unwrap().fee_tier, BtcFeeTier::Economy);
+    let standard = h.router.user_request_withdrawal(&user, &(50_000 * ISTSI_PER_SAT), &btc_address(&env));
+    assert_eq!(h.router.get_withdrawal_request(&standard).unwrap().fee_tier, BtcFeeTier::Standard);
+
+    let withdrawal_id = BytesN::from_array(&env, &[9u8; 32]);
+    env.as_contract(&h.router.address, || {
+        IntegrationRouter::initialize_withdrawal_status(
+            &env, &withdrawal_id, &user, 50_000 * ISTSI_PER_SAT, &btc_address(&env), &withdrawal_id, BtcFeeTier::Priority
+        );
+    });
+    let status = h.router.get_withdrawal_status(&withdrawal_id).unwrap();
+    assert_eq!((status.fee_tier, status.fee_rate), (BtcFeeTier::Priority, 20));
+    assert_eq!(status.network_fee_sats, 20 * WITHDRAWAL_TX_VBYTES);
+}
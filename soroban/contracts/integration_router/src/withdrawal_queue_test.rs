@@ -0,0 +1,178 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as TestAddress, Ledger},
+    Address, BytesN, Env
+};
+
+fn seed_withdrawal(env: &Env, contract_id: &Address, id: u8, btc_amount: u64) -> BytesN<32> {
+    let withdrawal_id = BytesN::from_array(env, &[id; 32]);
+    env.as_contract(contract_id, || {
+        let status = WithdrawalStatus {
+            withdrawal_id: withdrawal_id.clone(),
+            user: Address::generate(env),
+            istsi_amount: btc_amount * 100_000_000,
+            btc_amount,
+            btc_address: String::from_str(env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh"),
+            status: WithdrawalProcessingStatus::Pending,
+            operation_id: withdrawal_id.clone(),
+            btc_tx_hash: None,
+            created_at: env.ledger().timestamp(),
+            updated_at: env.ledger().timestamp(),
+            error_message: String::from_str(env, ""),
+            fee_tier: BtcFeeTier::Standard,
+            fee_rate: 0,
+            network_fee_sats: 0,
+        };
+        env.storage().persistent().set(&DataKey::WithdrawalStatus(withdrawal_id.clone()), &status);
+    });
+    withdrawal_id
+}
+
+#[test]
+fn test_withdrawal_queue_priority_claim_and_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 10_000;
+    });
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let other_operator = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+    client.set_user_role(&admin, &operator, &UserRole::Operator);
+    client.set_user_role(&admin, &other_operator, &UserRole::Operator);
+
+    let old_low = seed_withdrawal(&env, &contract_id, 1, 100);
+    let new_high = seed_withdrawal(&env, &contract_id, 2, 200);
+    let claimed = seed_withdrawal(&env, &contract_id, 3, 300);
+
+    client.enqueue_withdrawal(&operator, &old_low, &0u32);
+    env.ledger().with_mut(|li| {
+        li.timestamp = 11_000;
+    });
+    client.enqueue_withdrawal(&operator, &new_high, &1u32);
+    client.enqueue_withdrawal(&operator, &claimed, &5u32);
+    assert_eq!(
+        client.try_enqueue_withdrawal(&operator, &old_low, &0u32),
+        Err(Ok(IntegrationError::DuplicateOperation))
+    );
+
+    // Priority tier outweighs 1000s of extra waiting
+    let queue = client.get_withdrawal_queue();
+    assert_eq!(queue.get(0).unwrap().withdrawal_id, claimed);
+    assert_eq!(queue.get(1).unwrap().withdrawal_id, new_high);
+    assert_eq!(queue.get(2).unwrap().withdrawal_id, old_low);
+
+    client.claim_withdrawal(&other_operator, &claimed);
+    assert_eq!(
+        client.try_claim_withdrawal(&operator, &claimed),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+
+    let metrics = client.get_withdrawal_queue_metrics();
+    assert_eq!(metrics.queued, 2);
+    assert_eq!(metrics.claimed, 1);
+    assert_eq!(metrics.total_btc_queued, 600);
+    assert_eq!(metrics.oldest_enqueued_at, Some(10_000));
+
+    // Another operator's claim is skipped when batching
+    let batch = client.dequeue_withdrawal_batch(&operator, &10u32);
+    assert_eq!(batch.withdrawal_ids.len(), 2);
+    assert_eq!(batch.total_btc_amount, 300);
+    assert_eq!(client.get_queued_withdrawal(&new_high).unwrap().status, QueueStatus::Batched);
+    assert_eq!(client.get_withdrawal_batch(&batch.batch_id).unwrap(), batch);
+
+    let metrics = client.get_withdrawal_queue_metrics();
+    assert_eq!(metrics.claimed, 1);
+    assert_eq!(metrics.queued, 0);
+    assert_eq!(metrics.batches_created, 1);
+}
+
+#[test]
+fn test_batch_settlement_split_and_confirm() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let other_operator = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+    client.set_user_role(&admin, &operator, &UserRole::Operator);
+    client.set_user_role(&admin, &other_operator, &UserRole::Operator);
+
+    let first = seed_withdrawal(&env, &contract_id, 1, 100);
+    let second = seed_withdrawal(&env, &contract_id, 2, 200);
+    let invalid = seed_withdrawal(&env, &contract_id, 3, 300);
+    for id in [&first, &second, &invalid] {
+        client.enqueue_withdrawal(&operator, id, &0u32);
+    }
+
+    let batch = client.dequeue_withdrawal_batch(&operator, &10u32);
+    assert_eq!(batch.outputs.len(), 3);
+    assert_eq!(batch.status, SettlementStatus::Planned);
+    assert_eq!(client.get_withdrawal_settlement_batch(&invalid), Some(batch.batch_id.clone()));
+
+    let txid = BytesN::from_array(&env, &[9u8; 32]);
+    assert_eq!(
+        client.try_record_batch_broadcast(&other_operator, &batch.batch_id, &txid),
+        Err(Ok(IntegrationError::InsufficientPermissions))
+    );
+    client.record_batch_broadcast(&operator, &batch.batch_id, &txid);
+    assert_eq!(client.get_batch_by_btc_tx(&txid), Some(batch.batch_id.clone()));
+
+    // The rejected output is split out and the rest must be re-sent
+    let reason = String::from_str(&env, "invalid_address");
+    let batch = client.split_batch_output(&operator, &batch.batch_id, &invalid, &reason);
+    assert_eq!(batch.status, SettlementStatus::Planned);
+    assert_eq!(batch.withdrawal_ids.len(), 2);
+    assert_eq!(batch.total_btc_amount, 300);
+    assert_eq!(batch.outputs.get(2).unwrap().status, BatchOutputStatus::SplitOut);
+    assert_eq!(client.get_batch_by_btc_tx(&txid), None);
+    assert_eq!(client.get_withdrawal_settlement_batch(&invalid), None);
+    assert_eq!(client.get_queued_withdrawal(&invalid).unwrap().status, QueueStatus::Queued);
+    assert_eq!(client.get_withdrawal_queue().len(), 1);
+    assert_eq!(client.get_withdrawal_status(&invalid).unwrap().error_message, reason);
+
+    let resent = BytesN::from_array(&env, &[10u8; 32]);
+    assert_eq!(
+        client.try_confirm_batch_settlement(&operator, &batch.batch_id, &resent),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+    client.record_batch_broadcast(&operator, &batch.batch_id, &resent);
+    let batch = client.confirm_batch_settlement(&operator, &batch.batch_id, &resent);
+    assert_eq!(batch.status, SettlementStatus::Confirmed);
+    assert_eq!(batch.outputs.get(0).unwrap().status, BatchOutputStatus::Paid);
+
+    for id in [&first, &second] {
+        let status = client.get_withdrawal_status(id).unwrap();
+        assert_eq!(status.status, WithdrawalProcessingStatus::Completed);
+        assert_eq!(status.btc_tx_hash, Some(resent.clone()));
+        assert_eq!(client.get_queued_withdrawal(id).unwrap().status, QueueStatus::Settled);
+    }
+    assert_eq!(client.get_withdrawal_status(&invalid).unwrap().btc_tx_hash, None);
+    assert_eq!(
+        client.try_split_batch_output(&operator, &batch.batch_id, &first, &reason),
+        Err(Ok(IntegrationError::InvalidOperationState))
+    );
+}
@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as TestAddress, Address, BytesN, Env};
+
+#[test]
+fn test_workflow_pause_is_isolated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IntegrationRouter, ());
+    let client = IntegrationRouterClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+
+    let reason = String::from_str(&env, "Hot wallet rotation");
+    let operator = Address::generate(&env);
+    client.set_user_role(&admin, &operator, &UserRole::Operator);
+    assert!(client.try_pause_workflow(&operator, &PauseWorkflow::Withdrawals, &reason).is_err());
+
+    let officer = Address::generate(&env);
+    client.set_user_role(&admin, &officer, &UserRole::ComplianceOfficer);
+    client.pause_workflow(&officer, &PauseWorkflow::Withdrawals, &reason);
+
+    assert_eq!(client.get_pause_state(), PauseState {
+        system_paused: false,
+        deposits_paused: false,
+        withdrawals_paused: true,
+        exchanges_paused: false,
+    });
+    assert!(client.is_workflow_paused(&PauseWorkflow::Withdrawals));
+    assert!(!client.is_workflow_paused(&PauseWorkflow::Deposits));
+    assert!(!client.is_paused());
+
+    let user = Address::generate(&env);
+    let withdrawal = client.try_execute_token_withdrawal(
+        &operator,
+        &user,
+        &1_000u64,
+        &String::from_str(&env, "bc1qpaused"),
+    );
+    assert_eq!(withdrawal, Err(Ok(IntegrationError::WorkflowPaused.into())));
+
+    // Deposits still run (and fail later against the unconfigured contracts)
+    let deposit = client.try_execute_bitcoin_deposit(
+        &operator,
+        &user,
+        &1_000u64,
+        &BytesN::from_array(&env, &[6u8; 32]),
+        &6u32,
+    );
+    assert_ne!(deposit, Err(Ok(IntegrationError::WorkflowPaused.into())));
+
+    // Only SuperAdmin resumes a workflow
+    assert!(client.try_resume_workflow(&officer, &PauseWorkflow::Withdrawals).is_err());
+    client.resume_workflow(&admin, &PauseWorkflow::Withdrawals);
+    assert!(!client.is_workflow_paused(&PauseWorkflow::Withdrawals));
+
+    // A system-wide pause covers every workflow, but lifting it leaves
+    // workflow pauses in place
+    client.pause_workflow(&officer, &PauseWorkflow::Exchanges, &reason);
+    client.emergency_pause(&officer, &reason);
+    assert!(client.is_workflow_paused(&PauseWorkflow::Deposits));
+    client.resume_operations(&admin);
+    assert_eq!(client.get_pause_state(), PauseState {
+        system_paused: false,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        exchanges_paused: true,
+    });
+    assert!(client.is_workflow_paused(&PauseWorkflow::Exchanges));
+    assert!(!client.is_workflow_paused(&PauseWorkflow::Deposits));
+
+    client.resume_workflow(&admin, &PauseWorkflow::Exchanges);
+    assert!(!client.is_workflow_paused(&PauseWorkflow::Exchanges));
+}
@@ -3,7 +3,7 @@
 
 use soroban_sdk::{
     contract, contractimpl, contracttype, contracterror, symbol_short, panic_with_error,
-    Address, Env, String, Vec, Map, IntoVal, BytesN
+    Address, Env, String, Symbol, Vec, Map, IntoVal, BytesN
 };
 
 use stellar_access::ownable::{self as ownable, Ownable};
@@ -57,6 +57,45 @@ pub struct ComplianceTransferRequest {
     pub operation_type: u32, // 0=transfer, 1=mint, 2=burn
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClawbackRecord {
+    pub clawback_id: u64,
+    pub target: Address,
+    pub escrow: Address,
+    pub amount: i128,
+    pub evidence_hash: BytesN<32>,
+    pub case_id: BytesN<32>, // Router clawback proposal that authorized the seizure
+    pub executed_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BalanceSnapshot {
+    pub epoch: u64,     // timestamp / epoch_seconds; one snapshot per account per epoch
+    pub timestamp: u64, // Last balance change within the epoch
+    pub balance: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotPolicy {
+    pub epoch_seconds: u64,
+    pub retention_epochs: u64, // Snapshots older than this many epochs are pruned
+}
+
+/// Sequence numbers of an account's retained snapshots
+/// 
+/// Each epoch's snapshot is stored under its own `(account, seq)` key, so a
+/// balance change rewrites one entry and this small index at most.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotRange {
+    pub first: u32,      // Oldest retained snapshot
+    pub next: u32,       // Where the next epoch's snapshot goes
+    pub last_epoch: u64, // Epoch of the newest snapshot
+}
+
 //
 // Integration Errors
 //
@@ -83,8 +122,20 @@ pub enum IntegrationError {
     AutoComplianceDisabled = 130,
     ComplianceCheckFailed = 131,
     InvalidOperationType = 132,
+    AddressBlacklisted = 133,
+    AddressFrozen = 134,
+    SeizureEscrowNotSet = 135,
+    InvalidClawbackAmount = 136,
+    ApprovalTooLong = 137,
 }
 
+const DAY_IN_LEDGERS: u32 = 17280; // Approximately 1 day in ledgers (5s each)
+const DEFAULT_MAX_APPROVAL_LEDGERS: u32 = 30 * DAY_IN_LEDGERS;
+const DEFAULT_SNAPSHOT_EPOCH_SECONDS: u64 = 86_400;
+const DEFAULT_SNAPSHOT_RETENTION_EPOCHS: u64 = 400;
+const MAX_SNAPSHOT_PRUNE: u32 = 50; // Per prune_balance_snapshots call
+const MAX_SNAPSHOT_PRUNE_PER_WRITE: u32 = 4; // Keeps transfers O(1) while outpacing new epochs
+
 //
 // Enhanced iSTSi Token Contract with Integration Capabilities
 //
@@ -129,6 +180,7 @@ impl IntegratedISTSiToken {
         // Mint initial supply to admin
         if initial_supply > 0 {
             Base::mint(&env, &admin, initial_supply);
+            Self::record_balance_snapshots(&env, &[&admin]);
         }
         
         // Emit initialization event
@@ -166,6 +218,7 @@ impl IntegratedISTSiToken {
         
         // Mint tokens
         Base::mint(&env, &request.recipient, request.amount);
+        Self::record_balance_snapshots(&env, &[&request.recipient]);
         
         // Store mint record with Bitcoin transaction link
         let mint_key = (symbol_short!("INT_MINT"), request.btc_tx_hash.clone());
@@ -235,6 +288,7 @@ impl IntegratedISTSiToken {
         
         // Burn tokens
         Base::burn(&env, &request.from_address, request.amount);
+        Self::record_balance_snapshots(&env, &[&request.from_address]);
         
         // Store burn record
         let burn_key = (symbol_short!("INT_BURN"), request.request_id.clone());
@@ -301,14 +355,18 @@ impl IntegratedISTSiToken {
             Err(_) => return, // Exit early if no integration config
         };
         
+        Self::require_not_frozen(&env, &[&from, &to]);
+        
         // Perform compliance checks if auto-compliance is enabled
         if config.auto_compliance_enabled {
+            Self::require_not_blacklisted(&env, &[&from, &to]);
             let _ = Self::verify_address_compliance(&env, &from, amount, 0);
             let _ = Self::verify_address_compliance(&env, &to, amount, 0);
         }
         
         // Execute transfer
         Base::transfer(&env, &from, &to, amount);
+        Self::record_balance_snapshots(&env, &[&from, &to]);
         
         // Generate correlation ID for audit trail
         let correlation_id = Self::generate_correlation_id(&env);
@@ -343,6 +401,278 @@ impl IntegratedISTSiToken {
         results
     }
     
+    //
+    // Delegated Transfer Policy
+    //
+    
+    /// Set the longest an approval may stay live, in ledgers (Admin only)
+    #[only_owner]
+    pub fn set_max_approval_ledgers(env: Env, ledgers: u32) {
+        if ledgers == 0 {
+            panic_with_error!(&env, IntegrationError::InvalidOperationType);
+        }
+        env.storage().persistent().set(&symbol_short!("MAX_APPR"), &ledgers);
+        
+        env.events().publish(
+            (symbol_short!("MAX_APPR"), symbol_short!("SET")),
+            ledgers
+        );
+    }
+    
+    /// Longest an approval may stay live, in ledgers from the current one
+    pub fn get_max_approval_ledgers(env: Env) -> u32 {
+        env.storage().persistent()
+            .get(&symbol_short!("MAX_APPR"))
+            .unwrap_or(DEFAULT_MAX_APPROVAL_LEDGERS)
+    }
+    
+    /// Ledger an allowance stays live until (0 when there is no live allowance)
+    pub fn get_allowance_expiry(env: Env, owner: Address, spender: Address) -> u32 {
+        let allowance = Base::allowance_data(&env, &owner, &spender);
+        if allowance.amount == 0 || allowance.live_until_ledger < env.ledger().sequence() {
+            return 0;
+        }
+        allowance.live_until_ledger
+    }
+    
+    //
+    // Balance Snapshots
+    //
+    
+    /// Set the snapshot epoch length and retention (Admin only)
+    #[only_owner]
+    pub fn set_snapshot_policy(env: Env, policy: SnapshotPolicy) {
+        if policy.epoch_seconds == 0 || policy.retention_epochs == 0 {
+            panic_with_error!(&env, IntegrationError::InvalidOperationType);
+        }
+        env.storage().persistent().set(&symbol_short!("SNAP_POL"), &policy);
+        
+        env.events().publish(
+            (symbol_short!("SNAP_POL"), symbol_short!("SET")),
+            (policy.epoch_seconds, policy.retention_epochs)
+        );
+    }
+    
+    /// Get the snapshot policy
+    pub fn get_snapshot_policy(env: Env) -> SnapshotPolicy {
+        env.storage().persistent()
+            .get(&symbol_short!("SNAP_POL"))
+            .unwrap_or(SnapshotPolicy {
+                epoch_seconds: DEFAULT_SNAPSHOT_EPOCH_SECONDS,
+                retention_epochs: DEFAULT_SNAPSHOT_RETENTION_EPOCHS,
+            })
+    }
+    
+    /// Balance of an account at a point in time
+    /// 
+    /// Snapshots keep the last balance of each epoch, so a time inside an
+    /// epoch that saw several changes resolves to the latest change made at or
+    /// before it that is still on record. Returns None when the time predates
+    /// the account's retained history.
+    pub fn balance_at(env: Env, account: Address, timestamp: u64) -> Option<i128> {
+        let range = Self::get_snapshot_range(&env, &account);
+        if range.first == range.next {
+            // Untouched since snapshots were introduced
+            return Some(Base::balance(&env, &account));
+        }
+        
+        let seq = Self::snapshots_through(&env, &account, &range, timestamp);
+        if seq == range.first {
+            return None;
+        }
+        Some(Self::get_snapshot(&env, &account, seq - 1).balance)
+    }
+    
+    /// Snapshots of an account taken in `[start, end)`, oldest first
+    pub fn get_balance_snapshots(env: Env, account: Address, start: u64, end: u64) -> Vec<BalanceSnapshot> {
+        let range = Self::get_snapshot_range(&env, &account);
+        let mut seq = if start == 0 {
+            range.first
+        } else {
+            Self::snapshots_through(&env, &account, &range, start - 1)
+        };
+        
+        let mut snapshots = Vec::new(&env);
+        while seq < range.next {
+            let snapshot = Self::get_snapshot(&env, &account, seq);
+            if snapshot.timestamp >= end {
+                break;
+            }
+            snapshots.push_back(snapshot);
+            seq += 1;
+        }
+        snapshots
+    }
+    
+    /// Drop an account's snapshots that fall outside the retention window
+    /// 
+    /// Anyone may call this; snapshots are also pruned whenever a new one is
+    /// written. The latest snapshot before the window is kept so balances
+    /// inside the window stay answerable. At most 50 snapshots are removed
+    /// per call.
+    /// 
+    /// # Returns
+    /// Number of snapshots removed
+    pub fn prune_balance_snapshots(env: Env, account: Address) -> u32 {
+        let mut range = Self::get_snapshot_range(&env, &account);
+        let pruned = Self::prune_snapshots(&env, &account, &mut range, MAX_SNAPSHOT_PRUNE);
+        if pruned > 0 {
+            env.storage().persistent().set(&(symbol_short!("SNAP_RNG"), account), &range);
+        }
+        pruned
+    }
+    
+    //
+    // Compliance Seizures
+    //
+    
+    /// Set the account seized tokens are moved into (Admin only)
+    #[only_owner]
+    pub fn set_seizure_escrow(env: Env, escrow: Address) {
+        env.storage().persistent().set(&symbol_short!("SEIZ_ESC"), &escrow);
+        
+        env.events().publish(
+            (symbol_short!("SEIZ_ESC"), symbol_short!("SET")),
+            escrow
+        );
+    }
+    
+    /// Get the seizure escrow account
+    pub fn get_seizure_escrow(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&symbol_short!("SEIZ_ESC"))
+    }
+    
+    /// Move tokens from a sanctioned account into the seizure escrow
+    /// 
+    /// Only the integration router can claw back, after a compliance officer's
+    /// proposal has been approved by a super admin there. Frozen accounts and
+    /// a paused token do not block a seizure. Every clawback is recorded in a
+    /// public audit trail and announced to the target account.
+    pub fn clawback(
+        env: Env,
+        caller: Address,
+        target: Address,
+        amount: i128,
+        evidence_hash: BytesN<32>,
+        case_id: BytesN<32>
+    ) -> Result<u64, IntegrationError> {
+        caller.require_auth();
+        let config = Self::get_integration_config(&env)?;
+        if caller != config.integration_router {
+            return Err(IntegrationError::RouterCallFailed);
+        }
+        
+        if evidence_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            return Err(IntegrationError::InvalidComplianceProof);
+        }
+        if amount <= 0 || amount > Base::balance(&env, &target) {
+            return Err(IntegrationError::InvalidClawbackAmount);
+        }
+        let escrow = Self::get_seizure_escrow(env.clone()).ok_or(IntegrationError::SeizureEscrowNotSet)?;
+        
+        Base::update(&env, Some(&target), Some(&escrow), amount);
+        Self::record_balance_snapshots(&env, &[&target, &escrow]);
+        
+        let clawback_id = Self::get_clawback_count(env.clone()) + 1;
+        let record = ClawbackRecord {
+            clawback_id,
+            target: target.clone(),
+            escrow: escrow.clone(),
+            amount,
+            evidence_hash: evidence_hash.clone(),
+            case_id,
+            executed_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&(symbol_short!("CLAWBACK"), clawback_id), &record);
+        env.storage().persistent().set(&symbol_short!("CLAW_CNT"), &clawback_id);
+        
+        // Notify the target account
+        env.events().publish(
+            (symbol_short!("CLAWBACK"), target),
+            (clawback_id, amount, escrow, evidence_hash)
+        );
+        
+        Ok(clawback_id)
+    }
+    
+    /// Get a clawback from the audit trail
+    pub fn get_clawback(env: Env, clawback_id: u64) -> Option<ClawbackRecord> {
+        env.storage().persistent().get(&(symbol_short!("CLAWBACK"), clawback_id))
+    }
+    
+    /// Number of clawbacks executed; IDs run from 1 to this count
+    pub fn get_clawback_count(env: Env) -> u64 {
+        env.storage().persistent().get(&symbol_short!("CLAW_CNT")).unwrap_or(0)
+    }
+    
+    /// Page through the clawback audit trail, oldest first
+    pub fn get_clawbacks(env: Env, start_id: u64, limit: u32) -> Vec<ClawbackRecord> {
+        let mut records = Vec::new(&env);
+        let count = Self::get_clawback_count(env.clone());
+        
+        let mut clawback_id = start_id.max(1);
+        while clawback_id <= count && records.len() < limit {
+            if let Some(record) = Self::get_clawback(env.clone(), clawback_id) {
+                records.push_back(record);
+            }
+            clawback_id += 1;
+        }
+        
+        records
+    }
+    
+    //
+    // Address Freezes
+    //
+    
+    /// Mirror an integration router freeze (router only)
+    /// 
+    /// The router pushes its freezes here because it cannot be asked while it
+    /// is itself calling the token, so transfers are checked against local
+    /// state and never depend on a cross-contract lookup succeeding.
+    /// 
+    /// # Arguments
+    /// * `caller` - Must be the integration router
+    /// * `address` - Address the freeze applies to
+    /// * `frozen` - Whether the address is frozen; false lifts the freeze
+    /// * `expires_at` - Timestamp the freeze lapses at (0 = until lifted)
+    pub fn set_address_freeze(
+        env: Env,
+        caller: Address,
+        address: Address,
+        frozen: bool,
+        expires_at: u64
+    ) -> Result<(), IntegrationError> {
+        caller.require_auth();
+        let config = Self::get_integration_config(&env)?;
+        if caller != config.integration_router {
+            return Err(IntegrationError::RouterCallFailed);
+        }
+        
+        let key = (symbol_short!("FROZEN"), address.clone());
+        if frozen {
+            env.storage().persistent().set(&key, &expires_at);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        
+        env.events().publish(
+            (symbol_short!("FROZEN"), address),
+            (frozen, expires_at)
+        );
+        
+        Ok(())
+    }
+    
+    /// Check whether the router has an active freeze on an address
+    pub fn is_address_frozen(env: Env, address: Address) -> bool {
+        match env.storage().persistent().get::<_, u64>(&(symbol_short!("FROZEN"), address)) {
+            Some(0) => true,
+            Some(expires_at) => env.ledger().timestamp() < expires_at,
+            None => false,
+        }
+    }
+    
     //
     // Integration Configuration Management
     //
@@ -401,6 +731,84 @@ impl IntegratedISTSiToken {
     // Integration Helper Functions
     //
     
+    /// Snapshot the current balances of accounts whose balance just changed
+    fn record_balance_snapshots(env: &Env, accounts: &[&Address]) {
+        let policy = Self::get_snapshot_policy(env.clone());
+        let now = env.ledger().timestamp();
+        let epoch = now / policy.epoch_seconds;
+        
+        for account in accounts {
+            let account = (*account).clone();
+            let mut range = Self::get_snapshot_range(env, &account);
+            let balance = Base::balance(env, &account);
+            
+            // A later change in the newest epoch replaces its snapshot; an
+            // epoch number that went backwards after a policy change folds
+            // into it too, so epochs stay ordered by sequence
+            if range.first < range.next && epoch <= range.last_epoch {
+                let snapshot = BalanceSnapshot { epoch: range.last_epoch, timestamp: now, balance };
+                env.storage().persistent().set(&(symbol_short!("BAL_SNAP"), account, range.next - 1), &snapshot);
+                continue;
+            }
+            
+            let snapshot = BalanceSnapshot { epoch, timestamp: now, balance };
+            env.storage().persistent().set(&(symbol_short!("BAL_SNAP"), account.clone(), range.next), &snapshot);
+            range.next += 1;
+            range.last_epoch = epoch;
+            Self::prune_snapshots(env, &account, &mut range, MAX_SNAPSHOT_PRUNE_PER_WRITE);
+            env.storage().persistent().set(&(symbol_short!("SNAP_RNG"), account), &range);
+        }
+    }
+    
+    fn get_snapshot_range(env: &Env, account: &Address) -> SnapshotRange {
+        env.storage().persistent()
+            .get(&(symbol_short!("SNAP_RNG"), account.clone()))
+            .unwrap_or(SnapshotRange { first: 0, next: 0, last_epoch: 0 })
+    }
+    
+    fn get_snapshot(env: &Env, account: &Address, seq: u32) -> BalanceSnapshot {
+        env.storage().persistent()
+            .get(&(symbol_short!("BAL_SNAP"), account.clone(), seq))
+            .expect("snapshot inside its account's range")
+    }
+    
+    /// First sequence whose snapshot was taken after `timestamp`
+    /// 
+    /// Binary search over the retained range, so lookups read a handful of
+    /// entries however long the history is.
+    fn snapshots_through(env: &Env, account: &Address, range: &SnapshotRange, timestamp: u64) -> u32 {
+        let mut low = range.first;
+        let mut high = range.next;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if Self::get_snapshot(env, account, mid).timestamp <= timestamp {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+    
+    /// Remove up to `limit` snapshots before the retention window, keeping
+    /// the newest of them
+    fn prune_snapshots(env: &Env, account: &Address, range: &mut SnapshotRange, limit: u32) -> u32 {
+        let policy = Self::get_snapshot_policy(env.clone());
+        let current_epoch = env.ledger().timestamp() / policy.epoch_seconds;
+        let oldest_retained = current_epoch.saturating_sub(policy.retention_epochs);
+        
+        let mut pruned = 0u32;
+        while pruned < limit
+            && range.next - range.first > 1
+            && Self::get_snapshot(env, account, range.first + 1).epoch <= oldest_retained
+        {
+            env.storage().persistent().remove(&(symbol_short!("BAL_SNAP"), account.clone(), range.first));
+            range.first += 1;
+            pruned += 1;
+        }
+        pruned
+    }
+    
     /// Verify caller is authorized for integration operations
     fn require_integration_auth(env: &Env, caller: &Address) -> Result<(), IntegrationError> {
         // Check if caller is admin
@@ -477,6 +885,38 @@ impl IntegratedISTSiToken {
         }
     }
     
+    /// Reject transfers and approvals involving an address on the KYC registry blacklist
+    ///
+    /// A configured registry that cannot answer fails the check rather than
+    /// letting the address through unscreened.
+    fn require_not_blacklisted(env: &Env, addresses: &[&Address]) {
+        let registry: Address = match env.storage().persistent().get(&symbol_short!("KYC_REG")) {
+            Some(registry) => registry,
+            None => return,
+        };
+        
+        for address in addresses {
+            match env.try_invoke_contract::<bool, soroban_sdk::Error>(
+                &registry,
+                &Symbol::new(env, "is_blacklisted"),
+                Vec::from_array(env, [(*address).into_val(env)])
+            ) {
+                Ok(Ok(false)) => {}
+                Ok(Ok(true)) => panic_with_error!(env, IntegrationError::AddressBlacklisted),
+                _ => panic_with_error!(env, IntegrationError::ComplianceCheckFailed),
+            }
+        }
+    }
+    
+    /// Reject transfers and approvals involving an address frozen in the integration router
+    fn require_not_frozen(env: &Env, addresses: &[&Address]) {
+        for address in addresses {
+            if Self::is_address_frozen(env.clone(), (*address).clone()) {
+                panic_with_error!(env, IntegrationError::AddressFrozen);
+            }
+        }
+    }
+    
     /// Verify reserve backing through reserve manager
     fn verify_reserve_backing(
         _env: &Env,
@@ -598,6 +1038,11 @@ impl IntegratedISTSiToken {
 //
 // Enhanced Token Implementation with Integration Compliance
 //
+// Together with `FungibleBurnable` this is the full SEP-41 token interface
+// (allowance, approve, balance, transfer, transfer_from, burn, burn_from,
+// decimals, name, symbol), so wallets can use the standard token client.
+// Every mutating entry point keeps the freeze and compliance hooks.
+//
 
 #[default_impl]
 #[contractimpl]
@@ -608,7 +1053,10 @@ impl FungibleToken for IntegratedISTSiToken {
     fn transfer(env: &Env, from: Address, to: Address, amount: i128) {
         // Check if integration is enabled and auto-compliance is on
         if let Ok(config) = IntegratedISTSiToken::get_integration_config(env) {
+            IntegratedISTSiToken::require_not_frozen(env, &[&from, &to]);
+            
             if config.auto_compliance_enabled {
+                IntegratedISTSiToken::require_not_blacklisted(env, &[&from, &to]);
                 // Use integrated compliance checking
                 if let Err(_) = IntegratedISTSiToken::verify_address_compliance(env, &from, amount, 0) {
                     panic_with_error!(env, IntegrationError::ComplianceCheckFailed);
@@ -621,6 +1069,7 @@ impl FungibleToken for IntegratedISTSiToken {
         
         // Execute the transfer
         Self::ContractType::transfer(env, &from, &to, amount);
+        IntegratedISTSiToken::record_balance_snapshots(env, &[&from, &to]);
         
         // Emit integration event if integration is enabled
         if IntegratedISTSiToken::is_integration_enabled(env.clone()) {
@@ -636,7 +1085,11 @@ impl FungibleToken for IntegratedISTSiToken {
     fn transfer_from(env: &Env, spender: Address, from: Address, to: Address, amount: i128) {
         // Check if integration is enabled and auto-compliance is on
         if let Ok(config) = IntegratedISTSiToken::get_integration_config(env) {
+            // A frozen or blacklisted spender cannot keep using an earlier approval
+            IntegratedISTSiToken::require_not_frozen(env, &[&spender, &from, &to]);
+            
             if config.auto_compliance_enabled {
+                IntegratedISTSiToken::require_not_blacklisted(env, &[&spender, &from, &to]);
                 // Verify compliance for both sender and recipient
                 if let Err(_) = IntegratedISTSiToken::verify_address_compliance(env, &from, amount, 0) {
                     panic_with_error!(env, IntegrationError::ComplianceCheckFailed);
@@ -648,6 +1101,7 @@ impl FungibleToken for IntegratedISTSiToken {
         }
         
         Self::ContractType::transfer_from(env, &spender, &from, &to, amount);
+        IntegratedISTSiToken::record_balance_snapshots(env, &[&from, &to]);
         
         // Emit integration event if integration is enabled
         if IntegratedISTSiToken::is_integration_enabled(env.clone()) {
@@ -658,6 +1112,27 @@ impl FungibleToken for IntegratedISTSiToken {
             );
         }
     }
+
+    fn approve(env: &Env, owner: Address, spender: Address, amount: i128, live_until_ledger: u32) {
+        // Revoking (amount 0) is always allowed
+        if amount > 0 {
+            let max_live_until = env.ledger().sequence()
+                .saturating_add(IntegratedISTSiToken::get_max_approval_ledgers(env.clone()));
+            if live_until_ledger > max_live_until {
+                panic_with_error!(env, IntegrationError::ApprovalTooLong);
+            }
+            
+            if let Ok(config) = IntegratedISTSiToken::get_integration_config(env) {
+                IntegratedISTSiToken::require_not_frozen(env, &[&owner, &spender]);
+                
+                if config.auto_compliance_enabled {
+                    IntegratedISTSiToken::require_not_blacklisted(env, &[&spender]);
+                }
+            }
+        }
+        
+        Self::ContractType::approve(env, &owner, &spender, amount, live_until_ledger);
+    }
 }
 
 //
@@ -670,6 +1145,8 @@ impl FungibleBurnable for IntegratedISTSiToken {
     fn burn(env: &Env, from: Address, amount: i128) {
         // Use integrated compliance checking if enabled
         if let Ok(config) = IntegratedISTSiToken::get_integration_config(env) {
+            IntegratedISTSiToken::require_not_frozen(env, &[&from]);
+            
             if config.auto_compliance_enabled {
                 if let Err(_) = IntegratedISTSiToken::verify_address_compliance(env, &from, amount, 2) {
                     panic_with_error!(env, IntegrationError::ComplianceCheckFailed);
@@ -678,6 +1155,7 @@ impl FungibleBurnable for IntegratedISTSiToken {
         }
         
         Base::burn(env, &from, amount);
+        IntegratedISTSiToken::record_balance_snapshots(env, &[&from]);
         
         // Emit integration burn event if integration is enabled
         if IntegratedISTSiToken::is_integration_enabled(env.clone()) {
@@ -693,6 +1171,8 @@ impl FungibleBurnable for IntegratedISTSiToken {
     fn burn_from(env: &Env, spender: Address, from: Address, amount: i128) {
         // Use integrated compliance checking if enabled
         if let Ok(config) = IntegratedISTSiToken::get_integration_config(env) {
+            IntegratedISTSiToken::require_not_frozen(env, &[&spender, &from]);
+            
             if config.auto_compliance_enabled {
                 if let Err(_) = IntegratedISTSiToken::verify_address_compliance(env, &from, amount, 2) {
                     panic_with_error!(env, IntegrationError::ComplianceCheckFailed);
@@ -701,6 +1181,7 @@ impl FungibleBurnable for IntegratedISTSiToken {
         }
         
         Base::burn_from(env, &spender, &from, amount);
+        IntegratedISTSiToken::record_balance_snapshots(env, &[&from]);
         
         // Emit integration burn event if integration is enabled
         if IntegratedISTSiToken::is_integration_enabled(env.clone()) {
@@ -763,7 +1244,21 @@ impl Ownable for IntegratedISTSiToken {}
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{testutils::Address as AddressTestUtils, Address, Env, String};
+    use soroban_sdk::{testutils::{Address as AddressTestUtils, Ledger}, Address, Env, String};
+
+    #[contract]
+    pub struct MockKycRegistry;
+
+    #[contractimpl]
+    impl MockKycRegistry {
+        pub fn set_blacklisted(env: Env, address: Address, listed: bool) {
+            env.storage().persistent().set(&address, &listed);
+        }
+
+        pub fn is_blacklisted(env: Env, address: Address) -> bool {
+            env.storage().persistent().get(&address).unwrap_or(false)
+        }
+    }
 
     #[test]
     fn test_integration_initialization() {
@@ -905,7 +1400,7 @@ mod test {
         let admin = Address::generate(&env);
         let user1 = Address::generate(&env);
         let user2 = Address::generate(&env);
-        let kyc_registry = Address::generate(&env);
+        let kyc_registry = env.register(MockKycRegistry, ());
         let integration_router = Address::generate(&env);
         let reserve_manager = Address::generate(&env);
         
@@ -1012,6 +1507,356 @@ mod test {
         assert_eq!(client.is_integration_enabled(), true);
     }
     
+    #[test]
+    fn test_clawback_moves_funds_to_seizure_escrow() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(IntegratedISTSiToken, ());
+        let client = IntegratedISTSiTokenClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let sanctioned = Address::generate(&env);
+        let escrow = Address::generate(&env);
+        let integration_router = Address::generate(&env);
+        
+        client.initialize(
+            &admin,
+            &String::from_str(&env, "Integrated iSTSi"),
+            &String::from_str(&env, "iSTSi"),
+            &8u32,
+            &1000000000i128,
+            &Address::generate(&env),
+            &integration_router,
+            &Address::generate(&env)
+        );
+        client.set_auto_compliance(&false);
+        client.transfer(&admin, &sanctioned, &300000000i128);
+        
+        let evidence = BytesN::from_array(&env, &[7u8; 32]);
+        let case_id = BytesN::from_array(&env, &[9u8; 32]);
+        
+        // Seizures need the router, an escrow account and evidence
+        assert_eq!(
+            client.try_clawback(&admin, &sanctioned, &100000000i128, &evidence, &case_id),
+            Err(Ok(IntegrationError::RouterCallFailed))
+        );
+        assert_eq!(
+            client.try_clawback(&integration_router, &sanctioned, &100000000i128, &evidence, &case_id),
+            Err(Ok(IntegrationError::SeizureEscrowNotSet))
+        );
+        client.set_seizure_escrow(&escrow);
+        assert_eq!(
+            client.try_clawback(&integration_router, &sanctioned, &100000000i128, &BytesN::from_array(&env, &[0u8; 32]), &case_id),
+            Err(Ok(IntegrationError::InvalidComplianceProof))
+        );
+        assert_eq!(
+            client.try_clawback(&integration_router, &sanctioned, &400000000i128, &evidence, &case_id),
+            Err(Ok(IntegrationError::InvalidClawbackAmount))
+        );
+        
+        assert_eq!(client.clawback(&integration_router, &sanctioned, &100000000i128, &evidence, &case_id), 1);
+        assert_eq!(client.balance(&sanctioned), 200000000i128);
+        assert_eq!(client.balance(&escrow), 100000000i128);
+        assert_eq!(client.total_supply(), 1000000000i128);
+        
+        let trail = client.get_clawbacks(&0, &10);
+        assert_eq!(trail.len(), 1);
+        let record = trail.get_unchecked(0);
+        assert_eq!((record.target, record.escrow, record.evidence_hash), (sanctioned, escrow, evidence));
+    }
+    
+    #[test]
+    fn test_approvals_expire_and_delegated_transfers_are_checked() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(IntegratedISTSiToken, ());
+        let client = IntegratedISTSiTokenClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        let kyc_registry = env.register(MockKycRegistry, ());
+        
+        client.initialize(
+            &admin,
+            &String::from_str(&env, "Integrated iSTSi"),
+            &String::from_str(&env, "iSTSi"),
+            &8u32,
+            &1000000000i128,
+            &kyc_registry,
+            &Address::generate(&env),
+            &Address::generate(&env)
+        );
+        client.transfer(&admin, &owner, &500000000i128);
+        
+        // Approvals cannot outlive the configured maximum
+        client.set_max_approval_ledgers(&100);
+        let sequence = env.ledger().sequence();
+        assert_eq!(
+            client.try_approve(&owner, &spender, &200000000i128, &(sequence + 101)),
+            Err(Ok(soroban_sdk::Error::from_contract_error(IntegrationError::ApprovalTooLong as u32)))
+        );
+        client.approve(&owner, &spender, &200000000i128, &(sequence + 100));
+        assert_eq!(client.allowance(&owner, &spender), 200000000i128);
+        assert_eq!(client.get_allowance_expiry(&owner, &spender), sequence + 100);
+        
+        client.transfer_from(&spender, &owner, &recipient, &150000000i128);
+        assert_eq!(client.balance(&recipient), 150000000i128);
+        assert_eq!(client.allowance(&owner, &spender), 50000000i128);
+        
+        // Once expired the remaining allowance cannot be spent
+        env.ledger().with_mut(|li| li.sequence_number = sequence + 101);
+        assert_eq!(client.allowance(&owner, &spender), 0);
+        assert_eq!(client.get_allowance_expiry(&owner, &spender), 0);
+        assert!(client.try_transfer_from(&spender, &owner, &recipient, &10000000i128).is_err());
+        
+        // Revoking is always allowed
+        client.approve(&owner, &spender, &100000000i128, &(sequence + 150));
+        client.approve(&owner, &spender, &0i128, &0);
+        assert_eq!(client.allowance(&owner, &spender), 0);
+        
+        // A blacklisted spender cannot be approved
+        let registry = MockKycRegistryClient::new(&env, &kyc_registry);
+        registry.set_blacklisted(&spender, &true);
+        assert_eq!(
+            client.try_approve(&owner, &spender, &100000000i128, &(sequence + 150)),
+            Err(Ok(soroban_sdk::Error::from_contract_error(IntegrationError::AddressBlacklisted as u32)))
+        );
+        
+        // Plain and delegated transfers screen both ends, not just the spender
+        registry.set_blacklisted(&spender, &false);
+        client.approve(&owner, &spender, &100000000i128, &(sequence + 150));
+        registry.set_blacklisted(&recipient, &true);
+        assert_eq!(
+            client.try_transfer(&owner, &recipient, &10000000i128),
+            Err(Ok(soroban_sdk::Error::from_contract_error(IntegrationError::AddressBlacklisted as u32)))
+        );
+        assert_eq!(
+            client.try_transfer_from(&spender, &owner, &recipient, &10000000i128),
+            Err(Ok(soroban_sdk::Error::from_contract_error(IntegrationError::AddressBlacklisted as u32)))
+        );
+        registry.set_blacklisted(&recipient, &false);
+        registry.set_blacklisted(&owner, &true);
+        assert_eq!(
+            client.try_transfer_from(&spender, &owner, &recipient, &10000000i128),
+            Err(Ok(soroban_sdk::Error::from_contract_error(IntegrationError::AddressBlacklisted as u32)))
+        );
+        assert!(client.try_transfer(&owner, &admin, &10000000i128).is_err());
+    }
+    
+    #[test]
+    fn test_router_freezes_block_transfers_until_lifted() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(IntegratedISTSiToken, ());
+        let client = IntegratedISTSiTokenClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let router = Address::generate(&env);
+        let holder = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        client.initialize(
+            &admin,
+            &String::from_str(&env, "Integrated iSTSi"),
+            &String::from_str(&env, "iSTSi"),
+            &8u32,
+            &1000000000i128,
+            &env.register(MockKycRegistry, ()),
+            &router,
+            &Address::generate(&env)
+        );
+        client.transfer(&admin, &holder, &500000000i128);
+        
+        // Only the router can mirror a freeze
+        assert_eq!(
+            client.try_set_address_freeze(&admin, &holder, &true, &0),
+            Err(Ok(IntegrationError::RouterCallFailed))
+        );
+        
+        let expires_at = env.ledger().timestamp() + 100;
+        client.set_address_freeze(&router, &holder, &true, &expires_at);
+        assert!(client.is_address_frozen(&holder));
+        assert_eq!(
+            client.try_transfer(&holder, &recipient, &100000000i128),
+            Err(Ok(soroban_sdk::Error::from_contract_error(IntegrationError::AddressFrozen as u32)))
+        );
+        
+        // Timed freezes lapse on their own; indefinite ones need lifting
+        env.ledger().with_mut(|li| li.timestamp = expires_at);
+        assert!(!client.is_address_frozen(&holder));
+        client.transfer(&holder, &recipient, &100000000i128);
+        
+        client.set_address_freeze(&router, &holder, &true, &0);
+        assert!(client.try_transfer(&holder, &recipient, &100000000i128).is_err());
+        client.set_address_freeze(&router, &holder, &false, &0);
+        client.transfer(&holder, &recipient, &100000000i128);
+        assert_eq!(client.balance(&recipient), 200000000i128);
+    }
+    
+    #[test]
+    fn test_blacklist_screening_fails_closed_when_registry_is_unreachable() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(IntegratedISTSiToken, ());
+        let client = IntegratedISTSiTokenClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        
+        // The configured KYC registry is not a deployed contract
+        client.initialize(
+            &admin,
+            &String::from_str(&env, "Integrated iSTSi"),
+            &String::from_str(&env, "iSTSi"),
+            &8u32,
+            &1000000000i128,
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &Address::generate(&env)
+        );
+        
+        assert_eq!(
+            client.try_approve(&owner, &spender, &100000000i128, &(env.ledger().sequence() + 10)),
+            Err(Ok(soroban_sdk::Error::from_contract_error(IntegrationError::ComplianceCheckFailed as u32)))
+        );
+    }
+    
+    #[test]
+    fn test_sep41_token_interface() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(IntegratedISTSiToken, ());
+        let client = IntegratedISTSiTokenClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(
+            &admin,
+            &String::from_str(&env, "Integrated iSTSi"),
+            &String::from_str(&env, "iSTSi"),
+            &8u32,
+            &1000000000i128,
+            &env.register(MockKycRegistry, ()),
+            &Address::generate(&env),
+            &Address::generate(&env)
+        );
+        
+        // Drive the contract only through the standard SEP-41 client
+        let token = soroban_sdk::token::TokenClient::new(&env, &contract_id);
+        let holder = Address::generate(&env);
+        let spender = Address::generate(&env);
+        
+        assert_eq!(token.name(), String::from_str(&env, "Integrated iSTSi"));
+        assert_eq!(token.symbol(), String::from_str(&env, "iSTSi"));
+        assert_eq!(token.decimals(), 8);
+        
+        token.transfer(&admin, &holder, &400000000i128);
+        assert_eq!(token.balance(&holder), 400000000i128);
+        
+        token.approve(&holder, &spender, &300000000i128, &(env.ledger().sequence() + 10));
+        assert_eq!(token.allowance(&holder, &spender), 300000000i128);
+        token.transfer_from(&spender, &holder, &admin, &100000000i128);
+        token.burn_from(&spender, &holder, &100000000i128);
+        assert_eq!(token.allowance(&holder, &spender), 100000000i128);
+        
+        token.burn(&holder, &50000000i128);
+        assert_eq!(token.balance(&holder), 150000000i128);
+        assert_eq!(client.total_supply(), 850000000i128);
+    }
+    
+    #[test]
+    fn test_balance_at_reads_epoch_snapshots_within_retention() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(IntegratedISTSiToken, ());
+        let client = IntegratedISTSiTokenClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let holder = Address::generate(&env);
+        client.initialize(
+            &admin,
+            &String::from_str(&env, "Integrated iSTSi"),
+            &String::from_str(&env, "iSTSi"),
+            &8u32,
+            &1000000000i128,
+            &env.register(MockKycRegistry, ()),
+            &Address::generate(&env),
+            &Address::generate(&env)
+        );
+        client.set_snapshot_policy(&SnapshotPolicy { epoch_seconds: 100, retention_epochs: 3 });
+        
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        client.transfer(&admin, &holder, &100);
+        // A second change in the same epoch replaces its snapshot
+        env.ledger().with_mut(|li| li.timestamp = 1_050);
+        client.transfer(&admin, &holder, &50);
+        env.ledger().with_mut(|li| li.timestamp = 1_200);
+        client.burn(&holder, &30);
+        
+        assert_eq!(client.get_balance_snapshots(&holder, &0, &u64::MAX).len(), 2);
+        assert_eq!(client.balance_at(&holder, &999), None);
+        assert_eq!(client.balance_at(&holder, &1_100), Some(150));
+        assert_eq!(client.balance_at(&holder, &1_200), Some(120));
+        assert_eq!(client.balance_at(&Address::generate(&env), &1_100), Some(0));
+        
+        // Past the retention window only the newest older snapshot is kept
+        env.ledger().with_mut(|li| li.timestamp = 1_700);
+        client.transfer(&holder, &admin, &20);
+        let snapshots = client.get_balance_snapshots(&holder, &0, &u64::MAX);
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!((snapshots.get_unchecked(0).epoch, snapshots.get_unchecked(1).epoch), (12, 17));
+        assert_eq!(client.balance_at(&holder, &1_100), None);
+        assert_eq!(client.balance_at(&holder, &1_500), Some(120));
+        
+        env.ledger().with_mut(|li| li.timestamp = 2_100);
+        assert_eq!(client.prune_balance_snapshots(&holder), 1);
+        assert_eq!(client.balance_at(&holder, &2_000), Some(100));
+    }
+    
+    #[test]
+    fn test_long_snapshot_history_is_searched_and_pruned_in_bounded_steps() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(IntegratedISTSiToken, ());
+        let client = IntegratedISTSiTokenClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let holder = Address::generate(&env);
+        client.initialize(
+            &admin,
+            &String::from_str(&env, "Integrated iSTSi"),
+            &String::from_str(&env, "iSTSi"),
+            &8u32,
+            &1000000000i128,
+            &env.register(MockKycRegistry, ()),
+            &Address::generate(&env),
+            &Address::generate(&env)
+        );
+        client.set_snapshot_policy(&SnapshotPolicy { epoch_seconds: 100, retention_epochs: 400 });
+        
+        // One transfer per epoch; the holder ends epoch n with n + 1 tokens
+        for n in 0..80u64 {
+            env.ledger().with_mut(|li| li.timestamp = 1_000 + n * 100);
+            client.transfer(&admin, &holder, &1);
+        }
+        assert_eq!(client.get_balance_snapshots(&holder, &0, &u64::MAX).len(), 80);
+        assert_eq!(client.balance_at(&holder, &1_000), Some(1));
+        assert_eq!(client.balance_at(&holder, &4_250), Some(33));
+        assert_eq!(client.balance_at(&holder, &u64::MAX), Some(80));
+        
+        let window = client.get_balance_snapshots(&holder, &2_000, &2_300);
+        assert_eq!(window.len(), 3);
+        assert_eq!(window.get_unchecked(0).balance, 11);
+        
+        // Shrinking retention leaves a backlog that is cleared a batch at a time
+        client.set_snapshot_policy(&SnapshotPolicy { epoch_seconds: 100, retention_epochs: 1 });
+        assert_eq!(client.prune_balance_snapshots(&holder), 50);
+        assert_eq!(client.prune_balance_snapshots(&holder), 28);
+        assert_eq!(client.prune_balance_snapshots(&holder), 0);
+        assert_eq!(client.get_balance_snapshots(&holder, &0, &u64::MAX).len(), 2);
+        assert_eq!(client.balance_at(&holder, &8_850), Some(79));
+    }
+    
     #[test]
     fn test_unauthorized_integration_operations() {
         let env = Env::default();
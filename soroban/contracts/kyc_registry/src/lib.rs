@@ -1,7 +1,7 @@
 #![no_std]
 use soroban_sdk::{
     contract, contractimpl, contracttype, contracterror, symbol_short, vec, panic_with_error,
-    Address, Env, Map, Vec, String
+    Address, BytesN, Env, IntoVal, Map, Vec, String, Symbol
 };
 
 /// KYC Registry Contract for iSTSi Compliance Framework
@@ -92,6 +92,30 @@ pub enum DataKey {
     
     /// Integration hooks
     IntegrationRouter,            // Address of the integration router
+
+    /// Tier change workflow
+    TierChangeRequest(u64),       // Request ID -> TierChangeRequest
+    PendingTierChange(String),    // Customer ID -> pending request ID
+    PendingTierChanges,           // List of pending request IDs
+    TierChangeCount,              // Last issued request ID
+
+    /// Sanctions blacklist
+    Blacklist(Address),           // Address -> BlacklistEntry
+    BlacklistVersion,             // Hash of the last imported external list
+    ImportedBlacklistVersion(BytesN<32>), // List version hash -> number of addresses imported
+
+    /// Travel rule
+    TravelRuleThreshold(String),  // Jurisdiction -> withdrawal amount requiring a travel-rule payload
+
+    /// Tier limit policy
+    TierPolicy(KYCTier),          // Tier -> current TierPolicy
+    TierPolicyVersion(KYCTier, u32), // (Tier, version) -> TierPolicy as published at that version
+
+    /// KYC documents
+    Document(u64),                // Document ID -> KycDocument
+    CustomerDocuments(String),    // Customer ID -> document IDs on file
+    ActiveDocuments,              // Document IDs on file for any customer, for expiry scans
+    DocumentCount,                // Last issued document ID
 }
 
 /// Global registry settings
@@ -119,6 +143,111 @@ pub struct AuditLogEntry {
     pub notes: String,
 }
 
+/// Review state of a tier change request
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TierChangeStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// Customer-initiated tier change awaiting compliance review
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierChangeRequest {
+    pub request_id: u64,
+    pub customer_id: String,
+    pub requester: Address,
+    pub current_tier: KYCTier,     // Tier at the time of the request
+    pub requested_tier: KYCTier,
+    pub documents_hash: BytesN<32>, // Hash of the off-chain document bundle
+    pub status: TierChangeStatus,
+    pub requested_at: u64,
+    pub reviewed_at: u64,          // 0 while pending
+    pub reviewer: Option<Address>,
+    pub review_notes: String,
+}
+
+/// Blacklisted address with the reason it was listed
+///
+/// Reason codes: 1 = sanctions list match, 2 = fraud, 3 = law enforcement
+/// request, 4 = internal risk decision. Manual entries carry no list version.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlacklistEntry {
+    pub address: Address,
+    pub reason_code: u32,
+    pub list_version: Option<BytesN<32>>, // External list the entry was imported from
+    pub added_at: u64,
+    pub added_by: Address,
+}
+
+/// Limits and requirements applied to every customer of a tier
+///
+/// Amounts are in satoshis. Integrations derive user limits from the policy
+/// of the customer's effective tier, so edits apply to all customers of the
+/// tier without per-user writes. Version 0 is the built-in default.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierPolicy {
+    pub daily_deposit_limit: u64,
+    pub monthly_deposit_limit: u64,
+    pub daily_withdrawal_limit: u64,
+    pub monthly_withdrawal_limit: u64,
+    pub daily_exchange_limit: u64,
+    pub monthly_exchange_limit: u64,
+    pub min_confirmations: u32,     // Bitcoin confirmations required before minting
+    pub enhanced_verification_limit: u64, // Single operations at or above this need enhanced verification
+    pub version: u32,
+    pub updated_at: u64,
+    pub updated_by: Address,
+}
+
+/// Result of one request in a `verify_compliance_batch` call
+///
+/// Reason codes: 0 = approved, 1 = blacklisted, 2 = address not registered,
+/// 3 = KYC expired, 4 = sanctions not cleared, 5 = tier below the operation's
+/// requirement, 6 = operation disabled for the tier, 7 = amount above the
+/// single transaction limit, 8 = unknown operation code.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComplianceOutcome {
+    pub user: Address,
+    pub op_code: u32,
+    pub amount: i128,
+    pub approved: bool,
+    pub reason_code: u32,
+}
+
+/// Kind of document held on file for a customer
+///
+/// Passports, national IDs and driving licences are identity documents: a
+/// customer whose identity documents have all expired loses their tier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DocumentType {
+    Passport,
+    NationalId,
+    DriversLicense,
+    ProofOfAddress,
+    SourceOfFunds,
+}
+
+/// Hash of a customer document kept off-chain, with its validity period
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycDocument {
+    pub document_id: u64,
+    pub customer_id: String,
+    pub document_type: DocumentType,
+    pub document_hash: BytesN<32>,  // Hash of the document file
+    pub issued_at: u64,
+    pub expires_at: u64,           // 0 = does not expire
+    pub recorded_at: u64,
+    pub recorded_by: Address,
+}
+
 const DAY_IN_LEDGERS: u64 = 17280; // Approximately 1 day in ledgers (5s each)
 
 #[contractimpl]
@@ -228,6 +357,7 @@ impl KYCRegistry {
             (symbol_short!("kyc_reg"), customer_id.clone()),
             (kyc_tier, addresses.len())
         );
+        Self::notify_customer_change(&env, &customer_record);
     }
     
     /// Update customer KYC tier
@@ -293,6 +423,7 @@ impl KYCRegistry {
             (symbol_short!("kyc_tier"), customer_id),
             (old_tier, new_tier)
         );
+        Self::notify_customer_change(&env, &customer);
     }
     /// Add approved address to customer record
     pub fn add_approved_address(
@@ -333,8 +464,9 @@ impl KYCRegistry {
         // Emit address added event
         env.events().publish(
             (symbol_short!("kyc_addr"), customer_id),
-            (symbol_short!("added"), address)
+            (symbol_short!("added"), address.clone())
         );
+        Self::notify_compliance_change(&env, vec![&env, address]);
     }
     
     /// Remove approved address from customer record
@@ -381,8 +513,9 @@ impl KYCRegistry {
         // Emit address removed event
         env.events().publish(
             (symbol_short!("kyc_addr"), customer_id),
-            (symbol_short!("removed"), address)
+            (symbol_short!("removed"), address.clone())
         );
+        Self::notify_compliance_change(&env, vec![&env, address]);
     }
     
     /// Check if address is approved for operation type
@@ -392,72 +525,39 @@ impl KYCRegistry {
         operation: OperationType,
         amount: i128
     ) -> bool {
-        if !Self::get_global_settings_internal(&env).registry_enabled {
-            return true; // Registry disabled - allow all operations
-        }
-        
-        // Get customer ID from address
-        let customer_id = match env.storage().persistent().get::<DataKey, String>(
-            &DataKey::AddressToCustomer(address.clone())
-        ) {
-            Some(id) => id,
-            None => return false, // Address not registered
-        };
-        
-        // Get customer record
-        let customer = match Self::get_customer_record_internal(&env, &customer_id) {
-            Some(record) => record,
-            None => return false, // Customer record not found
-        };
-        
-        // Check if KYC is expired
-        if customer.expires_at > 0 && u64::from(env.ledger().timestamp()) > customer.expires_at {
-            return false; // KYC expired
-        }
-        
-        // Check sanctions clearance if required
-        let settings = Self::get_global_settings_internal(&env);
-        if settings.sanctions_required && !customer.sanctions_cleared {
-            return false; // Sanctions not cleared
-        }
-        
-        // Check minimum tier requirement for operation
-        let required_tier = Self::get_required_tier_internal(&env, &operation);
-        if !Self::tier_meets_requirement(&customer.kyc_tier, &required_tier) {
-            return false; // Insufficient KYC tier
-        }
-        
-        // Check operation limits
-        let limits = Self::get_tier_limits_internal(&env, &customer.kyc_tier, &operation);
-        if !limits.enabled {
-            return false; // Operation disabled for this tier
-        }
-        
-        // Check amount limits
-        if amount > limits.single_tx_limit {
-            return false; // Amount exceeds single transaction limit
-        }
-        
-        // TODO: Implement daily/monthly limit tracking
-        
-        // All checks passed, return true
-        true
+        Self::compliance_reason(&env, &address, &operation, amount) == 0
     }
 
     /// Simple approval endpoint for cross-contract calls that avoids enum ABI
     /// op_code mapping:
     /// 0 = Transfer, 1 = Mint, 2 = Burn, 3 = Deposit, 4 = Withdraw, 5 = Exchange
     pub fn is_approved_simple(env: Env, address: Address, op_code: u32, amount: i128) -> bool {
-        let operation = match op_code {
-            0 => OperationType::Transfer,
-            1 => OperationType::Mint,
-            2 => OperationType::Burn,
-            3 => OperationType::Deposit,
-            4 => OperationType::Withdraw,
-            5 => OperationType::Exchange,
-            _ => return false,
-        };
-        Self::is_approved_for_operation(env, address, operation, amount)
+        match Self::operation_from_code(op_code) {
+            Some(operation) => Self::is_approved_for_operation(env, address, operation, amount),
+            None => false,
+        }
+    }
+
+    /// Check many `(address, op_code, amount)` requests in one call
+    ///
+    /// Uses the `is_approved_simple` op codes and returns one outcome per
+    /// request, in request order, with the reason the request was refused.
+    pub fn verify_compliance_batch(env: Env, requests: Vec<(Address, u32, i128)>) -> Vec<ComplianceOutcome> {
+        let mut outcomes = Vec::new(&env);
+        for (user, op_code, amount) in requests.iter() {
+            let reason_code = match Self::operation_from_code(op_code) {
+                Some(operation) => Self::compliance_reason(&env, &user, &operation, amount),
+                None => 8, // Unknown operation code
+            };
+            outcomes.push_back(ComplianceOutcome {
+                user,
+                op_code,
+                amount,
+                approved: reason_code == 0,
+                reason_code,
+            });
+        }
+        outcomes
     }
     
     /// Helper method to require the caller to be an admin
@@ -539,6 +639,12 @@ impl KYCRegistry {
         env.storage().persistent().get(&DataKey::AddressToCustomer(address))
     }
 
+    /// Get the jurisdiction of the customer owning a registered address
+    pub fn get_jurisdiction_by_address(env: Env, address: Address) -> Option<String> {
+        let customer_id = env.storage().persistent().get::<_, String>(&DataKey::AddressToCustomer(address))?;
+        Self::get_customer_record_internal(&env, &customer_id).map(|rec| rec.jurisdiction)
+    }
+
     /// Return the numeric tier code for a registered address
     /// 0=None, 1=Basic, 2=Verified, 3=Enhanced, 4=Institutional
     pub fn get_tier_code_by_address(env: Env, address: Address) -> u32 {
@@ -548,148 +654,829 @@ impl KYCRegistry {
         let Some(rec) = env.storage().persistent().get::<_, CustomerRecord>(&DataKey::CustomerRecord(customer_id)) else {
             return 0;
         };
-        match rec.kyc_tier {
-            KYCTier::None => 0,
-            KYCTier::Basic => 1,
-            KYCTier::Verified => 2,
-            KYCTier::Enhanced => 3,
-            KYCTier::Institutional => 4,
-        }
+        Self::tier_level(&Self::get_effective_tier_internal(&env, &rec))
     }
 
     // =====================
-    // Admin management APIs
+    // Tier change workflow
     // =====================
 
-    /// Enable/disable the registry
-    pub fn set_registry_enabled(env: Env, caller: Address, enabled: bool) {
-        Self::require_admin(&env, &caller);
-        let mut s = Self::get_global_settings_internal(&env);
-        s.registry_enabled = enabled;
-        env.storage().instance().set(&DataKey::GlobalSettings, &s);
-env.events().publish((symbol_short!("kyc_set"), symbol_short!("reg_en")), enabled);
-    }
+    /// Request a tier change for the caller's customer record
+    ///
+    /// While the request is pending the lower of the current and requested
+    /// tier applies, so downgrades take effect immediately and upgrades only
+    /// after a compliance officer approves them.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `user` - Registered address of the customer (must authorize)
+    /// * `requested_tier` - Tier the customer is asking for
+    /// * `documents_hash` - Hash of the supporting documents submitted off-chain
+    ///
+    /// # Panics
+    /// - If the registry is disabled
+    /// - If the address is not registered
+    /// - If the customer already has a pending request
+    /// - If the requested tier equals the current tier
+    pub fn request_tier_upgrade(
+        env: Env,
+        user: Address,
+        requested_tier: KYCTier,
+        documents_hash: BytesN<32>
+    ) -> u64 {
+        user.require_auth();
+        Self::require_registry_enabled(&env);
 
-    /// Set strict mode
-    pub fn set_strict_mode(env: Env, caller: Address, strict: bool) {
-        Self::require_admin(&env, &caller);
-        let mut s = Self::get_global_settings_internal(&env);
-        s.strict_mode = strict;
-        env.storage().instance().set(&DataKey::GlobalSettings, &s);
-env.events().publish((symbol_short!("kyc_set"), symbol_short!("strict")), strict);
-    }
+        let customer_id: String = env.storage().persistent()
+            .get(&DataKey::AddressToCustomer(user.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, KYCError::NotFound));
+        let customer = Self::get_customer_record_internal(&env, &customer_id)
+            .unwrap_or_else(|| panic_with_error!(&env, KYCError::NotFound));
 
-    /// Replace all global settings
-    pub fn set_global_settings(env: Env, caller: Address, settings: GlobalSettings) {
-        Self::require_admin(&env, &caller);
-        env.storage().instance().set(&DataKey::GlobalSettings, &settings);
-env.events().publish((symbol_short!("kyc_set"), symbol_short!("update")), (settings.registry_enabled, settings.strict_mode));
-    }
+        if env.storage().persistent().has(&DataKey::PendingTierChange(customer_id.clone())) {
+            panic_with_error!(&env, KYCError::AlreadyExists);
+        }
+        if customer.kyc_tier == requested_tier {
+            panic_with_error!(&env, KYCError::InvalidInput);
+        }
 
-    /// Add a compliance officer
-    pub fn add_compliance_officer(env: Env, caller: Address, officer: Address) {
-        Self::require_admin(&env, &caller);
-        let mut officers: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::ComplianceOfficers)
+        let request_id: u64 = env.storage().instance().get(&DataKey::TierChangeCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::TierChangeCount, &request_id);
+
+        let request = TierChangeRequest {
+            request_id,
+            customer_id: customer_id.clone(),
+            requester: user.clone(),
+            current_tier: customer.kyc_tier.clone(),
+            requested_tier: requested_tier.clone(),
+            documents_hash,
+            status: TierChangeStatus::Pending,
+            requested_at: env.ledger().timestamp(),
+            reviewed_at: 0,
+            reviewer: None,
+            review_notes: String::from_str(&env, ""),
+        };
+
+        env.storage().persistent().set(&DataKey::TierChangeRequest(request_id), &request);
+        env.storage().persistent().set(&DataKey::PendingTierChange(customer_id.clone()), &request_id);
+
+        let mut pending: Vec<u64> = env.storage().persistent()
+            .get(&DataKey::PendingTierChanges)
             .unwrap_or(Vec::new(&env));
-        for o in officers.iter() {
-            if o == officer {
-                panic_with_error!(&env, KYCError::AlreadyExists);
-            }
-        }
-        officers.push_back(officer.clone());
-        env.storage().instance().set(&DataKey::ComplianceOfficers, &officers);
-env.events().publish((symbol_short!("kyc_ofc"), symbol_short!("add")), officer);
+        pending.push_back(request_id);
+        env.storage().persistent().set(&DataKey::PendingTierChanges, &pending);
+
+        env.events().publish(
+            (symbol_short!("kyc_req"), customer_id),
+            (request_id, customer.kyc_tier.clone(), requested_tier)
+        );
+        Self::notify_customer_change(&env, &customer);
+
+        request_id
     }
 
-    /// Remove a compliance officer
-    pub fn remove_compliance_officer(env: Env, caller: Address, officer: Address) {
-        Self::require_admin(&env, &caller);
-        let officers: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::ComplianceOfficers)
-            .unwrap_or(Vec::new(&env));
-        let mut next = Vec::new(&env);
-        let mut found = false;
-        for o in officers.iter() {
-            if o != officer {
-                next.push_back(o);
-            } else {
-                found = true;
-            }
+    /// Approve a pending tier change and apply the requested tier
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - Address of the reviewer (must be a compliance officer)
+    /// * `request_id` - ID of the pending request
+    /// * `notes` - Review notes (for audit purposes)
+    ///
+    /// # Panics
+    /// - If the caller is not a compliance officer
+    /// - If the request doesn't exist or is not pending
+    pub fn approve_tier_change(env: Env, caller: Address, request_id: u64, notes: String) {
+        Self::require_compliance_officer(&env, &caller);
+        Self::require_registry_enabled(&env);
+
+        let request = Self::close_tier_change_request(&env, &caller, request_id, TierChangeStatus::Approved, notes.clone());
+
+        let mut customer = Self::get_customer_record_internal(&env, &request.customer_id)
+            .unwrap_or_else(|| panic_with_error!(&env, KYCError::NotFound));
+        let old_tier = customer.kyc_tier.clone();
+
+        if old_tier != request.requested_tier {
+            customer.kyc_tier = request.requested_tier.clone();
+            customer.updated_at = env.ledger().timestamp();
+            env.storage().persistent().set(&DataKey::CustomerRecord(request.customer_id.clone()), &customer);
+
+            Self::update_tier_stats(&env, &old_tier, -1);
+            Self::update_tier_stats(&env, &request.requested_tier, 1);
         }
-        if !found { panic_with_error!(&env, KYCError::NotFound); }
-        env.storage().instance().set(&DataKey::ComplianceOfficers, &next);
-env.events().publish((symbol_short!("kyc_ofc"), symbol_short!("remove")), officer);
+
+        Self::log_audit_entry(&env, AuditLogEntry {
+            timestamp: env.ledger().timestamp(),
+            action: String::from_str(&env, "tier_approve"),
+            customer_id: request.customer_id.clone(),
+            address: request.requester.clone(),
+            old_tier: old_tier.clone(),
+            new_tier: request.requested_tier.clone(),
+            officer: caller.clone(),
+            notes,
+        });
+
+        env.events().publish(
+            (symbol_short!("kyc_rev"), request.customer_id),
+            (request_id, true, caller, old_tier, request.requested_tier)
+        );
+        Self::notify_customer_change(&env, &customer);
     }
 
-    /// Set required minimum tier per operation
-    pub fn set_required_tier(env: Env, caller: Address, operation: OperationType, tier: KYCTier) {
-        Self::require_admin(&env, &caller);
-        env.storage().persistent().set(&DataKey::RequiredTier(operation.clone()), &tier);
-env.events().publish((symbol_short!("req_tier"), operation), tier);
+    /// Reject a pending tier change, restoring the customer's current limits
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - Address of the reviewer (must be a compliance officer)
+    /// * `request_id` - ID of the pending request
+    /// * `notes` - Reason for the rejection (required)
+    ///
+    /// # Panics
+    /// - If the caller is not a compliance officer
+    /// - If the notes are empty
+    /// - If the request doesn't exist or is not pending
+    pub fn reject_tier_change(env: Env, caller: Address, request_id: u64, notes: String) {
+        Self::require_compliance_officer(&env, &caller);
+
+        if notes.is_empty() {
+            panic_with_error!(&env, KYCError::InvalidInput);
+        }
+
+        let request = Self::close_tier_change_request(&env, &caller, request_id, TierChangeStatus::Rejected, notes.clone());
+
+        Self::log_audit_entry(&env, AuditLogEntry {
+            timestamp: env.ledger().timestamp(),
+            action: String::from_str(&env, "tier_reject"),
+            customer_id: request.customer_id.clone(),
+            address: request.requester.clone(),
+            old_tier: request.current_tier.clone(),
+            new_tier: request.current_tier.clone(),
+            officer: caller.clone(),
+            notes,
+        });
+
+        env.events().publish(
+            (symbol_short!("kyc_rev"), request.customer_id.clone()),
+            (request_id, false, caller, request.current_tier, request.requested_tier)
+        );
+        if let Some(customer) = Self::get_customer_record_internal(&env, &request.customer_id) {
+            Self::notify_customer_change(&env, &customer);
+        }
     }
 
-    /// Set limits for a (tier, operation) pair
-    pub fn set_tier_limits(env: Env, caller: Address, tier: KYCTier, operation: OperationType, limits: OperationLimits) {
-        Self::require_admin(&env, &caller);
-        env.storage().persistent().set(&DataKey::TierLimits(tier.clone(), operation.clone()), &limits);
-env.events().publish((symbol_short!("kyc_lims"), (tier, operation)), (limits.single_tx_limit, limits.daily_limit, limits.monthly_limit));
+    /// Get a tier change request by ID
+    pub fn get_tier_change_request(env: Env, request_id: u64) -> Option<TierChangeRequest> {
+        env.storage().persistent().get(&DataKey::TierChangeRequest(request_id))
     }
 
-    /// Set sanctions cleared flag for a customer
-    pub fn set_sanctions_status(env: Env, caller: Address, customer_id: String, cleared: bool) {
-        Self::require_admin(&env, &caller);
-        let mut rec = Self::get_customer_record_internal(&env, &customer_id).unwrap_or_else(|| panic_with_error!(&env, KYCError::NotFound));
-        rec.sanctions_cleared = cleared;
-        rec.updated_at = env.ledger().timestamp();
-        env.storage().persistent().set(&DataKey::CustomerRecord(customer_id.clone()), &rec);
-env.events().publish((symbol_short!("kyc_cust"), symbol_short!("sanct")), (customer_id, cleared));
+    /// Get the pending tier change request for a customer, if any
+    pub fn get_pending_tier_change(env: Env, customer_id: String) -> Option<TierChangeRequest> {
+        let request_id: u64 = env.storage().persistent().get(&DataKey::PendingTierChange(customer_id))?;
+        env.storage().persistent().get(&DataKey::TierChangeRequest(request_id))
     }
 
-    /// Set expiration for a customer
-    pub fn set_customer_expiration(env: Env, caller: Address, customer_id: String, expires_at: u64) {
-        Self::require_admin(&env, &caller);
-        let mut rec = Self::get_customer_record_internal(&env, &customer_id).unwrap_or_else(|| panic_with_error!(&env, KYCError::NotFound));
-        rec.expires_at = expires_at;
-        rec.updated_at = env.ledger().timestamp();
-        env.storage().persistent().set(&DataKey::CustomerRecord(customer_id.clone()), &rec);
-env.events().publish((symbol_short!("kyc_cust"), symbol_short!("expire")), (customer_id, expires_at));
+    /// List all tier change requests awaiting review, oldest first
+    pub fn get_pending_tier_changes(env: Env) -> Vec<TierChangeRequest> {
+        let pending: Vec<u64> = env.storage().persistent()
+            .get(&DataKey::PendingTierChanges)
+            .unwrap_or(Vec::new(&env));
+
+        let mut requests = Vec::new(&env);
+        for request_id in pending.iter() {
+            if let Some(request) = env.storage().persistent().get(&DataKey::TierChangeRequest(request_id)) {
+                requests.push_back(request);
+            }
+        }
+        requests
     }
 
-    /// Update metadata entry for a customer (upsert)
-    pub fn set_customer_metadata(env: Env, caller: Address, customer_id: String, key: String, value: String) {
-        Self::require_admin(&env, &caller);
-        let mut rec = Self::get_customer_record_internal(&env, &customer_id).unwrap_or_else(|| panic_with_error!(&env, KYCError::NotFound));
-        rec.metadata.set(key.clone(), value.clone());
-        rec.updated_at = env.ledger().timestamp();
-        env.storage().persistent().set(&DataKey::CustomerRecord(customer_id.clone()), &rec);
-env.events().publish((symbol_short!("kyc_cust"), symbol_short!("meta")), (customer_id, key));
+    /// Get the tier currently used for compliance checks on a customer
+    pub fn get_effective_tier(env: Env, customer_id: String) -> Option<KYCTier> {
+        let customer = Self::get_customer_record_internal(&env, &customer_id)?;
+        Some(Self::get_effective_tier_internal(&env, &customer))
     }
-    
+
     // =====================
-    // Integration Functions
+    // Sanctions blacklist
     // =====================
-    
-    /// Set integration router address (admin only)
-    pub fn set_integration_router(env: Env, caller: Address, router_address: Address) {
-        Self::require_admin(&env, &caller);
-        env.storage().instance().set(&DataKey::IntegrationRouter, &router_address);
-        
+
+    /// Add an address to the blacklist
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - Address of the caller (must be a compliance officer)
+    /// * `address` - Address to blacklist
+    /// * `reason_code` - Why the address is listed (see `BlacklistEntry`)
+    ///
+    /// # Panics
+    /// - If the caller is not a compliance officer
+    /// - If the reason code is zero
+    /// - If the address is already blacklisted
+    pub fn add_to_blacklist(env: Env, caller: Address, address: Address, reason_code: u32) {
+        Self::require_compliance_officer(&env, &caller);
+
+        if reason_code == 0 {
+            panic_with_error!(&env, KYCError::InvalidInput);
+        }
+        if env.storage().persistent().has(&DataKey::Blacklist(address.clone())) {
+            panic_with_error!(&env, KYCError::AlreadyExists);
+        }
+
+        let entry = BlacklistEntry {
+            address: address.clone(),
+            reason_code,
+            list_version: None,
+            added_at: env.ledger().timestamp(),
+            added_by: caller,
+        };
+        env.storage().persistent().set(&DataKey::Blacklist(address.clone()), &entry);
+
         env.events().publish(
-            (symbol_short!("router"), router_address),
-            (symbol_short!("set"), symbol_short!("ok"))
+            (symbol_short!("kyc_bl"), symbol_short!("add")),
+            (address.clone(), reason_code)
         );
+        Self::notify_compliance_change(&env, vec![&env, address]);
     }
-    
-    /// Get integration router address
-    pub fn get_integration_router(env: Env) -> Option<Address> {
-        env.storage().instance().get(&DataKey::IntegrationRouter)
+
+    /// Remove an address from the blacklist
+    ///
+    /// # Panics
+    /// - If the caller is not a compliance officer
+    /// - If the address is not blacklisted
+    pub fn remove_from_blacklist(env: Env, caller: Address, address: Address) {
+        Self::require_compliance_officer(&env, &caller);
+
+        if !env.storage().persistent().has(&DataKey::Blacklist(address.clone())) {
+            panic_with_error!(&env, KYCError::NotFound);
+        }
+        env.storage().persistent().remove(&DataKey::Blacklist(address.clone()));
+
+        env.events().publish(
+            (symbol_short!("kyc_bl"), symbol_short!("remove")),
+            address.clone()
+        );
+        Self::notify_compliance_change(&env, vec![&env, address]);
     }
-    
+
+    /// Apply an update from an external sanctions list
+    ///
+    /// Each update is the delta between two list versions and must name the
+    /// version it builds on, so a skipped or replayed update is rejected.
+    /// Addresses that are already blacklisted keep their existing entry, and
+    /// delisting only removes entries that came from an imported list;
+    /// manual entries stay until a compliance officer removes them.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - Address of the caller (must be a compliance officer)
+    /// * `list_version` - Hash identifying the new list version
+    /// * `previous_version` - Version the delta applies to (None for the first import)
+    /// * `added` - Addresses that appear on the new list version
+    /// * `removed` - Addresses dropped from the list since `previous_version`
+    /// * `reason_code` - Reason code applied to every newly listed entry
+    ///
+    /// # Returns
+    /// Number of newly blacklisted and delisted addresses
+    pub fn import_blacklist(
+        env: Env,
+        caller: Address,
+        list_version: BytesN<32>,
+        previous_version: Option<BytesN<32>>,
+        added: Vec<Address>,
+        removed: Vec<Address>,
+        reason_code: u32
+    ) -> (u32, u32) {
+        Self::require_compliance_officer(&env, &caller);
+
+        if reason_code == 0 || (added.is_empty() && removed.is_empty()) {
+            panic_with_error!(&env, KYCError::InvalidInput);
+        }
+        if env.storage().persistent().has(&DataKey::ImportedBlacklistVersion(list_version.clone())) {
+            panic_with_error!(&env, KYCError::AlreadyExists);
+        }
+        let current_version: Option<BytesN<32>> = env.storage().instance().get(&DataKey::BlacklistVersion);
+        if current_version != previous_version {
+            panic_with_error!(&env, KYCError::InvalidInput);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut changed: Vec<Address> = Vec::new(&env);
+        let mut listed = 0u32;
+        for address in added.iter() {
+            let key = DataKey::Blacklist(address.clone());
+            if env.storage().persistent().has(&key) {
+                continue;
+            }
+            env.storage().persistent().set(&key, &BlacklistEntry {
+                address: address.clone(),
+                reason_code,
+                list_version: Some(list_version.clone()),
+                added_at: now,
+                added_by: caller.clone(),
+            });
+            changed.push_back(address);
+            listed += 1;
+        }
+
+        let mut delisted = 0u32;
+        for address in removed.iter() {
+            let key = DataKey::Blacklist(address.clone());
+            let imported = env.storage().persistent()
+                .get::<DataKey, BlacklistEntry>(&key)
+                .map(|entry| entry.list_version.is_some())
+                .unwrap_or(false);
+            if !imported {
+                continue;
+            }
+            env.storage().persistent().remove(&key);
+            changed.push_back(address);
+            delisted += 1;
+        }
+
+        env.storage().persistent().set(&DataKey::ImportedBlacklistVersion(list_version.clone()), &(listed + delisted));
+        env.storage().instance().set(&DataKey::BlacklistVersion, &list_version);
+
+        env.events().publish(
+            (symbol_short!("kyc_bl"), symbol_short!("import")),
+            (list_version, listed, delisted)
+        );
+        if !changed.is_empty() {
+            Self::notify_compliance_change(&env, changed);
+        }
+
+        (listed, delisted)
+    }
+
+    /// Get the blacklist entry for an address, if listed
+    pub fn get_blacklist_status(env: Env, address: Address) -> Option<BlacklistEntry> {
+        env.storage().persistent().get(&DataKey::Blacklist(address))
+    }
+
+    /// Check whether an address is blacklisted
+    pub fn is_blacklisted(env: Env, address: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Blacklist(address))
+    }
+
+    /// Get the version hash of the most recently imported external list
+    pub fn get_blacklist_version(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::BlacklistVersion)
+    }
+
+    // =====================
+    // Travel rule
+    // =====================
+
+    /// Set the withdrawal amount at or above which a travel-rule payload is required
+    ///
+    /// # Panics
+    /// - If the caller is not the admin
+    /// - If the threshold is not positive
+    pub fn set_travel_rule_threshold(env: Env, caller: Address, jurisdiction: String, threshold: i128) {
+        Self::require_admin(&env, &caller);
+
+        if threshold <= 0 || jurisdiction.is_empty() {
+            panic_with_error!(&env, KYCError::InvalidInput);
+        }
+        env.storage().persistent().set(&DataKey::TravelRuleThreshold(jurisdiction.clone()), &threshold);
+
+        env.events().publish(
+            (symbol_short!("kyc_trv"), symbol_short!("set")),
+            (jurisdiction, threshold)
+        );
+    }
+
+    /// Remove the travel-rule threshold for a jurisdiction
+    pub fn remove_travel_rule_threshold(env: Env, caller: Address, jurisdiction: String) {
+        Self::require_admin(&env, &caller);
+
+        if !env.storage().persistent().has(&DataKey::TravelRuleThreshold(jurisdiction.clone())) {
+            panic_with_error!(&env, KYCError::NotFound);
+        }
+        env.storage().persistent().remove(&DataKey::TravelRuleThreshold(jurisdiction.clone()));
+
+        env.events().publish(
+            (symbol_short!("kyc_trv"), symbol_short!("remove")),
+            jurisdiction
+        );
+    }
+
+    /// Get the travel-rule threshold for a jurisdiction
+    pub fn get_travel_rule_threshold(env: Env, jurisdiction: String) -> Option<i128> {
+        env.storage().persistent().get(&DataKey::TravelRuleThreshold(jurisdiction))
+    }
+
+    /// Check whether a withdrawal of `amount` by `address` needs a travel-rule payload
+    pub fn requires_travel_rule(env: Env, address: Address, amount: i128) -> bool {
+        let Some(customer_id) = env.storage().persistent().get::<_, String>(&DataKey::AddressToCustomer(address)) else {
+            return false;
+        };
+        let Some(customer) = Self::get_customer_record_internal(&env, &customer_id) else {
+            return false;
+        };
+
+        match env.storage().persistent().get::<_, i128>(&DataKey::TravelRuleThreshold(customer.jurisdiction)) {
+            Some(threshold) => amount >= threshold,
+            None => false,
+        }
+    }
+
+    // =====================
+    // Tier limit policy
+    // =====================
+
+    /// Publish a new policy version for a tier
+    ///
+    /// The version, timestamp and author in `policy` are ignored and set by
+    /// the registry. Returns the new version number.
+    ///
+    /// # Panics
+    /// - If the caller is not the admin
+    /// - If any daily limit exceeds its monthly limit
+    pub fn set_tier_policy(env: Env, caller: Address, tier: KYCTier, policy: TierPolicy) -> u32 {
+        Self::require_admin(&env, &caller);
+
+        if policy.daily_deposit_limit > policy.monthly_deposit_limit
+            || policy.daily_withdrawal_limit > policy.monthly_withdrawal_limit
+            || policy.daily_exchange_limit > policy.monthly_exchange_limit
+        {
+            panic_with_error!(&env, KYCError::InvalidInput);
+        }
+
+        let version = Self::get_tier_policy(env.clone(), tier.clone()).version + 1;
+        let policy = TierPolicy {
+            version,
+            updated_at: env.ledger().timestamp(),
+            updated_by: caller,
+            ..policy
+        };
+        env.storage().persistent().set(&DataKey::TierPolicy(tier.clone()), &policy);
+        env.storage().persistent().set(&DataKey::TierPolicyVersion(tier.clone(), version), &policy);
+
+        env.events().publish(
+            (symbol_short!("kyc_pol"), tier),
+            (version, policy.daily_deposit_limit, policy.daily_withdrawal_limit, policy.daily_exchange_limit)
+        );
+
+        version
+    }
+
+    /// Get the current policy for a tier
+    pub fn get_tier_policy(env: Env, tier: KYCTier) -> TierPolicy {
+        env.storage().persistent()
+            .get(&DataKey::TierPolicy(tier.clone()))
+            .unwrap_or_else(|| Self::default_tier_policy(&env, &tier))
+    }
+
+    /// Get a published policy version for a tier
+    pub fn get_tier_policy_version(env: Env, tier: KYCTier, version: u32) -> Option<TierPolicy> {
+        env.storage().persistent().get(&DataKey::TierPolicyVersion(tier, version))
+    }
+
+    /// Get the tier code and policy that apply to a registered address
+    ///
+    /// Uses the customer's effective tier, so pending downgrades apply immediately.
+    pub fn get_address_tier_policy(env: Env, address: Address) -> Option<(u32, TierPolicy)> {
+        let customer_id = env.storage().persistent().get::<_, String>(&DataKey::AddressToCustomer(address))?;
+        let customer = Self::get_customer_record_internal(&env, &customer_id)?;
+        let tier = Self::get_effective_tier_internal(&env, &customer);
+        Some((Self::tier_level(&tier), Self::get_tier_policy(env, tier)))
+    }
+
+    // =====================
+    // KYC documents
+    // =====================
+
+    /// Record a document on file for a customer
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - Address of the caller (must be a compliance officer)
+    /// * `customer_id` - Customer the document belongs to
+    /// * `document_type` - Kind of document
+    /// * `document_hash` - Hash of the document file kept off-chain
+    /// * `issued_at` - Issue date of the document
+    /// * `expires_at` - Expiry date of the document (0 = does not expire)
+    ///
+    /// # Returns
+    /// ID of the new document record
+    ///
+    /// # Panics
+    /// - If the caller is not a compliance officer
+    /// - If the customer doesn't exist
+    /// - If the hash is zero or the document expires before it was issued
+    pub fn add_document(
+        env: Env,
+        caller: Address,
+        customer_id: String,
+        document_type: DocumentType,
+        document_hash: BytesN<32>,
+        issued_at: u64,
+        expires_at: u64
+    ) -> u64 {
+        Self::require_compliance_officer(&env, &caller);
+
+        let customer = Self::get_customer_record_internal(&env, &customer_id)
+            .unwrap_or_else(|| panic_with_error!(&env, KYCError::NotFound));
+        if document_hash == BytesN::from_array(&env, &[0u8; 32]) || (expires_at != 0 && expires_at <= issued_at) {
+            panic_with_error!(&env, KYCError::InvalidInput);
+        }
+
+        let document_id: u64 = env.storage().instance().get(&DataKey::DocumentCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::DocumentCount, &document_id);
+
+        let document = KycDocument {
+            document_id,
+            customer_id: customer_id.clone(),
+            document_type: document_type.clone(),
+            document_hash,
+            issued_at,
+            expires_at,
+            recorded_at: env.ledger().timestamp(),
+            recorded_by: caller,
+        };
+        env.storage().persistent().set(&DataKey::Document(document_id), &document);
+
+        let mut documents = Self::get_customer_document_ids(&env, &customer_id);
+        documents.push_back(document_id);
+        env.storage().persistent().set(&DataKey::CustomerDocuments(customer_id.clone()), &documents);
+
+        let mut active: Vec<u64> = env.storage().persistent().get(&DataKey::ActiveDocuments).unwrap_or(Vec::new(&env));
+        active.push_back(document_id);
+        env.storage().persistent().set(&DataKey::ActiveDocuments, &active);
+
+        env.events().publish(
+            (symbol_short!("kyc_doc"), customer_id),
+            (symbol_short!("added"), document_id, document_type, expires_at)
+        );
+        Self::notify_customer_change(&env, &customer);
+
+        document_id
+    }
+
+    /// Remove a document from file, e.g. when it is revoked or replaced
+    ///
+    /// # Panics
+    /// - If the caller is not a compliance officer
+    /// - If the document doesn't exist
+    pub fn remove_document(env: Env, caller: Address, document_id: u64) {
+        Self::require_compliance_officer(&env, &caller);
+
+        let document: KycDocument = env.storage().persistent()
+            .get(&DataKey::Document(document_id))
+            .unwrap_or_else(|| panic_with_error!(&env, KYCError::NotFound));
+        env.storage().persistent().remove(&DataKey::Document(document_id));
+
+        let documents = Self::get_customer_document_ids(&env, &document.customer_id);
+        env.storage().persistent().set(
+            &DataKey::CustomerDocuments(document.customer_id.clone()),
+            &Self::without_document(&env, &documents, document_id)
+        );
+        let active: Vec<u64> = env.storage().persistent().get(&DataKey::ActiveDocuments).unwrap_or(Vec::new(&env));
+        env.storage().persistent().set(&DataKey::ActiveDocuments, &Self::without_document(&env, &active, document_id));
+
+        env.events().publish(
+            (symbol_short!("kyc_doc"), document.customer_id.clone()),
+            (symbol_short!("removed"), document_id)
+        );
+        if let Some(customer) = Self::get_customer_record_internal(&env, &document.customer_id) {
+            Self::notify_customer_change(&env, &customer);
+        }
+    }
+
+    /// Get a document record by ID
+    pub fn get_document(env: Env, document_id: u64) -> Option<KycDocument> {
+        env.storage().persistent().get(&DataKey::Document(document_id))
+    }
+
+    /// List the documents on file for a customer, oldest first
+    pub fn get_customer_documents(env: Env, customer_id: String) -> Vec<KycDocument> {
+        let mut documents = Vec::new(&env);
+        for document_id in Self::get_customer_document_ids(&env, &customer_id).iter() {
+            if let Some(document) = Self::get_document(env.clone(), document_id) {
+                documents.push_back(document);
+            }
+        }
+        documents
+    }
+
+    /// List documents that expire within `within` seconds from now, soonest first
+    ///
+    /// Already expired documents are not included.
+    pub fn get_expiring_documents(env: Env, within: u64) -> Vec<KycDocument> {
+        let now = env.ledger().timestamp();
+        let deadline = now.saturating_add(within);
+        let active: Vec<u64> = env.storage().persistent().get(&DataKey::ActiveDocuments).unwrap_or(Vec::new(&env));
+
+        let mut expiring: Vec<KycDocument> = Vec::new(&env);
+        for document_id in active.iter() {
+            let document = match Self::get_document(env.clone(), document_id) {
+                Some(document) if document.expires_at > now && document.expires_at <= deadline => document,
+                _ => continue,
+            };
+            let mut position = expiring.len();
+            while position > 0 && expiring.get_unchecked(position - 1).expires_at > document.expires_at {
+                position -= 1;
+            }
+            expiring.insert(position, document);
+        }
+        expiring
+    }
+
+    /// Downgrade a customer to no tier once all of their identity documents have expired
+    ///
+    /// Compliance checks already treat such customers as untiered; this
+    /// records the downgrade. Anyone may call it, e.g. a keeper.
+    ///
+    /// # Returns
+    /// Whether the customer was downgraded
+    pub fn enforce_document_expiry(env: Env, customer_id: String) -> bool {
+        let mut customer = Self::get_customer_record_internal(&env, &customer_id)
+            .unwrap_or_else(|| panic_with_error!(&env, KYCError::NotFound));
+        if customer.kyc_tier == KYCTier::None || !Self::identity_documents_lapsed(&env, &customer_id) {
+            return false;
+        }
+
+        let old_tier = customer.kyc_tier.clone();
+        customer.kyc_tier = KYCTier::None;
+        customer.updated_at = env.ledger().timestamp();
+        env.storage().persistent().set(&DataKey::CustomerRecord(customer_id.clone()), &customer);
+
+        Self::update_tier_stats(&env, &old_tier, -1);
+        Self::update_tier_stats(&env, &KYCTier::None, 1);
+
+        Self::log_audit_entry(&env, AuditLogEntry {
+            timestamp: env.ledger().timestamp(),
+            action: String::from_str(&env, "doc_expiry"),
+            customer_id: customer_id.clone(),
+            address: env.current_contract_address(),
+            old_tier: old_tier.clone(),
+            new_tier: KYCTier::None,
+            officer: env.current_contract_address(),
+            notes: String::from_str(&env, "All identity documents expired"),
+        });
+
+        env.events().publish(
+            (symbol_short!("kyc_tier"), customer_id),
+            (old_tier, KYCTier::None)
+        );
+        Self::notify_customer_change(&env, &customer);
+
+        true
+    }
+
+    // =====================
+    // Admin management APIs
+    // =====================
+
+    /// Enable/disable the registry
+    pub fn set_registry_enabled(env: Env, caller: Address, enabled: bool) {
+        Self::require_admin(&env, &caller);
+        let mut s = Self::get_global_settings_internal(&env);
+        s.registry_enabled = enabled;
+        env.storage().instance().set(&DataKey::GlobalSettings, &s);
+env.events().publish((symbol_short!("kyc_set"), symbol_short!("reg_en")), enabled);
+        Self::notify_compliance_change(&env, Vec::new(&env));
+    }
+
+    /// Set strict mode
+    pub fn set_strict_mode(env: Env, caller: Address, strict: bool) {
+        Self::require_admin(&env, &caller);
+        let mut s = Self::get_global_settings_internal(&env);
+        s.strict_mode = strict;
+        env.storage().instance().set(&DataKey::GlobalSettings, &s);
+env.events().publish((symbol_short!("kyc_set"), symbol_short!("strict")), strict);
+    }
+
+    /// Replace all global settings
+    pub fn set_global_settings(env: Env, caller: Address, settings: GlobalSettings) {
+        Self::require_admin(&env, &caller);
+        env.storage().instance().set(&DataKey::GlobalSettings, &settings);
+env.events().publish((symbol_short!("kyc_set"), symbol_short!("update")), (settings.registry_enabled, settings.strict_mode));
+        Self::notify_compliance_change(&env, Vec::new(&env));
+    }
+
+    /// Add a compliance officer
+    pub fn add_compliance_officer(env: Env, caller: Address, officer: Address) {
+        Self::require_admin(&env, &caller);
+        let mut officers: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ComplianceOfficers)
+            .unwrap_or(Vec::new(&env));
+        for o in officers.iter() {
+            if o == officer {
+                panic_with_error!(&env, KYCError::AlreadyExists);
+            }
+        }
+        officers.push_back(officer.clone());
+        env.storage().instance().set(&DataKey::ComplianceOfficers, &officers);
+env.events().publish((symbol_short!("kyc_ofc"), symbol_short!("add")), officer);
+    }
+
+    /// Remove a compliance officer
+    pub fn remove_compliance_officer(env: Env, caller: Address, officer: Address) {
+        Self::require_admin(&env, &caller);
+        let officers: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ComplianceOfficers)
+            .unwrap_or(Vec::new(&env));
+        let mut next = Vec::new(&env);
+        let mut found = false;
+        for o in officers.iter() {
+            if o != officer {
+                next.push_back(o);
+            } else {
+                found = true;
+            }
+        }
+        if !found { panic_with_error!(&env, KYCError::NotFound); }
+        env.storage().instance().set(&DataKey::ComplianceOfficers, &next);
+env.events().publish((symbol_short!("kyc_ofc"), symbol_short!("remove")), officer);
+    }
+
+    /// Set required minimum tier per operation
+    pub fn set_required_tier(env: Env, caller: Address, operation: OperationType, tier: KYCTier) {
+        Self::require_admin(&env, &caller);
+        env.storage().persistent().set(&DataKey::RequiredTier(operation.clone()), &tier);
+env.events().publish((symbol_short!("req_tier"), operation), tier);
+        Self::notify_compliance_change(&env, Vec::new(&env));
+    }
+
+    /// Set limits for a (tier, operation) pair
+    pub fn set_tier_limits(env: Env, caller: Address, tier: KYCTier, operation: OperationType, limits: OperationLimits) {
+        Self::require_admin(&env, &caller);
+        env.storage().persistent().set(&DataKey::TierLimits(tier.clone(), operation.clone()), &limits);
+env.events().publish((symbol_short!("kyc_lims"), (tier, operation)), (limits.single_tx_limit, limits.daily_limit, limits.monthly_limit));
+        Self::notify_compliance_change(&env, Vec::new(&env));
+    }
+
+    /// Set sanctions cleared flag for a customer
+    pub fn set_sanctions_status(env: Env, caller: Address, customer_id: String, cleared: bool) {
+        Self::require_admin(&env, &caller);
+        let mut rec = Self::get_customer_record_internal(&env, &customer_id).unwrap_or_else(|| panic_with_error!(&env, KYCError::NotFound));
+        rec.sanctions_cleared = cleared;
+        rec.updated_at = env.ledger().timestamp();
+        env.storage().persistent().set(&DataKey::CustomerRecord(customer_id.clone()), &rec);
+env.events().publish((symbol_short!("kyc_cust"), symbol_short!("sanct")), (customer_id, cleared));
+        Self::notify_customer_change(&env, &rec);
+    }
+
+    /// Set expiration for a customer
+    pub fn set_customer_expiration(env: Env, caller: Address, customer_id: String, expires_at: u64) {
+        Self::require_admin(&env, &caller);
+        let mut rec = Self::get_customer_record_internal(&env, &customer_id).unwrap_or_else(|| panic_with_error!(&env, KYCError::NotFound));
+        rec.expires_at = expires_at;
+        rec.updated_at = env.ledger().timestamp();
+        env.storage().persistent().set(&DataKey::CustomerRecord(customer_id.clone()), &rec);
+env.events().publish((symbol_short!("kyc_cust"), symbol_short!("expire")), (customer_id, expires_at));
+        Self::notify_customer_change(&env, &rec);
+    }
+
+    /// Set jurisdiction for a customer
+    pub fn set_customer_jurisdiction(env: Env, caller: Address, customer_id: String, jurisdiction: String) {
+        Self::require_admin(&env, &caller);
+        if jurisdiction.is_empty() { panic_with_error!(&env, KYCError::InvalidInput); }
+        let mut rec = Self::get_customer_record_internal(&env, &customer_id).unwrap_or_else(|| panic_with_error!(&env, KYCError::NotFound));
+        let old_jurisdiction = rec.jurisdiction.clone();
+        rec.jurisdiction = jurisdiction.clone();
+        rec.updated_at = env.ledger().timestamp();
+        env.storage().persistent().set(&DataKey::CustomerRecord(customer_id.clone()), &rec);
+        Self::update_jurisdiction_stats(&env, &old_jurisdiction, -1);
+        Self::update_jurisdiction_stats(&env, &jurisdiction, 1);
+env.events().publish((symbol_short!("kyc_cust"), symbol_short!("juris")), (customer_id, jurisdiction));
+    }
+
+    /// Update metadata entry for a customer (upsert)
+    pub fn set_customer_metadata(env: Env, caller: Address, customer_id: String, key: String, value: String) {
+        Self::require_admin(&env, &caller);
+        let mut rec = Self::get_customer_record_internal(&env, &customer_id).unwrap_or_else(|| panic_with_error!(&env, KYCError::NotFound));
+        rec.metadata.set(key.clone(), value.clone());
+        rec.updated_at = env.ledger().timestamp();
+        env.storage().persistent().set(&DataKey::CustomerRecord(customer_id.clone()), &rec);
+env.events().publish((symbol_short!("kyc_cust"), symbol_short!("meta")), (customer_id, key));
+    }
+    
+    // =====================
+    // Integration Functions
+    // =====================
+    
+    /// Set integration router address (admin only)
+    pub fn set_integration_router(env: Env, caller: Address, router_address: Address) {
+        Self::require_admin(&env, &caller);
+        env.storage().instance().set(&DataKey::IntegrationRouter, &router_address);
+        
+        env.events().publish(
+            (symbol_short!("router"), router_address),
+            (symbol_short!("set"), symbol_short!("ok"))
+        );
+    }
+    
+    /// Get integration router address
+    pub fn get_integration_router(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::IntegrationRouter)
+    }
+    
     /// Verify operation compliance for integration (simplified)
     pub fn verify_integration_compliance(
         env: Env,
@@ -884,37 +1671,257 @@ env.events().publish((symbol_short!("kyc_cust"), symbol_short!("meta")), (custom
                 Self::init_default_tier_limits(env, &tier, &operation);
             }
         }
-    }
-    
-    /// Log audit entry
-    fn log_audit_entry(env: &Env, entry: AuditLogEntry) {
-        let settings = Self::get_global_settings_internal(env);
-        if !settings.audit_enabled {
-            return;
+    }
+    
+    /// Log audit entry
+    fn log_audit_entry(env: &Env, entry: AuditLogEntry) {
+        let settings = Self::get_global_settings_internal(env);
+        if !settings.audit_enabled {
+            return;
+        }
+        
+        // Store the audit entry
+        env.storage().persistent().set(&DataKey::AuditLog(entry.timestamp), &entry);
+        
+        // Emit audit event
+        env.events().publish(
+            (symbol_short!("kyc_audit"), entry.action.clone()),
+            (entry.customer_id.clone(), entry.new_tier.clone())
+        );
+    }
+    
+    /// Update tier statistics
+    fn update_tier_stats(_env: &Env, _tier: &KYCTier, _delta: i64) {
+        // Simple implementation - would be more sophisticated in production
+        // This is a placeholder for statistical tracking
+    }
+    
+    /// Update jurisdiction statistics
+    fn update_jurisdiction_stats(_env: &Env, _jurisdiction: &String, _delta: i64) {
+        // Simple implementation - would be more sophisticated in production
+        // This is a placeholder for statistical tracking
+    }
+    
+    /// Built-in policy used until a tier's first published version
+    fn default_tier_policy(env: &Env, tier: &KYCTier) -> TierPolicy {
+        // (daily limit, monthly limit, confirmations, enhanced verification threshold)
+        let (daily, monthly, min_confirmations, enhanced) = match tier {
+            KYCTier::None => (0, 0, 6, 0),
+            KYCTier::Basic => (5_000_000, 50_000_000, 6, 1_000_000),          // 0.05 / 0.5 BTC
+            KYCTier::Verified => (50_000_000, 500_000_000, 6, 10_000_000),    // 0.5 / 5 BTC
+            KYCTier::Enhanced => (500_000_000, 5_000_000_000, 4, 100_000_000), // 5 / 50 BTC
+            KYCTier::Institutional => (u64::MAX / 4, u64::MAX / 4, 3, u64::MAX / 4),
+        };
+
+        TierPolicy {
+            daily_deposit_limit: daily,
+            monthly_deposit_limit: monthly,
+            daily_withdrawal_limit: daily,
+            monthly_withdrawal_limit: monthly,
+            daily_exchange_limit: daily,
+            monthly_exchange_limit: monthly,
+            min_confirmations,
+            enhanced_verification_limit: enhanced,
+            version: 0,
+            updated_at: 0,
+            updated_by: env.current_contract_address(),
+        }
+    }
+
+    /// Numeric level of a tier (0=None ... 4=Institutional)
+    fn tier_level(tier: &KYCTier) -> u32 {
+        match tier {
+            KYCTier::None => 0,
+            KYCTier::Basic => 1,
+            KYCTier::Verified => 2,
+            KYCTier::Enhanced => 3,
+            KYCTier::Institutional => 4,
+        }
+    }
+
+    /// Tier used for compliance checks: the lower of the current tier and
+    /// any pending requested tier, or no tier once identity documents lapse
+    fn get_effective_tier_internal(env: &Env, customer: &CustomerRecord) -> KYCTier {
+        // Customers whose identity documents have all expired have no tier
+        if Self::identity_documents_lapsed(env, &customer.customer_id) {
+            return KYCTier::None;
+        }
+
+        let pending = env.storage().persistent()
+            .get::<DataKey, u64>(&DataKey::PendingTierChange(customer.customer_id.clone()))
+            .and_then(|request_id| env.storage().persistent()
+                .get::<DataKey, TierChangeRequest>(&DataKey::TierChangeRequest(request_id)));
+
+        match pending {
+            Some(request) if Self::tier_level(&request.requested_tier) < Self::tier_level(&customer.kyc_tier) => {
+                request.requested_tier
+            }
+            _ => customer.kyc_tier.clone(),
+        }
+    }
+
+    /// IDs of the documents on file for a customer
+    fn get_customer_document_ids(env: &Env, customer_id: &String) -> Vec<u64> {
+        env.storage().persistent()
+            .get(&DataKey::CustomerDocuments(customer_id.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Copy of `ids` without `document_id`
+    fn without_document(env: &Env, ids: &Vec<u64>, document_id: u64) -> Vec<u64> {
+        let mut remaining = Vec::new(env);
+        for id in ids.iter() {
+            if id != document_id {
+                remaining.push_back(id);
+            }
+        }
+        remaining
+    }
+
+    /// Whether the customer has identity documents on file and all of them have expired
+    fn identity_documents_lapsed(env: &Env, customer_id: &String) -> bool {
+        let now = env.ledger().timestamp();
+        let mut has_identity = false;
+        for document_id in Self::get_customer_document_ids(env, customer_id).iter() {
+            let document: KycDocument = match env.storage().persistent().get(&DataKey::Document(document_id)) {
+                Some(document) => document,
+                None => continue,
+            };
+            if !matches!(
+                document.document_type,
+                DocumentType::Passport | DocumentType::NationalId | DocumentType::DriversLicense
+            ) {
+                continue;
+            }
+            if document.expires_at == 0 || document.expires_at > now {
+                return false;
+            }
+            has_identity = true;
+        }
+        has_identity
+    }
+
+    /// Mark a pending tier change as reviewed and drop it from the pending indexes
+    fn close_tier_change_request(
+        env: &Env,
+        reviewer: &Address,
+        request_id: u64,
+        status: TierChangeStatus,
+        notes: String
+    ) -> TierChangeRequest {
+        let mut request: TierChangeRequest = env.storage().persistent()
+            .get(&DataKey::TierChangeRequest(request_id))
+            .unwrap_or_else(|| panic_with_error!(env, KYCError::NotFound));
+
+        if request.status != TierChangeStatus::Pending {
+            panic_with_error!(env, KYCError::InvalidInput);
+        }
+
+        request.status = status;
+        request.reviewed_at = env.ledger().timestamp();
+        request.reviewer = Some(reviewer.clone());
+        request.review_notes = notes;
+        env.storage().persistent().set(&DataKey::TierChangeRequest(request_id), &request);
+        env.storage().persistent().remove(&DataKey::PendingTierChange(request.customer_id.clone()));
+
+        let pending: Vec<u64> = env.storage().persistent()
+            .get(&DataKey::PendingTierChanges)
+            .unwrap_or(Vec::new(env));
+        let mut remaining = Vec::new(env);
+        for id in pending.iter() {
+            if id != request_id {
+                remaining.push_back(id);
+            }
+        }
+        env.storage().persistent().set(&DataKey::PendingTierChanges, &remaining);
+
+        request
+    }
+
+    /// Map an `is_approved_simple` op code to its operation type
+    fn operation_from_code(op_code: u32) -> Option<OperationType> {
+        match op_code {
+            0 => Some(OperationType::Transfer),
+            1 => Some(OperationType::Mint),
+            2 => Some(OperationType::Burn),
+            3 => Some(OperationType::Deposit),
+            4 => Some(OperationType::Withdraw),
+            5 => Some(OperationType::Exchange),
+            _ => None,
+        }
+    }
+
+    /// Run every approval check, returning the first failure's reason code (0 = approved)
+    fn compliance_reason(env: &Env, address: &Address, operation: &OperationType, amount: i128) -> u32 {
+        // Blacklisted addresses are rejected even when the registry is disabled
+        if env.storage().persistent().has(&DataKey::Blacklist(address.clone())) {
+            return 1;
+        }
+
+        let settings = Self::get_global_settings_internal(env);
+        if !settings.registry_enabled {
+            return 0; // Registry disabled - allow all operations
+        }
+
+        // Get customer record from address
+        let customer = match env.storage().persistent()
+            .get::<DataKey, String>(&DataKey::AddressToCustomer(address.clone()))
+            .and_then(|customer_id| Self::get_customer_record_internal(env, &customer_id))
+        {
+            Some(record) => record,
+            None => return 2, // Address not registered
+        };
+
+        if customer.expires_at > 0 && env.ledger().timestamp() > customer.expires_at {
+            return 3; // KYC expired
+        }
+        if settings.sanctions_required && !customer.sanctions_cleared {
+            return 4; // Sanctions not cleared
+        }
+
+        // Pending tier changes apply the lower of the two tiers until reviewed
+        let effective_tier = Self::get_effective_tier_internal(env, &customer);
+        let required_tier = Self::get_required_tier_internal(env, operation);
+        if !Self::tier_meets_requirement(&effective_tier, &required_tier) {
+            return 5; // Insufficient KYC tier
         }
-        
-        // Store the audit entry
-        env.storage().persistent().set(&DataKey::AuditLog(entry.timestamp), &entry);
-        
-        // Emit audit event
-        env.events().publish(
-            (symbol_short!("kyc_audit"), entry.action.clone()),
-            (entry.customer_id.clone(), entry.new_tier.clone())
-        );
+
+        let limits = Self::get_tier_limits_internal(env, &effective_tier, operation);
+        if !limits.enabled {
+            return 6; // Operation disabled for this tier
+        }
+        if amount > limits.single_tx_limit {
+            return 7; // Amount exceeds single transaction limit
+        }
+
+        // TODO: Implement daily/monthly limit tracking
+        0
     }
-    
-    /// Update tier statistics
-    fn update_tier_stats(_env: &Env, _tier: &KYCTier, _delta: i64) {
-        // Simple implementation - would be more sophisticated in production
-        // This is a placeholder for statistical tracking
+
+    /// Tell the integration router that approvals for `addresses` may have changed
+    ///
+    /// An empty list means a registry-wide change. The router drops its cached
+    /// compliance answers for the affected addresses; a missing or failing
+    /// router never blocks the registry update.
+    fn notify_compliance_change(env: &Env, addresses: Vec<Address>) {
+        env.events().publish((symbol_short!("kyc_chg"),), addresses.clone());
+
+        if let Some(router) = env.storage().instance().get::<DataKey, Address>(&DataKey::IntegrationRouter) {
+            let _ = env.try_invoke_contract::<(), soroban_sdk::Error>(
+                &router,
+                &Symbol::new(env, "notify_kyc_change"),
+                vec![env, addresses.into_val(env)]
+            );
+        }
     }
-    
-    /// Update jurisdiction statistics
-    fn update_jurisdiction_stats(_env: &Env, _jurisdiction: &String, _delta: i64) {
-        // Simple implementation - would be more sophisticated in production
-        // This is a placeholder for statistical tracking
+
+    /// Notify the router about every approved address of a customer
+    fn notify_customer_change(env: &Env, customer: &CustomerRecord) {
+        if !customer.approved_addresses.is_empty() {
+            Self::notify_compliance_change(env, customer.approved_addresses.clone());
+        }
     }
-    
+
     /// Require caller to be integration router or admin
     fn require_integration_caller(env: &Env, caller: &Address) {
         caller.require_auth();
@@ -951,7 +1958,11 @@ env.events().publish((symbol_short!("kyc_cust"), symbol_short!("meta")), (custom
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{testutils::Address as AddressTestUtils, Address, Env};
+    use soroban_sdk::{
+        contract, contractimpl,
+        testutils::{Address as AddressTestUtils, Ledger},
+        Address, Env
+    };
 
     #[test]
     fn test_initialize() {
@@ -1244,4 +2255,446 @@ mod test {
         // Verify correlation ID was generated
         assert_eq!(correlation_id, String::from_str(&env, "correlation_id"));
     }
+
+    #[test]
+    fn test_tier_upgrade_request_approval() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(KYCRegistry, ());
+        let client = KYCRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let officer = Address::generate(&env);
+        let customer_addr = Address::generate(&env);
+        client.initialize(&admin);
+        client.add_compliance_officer(&admin, &officer);
+
+        let customer_id = String::from_str(&env, "upgrade_user");
+        client.register_customer(
+            &admin,
+            &customer_id,
+            &KYCTier::Basic,
+            &vec![&env, customer_addr.clone()],
+            &String::from_str(&env, "US"),
+            &Map::new(&env)
+        );
+        client.set_sanctions_status(&admin, &customer_id, &true);
+
+        let documents_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let request_id = client.request_tier_upgrade(&customer_addr, &KYCTier::Enhanced, &documents_hash);
+
+        // Upgrade is not applied until reviewed
+        assert_eq!(client.get_effective_tier(&customer_id), Some(KYCTier::Basic));
+        assert_eq!(client.get_pending_tier_changes().len(), 1);
+        assert!(client.try_request_tier_upgrade(&customer_addr, &KYCTier::Verified, &documents_hash).is_err());
+
+        client.approve_tier_change(&officer, &request_id, &String::from_str(&env, "Documents verified"));
+
+        let request = client.get_tier_change_request(&request_id).unwrap();
+        assert_eq!(request.status, TierChangeStatus::Approved);
+        assert_eq!(request.reviewer, Some(officer.clone()));
+        assert_eq!(client.get_customer_record(&customer_id).unwrap().kyc_tier, KYCTier::Enhanced);
+        assert_eq!(client.get_pending_tier_change(&customer_id), None);
+        assert_eq!(client.get_pending_tier_changes().len(), 0);
+
+        // A reviewed request cannot be reviewed again
+        assert!(client.try_reject_tier_change(&officer, &request_id, &String::from_str(&env, "late")).is_err());
+    }
+
+    #[test]
+    fn test_pending_downgrade_applies_lower_limits() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(KYCRegistry, ());
+        let client = KYCRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let customer_addr = Address::generate(&env);
+        client.initialize(&admin);
+
+        let customer_id = String::from_str(&env, "downgrade_user");
+        client.register_customer(
+            &admin,
+            &customer_id,
+            &KYCTier::Enhanced,
+            &vec![&env, customer_addr.clone()],
+            &String::from_str(&env, "US"),
+            &Map::new(&env)
+        );
+        client.set_sanctions_status(&admin, &customer_id, &true);
+
+        let amount = 50_000_0000000; // Above the Basic single tx limit
+        assert!(client.is_approved_for_operation(&customer_addr, &OperationType::Transfer, &amount));
+
+        let request_id = client.request_tier_upgrade(&customer_addr, &KYCTier::Basic, &BytesN::from_array(&env, &[1u8; 32]));
+        assert_eq!(client.get_tier_code_by_address(&customer_addr), 1);
+        assert!(!client.is_approved_for_operation(&customer_addr, &OperationType::Transfer, &amount));
+
+        // Rejection restores the current tier's limits
+        client.reject_tier_change(&admin, &request_id, &String::from_str(&env, "Customer withdrew request"));
+        assert_eq!(client.get_tier_change_request(&request_id).unwrap().status, TierChangeStatus::Rejected);
+        assert_eq!(client.get_customer_record(&customer_id).unwrap().kyc_tier, KYCTier::Enhanced);
+        assert!(client.is_approved_for_operation(&customer_addr, &OperationType::Transfer, &amount));
+    }
+
+    #[test]
+    fn test_tier_change_review_requires_compliance_officer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(KYCRegistry, ());
+        let client = KYCRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let customer_addr = Address::generate(&env);
+        client.initialize(&admin);
+
+        let customer_id = String::from_str(&env, "review_user");
+        client.register_customer(
+            &admin,
+            &customer_id,
+            &KYCTier::Basic,
+            &vec![&env, customer_addr.clone()],
+            &String::from_str(&env, "US"),
+            &Map::new(&env)
+        );
+
+        let request_id = client.request_tier_upgrade(&customer_addr, &KYCTier::Verified, &BytesN::from_array(&env, &[2u8; 32]));
+        let notes = String::from_str(&env, "Self approval");
+        assert!(client.try_approve_tier_change(&customer_addr, &request_id, &notes).is_err());
+        assert_eq!(client.get_tier_change_request(&request_id).unwrap().status, TierChangeStatus::Pending);
+    }
+
+    #[test]
+    fn test_blacklist_management() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(KYCRegistry, ());
+        let client = KYCRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let customer_addr = Address::generate(&env);
+        client.initialize(&admin);
+
+        let customer_id = String::from_str(&env, "listed_user");
+        client.register_customer(
+            &admin,
+            &customer_id,
+            &KYCTier::Verified,
+            &vec![&env, customer_addr.clone()],
+            &String::from_str(&env, "US"),
+            &Map::new(&env)
+        );
+        client.set_sanctions_status(&admin, &customer_id, &true);
+        assert!(client.is_approved_simple(&customer_addr, &3u32, &1_000_000));
+
+        client.add_to_blacklist(&admin, &customer_addr, &2u32);
+        let entry = client.get_blacklist_status(&customer_addr).unwrap();
+        assert_eq!(entry.reason_code, 2);
+        assert_eq!(entry.list_version, None);
+        assert!(client.try_add_to_blacklist(&admin, &customer_addr, &2u32).is_err());
+
+        // Deposits and withdrawals are rejected, even with the registry disabled
+        assert!(!client.is_approved_simple(&customer_addr, &3u32, &1_000_000));
+        assert!(!client.is_approved_simple(&customer_addr, &4u32, &1_000_000));
+        client.set_registry_enabled(&admin, &false);
+        assert!(!client.is_approved_for_operation(&customer_addr, &OperationType::Transfer, &1_000_000));
+        client.set_registry_enabled(&admin, &true);
+
+        client.remove_from_blacklist(&admin, &customer_addr);
+        assert!(!client.is_blacklisted(&customer_addr));
+        assert!(client.is_approved_simple(&customer_addr, &3u32, &1_000_000));
+    }
+
+    #[test]
+    fn test_blacklist_import_is_versioned() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(KYCRegistry, ());
+        let client = KYCRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let manual = Address::generate(&env);
+        let listed = Address::generate(&env);
+        client.add_to_blacklist(&admin, &manual, &4u32);
+
+        let version = BytesN::from_array(&env, &[9u8; 32]);
+        let (listed_count, delisted_count) = client.import_blacklist(
+            &admin, &version, &None, &vec![&env, manual.clone(), listed.clone()], &vec![&env], &1u32
+        );
+        assert_eq!((listed_count, delisted_count), (1, 0));
+        assert_eq!(client.get_blacklist_version(), Some(version.clone()));
+
+        // Existing manual entries keep their original reason
+        assert_eq!(client.get_blacklist_status(&manual).unwrap().reason_code, 4);
+        assert_eq!(client.get_blacklist_status(&listed).unwrap().list_version, Some(version.clone()));
+
+        // The same list version cannot be imported twice
+        assert!(client.try_import_blacklist(&admin, &version, &None, &vec![&env, listed.clone()], &vec![&env], &1u32).is_err());
+
+        // A delta must build on the current version
+        let next = BytesN::from_array(&env, &[10u8; 32]);
+        let stale = BytesN::from_array(&env, &[8u8; 32]);
+        assert!(client.try_import_blacklist(&admin, &next, &Some(stale), &vec![&env], &vec![&env, listed.clone()], &1u32).is_err());
+
+        // Dropping addresses from the list delists imported entries but not manual ones
+        let (listed_count, delisted_count) = client.import_blacklist(
+            &admin, &next, &Some(version), &vec![&env], &vec![&env, manual.clone(), listed.clone()], &1u32
+        );
+        assert_eq!((listed_count, delisted_count), (0, 1));
+        assert!(!client.is_blacklisted(&listed));
+        assert!(client.is_blacklisted(&manual));
+        assert_eq!(client.get_blacklist_version(), Some(next));
+    }
+
+    #[test]
+    fn test_travel_rule_thresholds_by_jurisdiction() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(KYCRegistry, ());
+        let client = KYCRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let us_user = Address::generate(&env);
+        let ch_user = Address::generate(&env);
+        client.initialize(&admin);
+
+        client.register_customer(
+            &admin,
+            &String::from_str(&env, "us_customer"),
+            &KYCTier::Verified,
+            &vec![&env, us_user.clone()],
+            &String::from_str(&env, "US"),
+            &Map::new(&env)
+        );
+        client.register_customer(
+            &admin,
+            &String::from_str(&env, "ch_customer"),
+            &KYCTier::Verified,
+            &vec![&env, ch_user.clone()],
+            &String::from_str(&env, "CH"),
+            &Map::new(&env)
+        );
+
+        let us = String::from_str(&env, "US");
+        client.set_travel_rule_threshold(&admin, &us, &3_000_0000000);
+        assert_eq!(client.get_travel_rule_threshold(&us), Some(3_000_0000000));
+
+        assert!(client.requires_travel_rule(&us_user, &3_000_0000000));
+        assert!(!client.requires_travel_rule(&us_user, &2_999_0000000));
+        // No threshold configured for CH, unknown addresses never match
+        assert!(!client.requires_travel_rule(&ch_user, &3_000_0000000));
+        assert!(!client.requires_travel_rule(&Address::generate(&env), &3_000_0000000));
+
+        assert!(client.try_set_travel_rule_threshold(&admin, &us, &0).is_err());
+        client.remove_travel_rule_threshold(&admin, &us);
+        assert!(!client.requires_travel_rule(&us_user, &3_000_0000000));
+    }
+
+    #[test]
+    fn test_tier_policies_are_versioned_and_follow_effective_tier() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(KYCRegistry, ());
+        let client = KYCRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        client.initialize(&admin);
+        assert_eq!(client.get_address_tier_policy(&user), None);
+
+        let customer_id = String::from_str(&env, "policy_user");
+        client.register_customer(
+            &admin,
+            &customer_id,
+            &KYCTier::Verified,
+            &vec![&env, user.clone()],
+            &String::from_str(&env, "US"),
+            &Map::new(&env)
+        );
+        let (tier_code, default_policy) = client.get_address_tier_policy(&user).unwrap();
+        assert_eq!((tier_code, default_policy.version), (2, 0));
+        assert_eq!(default_policy.daily_deposit_limit, 50_000_000);
+
+        let mut policy = default_policy.clone();
+        policy.daily_withdrawal_limit = policy.monthly_withdrawal_limit + 1;
+        assert!(client.try_set_tier_policy(&admin, &KYCTier::Verified, &policy).is_err());
+        assert!(client.try_set_tier_policy(&user, &KYCTier::Verified, &default_policy).is_err());
+
+        policy.daily_withdrawal_limit = 20_000_000;
+        assert_eq!(client.set_tier_policy(&admin, &KYCTier::Verified, &policy), 1);
+        policy.min_confirmations = 3;
+        assert_eq!(client.set_tier_policy(&admin, &KYCTier::Verified, &policy), 2);
+
+        let current = client.get_tier_policy(&KYCTier::Verified);
+        assert_eq!((current.version, current.min_confirmations, current.updated_by.clone()), (2, 3, admin.clone()));
+        assert_eq!(client.get_tier_policy_version(&KYCTier::Verified, &1).unwrap().min_confirmations, 6);
+        assert_eq!(client.get_tier_policy_version(&KYCTier::Verified, &3), None);
+        assert_eq!(client.get_address_tier_policy(&user).unwrap().1, current);
+
+        // A pending downgrade switches the customer to the lower tier's policy
+        client.request_tier_upgrade(&user, &KYCTier::Basic, &BytesN::from_array(&env, &[1u8; 32]));
+        let (tier_code, policy) = client.get_address_tier_policy(&user).unwrap();
+        assert_eq!(tier_code, 1);
+        assert_eq!(policy, client.get_tier_policy(&KYCTier::Basic));
+    }
+
+    /// Router stand-in that records compliance change notifications
+    #[contract]
+    pub struct NotifiedRouter;
+
+    #[contractimpl]
+    impl NotifiedRouter {
+        pub fn notify_kyc_change(env: Env, addresses: Vec<Address>) {
+            let mut received: Vec<Vec<Address>> = env.storage().instance()
+                .get(&symbol_short!("received"))
+                .unwrap_or(Vec::new(&env));
+            received.push_back(addresses);
+            env.storage().instance().set(&symbol_short!("received"), &received);
+        }
+
+        pub fn received(env: Env) -> Vec<Vec<Address>> {
+            env.storage().instance().get(&symbol_short!("received")).unwrap_or(Vec::new(&env))
+        }
+    }
+
+    #[test]
+    fn test_compliance_batch_reports_reasons_and_changes_notify_router() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(KYCRegistry, ());
+        let client = KYCRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        client.initialize(&admin);
+        let customer_id = String::from_str(&env, "batch_user");
+        client.register_customer(
+            &admin,
+            &customer_id,
+            &KYCTier::Verified,
+            &vec![&env, user.clone()],
+            &String::from_str(&env, "US"),
+            &Map::new(&env)
+        );
+        client.set_sanctions_status(&admin, &customer_id, &true);
+
+        let requests = vec![
+            &env,
+            (user.clone(), 0u32, 1_000i128),
+            (user.clone(), 0u32, 100_000_000_001i128),
+            (user.clone(), 5u32, 1_000i128),
+            (stranger.clone(), 3u32, 1_000i128),
+            (user.clone(), 9u32, 1_000i128),
+        ];
+        let mut reasons = Vec::new(&env);
+        for outcome in client.verify_compliance_batch(&requests).iter() {
+            reasons.push_back(outcome.reason_code);
+        }
+        assert_eq!(reasons, vec![&env, 0u32, 7, 5, 2, 8]);
+        let first = client.verify_compliance_batch(&requests).get_unchecked(0);
+        assert!(first.approved);
+        assert_eq!(first.approved, client.is_approved_simple(&user, &0, &1_000));
+
+        // Changes reach the router; an unreachable router never blocks them
+        client.set_integration_router(&admin, &Address::generate(&env));
+        client.add_to_blacklist(&admin, &stranger, &1);
+
+        let router = NotifiedRouterClient::new(&env, &env.register(NotifiedRouter, ()));
+        client.set_integration_router(&admin, &router.address);
+        client.add_to_blacklist(&admin, &user, &1);
+        assert_eq!(client.verify_compliance_batch(&requests).get_unchecked(0).reason_code, 1);
+        client.set_customer_expiration(&admin, &customer_id, &1);
+        client.set_registry_enabled(&admin, &false);
+        assert_eq!(
+            router.received(),
+            vec![&env, vec![&env, user.clone()], vec![&env, user], Vec::new(&env)]
+        );
+    }
+
+    #[test]
+    fn test_documents_expire_into_reminders_and_tier_downgrade() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+        let contract_id = env.register(KYCRegistry, ());
+        let client = KYCRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        client.initialize(&admin);
+        let customer_id = String::from_str(&env, "doc_user");
+        client.register_customer(
+            &admin,
+            &customer_id,
+            &KYCTier::Verified,
+            &vec![&env, user.clone()],
+            &String::from_str(&env, "US"),
+            &Map::new(&env)
+        );
+        client.set_sanctions_status(&admin, &customer_id, &true);
+
+        let hash = BytesN::from_array(&env, &[3u8; 32]);
+        assert!(client.try_add_document(&admin, &customer_id, &DocumentType::Passport, &hash, &2_000, &1_000).is_err());
+        assert!(client.try_add_document(&user, &customer_id, &DocumentType::Passport, &hash, &0, &2_000_000).is_err());
+        let passport = client.add_document(&admin, &customer_id, &DocumentType::Passport, &hash, &0, &1_500_000);
+        let licence = client.add_document(&admin, &customer_id, &DocumentType::DriversLicense, &hash, &0, &1_200_000);
+        client.add_document(&admin, &customer_id, &DocumentType::ProofOfAddress, &hash, &0, &1_100_000);
+        assert_eq!(client.get_customer_documents(&customer_id).len(), 3);
+
+        // Reminders list upcoming expiries, soonest first
+        let expiring = client.get_expiring_documents(&300_000);
+        assert_eq!(expiring.len(), 2);
+        assert_eq!(expiring.get_unchecked(0).document_type, DocumentType::ProofOfAddress);
+        assert_eq!(expiring.get_unchecked(1).document_id, licence);
+
+        // One valid identity document keeps the tier
+        env.ledger().with_mut(|li| li.timestamp = 1_300_000);
+        assert_eq!(client.get_expiring_documents(&300_000).get_unchecked(0).document_id, passport);
+        assert!(client.is_approved_simple(&user, &0, &1_000));
+        assert!(!client.enforce_document_expiry(&customer_id));
+
+        // Once every identity document has expired the customer loses their tier
+        env.ledger().with_mut(|li| li.timestamp = 1_500_001);
+        assert!(!client.is_approved_simple(&user, &0, &1_000));
+        assert_eq!(client.get_effective_tier(&customer_id), Some(KYCTier::None));
+        assert!(client.enforce_document_expiry(&customer_id));
+        assert_eq!(client.get_customer_record(&customer_id).unwrap().kyc_tier, KYCTier::None);
+        assert!(!client.enforce_document_expiry(&customer_id));
+
+        client.remove_document(&admin, &licence);
+        assert_eq!(client.get_customer_documents(&customer_id).len(), 2);
+        assert_eq!(client.get_document(&licence), None);
+    }
+
+    #[test]
+    fn test_customer_jurisdiction_updates() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(KYCRegistry, ());
+        let client = KYCRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        client.initialize(&admin);
+        assert_eq!(client.get_jurisdiction_by_address(&user), None);
+
+        let customer_id = String::from_str(&env, "moving_user");
+        client.register_customer(
+            &admin,
+            &customer_id,
+            &KYCTier::Verified,
+            &vec![&env, user.clone()],
+            &String::from_str(&env, "US"),
+            &Map::new(&env)
+        );
+        assert_eq!(client.get_jurisdiction_by_address(&user), Some(String::from_str(&env, "US")));
+
+        assert!(client.try_set_customer_jurisdiction(&admin, &customer_id, &String::from_str(&env, "")).is_err());
+        assert!(client.try_set_customer_jurisdiction(&user, &customer_id, &String::from_str(&env, "SG")).is_err());
+        client.set_customer_jurisdiction(&admin, &customer_id, &String::from_str(&env, "SG"));
+        assert_eq!(client.get_jurisdiction_by_address(&user), Some(String::from_str(&env, "SG")));
+    }
 }
@@ -0,0 +1,51 @@
+//! Reserve allocations to yield venues
+//!
+//! Treasury may deploy part of the BTC reserves to approved venues. Allocated
+//! BTC still backs the token supply but no longer sits in custody UTXOs, so
+//! reports carry it as its own category next to the on-chain total. Yield is
+//! reported by allocation operators per venue and accrues as treasury revenue;
+//! it is never added to the reserve counter.
+
+use soroban_sdk::{contracttype, BytesN, String, Vec};
+
+/// Longest accepted venue name in bytes
+pub const MAX_VENUE_NAME_LEN: u32 = 32;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllocationVenue {
+    pub name: String,
+    pub cap_sats: u64,                      // Maximum satoshis deployable to the venue
+    pub allocated_sats: u64,                // Currently deployed
+    pub accrued_yield_sats: u64,            // Attested yield since registration
+    pub last_attested_at: u64,              // End of the last attested period
+    pub last_report_hash: Option<BytesN<32>>, // Hash of the venue statement behind the last attestation
+    pub registered_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllocationReport {
+    pub total_reserves: u64,
+    pub onchain_sats: u64,          // Unspent custody UTXOs
+    pub allocated_sats: u64,        // Deployed to venues, not on-chain
+    pub unreconciled_sats: i64,     // Reserves explained by neither category (negative = over-accounted)
+    pub accrued_yield_sats: u64,
+    pub venues: Vec<AllocationVenue>,
+    pub generated_at: u64,
+}
+
+/// Whether a venue name is non-empty and within the length limit
+pub fn is_valid_venue_name(name: &String) -> bool {
+    !name.is_empty() && name.len() <= MAX_VENUE_NAME_LEN
+}
+
+/// Satoshis that can still be deployed to a venue
+pub fn remaining_capacity(venue: &AllocationVenue) -> u64 {
+    venue.cap_sats.saturating_sub(venue.allocated_sats)
+}
+
+/// Reserves not covered by custody UTXOs or venue allocations
+pub fn unreconciled_sats(total_reserves: u64, onchain_sats: u64, allocated_sats: u64) -> i64 {
+    total_reserves as i64 - onchain_sats as i64 - allocated_sats as i64
+}
@@ -0,0 +1,59 @@
+//! Custodian attestations of off-chain wallet balances
+//!
+//! Sweeps between cold storage and the hot wallet happen off-chain, so the
+//! bucket balances only move when someone reports them. Registered custodians
+//! attest each bucket's balance together with the movements behind it.
+//! Movements that match a pending rebalance request settle it; anything else
+//! raises an alert. The latest attestation per bucket is kept as a separate
+//! reserve source for reconciliation, which only trusts it while fresh.
+
+use soroban_sdk::{contracttype, Address, BytesN, Vec};
+
+use crate::custody::{WalletBucket, BUCKETS};
+
+/// Attestations older than this no longer count as fresh by default
+pub const DEFAULT_ATTESTATION_MAX_AGE: u64 = 86_400;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustodyMovement {
+    pub from_bucket: WalletBucket,
+    pub to_bucket: WalletBucket,
+    pub amount: u64,
+    pub btc_tx_hash: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustodianAttestation {
+    pub attestation_id: u64,
+    pub custodian: Address,
+    pub bucket: WalletBucket,
+    pub balance_sats: u64,              // Balance attested by the custodian
+    pub recorded_sats: u64,             // Bucket balance on record once movements settled
+    pub movements: Vec<CustodyMovement>,
+    pub matched_requests: Vec<u64>,     // Rebalance requests settled by the movements
+    pub unmatched_movements: u32,
+    pub evidence_hash: BytesN<32>,      // Hash of the signed custodian statement
+    pub as_of: u64,                     // Time the attested balance refers to
+    pub submitted_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestedReserves {
+    pub hot_sats: u64,
+    pub warm_sats: u64,
+    pub cold_sats: u64,
+    pub total_sats: u64,
+    pub attested_buckets: u32,          // Buckets with at least one attestation
+    pub oldest_as_of: u64,              // 0 when no bucket has been attested
+    pub max_age: u64,
+    pub fresh: bool,                    // Every bucket attested within max_age
+}
+
+/// Whether a set of attestations covering `attested_buckets` buckets, the oldest
+/// as of `oldest_as_of`, is recent enough to trust at `now`
+pub fn is_fresh(attested_buckets: u32, oldest_as_of: u64, now: u64, max_age: u64) -> bool {
+    attested_buckets == BUCKETS.len() as u32 && now.saturating_sub(oldest_as_of) <= max_age
+}
@@ -0,0 +1,125 @@
+//! Hot, warm and cold wallet segregation
+//!
+//! Custody policy keeps most reserves in cold storage. Processed deposits land
+//! in the hot bucket and withdrawals are paid from hot first, falling back to
+//! warm and then cold. Whenever the hot bucket cannot float the pending
+//! withdrawals, a cold-to-hot replenishment request is queued; operators
+//! complete rebalancing requests once the on-chain sweep confirms.
+
+use soroban_sdk::{contracttype, BytesN, Vec};
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WalletBucket {
+    Hot,
+    Warm,
+    Cold,
+}
+
+/// Target share of reserves per bucket, in basis points summing to 10000
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustodyPolicy {
+    pub hot_target_bps: u32,
+    pub warm_target_bps: u32,
+    pub cold_target_bps: u32,
+    pub drift_tolerance_bps: u32,   // Allowed deviation before a drift alert
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RebalanceStatus {
+    Pending,
+    Completed,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RebalanceRequest {
+    pub request_id: u64,
+    pub from_bucket: WalletBucket,
+    pub to_bucket: WalletBucket,
+    pub amount: u64,
+    pub replenishment: bool,            // Queued automatically to refill the hot float
+    pub requested_at: u64,
+    pub status: RebalanceStatus,
+    pub btc_tx_hash: Option<BytesN<32>>, // Sweep transaction, set on completion
+    pub settled_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BucketAllocation {
+    pub bucket: WalletBucket,
+    pub balance_sats: u64,
+    pub actual_bps: u32,
+    pub target_bps: u32,
+    pub drifted: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustodyReport {
+    pub total_reserves: u64,
+    pub custodied_sats: u64,            // Sum of the bucket balances
+    pub pending_withdrawal_sats: u64,   // Float the hot bucket must cover
+    pub hot_shortfall_sats: u64,
+    pub queued_replenishment_sats: u64, // Pending cold-to-hot requests
+    pub buckets: Vec<BucketAllocation>,
+    pub generated_at: u64,
+}
+
+/// Buckets in withdrawal sourcing order
+pub const BUCKETS: [WalletBucket; 3] = [WalletBucket::Hot, WalletBucket::Warm, WalletBucket::Cold];
+
+/// Default policy: 5% hot, 15% warm, 80% cold, 5% drift tolerance
+pub fn default_policy() -> CustodyPolicy {
+    CustodyPolicy {
+        hot_target_bps: 500,
+        warm_target_bps: 1_500,
+        cold_target_bps: 8_000,
+        drift_tolerance_bps: 500,
+    }
+}
+
+/// Whether the targets cover exactly all reserves and the tolerance is a valid share
+pub fn is_valid_policy(policy: &CustodyPolicy) -> bool {
+    policy.hot_target_bps + policy.warm_target_bps + policy.cold_target_bps == 10_000
+        && policy.drift_tolerance_bps <= 10_000
+}
+
+pub fn target_bps(policy: &CustodyPolicy, bucket: WalletBucket) -> u32 {
+    match bucket {
+        WalletBucket::Hot => policy.hot_target_bps,
+        WalletBucket::Warm => policy.warm_target_bps,
+        WalletBucket::Cold => policy.cold_target_bps,
+    }
+}
+
+/// Share of `total` held in a bucket, in basis points
+pub fn share_bps(balance: u64, total: u64) -> u32 {
+    if total == 0 {
+        return 0;
+    }
+    ((balance as u128 * 10_000) / total as u128) as u32
+}
+
+/// Whether a share deviates from its target by more than the tolerance
+pub fn is_drifted(actual_bps: u32, target_bps: u32, tolerance_bps: u32) -> bool {
+    actual_bps.abs_diff(target_bps) > tolerance_bps
+}
+
+/// Split a withdrawal across `BUCKETS` balances in order, returning the debit per bucket
+///
+/// Any amount the buckets cannot cover (reserves held before segregation) is
+/// left undebited.
+pub fn source_withdrawal(balances: [u64; 3], amount: u64) -> [u64; 3] {
+    let mut debits = [0u64; 3];
+    let mut remaining = amount;
+    for (i, balance) in balances.iter().enumerate() {
+        debits[i] = remaining.min(*balance);
+        remaining -= debits[i];
+    }
+    debits
+}
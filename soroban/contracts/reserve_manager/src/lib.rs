@@ -1,9 +1,18 @@
 #![no_std]
 use soroban_sdk::{
     contract, contractimpl, contracttype, contracterror, symbol_short, panic_with_error,
-    Address, Env, String, BytesN
+    Address, Env, String, BytesN, Bytes, Vec, U256
 };
 
+mod allocation;
+mod attestation;
+mod custody;
+mod spv;
+pub use allocation::{AllocationReport, AllocationVenue};
+pub use attestation::{AttestedReserves, CustodianAttestation, CustodyMovement};
+pub use custody::{BucketAllocation, CustodyPolicy, CustodyReport, RebalanceRequest, RebalanceStatus, WalletBucket};
+pub use spv::SpvHeader;
+
 /// Reserve Manager Contract for Bitcoin-backed Token System
 /// 
 /// This contract manages Bitcoin reserves, tracks deposits/withdrawals,
@@ -37,6 +46,32 @@ pub enum DataKey {
     ProofOfReserves,
     OperationHistory(u64),          // timestamp -> OperationRecord
     ReserveRatioHistory(u64),       // timestamp -> u64 (ratio in basis points)
+    ReserveEntries,                 // Legacy: append-only deposit list, no longer written
+    MerkleSnapshot,                 // Legacy: deposit-based snapshot, removed by the next proof
+    Utxo(UtxoOutpoint),             // outpoint -> Utxo
+    UnspentUtxos,                   // -> Vec<UtxoOutpoint>
+    UnspentUtxoTotal,               // -> u64 (sum of unspent UTXO amounts)
+    SpvHeader(BytesN<32>),          // block hash -> SpvHeader
+    SpvHeaderAtHeight(u64),         // height -> block hash
+    SpvTip,                         // -> BytesN<32> (best block hash)
+    SpvChainWork(BytesN<32>),       // block hash -> U256 (cumulative work from the checkpoint)
+    SpvRelayer(Address),            // relayer -> bool (may submit block headers)
+    AllocationVenue(String),        // venue name -> AllocationVenue
+    AllocationVenues,               // -> Vec<String> (registered venue names)
+    TotalAllocated,                 // -> u64 (satoshis deployed across venues)
+    AllocationOperator(Address),    // operator -> bool (may attest venue yield)
+    CustodyPolicy,                  // -> CustodyPolicy
+    BucketBalance(WalletBucket),    // bucket -> u64 (satoshis held)
+    PendingWithdrawalTotal,         // -> u64 (satoshis requested but not yet paid out)
+    RebalanceRequest(u64),          // request_id -> RebalanceRequest
+    PendingRebalances,              // -> Vec<u64> (open rebalance request IDs)
+    NextRebalanceId,                // -> u64
+    Custodian(Address),             // custodian -> bool (may attest wallet balances)
+    CustodianAttestation(u64),      // attestation_id -> CustodianAttestation
+    LatestAttestation(WalletBucket), // bucket -> attestation_id
+    NextAttestationId,              // -> u64
+    UtxoMerkleSnapshot,             // -> Vec<ReserveEntry> (unspent UTXOs committed by the latest proof)
+    AttestationMaxAge,              // -> u64 (seconds an attestation stays fresh)
 }
 
 #[contracttype]
@@ -94,6 +129,46 @@ pub struct ProofOfReserves {
     pub signature: BytesN<64>,   // Cryptographic proof
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveEntry {
+    pub utxo_id: BytesN<32>,     // Bitcoin txid of the unspent output
+    pub vout: u32,
+    pub amount: u64,             // Satoshis
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveMerkleProof {
+    pub utxo_id: BytesN<32>,
+    pub vout: u32,
+    pub amount: u64,
+    pub leaf_index: u32,
+    pub siblings: Vec<BytesN<32>>, // Sibling hashes from leaf level up to the root
+    pub root: BytesN<32>,          // Root the proof was built against
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UtxoOutpoint {
+    pub txid: BytesN<32>,
+    pub vout: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Utxo {
+    pub outpoint: UtxoOutpoint,
+    pub amount: u64,                       // Satoshis
+    pub custody_address: String,
+    pub confirmation_height: u64,
+    pub depositor: Option<Address>,        // Set when txid matches a registered deposit
+    pub registered_at: u64,
+    pub spent: bool,
+    pub spent_by: Option<BytesN<32>>,      // Withdrawal ID that spent this UTXO
+    pub spent_at: Option<u64>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum OperationType {
@@ -222,6 +297,10 @@ impl ReserveManager {
         let new_reserves = current_reserves + deposit.amount;
         env.storage().persistent().set(&DataKey::TotalReserves, &new_reserves);
         
+        // Deposit addresses are hot wallets
+        Self::adjust_bucket_balance(&env, WalletBucket::Hot, deposit.amount as i128);
+        Self::check_custody_allocation(&env);
+        
         // Update reserve ratio and check thresholds
         Self::update_reserve_ratio(&env);
         Self::check_reserve_thresholds(&env);
@@ -271,6 +350,10 @@ impl ReserveManager {
         
         env.storage().persistent().set(&DataKey::WithdrawalRequest(withdrawal_id.clone()), &withdrawal);
         
+        let pending_total = Self::get_pending_withdrawal_total(env.clone()) + amount;
+        env.storage().persistent().set(&DataKey::PendingWithdrawalTotal, &pending_total);
+        Self::queue_hot_replenishment(&env);
+        
         // Log operation
         Self::log_operation(&env, OperationRecord {
             operation_type: OperationType::Withdrawal,
@@ -322,8 +405,29 @@ impl ReserveManager {
         }
         
         let new_reserves = current_reserves - withdrawal.amount;
+        
+        // BTC deployed to venues cannot fund a withdrawal until it is recalled
+        if new_reserves < Self::get_total_allocated(env.clone()) {
+            panic_with_error!(&env, ReserveError::InsufficientReserves);
+        }
+        
         env.storage().persistent().set(&DataKey::TotalReserves, &new_reserves);
         
+        let pending_total = Self::get_pending_withdrawal_total(env.clone()).saturating_sub(withdrawal.amount);
+        env.storage().persistent().set(&DataKey::PendingWithdrawalTotal, &pending_total);
+        
+        // Pay out of hot first, then warm, then cold
+        let debits = custody::source_withdrawal(Self::bucket_balances(&env), withdrawal.amount);
+        for (bucket, debit) in custody::BUCKETS.iter().zip(debits.iter()) {
+            Self::adjust_bucket_balance(&env, *bucket, -(*debit as i128));
+        }
+        env.events().publish(
+            (symbol_short!("with_src"), withdrawal_id.clone()),
+            (debits[0], debits[1], debits[2])
+        );
+        Self::queue_hot_replenishment(&env);
+        Self::check_custody_allocation(&env);
+        
         // Update reserve ratio and check thresholds
         Self::update_reserve_ratio(&env);
         Self::check_reserve_thresholds(&env);
@@ -400,8 +504,18 @@ impl ReserveManager {
         
         let ratio = Self::get_reserve_ratio(env.clone());
         
-        // Generate merkle root of all deposits (simplified)
-        let merkle_root = Self::calculate_merkle_root(&env);
+        // Commit to the unspent UTXO set so spent outputs drop out of the proof
+        let mut entries: Vec<ReserveEntry> = Vec::new(&env);
+        for utxo in Self::get_unspent_utxos(env.clone()).iter() {
+            entries.push_back(ReserveEntry {
+                utxo_id: utxo.outpoint.txid,
+                vout: utxo.outpoint.vout,
+                amount: utxo.amount,
+            });
+        }
+        let merkle_root = Self::calculate_merkle_root(&env, &entries);
+        env.storage().persistent().set(&DataKey::UtxoMerkleSnapshot, &entries);
+        env.storage().persistent().remove(&DataKey::MerkleSnapshot);
         
         // Generate cryptographic signature (simplified)
         let signature = Self::generate_proof_signature(&env, reserves, supply, ratio);
@@ -440,6 +554,59 @@ impl ReserveManager {
         env.storage().instance().get(&DataKey::ProofOfReserves)
     }
     
+    /// Get a merkle inclusion proof for a UTXO against the latest proof of reserves
+    pub fn get_reserve_merkle_proof(env: Env, utxo_id: BytesN<32>, vout: u32) -> Option<ReserveMerkleProof> {
+        let entries: Vec<ReserveEntry> = env.storage().persistent()
+            .get(&DataKey::UtxoMerkleSnapshot)?;
+        
+        let leaf_index = entries.iter().position(|entry| entry.utxo_id == utxo_id && entry.vout == vout)? as u32;
+        let entry = entries.get(leaf_index).unwrap();
+        
+        let mut level = Self::merkle_leaves(&env, &entries);
+        let mut index = leaf_index;
+        let mut siblings = Vec::new(&env);
+        
+        while level.len() > 1 {
+            let sibling_index = if index % 2 == 1 {
+                index - 1
+            } else if index + 1 < level.len() {
+                index + 1
+            } else {
+                index // Odd node is paired with itself
+            };
+            siblings.push_back(level.get(sibling_index).unwrap());
+            
+            level = Self::merkle_parent_level(&env, &level);
+            index /= 2;
+        }
+        
+        Some(ReserveMerkleProof {
+            utxo_id,
+            vout,
+            amount: entry.amount,
+            leaf_index,
+            siblings,
+            root: level.get(0).unwrap(),
+        })
+    }
+    
+    /// Verify that a merkle proof includes its UTXO under the given root
+    pub fn verify_reserve_inclusion(env: Env, proof: ReserveMerkleProof, root: BytesN<32>) -> bool {
+        let mut hash = Self::merkle_leaf(&env, &proof.utxo_id, proof.vout, proof.amount);
+        let mut index = proof.leaf_index;
+        
+        for sibling in proof.siblings.iter() {
+            hash = if index % 2 == 0 {
+                Self::merkle_node(&env, &hash, &sibling)
+            } else {
+                Self::merkle_node(&env, &sibling, &hash)
+            };
+            index /= 2;
+        }
+        
+        hash == root
+    }
+    
     /// Get total reserves
     pub fn get_total_reserves(env: Env) -> u64 {
         env.storage().persistent()
@@ -464,174 +631,1336 @@ impl ReserveManager {
         env.storage().persistent().get(&DataKey::WithdrawalRequest(withdrawal_id))
     }
     
-    /// Set reserve thresholds (admin only)
-    pub fn set_reserve_thresholds(
+    /// Register a Bitcoin UTXO held in custody
+    pub fn register_utxo(
         env: Env,
         caller: Address,
-        thresholds: ReserveThresholds
+        txid: BytesN<32>,
+        vout: u32,
+        amount: u64,
+        custody_address: String,
+        confirmation_height: u64
     ) {
-        Self::require_admin(&env, &caller);
+        Self::require_authorized(&env, &caller);
         
-        env.storage().instance().set(&DataKey::ReserveThresholds, &thresholds);
+        if amount == 0 {
+            panic_with_error!(&env, ReserveError::InvalidInput);
+        }
+        
+        let outpoint = UtxoOutpoint { txid: txid.clone(), vout };
+        if env.storage().persistent().has(&DataKey::Utxo(outpoint.clone())) {
+            panic_with_error!(&env, ReserveError::AlreadyProcessed);
+        }
+        
+        // Link the UTXO back to the deposit that created it, if known
+        let depositor = env.storage().persistent()
+            .get::<DataKey, BitcoinTransaction>(&DataKey::BitcoinDeposit(txid.clone()))
+            .map(|deposit| deposit.user);
+        
+        let utxo = Utxo {
+            outpoint: outpoint.clone(),
+            amount,
+            custody_address,
+            confirmation_height,
+            depositor,
+            registered_at: env.ledger().timestamp(),
+            spent: false,
+            spent_by: None,
+            spent_at: None,
+        };
+        env.storage().persistent().set(&DataKey::Utxo(outpoint.clone()), &utxo);
+        
+        let mut unspent: Vec<UtxoOutpoint> = env.storage().persistent()
+            .get(&DataKey::UnspentUtxos)
+            .unwrap_or(Vec::new(&env));
+        unspent.push_back(outpoint);
+        env.storage().persistent().set(&DataKey::UnspentUtxos, &unspent);
+        
+        let total = Self::get_unspent_utxo_total(env.clone()) + amount;
+        env.storage().persistent().set(&DataKey::UnspentUtxoTotal, &total);
         
         env.events().publish(
-            (symbol_short!("thresh"), caller),
-            (thresholds.minimum_ratio, thresholds.warning_ratio, thresholds.critical_ratio)
+            (symbol_short!("utxo_reg"), txid),
+            (vout, amount, confirmation_height)
         );
     }
     
-    /// Get reserve thresholds
-    pub fn get_reserve_thresholds(env: Env) -> ReserveThresholds {
-        env.storage().instance()
-            .get(&DataKey::ReserveThresholds)
-            .unwrap_or(ReserveThresholds {
-                minimum_ratio: 10000,
-                warning_ratio: 11000,
-                critical_ratio: 10500,
-                emergency_halt: true,
-            })
-    }
-    
-    // =====================
-    // Helper Functions
-    // =====================
-    
-    /// Require caller to be admin
-    fn require_admin(env: &Env, caller: &Address) {
-        caller.require_auth();
+    /// Mark UTXOs as spent by a withdrawal
+    pub fn mark_utxos_spent(
+        env: Env,
+        caller: Address,
+        withdrawal_id: BytesN<32>,
+        outpoints: Vec<UtxoOutpoint>
+    ) {
+        Self::require_authorized(&env, &caller);
         
-        let admin: Address = env.storage().instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic_with_error!(env, ReserveError::Unauthorized));
+        if !env.storage().persistent().has(&DataKey::WithdrawalRequest(withdrawal_id.clone())) {
+            panic_with_error!(&env, ReserveError::NotFound);
+        }
         
-        if *caller != admin {
-            panic_with_error!(env, ReserveError::Unauthorized);
+        let mut unspent: Vec<UtxoOutpoint> = env.storage().persistent()
+            .get(&DataKey::UnspentUtxos)
+            .unwrap_or(Vec::new(&env));
+        let mut total = Self::get_unspent_utxo_total(env.clone());
+        let mut spent_amount = 0u64;
+        
+        for outpoint in outpoints.iter() {
+            let mut utxo: Utxo = env.storage().persistent()
+                .get(&DataKey::Utxo(outpoint.clone()))
+                .unwrap_or_else(|| panic_with_error!(&env, ReserveError::NotFound));
+            
+            if utxo.spent {
+                panic_with_error!(&env, ReserveError::AlreadyProcessed);
+            }
+            
+            utxo.spent = true;
+            utxo.spent_by = Some(withdrawal_id.clone());
+            utxo.spent_at = Some(env.ledger().timestamp());
+            env.storage().persistent().set(&DataKey::Utxo(outpoint.clone()), &utxo);
+            
+            if let Some(index) = unspent.first_index_of(&outpoint) {
+                unspent.remove(index);
+            }
+            total -= utxo.amount;
+            spent_amount += utxo.amount;
         }
+        
+        env.storage().persistent().set(&DataKey::UnspentUtxos, &unspent);
+        env.storage().persistent().set(&DataKey::UnspentUtxoTotal, &total);
+        
+        env.events().publish(
+            (symbol_short!("utxo_spnt"), withdrawal_id),
+            (outpoints.len(), spent_amount)
+        );
     }
     
-    /// Require caller to be authorized (admin or integration router)
-    fn require_authorized(env: &Env, caller: &Address) {
-        caller.require_auth();
+    /// Retire the reserve UTXOs spent by a confirmed withdrawal payout
+    /// 
+    /// Called by the router with the inputs of the payout transaction that
+    /// confirmed. Inputs the ledger does not track are skipped, and inputs
+    /// already spent by the same withdrawal are left as they are, so the call
+    /// can be repeated. Returns the satoshis newly retired.
+    pub fn mark_payout_utxos_spent(
+        env: Env,
+        caller: Address,
+        withdrawal_id: BytesN<32>,
+        outpoints: Vec<UtxoOutpoint>
+    ) -> u64 {
+        Self::require_authorized(&env, &caller);
         
-        // Check if caller is admin
-        if let Some(admin) = env.storage().instance().get::<DataKey, Address>(&DataKey::Admin) {
-            if admin == *caller {
-                return;
+        let mut unspent: Vec<UtxoOutpoint> = env.storage().persistent()
+            .get(&DataKey::UnspentUtxos)
+            .unwrap_or(Vec::new(&env));
+        let mut total = Self::get_unspent_utxo_total(env.clone());
+        let mut spent_amount = 0u64;
+        
+        for outpoint in outpoints.iter() {
+            let mut utxo: Utxo = match env.storage().persistent().get(&DataKey::Utxo(outpoint.clone())) {
+                Some(utxo) => utxo,
+                None => continue,
+            };
+            
+            if utxo.spent {
+                // A different payout spending the same output means the records disagree
+                if utxo.spent_by != Some(withdrawal_id.clone()) {
+                    panic_with_error!(&env, ReserveError::AlreadyProcessed);
+                }
+                continue;
             }
+            
+            utxo.spent = true;
+            utxo.spent_by = Some(withdrawal_id.clone());
+            utxo.spent_at = Some(env.ledger().timestamp());
+            env.storage().persistent().set(&DataKey::Utxo(outpoint.clone()), &utxo);
+            
+            if let Some(index) = unspent.first_index_of(&outpoint) {
+                unspent.remove(index);
+            }
+            total -= utxo.amount;
+            spent_amount += utxo.amount;
         }
         
-        // Check if caller is integration router
-        if let Some(router) = env.storage().instance().get::<DataKey, Address>(&DataKey::IntegrationRouter) {
-            if router == *caller {
-                return;
+        env.storage().persistent().set(&DataKey::UnspentUtxos, &unspent);
+        env.storage().persistent().set(&DataKey::UnspentUtxoTotal, &total);
+        
+        env.events().publish(
+            (symbol_short!("utxo_pay"), withdrawal_id),
+            (outpoints.len(), spent_amount)
+        );
+        
+        spent_amount
+    }
+    
+    /// Get a UTXO by outpoint
+    pub fn get_utxo(env: Env, txid: BytesN<32>, vout: u32) -> Option<Utxo> {
+        env.storage().persistent().get(&DataKey::Utxo(UtxoOutpoint { txid, vout }))
+    }
+    
+    /// Get all unspent UTXOs
+    pub fn get_unspent_utxos(env: Env) -> Vec<Utxo> {
+        let unspent: Vec<UtxoOutpoint> = env.storage().persistent()
+            .get(&DataKey::UnspentUtxos)
+            .unwrap_or(Vec::new(&env));
+        
+        let mut utxos = Vec::new(&env);
+        for outpoint in unspent.iter() {
+            if let Some(utxo) = env.storage().persistent().get::<DataKey, Utxo>(&DataKey::Utxo(outpoint)) {
+                utxos.push_back(utxo);
             }
         }
+        utxos
+    }
+    
+    /// Get the sum of all unspent UTXO amounts in satoshis
+    pub fn get_unspent_utxo_total(env: Env) -> u64 {
+        env.storage().persistent()
+            .get(&DataKey::UnspentUtxoTotal)
+            .unwrap_or(0)
+    }
+    
+    /// Get the unspent UTXO total, or None until the first UTXO is registered
+    /// 
+    /// Reconciliation compares this against the reserve counter only once the
+    /// ledger is in use, so deployments that never registered UTXOs are not flagged.
+    pub fn get_utxo_ledger_total(env: Env) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::UnspentUtxoTotal)
+    }
+    
+    /// Get the UTXO-backed reserve ratio in basis points
+    pub fn get_utxo_reserve_ratio(env: Env) -> u64 {
+        let supply = Self::get_total_token_supply(env.clone());
+        if supply == 0 {
+            return 0;
+        }
         
-        panic_with_error!(env, ReserveError::Unauthorized);
+        (Self::get_unspent_utxo_total(env) * 10000) / supply
     }
     
-    /// Generate withdrawal ID
-    fn generate_withdrawal_id(env: &Env, user: &Address, amount: u64) -> BytesN<32> {
-        let timestamp = env.ledger().timestamp();
-        let sequence = env.ledger().sequence();
+    /// Register a yield venue or update its allocation cap (admin only)
+    pub fn register_allocation_venue(
+        env: Env,
+        caller: Address,
+        name: String,
+        cap_sats: u64
+    ) -> AllocationVenue {
+        Self::require_admin(&env, &caller);
         
-        let mut id_bytes = [0u8; 32];
-        id_bytes[0..8].copy_from_slice(&timestamp.to_be_bytes());
-        id_bytes[8..12].copy_from_slice(&sequence.to_be_bytes());
-        id_bytes[12..20].copy_from_slice(&amount.to_be_bytes());
+        if !allocation::is_valid_venue_name(&name) {
+            panic_with_error!(&env, ReserveError::InvalidInput);
+        }
         
-        // Add some user address bytes for uniqueness (simplified)
-        // In a real implementation, you'd use proper address serialization
-        let user_hash = user.clone().to_string().len() as u64;
-        id_bytes[20..28].copy_from_slice(&user_hash.to_be_bytes());
+        let venue = match Self::get_allocation_venue(env.clone(), name.clone()) {
+            Some(mut venue) => {
+                // A cap may not strand BTC that is already deployed
+                if cap_sats < venue.allocated_sats {
+                    panic_with_error!(&env, ReserveError::ThresholdBreach);
+                }
+                venue.cap_sats = cap_sats;
+                venue
+            },
+            None => {
+                let mut venues: Vec<String> = env.storage().persistent()
+                    .get(&DataKey::AllocationVenues)
+                    .unwrap_or(Vec::new(&env));
+                venues.push_back(name.clone());
+                env.storage().persistent().set(&DataKey::AllocationVenues, &venues);
+                
+                AllocationVenue {
+                    name: name.clone(),
+                    cap_sats,
+                    allocated_sats: 0,
+                    accrued_yield_sats: 0,
+                    last_attested_at: 0,
+                    last_report_hash: None,
+                    registered_at: env.ledger().timestamp(),
+                }
+            },
+        };
+        env.storage().persistent().set(&DataKey::AllocationVenue(name.clone()), &venue);
         
-        BytesN::from_array(env, &id_bytes)
-    }
-    
-    /// Update reserve ratio and store in history
-    fn update_reserve_ratio(env: &Env) {
-        let ratio = Self::get_reserve_ratio(env.clone());
-        let timestamp = env.ledger().timestamp();
+        env.events().publish(
+            (symbol_short!("venue_reg"), name),
+            (cap_sats, venue.allocated_sats)
+        );
         
-        env.storage().persistent().set(&DataKey::ReserveRatioHistory(timestamp), &ratio);
+        venue
     }
     
-    /// Check reserve thresholds and emit alerts
-    fn check_reserve_thresholds(env: &Env) {
-        let thresholds = Self::get_reserve_thresholds(env.clone());
-        let current_ratio = Self::get_reserve_ratio(env.clone());
+    /// Allow or revoke an operator attesting venue yield (admin only)
+    pub fn set_allocation_operator(
+        env: Env,
+        caller: Address,
+        operator: Address,
+        enabled: bool
+    ) {
+        Self::require_admin(&env, &caller);
         
-        if current_ratio < thresholds.critical_ratio {
-            // Critical threshold breach
-            env.events().publish(
-                (symbol_short!("alert"), symbol_short!("critical")),
-                (current_ratio, thresholds.critical_ratio)
-            );
-            
-            if thresholds.emergency_halt {
-                // In a real implementation, this would trigger emergency procedures
-                env.events().publish(
-                    (symbol_short!("emergency"), symbol_short!("halt")),
-                    current_ratio
-                );
-            }
-        } else if current_ratio < thresholds.warning_ratio {
-            // Warning threshold breach
-            env.events().publish(
-                (symbol_short!("alert"), symbol_short!("warning")),
-                (current_ratio, thresholds.warning_ratio)
-            );
+        if enabled {
+            env.storage().instance().set(&DataKey::AllocationOperator(operator.clone()), &true);
+        } else {
+            env.storage().instance().remove(&DataKey::AllocationOperator(operator.clone()));
         }
+        
+        env.events().publish((symbol_short!("alloc_op"), operator), enabled);
     }
     
-    /// Calculate merkle root of all deposits (simplified implementation)
-    fn calculate_merkle_root(env: &Env) -> BytesN<32> {
-        // In a real implementation, this would calculate the actual merkle root
-        // of all Bitcoin deposits. For now, we'll create a simple hash.
-        let reserves: u64 = env.storage().persistent()
-            .get(&DataKey::TotalReserves)
-            .unwrap_or(0);
+    /// Record BTC deployed from reserves to a venue
+    pub fn allocate_reserves(
+        env: Env,
+        caller: Address,
+        venue_name: String,
+        amount: u64
+    ) -> AllocationVenue {
+        Self::require_authorized(&env, &caller);
         
-        let timestamp = env.ledger().timestamp();
+        if amount == 0 {
+            panic_with_error!(&env, ReserveError::InvalidInput);
+        }
+        
+        let mut venue = Self::get_allocation_venue(env.clone(), venue_name.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ReserveError::NotFound));
+        
+        if amount > allocation::remaining_capacity(&venue) {
+            panic_with_error!(&env, ReserveError::ThresholdBreach);
+        }
+        
+        // Only reserves that are not already deployed can be allocated
+        let total_allocated = Self::get_total_allocated(env.clone()) + amount;
+        if total_allocated > Self::get_total_reserves(env.clone()) {
+            panic_with_error!(&env, ReserveError::InsufficientReserves);
+        }
         
-        let mut hash_input = [0u8; 32];
-        hash_input[0..8].copy_from_slice(&reserves.to_be_bytes());
-        hash_input[8..16].copy_from_slice(&timestamp.to_be_bytes());
+        venue.allocated_sats += amount;
+        env.storage().persistent().set(&DataKey::AllocationVenue(venue_name.clone()), &venue);
+        env.storage().persistent().set(&DataKey::TotalAllocated, &total_allocated);
         
-        BytesN::from_array(env, &hash_input)
+        env.events().publish(
+            (symbol_short!("alloc"), venue_name),
+            (amount, venue.allocated_sats, total_allocated)
+        );
+        
+        venue
     }
     
-    /// Generate cryptographic proof signature (simplified implementation)
-    fn generate_proof_signature(env: &Env, reserves: u64, supply: u64, ratio: u64) -> BytesN<64> {
-        // In a real implementation, this would use proper cryptographic signatures
-        // For now, we'll create a deterministic signature-like value
-        let timestamp = env.ledger().timestamp();
+    /// Record BTC returned from a venue to custody
+    pub fn recall_allocation(
+        env: Env,
+        caller: Address,
+        venue_name: String,
+        amount: u64
+    ) -> AllocationVenue {
+        Self::require_authorized(&env, &caller);
         
-        let mut signature = [0u8; 64];
-        signature[0..8].copy_from_slice(&reserves.to_be_bytes());
-        signature[8..16].copy_from_slice(&supply.to_be_bytes());
-        signature[16..24].copy_from_slice(&ratio.to_be_bytes());
-        signature[24..32].copy_from_slice(&timestamp.to_be_bytes());
+        let mut venue = Self::get_allocation_venue(env.clone(), venue_name.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ReserveError::NotFound));
         
-        // Fill the rest with a pattern
-        for i in 32..64 {
-            signature[i] = ((i * 7) % 256) as u8;
+        if amount == 0 || amount > venue.allocated_sats {
+            panic_with_error!(&env, ReserveError::InvalidInput);
         }
         
-        BytesN::from_array(env, &signature)
+        venue.allocated_sats -= amount;
+        let total_allocated = Self::get_total_allocated(env.clone()) - amount;
+        env.storage().persistent().set(&DataKey::AllocationVenue(venue_name.clone()), &venue);
+        env.storage().persistent().set(&DataKey::TotalAllocated, &total_allocated);
+        
+        env.events().publish(
+            (symbol_short!("recall"), venue_name),
+            (amount, venue.allocated_sats, total_allocated)
+        );
+        
+        venue
     }
     
-    /// Log operation for audit trail
-    fn log_operation(env: &Env, operation: OperationRecord) {
-        let timestamp = operation.timestamp;
-        env.storage().persistent().set(&DataKey::OperationHistory(timestamp), &operation);
+    /// Attest yield earned by a venue over a reporting period (allocation operators only)
+    /// 
+    /// Periods must be attested in order and may not end in the future.
+    pub fn attest_venue_yield(
+        env: Env,
+        operator: Address,
+        venue_name: String,
+        yield_sats: u64,
+        period_end: u64,
+        report_hash: BytesN<32>
+    ) -> AllocationVenue {
+        operator.require_auth();
+        
+        if !env.storage().instance().has(&DataKey::AllocationOperator(operator.clone())) {
+            panic_with_error!(&env, ReserveError::Unauthorized);
+        }
+        
+        let mut venue = Self::get_allocation_venue(env.clone(), venue_name.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ReserveError::NotFound));
+        
+        if period_end <= venue.last_attested_at {
+            panic_with_error!(&env, ReserveError::AlreadyProcessed);
+        }
+        if period_end > env.ledger().timestamp() {
+            panic_with_error!(&env, ReserveError::InvalidInput);
+        }
+        
+        venue.accrued_yield_sats += yield_sats;
+        venue.last_attested_at = period_end;
+        venue.last_report_hash = Some(report_hash.clone());
+        env.storage().persistent().set(&DataKey::AllocationVenue(venue_name.clone()), &venue);
         
         env.events().publish(
-            (symbol_short!("op_log"), operation.operation_type),
-            (operation.amount, timestamp)
+            (symbol_short!("yield_att"), venue_name, operator),
+            (yield_sats, period_end, report_hash)
         );
+        
+        venue
     }
-}
+    
+    /// Get a yield venue by name
+    pub fn get_allocation_venue(env: Env, name: String) -> Option<AllocationVenue> {
+        env.storage().persistent().get(&DataKey::AllocationVenue(name))
+    }
+    
+    /// Get the satoshis currently deployed across all venues
+    pub fn get_total_allocated(env: Env) -> u64 {
+        env.storage().persistent()
+            .get(&DataKey::TotalAllocated)
+            .unwrap_or(0)
+    }
+    
+    /// Break reserves down into on-chain custody and venue allocations
+    pub fn get_allocation_report(env: Env) -> AllocationReport {
+        let names: Vec<String> = env.storage().persistent()
+            .get(&DataKey::AllocationVenues)
+            .unwrap_or(Vec::new(&env));
+        
+        let mut venues = Vec::new(&env);
+        let mut accrued_yield_sats = 0u64;
+        for name in names.iter() {
+            if let Some(venue) = Self::get_allocation_venue(env.clone(), name) {
+                accrued_yield_sats += venue.accrued_yield_sats;
+                venues.push_back(venue);
+            }
+        }
+        
+        let total_reserves = Self::get_total_reserves(env.clone());
+        let onchain_sats = Self::get_unspent_utxo_total(env.clone());
+        let allocated_sats = Self::get_total_allocated(env.clone());
+        
+        AllocationReport {
+            total_reserves,
+            onchain_sats,
+            allocated_sats,
+            unreconciled_sats: allocation::unreconciled_sats(total_reserves, onchain_sats, allocated_sats),
+            accrued_yield_sats,
+            venues,
+            generated_at: env.ledger().timestamp(),
+        }
+    }
+    
+    /// Set the target allocation across hot, warm and cold wallets (admin only)
+    pub fn set_custody_policy(
+        env: Env,
+        caller: Address,
+        policy: CustodyPolicy
+    ) {
+        Self::require_admin(&env, &caller);
+        
+        if !custody::is_valid_policy(&policy) {
+            panic_with_error!(&env, ReserveError::InvalidInput);
+        }
+        
+        env.storage().instance().set(&DataKey::CustodyPolicy, &policy);
+        
+        env.events().publish(
+            (symbol_short!("custody"), caller),
+            (policy.hot_target_bps, policy.warm_target_bps, policy.cold_target_bps)
+        );
+        Self::check_custody_allocation(&env);
+    }
+    
+    /// Get the custody policy
+    pub fn get_custody_policy(env: Env) -> CustodyPolicy {
+        env.storage().instance()
+            .get(&DataKey::CustodyPolicy)
+            .unwrap_or(custody::default_policy())
+    }
+    
+    /// Get the satoshis held in a wallet bucket
+    pub fn get_bucket_balance(env: Env, bucket: WalletBucket) -> u64 {
+        env.storage().persistent()
+            .get(&DataKey::BucketBalance(bucket))
+            .unwrap_or(0)
+    }
+    
+    /// Get the satoshis requested for withdrawal but not yet paid out
+    pub fn get_pending_withdrawal_total(env: Env) -> u64 {
+        env.storage().persistent()
+            .get(&DataKey::PendingWithdrawalTotal)
+            .unwrap_or(0)
+    }
+    
+    /// Request a sweep of BTC between wallet buckets
+    pub fn request_rebalance(
+        env: Env,
+        caller: Address,
+        from_bucket: WalletBucket,
+        to_bucket: WalletBucket,
+        amount: u64
+    ) -> RebalanceRequest {
+        Self::require_authorized(&env, &caller);
+        
+        if amount == 0 || from_bucket == to_bucket {
+            panic_with_error!(&env, ReserveError::InvalidInput);
+        }
+        if amount > Self::get_bucket_balance(env.clone(), from_bucket) {
+            panic_with_error!(&env, ReserveError::InsufficientReserves);
+        }
+        
+        Self::create_rebalance_request(&env, from_bucket, to_bucket, amount, false)
+    }
+    
+    /// Record a confirmed sweep, moving the balance between buckets
+    pub fn complete_rebalance(
+        env: Env,
+        caller: Address,
+        request_id: u64,
+        btc_tx_hash: BytesN<32>
+    ) -> RebalanceRequest {
+        Self::require_authorized(&env, &caller);
+        
+        let request = Self::get_pending_rebalance(&env, request_id);
+        let request = Self::execute_rebalance(&env, request, btc_tx_hash);
+        Self::check_custody_allocation(&env);
+        
+        request
+    }
+    
+    /// Cancel a pending rebalance request
+    pub fn cancel_rebalance(
+        env: Env,
+        caller: Address,
+        request_id: u64
+    ) -> RebalanceRequest {
+        Self::require_authorized(&env, &caller);
+        
+        let mut request = Self::get_pending_rebalance(&env, request_id);
+        request.status = RebalanceStatus::Cancelled;
+        request.settled_at = env.ledger().timestamp();
+        Self::settle_rebalance_request(&env, &request);
+        
+        env.events().publish((symbol_short!("rebal_can"), request_id), request.amount);
+        
+        request
+    }
+    
+    /// Get a rebalance request by ID
+    pub fn get_rebalance_request(env: Env, request_id: u64) -> Option<RebalanceRequest> {
+        env.storage().persistent().get(&DataKey::RebalanceRequest(request_id))
+    }
+    
+    /// Get the rebalance requests still awaiting a sweep
+    pub fn get_pending_rebalances(env: Env) -> Vec<RebalanceRequest> {
+        let ids: Vec<u64> = env.storage().persistent()
+            .get(&DataKey::PendingRebalances)
+            .unwrap_or(Vec::new(&env));
+        
+        let mut requests = Vec::new(&env);
+        for id in ids.iter() {
+            if let Some(request) = Self::get_rebalance_request(env.clone(), id) {
+                requests.push_back(request);
+            }
+        }
+        requests
+    }
+    
+    /// Break custodied reserves down by wallet bucket against the policy targets
+    pub fn get_custody_report(env: Env) -> CustodyReport {
+        let policy = Self::get_custody_policy(env.clone());
+        let balances = Self::bucket_balances(&env);
+        let custodied_sats: u64 = balances.iter().sum();
+        
+        let mut buckets = Vec::new(&env);
+        for (bucket, balance) in custody::BUCKETS.iter().zip(balances.iter()) {
+            let actual_bps = custody::share_bps(*balance, custodied_sats);
+            let target_bps = custody::target_bps(&policy, *bucket);
+            buckets.push_back(BucketAllocation {
+                bucket: *bucket,
+                balance_sats: *balance,
+                actual_bps,
+                target_bps,
+                drifted: custodied_sats > 0
+                    && custody::is_drifted(actual_bps, target_bps, policy.drift_tolerance_bps),
+            });
+        }
+        
+        let pending_withdrawal_sats = Self::get_pending_withdrawal_total(env.clone());
+        CustodyReport {
+            total_reserves: Self::get_total_reserves(env.clone()),
+            custodied_sats,
+            pending_withdrawal_sats,
+            hot_shortfall_sats: pending_withdrawal_sats.saturating_sub(balances[0]),
+            queued_replenishment_sats: Self::queued_replenishment(&env),
+            buckets,
+            generated_at: env.ledger().timestamp(),
+        }
+    }
+    
+    /// Allow or revoke a custodian attesting wallet balances (admin only)
+    pub fn set_custodian(
+        env: Env,
+        caller: Address,
+        custodian: Address,
+        enabled: bool
+    ) {
+        Self::require_admin(&env, &caller);
+        
+        if enabled {
+            env.storage().instance().set(&DataKey::Custodian(custodian.clone()), &true);
+        } else {
+            env.storage().instance().remove(&DataKey::Custodian(custodian.clone()));
+        }
+        
+        env.events().publish((symbol_short!("custodian"), custodian), enabled);
+    }
+    
+    /// Set how long an attestation counts as fresh for reconciliation (admin only)
+    pub fn set_attestation_max_age(env: Env, caller: Address, max_age: u64) {
+        Self::require_admin(&env, &caller);
+        
+        if max_age == 0 {
+            panic_with_error!(&env, ReserveError::InvalidInput);
+        }
+        
+        env.storage().instance().set(&DataKey::AttestationMaxAge, &max_age);
+        env.events().publish((symbol_short!("att_age"), caller), max_age);
+    }
+    
+    /// Get how long an attestation counts as fresh
+    pub fn get_attestation_max_age(env: Env) -> u64 {
+        env.storage().instance()
+            .get(&DataKey::AttestationMaxAge)
+            .unwrap_or(attestation::DEFAULT_ATTESTATION_MAX_AGE)
+    }
+    
+    /// Attest a wallet bucket's balance and the movements behind it (custodians only)
+    /// 
+    /// Each movement settles the oldest pending rebalance request with the same
+    /// buckets and amount; movements without one raise an alert. Attestations
+    /// for a bucket must move forward in time and may not refer to the future.
+    pub fn submit_custodian_attestation(
+        env: Env,
+        custodian: Address,
+        bucket: WalletBucket,
+        balance_sats: u64,
+        movements: Vec<CustodyMovement>,
+        evidence_hash: BytesN<32>,
+        as_of: u64
+    ) -> CustodianAttestation {
+        custodian.require_auth();
+        
+        if !env.storage().instance().has(&DataKey::Custodian(custodian.clone())) {
+            panic_with_error!(&env, ReserveError::Unauthorized);
+        }
+        if evidence_hash == BytesN::from_array(&env, &[0u8; 32]) || as_of > env.ledger().timestamp() {
+            panic_with_error!(&env, ReserveError::InvalidInput);
+        }
+        if let Some(previous) = Self::get_latest_attestation(env.clone(), bucket) {
+            if as_of <= previous.as_of {
+                panic_with_error!(&env, ReserveError::AlreadyProcessed);
+            }
+        }
+        
+        let attestation_id: u64 = env.storage().instance()
+            .get(&DataKey::NextAttestationId)
+            .unwrap_or(1);
+        env.storage().instance().set(&DataKey::NextAttestationId, &(attestation_id + 1));
+        
+        let mut matched_requests = Vec::new(&env);
+        let mut unmatched_movements = 0u32;
+        for movement in movements.iter() {
+            let expected = Self::get_pending_rebalances(env.clone()).iter().find(|request| {
+                request.from_bucket == movement.from_bucket
+                    && request.to_bucket == movement.to_bucket
+                    && request.amount == movement.amount
+            });
+            match expected {
+                Some(request) => {
+                    matched_requests.push_back(request.request_id);
+                    Self::execute_rebalance(&env, request, movement.btc_tx_hash.clone());
+                },
+                None => {
+                    unmatched_movements += 1;
+                    env.events().publish(
+                        (symbol_short!("alert"), symbol_short!("unmatched")),
+                        (attestation_id, movement.from_bucket, movement.to_bucket, movement.amount, movement.btc_tx_hash)
+                    );
+                },
+            }
+        }
+        
+        let recorded_sats = Self::get_bucket_balance(env.clone(), bucket);
+        if recorded_sats != balance_sats {
+            env.events().publish(
+                (symbol_short!("alert"), symbol_short!("att_var")),
+                (attestation_id, bucket, balance_sats, recorded_sats)
+            );
+        }
+        
+        let attestation = CustodianAttestation {
+            attestation_id,
+            custodian: custodian.clone(),
+            bucket,
+            balance_sats,
+            recorded_sats,
+            movements,
+            matched_requests,
+            unmatched_movements,
+            evidence_hash: evidence_hash.clone(),
+            as_of,
+            submitted_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&DataKey::CustodianAttestation(attestation_id), &attestation);
+        env.storage().persistent().set(&DataKey::LatestAttestation(bucket), &attestation_id);
+        
+        env.events().publish(
+            (symbol_short!("attest"), bucket, custodian),
+            (attestation_id, balance_sats, as_of, evidence_hash)
+        );
+        Self::check_custody_allocation(&env);
+        
+        attestation
+    }
+    
+    /// Get a custodian attestation by ID
+    pub fn get_custodian_attestation(env: Env, attestation_id: u64) -> Option<CustodianAttestation> {
+        env.storage().persistent().get(&DataKey::CustodianAttestation(attestation_id))
+    }
+    
+    /// Get the latest attestation for a wallet bucket
+    pub fn get_latest_attestation(env: Env, bucket: WalletBucket) -> Option<CustodianAttestation> {
+        let attestation_id: u64 = env.storage().persistent().get(&DataKey::LatestAttestation(bucket))?;
+        Self::get_custodian_attestation(env, attestation_id)
+    }
+    
+    /// Reserves according to the latest custodian attestations, with their freshness
+    pub fn get_attested_reserves(env: Env) -> AttestedReserves {
+        let mut balances = [0u64; 3];
+        let mut attested_buckets = 0u32;
+        let mut oldest_as_of = u64::MAX;
+        for (i, bucket) in custody::BUCKETS.iter().enumerate() {
+            if let Some(attestation) = Self::get_latest_attestation(env.clone(), *bucket) {
+                balances[i] = attestation.balance_sats;
+                attested_buckets += 1;
+                oldest_as_of = oldest_as_of.min(attestation.as_of);
+            }
+        }
+        if attested_buckets == 0 {
+            oldest_as_of = 0;
+        }
+        
+        let max_age = Self::get_attestation_max_age(env.clone());
+        AttestedReserves {
+            hot_sats: balances[0],
+            warm_sats: balances[1],
+            cold_sats: balances[2],
+            total_sats: balances.iter().sum(),
+            attested_buckets,
+            oldest_as_of,
+            max_age,
+            fresh: attestation::is_fresh(attested_buckets, oldest_as_of, env.ledger().timestamp(), max_age),
+        }
+    }
+    
+    /// Attested reserve total, oldest attestation time and freshness, for reconciliation
+    pub fn get_attested_reserve_total(env: Env) -> (u64, u64, bool) {
+        let attested = Self::get_attested_reserves(env);
+        (attested.total_sats, attested.oldest_as_of, attested.fresh)
+    }
+    
+    /// Anchor the SPV header chain at a trusted checkpoint (admin only, retarget boundary)
+    pub fn initialize_spv_checkpoint(
+        env: Env,
+        caller: Address,
+        header: BytesN<80>,
+        height: u64
+    ) {
+        Self::require_admin(&env, &caller);
+        
+        if env.storage().instance().has(&DataKey::SpvTip) {
+            panic_with_error!(&env, ReserveError::AlreadyProcessed);
+        }
+        
+        // Retargeting needs the first header of each interval
+        if height % spv::RETARGET_INTERVAL != 0 {
+            panic_with_error!(&env, ReserveError::InvalidInput);
+        }
+        
+        let parsed = spv::parse_header(&env, &header);
+        let hash = spv::block_hash(&env, &header);
+        if !spv::meets_target(&env, &hash, parsed.bits) {
+            panic_with_error!(&env, ReserveError::InvalidTransaction);
+        }
+        
+        let checkpoint = SpvHeader {
+            hash: hash.clone(),
+            height,
+            prev_hash: parsed.prev_hash,
+            merkle_root: parsed.merkle_root,
+            timestamp: parsed.timestamp,
+            bits: parsed.bits,
+        };
+        Self::store_spv_header(&env, &checkpoint, &spv::header_work(&env, parsed.bits));
+        Self::set_spv_best_chain(&env, &checkpoint, height);
+        
+        env.events().publish(
+            (symbol_short!("spv_init"), hash),
+            height
+        );
+    }
+    
+    /// Allow or revoke a relayer submitting Bitcoin block headers (admin only)
+    pub fn set_spv_relayer(
+        env: Env,
+        caller: Address,
+        relayer: Address,
+        enabled: bool
+    ) {
+        Self::require_admin(&env, &caller);
+        
+        if enabled {
+            env.storage().instance().set(&DataKey::SpvRelayer(relayer.clone()), &true);
+        } else {
+            env.storage().instance().remove(&DataKey::SpvRelayer(relayer.clone()));
+        }
+        
+        env.events().publish((symbol_short!("spv_rly"), relayer), enabled);
+    }
+    
+    /// Submit block headers to the SPV header chain (admin, router or relayer)
+    /// 
+    /// The first header may build on any stored header whose branch meets the
+    /// best chain within `spv::MAX_REORG_DEPTH` of the tip. A branch becomes the
+    /// best chain once it carries more cumulative work than the current tip;
+    /// lighter branches are stored so later submissions can extend them.
+    /// Returns the height of the best tip.
+    pub fn submit_block_headers(env: Env, caller: Address, headers: Vec<BytesN<80>>) -> u64 {
+        Self::require_spv_relayer(&env, &caller);
+        
+        if headers.is_empty() || headers.len() > spv::MAX_HEADERS_PER_CALL {
+            panic_with_error!(&env, ReserveError::InvalidInput);
+        }
+        
+        let best = Self::get_spv_tip(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ReserveError::NotFound));
+        
+        let first = spv::parse_header(&env, &headers.get_unchecked(0));
+        let mut parent: SpvHeader = env.storage().persistent()
+            .get(&DataKey::SpvHeader(first.prev_hash))
+            .unwrap_or_else(|| panic_with_error!(&env, ReserveError::InvalidTransaction));
+        
+        // Retarget lookups below read the best chain, which is shared below a shallow fork
+        let fork = Self::spv_best_chain_ancestor(&env, &parent);
+        if fork.height + spv::MAX_REORG_DEPTH < best.height {
+            panic_with_error!(&env, ReserveError::InvalidTransaction);
+        }
+        
+        let mut work = Self::spv_chain_work(&env, &parent);
+        for header in headers.iter() {
+            let parsed = spv::parse_header(&env, &header);
+            if parsed.prev_hash != parent.hash {
+                panic_with_error!(&env, ReserveError::InvalidTransaction);
+            }
+            
+            let height = parent.height + 1;
+            let expected_bits = if height % spv::RETARGET_INTERVAL == 0 {
+                let first = Self::get_spv_header_at_height(&env, height - spv::RETARGET_INTERVAL)
+                    .unwrap_or_else(|| panic_with_error!(&env, ReserveError::NotFound));
+                spv::retarget_bits(&env, parent.bits, first.timestamp, parent.timestamp)
+            } else {
+                parent.bits
+            };
+            
+            if parsed.bits != expected_bits {
+                panic_with_error!(&env, ReserveError::InvalidTransaction);
+            }
+            
+            let hash = spv::block_hash(&env, &header);
+            if !spv::meets_target(&env, &hash, parsed.bits) {
+                panic_with_error!(&env, ReserveError::InvalidTransaction);
+            }
+            
+            work = work.add(&spv::header_work(&env, parsed.bits));
+            parent = SpvHeader {
+                hash,
+                height,
+                prev_hash: parsed.prev_hash,
+                merkle_root: parsed.merkle_root,
+                timestamp: parsed.timestamp,
+                bits: parsed.bits,
+            };
+            Self::store_spv_header(&env, &parent, &work);
+        }
+        
+        if work > Self::spv_chain_work(&env, &best) {
+            Self::set_spv_best_chain(&env, &parent, best.height);
+            if fork.hash != best.hash {
+                env.events().publish(
+                    (symbol_short!("spv_reorg"), parent.hash.clone()),
+                    (fork.height, best.hash.clone())
+                );
+            }
+        }
+        
+        let tip = Self::get_spv_tip(env.clone()).unwrap_or(best);
+        env.events().publish(
+            (symbol_short!("spv_hdr"), tip.hash),
+            (headers.len(), tip.height)
+        );
+        
+        tip.height
+    }
+    
+    /// Verify a deposit transaction against the SPV header chain, returning its confirmations
+    pub fn verify_deposit_spv(
+        env: Env,
+        tx_hash: BytesN<32>,
+        merkle_branch: Vec<BytesN<32>>,
+        tx_index: u32,
+        block_header: BytesN<80>
+    ) -> u32 {
+        let hash = spv::block_hash(&env, &block_header);
+        let header: SpvHeader = env.storage().persistent()
+            .get(&DataKey::SpvHeader(hash.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, ReserveError::NotFound));
+        
+        // Headers on a stale branch confirm nothing
+        if env.storage().persistent().get::<DataKey, BytesN<32>>(&DataKey::SpvHeaderAtHeight(header.height)) != Some(hash) {
+            panic_with_error!(&env, ReserveError::NotFound);
+        }
+        
+        let root = spv::merkle_root_from_branch(&env, &tx_hash, &merkle_branch, tx_index);
+        if root != header.merkle_root {
+            panic_with_error!(&env, ReserveError::InvalidTransaction);
+        }
+        
+        let tip = Self::get_spv_tip(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ReserveError::NotFound));
+        
+        (tip.height - header.height + 1) as u32
+    }
+    
+    /// Get the best header in the SPV chain
+    pub fn get_spv_tip(env: Env) -> Option<SpvHeader> {
+        let hash: BytesN<32> = env.storage().instance().get(&DataKey::SpvTip)?;
+        env.storage().persistent().get(&DataKey::SpvHeader(hash))
+    }
+    
+    /// Get an SPV header by block hash
+    pub fn get_spv_header(env: Env, hash: BytesN<32>) -> Option<SpvHeader> {
+        env.storage().persistent().get(&DataKey::SpvHeader(hash))
+    }
+    
+    /// Set reserve thresholds (admin only)
+    pub fn set_reserve_thresholds(
+        env: Env,
+        caller: Address,
+        thresholds: ReserveThresholds
+    ) {
+        Self::require_admin(&env, &caller);
+        
+        env.storage().instance().set(&DataKey::ReserveThresholds, &thresholds);
+        
+        env.events().publish(
+            (symbol_short!("thresh"), caller),
+            (thresholds.minimum_ratio, thresholds.warning_ratio, thresholds.critical_ratio)
+        );
+    }
+    
+    /// Get reserve thresholds
+    pub fn get_reserve_thresholds(env: Env) -> ReserveThresholds {
+        env.storage().instance()
+            .get(&DataKey::ReserveThresholds)
+            .unwrap_or(ReserveThresholds {
+                minimum_ratio: 10000,
+                warning_ratio: 11000,
+                critical_ratio: 10500,
+                emergency_halt: true,
+            })
+    }
+    
+    // =====================
+    // Helper Functions
+    // =====================
+    
+    /// Require caller to be admin
+    fn require_admin(env: &Env, caller: &Address) {
+        caller.require_auth();
+        
+        let admin: Address = env.storage().instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, ReserveError::Unauthorized));
+        
+        if *caller != admin {
+            panic_with_error!(env, ReserveError::Unauthorized);
+        }
+    }
+    
+    /// Require caller to be authorized (admin or integration router)
+    fn require_authorized(env: &Env, caller: &Address) {
+        caller.require_auth();
+        
+        // Check if caller is admin
+        if let Some(admin) = env.storage().instance().get::<DataKey, Address>(&DataKey::Admin) {
+            if admin == *caller {
+                return;
+            }
+        }
+        
+        // Check if caller is integration router
+        if let Some(router) = env.storage().instance().get::<DataKey, Address>(&DataKey::IntegrationRouter) {
+            if router == *caller {
+                return;
+            }
+        }
+        
+        panic_with_error!(env, ReserveError::Unauthorized);
+    }
+    
+    /// Generate withdrawal ID
+    fn generate_withdrawal_id(env: &Env, user: &Address, amount: u64) -> BytesN<32> {
+        let timestamp = env.ledger().timestamp();
+        let sequence = env.ledger().sequence();
+        
+        let mut id_bytes = [0u8; 32];
+        id_bytes[0..8].copy_from_slice(&timestamp.to_be_bytes());
+        id_bytes[8..12].copy_from_slice(&sequence.to_be_bytes());
+        id_bytes[12..20].copy_from_slice(&amount.to_be_bytes());
+        
+        // Add some user address bytes for uniqueness (simplified)
+        // In a real implementation, you'd use proper address serialization
+        let user_hash = user.clone().to_string().len() as u64;
+        id_bytes[20..28].copy_from_slice(&user_hash.to_be_bytes());
+        
+        BytesN::from_array(env, &id_bytes)
+    }
+    
+    /// Update reserve ratio and store in history
+    fn update_reserve_ratio(env: &Env) {
+        let ratio = Self::get_reserve_ratio(env.clone());
+        let timestamp = env.ledger().timestamp();
+        
+        env.storage().persistent().set(&DataKey::ReserveRatioHistory(timestamp), &ratio);
+    }
+    
+    /// Check reserve thresholds and emit alerts
+    fn check_reserve_thresholds(env: &Env) {
+        let thresholds = Self::get_reserve_thresholds(env.clone());
+        let current_ratio = Self::get_reserve_ratio(env.clone());
+        
+        if current_ratio < thresholds.critical_ratio {
+            // Critical threshold breach
+            env.events().publish(
+                (symbol_short!("alert"), symbol_short!("critical")),
+                (current_ratio, thresholds.critical_ratio)
+            );
+            
+            if thresholds.emergency_halt {
+                // In a real implementation, this would trigger emergency procedures
+                env.events().publish(
+                    (symbol_short!("emergency"), symbol_short!("halt")),
+                    current_ratio
+                );
+            }
+        } else if current_ratio < thresholds.warning_ratio {
+            // Warning threshold breach
+            env.events().publish(
+                (symbol_short!("alert"), symbol_short!("warning")),
+                (current_ratio, thresholds.warning_ratio)
+            );
+        }
+    }
+    
+    /// Calculate merkle root over reserve entries (zero hash when empty)
+    fn calculate_merkle_root(env: &Env, entries: &Vec<ReserveEntry>) -> BytesN<32> {
+        if entries.is_empty() {
+            return BytesN::from_array(env, &[0u8; 32]);
+        }
+        
+        let mut level = Self::merkle_leaves(env, entries);
+        while level.len() > 1 {
+            level = Self::merkle_parent_level(env, &level);
+        }
+        
+        level.get(0).unwrap()
+    }
+    
+    /// Hash every reserve entry into a leaf
+    fn merkle_leaves(env: &Env, entries: &Vec<ReserveEntry>) -> Vec<BytesN<32>> {
+        let mut leaves = Vec::new(env);
+        for entry in entries.iter() {
+            leaves.push_back(Self::merkle_leaf(env, &entry.utxo_id, entry.vout, entry.amount));
+        }
+        leaves
+    }
+    
+    /// Hash pairs of nodes into the next level, pairing an odd last node with itself
+    fn merkle_parent_level(env: &Env, level: &Vec<BytesN<32>>) -> Vec<BytesN<32>> {
+        let mut parents = Vec::new(env);
+        let mut i = 0;
+        while i < level.len() {
+            let left = level.get(i).unwrap();
+            let right = level.get(i + 1).unwrap_or(left.clone());
+            parents.push_back(Self::merkle_node(env, &left, &right));
+            i += 2;
+        }
+        parents
+    }
+    
+    /// Leaf hash: sha256(0x00 || txid || vout_be || amount_be)
+    fn merkle_leaf(env: &Env, utxo_id: &BytesN<32>, vout: u32, amount: u64) -> BytesN<32> {
+        let mut data = Bytes::new(env);
+        data.push_back(0x00);
+        data.extend_from_array(&utxo_id.to_array());
+        data.extend_from_array(&vout.to_be_bytes());
+        data.extend_from_array(&amount.to_be_bytes());
+        env.crypto().sha256(&data).into()
+    }
+    
+    /// Interior node hash: sha256(0x01 || left || right)
+    fn merkle_node(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+        let mut data = Bytes::new(env);
+        data.push_back(0x01);
+        data.extend_from_array(&left.to_array());
+        data.extend_from_array(&right.to_array());
+        env.crypto().sha256(&data).into()
+    }
+    
+    /// Generate cryptographic proof signature (simplified implementation)
+    fn generate_proof_signature(env: &Env, reserves: u64, supply: u64, ratio: u64) -> BytesN<64> {
+        // In a real implementation, this would use proper cryptographic signatures
+        // For now, we'll create a deterministic signature-like value
+        let timestamp = env.ledger().timestamp();
+        
+        let mut signature = [0u8; 64];
+        signature[0..8].copy_from_slice(&reserves.to_be_bytes());
+        signature[8..16].copy_from_slice(&supply.to_be_bytes());
+        signature[16..24].copy_from_slice(&ratio.to_be_bytes());
+        signature[24..32].copy_from_slice(&timestamp.to_be_bytes());
+        
+        // Fill the rest with a pattern
+        for i in 32..64 {
+            signature[i] = ((i * 7) % 256) as u8;
+        }
+        
+        BytesN::from_array(env, &signature)
+    }
+    
+    /// Store a header and its cumulative work without touching the best chain
+    fn store_spv_header(env: &Env, header: &SpvHeader, work: &U256) {
+        env.storage().persistent().set(&DataKey::SpvHeader(header.hash.clone()), header);
+        env.storage().persistent().set(&DataKey::SpvChainWork(header.hash.clone()), work);
+    }
+    
+    /// Make `tip` the best chain: re-index heights back to the fork and drop heights above it
+    fn set_spv_best_chain(env: &Env, tip: &SpvHeader, previous_height: u64) {
+        for height in tip.height + 1..=previous_height {
+            env.storage().persistent().remove(&DataKey::SpvHeaderAtHeight(height));
+        }
+        
+        let mut current = tip.clone();
+        loop {
+            let indexed: Option<BytesN<32>> = env.storage().persistent()
+                .get(&DataKey::SpvHeaderAtHeight(current.height));
+            if indexed == Some(current.hash.clone()) {
+                break;
+            }
+            env.storage().persistent().set(&DataKey::SpvHeaderAtHeight(current.height), &current.hash);
+            current = match env.storage().persistent().get(&DataKey::SpvHeader(current.prev_hash.clone())) {
+                Some(parent) => parent,
+                None => break, // Reached the checkpoint
+            };
+        }
+        
+        env.storage().instance().set(&DataKey::SpvTip, &tip.hash);
+    }
+    
+    /// First ancestor of `header` (or itself) on the best chain, at most `spv::MAX_REORG_DEPTH` steps back
+    fn spv_best_chain_ancestor(env: &Env, header: &SpvHeader) -> SpvHeader {
+        let mut current = header.clone();
+        for _ in 0..=spv::MAX_REORG_DEPTH {
+            let indexed: Option<BytesN<32>> = env.storage().persistent()
+                .get(&DataKey::SpvHeaderAtHeight(current.height));
+            if indexed == Some(current.hash.clone()) {
+                return current;
+            }
+            current = env.storage().persistent()
+                .get(&DataKey::SpvHeader(current.prev_hash.clone()))
+                .unwrap_or_else(|| panic_with_error!(env, ReserveError::InvalidTransaction));
+        }
+        panic_with_error!(env, ReserveError::InvalidTransaction)
+    }
+    
+    /// Cumulative work up to `header`
+    /// 
+    /// Headers stored before work was tracked all extended the single best chain,
+    /// so their work is approximated as height × their own header work.
+    fn spv_chain_work(env: &Env, header: &SpvHeader) -> U256 {
+        env.storage().persistent()
+            .get(&DataKey::SpvChainWork(header.hash.clone()))
+            .unwrap_or_else(|| spv::header_work(env, header.bits).mul(&U256::from_u128(env, header.height as u128 + 1)))
+    }
+    
+    /// Require the admin, the router or an SPV relayer
+    fn require_spv_relayer(env: &Env, caller: &Address) {
+        if env.storage().instance().has(&DataKey::SpvRelayer(caller.clone())) {
+            caller.require_auth();
+            return;
+        }
+        Self::require_authorized(env, caller);
+    }
+    
+    /// Look up the header stored at a given height
+    fn get_spv_header_at_height(env: &Env, height: u64) -> Option<SpvHeader> {
+        let hash: BytesN<32> = env.storage().persistent().get(&DataKey::SpvHeaderAtHeight(height))?;
+        env.storage().persistent().get(&DataKey::SpvHeader(hash))
+    }
+    
+    /// Current balances in `custody::BUCKETS` order
+    fn bucket_balances(env: &Env) -> [u64; 3] {
+        custody::BUCKETS.map(|bucket| Self::get_bucket_balance(env.clone(), bucket))
+    }
+    
+    fn adjust_bucket_balance(env: &Env, bucket: WalletBucket, delta: i128) {
+        let balance = Self::get_bucket_balance(env.clone(), bucket) as i128 + delta;
+        env.storage().persistent().set(&DataKey::BucketBalance(bucket), &(balance.max(0) as u64));
+    }
+    
+    /// Emit a drift alert for every bucket outside its target tolerance
+    fn check_custody_allocation(env: &Env) {
+        for allocation in Self::get_custody_report(env.clone()).buckets.iter() {
+            if allocation.drifted {
+                env.events().publish(
+                    (symbol_short!("alert"), symbol_short!("drift")),
+                    (allocation.bucket, allocation.actual_bps, allocation.target_bps)
+                );
+            }
+        }
+    }
+    
+    /// Satoshis already queued to move from cold to hot
+    fn queued_replenishment(env: &Env) -> u64 {
+        Self::get_pending_rebalances(env.clone()).iter()
+            .filter(|request| request.replenishment)
+            .map(|request| request.amount)
+            .sum()
+    }
+    
+    /// Queue a cold-to-hot sweep when hot cannot float the pending withdrawals
+    fn queue_hot_replenishment(env: &Env) {
+        let report = Self::get_custody_report(env.clone());
+        let needed = report.hot_shortfall_sats.saturating_sub(report.queued_replenishment_sats);
+        let cold = Self::get_bucket_balance(env.clone(), WalletBucket::Cold)
+            .saturating_sub(report.queued_replenishment_sats);
+        let amount = needed.min(cold);
+        
+        if amount > 0 {
+            Self::create_rebalance_request(env, WalletBucket::Cold, WalletBucket::Hot, amount, true);
+        }
+    }
+    
+    fn create_rebalance_request(
+        env: &Env,
+        from_bucket: WalletBucket,
+        to_bucket: WalletBucket,
+        amount: u64,
+        replenishment: bool
+    ) -> RebalanceRequest {
+        let request_id: u64 = env.storage().instance()
+            .get(&DataKey::NextRebalanceId)
+            .unwrap_or(1);
+        env.storage().instance().set(&DataKey::NextRebalanceId, &(request_id + 1));
+        
+        let request = RebalanceRequest {
+            request_id,
+            from_bucket,
+            to_bucket,
+            amount,
+            replenishment,
+            requested_at: env.ledger().timestamp(),
+            status: RebalanceStatus::Pending,
+            btc_tx_hash: None,
+            settled_at: 0,
+        };
+        env.storage().persistent().set(&DataKey::RebalanceRequest(request_id), &request);
+        
+        let mut pending: Vec<u64> = env.storage().persistent()
+            .get(&DataKey::PendingRebalances)
+            .unwrap_or(Vec::new(env));
+        pending.push_back(request_id);
+        env.storage().persistent().set(&DataKey::PendingRebalances, &pending);
+        
+        env.events().publish(
+            (symbol_short!("rebal_req"), request_id),
+            (from_bucket, to_bucket, amount, replenishment)
+        );
+        
+        request
+    }
+    
+    /// Move a pending request's amount between buckets and mark it completed
+    fn execute_rebalance(env: &Env, mut request: RebalanceRequest, btc_tx_hash: BytesN<32>) -> RebalanceRequest {
+        if request.amount > Self::get_bucket_balance(env.clone(), request.from_bucket) {
+            panic_with_error!(env, ReserveError::InsufficientReserves);
+        }
+        Self::adjust_bucket_balance(env, request.from_bucket, -(request.amount as i128));
+        Self::adjust_bucket_balance(env, request.to_bucket, request.amount as i128);
+        
+        request.status = RebalanceStatus::Completed;
+        request.btc_tx_hash = Some(btc_tx_hash.clone());
+        request.settled_at = env.ledger().timestamp();
+        Self::settle_rebalance_request(env, &request);
+        
+        env.events().publish(
+            (symbol_short!("rebal_ok"), request.request_id),
+            (request.amount, btc_tx_hash)
+        );
+        
+        request
+    }
+    
+    fn get_pending_rebalance(env: &Env, request_id: u64) -> RebalanceRequest {
+        let request = Self::get_rebalance_request(env.clone(), request_id)
+            .unwrap_or_else(|| panic_with_error!(env, ReserveError::NotFound));
+        
+        if request.status != RebalanceStatus::Pending {
+            panic_with_error!(env, ReserveError::AlreadyProcessed);
+        }
+        request
+    }
+    
+    /// Store a settled request and drop it from the pending list
+    fn settle_rebalance_request(env: &Env, request: &RebalanceRequest) {
+        env.storage().persistent().set(&DataKey::RebalanceRequest(request.request_id), request);
+        
+        let pending: Vec<u64> = env.storage().persistent()
+            .get(&DataKey::PendingRebalances)
+            .unwrap_or(Vec::new(env));
+        let mut remaining = Vec::new(env);
+        for id in pending.iter() {
+            if id != request.request_id {
+                remaining.push_back(id);
+            }
+        }
+        env.storage().persistent().set(&DataKey::PendingRebalances, &remaining);
+    }
+    
+    /// Log operation for audit trail
+    fn log_operation(env: &Env, operation: OperationRecord) {
+        let timestamp = operation.timestamp;
+        env.storage().persistent().set(&DataKey::OperationHistory(timestamp), &operation);
+        
+        env.events().publish(
+            (symbol_short!("op_log"), operation.operation_type),
+            (operation.amount, timestamp)
+        );
+    }
+}
 
 // Unit tests
 #[cfg(test)]
@@ -897,4 +2226,451 @@ mod test {
         client.register_bitcoin_deposit(&router, &tx_hash, &100_000_000u64, &6u32, &user, &800000u64);
         client.register_bitcoin_deposit(&router, &tx_hash, &100_000_000u64, &6u32, &user, &800000u64);
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_reserve_merkle_inclusion() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ReserveManager, ());
+        let client = ReserveManagerClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let router = Address::generate(&env);
+        let user = Address::generate(&env);
+        
+        client.initialize(&admin, &router);
+        
+        // Four unspent outputs; spending one leaves three to exercise the odd-node pairing
+        let custody = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
+        for i in 1..=3u8 {
+            let tx_hash = BytesN::from_array(&env, &[i; 32]);
+            client.register_utxo(&router, &tx_hash, &0u32, &(i as u64 * 10_000_000), &custody, &800000u64);
+        }
+        let spent_tx = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_utxo(&router, &spent_tx, &1u32, &5_000_000u64, &custody, &800000u64);
+        
+        // No proof published yet
+        assert!(client.get_reserve_merkle_proof(&spent_tx, &1u32).is_none());
+        
+        client.register_bitcoin_deposit(&router, &spent_tx, &5_000_000u64, &6u32, &user, &800000u64);
+        client.process_bitcoin_deposit(&router, &spent_tx);
+        let btc_address = String::from_str(&env, "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq");
+        let withdrawal_id = client.create_withdrawal_request(&router, &user, &5_000_000u64, &btc_address);
+        client.mark_utxos_spent(&router, &withdrawal_id, &Vec::from_array(&env, [UtxoOutpoint { txid: spent_tx.clone(), vout: 1 }]));
+        
+        let proof_of_reserves = client.generate_proof_of_reserves(&admin);
+        let root = proof_of_reserves.merkle_root;
+        
+        for i in 1..=3u8 {
+            let proof = client.get_reserve_merkle_proof(&BytesN::from_array(&env, &[i; 32]), &0u32).unwrap();
+            assert_eq!(proof.root, root);
+            assert_eq!(proof.amount, i as u64 * 10_000_000);
+            assert!(client.verify_reserve_inclusion(&proof, &root));
+        }
+        
+        // Spent outputs are no longer committed
+        assert!(client.get_reserve_merkle_proof(&spent_tx, &1u32).is_none());
+        
+        // A tampered amount or output index must not verify
+        let mut forged = client.get_reserve_merkle_proof(&BytesN::from_array(&env, &[2u8; 32]), &0u32).unwrap();
+        forged.amount += 1;
+        assert!(!client.verify_reserve_inclusion(&forged, &root));
+        forged.amount -= 1;
+        forged.vout = 1;
+        assert!(!client.verify_reserve_inclusion(&forged, &root));
+        
+        assert!(client.get_reserve_merkle_proof(&BytesN::from_array(&env, &[9u8; 32]), &0u32).is_none());
+    }
+    
+    #[test]
+    fn test_utxo_ledger() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ReserveManager, ());
+        let client = ReserveManagerClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let router = Address::generate(&env);
+        let user = Address::generate(&env);
+        
+        client.initialize(&admin, &router);
+        assert_eq!(client.get_utxo_ledger_total(), None);
+        
+        let tx_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let custody = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
+        client.register_bitcoin_deposit(&router, &tx_hash, &150_000_000u64, &6u32, &user, &800000u64);
+        client.process_bitcoin_deposit(&router, &tx_hash);
+        
+        client.register_utxo(&router, &tx_hash, &0u32, &100_000_000u64, &custody, &800000u64);
+        client.register_utxo(&router, &tx_hash, &1u32, &50_000_000u64, &custody, &800000u64);
+        
+        assert_eq!(client.get_unspent_utxos().len(), 2);
+        assert_eq!(client.get_unspent_utxo_total(), 150_000_000);
+        assert_eq!(client.get_utxo(&tx_hash, &0u32).unwrap().depositor, Some(user.clone()));
+        
+        client.update_token_supply(&router, &150_000_000u64);
+        assert_eq!(client.get_utxo_reserve_ratio(), 10000);
+        
+        // Spend one output for a withdrawal
+        let btc_address = String::from_str(&env, "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq");
+        let withdrawal_id = client.create_withdrawal_request(&router, &user, &50_000_000u64, &btc_address);
+        let outpoints = Vec::from_array(&env, [UtxoOutpoint { txid: tx_hash.clone(), vout: 1 }]);
+        client.mark_utxos_spent(&router, &withdrawal_id, &outpoints);
+        
+        let spent = client.get_utxo(&tx_hash, &1u32).unwrap();
+        assert!(spent.spent);
+        assert_eq!(spent.spent_by, Some(withdrawal_id));
+        assert_eq!(client.get_unspent_utxos().len(), 1);
+        assert_eq!(client.get_unspent_utxo_total(), 100_000_000);
+        assert_eq!(client.get_utxo_ledger_total(), Some(100_000_000));
+        
+        // A confirmed payout retires its inputs, skipping outputs the ledger does not track
+        let payout_id = BytesN::from_array(&env, &[8u8; 32]);
+        let payout_inputs = Vec::from_array(&env, [
+            UtxoOutpoint { txid: tx_hash.clone(), vout: 0 },
+            UtxoOutpoint { txid: BytesN::from_array(&env, &[2u8; 32]), vout: 0 },
+        ]);
+        assert_eq!(client.mark_payout_utxos_spent(&router, &payout_id, &payout_inputs), 100_000_000);
+        assert_eq!(client.mark_payout_utxos_spent(&router, &payout_id, &payout_inputs), 0);
+        assert_eq!(client.get_utxo_ledger_total(), Some(0));
+        
+        // Another payout cannot claim the same output
+        let other_payout = BytesN::from_array(&env, &[9u8; 32]);
+        assert!(client.try_mark_payout_utxos_spent(&router, &other_payout, &payout_inputs).is_err());
+    }
+    
+    fn raw_header(env: &Env, hex: &str) -> BytesN<80> {
+        let digits = hex.as_bytes();
+        let nibble = |c: u8| match c {
+            b'0'..=b'9' => c - b'0',
+            _ => c - b'a' + 10,
+        };
+        
+        let mut raw = [0u8; 80];
+        for i in 0..80 {
+            raw[i] = (nibble(digits[2 * i]) << 4) | nibble(digits[2 * i + 1]);
+        }
+        BytesN::from_array(env, &raw)
+    }
+    
+    const GENESIS_HEADER: &str = "0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c";
+    const BLOCK_1_HEADER: &str = "010000006fe28c0ab6f1b372c1a6a246ae63f74f931e8365e15a089c68d6190000000000982051fd1e4ba744bbbe680e1fee14677ba1a3c3540bf7b1cdb606e857233e0e61bc6649ffff001d01e36299";
+    const BLOCK_2_HEADER: &str = "010000004860eb18bf1b1620e37e9490fc8a427514416fd75159ab86688e9a8300000000d5fdcc541e25de1c7a5addedf24858b8bb665c9f36ef744ee42c316022c90f9bb0bc6649ffff001d08d2bd61";
+    
+    #[test]
+    fn test_spv_header_chain_and_deposit_verification() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ReserveManager, ());
+        let client = ReserveManagerClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let router = Address::generate(&env);
+        
+        client.initialize(&admin, &router);
+        
+        let genesis = raw_header(&env, GENESIS_HEADER);
+        client.initialize_spv_checkpoint(&admin, &genesis, &0u64);
+        
+        let headers = Vec::from_array(&env, [raw_header(&env, BLOCK_1_HEADER), raw_header(&env, BLOCK_2_HEADER)]);
+        assert_eq!(client.submit_block_headers(&router, &headers), 2);
+        assert_eq!(client.get_spv_tip().unwrap().height, 2);
+        
+        let block_1 = client.get_spv_header(&client.get_spv_tip().unwrap().prev_hash).unwrap();
+        assert_eq!(block_1.height, 1);
+        
+        // The genesis coinbase is the only transaction, so its hash is the merkle root
+        let genesis_coinbase = spv::parse_header(&env, &genesis).merkle_root;
+        assert_eq!(client.verify_deposit_spv(&genesis_coinbase, &Vec::new(&env), &0u32, &genesis), 3);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Error(Contract, #5)")]
+    fn test_spv_rejects_disconnected_header() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ReserveManager, ());
+        let client = ReserveManagerClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &Address::generate(&env));
+        client.initialize_spv_checkpoint(&admin, &raw_header(&env, GENESIS_HEADER), &0u64);
+        
+        // Block 2 does not build on genesis
+        client.submit_block_headers(&admin, &Vec::from_array(&env, [raw_header(&env, BLOCK_2_HEADER)]));
+    }
+    
+    #[test]
+    fn test_spv_header_submission_requires_a_relayer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ReserveManager, ());
+        let client = ReserveManagerClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let relayer = Address::generate(&env);
+        client.initialize(&admin, &Address::generate(&env));
+        client.initialize_spv_checkpoint(&admin, &raw_header(&env, GENESIS_HEADER), &0u64);
+        
+        let headers = Vec::from_array(&env, [raw_header(&env, BLOCK_1_HEADER)]);
+        assert!(client.try_submit_block_headers(&relayer, &headers).is_err());
+        
+        client.set_spv_relayer(&admin, &relayer, &true);
+        assert_eq!(client.submit_block_headers(&relayer, &headers), 1);
+        
+        client.set_spv_relayer(&admin, &relayer, &false);
+        let next = Vec::from_array(&env, [raw_header(&env, BLOCK_2_HEADER)]);
+        assert!(client.try_submit_block_headers(&relayer, &next).is_err());
+    }
+    
+    #[test]
+    fn test_spv_reorgs_to_the_branch_with_more_work() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ReserveManager, ());
+        let client = ReserveManagerClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &Address::generate(&env));
+        let genesis = raw_header(&env, GENESIS_HEADER);
+        client.initialize_spv_checkpoint(&admin, &genesis, &0u64);
+        let genesis_hash = spv::block_hash(&env, &genesis);
+        
+        // Seed a competing block 1 as the current tip; mining a real one is out of reach in a test
+        let competitor = SpvHeader {
+            hash: BytesN::from_array(&env, &[0xabu8; 32]),
+            height: 1,
+            prev_hash: genesis_hash.clone(),
+            merkle_root: BytesN::from_array(&env, &[0u8; 32]),
+            timestamp: 1_231_469_665,
+            bits: 0x1d00ffff,
+        };
+        env.as_contract(&contract_id, || {
+            let work = spv::header_work(&env, 0x1d00ffff).mul(&U256::from_u32(&env, 2));
+            ReserveManager::store_spv_header(&env, &competitor, &work);
+            ReserveManager::set_spv_best_chain(&env, &competitor, 0);
+        });
+        assert_eq!(client.get_spv_tip().unwrap(), competitor);
+        
+        // The real block 1 only ties on work, so it is kept as a side branch
+        let block_1 = raw_header(&env, BLOCK_1_HEADER);
+        assert_eq!(client.submit_block_headers(&admin, &Vec::from_array(&env, [block_1.clone()])), 1);
+        assert_eq!(client.get_spv_tip().unwrap(), competitor);
+        let coinbase_1 = spv::parse_header(&env, &block_1).merkle_root;
+        assert!(client.try_verify_deposit_spv(&coinbase_1, &Vec::new(&env), &0u32, &block_1).is_err());
+        
+        // Extending the side branch gives it more work and makes it the best chain
+        assert_eq!(client.submit_block_headers(&admin, &Vec::from_array(&env, [raw_header(&env, BLOCK_2_HEADER)])), 2);
+        let tip = client.get_spv_tip().unwrap();
+        assert_eq!(tip.prev_hash, spv::block_hash(&env, &block_1));
+        assert_eq!(client.verify_deposit_spv(&coinbase_1, &Vec::new(&env), &0u32, &block_1), 2);
+        
+        // Resubmitting known headers is harmless
+        assert_eq!(client.submit_block_headers(&admin, &Vec::from_array(&env, [block_1])), 2);
+    }
+    
+    #[test]
+    fn test_reserve_allocation_and_yield_attestation() {
+        use soroban_sdk::testutils::Ledger;
+        
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+        let contract_id = env.register(ReserveManager, ());
+        let client = ReserveManagerClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let router = Address::generate(&env);
+        let operator = Address::generate(&env);
+        let user = Address::generate(&env);
+        client.initialize(&admin, &router);
+        
+        // 2 BTC of reserves, 1.5 BTC of it still in custody
+        let tx_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_bitcoin_deposit(&router, &tx_hash, &200_000_000u64, &6u32, &user, &800000u64);
+        client.process_bitcoin_deposit(&router, &tx_hash);
+        let custody = String::from_str(&env, "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
+        client.register_utxo(&router, &tx_hash, &0u32, &150_000_000u64, &custody, &800000u64);
+        
+        let desk = String::from_str(&env, "lending_desk");
+        let vault = String::from_str(&env, "custody_vault");
+        client.register_allocation_venue(&admin, &desk, &40_000_000u64);
+        client.register_allocation_venue(&admin, &vault, &500_000_000u64);
+        
+        // Allocations respect the venue cap and the reserves not yet deployed
+        assert!(client.try_allocate_reserves(&router, &desk, &50_000_000u64).is_err());
+        client.allocate_reserves(&router, &desk, &40_000_000u64);
+        assert!(client.try_allocate_reserves(&router, &vault, &170_000_000u64).is_err());
+        client.allocate_reserves(&router, &vault, &10_000_000u64);
+        assert!(client.try_register_allocation_venue(&admin, &desk, &30_000_000u64).is_err());
+        assert_eq!(client.get_total_allocated(), 50_000_000);
+        
+        client.set_allocation_operator(&admin, &operator, &true);
+        let report_hash = BytesN::from_array(&env, &[7u8; 32]);
+        assert!(client.try_attest_venue_yield(&router, &desk, &1_000u64, &999_000u64, &report_hash).is_err());
+        assert!(client.try_attest_venue_yield(&operator, &desk, &1_000u64, &2_000_000u64, &report_hash).is_err());
+        
+        let venue = client.attest_venue_yield(&operator, &desk, &1_000u64, &999_000u64, &report_hash);
+        assert_eq!(venue.accrued_yield_sats, 1_000);
+        assert_eq!(venue.last_report_hash, Some(report_hash.clone()));
+        
+        // Periods are attested once, in order
+        assert!(client.try_attest_venue_yield(&operator, &desk, &1_000u64, &999_000u64, &report_hash).is_err());
+        client.attest_venue_yield(&operator, &desk, &500u64, &1_000_000u64, &report_hash);
+        
+        // Yield is revenue, not reserves
+        assert_eq!(client.get_total_reserves(), 200_000_000);
+        
+        let report = client.get_allocation_report();
+        assert_eq!(report.onchain_sats, 150_000_000);
+        assert_eq!(report.allocated_sats, 50_000_000);
+        assert_eq!(report.unreconciled_sats, 0);
+        assert_eq!(report.accrued_yield_sats, 1_500);
+        assert_eq!(report.venues.len(), 2);
+        
+        client.recall_allocation(&router, &desk, &15_000_000u64);
+        let report = client.get_allocation_report();
+        assert_eq!(report.allocated_sats, 35_000_000);
+        assert_eq!(report.unreconciled_sats, 15_000_000);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_withdrawal_cannot_spend_allocated_reserves() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ReserveManager, ());
+        let client = ReserveManagerClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let router = Address::generate(&env);
+        let user = Address::generate(&env);
+        client.initialize(&admin, &router);
+        
+        let tx_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_bitcoin_deposit(&router, &tx_hash, &100_000_000u64, &6u32, &user, &800000u64);
+        client.process_bitcoin_deposit(&router, &tx_hash);
+        
+        let desk = String::from_str(&env, "lending_desk");
+        client.register_allocation_venue(&admin, &desk, &100_000_000u64);
+        client.allocate_reserves(&router, &desk, &60_000_000u64);
+        
+        // Only 0.4 BTC is left in custody
+        let btc_address = String::from_str(&env, "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq");
+        let withdrawal_id = client.create_withdrawal_request(&router, &user, &50_000_000u64, &btc_address);
+        client.process_bitcoin_withdrawal(&router, &withdrawal_id, &BytesN::from_array(&env, &[2u8; 32]));
+    }
+    
+    #[test]
+    fn test_custody_buckets_rebalancing_and_hot_replenishment() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ReserveManager, ());
+        let client = ReserveManagerClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let router = Address::generate(&env);
+        let user = Address::generate(&env);
+        client.initialize(&admin, &router);
+        
+        let policy = CustodyPolicy { hot_target_bps: 1_000, warm_target_bps: 2_000, cold_target_bps: 7_000, drift_tolerance_bps: 500 };
+        assert!(client.try_set_custody_policy(&admin, &CustodyPolicy { cold_target_bps: 6_000, ..policy.clone() }).is_err());
+        assert!(client.try_set_custody_policy(&router, &policy).is_err());
+        client.set_custody_policy(&admin, &policy);
+        
+        // Deposits land hot, drifting from the targets until swept
+        let tx_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_bitcoin_deposit(&router, &tx_hash, &100_000_000u64, &6u32, &user, &800000u64);
+        client.process_bitcoin_deposit(&router, &tx_hash);
+        assert_eq!(client.get_bucket_balance(&WalletBucket::Hot), 100_000_000);
+        assert!(client.get_custody_report().buckets.iter().all(|b| b.drifted));
+        
+        assert!(client.try_request_rebalance(&router, &WalletBucket::Hot, &WalletBucket::Hot, &1u64).is_err());
+        assert!(client.try_request_rebalance(&router, &WalletBucket::Warm, &WalletBucket::Cold, &1u64).is_err());
+        let to_cold = client.request_rebalance(&router, &WalletBucket::Hot, &WalletBucket::Cold, &70_000_000u64);
+        let to_warm = client.request_rebalance(&router, &WalletBucket::Hot, &WalletBucket::Warm, &20_000_000u64);
+        let sweep_tx = BytesN::from_array(&env, &[9u8; 32]);
+        client.complete_rebalance(&router, &to_cold.request_id, &sweep_tx);
+        client.complete_rebalance(&router, &to_warm.request_id, &sweep_tx);
+        assert!(client.try_complete_rebalance(&router, &to_warm.request_id, &sweep_tx).is_err());
+        assert!(client.get_custody_report().buckets.iter().all(|b| !b.drifted));
+        
+        // Pending withdrawals beyond the hot float queue a cold replenishment
+        let btc_address = String::from_str(&env, "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq");
+        let first = client.create_withdrawal_request(&router, &user, &30_000_000u64, &btc_address);
+        let queued = client.get_pending_rebalances();
+        assert_eq!(queued.len(), 1);
+        let replenishment = queued.get_unchecked(0);
+        assert!(replenishment.replenishment);
+        assert_eq!((replenishment.from_bucket, replenishment.to_bucket, replenishment.amount), (WalletBucket::Cold, WalletBucket::Hot, 20_000_000));
+        
+        // Payouts draw on hot first, then warm
+        client.process_bitcoin_withdrawal(&router, &first, &BytesN::from_array(&env, &[2u8; 32]));
+        assert_eq!(client.get_bucket_balance(&WalletBucket::Hot), 0);
+        assert_eq!(client.get_bucket_balance(&WalletBucket::Warm), 0);
+        assert_eq!(client.get_bucket_balance(&WalletBucket::Cold), 70_000_000);
+        assert_eq!(client.get_pending_withdrawal_total(), 0);
+        
+        client.complete_rebalance(&router, &replenishment.request_id, &sweep_tx);
+        let report = client.get_custody_report();
+        assert_eq!((report.custodied_sats, report.total_reserves), (70_000_000, 70_000_000));
+        assert_eq!((report.hot_shortfall_sats, report.queued_replenishment_sats), (0, 0));
+        assert_eq!(client.get_bucket_balance(&WalletBucket::Hot), 20_000_000);
+        
+        let spare = client.request_rebalance(&router, &WalletBucket::Cold, &WalletBucket::Warm, &5_000_000u64);
+        assert_eq!(client.cancel_rebalance(&router, &spare.request_id).status, RebalanceStatus::Cancelled);
+        assert_eq!(client.get_pending_rebalances().len(), 0);
+    }
+    
+    #[test]
+    fn test_custodian_attestations_settle_rebalances_and_track_freshness() {
+        use soroban_sdk::testutils::Ledger;
+        
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+        let contract_id = env.register(ReserveManager, ());
+        let client = ReserveManagerClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let router = Address::generate(&env);
+        let custodian = Address::generate(&env);
+        let user = Address::generate(&env);
+        client.initialize(&admin, &router);
+        
+        let tx_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_bitcoin_deposit(&router, &tx_hash, &100_000_000u64, &6u32, &user, &800000u64);
+        client.process_bitcoin_deposit(&router, &tx_hash);
+        let sweep = client.request_rebalance(&router, &WalletBucket::Hot, &WalletBucket::Cold, &80_000_000u64);
+        
+        let evidence = BytesN::from_array(&env, &[7u8; 32]);
+        let sweep_tx = BytesN::from_array(&env, &[9u8; 32]);
+        let to_cold = CustodyMovement { from_bucket: WalletBucket::Hot, to_bucket: WalletBucket::Cold, amount: 80_000_000, btc_tx_hash: sweep_tx.clone() };
+        let stray = CustodyMovement { from_bucket: WalletBucket::Cold, to_bucket: WalletBucket::Warm, amount: 1_000, btc_tx_hash: sweep_tx.clone() };
+        let movements = Vec::from_array(&env, [to_cold, stray]);
+        assert!(client.try_submit_custodian_attestation(&custodian, &WalletBucket::Cold, &80_000_000u64, &movements, &evidence, &999_000u64).is_err());
+        client.set_custodian(&admin, &custodian, &true);
+        assert!(client.try_submit_custodian_attestation(&custodian, &WalletBucket::Cold, &80_000_000u64, &movements, &evidence, &1_000_001u64).is_err());
+        
+        // The expected sweep settles; the stray movement is left unmatched
+        let attestation = client.submit_custodian_attestation(&custodian, &WalletBucket::Cold, &79_999_000u64, &movements, &evidence, &999_000u64);
+        assert_eq!(attestation.matched_requests, Vec::from_array(&env, [sweep.request_id]));
+        assert_eq!(attestation.unmatched_movements, 1);
+        assert_eq!(attestation.recorded_sats, 80_000_000);
+        assert_eq!(client.get_rebalance_request(&sweep.request_id).unwrap().status, RebalanceStatus::Completed);
+        assert_eq!(client.get_bucket_balance(&WalletBucket::Hot), 20_000_000);
+        assert!(client.try_submit_custodian_attestation(&custodian, &WalletBucket::Cold, &0u64, &Vec::new(&env), &evidence, &999_000u64).is_err());
+        
+        // Attested reserves are fresh only once every bucket is covered within the max age
+        let attested = client.get_attested_reserves();
+        assert_eq!((attested.cold_sats, attested.attested_buckets, attested.fresh), (79_999_000, 1, false));
+        client.submit_custodian_attestation(&custodian, &WalletBucket::Hot, &20_000_000u64, &Vec::new(&env), &evidence, &999_500u64);
+        client.submit_custodian_attestation(&custodian, &WalletBucket::Warm, &1_000u64, &Vec::new(&env), &evidence, &999_500u64);
+        assert_eq!(client.get_attested_reserve_total(), (100_000_000, 999_000, true));
+        
+        env.ledger().with_mut(|li| li.timestamp = 999_000 + 86_401);
+        assert!(!client.get_attested_reserves().fresh);
+        client.set_attestation_max_age(&admin, &172_800u64);
+        assert!(client.get_attested_reserves().fresh);
+    }
+}
@@ -0,0 +1,173 @@
+//! Bitcoin SPV helpers
+//!
+//! Header parsing, proof-of-work and difficulty retarget checks, and
+//! transaction merkle branch evaluation. Hashes are kept in Bitcoin's
+//! internal byte order (the reverse of what block explorers display).
+
+use soroban_sdk::{contracttype, Bytes, BytesN, Env, Vec, U256};
+
+/// Blocks between difficulty adjustments
+pub const RETARGET_INTERVAL: u64 = 2016;
+
+/// Expected duration of a retarget interval (two weeks)
+pub const TARGET_TIMESPAN: u64 = 14 * 24 * 60 * 60;
+
+/// Compact encoding of the mainnet proof-of-work limit
+pub const POW_LIMIT_BITS: u32 = 0x1d00ffff;
+
+/// Deepest fork, below the best tip, that a submitted branch may start from
+pub const MAX_REORG_DEPTH: u64 = 100;
+
+/// Headers accepted by one submission
+pub const MAX_HEADERS_PER_CALL: u32 = 144;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpvHeader {
+    pub hash: BytesN<32>,
+    pub height: u64,
+    pub prev_hash: BytesN<32>,
+    pub merkle_root: BytesN<32>,
+    pub timestamp: u64,
+    pub bits: u32,
+}
+
+/// Fields of a raw 80-byte block header
+pub struct ParsedHeader {
+    pub prev_hash: BytesN<32>,
+    pub merkle_root: BytesN<32>,
+    pub timestamp: u64,
+    pub bits: u32,
+}
+
+/// Parse a raw 80-byte block header
+pub fn parse_header(env: &Env, header: &BytesN<80>) -> ParsedHeader {
+    let raw = header.to_array();
+
+    let mut prev_hash = [0u8; 32];
+    prev_hash.copy_from_slice(&raw[4..36]);
+    let mut merkle_root = [0u8; 32];
+    merkle_root.copy_from_slice(&raw[36..68]);
+
+    ParsedHeader {
+        prev_hash: BytesN::from_array(env, &prev_hash),
+        merkle_root: BytesN::from_array(env, &merkle_root),
+        timestamp: u32::from_le_bytes([raw[68], raw[69], raw[70], raw[71]]) as u64,
+        bits: u32::from_le_bytes([raw[72], raw[73], raw[74], raw[75]]),
+    }
+}
+
+/// Double SHA-256 as used for block and transaction hashes
+pub fn hash256(env: &Env, data: &Bytes) -> BytesN<32> {
+    let first = env.crypto().sha256(data).to_array();
+    env.crypto().sha256(&Bytes::from_array(env, &first)).into()
+}
+
+/// Block hash of a raw header
+pub fn block_hash(env: &Env, header: &BytesN<80>) -> BytesN<32> {
+    hash256(env, &Bytes::from_array(env, &header.to_array()))
+}
+
+/// Expand compact `bits` into a 256-bit target (zero for invalid encodings)
+pub fn target_from_bits(env: &Env, bits: u32) -> U256 {
+    let exponent = bits >> 24;
+    let mantissa = bits & 0x007fffff;
+
+    // Negative or oversized targets are never valid
+    if bits & 0x00800000 != 0 || exponent > 32 {
+        return U256::from_u32(env, 0);
+    }
+
+    if exponent <= 3 {
+        U256::from_u32(env, mantissa >> (8 * (3 - exponent)))
+    } else {
+        U256::from_u32(env, mantissa).shl(8 * (exponent - 3))
+    }
+}
+
+/// Compress a 256-bit target into compact `bits`
+pub fn bits_from_target(target: &U256) -> u32 {
+    let mut bytes = [0u8; 32];
+    target.to_be_bytes().copy_into_slice(&mut bytes);
+
+    let first = match bytes.iter().position(|b| *b != 0) {
+        Some(index) => index,
+        None => return 0,
+    };
+
+    let mut size = (32 - first) as u32;
+    let byte_at = |i: usize| if i < 32 { bytes[i] as u32 } else { 0 };
+    let mut compact = (byte_at(first) << 16) | (byte_at(first + 1) << 8) | byte_at(first + 2);
+
+    // The mantissa is signed; shift right if the high bit would be set
+    if compact & 0x00800000 != 0 {
+        compact >>= 8;
+        size += 1;
+    }
+
+    compact | (size << 24)
+}
+
+/// Check that a block hash satisfies the target encoded in `bits`
+pub fn meets_target(env: &Env, hash: &BytesN<32>, bits: u32) -> bool {
+    let target = target_from_bits(env, bits);
+    if target == U256::from_u32(env, 0) || target > target_from_bits(env, POW_LIMIT_BITS) {
+        return false;
+    }
+
+    // Hashes are little-endian integers
+    let mut big_endian = hash.to_array();
+    big_endian.reverse();
+    let value = U256::from_be_bytes(env, &Bytes::from_array(env, &big_endian));
+
+    value <= target
+}
+
+/// Expected hashes needed to find a block at `bits`: 2^256 / (target + 1)
+pub fn header_work(env: &Env, bits: u32) -> U256 {
+    let target = target_from_bits(env, bits);
+    let one = U256::from_u32(env, 1);
+    let max = U256::from_be_bytes(env, &Bytes::from_array(env, &[0xff; 32]));
+
+    // (2^256 - 1 - target) / (target + 1) + 1 avoids representing 2^256
+    max.sub(&target).div(&target.add(&one)).add(&one)
+}
+
+/// Compute the bits required after a retarget interval
+pub fn retarget_bits(env: &Env, prev_bits: u32, first_timestamp: u64, last_timestamp: u64) -> u32 {
+    let actual_timespan = last_timestamp
+        .saturating_sub(first_timestamp)
+        .clamp(TARGET_TIMESPAN / 4, TARGET_TIMESPAN * 4);
+
+    let new_target = target_from_bits(env, prev_bits)
+        .mul(&U256::from_u128(env, actual_timespan as u128))
+        .div(&U256::from_u128(env, TARGET_TIMESPAN as u128));
+
+    let pow_limit = target_from_bits(env, POW_LIMIT_BITS);
+    if new_target > pow_limit {
+        bits_from_target(&pow_limit)
+    } else {
+        bits_from_target(&new_target)
+    }
+}
+
+/// Fold a transaction merkle branch up to the block merkle root
+pub fn merkle_root_from_branch(env: &Env, tx_hash: &BytesN<32>, branch: &Vec<BytesN<32>>, tx_index: u32) -> BytesN<32> {
+    let mut hash = tx_hash.clone();
+    let mut index = tx_index;
+
+    for sibling in branch.iter() {
+        let mut data = Bytes::new(env);
+        if index & 1 == 0 {
+            data.extend_from_array(&hash.to_array());
+            data.extend_from_array(&sibling.to_array());
+        } else {
+            data.extend_from_array(&sibling.to_array());
+            data.extend_from_array(&hash.to_array());
+        }
+        hash = hash256(env, &data);
+        index >>= 1;
+    }
+
+    hash
+}
@@ -13,11 +13,14 @@ pub enum IntegrationError {
     ContractNotFound = 10,
     ContractCallFailed = 11,
     InvalidContractResponse = 12,
+    OracleStale = 13,
+    ContractIsolated = 14,
     
     // Compliance & KYC
     ComplianceCheckFailed = 20,
     InsufficientKYCTier = 21,
     AddressBlacklisted = 22,
+    JurisdictionRestricted = 23,
     
     // Reserve Management
     InsufficientReserves = 30,
@@ -28,11 +31,15 @@ pub enum IntegrationError {
     OperationTimeout = 40,
     InvalidOperationState = 41,
     DuplicateOperation = 42,
+    RateLimited = 43,
+    DustAmount = 44,
     
     // System State
     SystemPaused = 50,
     EmergencyMode = 51,
     MaintenanceMode = 52,
+    WorkflowPaused = 53,
+    MigrationInProgress = 54,
 }
 
 #[contracterror]